@@ -1,44 +1,69 @@
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, Result};
 use futures::StreamExt as _;
 use hex::ToHex;
 use lightning_invoice::Bolt11Invoice;
-use lnd_grpc_tonic_client::{
-    create_invoices_client, create_router_client, invoicesrpc, lnrpc, routerrpc, InvoicesClient,
-    RouterClient, Uri,
-};
 use ractor::{call, RpcReplyPort};
 use ractor::{Actor, ActorCell, ActorProcessingErr, ActorRef};
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::{select, time::sleep};
 use tokio_util::{sync::CancellationToken, task::TaskTracker};
 
 use crate::ckb::contracts::{get_script_by_contract, Contract};
 use crate::fiber::channel::{
-    AddTlcCommand, ChannelCommand, ChannelCommandWithId, RemoveTlcCommand, TlcNotification,
+    AddTlcCommand, ChannelActorStateStore, ChannelCommand, ChannelCommandWithId, ChannelState,
+    RemoveTlcCommand, TlcNotification,
 };
 use crate::fiber::hash_algorithm::HashAlgorithm;
-use crate::fiber::types::{Hash256, LockTime, RemoveTlcFulfill, RemoveTlcReason};
+use crate::fiber::subscription::NodeEventSubscribers;
+use crate::fiber::types::{
+    Hash256, LockTime, RemoveTlcFail, RemoveTlcFulfill, RemoveTlcReason, TlcErr,
+};
 use crate::fiber::{NetworkActorCommand, NetworkActorMessage};
 use crate::invoice::Currency;
+use crate::ldk::LdkBackend;
 
+use super::backend::{InvoiceUpdate, LightningBackend, PaymentUpdate};
 use super::error::CchDbError;
-use super::{CchConfig, CchError, CchOrderStatus, CchOrdersDb, ReceiveBTCOrder, SendBTCOrder};
+use super::{
+    CchConfig, CchError, CchOrderEvent, CchOrderStatus, CchOrderStore, CchPricing,
+    LightningBackendType, LndBackend, ReceiveBTCOrder, SendBTCOrder, SwapDirection, SwapQuote,
+};
 
 pub const BTC_PAYMENT_TIMEOUT_SECONDS: i32 = 60;
 pub const DEFAULT_ORDER_EXPIRY_SECONDS: u64 = 86400; // 24 hours
+pub const EXPIRED_ORDERS_CHECK_INTERVAL: Duration = Duration::from_secs(60);
 
-pub async fn start_cch(
+pub async fn start_cch<S: CchOrderStore + ChannelActorStateStore + Send + Sync + 'static>(
     config: CchConfig,
     tracker: TaskTracker,
     token: CancellationToken,
     root_actor: ActorCell,
     network_actor: Option<ActorRef<NetworkActorMessage>>,
+    store: S,
+    node_events: NodeEventSubscribers,
+    ldk_backend: Option<LdkBackend>,
 ) -> Result<ActorRef<CchMessage>> {
+    let backend: Arc<dyn LightningBackend> = match config.lightning_backend {
+        LightningBackendType::Lnd => Arc::new(LndBackend::new(&config).await?),
+        LightningBackendType::Ldk => Arc::new(
+            ldk_backend.ok_or_else(|| anyhow!("ldk lightning backend requires the ldk service"))?,
+        ),
+    };
     let (actor, _handle) = Actor::spawn_linked(
         Some("cch actor".to_string()),
-        CchActor::new(config, tracker, token, network_actor),
+        CchActor::new(
+            config,
+            tracker,
+            token,
+            network_actor,
+            store,
+            node_events,
+            backend,
+        ),
         (),
         root_actor,
     )
@@ -64,6 +89,8 @@ pub struct SettleReceiveBTCOrderEvent {
 pub struct SendBTC {
     pub btc_pay_req: String,
     pub currency: Currency,
+    /// Charge the fee of this quote instead of the current price.
+    pub quote_id: Option<Hash256>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -79,13 +106,41 @@ pub struct ReceiveBTC {
     pub amount_sats: u128,
     /// Expiry set for the HTLC for the CKB payment to the payee.
     pub final_tlc_expiry: u64,
+    /// Charge the fee of this quote instead of the current price.
+    pub quote_id: Option<Hash256>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct QuoteSwap {
+    pub direction: SwapDirection,
+    /// The BTC invoice amount for SendBTC, or the amount paid via BTC including the fee for
+    /// ReceiveBTC.
+    pub amount_sats: u128,
+    /// The channel to pay the CKB payee for ReceiveBTC. The liquidity of all the wrapped BTC
+    /// channels is used if it's not set.
+    pub channel_id: Option<Hash256>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CchOrders {
+    pub send_btc_orders: Vec<SendBTCOrder>,
+    pub receive_btc_orders: Vec<ReceiveBTCOrder>,
 }
 
 pub enum CchMessage {
     SendBTC(SendBTC, RpcReplyPort<Result<SendBTCOrder, CchError>>),
     ReceiveBTC(ReceiveBTC, RpcReplyPort<Result<ReceiveBTCOrder, CchError>>),
+    /// Quote the fee of a swap, the quote can be used by the order created before it expires.
+    QuoteSwap(QuoteSwap, RpcReplyPort<Result<SwapQuote, CchError>>),
 
+    GetSendBTCOrder(String, RpcReplyPort<Result<SendBTCOrder, CchError>>),
     GetReceiveBTCOrder(String, RpcReplyPort<Result<ReceiveBTCOrder, CchError>>),
+    /// List the orders, optionally filtered by the status.
+    ListOrders(Option<CchOrderStatus>, RpcReplyPort<CchOrders>),
+
+    /// Fail the orders which are expired before any payment is made, and drop the expired
+    /// quotes.
+    CheckExpiredOrders,
 
     SettleSendBTCOrder(SettleSendBTCOrderEvent),
     SettleReceiveBTCOrder(SettleReceiveBTCOrderEvent),
@@ -94,51 +149,27 @@ pub enum CchMessage {
     SettledTlcNotification(TlcNotification),
 }
 
-#[derive(Clone)]
-struct LndConnectionInfo {
-    uri: Uri,
-    cert: Option<Vec<u8>>,
-    macaroon: Option<Vec<u8>>,
-}
-
-impl LndConnectionInfo {
-    async fn create_router_client(
-        &self,
-    ) -> Result<RouterClient, lnd_grpc_tonic_client::channel::Error> {
-        create_router_client(
-            self.uri.clone(),
-            self.cert.as_deref(),
-            self.macaroon.as_deref(),
-        )
-        .await
-    }
-
-    async fn create_invoices_client(
-        &self,
-    ) -> Result<InvoicesClient, lnd_grpc_tonic_client::channel::Error> {
-        create_invoices_client(
-            self.uri.clone(),
-            self.cert.as_deref(),
-            self.macaroon.as_deref(),
-        )
-        .await
-    }
+#[derive(Default)]
+pub struct CchState {
+    // The quotes are kept in memory only, they are short-lived and used at most once.
+    quotes: HashMap<Hash256, SwapQuote>,
 }
 
-pub struct CchActor {
+pub struct CchActor<S> {
     config: CchConfig,
     tracker: TaskTracker,
     token: CancellationToken,
     network_actor: Option<ActorRef<NetworkActorMessage>>,
-}
-
-pub struct CchState {
-    lnd_connection: LndConnectionInfo,
-    orders_db: CchOrdersDb,
+    store: S,
+    node_events: NodeEventSubscribers,
+    backend: Arc<dyn LightningBackend>,
 }
 
 #[ractor::async_trait]
-impl Actor for CchActor {
+impl<S> Actor for CchActor<S>
+where
+    S: CchOrderStore + ChannelActorStateStore + Send + Sync + 'static,
+{
     type Msg = CchMessage;
     type State = CchState;
     type Arguments = ();
@@ -148,38 +179,45 @@ impl Actor for CchActor {
         myself: ActorRef<Self::Msg>,
         _config: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
-        let lnd_rpc_url: Uri = self.config.lnd_rpc_url.clone().try_into()?;
-        let cert = match self.config.resolve_lnd_cert_path() {
-            Some(path) => Some(
-                tokio::fs::read(&path)
-                    .await
-                    .with_context(|| format!("read cert file {}", path.display()))?,
-            ),
-            None => None,
-        };
-        let macaroon = match self.config.resolve_lnd_macaroon_path() {
-            Some(path) => Some(
-                tokio::fs::read(&path)
-                    .await
-                    .with_context(|| format!("read macaroon file {}", path.display()))?,
-            ),
-            None => None,
-        };
-        let lnd_connection = LndConnectionInfo {
-            uri: lnd_rpc_url,
-            cert,
-            macaroon,
-        };
-
-        let payments_tracker =
-            LndPaymentsTracker::new(myself.clone(), lnd_connection.clone(), self.token.clone());
+        // Resume tracking the orders which are not finished before the last shutdown. The
+        // outgoing BTC payments may be finished while we are offline, so they are queried
+        // individually before tracking the new payments.
+        let pending_payment_hashes = self
+            .store
+            .get_send_btc_orders()
+            .into_iter()
+            .filter(|order| order.tlc_id.is_some() && !order.status.is_final())
+            .map(|order| order.payment_hash)
+            .collect();
+        let payments_tracker = PaymentsTracker::new(
+            myself.clone(),
+            self.backend.clone(),
+            self.token.clone(),
+            pending_payment_hashes,
+        );
         self.tracker
             .spawn(async move { payments_tracker.run().await });
 
-        Ok(CchState {
-            lnd_connection,
-            orders_db: Default::default(),
-        })
+        for order in self.store.get_receive_btc_orders() {
+            if order.status.is_final() {
+                continue;
+            }
+            tracing::info!("Resume tracking ReceiveBTC order {}", order.payment_hash);
+            let invoice_tracker = InvoiceTracker::new(
+                myself.clone(),
+                order.payment_hash,
+                self.backend.clone(),
+                self.token.clone(),
+            );
+            self.tracker
+                .spawn(async move { invoice_tracker.run().await });
+        }
+
+        myself.send_interval(EXPIRED_ORDERS_CHECK_INTERVAL, || {
+            CchMessage::CheckExpiredOrders
+        });
+
+        Ok(CchState::default())
     }
 
     async fn handle(
@@ -205,11 +243,29 @@ impl Actor for CchActor {
                 }
                 Ok(())
             }
+            CchMessage::QuoteSwap(quote_swap, port) => {
+                let result = self.quote_swap(state, quote_swap);
+                if !port.is_closed() {
+                    // ignore error
+                    let _ = port.send(result);
+                }
+                Ok(())
+            }
+            CchMessage::GetSendBTCOrder(payment_hash, port) => {
+                let result = self
+                    .store
+                    .get_send_btc_order(&payment_hash)
+                    .map_err(Into::into);
+                if !port.is_closed() {
+                    // ignore error
+                    let _ = port.send(result);
+                }
+                Ok(())
+            }
             CchMessage::GetReceiveBTCOrder(payment_hash, port) => {
-                let result = state
-                    .orders_db
+                let result = self
+                    .store
                     .get_receive_btc_order(&payment_hash)
-                    .await
                     .map_err(Into::into);
                 if !port.is_closed() {
                     // ignore error
@@ -217,23 +273,58 @@ impl Actor for CchActor {
                 }
                 Ok(())
             }
+            CchMessage::ListOrders(status, port) => {
+                let matches_status = |order_status: CchOrderStatus| {
+                    status.map_or(true, |status| status == order_status)
+                };
+                let orders = CchOrders {
+                    send_btc_orders: self
+                        .store
+                        .get_send_btc_orders()
+                        .into_iter()
+                        .filter(|order| matches_status(order.status))
+                        .collect(),
+                    receive_btc_orders: self
+                        .store
+                        .get_receive_btc_orders()
+                        .into_iter()
+                        .filter(|order| matches_status(order.status))
+                        .collect(),
+                };
+                if !port.is_closed() {
+                    // ignore error
+                    let _ = port.send(orders);
+                }
+                Ok(())
+            }
+            CchMessage::CheckExpiredOrders => {
+                if let Ok(now) = SystemTime::now().duration_since(UNIX_EPOCH) {
+                    state
+                        .quotes
+                        .retain(|_, quote| !quote.is_expired(now.as_secs()));
+                }
+                if let Err(err) = self.check_expired_orders().await {
+                    tracing::error!("check_expired_orders failed: {}", err);
+                }
+                Ok(())
+            }
             CchMessage::SettleSendBTCOrder(event) => {
                 tracing::debug!("settle_send_btc_order {:?}", event);
-                if let Err(err) = self.settle_send_btc_order(state, event).await {
+                if let Err(err) = self.settle_send_btc_order(event).await {
                     tracing::error!("settle_send_btc_order failed: {}", err);
                 }
                 Ok(())
             }
             CchMessage::SettleReceiveBTCOrder(event) => {
                 tracing::debug!("settle_receive_btc_order {:?}", event);
-                if let Err(err) = self.settle_receive_btc_order(state, event).await {
+                if let Err(err) = self.settle_receive_btc_order(event).await {
                     tracing::error!("settle_receive_btc_order failed: {}", err);
                 }
                 Ok(())
             }
             CchMessage::PendingReceivedTlcNotification(tlc_notification) => {
                 if let Err(err) = self
-                    .handle_pending_received_tlc_notification(state, tlc_notification)
+                    .handle_pending_received_tlc_notification(tlc_notification)
                     .await
                 {
                     tracing::error!("handle_pending_received_tlc_notification failed: {}", err);
@@ -241,10 +332,7 @@ impl Actor for CchActor {
                 Ok(())
             }
             CchMessage::SettledTlcNotification(tlc_notification) => {
-                if let Err(err) = self
-                    .handle_settled_tlc_notification(state, tlc_notification)
-                    .await
-                {
+                if let Err(err) = self.handle_settled_tlc_notification(tlc_notification).await {
                     tracing::error!("handle_settled_tlc_notification failed: {}", err);
                 }
                 Ok(())
@@ -253,21 +341,149 @@ impl Actor for CchActor {
     }
 }
 
-impl CchActor {
+impl<S> CchActor<S>
+where
+    S: CchOrderStore + ChannelActorStateStore,
+{
     pub fn new(
         config: CchConfig,
         tracker: TaskTracker,
         token: CancellationToken,
         network_actor: Option<ActorRef<NetworkActorMessage>>,
+        store: S,
+        node_events: NodeEventSubscribers,
+        backend: Arc<dyn LightningBackend>,
     ) -> Self {
         Self {
             config,
             tracker,
             token,
             network_actor,
+            store,
+            node_events,
+            backend,
         }
     }
 
+    // Save the updated order and notify the subscribers.
+    fn update_send_btc_order(&self, order: SendBTCOrder) -> Result<(), CchDbError> {
+        self.store.update_send_btc_order(order.clone())?;
+        self.node_events
+            .notify_cch_order(CchOrderEvent::SendBTC(order));
+        Ok(())
+    }
+
+    // Save the updated order and notify the subscribers.
+    fn update_receive_btc_order(&self, order: ReceiveBTCOrder) -> Result<(), CchDbError> {
+        self.store.update_receive_btc_order(order.clone())?;
+        self.node_events
+            .notify_cch_order(CchOrderEvent::ReceiveBTC(order));
+        Ok(())
+    }
+
+    fn wrapped_btc_type_script(&self) -> Result<ckb_types::packed::Script, CchError> {
+        let args = hex::decode(
+            self.config
+                .wrapped_btc_type_script_args
+                .trim_start_matches("0x"),
+        )
+        .map_err(|_| {
+            CchError::HexDecodingError(self.config.wrapped_btc_type_script_args.clone())
+        })?;
+        Ok(get_script_by_contract(Contract::SimpleUDT, &args))
+    }
+
+    // The wrapped BTC balance the swap is settled with. The hub receives wrapped BTC for
+    // SendBTC orders, and pays wrapped BTC for ReceiveBTC orders.
+    fn available_liquidity_sats(
+        &self,
+        direction: SwapDirection,
+        channel_id: Option<Hash256>,
+    ) -> Result<u128, CchError> {
+        let wrapped_btc_type_script = self.wrapped_btc_type_script()?;
+        let channel_ids = match channel_id {
+            Some(channel_id) => vec![channel_id],
+            None => self
+                .store
+                .get_active_channel_states(None)
+                .into_iter()
+                .map(|(_, channel_id, _)| channel_id)
+                .collect(),
+        };
+        Ok(channel_ids
+            .iter()
+            .filter_map(|channel_id| self.store.get_channel_actor_state(channel_id))
+            .filter(|channel| {
+                matches!(channel.state, ChannelState::ChannelReady())
+                    && channel.funding_udt_type_script.as_ref() == Some(&wrapped_btc_type_script)
+            })
+            .map(|channel| match direction {
+                SwapDirection::SendBtc => channel.to_remote_amount,
+                SwapDirection::ReceiveBtc => channel.to_local_amount,
+            })
+            .sum())
+    }
+
+    fn swap_fee_sats(
+        &self,
+        direction: SwapDirection,
+        amount_msat: u128,
+        channel_id: Option<Hash256>,
+    ) -> Result<u128, CchError> {
+        let pricing = CchPricing::new(&self.config, direction);
+        let liquidity_sats = if pricing.requires_liquidity() {
+            self.available_liquidity_sats(direction, channel_id)?
+        } else {
+            0
+        };
+        pricing.fee_sats(amount_msat, liquidity_sats)
+    }
+
+    // Use the quoted fee for the order, the quote is removed once used.
+    fn take_quote(
+        &self,
+        state: &mut CchState,
+        quote_id: Hash256,
+        direction: SwapDirection,
+        amount_sats: u128,
+        now: u64,
+    ) -> Result<u128, CchError> {
+        let quote = state
+            .quotes
+            .remove(&quote_id)
+            .filter(|quote| !quote.is_expired(now))
+            .ok_or_else(|| CchError::SwapQuoteNotFound(format!("{:#x}", quote_id)))?;
+        if quote.direction != direction || quote.amount_sats != amount_sats {
+            return Err(CchError::SwapQuoteMismatch);
+        }
+        Ok(quote.fee_sats)
+    }
+
+    fn quote_swap(
+        &self,
+        state: &mut CchState,
+        quote_swap: QuoteSwap,
+    ) -> Result<SwapQuote, CchError> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let pricing = CchPricing::new(&self.config, quote_swap.direction);
+        pricing.check_amount_sats(quote_swap.amount_sats)?;
+        let amount_msat = quote_swap
+            .amount_sats
+            .checked_mul(1_000u128)
+            .ok_or(CchError::SwapAmountTooLarge(pricing.max_amount_sats()))?;
+        let fee_sats =
+            self.swap_fee_sats(quote_swap.direction, amount_msat, quote_swap.channel_id)?;
+        let quote = SwapQuote {
+            quote_id: rand::random::<[u8; 32]>().into(),
+            direction: quote_swap.direction,
+            amount_sats: quote_swap.amount_sats,
+            fee_sats,
+            expires_at: now + self.config.quote_expiry,
+        };
+        state.quotes.insert(quote.quote_id, quote.clone());
+        Ok(quote)
+    }
+
     async fn send_btc(
         &self,
         state: &mut CchState,
@@ -288,23 +504,18 @@ impl CchActor {
             .amount_milli_satoshis()
             .ok_or(CchError::BTCInvoiceMissingAmount)? as u128;
 
-        let fee_sats = amount_msat * (self.config.fee_rate_per_million_sats as u128)
-            / 1_000_000_000u128
-            + (self.config.base_fee_sats as u128);
+        let fee_sats = match send_btc.quote_id {
+            Some(quote_id) => self.take_quote(
+                state,
+                quote_id,
+                SwapDirection::SendBtc,
+                amount_msat.div_ceil(1_000u128),
+                duration_since_epoch.as_secs(),
+            )?,
+            None => self.swap_fee_sats(SwapDirection::SendBtc, amount_msat, None)?,
+        };
 
-        let wrapped_btc_type_script: ckb_jsonrpc_types::Script = get_script_by_contract(
-            Contract::SimpleUDT,
-            hex::decode(
-                self.config
-                    .wrapped_btc_type_script_args
-                    .trim_start_matches("0x"),
-            )
-            .map_err(|_| {
-                CchError::HexDecodingError(self.config.wrapped_btc_type_script_args.clone())
-            })?
-            .as_ref(),
-        )
-        .into();
+        let wrapped_btc_type_script = self.wrapped_btc_type_script()?.into();
         let mut order = SendBTCOrder {
             expires_after: expiry,
             wrapped_btc_type_script,
@@ -323,8 +534,9 @@ impl CchActor {
         };
         order.generate_ckb_invoice()?;
 
-        state.orders_db.insert_send_btc_order(order.clone()).await?;
-        // TODO(now): save order and invoice into db: store.insert_invoice(invoice.clone())
+        self.store.insert_send_btc_order(order.clone())?;
+        self.node_events
+            .notify_cch_order(CchOrderEvent::SendBTC(order.clone()));
 
         Ok(order)
     }
@@ -332,13 +544,12 @@ impl CchActor {
     // On receiving new TLC, check whether it matches the SendBTC order
     async fn handle_pending_received_tlc_notification(
         &self,
-        state: &mut CchState,
         tlc_notification: TlcNotification,
     ) -> Result<()> {
         let payment_hash = format!("{:#x}", tlc_notification.tlc.payment_hash);
         tracing::debug!("[inbounding tlc] payment hash: {}", payment_hash);
 
-        let mut order = match state.orders_db.get_send_btc_order(&payment_hash).await {
+        let mut order = match self.store.get_send_btc_order(&payment_hash) {
             Err(CchDbError::NotFound(_)) => return Ok(()),
             Err(err) => return Err(err.into()),
             Ok(order) => order,
@@ -355,28 +566,13 @@ impl CchActor {
 
         order.channel_id = Some(tlc_notification.channel_id);
         order.tlc_id = Some(tlc_notification.tlc.id.into());
-        state.orders_db.update_send_btc_order(order.clone()).await?;
-
-        let req = routerrpc::SendPaymentRequest {
-            payment_request: order.btc_pay_req.clone(),
-            timeout_seconds: BTC_PAYMENT_TIMEOUT_SECONDS,
-            ..Default::default()
-        };
-        tracing::debug!("[inbounding tlc] SendPaymentRequest: {:?}", req);
+        self.update_send_btc_order(order.clone())?;
 
-        let mut client = state.lnd_connection.create_router_client().await?;
-        // TODO: set a fee
-        let mut stream = client.send_payment_v2(req).await?.into_inner();
-        // Wait for the first message then quit
         select! {
-            payment_result_opt = stream.next() => {
-                tracing::debug!("[inbounding tlc] payment result: {:?}", payment_result_opt);
-                if let Some(Ok(payment)) = payment_result_opt {
-                    order.status = lnrpc::payment::PaymentStatus::try_from(payment.status)?.into();
-                    state.orders_db
-                        .update_send_btc_order(order)
-                        .await?;
-                }
+            payment_result = self.backend.pay_invoice(&order.btc_pay_req) => {
+                tracing::debug!("[inbounding tlc] payment result: {:?}", payment_result);
+                order.status = payment_result?.status;
+                self.update_send_btc_order(order)?;
             }
             _ = self.token.cancelled() => {
                 tracing::debug!("Cancellation received, shutting down cch service");
@@ -389,13 +585,12 @@ impl CchActor {
 
     async fn handle_settled_tlc_notification(
         &self,
-        state: &mut CchState,
         tlc_notification: TlcNotification,
     ) -> Result<()> {
         let payment_hash = format!("{:#x}", tlc_notification.tlc.payment_hash);
         tracing::debug!("[settled tlc] payment hash: {}", payment_hash);
 
-        match state.orders_db.get_receive_btc_order(&payment_hash).await {
+        match self.store.get_receive_btc_order(&payment_hash) {
             Err(CchDbError::NotFound(_)) => return Ok(()),
             Err(err) => return Err(err.into()),
             _ => {
@@ -410,30 +605,14 @@ impl CchActor {
 
         tracing::debug!("[settled tlc] preimage: {:#x}", preimage);
 
-        // settle the lnd invoice
-        let req = invoicesrpc::SettleInvoiceMsg {
-            preimage: preimage.as_ref().to_vec(),
-        };
-        tracing::debug!("[settled tlc] SettleInvoiceMsg: {:?}", req);
-
-        let mut client = state.lnd_connection.create_invoices_client().await?;
-        // TODO: set a fee
-        let resp = client.settle_invoice(req).await?.into_inner();
-        tracing::debug!("[settled tlc] SettleInvoiceResp: {:?}", resp);
+        // settle the BTC hold invoice
+        self.backend.settle_invoice(preimage).await?;
 
         Ok(())
     }
 
-    async fn settle_send_btc_order(
-        &self,
-        state: &mut CchState,
-        event: SettleSendBTCOrderEvent,
-    ) -> Result<()> {
-        let mut order = match state
-            .orders_db
-            .get_send_btc_order(&event.payment_hash)
-            .await
-        {
+    async fn settle_send_btc_order(&self, event: SettleSendBTCOrderEvent) -> Result<()> {
+        let mut order = match self.store.get_send_btc_order(&event.payment_hash) {
             Err(CchDbError::NotFound(_)) => return Ok(()),
             Err(err) => return Err(err.into()),
             Ok(order) => order,
@@ -476,7 +655,7 @@ impl CchActor {
                 .map_err(|msg| anyhow!(msg))?;
         }
 
-        state.orders_db.update_send_btc_order(order).await?;
+        self.update_send_btc_order(order)?;
 
         Ok(())
     }
@@ -488,48 +667,42 @@ impl CchActor {
         receive_btc: ReceiveBTC,
     ) -> Result<ReceiveBTCOrder, CchError> {
         let duration_since_epoch = SystemTime::now().duration_since(UNIX_EPOCH)?;
-        let hash_bin = hex::decode(receive_btc.payment_hash.trim_start_matches("0x"))
+        let payment_hash = Hash256::from_str(&receive_btc.payment_hash)
             .map_err(|_| CchError::HexDecodingError(receive_btc.payment_hash.clone()))?;
 
         let amount_sats = receive_btc.amount_sats;
-        let fee_sats = amount_sats * (self.config.fee_rate_per_million_sats as u128)
-            / 1_000_000u128
-            + (self.config.base_fee_sats as u128);
-        if amount_sats <= fee_sats {
-            return Err(CchError::ReceiveBTCOrderAmountTooSmall);
-        }
         if amount_sats > (i64::MAX / 1_000i64) as u128 {
             return Err(CchError::ReceiveBTCOrderAmountTooLarge);
         }
-
-        let mut client = state.lnd_connection.create_invoices_client().await?;
-        let req = invoicesrpc::AddHoldInvoiceRequest {
-            hash: hash_bin,
-            value_msat: (amount_sats * 1_000u128) as i64,
-            expiry: DEFAULT_ORDER_EXPIRY_SECONDS as i64,
-            cltv_expiry: self.config.btc_final_tlc_expiry + receive_btc.final_tlc_expiry,
-            ..Default::default()
+        let fee_sats = match receive_btc.quote_id {
+            Some(quote_id) => self.take_quote(
+                state,
+                quote_id,
+                SwapDirection::ReceiveBtc,
+                amount_sats,
+                duration_since_epoch.as_secs(),
+            )?,
+            None => self.swap_fee_sats(
+                SwapDirection::ReceiveBtc,
+                amount_sats * 1_000u128,
+                Some(receive_btc.channel_id),
+            )?,
         };
-        let invoice = client
-            .add_hold_invoice(req)
-            .await
-            .map_err(|err| CchError::LndRpcError(err.to_string()))?
-            .into_inner();
-        let btc_pay_req = invoice.payment_request;
-
-        let wrapped_btc_type_script: ckb_jsonrpc_types::Script = get_script_by_contract(
-            Contract::SimpleUDT,
-            hex::decode(
-                self.config
-                    .wrapped_btc_type_script_args
-                    .trim_start_matches("0x"),
+        if amount_sats <= fee_sats {
+            return Err(CchError::ReceiveBTCOrderAmountTooSmall);
+        }
+
+        let btc_pay_req = self
+            .backend
+            .add_hold_invoice(
+                payment_hash,
+                (amount_sats * 1_000u128) as u64,
+                DEFAULT_ORDER_EXPIRY_SECONDS,
+                self.config.btc_final_tlc_expiry + receive_btc.final_tlc_expiry,
             )
-            .map_err(|_| {
-                CchError::HexDecodingError(self.config.wrapped_btc_type_script_args.clone())
-            })?
-            .as_ref(),
-        )
-        .into();
+            .await?;
+
+        let wrapped_btc_type_script = self.wrapped_btc_type_script()?.into();
         let order = ReceiveBTCOrder {
             created_at: duration_since_epoch.as_secs(),
             expires_after: DEFAULT_ORDER_EXPIRY_SECONDS,
@@ -546,15 +719,14 @@ impl CchActor {
             tlc_id: None,
         };
 
-        state
-            .orders_db
-            .insert_receive_btc_order(order.clone())
-            .await?;
+        self.store.insert_receive_btc_order(order.clone())?;
+        self.node_events
+            .notify_cch_order(CchOrderEvent::ReceiveBTC(order.clone()));
 
-        let invoice_tracker = LndInvoiceTracker::new(
+        let invoice_tracker = InvoiceTracker::new(
             myself,
             receive_btc.payment_hash,
-            state.lnd_connection.clone(),
+            self.backend.clone(),
             self.token.clone(),
         );
         self.tracker
@@ -563,22 +735,19 @@ impl CchActor {
         Ok(order)
     }
 
-    async fn settle_receive_btc_order(
-        &self,
-        state: &mut CchState,
-        event: SettleReceiveBTCOrderEvent,
-    ) -> Result<()> {
-        let mut order = match state
-            .orders_db
-            .get_receive_btc_order(&event.payment_hash)
-            .await
-        {
+    async fn settle_receive_btc_order(&self, event: SettleReceiveBTCOrderEvent) -> Result<()> {
+        let mut order = match self.store.get_receive_btc_order(&event.payment_hash) {
             Err(CchDbError::NotFound(_)) => return Ok(()),
             Err(err) => return Err(err.into()),
             Ok(order) => order,
         };
 
-        if event.status == CchOrderStatus::Accepted && self.network_actor.is_some() {
+        // The tracker reports the accepted invoice again after restarting, the tlc is only
+        // added once.
+        if event.status == CchOrderStatus::Accepted
+            && order.tlc_id.is_none()
+            && self.network_actor.is_some()
+        {
             // AddTlc to initiate the CKB payment
             let message = |rpc_reply| -> NetworkActorMessage {
                 NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
@@ -610,35 +779,140 @@ impl CchActor {
         order.status = event.status;
         order.payment_preimage = event.preimage.clone();
 
-        state
-            .orders_db
-            .update_receive_btc_order(order.clone())
-            .await?;
+        self.update_receive_btc_order(order)?;
+        Ok(())
+    }
+
+    async fn check_expired_orders(&self) -> Result<()> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+
+        // Only the orders whose BTC payment is not sent yet are failed. The status of a SendBTC
+        // order is changed once the BTC payment is sent, and the payment result settles the order.
+        for mut order in self.store.get_send_btc_orders() {
+            if order.status != CchOrderStatus::Pending || !order.is_expired(now) {
+                continue;
+            }
+            tracing::info!("SendBTC order {} expired", order.payment_hash);
+            if let (Some(channel_id), Some(tlc_id)) = (order.channel_id, order.tlc_id) {
+                // The BTC payment may have been sent before the order status is saved, e.g. the
+                // service is restarted while paying. The tlc can only be failed if the payment
+                // is absent or failed, otherwise the payment result settles the order.
+                let payment_hash = Hash256::from_str(&order.payment_hash)?;
+                match self.backend.get_payment(payment_hash).await {
+                    Err(CchError::BTCPaymentNotFound(_)) => {}
+                    Ok(payment) if payment.status == CchOrderStatus::Failed => {}
+                    Ok(payment) => {
+                        self.settle_send_btc_order(SettleSendBTCOrderEvent {
+                            payment_hash: order.payment_hash.clone(),
+                            preimage: payment.preimage.map(|preimage| format!("{:#x}", preimage)),
+                            status: payment.status,
+                        })
+                        .await?;
+                        continue;
+                    }
+                    Err(err) => {
+                        tracing::error!(
+                            "Failed to get the BTC payment of the expired SendBTC order {}: {}",
+                            order.payment_hash,
+                            err
+                        );
+                        continue;
+                    }
+                }
+                if let Err(err) = self.fail_received_tlc(channel_id, tlc_id).await {
+                    tracing::error!(
+                        "Failed to remove the tlc of the expired SendBTC order {}: {}",
+                        order.payment_hash,
+                        err
+                    );
+                    continue;
+                }
+            }
+            order.status = CchOrderStatus::Failed;
+            self.update_send_btc_order(order)?;
+        }
+
+        // The ReceiveBTC orders which have added the CKB tlc are settled or failed by the tlc.
+        for mut order in self.store.get_receive_btc_orders() {
+            if !matches!(
+                order.status,
+                CchOrderStatus::Pending | CchOrderStatus::Accepted
+            ) || order.tlc_id.is_some()
+                || !order.is_expired(now)
+            {
+                continue;
+            }
+            tracing::info!("ReceiveBTC order {} expired", order.payment_hash);
+            // Cancel the hold invoice to refund the accepted BTC HTLC.
+            let payment_hash = Hash256::from_str(&order.payment_hash)?;
+            if let Err(err) = self.backend.cancel_invoice(payment_hash).await {
+                tracing::error!(
+                    "Failed to cancel the hold invoice of the expired ReceiveBTC order {}: {}",
+                    order.payment_hash,
+                    err
+                );
+                continue;
+            }
+            order.status = CchOrderStatus::Failed;
+            self.update_receive_btc_order(order)?;
+        }
+
+        Ok(())
+    }
+
+    // Fail the tlc received for a SendBTC order, which returns the fund to the payer.
+    async fn fail_received_tlc(&self, channel_id: Hash256, tlc_id: u64) -> Result<()> {
+        let Some(network_actor) = &self.network_actor else {
+            return Err(anyhow!("network actor is not available"));
+        };
+        let message = move |rpc_reply| -> NetworkActorMessage {
+            NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
+                ChannelCommandWithId {
+                    channel_id,
+                    command: ChannelCommand::RemoveTlc(
+                        RemoveTlcCommand {
+                            id: tlc_id,
+                            reason: RemoveTlcReason::RemoveTlcFail(RemoveTlcFail::new_plaintext(
+                                TlcErr::new(TlcErr::INCORRECT_OR_UNKNOWN_PAYMENT_DETAILS),
+                            )),
+                        },
+                        rpc_reply,
+                    ),
+                },
+            ))
+        };
+
+        call!(network_actor, message)
+            .expect("call actor")
+            .map_err(|msg| anyhow!(msg))?;
         Ok(())
     }
 }
 
-struct LndPaymentsTracker {
+struct PaymentsTracker {
     cch_actor: ActorRef<CchMessage>,
-    lnd_connection: LndConnectionInfo,
+    backend: Arc<dyn LightningBackend>,
     token: CancellationToken,
+    // The payments sent before the last shutdown whose results are unknown yet.
+    pending_payment_hashes: Vec<String>,
 }
 
-impl LndPaymentsTracker {
+impl PaymentsTracker {
     fn new(
         cch_actor: ActorRef<CchMessage>,
-        lnd_connection: LndConnectionInfo,
+        backend: Arc<dyn LightningBackend>,
         token: CancellationToken,
+        pending_payment_hashes: Vec<String>,
     ) -> Self {
         Self {
             cch_actor,
-            lnd_connection,
+            backend,
             token,
+            pending_payment_hashes,
         }
     }
 
     async fn run(self) {
-        // TODO: clean up expired orders
         loop {
             select! {
                 result = self.run_inner() => {
@@ -648,7 +922,7 @@ impl LndPaymentsTracker {
                         }
                         Err(err) => {
                             tracing::error!(
-                                "Error tracking LND payments, retry 15 seconds later: {:?}",
+                                "Error tracking BTC payments, retry 15 seconds later: {:?}",
                                 err
                             );
                             select! {
@@ -672,23 +946,31 @@ impl LndPaymentsTracker {
     }
 
     async fn run_inner(&self) -> Result<()> {
-        tracing::debug!(
-            "[LndPaymentsTracker] will connect {}",
-            self.lnd_connection.uri
-        );
-        let mut client = self.lnd_connection.create_router_client().await?;
-        let mut stream = client
-            .track_payments(routerrpc::TrackPaymentsRequest {
-                no_inflight_updates: true,
-            })
-            .await?
-            .into_inner();
+        for payment_hash in &self.pending_payment_hashes {
+            // Report the current status of the payments sent before the last shutdown.
+            let result = match Hash256::from_str(payment_hash) {
+                Ok(hash) => self.backend.get_payment(hash).await.map_err(Into::into),
+                Err(err) => Err(err),
+            };
+            match result {
+                Ok(payment) => self.on_payment(payment)?,
+                Err(err) => {
+                    // The payment may be not sent yet, e.g. we stopped right after the tlc is received.
+                    tracing::warn!(
+                        "[PaymentsTracker] failed to track payment {}: {:?}",
+                        payment_hash,
+                        err
+                    );
+                }
+            }
+        }
+        let mut stream = self.backend.track_payments().await?;
 
         loop {
             select! {
                 payment_opt = stream.next() => {
                     match payment_opt {
-                        Some(Ok(payment)) => self.on_payment(payment).await?,
+                        Some(Ok(payment)) => self.on_payment(payment)?,
                         Some(Err(err)) => return Err(err.into()),
                         None => return Err(anyhow!("unexpected closed stream")),
                     }
@@ -701,43 +983,36 @@ impl LndPaymentsTracker {
         }
     }
 
-    async fn on_payment(&self, payment: lnrpc::Payment) -> Result<()> {
-        tracing::debug!("[LndPaymentsTracker] payment: {:?}", payment);
+    fn on_payment(&self, payment: PaymentUpdate) -> Result<()> {
+        tracing::debug!("[PaymentsTracker] payment: {:?}", payment);
         let event = CchMessage::SettleSendBTCOrder(SettleSendBTCOrderEvent {
-            payment_hash: format!("0x{}", payment.payment_hash),
-            preimage: (!payment.payment_preimage.is_empty())
-                .then(|| format!("0x{}", payment.payment_preimage)),
-            status: lnrpc::payment::PaymentStatus::try_from(payment.status)
-                .map(Into::into)
-                .unwrap_or(CchOrderStatus::InFlight),
+            payment_hash: format!("{:#x}", payment.payment_hash),
+            preimage: payment.preimage.map(|preimage| format!("{:#x}", preimage)),
+            status: payment.status,
         });
         self.cch_actor.cast(event).map_err(Into::into)
     }
 }
 
-/// Subscribe single invoice.
-///
-/// Lnd does not notify Accepted event in SubscribeInvoices rpc.
-///
-/// <https://github.com/lightningnetwork/lnd/blob/07b6af41dbe2a5a1c85e5c46cc41019b64640d90/invoices/invoiceregistry.go#L292-L293>
-struct LndInvoiceTracker {
+/// Track the status of a single hold invoice.
+struct InvoiceTracker {
     cch_actor: ActorRef<CchMessage>,
     payment_hash: String,
-    lnd_connection: LndConnectionInfo,
+    backend: Arc<dyn LightningBackend>,
     token: CancellationToken,
 }
 
-impl LndInvoiceTracker {
+impl InvoiceTracker {
     fn new(
         cch_actor: ActorRef<CchMessage>,
         payment_hash: String,
-        lnd_connection: LndConnectionInfo,
+        backend: Arc<dyn LightningBackend>,
         token: CancellationToken,
     ) -> Self {
         Self {
             cch_actor,
             payment_hash,
-            lnd_connection,
+            backend,
             token,
         }
     }
@@ -752,7 +1027,7 @@ impl LndInvoiceTracker {
                         }
                         Err(err) => {
                             tracing::error!(
-                                "Error tracking LND invoices, retry 15 seconds later: {:?}",
+                                "Error tracking BTC invoices, retry 15 seconds later: {:?}",
                                 err
                             );
                             select! {
@@ -776,24 +1051,16 @@ impl LndInvoiceTracker {
     }
 
     async fn run_inner(&self) -> Result<()> {
-        tracing::debug!(
-            "[LndInvoiceTracker] will connect {}",
-            self.lnd_connection.uri
-        );
-        let mut client = self.lnd_connection.create_invoices_client().await?;
-        // TODO: clean up expired orders
-        let mut stream = client
-            .subscribe_single_invoice(invoicesrpc::SubscribeSingleInvoiceRequest {
-                r_hash: hex::decode(self.payment_hash.trim_start_matches("0x"))?,
-            })
-            .await?
-            .into_inner();
+        let mut stream = self
+            .backend
+            .track_invoice(Hash256::from_str(&self.payment_hash)?)
+            .await?;
 
         loop {
             select! {
                 invoice_opt = stream.next() => {
                     match invoice_opt {
-                        Some(Ok(invoice)) => if self.on_invoice(invoice).await? {
+                        Some(Ok(invoice)) => if self.on_invoice(invoice)? {
                             return Ok(());
                         },
                         Some(Err(err)) => return Err(err.into()),
@@ -809,19 +1076,370 @@ impl LndInvoiceTracker {
     }
 
     // Return true to quit the tracker
-    async fn on_invoice(&self, invoice: lnrpc::Invoice) -> Result<bool> {
-        tracing::debug!("[LndInvoiceTracker] invoice: {:?}", invoice);
-        let status = lnrpc::invoice::InvoiceState::try_from(invoice.state)
-            .map(Into::into)
-            .unwrap_or(CchOrderStatus::Pending);
+    fn on_invoice(&self, invoice: InvoiceUpdate) -> Result<bool> {
+        tracing::debug!("[InvoiceTracker] invoice: {:?}", invoice);
+        let status = invoice.status;
         let event = CchMessage::SettleReceiveBTCOrder(SettleReceiveBTCOrderEvent {
-            payment_hash: format!("0x{}", hex::encode(invoice.r_hash)),
-            preimage: (!invoice.r_preimage.is_empty())
-                .then(|| format!("0x{}", hex::encode(invoice.r_preimage))),
+            payment_hash: format!("{:#x}", invoice.payment_hash),
+            preimage: invoice.preimage.map(|preimage| format!("{:#x}", preimage)),
             status,
         });
         self.cch_actor.cast(event)?;
         // Quit tracker when the status is final
-        Ok(status == CchOrderStatus::Succeeded || status == CchOrderStatus::Failed)
+        Ok(status.is_final())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cch::mock_backend::{new_btc_invoice, MockLightningBackend};
+    use crate::fiber::channel::{TLCId, TLC};
+    use crate::fiber::test_utils::{gen_sha256_hash, get_test_root_actor};
+    use crate::store::Store;
+    use crate::tasks::{new_tokio_cancellation_token, new_tokio_task_tracker};
+    use bitcoin::hashes::{sha256, Hash};
+    use ckb_types::packed::Script;
+    use ractor::call_t;
+    use tempfile::tempdir;
+
+    async fn start_cch_actor(
+        store: Store,
+        node_events: NodeEventSubscribers,
+        backend: MockLightningBackend,
+    ) -> ActorRef<CchMessage> {
+        let root_actor = get_test_root_actor().await;
+        Actor::spawn_linked(
+            None,
+            CchActor::new(
+                CchConfig::default(),
+                new_tokio_task_tracker(),
+                new_tokio_cancellation_token(),
+                None,
+                store,
+                node_events,
+                Arc::new(backend),
+            ),
+            (),
+            root_actor.get_cell(),
+        )
+        .await
+        .expect("start cch actor")
+        .0
+    }
+
+    async fn wait_until<F: Fn() -> bool>(condition: F) {
+        for _ in 0..50 {
+            if condition() {
+                return;
+            }
+            sleep(Duration::from_millis(100)).await;
+        }
+        panic!("timeout waiting for the condition");
+    }
+
+    fn gen_preimage_and_hash() -> (Hash256, Hash256) {
+        let preimage = gen_sha256_hash();
+        let payment_hash: [u8; 32] = sha256::Hash::hash(preimage.as_ref()).to_byte_array();
+        (preimage, payment_hash.into())
+    }
+
+    fn tlc_notification(
+        payment_hash: Hash256,
+        payment_preimage: Option<Hash256>,
+        amount: u128,
+    ) -> TlcNotification {
+        TlcNotification {
+            channel_id: gen_sha256_hash(),
+            tlc: TLC {
+                id: TLCId::Received(0),
+                amount,
+                lock_time: LockTime::new(100),
+                payment_hash,
+                payment_preimage,
+                hash_algorithm: HashAlgorithm::Sha256,
+                onion_packet: vec![],
+                previous_tlc: None,
+                shared_secret: [0; 32],
+                total_amount: None,
+                is_last_hop: true,
+            },
+            script: Script::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_btc_with_mock_backend() {
+        let dir = tempdir().unwrap();
+        let store = Store::new(dir.path().join("cch_store"));
+        let backend = MockLightningBackend::default();
+        let cch_actor = start_cch_actor(
+            store.clone(),
+            NodeEventSubscribers::default(),
+            backend.clone(),
+        )
+        .await;
+
+        let (preimage, payment_hash) = gen_preimage_and_hash();
+        let order = call_t!(
+            cch_actor,
+            CchMessage::SendBTC,
+            1000,
+            SendBTC {
+                btc_pay_req: new_btc_invoice(payment_hash, 100_000_000, 40),
+                currency: Currency::Fibd,
+                quote_id: None,
+            }
+        )
+        .expect("call cch actor")
+        .expect("send btc");
+        assert_eq!(order.status, CchOrderStatus::Pending);
+        assert_eq!(order.payment_hash, format!("{:#x}", payment_hash));
+
+        // The BTC payment is sent once the CKB tlc is received.
+        cch_actor
+            .cast(CchMessage::PendingReceivedTlcNotification(
+                tlc_notification(payment_hash, None, order.amount_sats),
+            ))
+            .expect("cch actor alive");
+        wait_until(|| backend.payment_status(&payment_hash) == Some(CchOrderStatus::InFlight))
+            .await;
+
+        backend.update_payment(payment_hash, Some(preimage), CchOrderStatus::Succeeded);
+        wait_until(|| {
+            store
+                .get_send_btc_order(&order.payment_hash)
+                .is_ok_and(|order| order.status == CchOrderStatus::Succeeded)
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_receive_btc_with_mock_backend() {
+        let dir = tempdir().unwrap();
+        let store = Store::new(dir.path().join("cch_store"));
+        let backend = MockLightningBackend::default();
+        let cch_actor = start_cch_actor(
+            store.clone(),
+            NodeEventSubscribers::default(),
+            backend.clone(),
+        )
+        .await;
+
+        let (preimage, payment_hash) = gen_preimage_and_hash();
+        let order = call_t!(
+            cch_actor,
+            CchMessage::ReceiveBTC,
+            1000,
+            ReceiveBTC {
+                payment_hash: format!("{:#x}", payment_hash),
+                channel_id: gen_sha256_hash(),
+                amount_sats: 100_000,
+                final_tlc_expiry: 40,
+                quote_id: None,
+            }
+        )
+        .expect("call cch actor")
+        .expect("receive btc");
+        assert_eq!(
+            backend.invoice_status(&payment_hash),
+            Some(CchOrderStatus::Pending)
+        );
+
+        backend.update_invoice(payment_hash, None, CchOrderStatus::Accepted);
+        wait_until(|| {
+            store
+                .get_receive_btc_order(&order.payment_hash)
+                .is_ok_and(|order| order.status == CchOrderStatus::Accepted)
+        })
+        .await;
+
+        // The hold invoice is settled once the CKB tlc is fulfilled by the payee.
+        cch_actor
+            .cast(CchMessage::SettledTlcNotification(tlc_notification(
+                payment_hash,
+                Some(preimage),
+                order.amount_sats - order.fee_sats,
+            )))
+            .expect("cch actor alive");
+        wait_until(|| {
+            store
+                .get_receive_btc_order(&order.payment_hash)
+                .is_ok_and(|order| order.status == CchOrderStatus::Succeeded)
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_expired_orders_are_failed() {
+        let dir = tempdir().unwrap();
+        let store = Store::new(dir.path().join("cch_store"));
+        let backend = MockLightningBackend::default();
+        let node_events = NodeEventSubscribers::default();
+        let mut order_events = node_events.subscribe_cch_order_events();
+
+        let payment_hash = gen_sha256_hash();
+        store
+            .insert_receive_btc_order(ReceiveBTCOrder {
+                created_at: 0,
+                expires_after: DEFAULT_ORDER_EXPIRY_SECONDS,
+                ckb_final_tlc_expiry: 40,
+                wrapped_btc_type_script: Default::default(),
+                btc_pay_req: new_btc_invoice(payment_hash, 100_000_000, 40),
+                payment_hash: format!("{:#x}", payment_hash),
+                payment_preimage: None,
+                channel_id: gen_sha256_hash(),
+                tlc_id: None,
+                amount_sats: 100_000,
+                fee_sats: 0,
+                status: CchOrderStatus::Pending,
+            })
+            .expect("insert order");
+
+        let cch_actor = start_cch_actor(store.clone(), node_events, backend.clone()).await;
+        cch_actor
+            .cast(CchMessage::CheckExpiredOrders)
+            .expect("cch actor alive");
+
+        let event = order_events.recv().await.expect("receive order event");
+        assert!(matches!(
+            event,
+            CchOrderEvent::ReceiveBTC(order) if order.status == CchOrderStatus::Failed
+        ));
+        assert_eq!(
+            backend.invoice_status(&payment_hash),
+            Some(CchOrderStatus::Failed)
+        );
+        let orders = call_t!(
+            cch_actor,
+            CchMessage::ListOrders,
+            1000,
+            Some(CchOrderStatus::Failed)
+        )
+        .expect("call cch actor");
+        assert_eq!(orders.receive_btc_orders.len(), 1);
+        assert!(orders.send_btc_orders.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_expired_send_btc_order_with_sent_payment() {
+        let dir = tempdir().unwrap();
+        let store = Store::new(dir.path().join("cch_store"));
+        let backend = MockLightningBackend::default();
+
+        // The service was restarted after the BTC payment is sent, but before the order is saved.
+        let payment_hash = gen_sha256_hash();
+        let btc_pay_req = new_btc_invoice(payment_hash, 100_000_000, 40);
+        backend
+            .pay_invoice(&btc_pay_req)
+            .await
+            .expect("pay invoice");
+        store
+            .insert_send_btc_order(SendBTCOrder {
+                created_at: 0,
+                expires_after: DEFAULT_ORDER_EXPIRY_SECONDS,
+                ckb_final_tlc_expiry: 40,
+                currency: Currency::Fibd,
+                wrapped_btc_type_script: Default::default(),
+                btc_pay_req,
+                ckb_pay_req: String::new(),
+                payment_hash: format!("{:#x}", payment_hash),
+                payment_preimage: None,
+                channel_id: Some(gen_sha256_hash()),
+                tlc_id: Some(0),
+                amount_sats: 100_000,
+                fee_sats: 0,
+                status: CchOrderStatus::Pending,
+            })
+            .expect("insert order");
+
+        let cch_actor =
+            start_cch_actor(store.clone(), NodeEventSubscribers::default(), backend).await;
+        cch_actor
+            .cast(CchMessage::CheckExpiredOrders)
+            .expect("cch actor alive");
+
+        // The order is not failed, as the tlc is settled by the result of the in-flight payment.
+        wait_until(|| {
+            store
+                .get_send_btc_order(&format!("{:#x}", payment_hash))
+                .is_ok_and(|order| order.status == CchOrderStatus::InFlight)
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_send_btc_with_quote() {
+        let dir = tempdir().unwrap();
+        let store = Store::new(dir.path().join("cch_store"));
+        let cch_actor = start_cch_actor(
+            store,
+            NodeEventSubscribers::default(),
+            MockLightningBackend::default(),
+        )
+        .await;
+
+        let quote = call_t!(
+            cch_actor,
+            CchMessage::QuoteSwap,
+            1000,
+            QuoteSwap {
+                direction: SwapDirection::SendBtc,
+                amount_sats: 100_000,
+                channel_id: None,
+            }
+        )
+        .expect("call cch actor")
+        .expect("quote swap");
+        // The default fee rate is 1 per million sats.
+        assert_eq!(quote.fee_sats, 0);
+
+        let (_, payment_hash) = gen_preimage_and_hash();
+        let send_btc = SendBTC {
+            btc_pay_req: new_btc_invoice(payment_hash, 100_000_000, 40),
+            currency: Currency::Fibd,
+            quote_id: Some(quote.quote_id),
+        };
+        let result = call_t!(cch_actor, CchMessage::SendBTC, 1000, send_btc.clone())
+            .expect("call cch actor");
+        assert!(matches!(result, Err(CchError::SwapQuoteMismatch)));
+
+        let quote = call_t!(
+            cch_actor,
+            CchMessage::QuoteSwap,
+            1000,
+            QuoteSwap {
+                direction: SwapDirection::SendBtc,
+                amount_sats: 100_000_000 / 1_000,
+                channel_id: None,
+            }
+        )
+        .expect("call cch actor")
+        .expect("quote swap");
+        let order = call_t!(
+            cch_actor,
+            CchMessage::SendBTC,
+            1000,
+            SendBTC {
+                quote_id: Some(quote.quote_id),
+                ..send_btc.clone()
+            }
+        )
+        .expect("call cch actor")
+        .expect("send btc");
+        assert_eq!(order.fee_sats, quote.fee_sats);
+
+        // A quote is only used once.
+        let result = call_t!(
+            cch_actor,
+            CchMessage::SendBTC,
+            1000,
+            SendBTC {
+                quote_id: Some(quote.quote_id),
+                ..send_btc
+            }
+        )
+        .expect("call cch actor");
+        assert!(matches!(result, Err(CchError::SwapQuoteNotFound(_))));
     }
 }
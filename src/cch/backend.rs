@@ -0,0 +1,57 @@
+use futures::stream::BoxStream;
+
+use super::{CchError, CchOrderStatus};
+use crate::fiber::types::Hash256;
+
+/// The status of an outgoing BTC payment, which is the second half of a SendBTC order.
+#[derive(Clone, Debug)]
+pub struct PaymentUpdate {
+    pub payment_hash: Hash256,
+    pub preimage: Option<Hash256>,
+    /// One of `InFlight`, `Succeeded` and `Failed`.
+    pub status: CchOrderStatus,
+}
+
+/// The status of a BTC hold invoice, which is the first half of a ReceiveBTC order.
+#[derive(Clone, Debug)]
+pub struct InvoiceUpdate {
+    pub payment_hash: Hash256,
+    pub preimage: Option<Hash256>,
+    /// `Pending` until the HTLC is accepted, then one of `Accepted`, `Succeeded` and `Failed`.
+    pub status: CchOrderStatus,
+}
+
+pub type PaymentUpdateStream = BoxStream<'static, Result<PaymentUpdate, CchError>>;
+pub type InvoiceUpdateStream = BoxStream<'static, Result<InvoiceUpdate, CchError>>;
+
+/// The Lightning node used by the cross-chain hub to send and receive BTC payments.
+#[ractor::async_trait]
+pub trait LightningBackend: Send + Sync + 'static {
+    /// Pay the BOLT11 invoice, returns the status once the payment is sent out.
+    async fn pay_invoice(&self, btc_pay_req: &str) -> Result<PaymentUpdate, CchError>;
+
+    /// Get the current status of a payment sent by `pay_invoice`.
+    async fn get_payment(&self, payment_hash: Hash256) -> Result<PaymentUpdate, CchError>;
+
+    /// Stream the final status of all the outgoing payments.
+    async fn track_payments(&self) -> Result<PaymentUpdateStream, CchError>;
+
+    /// Create an invoice whose HTLC is held after being accepted, until the invoice is settled
+    /// with the preimage or cancelled. Returns the BOLT11 payment request.
+    async fn add_hold_invoice(
+        &self,
+        payment_hash: Hash256,
+        amount_msat: u64,
+        expiry_seconds: u64,
+        cltv_expiry: u64,
+    ) -> Result<String, CchError>;
+
+    /// Stream the status of a hold invoice, starting with the current one.
+    async fn track_invoice(&self, payment_hash: Hash256) -> Result<InvoiceUpdateStream, CchError>;
+
+    /// Claim the accepted HTLC of a hold invoice.
+    async fn settle_invoice(&self, preimage: Hash256) -> Result<(), CchError>;
+
+    /// Cancel a hold invoice, the accepted HTLC is failed back to the payer.
+    async fn cancel_invoice(&self, payment_hash: Hash256) -> Result<(), CchError>;
+}
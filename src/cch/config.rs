@@ -1,6 +1,8 @@
 use std::path::PathBuf;
 
+use clap::ValueEnum;
 use clap_serde_derive::ClapSerde;
+use serde::Deserialize;
 
 /// Default cross-chain order expiry time in seconds.
 pub const DEFAULT_ORDER_EXPIRY_TIME: u64 = 3600;
@@ -8,6 +10,8 @@ pub const DEFAULT_ORDER_EXPIRY_TIME: u64 = 3600;
 pub const DEFAULT_BTC_FINAL_TLC_EXPIRY_TIME: u64 = 36;
 /// Default CKB final-hop HTLC expiry time in blocks.
 pub const DEFAULT_CKB_FINAL_TLC_EXPIRY_BLOCKS: u64 = 10;
+/// Default swap quote expiry time in seconds.
+pub const DEFAULT_QUOTE_EXPIRY_TIME: u64 = 60;
 
 // Use prefix `cch-`/`CCH_`
 #[derive(ClapSerde, Debug, Clone)]
@@ -21,6 +25,16 @@ pub struct CchConfig {
     )]
     pub base_dir: Option<PathBuf>,
 
+    /// The Lightning node used to send and receive BTC payments.
+    #[default(LightningBackendType::Lnd)]
+    #[arg(
+        name = "CCH_LIGHTNING_BACKEND",
+        long = "cch-lightning-backend",
+        env,
+        help = "the lightning node to use, either `lnd` (an external lnd node) or `ldk` (the in-process ldk node), default is lnd"
+    )]
+    pub lightning_backend: LightningBackendType,
+
     #[default("https://127.0.0.1:10009".to_string())]
     #[arg(
         name = "CCH_LND_RPC_URL",
@@ -83,6 +97,75 @@ pub struct CchConfig {
     )]
     pub fee_rate_per_million_sats: u64,
 
+    #[arg(
+        name = "CCH_SEND_BTC_BASE_FEE_SATS",
+        long = "cch-send-btc-base-fee-sats",
+        env,
+        help = "The base fee charged for each SendBTC order, default is the value of cch-base-fee-sats"
+    )]
+    pub send_btc_base_fee_sats: Option<u64>,
+
+    #[arg(
+        name = "CCH_SEND_BTC_FEE_RATE_PER_MILLION_SATS",
+        long = "cch-send-btc-fee-rate-per-million-sats",
+        env,
+        help = "The proportional fee charged per million satoshis for SendBTC orders, default is the value of cch-fee-rate-per-million-sats"
+    )]
+    pub send_btc_fee_rate_per_million_sats: Option<u64>,
+
+    #[arg(
+        name = "CCH_RECEIVE_BTC_BASE_FEE_SATS",
+        long = "cch-receive-btc-base-fee-sats",
+        env,
+        help = "The base fee charged for each ReceiveBTC order, default is the value of cch-base-fee-sats"
+    )]
+    pub receive_btc_base_fee_sats: Option<u64>,
+
+    #[arg(
+        name = "CCH_RECEIVE_BTC_FEE_RATE_PER_MILLION_SATS",
+        long = "cch-receive-btc-fee-rate-per-million-sats",
+        env,
+        help = "The proportional fee charged per million satoshis for ReceiveBTC orders, default is the value of cch-fee-rate-per-million-sats"
+    )]
+    pub receive_btc_fee_rate_per_million_sats: Option<u64>,
+
+    #[arg(
+        name = "CCH_MIN_AMOUNT_SATS",
+        long = "cch-min-amount-sats",
+        env,
+        help = "The minimal amount in satoshis of a cross-chain order, no limit if it's not set"
+    )]
+    pub min_amount_sats: Option<u64>,
+
+    #[arg(
+        name = "CCH_MAX_AMOUNT_SATS",
+        long = "cch-max-amount-sats",
+        env,
+        help = "The maximal amount in satoshis of a cross-chain order, no limit if it's not set"
+    )]
+    pub max_amount_sats: Option<u64>,
+
+    /// The extra proportional fee charged when an order consumes all the channel liquidity, it
+    /// is scaled down by the share of the liquidity consumed by the order.
+    #[default(0)]
+    #[arg(
+        name = "CCH_LIQUIDITY_SPREAD_PER_MILLION_SATS",
+        long = "cch-liquidity-spread-per-million-sats",
+        env,
+        help = "The proportional fee per million satoshis added when an order consumes all the available channel balance, scaled by the consumed share of the balance, default is 0"
+    )]
+    pub liquidity_spread_per_million_sats: u64,
+
+    /// Swap quote expiry time in seconds.
+    #[default(DEFAULT_QUOTE_EXPIRY_TIME)]
+    #[arg(
+        name = "CCH_QUOTE_EXPIRY",
+        long = "cch-quote-expiry",
+        env,
+        help = format!("swap quote expiry time in seconds, default is {}", DEFAULT_QUOTE_EXPIRY_TIME),
+    )]
+    pub quote_expiry: u64,
+
     /// Final tlc expiry time for BTC network.
     #[default(DEFAULT_BTC_FINAL_TLC_EXPIRY_TIME)]
     #[arg(
@@ -108,6 +191,13 @@ pub struct CchConfig {
     pub ignore_startup_failure: bool,
 }
 
+#[derive(Debug, Clone, Copy, ValueEnum, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LightningBackendType {
+    Lnd,
+    Ldk,
+}
+
 impl CchConfig {
     pub fn resolve_lnd_cert_path(&self) -> Option<PathBuf> {
         self.lnd_cert_path.as_ref().map(|lnd_cert_path| {
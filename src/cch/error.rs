@@ -38,6 +38,16 @@ pub enum CchError {
     ReceiveBTCReceivedAmountTooSmall,
     #[error("ReceiveBTC expected preimage but missing")]
     ReceiveBTCMissingPreimage,
+    #[error("Swap amount is smaller than the minimal amount {0} sats")]
+    SwapAmountTooSmall(u128),
+    #[error("Swap amount is larger than the maximal amount {0} sats")]
+    SwapAmountTooLarge(u128),
+    #[error("Swap amount exceeds the available channel liquidity {0} sats")]
+    SwapInsufficientLiquidity(u128),
+    #[error("Swap quote not found or expired: {0}")]
+    SwapQuoteNotFound(String),
+    #[error("Swap quote does not match the order")]
+    SwapQuoteMismatch,
     #[error("System time error: {0}")]
     SystemTimeError(#[from] SystemTimeError),
     #[error("JSON serialization error: {0}")]
@@ -48,6 +58,10 @@ pub enum CchError {
     LndChannelError(#[from] lnd_grpc_tonic_client::channel::Error),
     #[error("Lnd RPC error: {0}")]
     LndRpcError(String),
+    #[error("Lightning backend error: {0}")]
+    LightningBackendError(String),
+    #[error("BTC payment not found: {0}")]
+    BTCPaymentNotFound(String),
 }
 
 pub type CchResult<T> = std::result::Result<T, CchError>;
@@ -0,0 +1,244 @@
+use anyhow::{Context, Result};
+use futures::{StreamExt as _, TryStreamExt as _};
+use lnd_grpc_tonic_client::{
+    create_invoices_client, create_router_client, invoicesrpc, lnrpc, routerrpc, InvoicesClient,
+    RouterClient, Uri,
+};
+use std::str::FromStr;
+
+use super::actor::BTC_PAYMENT_TIMEOUT_SECONDS;
+use super::backend::{
+    InvoiceUpdate, InvoiceUpdateStream, LightningBackend, PaymentUpdate, PaymentUpdateStream,
+};
+use super::{CchConfig, CchError, CchOrderStatus};
+use crate::fiber::types::Hash256;
+
+/// The Lightning backend connecting to an external LND node via its gRPC interface.
+#[derive(Clone)]
+pub struct LndBackend {
+    uri: Uri,
+    cert: Option<Vec<u8>>,
+    macaroon: Option<Vec<u8>>,
+}
+
+impl LndBackend {
+    pub async fn new(config: &CchConfig) -> Result<Self> {
+        let uri: Uri = config.lnd_rpc_url.clone().try_into()?;
+        let cert = match config.resolve_lnd_cert_path() {
+            Some(path) => Some(
+                tokio::fs::read(&path)
+                    .await
+                    .with_context(|| format!("read cert file {}", path.display()))?,
+            ),
+            None => None,
+        };
+        let macaroon = match config.resolve_lnd_macaroon_path() {
+            Some(path) => Some(
+                tokio::fs::read(&path)
+                    .await
+                    .with_context(|| format!("read macaroon file {}", path.display()))?,
+            ),
+            None => None,
+        };
+        Ok(Self {
+            uri,
+            cert,
+            macaroon,
+        })
+    }
+
+    async fn create_router_client(
+        &self,
+    ) -> Result<RouterClient, lnd_grpc_tonic_client::channel::Error> {
+        create_router_client(
+            self.uri.clone(),
+            self.cert.as_deref(),
+            self.macaroon.as_deref(),
+        )
+        .await
+    }
+
+    async fn create_invoices_client(
+        &self,
+    ) -> Result<InvoicesClient, lnd_grpc_tonic_client::channel::Error> {
+        create_invoices_client(
+            self.uri.clone(),
+            self.cert.as_deref(),
+            self.macaroon.as_deref(),
+        )
+        .await
+    }
+}
+
+fn parse_hash(hex: &str) -> Result<Hash256, CchError> {
+    Hash256::from_str(hex).map_err(|_| CchError::HexDecodingError(hex.to_string()))
+}
+
+fn parse_hash_bytes(bytes: &[u8]) -> Result<Hash256, CchError> {
+    parse_hash(&hex::encode(bytes))
+}
+
+fn payment_update(payment: lnrpc::Payment) -> Result<PaymentUpdate, CchError> {
+    tracing::debug!("[LndBackend] payment: {:?}", payment);
+    Ok(PaymentUpdate {
+        payment_hash: parse_hash(&payment.payment_hash)?,
+        preimage: (!payment.payment_preimage.is_empty())
+            .then(|| parse_hash(&payment.payment_preimage))
+            .transpose()?,
+        status: lnrpc::payment::PaymentStatus::try_from(payment.status)
+            .map(Into::into)
+            .unwrap_or(CchOrderStatus::InFlight),
+    })
+}
+
+fn invoice_update(invoice: lnrpc::Invoice) -> Result<InvoiceUpdate, CchError> {
+    tracing::debug!("[LndBackend] invoice: {:?}", invoice);
+    Ok(InvoiceUpdate {
+        payment_hash: parse_hash_bytes(&invoice.r_hash)?,
+        preimage: (!invoice.r_preimage.is_empty())
+            .then(|| parse_hash_bytes(&invoice.r_preimage))
+            .transpose()?,
+        status: lnrpc::invoice::InvoiceState::try_from(invoice.state)
+            .map(Into::into)
+            .unwrap_or(CchOrderStatus::Pending),
+    })
+}
+
+fn rpc_error(status: impl ToString) -> CchError {
+    CchError::LndRpcError(status.to_string())
+}
+
+// LND returns NotFound if the payment has never been sent.
+fn payment_error(payment_hash: Hash256, status: tonic::Status) -> CchError {
+    if status.code() == tonic::Code::NotFound {
+        CchError::BTCPaymentNotFound(format!("{:#x}", payment_hash))
+    } else {
+        rpc_error(status)
+    }
+}
+
+#[ractor::async_trait]
+impl LightningBackend for LndBackend {
+    async fn pay_invoice(&self, btc_pay_req: &str) -> Result<PaymentUpdate, CchError> {
+        let req = routerrpc::SendPaymentRequest {
+            payment_request: btc_pay_req.to_string(),
+            timeout_seconds: BTC_PAYMENT_TIMEOUT_SECONDS,
+            ..Default::default()
+        };
+        tracing::debug!("[LndBackend] SendPaymentRequest: {:?}", req);
+
+        let mut client = self.create_router_client().await?;
+        // TODO: set a fee
+        let mut stream = client
+            .send_payment_v2(req)
+            .await
+            .map_err(rpc_error)?
+            .into_inner();
+        // Wait for the first message only, the final status is reported by `track_payments`.
+        match stream.next().await {
+            Some(Ok(payment)) => payment_update(payment),
+            Some(Err(err)) => Err(rpc_error(err)),
+            None => Err(rpc_error("unexpected closed stream")),
+        }
+    }
+
+    async fn get_payment(&self, payment_hash: Hash256) -> Result<PaymentUpdate, CchError> {
+        let mut client = self.create_router_client().await?;
+        let mut stream = client
+            .track_payment_v2(routerrpc::TrackPaymentRequest {
+                payment_hash: payment_hash.as_ref().to_vec(),
+                no_inflight_updates: false,
+            })
+            .await
+            .map_err(|status| payment_error(payment_hash, status))?
+            .into_inner();
+        match stream.next().await {
+            Some(Ok(payment)) => payment_update(payment),
+            Some(Err(status)) => Err(payment_error(payment_hash, status)),
+            None => Err(rpc_error("unexpected closed stream")),
+        }
+    }
+
+    async fn track_payments(&self) -> Result<PaymentUpdateStream, CchError> {
+        let mut client = self.create_router_client().await?;
+        let stream = client
+            .track_payments(routerrpc::TrackPaymentsRequest {
+                no_inflight_updates: true,
+            })
+            .await
+            .map_err(rpc_error)?
+            .into_inner();
+        Ok(stream
+            .map_err(rpc_error)
+            .and_then(|payment| async move { payment_update(payment) })
+            .boxed())
+    }
+
+    async fn add_hold_invoice(
+        &self,
+        payment_hash: Hash256,
+        amount_msat: u64,
+        expiry_seconds: u64,
+        cltv_expiry: u64,
+    ) -> Result<String, CchError> {
+        let mut client = self.create_invoices_client().await?;
+        let req = invoicesrpc::AddHoldInvoiceRequest {
+            hash: payment_hash.as_ref().to_vec(),
+            value_msat: amount_msat as i64,
+            expiry: expiry_seconds as i64,
+            cltv_expiry,
+            ..Default::default()
+        };
+        let invoice = client
+            .add_hold_invoice(req)
+            .await
+            .map_err(rpc_error)?
+            .into_inner();
+        Ok(invoice.payment_request)
+    }
+
+    async fn track_invoice(&self, payment_hash: Hash256) -> Result<InvoiceUpdateStream, CchError> {
+        let mut client = self.create_invoices_client().await?;
+        // Lnd does not notify Accepted event in SubscribeInvoices rpc.
+        //
+        // <https://github.com/lightningnetwork/lnd/blob/07b6af41dbe2a5a1c85e5c46cc41019b64640d90/invoices/invoiceregistry.go#L292-L293>
+        let stream = client
+            .subscribe_single_invoice(invoicesrpc::SubscribeSingleInvoiceRequest {
+                r_hash: payment_hash.as_ref().to_vec(),
+            })
+            .await
+            .map_err(rpc_error)?
+            .into_inner();
+        Ok(stream
+            .map_err(rpc_error)
+            .and_then(|invoice| async move { invoice_update(invoice) })
+            .boxed())
+    }
+
+    async fn settle_invoice(&self, preimage: Hash256) -> Result<(), CchError> {
+        let req = invoicesrpc::SettleInvoiceMsg {
+            preimage: preimage.as_ref().to_vec(),
+        };
+        tracing::debug!("[LndBackend] SettleInvoiceMsg: {:?}", req);
+
+        let mut client = self.create_invoices_client().await?;
+        let resp = client
+            .settle_invoice(req)
+            .await
+            .map_err(rpc_error)?
+            .into_inner();
+        tracing::debug!("[LndBackend] SettleInvoiceResp: {:?}", resp);
+        Ok(())
+    }
+
+    async fn cancel_invoice(&self, payment_hash: Hash256) -> Result<(), CchError> {
+        let mut client = self.create_invoices_client().await?;
+        client
+            .cancel_invoice(invoicesrpc::CancelInvoiceMsg {
+                payment_hash: payment_hash.as_ref().to_vec(),
+            })
+            .await
+            .map_err(rpc_error)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+use bitcoin::hashes::{sha256, Hash};
+use bitcoin::secp256k1::{Secp256k1, SecretKey};
+use futures::{stream, StreamExt as _};
+use lightning::ln::PaymentSecret;
+use lightning_invoice::{Bolt11Invoice, Currency, InvoiceBuilder};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast;
+
+use super::backend::{
+    InvoiceUpdate, InvoiceUpdateStream, LightningBackend, PaymentUpdate, PaymentUpdateStream,
+};
+use super::{CchError, CchOrderStatus};
+use crate::fiber::types::Hash256;
+
+/// Create a BOLT11 invoice signed by a fixed key.
+pub fn new_btc_invoice(payment_hash: Hash256, amount_msat: u64, cltv_expiry: u64) -> String {
+    let secp = Secp256k1::new();
+    let key = SecretKey::from_slice(&[42; 32]).expect("valid secret key");
+    InvoiceBuilder::new(Currency::Regtest)
+        .description("mock".to_string())
+        .payment_hash(sha256::Hash::from_slice(payment_hash.as_ref()).expect("32 bytes"))
+        .payment_secret(PaymentSecret([0; 32]))
+        .current_timestamp()
+        .min_final_cltv_expiry_delta(cltv_expiry)
+        .amount_milli_satoshis(amount_msat)
+        .build_signed(|hash| secp.sign_ecdsa_recoverable(hash, &key))
+        .expect("build invoice")
+        .to_string()
+}
+
+/// An in-memory Lightning backend, the payments and invoices are resolved by the tests.
+#[derive(Clone)]
+pub struct MockLightningBackend {
+    payments: Arc<Mutex<HashMap<Hash256, PaymentUpdate>>>,
+    invoices: Arc<Mutex<HashMap<Hash256, InvoiceUpdate>>>,
+    payment_sender: broadcast::Sender<PaymentUpdate>,
+    invoice_sender: broadcast::Sender<InvoiceUpdate>,
+}
+
+impl Default for MockLightningBackend {
+    fn default() -> Self {
+        Self {
+            payments: Default::default(),
+            invoices: Default::default(),
+            payment_sender: broadcast::channel(16).0,
+            invoice_sender: broadcast::channel(16).0,
+        }
+    }
+}
+
+impl MockLightningBackend {
+    pub fn payment_status(&self, payment_hash: &Hash256) -> Option<CchOrderStatus> {
+        let payments = self.payments.lock().unwrap();
+        payments.get(payment_hash).map(|payment| payment.status)
+    }
+
+    pub fn invoice_status(&self, payment_hash: &Hash256) -> Option<CchOrderStatus> {
+        let invoices = self.invoices.lock().unwrap();
+        invoices.get(payment_hash).map(|invoice| invoice.status)
+    }
+
+    pub fn update_payment(
+        &self,
+        payment_hash: Hash256,
+        preimage: Option<Hash256>,
+        status: CchOrderStatus,
+    ) {
+        let update = PaymentUpdate {
+            payment_hash,
+            preimage,
+            status,
+        };
+        self.payments
+            .lock()
+            .unwrap()
+            .insert(payment_hash, update.clone());
+        let _ = self.payment_sender.send(update);
+    }
+
+    pub fn update_invoice(
+        &self,
+        payment_hash: Hash256,
+        preimage: Option<Hash256>,
+        status: CchOrderStatus,
+    ) {
+        let update = InvoiceUpdate {
+            payment_hash,
+            preimage,
+            status,
+        };
+        self.invoices
+            .lock()
+            .unwrap()
+            .insert(payment_hash, update.clone());
+        let _ = self.invoice_sender.send(update);
+    }
+}
+
+#[ractor::async_trait]
+impl LightningBackend for MockLightningBackend {
+    async fn pay_invoice(&self, btc_pay_req: &str) -> Result<PaymentUpdate, CchError> {
+        let invoice = Bolt11Invoice::from_str(btc_pay_req)?;
+        let payment_hash = Hash256::from(invoice.payment_hash().to_byte_array());
+        self.update_payment(payment_hash, None, CchOrderStatus::InFlight);
+        Ok(PaymentUpdate {
+            payment_hash,
+            preimage: None,
+            status: CchOrderStatus::InFlight,
+        })
+    }
+
+    async fn get_payment(&self, payment_hash: Hash256) -> Result<PaymentUpdate, CchError> {
+        let payments = self.payments.lock().unwrap();
+        payments
+            .get(&payment_hash)
+            .cloned()
+            .ok_or_else(|| CchError::BTCPaymentNotFound(format!("{:#x}", payment_hash)))
+    }
+
+    async fn track_payments(&self) -> Result<PaymentUpdateStream, CchError> {
+        let receiver = self.payment_sender.subscribe();
+        Ok(stream::unfold(receiver, |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(payment) if payment.status.is_final() => {
+                        return Some((Ok(payment), receiver))
+                    }
+                    Ok(_) => continue,
+                    Err(_) => return None,
+                }
+            }
+        })
+        .boxed())
+    }
+
+    async fn add_hold_invoice(
+        &self,
+        payment_hash: Hash256,
+        amount_msat: u64,
+        _expiry_seconds: u64,
+        cltv_expiry: u64,
+    ) -> Result<String, CchError> {
+        self.update_invoice(payment_hash, None, CchOrderStatus::Pending);
+        Ok(new_btc_invoice(payment_hash, amount_msat, cltv_expiry))
+    }
+
+    async fn track_invoice(&self, payment_hash: Hash256) -> Result<InvoiceUpdateStream, CchError> {
+        let receiver = self.invoice_sender.subscribe();
+        let current = self.invoices.lock().unwrap().get(&payment_hash).cloned();
+        let updates = stream::unfold(receiver, move |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(invoice) if invoice.payment_hash == payment_hash => {
+                        return Some((invoice, receiver))
+                    }
+                    Ok(_) => continue,
+                    Err(_) => return None,
+                }
+            }
+        });
+        Ok(stream::iter(current).chain(updates).map(Ok).boxed())
+    }
+
+    async fn settle_invoice(&self, preimage: Hash256) -> Result<(), CchError> {
+        let payment_hash: [u8; 32] = sha256::Hash::hash(preimage.as_ref()).to_byte_array();
+        self.update_invoice(
+            payment_hash.into(),
+            Some(preimage),
+            CchOrderStatus::Succeeded,
+        );
+        Ok(())
+    }
+
+    async fn cancel_invoice(&self, payment_hash: Hash256) -> Result<(), CchError> {
+        self.update_invoice(payment_hash, None, CchOrderStatus::Failed);
+        Ok(())
+    }
+}
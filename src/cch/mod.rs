@@ -1,17 +1,31 @@
 mod actor;
-pub use actor::{start_cch, CchActor, CchMessage, ReceiveBTC, SendBTC};
+pub use actor::{start_cch, CchActor, CchMessage, CchOrders, QuoteSwap, ReceiveBTC, SendBTC};
+
+mod backend;
+pub use backend::{
+    InvoiceUpdate, InvoiceUpdateStream, LightningBackend, PaymentUpdate, PaymentUpdateStream,
+};
+
+mod lnd;
+pub use lnd::LndBackend;
+
+#[cfg(test)]
+mod mock_backend;
 
 mod error;
-pub use error::{CchError, CchResult};
+pub use error::{CchDbError, CchError, CchResult};
 
 mod config;
 pub use config::{
-    CchConfig, DEFAULT_BTC_FINAL_TLC_EXPIRY_TIME, DEFAULT_CKB_FINAL_TLC_EXPIRY_BLOCKS,
-    DEFAULT_ORDER_EXPIRY_TIME,
+    CchConfig, LightningBackendType, DEFAULT_BTC_FINAL_TLC_EXPIRY_TIME,
+    DEFAULT_CKB_FINAL_TLC_EXPIRY_BLOCKS, DEFAULT_ORDER_EXPIRY_TIME, DEFAULT_QUOTE_EXPIRY_TIME,
 };
 
 mod order;
-pub use order::{CchOrderStatus, ReceiveBTCOrder, SendBTCOrder};
+pub use order::{CchOrderEvent, CchOrderStatus, ReceiveBTCOrder, SendBTCOrder};
+
+mod pricing;
+pub use pricing::{CchPricing, SwapDirection, SwapQuote};
 
 mod orders_db;
-pub use orders_db::CchOrdersDb;
+pub use orders_db::CchOrderStore;
@@ -27,6 +27,13 @@ pub enum CchOrderStatus {
     Failed = 4,
 }
 
+impl CchOrderStatus {
+    /// Whether the order is settled or failed, which won't be changed any more.
+    pub fn is_final(&self) -> bool {
+        matches!(self, CchOrderStatus::Succeeded | CchOrderStatus::Failed)
+    }
+}
+
 /// lnd payment is the second half of SendBTCOrder
 impl From<lnrpc::payment::PaymentStatus> for CchOrderStatus {
     fn from(status: lnrpc::payment::PaymentStatus) -> Self {
@@ -103,6 +110,11 @@ impl SendBTCOrder {
 
         Ok(())
     }
+
+    /// Whether the order is expired at `now`, in seconds since epoch.
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.created_at.saturating_add(self.expires_after) <= now
+    }
 }
 
 #[serde_as]
@@ -135,3 +147,17 @@ pub struct ReceiveBTCOrder {
 
     pub status: CchOrderStatus,
 }
+
+impl ReceiveBTCOrder {
+    /// Whether the order is expired at `now`, in seconds since epoch.
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.created_at.saturating_add(self.expires_after) <= now
+    }
+}
+
+/// The order emitted when it is created or updated.
+#[derive(Debug, Clone)]
+pub enum CchOrderEvent {
+    SendBTC(SendBTCOrder),
+    ReceiveBTC(ReceiveBTCOrder),
+}
@@ -0,0 +1,212 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CchConfig, CchError, CchResult};
+use crate::fiber::types::Hash256;
+
+/// The direction of a cross-chain swap, named after the order created for it.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SwapDirection {
+    /// Pay a BTC invoice with wrapped BTC on CKB.
+    SendBtc,
+    /// Receive wrapped BTC on CKB by paying a BTC hold invoice.
+    ReceiveBtc,
+}
+
+/// The fee charged for a swap, which is valid until `expires_at`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SwapQuote {
+    pub quote_id: Hash256,
+    pub direction: SwapDirection,
+    /// The BTC invoice amount for SendBTC, or the amount paid via BTC including the fee for
+    /// ReceiveBTC, the same as `amount_sats` in `ReceiveBTC`.
+    pub amount_sats: u128,
+    pub fee_sats: u128,
+    /// Seconds since epoch when the quote expires.
+    pub expires_at: u64,
+}
+
+impl SwapQuote {
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at <= now
+    }
+}
+
+/// The swap amounts are bounded by the amounts of the BOLT11 invoices, which are u64 millisatoshis.
+pub const MAX_SWAP_AMOUNT_SATS: u128 = (u64::MAX / 1_000) as u128;
+
+/// The pricing rules for one swap direction.
+///
+/// The fee is `base_fee_sats + amount * fee_rate_per_million_sats / 1_000_000`, plus a spread
+/// growing with the share of the channel liquidity consumed by the swap:
+/// `amount * liquidity_spread_per_million_sats / 1_000_000 * amount / liquidity`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CchPricing {
+    pub base_fee_sats: u64,
+    pub fee_rate_per_million_sats: u64,
+    pub min_amount_sats: Option<u64>,
+    pub max_amount_sats: Option<u64>,
+    pub liquidity_spread_per_million_sats: u64,
+}
+
+impl CchPricing {
+    pub fn new(config: &CchConfig, direction: SwapDirection) -> Self {
+        let (base_fee_sats, fee_rate_per_million_sats) = match direction {
+            SwapDirection::SendBtc => (
+                config.send_btc_base_fee_sats,
+                config.send_btc_fee_rate_per_million_sats,
+            ),
+            SwapDirection::ReceiveBtc => (
+                config.receive_btc_base_fee_sats,
+                config.receive_btc_fee_rate_per_million_sats,
+            ),
+        };
+        Self {
+            base_fee_sats: base_fee_sats.unwrap_or(config.base_fee_sats),
+            fee_rate_per_million_sats: fee_rate_per_million_sats
+                .unwrap_or(config.fee_rate_per_million_sats),
+            min_amount_sats: config.min_amount_sats,
+            max_amount_sats: config.max_amount_sats,
+            liquidity_spread_per_million_sats: config.liquidity_spread_per_million_sats,
+        }
+    }
+
+    /// Whether the fee depends on the channel liquidity, the liquidity is not required otherwise.
+    pub fn requires_liquidity(&self) -> bool {
+        self.liquidity_spread_per_million_sats > 0
+    }
+
+    /// The maximal swap amount in satoshis, which is at most MAX_SWAP_AMOUNT_SATS.
+    pub fn max_amount_sats(&self) -> u128 {
+        self.max_amount_sats
+            .map_or(MAX_SWAP_AMOUNT_SATS, |max_amount_sats| {
+                (max_amount_sats as u128).min(MAX_SWAP_AMOUNT_SATS)
+            })
+    }
+
+    /// Check the swap amount in satoshis against the minimal and maximal amounts.
+    pub fn check_amount_sats(&self, amount_sats: u128) -> CchResult<()> {
+        if let Some(min_amount_sats) = self.min_amount_sats {
+            if amount_sats < min_amount_sats as u128 {
+                return Err(CchError::SwapAmountTooSmall(min_amount_sats as u128));
+            }
+        }
+        if amount_sats > self.max_amount_sats() {
+            return Err(CchError::SwapAmountTooLarge(self.max_amount_sats()));
+        }
+        Ok(())
+    }
+
+    /// Compute the fee in satoshis for the swap amount in millisatoshis.
+    ///
+    /// `liquidity_sats` is the channel balance the swap is settled with, it's only used when
+    /// `requires_liquidity` returns true.
+    pub fn fee_sats(&self, amount_msat: u128, liquidity_sats: u128) -> CchResult<u128> {
+        let amount_sats = amount_msat.div_ceil(1_000u128);
+        self.check_amount_sats(amount_sats)?;
+
+        let overflow = || CchError::SwapAmountTooLarge(self.max_amount_sats());
+        let mut fee_sats = amount_msat
+            .checked_mul(self.fee_rate_per_million_sats as u128)
+            .ok_or_else(overflow)?
+            / 1_000_000_000u128
+            + (self.base_fee_sats as u128);
+        if self.requires_liquidity() {
+            if liquidity_sats == 0 || amount_sats > liquidity_sats {
+                return Err(CchError::SwapInsufficientLiquidity(liquidity_sats));
+            }
+            let spread_sats = amount_msat
+                .checked_mul(self.liquidity_spread_per_million_sats as u128)
+                .ok_or_else(overflow)?
+                / 1_000_000_000u128;
+            fee_sats += spread_sats.checked_mul(amount_sats).ok_or_else(overflow)? / liquidity_sats;
+        }
+        Ok(fee_sats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pricing() -> CchPricing {
+        CchPricing {
+            base_fee_sats: 10,
+            fee_rate_per_million_sats: 1_000,
+            min_amount_sats: Some(1_000),
+            max_amount_sats: Some(10_000_000),
+            liquidity_spread_per_million_sats: 0,
+        }
+    }
+
+    #[test]
+    fn test_fee_with_base_and_proportional_fee() {
+        assert_eq!(pricing().fee_sats(1_000_000_000, 0).unwrap(), 1_010);
+        assert_eq!(pricing().fee_sats(1_000_000, 0).unwrap(), 11);
+    }
+
+    #[test]
+    fn test_fee_with_amount_limits() {
+        assert!(matches!(
+            pricing().fee_sats(999_000, 0),
+            Err(CchError::SwapAmountTooSmall(1_000))
+        ));
+        assert!(matches!(
+            pricing().fee_sats(10_000_001_000, 0),
+            Err(CchError::SwapAmountTooLarge(10_000_000))
+        ));
+
+        // The amounts are bounded by the BOLT11 invoice amounts without a configured maximum,
+        // and the fee computation never overflows.
+        let pricing = CchPricing {
+            max_amount_sats: None,
+            fee_rate_per_million_sats: u64::MAX,
+            ..pricing()
+        };
+        assert!(pricing.fee_sats(MAX_SWAP_AMOUNT_SATS * 1_000, 0).is_ok());
+        assert!(matches!(
+            pricing.fee_sats(u128::MAX, 0),
+            Err(CchError::SwapAmountTooLarge(MAX_SWAP_AMOUNT_SATS))
+        ));
+        let pricing = CchPricing {
+            liquidity_spread_per_million_sats: u64::MAX,
+            ..pricing
+        };
+        assert!(matches!(
+            pricing.fee_sats(MAX_SWAP_AMOUNT_SATS * 1_000, MAX_SWAP_AMOUNT_SATS),
+            Err(CchError::SwapAmountTooLarge(MAX_SWAP_AMOUNT_SATS))
+        ));
+    }
+
+    #[test]
+    fn test_fee_with_liquidity_spread() {
+        let pricing = CchPricing {
+            liquidity_spread_per_million_sats: 10_000,
+            ..pricing()
+        };
+        // Use a quarter of the liquidity: 1_010 + 10_000 * 1 / 4
+        assert_eq!(pricing.fee_sats(1_000_000_000, 4_000_000).unwrap(), 3_510);
+        // Use all the liquidity: 1_010 + 10_000
+        assert_eq!(pricing.fee_sats(1_000_000_000, 1_000_000).unwrap(), 11_010);
+        assert!(matches!(
+            pricing.fee_sats(1_000_000_000, 999_999),
+            Err(CchError::SwapInsufficientLiquidity(999_999))
+        ));
+    }
+
+    #[test]
+    fn test_direction_overrides_default_fee() {
+        let config = CchConfig {
+            base_fee_sats: 1,
+            fee_rate_per_million_sats: 2,
+            receive_btc_base_fee_sats: Some(3),
+            ..Default::default()
+        };
+        let send_btc = CchPricing::new(&config, SwapDirection::SendBtc);
+        assert_eq!(send_btc.base_fee_sats, 1);
+        assert_eq!(send_btc.fee_rate_per_million_sats, 2);
+        let receive_btc = CchPricing::new(&config, SwapDirection::ReceiveBtc);
+        assert_eq!(receive_btc.base_fee_sats, 3);
+        assert_eq!(receive_btc.fee_rate_per_million_sats, 2);
+    }
+}
@@ -1,4 +1,11 @@
-use ckb_sdk::{rpc::ResponseFormatGetter, CkbRpcClient, RpcError};
+use ckb_jsonrpc_types::{Either, Status};
+use ckb_sdk::{
+    rpc::{
+        ckb_indexer::{CellType, Order, ScriptType, SearchKey, SearchMode, Tx},
+        ResponseFormatGetter,
+    },
+    CkbRpcClient, RpcError,
+};
 use ckb_types::{core::TransactionView, packed, prelude::*};
 use ractor::{
     concurrency::{sleep, Duration},
@@ -7,7 +14,11 @@ use ractor::{
 
 use crate::ckb::contracts::{get_script_by_contract, Contract};
 
-use super::{funding::FundingContext, CkbConfig, FundingError, FundingRequest, FundingTx};
+use super::{
+    funding::FundingContext,
+    wallet::{SendToAddressRequest, UnspentCell, WalletBalance, WalletContext},
+    CkbConfig, FundingError, FundingRequest, FundingTx, WalletError,
+};
 
 pub struct CkbChainActor {}
 
@@ -36,6 +47,32 @@ pub enum CkbChainMessage {
     SendTx(TransactionView, RpcReplyPort<Result<(), RpcError>>),
     TraceTx(TraceTxRequest, RpcReplyPort<TraceTxResponse>),
     GetCurrentBlockNumber((), RpcReplyPort<Result<u64, RpcError>>),
+    /// Get a committed transaction by its hash.
+    GetTransaction(
+        packed::Byte32,
+        RpcReplyPort<Result<Option<TransactionView>, RpcError>>,
+    ),
+    /// Get the latest committed transaction which consumes a cell locked by the script.
+    GetSpendingTransaction(
+        packed::Script,
+        RpcReplyPort<Result<Option<TransactionView>, RpcError>>,
+    ),
+    /// Check whether the cell is live, i.e. it is committed and not consumed yet.
+    IsCellLive(packed::OutPoint, RpcReplyPort<Result<bool, RpcError>>),
+    /// Pay the fee of the transaction with the cells of the node's funding key,
+    /// the second parameter is the fee rate.
+    FundFee(
+        TransactionView,
+        u64,
+        RpcReplyPort<Result<TransactionView, WalletError>>,
+    ),
+    GetWalletBalance((), RpcReplyPort<Result<WalletBalance, WalletError>>),
+    ListUnspent((), RpcReplyPort<Result<Vec<UnspentCell>, WalletError>>),
+    GetWalletAddress((), RpcReplyPort<Result<String, WalletError>>),
+    SendToAddress(
+        SendToAddressRequest,
+        RpcReplyPort<Result<packed::Byte32, WalletError>>,
+    ),
 }
 
 #[derive(Debug)]
@@ -89,7 +126,11 @@ impl Actor for CkbChainActor {
         message: Self::Msg,
         state: &mut Self::State,
     ) -> Result<(), ActorProcessingErr> {
-        use CkbChainMessage::{Fund, GetCurrentBlockNumber, SendTx, Sign, TraceTx};
+        use CkbChainMessage::{
+            Fund, FundFee, GetCurrentBlockNumber, GetSpendingTransaction, GetTransaction,
+            GetWalletAddress, GetWalletBalance, IsCellLive, ListUnspent, SendToAddress, SendTx,
+            Sign, TraceTx,
+        };
         match message {
             GetCurrentBlockNumber(_, reply) => {
                 // Have to use block_in_place here, see https://github.com/seanmonstar/reqwest/issues/1017.
@@ -100,6 +141,84 @@ impl Actor for CkbChainActor {
                 });
                 let _ = reply.send(result);
             }
+            GetTransaction(tx_hash, reply_port) => {
+                let rpc_url = state.config.rpc_url.clone();
+                tokio::task::block_in_place(move || {
+                    let ckb_client = CkbRpcClient::new(&rpc_url);
+                    let _ = reply_port.send(get_committed_transaction(&ckb_client, tx_hash));
+                });
+            }
+            GetSpendingTransaction(lock_script, reply_port) => {
+                let rpc_url = state.config.rpc_url.clone();
+                tokio::task::block_in_place(move || {
+                    let ckb_client = CkbRpcClient::new(&rpc_url);
+                    let search_key = SearchKey {
+                        script: lock_script.into(),
+                        script_type: ScriptType::Lock,
+                        script_search_mode: Some(SearchMode::Exact),
+                        with_data: None,
+                        filter: None,
+                        group_by_transaction: None,
+                    };
+                    let result = ckb_client
+                        .get_transactions(search_key, Order::Desc, 1u32.into(), None)
+                        .and_then(|txs| match txs.objects.first() {
+                            Some(Tx::Ungrouped(tx)) if matches!(tx.io_type, CellType::Input) => {
+                                get_committed_transaction(&ckb_client, tx.tx_hash.pack())
+                            }
+                            _ => Ok(None),
+                        });
+                    let _ = reply_port.send(result);
+                });
+            }
+            IsCellLive(out_point, reply_port) => {
+                let rpc_url = state.config.rpc_url.clone();
+                tokio::task::block_in_place(move || {
+                    let ckb_client = CkbRpcClient::new(&rpc_url);
+                    let result = ckb_client
+                        .get_live_cell(out_point.into(), false)
+                        .map(|cell| cell.status == "live");
+                    let _ = reply_port.send(result);
+                });
+            }
+            FundFee(tx, fee_rate, reply_port) => {
+                let context = state.build_wallet_context();
+                tokio::task::block_in_place(move || {
+                    let _ = reply_port.send(context.fund_fee(tx, fee_rate));
+                });
+            }
+            GetWalletBalance(_, reply_port) => {
+                let context = state.build_wallet_context();
+                tokio::task::block_in_place(move || {
+                    let _ = reply_port.send(context.get_balance());
+                });
+            }
+            ListUnspent(_, reply_port) => {
+                let context = state.build_wallet_context();
+                tokio::task::block_in_place(move || {
+                    let _ = reply_port.send(context.list_unspent());
+                });
+            }
+            GetWalletAddress(_, reply_port) => {
+                let context = state.build_wallet_context();
+                tokio::task::block_in_place(move || {
+                    let _ = reply_port.send(context.get_address());
+                });
+            }
+            SendToAddress(request, reply_port) => {
+                let context = state.build_wallet_context();
+                tokio::task::block_in_place(move || {
+                    let result = context.send_to_address(request);
+                    if let Err(err) = &result {
+                        tracing::error!(
+                            "[{}] send to address failed: {:?}",
+                            myself.get_name().unwrap_or_default(),
+                            err
+                        );
+                    }
+                    let _ = reply_port.send(result);
+                });
+            }
             Fund(tx, request, reply_port) => {
                 let context = state.build_funding_context(&request);
                 if !reply_port.is_closed() {
@@ -256,6 +375,22 @@ impl Actor for CkbChainActor {
     }
 }
 
+fn get_committed_transaction(
+    ckb_client: &CkbRpcClient,
+    tx_hash: packed::Byte32,
+) -> Result<Option<TransactionView>, RpcError> {
+    let tx = match ckb_client.get_transaction(tx_hash.unpack())? {
+        Some(tx_with_status) if tx_with_status.tx_status.status == Status::Committed => {
+            tx_with_status.transaction
+        }
+        _ => None,
+    };
+    Ok(tx.and_then(|tx| match tx.inner {
+        Either::Left(tx) => Some(packed::Transaction::from(tx.inner).into_view()),
+        Either::Right(_tx) => None,
+    }))
+}
+
 impl CkbChainState {
     fn build_funding_context(&self, request: &FundingRequest) -> FundingContext {
         FundingContext {
@@ -265,6 +400,14 @@ impl CkbChainState {
             funding_cell_lock_script: request.script.clone(),
         }
     }
+
+    fn build_wallet_context(&self) -> WalletContext {
+        WalletContext {
+            secret_key: self.secret_key,
+            rpc_url: self.config.rpc_url.clone(),
+            lock_script: self.funding_source_lock_script.clone(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -273,16 +416,21 @@ pub use test_utils::{submit_tx, trace_tx, trace_tx_hash, MockChainActor};
 #[cfg(test)]
 mod test_utils {
     use std::collections::HashMap;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
 
     use anyhow::anyhow;
     use ckb_jsonrpc_types::TxStatus;
     use ckb_types::{
+        bytes::Bytes,
         core::TransactionView,
-        packed::{CellOutput, OutPoint},
-        prelude::{Builder, Entity, Pack, PackVec, Unpack},
+        packed::{CellInput, CellOutput, OutPoint, Transaction},
+        prelude::{Builder, Entity, IntoTransactionView, Pack, PackVec, Unpack},
     };
 
-    use crate::ckb::{TraceTxRequest, TraceTxResponse};
+    use crate::ckb::contracts::{get_cell_deps_by_contracts, get_script_by_contract, Contract};
+    use crate::ckb::{TraceTxRequest, TraceTxResponse, WalletError};
 
     use super::super::contracts::MockContext;
     use super::CkbChainMessage;
@@ -298,6 +446,12 @@ mod test_utils {
         Consumed,
     }
 
+    // The cells funding the channels are shared by all the mock chains, as the parties of
+    // a channel broadcast the same funding transaction to their own mock chains.
+    static FUNDING_SOURCE_CELLS: once_cell::sync::Lazy<
+        std::sync::Mutex<HashMap<OutPoint, CellOutput>>,
+    > = once_cell::sync::Lazy::new(Default::default);
+
     pub struct MockChainActorState {
         ctx: MockContext,
         tx_status: HashMap<
@@ -326,7 +480,11 @@ mod test_utils {
         }
     }
 
-    pub struct MockChainActor {}
+    pub struct MockChainActor {
+        // The transactions are not reported as committed while this flag is set,
+        // so that tests can act while a transaction is still pending.
+        confirmations_held: Arc<AtomicBool>,
+    }
 
     impl Default for MockChainActor {
         fn default() -> Self {
@@ -336,7 +494,11 @@ mod test_utils {
 
     impl MockChainActor {
         pub fn new() -> Self {
-            Self {}
+            Self::with_confirmations_held(Default::default())
+        }
+
+        pub fn with_confirmations_held(confirmations_held: Arc<AtomicBool>) -> Self {
+            Self { confirmations_held }
         }
     }
 
@@ -365,6 +527,119 @@ mod test_utils {
                 GetCurrentBlockNumber(_, reply) => {
                     let _ = reply.send(Ok(0));
                 }
+                GetTransaction(tx_hash, reply) => {
+                    let tx = match state.tx_status.get(&tx_hash) {
+                        Some((tx, ckb_jsonrpc_types::Status::Committed)) => {
+                            Some(Transaction::from(tx.inner.clone()).into_view())
+                        }
+                        _ => None,
+                    };
+                    let _ = reply.send(Ok(tx));
+                }
+                GetSpendingTransaction(lock_script, reply) => {
+                    let context = state.ctx.read();
+                    let tx = state
+                        .tx_status
+                        .values()
+                        .filter(|(_, status)| *status == ckb_jsonrpc_types::Status::Committed)
+                        .map(|(tx, _)| Transaction::from(tx.inner.clone()).into_view())
+                        .find(|tx| {
+                            tx.input_pts_iter().any(|out_point| {
+                                context
+                                    .get_cell(&out_point)
+                                    .is_some_and(|(output, _)| output.lock() == lock_script)
+                            })
+                        });
+                    let _ = reply.send(Ok(tx));
+                }
+                IsCellLive(out_point, reply) => {
+                    let consumed = state
+                        .tx_status
+                        .values()
+                        .filter(|(_, status)| *status == ckb_jsonrpc_types::Status::Committed)
+                        .any(|(tx, _)| {
+                            Transaction::from(tx.inner.clone())
+                                .into_view()
+                                .input_pts_iter()
+                                .any(|input| input == out_point)
+                        });
+                    let live = !consumed && state.ctx.read().get_cell(&out_point).is_some();
+                    let _ = reply.send(Ok(live));
+                }
+                FundFee(tx, _fee_rate, reply) => {
+                    // Transactions are accepted without fee in the mock chain.
+                    let _ = reply.send(Ok(tx));
+                }
+                GetWalletBalance(_, reply) => {
+                    let _ = reply.send(Ok(Default::default()));
+                }
+                ListUnspent(_, reply) => {
+                    let _ = reply.send(Ok(vec![]));
+                }
+                GetWalletAddress(_, reply) => {
+                    let _ = reply.send(Err(WalletError::InvalidParameter(
+                        "No wallet address in the mock chain".to_string(),
+                    )));
+                }
+                SendToAddress(_, reply) => {
+                    let _ = reply.send(Err(WalletError::InvalidParameter(
+                        "Unable to send to address in the mock chain".to_string(),
+                    )));
+                }
+                Fund(tx, request, reply_port) if request.splice_input.is_some() => {
+                    // The splice transaction spends the current funding cell and creates
+                    // a new one holding the balances of both parties after the splice.
+                    let splice_input = request.splice_input.clone().expect("splice input");
+                    let mut fulfilled_tx = tx.clone();
+                    let (capacity, data) = match request.udt_type_script {
+                        Some(_) => {
+                            let udt_amount = (request.local_amount + request.remote_amount) as u128;
+                            (
+                                request.local_reserved_ckb_amount
+                                    + request.remote_reserved_ckb_amount,
+                                Bytes::from(udt_amount.to_le_bytes().to_vec()),
+                            )
+                        }
+                        None => (
+                            request.local_amount
+                                + request.remote_amount
+                                + request.local_reserved_ckb_amount
+                                + request.remote_reserved_ckb_amount,
+                            Bytes::new(),
+                        ),
+                    };
+                    let output = CellOutput::new_builder()
+                        .capacity(capacity.pack())
+                        .lock(request.script.clone())
+                        .type_(request.udt_type_script.clone().pack())
+                        .build();
+                    let tx_builder = fulfilled_tx
+                        .take()
+                        .map(|x| x.as_advanced_builder())
+                        .unwrap_or_default();
+                    fulfilled_tx
+                        .update_for_self(
+                            tx_builder
+                                .set_inputs(vec![CellInput::new(splice_input.out_point, 0)])
+                                .set_outputs(vec![output])
+                                .set_outputs_data(vec![data.pack()])
+                                .build(),
+                        )
+                        .expect("update tx");
+
+                    debug!(
+                        "Fulfilling splice funding request: request: {:?}, original tx: {:?}, fulfilled tx: {:?}",
+                        request, &tx, &fulfilled_tx
+                    );
+
+                    if let Err(e) = reply_port.send(Ok(fulfilled_tx)) {
+                        error!(
+                            "[{}] send reply failed: {:?}",
+                            myself.get_name().unwrap_or_default(),
+                            e
+                        );
+                    }
+                }
                 Fund(tx, request, reply_port) => {
                     let mut fulfilled_tx = tx.clone();
                     let outputs = fulfilled_tx
@@ -408,11 +683,30 @@ mod test_utils {
                         outputs_data
                     };
 
-                    let tx_builder = fulfilled_tx
+                    let mut tx_builder = fulfilled_tx
                         .take()
                         .map(|x| x.as_advanced_builder())
                         .unwrap_or_default();
 
+                    // Spend a new cell of the funding source, so that the funding transactions
+                    // replacing this one conflict with it.
+                    if tx.as_ref().map_or(true, |tx| tx.inputs().is_empty()) {
+                        let out_point = OutPoint::new(rand::random::<[u8; 32]>().pack(), 0);
+                        let cell = CellOutput::new_builder()
+                            .capacity(
+                                (request.local_amount + request.local_reserved_ckb_amount).pack(),
+                            )
+                            .lock(get_script_by_contract(Contract::AlwaysSuccess, &[]))
+                            .build();
+                        FUNDING_SOURCE_CELLS
+                            .lock()
+                            .unwrap()
+                            .insert(out_point.clone(), cell);
+                        tx_builder = tx_builder
+                            .input(CellInput::new(out_point, 0))
+                            .cell_deps(get_cell_deps_by_contracts(vec![Contract::AlwaysSuccess]));
+                    }
+
                     fulfilled_tx
                         .update_for_self(
                             tx_builder
@@ -438,8 +732,8 @@ mod test_utils {
                 Sign(tx, reply_port) => {
                     // We don't need to sign the funding transaction in mock chain actor,
                     // as any funding transaction is considered correct if we can successfully
-                    // run the scripts of transaction inputs, and we don't have inputs in the
-                    // funding transaction.
+                    // run the scripts of transaction inputs, and the inputs of the funding
+                    // transaction are locked by the always success script.
                     let signed_tx = tx.clone();
                     debug!(
                         "Signing transaction: original tx: {:?}, signed tx: {:?}",
@@ -455,20 +749,45 @@ mod test_utils {
                 }
                 SendTx(tx, reply_port) => {
                     const MAX_CYCLES: u64 = 100_000_000;
+                    let confirmations_held = self.confirmations_held.load(Ordering::SeqCst);
                     let mut context = state.ctx.write();
                     let mut f = || {
                         // Mark the inputs as consumed
                         for input in tx.input_pts_iter() {
+                            if context.get_cell(&input).is_none() {
+                                if let Some(cell) =
+                                    FUNDING_SOURCE_CELLS.lock().unwrap().get(&input).cloned()
+                                {
+                                    context.create_cell_with_out_point(
+                                        input.clone(),
+                                        cell,
+                                        Bytes::new(),
+                                    );
+                                }
+                            }
                             match state.cell_status.entry(input.clone()) {
                                 std::collections::hash_map::Entry::Occupied(mut entry) => {
                                     if *entry.get() == CellStatus::Consumed {
-                                        return (
-                                            ckb_jsonrpc_types::Status::Rejected,
-                                            Err(ckb_sdk::RpcError::Other(anyhow!(
-                                                "Cell {:?} already consumed",
-                                                &input
-                                            ))),
-                                        );
+                                        if !confirmations_held {
+                                            return (
+                                                ckb_jsonrpc_types::Status::Rejected,
+                                                Err(ckb_sdk::RpcError::Other(anyhow!(
+                                                    "Cell {:?} already consumed",
+                                                    &input
+                                                ))),
+                                            );
+                                        }
+                                        // The transactions spending this cell are not confirmed
+                                        // yet, so they are replaced by this one.
+                                        for (replaced_tx, status) in state.tx_status.values_mut() {
+                                            if Transaction::from(replaced_tx.inner.clone())
+                                                .into_view()
+                                                .input_pts_iter()
+                                                .any(|x| x == input)
+                                            {
+                                                *status = ckb_jsonrpc_types::Status::Rejected;
+                                            }
+                                        }
                                     }
                                     *entry.get_mut() = CellStatus::Consumed;
                                 }
@@ -521,6 +840,18 @@ mod test_utils {
                     }
                 }
                 TraceTx(tx, reply_port) => {
+                    if self.confirmations_held.load(Ordering::SeqCst) {
+                        // Trace the transaction again once the confirmations are released,
+                        // as it may have been replaced in the meantime.
+                        let confirmations_held = self.confirmations_held.clone();
+                        ractor::concurrency::tokio_primatives::spawn(async move {
+                            while confirmations_held.load(Ordering::SeqCst) {
+                                tokio::time::sleep(Duration::from_millis(100)).await;
+                            }
+                            let _ = myself.send_message(TraceTx(tx, reply_port));
+                        });
+                        return Ok(());
+                    }
                     let (tx_view, status) = match state.tx_status.get(&tx.tx_hash).cloned() {
                         Some((tx_view, status)) => (Some(tx_view), status),
                         None => (None, ckb_jsonrpc_types::Status::Unknown),
@@ -543,7 +874,6 @@ mod test_utils {
                         tx: tx_view,
                         status,
                     };
-
                     if let Err(e) = reply_port.send(response) {
                         error!(
                             "[{}] send reply failed: {:?}",
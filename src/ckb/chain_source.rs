@@ -0,0 +1,68 @@
+use ckb_types::{core::TransactionView, packed};
+use ractor::{call, ActorRef};
+
+use super::{error::ChainSourceError, CkbChainMessage};
+
+/// The source of chain data for the services which watch the chain, e.g. the watchtower.
+/// It's implemented by the chain actor, so the services can be tested against the mock chain.
+#[ractor::async_trait]
+pub trait ChainSource: Send + Sync {
+    /// Get a committed transaction by its hash.
+    async fn get_transaction(
+        &self,
+        tx_hash: packed::Byte32,
+    ) -> Result<Option<TransactionView>, ChainSourceError>;
+
+    /// Get the latest committed transaction which consumes a cell locked by the script.
+    async fn get_spending_transaction(
+        &self,
+        lock_script: packed::Script,
+    ) -> Result<Option<TransactionView>, ChainSourceError>;
+
+    /// Pay the fee of the transaction with the cells of the node's funding key.
+    async fn fund_fee(
+        &self,
+        tx: TransactionView,
+        fee_rate: u64,
+    ) -> Result<TransactionView, ChainSourceError>;
+
+    /// Send the transaction to the chain.
+    async fn send_transaction(&self, tx: TransactionView) -> Result<(), ChainSourceError>;
+}
+
+#[ractor::async_trait]
+impl ChainSource for ActorRef<CkbChainMessage> {
+    async fn get_transaction(
+        &self,
+        tx_hash: packed::Byte32,
+    ) -> Result<Option<TransactionView>, ChainSourceError> {
+        call!(self, CkbChainMessage::GetTransaction, tx_hash)
+            .map_err(|err| ChainSourceError::ActorError(err.to_string()))?
+            .map_err(Into::into)
+    }
+
+    async fn get_spending_transaction(
+        &self,
+        lock_script: packed::Script,
+    ) -> Result<Option<TransactionView>, ChainSourceError> {
+        call!(self, CkbChainMessage::GetSpendingTransaction, lock_script)
+            .map_err(|err| ChainSourceError::ActorError(err.to_string()))?
+            .map_err(Into::into)
+    }
+
+    async fn fund_fee(
+        &self,
+        tx: TransactionView,
+        fee_rate: u64,
+    ) -> Result<TransactionView, ChainSourceError> {
+        call!(self, CkbChainMessage::FundFee, tx, fee_rate)
+            .map_err(|err| ChainSourceError::ActorError(err.to_string()))?
+            .map_err(Into::into)
+    }
+
+    async fn send_transaction(&self, tx: TransactionView) -> Result<(), ChainSourceError> {
+        call!(self, CkbChainMessage::SendTx, tx)
+            .map_err(|err| ChainSourceError::ActorError(err.to_string()))?
+            .map_err(Into::into)
+    }
+}
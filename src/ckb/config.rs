@@ -22,6 +22,7 @@ use serde::Deserialize;
 
 pub const DEFAULT_CKB_BASE_DIR_NAME: &str = "ckb";
 const DEFAULT_CKB_NODE_RPC_URL: &str = "http://127.0.0.1:8114";
+const DEFAULT_SWEEP_FEE_RATE: u64 = 1000;
 
 #[derive(ClapSerde, Debug, Clone)]
 pub struct CkbConfig {
@@ -50,6 +51,15 @@ pub struct CkbConfig {
         help = "a list of supported UDT scripts"
     )]
     pub udt_whitelist: Option<UdtCfgInfos>,
+
+    /// The fee rate of the transactions claiming funds from force closed channels, in shannons per kilo-bytes. [default: 1000]
+    #[arg(
+        name = "CKB_SWEEP_FEE_RATE",
+        long = "ckb-sweep-fee-rate",
+        env,
+        help = "The fee rate of the transactions claiming funds from force closed channels, in shannons per kilo-bytes. [default: 1000]"
+    )]
+    pub sweep_fee_rate: Option<u64>,
 }
 
 impl CkbConfig {
@@ -57,6 +67,10 @@ impl CkbConfig {
         self.base_dir.as_ref().expect("have set base dir")
     }
 
+    pub fn sweep_fee_rate(&self) -> u64 {
+        self.sweep_fee_rate.unwrap_or(DEFAULT_SWEEP_FEE_RATE)
+    }
+
     pub fn create_base_dir(&self) -> crate::Result<()> {
         if !self.base_dir().exists() {
             std::fs::create_dir_all(self.base_dir()).map_err(Into::into)
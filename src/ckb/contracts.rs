@@ -135,6 +135,26 @@ pub enum Contract {
     SimpleUDT,
 }
 
+/// The fixed part of the commitment lock args: the hash of the aggregated pubkey (20 bytes),
+/// the contest delay as a since value in little endian (8 bytes) and the commitment number
+/// in big endian (8 bytes). It is followed by the hash of the pending tlcs if there are any.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CommitmentLockArgs {
+    pub pubkey_hash: [u8; 20],
+    pub delay: u64,
+    pub commitment_number: u64,
+}
+
+impl CommitmentLockArgs {
+    pub fn decode(args: &[u8]) -> Option<Self> {
+        Some(Self {
+            pubkey_hash: args.get(0..20)?.try_into().ok()?,
+            delay: u64::from_le_bytes(args.get(20..28)?.try_into().ok()?),
+            commitment_number: u64::from_be_bytes(args.get(28..36)?.try_into().ok()?),
+        })
+    }
+}
+
 #[derive(Clone, Debug)]
 struct ContractsInfo {
     contract_default_scripts: HashMap<Contract, Script>,
@@ -528,7 +548,7 @@ pub fn get_cell_deps_by_contracts(contracts: Vec<Contract>) -> CellDepVec {
     init_contracts_context(None, None).get_cell_deps(contracts)
 }
 
-fn get_udt_info(script: &Script) -> Option<&UdtArgInfo> {
+pub(crate) fn get_udt_info(script: &Script) -> Option<&UdtArgInfo> {
     init_contracts_context(None, None).get_udt_info(script)
 }
 
@@ -1,4 +1,6 @@
-use ckb_sdk::{tx_builder::TxBuilderError, unlock::UnlockError, RpcError};
+use ckb_sdk::{
+    traits::CellCollectorError, tx_builder::TxBuilderError, unlock::UnlockError, RpcError,
+};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -21,8 +23,40 @@ pub enum FundingError {
     InvalidChannel,
 }
 
+#[derive(Error, Debug)]
+pub enum WalletError {
+    #[error("Failed to call CKB node RPC: {0}")]
+    CkbRpcError(#[from] RpcError),
+
+    #[error("Failed to collect cells: {0}")]
+    CellCollectorError(#[from] CellCollectorError),
+
+    #[error("Failed to build CKB tx: {0}")]
+    CkbTxBuilderError(#[from] TxBuilderError),
+
+    #[error("Invalid address {0}: {1}")]
+    InvalidAddress(String, String),
+
+    #[error("Invalid parameter: {0}")]
+    InvalidParameter(String),
+}
+
+#[derive(Error, Debug)]
+pub enum ChainSourceError {
+    #[error("Failed to call CKB node RPC: {0}")]
+    CkbRpcError(#[from] RpcError),
+
+    #[error("Wallet error: {0}")]
+    WalletError(#[from] WalletError),
+
+    #[error("Failed to call chain actor: {0}")]
+    ActorError(String),
+}
+
 #[derive(Error, Debug)]
 pub enum CkbChainError {
     #[error("Funding error: {0}")]
     FundingError(#[from] FundingError),
+    #[error("Wallet error: {0}")]
+    WalletError(#[from] WalletError),
 }
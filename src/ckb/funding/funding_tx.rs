@@ -1,5 +1,8 @@
 use super::super::FundingError;
-use crate::{ckb::contracts::get_udt_cell_deps, fiber::serde_utils::EntityHex};
+use crate::{
+    ckb::contracts::{get_cell_deps, get_udt_cell_deps, Contract},
+    fiber::{channel::FUNDING_CELL_WITNESS_LEN, serde_utils::EntityHex},
+};
 use anyhow::anyhow;
 use ckb_sdk::{
     constants::SIGHASH_TYPE_HASH,
@@ -15,7 +18,7 @@ use ckb_sdk::{
 };
 use ckb_types::{
     core::{BlockView, Capacity, TransactionView},
-    packed::{self, Bytes, CellInput, CellOutput, Script, Transaction},
+    packed::{self, Bytes, CellInput, CellOutput, OutPoint, Script, Transaction},
     prelude::*,
 };
 use molecule::{
@@ -67,6 +70,23 @@ pub struct FundingRequest {
     pub local_reserved_ckb_amount: u64,
     /// CKB amount to be provided by the remote party.
     pub remote_reserved_ckb_amount: u64,
+    /// The current funding cell to spend when splicing an established channel.
+    #[serde(default)]
+    pub splice_input: Option<SpliceInput>,
+}
+
+/// The funding cell of an established channel, which is spent by the splice transaction.
+/// When splicing, `local_amount` and `remote_amount` in the request are the balances of
+/// both parties after the splice, and the assets already locked in this cell are reused.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize)]
+pub struct SpliceInput {
+    #[serde_as(as = "EntityHex")]
+    pub out_point: OutPoint,
+    /// CKB capacity of the current funding cell.
+    pub capacity: u64,
+    /// UDT amount of the current funding cell, zero for CKB channels.
+    pub udt_amount: u128,
 }
 
 // TODO: trace locked cells
@@ -108,6 +128,17 @@ impl TxBuilder for FundingTxBuilder {
             inputs = tx.inputs().into_iter().collect();
             cell_deps = tx.cell_deps().into_iter().collect();
         }
+        // The splice transaction always spends the current funding cell as the first input.
+        if let Some(ref splice_input) = self.request.splice_input {
+            let input = CellInput::new(splice_input.out_point.clone(), 0);
+            if !inputs.contains(&input) {
+                inputs.insert(0, input);
+            }
+            cell_deps.extend(get_cell_deps(
+                vec![Contract::FundingLock],
+                &self.request.udt_type_script,
+            ));
+        }
         self.build_udt_inputs_outputs(
             cell_collector,
             &mut inputs,
@@ -132,12 +163,21 @@ impl TxBuilder for FundingTxBuilder {
             .lock(Some(molecule::bytes::Bytes::from(vec![0u8; 170])).pack())
             .build();
 
+        let witnesses = match self.request.splice_input {
+            // The funding cell is unlocked by an aggregated musig2 signature from both parties,
+            // placeholder for the funding source inputs will be filled by the balancer.
+            Some(_) => {
+                vec![molecule::bytes::Bytes::from(vec![0u8; FUNDING_CELL_WITNESS_LEN]).pack()]
+            }
+            None => vec![placeholder_witness.as_bytes().pack()],
+        };
+
         let tx_builder = builder
             .set_inputs(inputs)
             .set_outputs(outputs)
             .set_outputs_data(outputs_data)
             .set_cell_deps(cell_deps.into_iter().collect())
-            .set_witnesses(vec![placeholder_witness.as_bytes().pack()]);
+            .set_witnesses(witnesses);
         let tx = tx_builder.build();
         Ok(tx)
     }
@@ -146,12 +186,14 @@ impl TxBuilder for FundingTxBuilder {
 impl FundingTxBuilder {
     fn build_funding_cell(&self) -> Result<(packed::CellOutput, packed::Bytes), FundingError> {
         // If outputs is not empty, assume that the remote party has already funded.
-        let remote_funded = self
-            .funding_tx
-            .tx
-            .as_ref()
-            .map(|tx| !tx.outputs().is_empty())
-            .unwrap_or(false);
+        // The assets of the remote party are already in the funding cell when splicing.
+        let remote_funded = self.request.splice_input.is_some()
+            || self
+                .funding_tx
+                .tx
+                .as_ref()
+                .map(|tx| !tx.outputs().is_empty())
+                .unwrap_or(false);
 
         match self.request.udt_type_script {
             Some(ref udt_type_script) => {
@@ -206,14 +248,43 @@ impl FundingTxBuilder {
         outputs_data: &mut Vec<packed::Bytes>,
         cell_deps: &mut HashSet<packed::CellDep>,
     ) -> Result<(), TxBuilderError> {
-        let udt_amount = self.request.local_amount as u128;
+        // When splicing, only the difference from the current funding cell is provided by
+        // (or returned to) the local party.
+        let (udt_amount, released_udt_amount) = match self.request.splice_input {
+            Some(ref splice_input) => {
+                let total_amount = (self.request.local_amount + self.request.remote_amount) as u128;
+                (
+                    total_amount.saturating_sub(splice_input.udt_amount),
+                    splice_input.udt_amount.saturating_sub(total_amount),
+                )
+            }
+            None => (self.request.local_amount as u128, 0),
+        };
         // return early if we don't need to build UDT cell
-        if self.request.udt_type_script.is_none() || udt_amount == 0 {
+        let udt_type_script = match self.request.udt_type_script {
+            Some(ref udt_type_script) => udt_type_script.clone(),
+            None => return Ok(()),
+        };
+        if self.request.splice_input.is_some() {
+            let udt_cell_deps =
+                get_udt_cell_deps(&udt_type_script).expect("get_udt_cell_deps failed");
+            cell_deps.extend(udt_cell_deps);
+        }
+        let owner = self.context.funding_source_lock_script.clone();
+        if released_udt_amount > 0 {
+            Self::push_udt_change_output(
+                owner,
+                udt_type_script,
+                released_udt_amount,
+                outputs,
+                outputs_data,
+            );
+            return Ok(());
+        }
+        if udt_amount == 0 {
             return Ok(());
         }
 
-        let udt_type_script = self.request.udt_type_script.clone().unwrap();
-        let owner = self.context.funding_source_lock_script.clone();
         let mut found_udt_amount = 0;
 
         let mut query = CellQueryOptions::new_lock(owner.clone());
@@ -240,24 +311,13 @@ impl FundingTxBuilder {
                 inputs.push(CellInput::new(cell.out_point.clone(), 0));
 
                 if found_udt_amount >= udt_amount {
-                    let change_output_data: Bytes =
-                        (found_udt_amount - udt_amount).to_le_bytes().pack();
-
-                    let dummy_output = CellOutput::new_builder()
-                        .lock(owner)
-                        .type_(Some(udt_type_script.clone()).pack())
-                        .build();
-                    let required_capacity = dummy_output
-                        .occupied_capacity(Capacity::bytes(change_output_data.len()).unwrap())
-                        .unwrap()
-                        .pack();
-                    let change_output = dummy_output
-                        .as_builder()
-                        .capacity(required_capacity)
-                        .build();
-
-                    outputs.push(change_output);
-                    outputs_data.push(change_output_data);
+                    Self::push_udt_change_output(
+                        owner,
+                        udt_type_script.clone(),
+                        found_udt_amount - udt_amount,
+                        outputs,
+                        outputs_data,
+                    );
 
                     debug!("find proper UDT owner cells: {:?}", inputs);
                     // we need to filter the cell deps by the contracts_context
@@ -275,6 +335,32 @@ impl FundingTxBuilder {
         )));
     }
 
+    fn push_udt_change_output(
+        owner: Script,
+        udt_type_script: Script,
+        udt_amount: u128,
+        outputs: &mut Vec<packed::CellOutput>,
+        outputs_data: &mut Vec<packed::Bytes>,
+    ) {
+        let change_output_data: Bytes = udt_amount.to_le_bytes().pack();
+
+        let dummy_output = CellOutput::new_builder()
+            .lock(owner)
+            .type_(Some(udt_type_script).pack())
+            .build();
+        let required_capacity = dummy_output
+            .occupied_capacity(Capacity::bytes(change_output_data.len()).unwrap())
+            .unwrap()
+            .pack();
+        let change_output = dummy_output
+            .as_builder()
+            .capacity(required_capacity)
+            .build();
+
+        outputs.push(change_output);
+        outputs_data.push(change_output_data);
+    }
+
     fn build(self) -> Result<FundingTx, FundingError> {
         // Build ScriptUnlocker
         let signer = SecpCkbRawKeySigner::new_with_secret_keys(vec![]);
@@ -1,12 +1,16 @@
 mod actor;
+mod chain_source;
 mod config;
 mod error;
 mod funding;
+mod wallet;
 
 pub use actor::{CkbChainActor, CkbChainMessage, TraceTxRequest, TraceTxResponse};
+pub use chain_source::ChainSource;
 pub use config::{CkbConfig, DEFAULT_CKB_BASE_DIR_NAME};
-pub use error::{CkbChainError, FundingError};
-pub use funding::{FundingRequest, FundingTx};
+pub use error::{ChainSourceError, CkbChainError, FundingError, WalletError};
+pub use funding::{FundingRequest, FundingTx, SpliceInput};
+pub use wallet::{SendToAddressRequest, UdtBalance, UnspentCell, WalletBalance};
 
 #[cfg(test)]
 pub use actor::{submit_tx, trace_tx, trace_tx_hash, MockChainActor};
@@ -0,0 +1,482 @@
+use super::{
+    contracts::{get_udt_cell_deps, get_udt_info},
+    WalletError,
+};
+use anyhow::anyhow;
+use ckb_sdk::{
+    constants::SIGHASH_TYPE_HASH,
+    rpc::ckb_indexer::SearchMode,
+    traits::{
+        CellCollector, CellDepResolver, CellQueryOptions, DefaultCellCollector,
+        DefaultCellDepResolver, DefaultHeaderDepResolver, DefaultTransactionDependencyProvider,
+        HeaderDepResolver, LiveCell, SecpCkbRawKeySigner, TransactionDependencyProvider,
+        ValueRangeOption,
+    },
+    tx_builder::{balance_tx_capacity, unlock_tx, CapacityBalancer, TxBuilder, TxBuilderError},
+    unlock::{ScriptUnlocker, SecpSighashUnlocker},
+    Address, AddressPayload, CkbRpcClient, NetworkType, ScriptId,
+};
+use ckb_types::{
+    core::{BlockView, Capacity, TransactionView},
+    packed::{self, Bytes, CellInput, CellOutput, OutPoint, Script},
+    prelude::*,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+use tracing::debug;
+
+/// Balances of the live cells locked by the funding source lock script of the node.
+#[derive(Clone, Debug, Default)]
+pub struct WalletBalance {
+    /// Capacity of the cells without type script and data, which can be spent freely.
+    pub ckb_amount: u64,
+    /// Capacity of all the live cells, including the capacity occupied by UDT cells.
+    pub total_capacity: u64,
+    /// Balances of the whitelisted UDTs held by the node.
+    pub udt_balances: Vec<UdtBalance>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UdtBalance {
+    /// The name of the UDT in the whitelist.
+    pub name: String,
+    pub type_script: Script,
+    pub amount: u128,
+}
+
+#[derive(Clone, Debug)]
+pub struct UnspentCell {
+    pub out_point: OutPoint,
+    pub capacity: u64,
+    pub type_script: Option<Script>,
+    /// The amount of UDT in the cell, only set for the whitelisted UDTs.
+    pub udt_amount: Option<u128>,
+    pub block_number: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct SendToAddressRequest {
+    pub address: String,
+    /// CKB amount in shannons, or UDT amount if `udt_type_script` is set.
+    pub amount: u128,
+    pub udt_type_script: Option<Script>,
+    pub fee_rate: u64,
+}
+
+fn get_udt_amount(cell: &LiveCell) -> Option<u128> {
+    let type_script = cell.output.type_().to_opt()?;
+    get_udt_info(&type_script)?;
+    let data = cell.output_data.as_ref();
+    if data.len() < 16 {
+        return None;
+    }
+    let mut amount_bytes = [0u8; 16];
+    amount_bytes.copy_from_slice(&data[0..16]);
+    Some(u128::from_le_bytes(amount_bytes))
+}
+
+impl WalletBalance {
+    pub fn from_cells<'a>(cells: impl IntoIterator<Item = &'a LiveCell>) -> Self {
+        let mut balance = Self::default();
+        for cell in cells {
+            let capacity: u64 = cell.output.capacity().unpack();
+            balance.total_capacity += capacity;
+            let type_script = match cell.output.type_().to_opt() {
+                Some(type_script) => type_script,
+                None => {
+                    if cell.output_data.is_empty() {
+                        balance.ckb_amount += capacity;
+                    }
+                    continue;
+                }
+            };
+            let (udt_info, amount) = match (get_udt_info(&type_script), get_udt_amount(cell)) {
+                (Some(udt_info), Some(amount)) => (udt_info, amount),
+                _ => continue,
+            };
+            match balance
+                .udt_balances
+                .iter_mut()
+                .find(|udt| udt.type_script == type_script)
+            {
+                Some(udt) => udt.amount += amount,
+                None => balance.udt_balances.push(UdtBalance {
+                    name: udt_info.name.clone(),
+                    type_script,
+                    amount,
+                }),
+            }
+        }
+        balance
+    }
+}
+
+impl From<&LiveCell> for UnspentCell {
+    fn from(cell: &LiveCell) -> Self {
+        Self {
+            out_point: cell.out_point.clone(),
+            capacity: cell.output.capacity().unpack(),
+            type_script: cell.output.type_().to_opt(),
+            udt_amount: get_udt_amount(cell),
+            block_number: cell.block_number,
+        }
+    }
+}
+
+/// The on-chain wallet of the node, which holds the cells locked by the funding source lock
+/// script derived from the configured secret key.
+#[derive(Clone, Debug)]
+pub struct WalletContext {
+    pub secret_key: secp256k1::SecretKey,
+    pub rpc_url: String,
+    pub lock_script: packed::Script,
+}
+
+impl WalletContext {
+    pub fn list_live_cells(&self) -> Result<Vec<LiveCell>, WalletError> {
+        let mut query = CellQueryOptions::new_lock(self.lock_script.clone());
+        query.script_search_mode = Some(SearchMode::Exact);
+        // Collect all the cells instead of stopping at the first one.
+        query.min_total_capacity = u64::MAX;
+        let mut cell_collector = DefaultCellCollector::new(&self.rpc_url);
+        let (cells, _) = cell_collector.collect_live_cells(&query, false)?;
+        Ok(cells)
+    }
+
+    pub fn get_balance(&self) -> Result<WalletBalance, WalletError> {
+        Ok(WalletBalance::from_cells(&self.list_live_cells()?))
+    }
+
+    pub fn list_unspent(&self) -> Result<Vec<UnspentCell>, WalletError> {
+        Ok(self
+            .list_live_cells()?
+            .iter()
+            .map(UnspentCell::from)
+            .collect())
+    }
+
+    fn get_network(&self) -> Result<NetworkType, WalletError> {
+        let chain_info = CkbRpcClient::new(&self.rpc_url).get_blockchain_info()?;
+        Ok(NetworkType::from_raw_str(&chain_info.chain).unwrap_or(NetworkType::Dev))
+    }
+
+    // The node has a single funding key, so the same address is returned every time.
+    pub fn get_address(&self) -> Result<String, WalletError> {
+        let network = self.get_network()?;
+        let payload = AddressPayload::from(self.lock_script.clone());
+        Ok(Address::new(network, payload, true).to_string())
+    }
+
+    fn parse_address(&self, address: &str) -> Result<Script, WalletError> {
+        let parsed = Address::from_str(address)
+            .map_err(|err| WalletError::InvalidAddress(address.to_string(), err))?;
+        // Dev chains share the address prefix with the testnet.
+        let network = self.get_network()?;
+        if parsed.network().to_prefix() != network.to_prefix() {
+            return Err(WalletError::InvalidAddress(
+                address.to_string(),
+                format!("address is not for network {:?}", network),
+            ));
+        }
+        Ok(Script::from(&parsed))
+    }
+
+    /// Builds and signs a transaction transferring CKB or UDT to the address.
+    pub fn build_transfer_tx(
+        &self,
+        request: SendToAddressRequest,
+    ) -> Result<TransactionView, WalletError> {
+        let receiver = self.parse_address(&request.address)?;
+        let builder = TransferTxBuilder {
+            sender: self.lock_script.clone(),
+            receiver,
+            amount: request.amount,
+            udt_type_script: request.udt_type_script,
+        };
+        builder.validate()?;
+
+        let unlockers = self.build_unlockers();
+        let balancer = self.build_balancer(request.fee_rate);
+        let cell_dep_resolver = self.build_cell_dep_resolver()?;
+        let header_dep_resolver = DefaultHeaderDepResolver::new(&self.rpc_url);
+        let mut cell_collector = DefaultCellCollector::new(&self.rpc_url);
+        let tx_dep_provider = DefaultTransactionDependencyProvider::new(&self.rpc_url, 10);
+
+        let (tx, still_locked_groups) = builder.build_unlocked(
+            &mut cell_collector,
+            &cell_dep_resolver,
+            &header_dep_resolver,
+            &tx_dep_provider,
+            &balancer,
+            &unlockers,
+        )?;
+        if !still_locked_groups.is_empty() {
+            return Err(TxBuilderError::Other(anyhow!(
+                "some inputs of the transfer transaction are not signed"
+            ))
+            .into());
+        }
+        debug!("Built transfer transaction: {:?}", tx);
+        Ok(tx)
+    }
+
+    /// Pays the fee of the transaction with the cells of the wallet. The fee inputs and a change
+    /// output are appended to the transaction, and only the fee inputs are signed, so the other
+    /// inputs must be unlocked by the witnesses already in the transaction.
+    pub fn fund_fee(
+        &self,
+        tx: TransactionView,
+        fee_rate: u64,
+    ) -> Result<TransactionView, WalletError> {
+        let unlockers = self.build_unlockers();
+        let balancer = self.build_balancer(fee_rate);
+        let cell_dep_resolver = self.build_cell_dep_resolver()?;
+        let header_dep_resolver = DefaultHeaderDepResolver::new(&self.rpc_url);
+        let mut cell_collector = DefaultCellCollector::new(&self.rpc_url);
+        let tx_dep_provider = DefaultTransactionDependencyProvider::new(&self.rpc_url, 10);
+
+        let tx = balance_tx_capacity(
+            &tx,
+            &balancer,
+            &mut cell_collector,
+            &tx_dep_provider,
+            &cell_dep_resolver,
+            &header_dep_resolver,
+        )
+        .map_err(TxBuilderError::from)?;
+        let (tx, _still_locked_groups) =
+            unlock_tx(tx, &tx_dep_provider, &unlockers).map_err(TxBuilderError::from)?;
+        debug!("Funded fee of transaction: {:?}", tx);
+        Ok(tx)
+    }
+
+    fn build_unlockers(&self) -> HashMap<ScriptId, Box<dyn ScriptUnlocker>> {
+        let signer = SecpCkbRawKeySigner::new_with_secret_keys(vec![FromStr::from_str(
+            hex::encode(self.secret_key.as_ref()).as_ref(),
+        )
+        .unwrap()]);
+        let sighash_unlocker = SecpSighashUnlocker::from(Box::new(signer) as Box<_>);
+        let sighash_script_id = ScriptId::new_type(SIGHASH_TYPE_HASH.clone());
+        let mut unlockers = HashMap::default();
+        unlockers.insert(
+            sighash_script_id,
+            Box::new(sighash_unlocker) as Box<dyn ScriptUnlocker>,
+        );
+        unlockers
+    }
+
+    fn build_balancer(&self, fee_rate: u64) -> CapacityBalancer {
+        let placeholder_witness = packed::WitnessArgs::new_builder()
+            .lock(Some(molecule::bytes::Bytes::from(vec![0u8; 65])).pack())
+            .build();
+        CapacityBalancer::new_simple(self.lock_script.clone(), placeholder_witness, fee_rate)
+    }
+
+    fn build_cell_dep_resolver(&self) -> Result<DefaultCellDepResolver, WalletError> {
+        let genesis_block = CkbRpcClient::new(&self.rpc_url)
+            .get_block_by_number(0.into())?
+            .ok_or_else(|| TxBuilderError::Other(anyhow!("genesis block not found")))?;
+        Ok(
+            DefaultCellDepResolver::from_genesis(&BlockView::from(genesis_block))
+                .map_err(|err| TxBuilderError::Other(anyhow!(err)))?,
+        )
+    }
+
+    pub fn send_to_address(
+        &self,
+        request: SendToAddressRequest,
+    ) -> Result<packed::Byte32, WalletError> {
+        let tx = self.build_transfer_tx(request)?;
+        CkbRpcClient::new(&self.rpc_url).send_transaction(tx.data().into(), None)?;
+        Ok(tx.hash())
+    }
+}
+
+struct TransferTxBuilder {
+    sender: Script,
+    receiver: Script,
+    amount: u128,
+    udt_type_script: Option<Script>,
+}
+
+impl TransferTxBuilder {
+    fn validate(&self) -> Result<(), WalletError> {
+        if self.amount == 0 {
+            return Err(WalletError::InvalidParameter(
+                "Amount must be greater than zero".to_string(),
+            ));
+        }
+        match self.udt_type_script {
+            Some(ref udt_type_script) => {
+                if get_udt_info(udt_type_script).is_none() {
+                    return Err(WalletError::InvalidParameter(format!(
+                        "UDT type script {:?} is not in the whitelist",
+                        udt_type_script
+                    )));
+                }
+            }
+            None => {
+                let (output, data) = self.build_receiver_output();
+                let occupied_capacity: u64 = output
+                    .occupied_capacity(Capacity::bytes(data.len()).expect("valid capacity"))
+                    .expect("valid capacity")
+                    .as_u64();
+                if self.amount > u64::MAX as u128 || (self.amount as u64) < occupied_capacity {
+                    return Err(WalletError::InvalidParameter(format!(
+                        "CKB amount {} is out of range, it must be at least {}",
+                        self.amount, occupied_capacity
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn build_receiver_output(&self) -> (CellOutput, Bytes) {
+        match self.udt_type_script {
+            Some(ref udt_type_script) => {
+                let data: Bytes = self.amount.to_le_bytes().pack();
+                let output = CellOutput::new_builder()
+                    .lock(self.receiver.clone())
+                    .type_(Some(udt_type_script.clone()).pack())
+                    .build();
+                let capacity = output
+                    .occupied_capacity(Capacity::bytes(data.len()).expect("valid capacity"))
+                    .expect("valid capacity");
+                (output.as_builder().capacity(capacity.pack()).build(), data)
+            }
+            None => (
+                CellOutput::new_builder()
+                    .capacity((self.amount as u64).pack())
+                    .lock(self.receiver.clone())
+                    .build(),
+                Bytes::default(),
+            ),
+        }
+    }
+
+    fn build_udt_change_output(
+        udt_type_script: Script,
+        owner: Script,
+        amount: u128,
+    ) -> (CellOutput, Bytes) {
+        let data: Bytes = amount.to_le_bytes().pack();
+        let output = CellOutput::new_builder()
+            .lock(owner)
+            .type_(Some(udt_type_script).pack())
+            .build();
+        let capacity = output
+            .occupied_capacity(Capacity::bytes(data.len()).expect("valid capacity"))
+            .expect("valid capacity");
+        (output.as_builder().capacity(capacity.pack()).build(), data)
+    }
+}
+
+impl TxBuilder for TransferTxBuilder {
+    fn build_base(
+        &self,
+        cell_collector: &mut dyn CellCollector,
+        _cell_dep_resolver: &dyn CellDepResolver,
+        _header_dep_resolver: &dyn HeaderDepResolver,
+        _tx_dep_provider: &dyn TransactionDependencyProvider,
+    ) -> Result<TransactionView, TxBuilderError> {
+        let (output, data) = self.build_receiver_output();
+        let mut inputs = vec![];
+        let mut outputs = vec![output];
+        let mut outputs_data = vec![data];
+        let mut cell_deps = HashSet::new();
+
+        // The capacity is provided by the balancer, only the UDT cells are collected here.
+        if let Some(ref udt_type_script) = self.udt_type_script {
+            let mut query = CellQueryOptions::new_lock(self.sender.clone());
+            query.script_search_mode = Some(SearchMode::Exact);
+            query.secondary_script = Some(udt_type_script.clone());
+            query.data_len_range = Some(ValueRangeOption::new_min(16));
+
+            let mut found_udt_amount = 0;
+            while found_udt_amount < self.amount {
+                // each query will found at most one cell because of `min_total_capacity == 1` in CellQueryOptions
+                let (udt_cells, _) = cell_collector.collect_live_cells(&query, true)?;
+                if udt_cells.is_empty() {
+                    return Err(TxBuilderError::Other(anyhow!(
+                        "can not find enough UDT cells to transfer {}",
+                        self.amount
+                    )));
+                }
+                for cell in udt_cells.iter() {
+                    let mut amount_bytes = [0u8; 16];
+                    amount_bytes.copy_from_slice(&cell.output_data.as_ref()[0..16]);
+                    found_udt_amount += u128::from_le_bytes(amount_bytes);
+                    inputs.push(CellInput::new(cell.out_point.clone(), 0));
+                }
+            }
+            if found_udt_amount > self.amount {
+                let (change_output, change_data) = Self::build_udt_change_output(
+                    udt_type_script.clone(),
+                    self.sender.clone(),
+                    found_udt_amount - self.amount,
+                );
+                outputs.push(change_output);
+                outputs_data.push(change_data);
+            }
+            cell_deps.extend(get_udt_cell_deps(udt_type_script).unwrap_or_default());
+        }
+
+        Ok(TransactionView::new_advanced_builder()
+            .set_inputs(inputs)
+            .set_outputs(outputs)
+            .set_outputs_data(outputs_data)
+            .set_cell_deps(cell_deps.into_iter().collect())
+            .build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WalletBalance;
+    use ckb_sdk::traits::LiveCell;
+    use ckb_types::{
+        core::ScriptHashType,
+        packed::{CellOutput, OutPoint, Script},
+        prelude::{Builder, Entity, Pack},
+    };
+
+    fn live_cell(capacity: u64, type_script: Option<Script>, data: Vec<u8>) -> LiveCell {
+        LiveCell {
+            output: CellOutput::new_builder()
+                .capacity(capacity.pack())
+                .type_(type_script.pack())
+                .build(),
+            output_data: data.into(),
+            out_point: OutPoint::default(),
+            block_number: 0,
+            tx_index: 0,
+        }
+    }
+
+    #[test]
+    fn test_wallet_balance_from_cells() {
+        let unknown_type_script = Script::new_builder()
+            .code_hash([1u8; 32].pack())
+            .hash_type(ScriptHashType::Type.into())
+            .build();
+        let cells = vec![
+            live_cell(100_00000000, None, vec![]),
+            live_cell(200_00000000, None, vec![]),
+            // Cells with data are not spent by the wallet.
+            live_cell(300_00000000, None, vec![1, 2, 3]),
+            // Cells with unknown type scripts are not counted as UDT.
+            live_cell(
+                400_00000000,
+                Some(unknown_type_script),
+                100u128.to_le_bytes().to_vec(),
+            ),
+        ];
+        let balance = WalletBalance::from_cells(&cells);
+        assert_eq!(balance.ckb_amount, 300_00000000);
+        assert_eq!(balance.total_capacity, 1000_00000000);
+        assert!(balance.udt_balances.is_empty());
+    }
+}
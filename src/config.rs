@@ -9,7 +9,9 @@ use home::home_dir;
 use serde::{Deserialize, Serialize};
 use tracing::error;
 
-use crate::{ckb::CkbConfig, CchConfig, FiberConfig, LdkConfig, RpcConfig};
+use crate::{
+    ckb::CkbConfig, watchtower::WatchtowerConfig, CchConfig, FiberConfig, LdkConfig, RpcConfig,
+};
 
 const DEFAULT_CONFIG_FILE_NAME: &str = "config.yml";
 const DEFAULT_FIBER_DIR_NAME: &str = "fiber";
@@ -40,6 +42,8 @@ enum Service {
     RPC,
     #[serde(alias = "ckb", alias = "CKB")]
     CkbChain,
+    #[serde(alias = "watchtower", alias = "WATCHTOWER")]
+    WATCHTOWER,
 }
 
 impl FromStr for Service {
@@ -51,6 +55,7 @@ impl FromStr for Service {
             "cch" | "CCH" => Ok(Self::CCH),
             "rpc" | "RPC" => Ok(Self::RPC),
             "ckb" | "CKB" => Ok(Self::CkbChain),
+            "watchtower" | "WATCHTOWER" => Ok(Self::WATCHTOWER),
             _ => Err(format!("invalid service {}", s)),
         }
     }
@@ -93,6 +98,10 @@ struct Args {
     /// config for ckb
     #[command(flatten)]
     pub ckb: <CkbConfig as ClapSerde>::Opt,
+
+    /// config for the standalone watchtower
+    #[command(flatten)]
+    pub watchtower: <WatchtowerConfig as ClapSerde>::Opt,
 }
 
 #[derive(Deserialize)]
@@ -103,6 +112,7 @@ struct SerializedConfig {
     cch: Option<<CchConfig as ClapSerde>::Opt>,
     rpc: Option<<RpcConfig as ClapSerde>::Opt>,
     ckb: Option<<CkbConfig as ClapSerde>::Opt>,
+    watchtower: Option<<WatchtowerConfig as ClapSerde>::Opt>,
 }
 
 #[derive(Debug)]
@@ -117,6 +127,8 @@ pub struct Config {
     pub rpc: Option<RpcConfig>,
     // ckb actor config, None represents that we should not run ckb actor
     pub ckb: Option<CkbConfig>,
+    // watchtower config, None represents that we should not run the standalone watchtower service
+    pub watchtower: Option<WatchtowerConfig>,
 }
 
 pub(crate) fn print_help_and_exit(code: i32) {
@@ -174,8 +186,11 @@ impl Config {
         args.ldk.base_dir = Some(Some(base_dir.join(DEFAULT_LDK_DIR_NAME)));
         args.ckb.base_dir = Some(Some(base_dir.join(crate::ckb::DEFAULT_CKB_BASE_DIR_NAME)));
         args.cch.base_dir = Some(Some(base_dir.join(DEFAULT_CCH_DIR_NAME)));
+        args.watchtower.base_dir = Some(Some(
+            base_dir.join(crate::watchtower::DEFAULT_WATCHTOWER_BASE_DIR_NAME),
+        ));
 
-        let (fiber, ldk, cch, rpc, ckb) = config_from_file
+        let (fiber, ldk, cch, rpc, ckb, watchtower) = config_from_file
             .map(|x| {
                 let SerializedConfig {
                     services: _,
@@ -184,6 +199,7 @@ impl Config {
                     cch,
                     rpc,
                     ckb,
+                    watchtower,
                 } = x;
                 (
                     // Successfully read config file, merging these options with the default ones.
@@ -192,15 +208,17 @@ impl Config {
                     cch.map(|c| CchConfig::from(c).merge(&mut args.cch)),
                     rpc.map(|c| RpcConfig::from(c).merge(&mut args.rpc)),
                     ckb.map(|c| CkbConfig::from(c).merge(&mut args.ckb)),
+                    watchtower.map(|c| WatchtowerConfig::from(c).merge(&mut args.watchtower)),
                 )
             })
-            .unwrap_or((None, None, None, None, None));
-        let (fiber, ldk, cch, rpc, ckb) = (
+            .unwrap_or((None, None, None, None, None, None));
+        let (fiber, ldk, cch, rpc, ckb, watchtower) = (
             fiber.unwrap_or(FiberConfig::from(&mut args.fiber)),
             ldk.unwrap_or(LdkConfig::from(&mut args.ldk)),
             cch.unwrap_or(CchConfig::from(&mut args.cch)),
             rpc.unwrap_or(RpcConfig::from(&mut args.rpc)),
             ckb.unwrap_or(CkbConfig::from(&mut args.ckb)),
+            watchtower.unwrap_or(WatchtowerConfig::from(&mut args.watchtower)),
         );
 
         let fiber = services.contains(&Service::FIBER).then_some(fiber);
@@ -208,12 +226,16 @@ impl Config {
         let cch = services.contains(&Service::CCH).then_some(cch);
         let rpc = services.contains(&Service::RPC).then_some(rpc);
         let ckb = services.contains(&Service::CkbChain).then_some(ckb);
+        let watchtower = services
+            .contains(&Service::WATCHTOWER)
+            .then_some(watchtower);
         Self {
             fiber,
             ldk,
             cch,
             rpc,
             ckb,
+            watchtower,
         }
     }
 }
@@ -44,6 +44,8 @@ pub enum Error {
     InvalidPeerMessage(String),
     #[error("Onion packet error: {0}")]
     InvalidOnionPacket(crate::fiber::types::Error),
+    #[error("Send payment error: {0}")]
+    SendPaymentError(String),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
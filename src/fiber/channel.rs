@@ -1,10 +1,10 @@
 use bitflags::bitflags;
-use secp256k1::XOnlyPublicKey;
+use secp256k1::{SecretKey, XOnlyPublicKey};
 use tracing::{debug, error, info, warn};
 
 use crate::{
     fiber::{network::get_chain_hash, types::ChannelUpdate},
-    invoice::InvoiceStore,
+    invoice::{get_invoice_status_with_expiry, CkbInvoiceStatus, InvoiceStore},
 };
 use ckb_hash::{blake2b_256, new_blake2b};
 use ckb_sdk::Since;
@@ -37,21 +37,26 @@ use std::{
     borrow::Borrow,
     collections::BTreeMap,
     fmt::Debug,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     time::{SystemTime, UNIX_EPOCH},
 };
 
 use crate::{
     ckb::{
         contracts::{get_cell_deps, get_script_by_contract, Contract},
-        FundingRequest,
+        FundingRequest, SpliceInput,
     },
     fiber::{
         config::{DEFAULT_UDT_MINIMAL_CKB_AMOUNT, MIN_OCCUPIED_CAPACITY},
         fee::{calculate_commitment_tx_fee, shutdown_tx_size},
-        network::{emit_service_event, sign_network_message},
+        network::{emit_service_event, sign_network_message, ReceivedMppTlc},
         types::{AnnouncementSignatures, FiberBroadcastMessage, Shutdown},
     },
+    sweeper::{SettlementData, SweepOutput, SweepTlc},
+    watchtower::RevocationData,
     NetworkServiceEvent,
 };
 
@@ -62,11 +67,15 @@ use super::{
     key::blake2b_hash_with_salt,
     network::FiberMessageWithPeerId,
     serde_utils::EntityHex,
+    subscription::NodeEventSubscribers,
     types::{
-        AcceptChannel, AddTlc, ChannelAnnouncement, ChannelReady, ClosingSigned, CommitmentSigned,
-        EcdsaSignature, FiberChannelMessage, FiberMessage, Hash256, LockTime, OpenChannel, Privkey,
-        Pubkey, ReestablishChannel, RemoveTlc, RemoveTlcFulfill, RemoveTlcReason, RevokeAndAck,
-        TxCollaborationMsg, TxComplete, TxUpdate,
+        AcceptChannel, AddTlc, ChannelAnnouncement, ChannelReady, ClosingSigned, CommitmentSecret,
+        CommitmentSigned, DataLossProtect, EcdsaSignature, FiberChannelMessage, FiberMessage,
+        Hash256, LockTime, OpenChannel, PeeledPaymentOnionPacket, Privkey, Pubkey,
+        ReestablishChannel, RemoveTlc, RemoveTlcFail, RemoveTlcFulfill, RemoveTlcReason,
+        RevokeAndAck, SpliceAck, SpliceCommitmentSigned, SpliceInit, SpliceLocked,
+        SpliceRevokeAndAck, TlcErr, TxAbort, TxAckRBF, TxCollaborationMsg, TxComplete, TxInitRBF,
+        TxSignatures, TxUpdate, NO_SHARED_SECRET,
     },
     NetworkActorCommand, NetworkActorEvent, NetworkActorMessage, ASSUME_NETWORK_ACTOR_ALIVE,
 };
@@ -114,6 +123,8 @@ pub enum ChannelCommand {
     RemoveTlc(RemoveTlcCommand, RpcReplyPort<Result<(), String>>),
     Shutdown(ShutdownCommand, RpcReplyPort<Result<(), String>>),
     Update(UpdateCommand, RpcReplyPort<Result<(), String>>),
+    BumpFundingFee(BumpFundingFeeCommand, RpcReplyPort<Result<(), String>>),
+    Splice(SpliceCommand, RpcReplyPort<Result<(), String>>),
 }
 
 #[derive(Debug)]
@@ -146,6 +157,19 @@ pub struct ShutdownCommand {
     pub force: bool,
 }
 
+#[derive(Debug)]
+pub struct BumpFundingFeeCommand {
+    pub funding_fee_rate: u64,
+}
+
+#[derive(Debug)]
+pub struct SpliceCommand {
+    // The amount of CKB/UDT we own in the channel after the splice, the difference
+    // is added from or withdrawn to our wallet.
+    pub funding_amount: u128,
+    pub funding_fee_rate: u64,
+}
+
 #[derive(Debug)]
 pub struct UpdateCommand {
     pub tlc_locktime_expiry_delta: Option<u64>,
@@ -173,6 +197,10 @@ pub const DEFAULT_MAX_NUM_OF_ACCEPT_TLCS: u64 = 30;
 pub const SYS_MAX_NUM_OF_ACCEPT_TLCS: u64 = 254;
 pub const DEFAULT_MIN_TLC_VALUE: u128 = 0;
 pub const DEFAULT_TO_LOCAL_DELAY_BLOCKS: u64 = 10;
+// The musig2 nonces for the commitment transactions spending a spliced funding cell are
+// derived with an offset of `SPLICE_NONCE_OFFSET * splice_rounds`, which never collides
+// with the nonces derived for the funding cells before.
+pub const SPLICE_NONCE_OFFSET: u64 = 1 << 32;
 
 #[derive(Debug)]
 pub struct TxUpdateCommand {
@@ -220,6 +248,9 @@ pub enum ChannelInitializationParameter {
 pub struct ChannelSubscribers {
     pub pending_received_tlcs_subscribers: Arc<OutputPort<TlcNotification>>,
     pub settled_tlcs_subscribers: Arc<OutputPort<TlcNotification>>,
+    pub node_events: NodeEventSubscribers,
+    // Whether any actor subscribes the pending received tlcs, e.g. the cross-chain hub.
+    has_pending_received_tlcs_subscribers: Arc<AtomicBool>,
 }
 
 impl Default for ChannelSubscribers {
@@ -227,10 +258,40 @@ impl Default for ChannelSubscribers {
         Self {
             pending_received_tlcs_subscribers: Arc::new(OutputPort::default()),
             settled_tlcs_subscribers: Arc::new(OutputPort::default()),
+            node_events: NodeEventSubscribers::default(),
+            has_pending_received_tlcs_subscribers: Arc::new(AtomicBool::new(false)),
         }
     }
 }
 
+impl ChannelSubscribers {
+    /// Subscribes the pending received tlcs of the UDT channels. The subscriber may settle
+    /// the tlcs paying to the payment hashes unknown to the node, e.g. the cross-chain hub
+    /// settles the tlcs of the SendBTC orders after the BTC payments succeed.
+    pub fn subscribe_pending_received_tlcs<TReceiverMsg, F>(
+        &self,
+        receiver: ActorRef<TReceiverMsg>,
+        converter: F,
+    ) where
+        TReceiverMsg: ractor::Message,
+        F: Fn(TlcNotification) -> Option<TReceiverMsg> + Send + 'static,
+    {
+        self.pending_received_tlcs_subscribers
+            .subscribe(receiver, converter);
+        self.has_pending_received_tlcs_subscribers
+            .store(true, Ordering::SeqCst);
+    }
+
+    /// Returns true if the tlcs of the channel with unknown payment hashes may be claimed
+    /// by the subscribers of the pending received tlcs.
+    fn may_claim_received_tlc(&self, state: &ChannelActorState) -> bool {
+        state.funding_udt_type_script.is_some()
+            && self
+                .has_pending_received_tlcs_subscribers
+                .load(Ordering::SeqCst)
+    }
+}
+
 pub struct ChannelActor<S> {
     local_pubkey: Pubkey,
     remote_pubkey: Pubkey,
@@ -271,6 +332,39 @@ where
         self.remote_pubkey.tentacle_peer_id()
     }
 
+    // The commitment cell of our confirmed commitment transaction for the sweeper to claim
+    // our funds. The preimages of the received tlcs are filled if we know them now.
+    fn get_sweep_output(&self, state: &ChannelActorState) -> Option<SweepOutput> {
+        let commitment_tx = state.latest_commitment_transaction.as_ref()?;
+        let mut settlement = state.latest_commitment_settlement.clone()?;
+        for tlc in settlement.tlcs.iter_mut().filter(|tlc| !tlc.offered) {
+            tlc.preimage = tlc
+                .preimage
+                .or_else(|| {
+                    state
+                        .tlcs
+                        .values()
+                        .filter(|info| info.tlc.payment_hash == tlc.payment_hash)
+                        .find_map(|info| match &info.removed_at {
+                            // The tlc may be fulfilled after the channel is force closed.
+                            Some((_, RemoveTlcReason::RemoveTlcFulfill(fulfill))) => {
+                                Some(fulfill.payment_preimage)
+                            }
+                            _ => info.tlc.payment_preimage,
+                        })
+                })
+                .or_else(|| self.store.get_invoice_preimage(&tlc.payment_hash));
+        }
+        Some(SweepOutput {
+            channel_id: state.get_id(),
+            commitment_out_point: OutPoint::new(commitment_tx.calc_tx_hash(), 0),
+            commitment_lock: commitment_tx.raw().outputs().get(0)?.lock(),
+            shutdown_script: state.get_local_shutdown_script(),
+            settlement,
+            signer: state.signer.clone(),
+        })
+    }
+
     pub async fn handle_peer_message(
         &self,
         state: &mut ChannelActorState,
@@ -327,6 +421,16 @@ where
                     .expect(ASSUME_NETWORK_ACTOR_ALIVE);
                 Ok(())
             }
+            FiberChannelMessage::TxUpdate(tx) if state.splice.is_some() => state
+                .handle_splice_tx_collaboration_msg(
+                    TxCollaborationMsg::TxUpdate(tx),
+                    &self.network,
+                ),
+            FiberChannelMessage::TxComplete(tx) if state.splice.is_some() => state
+                .handle_splice_tx_collaboration_msg(
+                    TxCollaborationMsg::TxComplete(tx),
+                    &self.network,
+                ),
             FiberChannelMessage::TxUpdate(tx) => {
                 state.handle_tx_collaboration_msg(TxCollaborationMsg::TxUpdate(tx), &self.network)
             }
@@ -365,6 +469,9 @@ where
                 self.try_to_settle_down_tlc(state);
                 Ok(())
             }
+            FiberChannelMessage::TxSignatures(tx_signatures) if state.splice.is_some() => {
+                state.handle_splice_tx_signatures(tx_signatures, &self.network)
+            }
             FiberChannelMessage::TxSignatures(tx_signatures) => {
                 // We're the one who sent tx_signature first, and we received a tx_signature message.
                 // This means that the tx_signature procedure is now completed. Just change state,
@@ -411,6 +518,9 @@ where
                 state.handle_revoke_and_ack_message(&self.network, revoke_and_ack)?;
                 Ok(())
             }
+            FiberChannelMessage::CommitmentSecret(commitment_secret) => {
+                state.handle_commitment_secret_message(commitment_secret)
+            }
             FiberChannelMessage::ChannelReady(channel_ready) => {
                 let flags = match state.state {
                     ChannelState::AwaitingTxSignatures(flags) => {
@@ -451,14 +561,14 @@ where
                 // If there is a next hop, we should send the AddTlc message to the next hop.
                 // If this is the last hop, we should check the payment hash and amount and then
                 // try to fulfill the payment, find the corresponding payment preimage from payment hash.
-                let mut preimage = None;
+                let mut peeled_packet = None;
                 let mut peeled_packet_bytes: Option<Vec<u8>> = None;
 
                 if !add_tlc.onion_packet.is_empty() {
                     // TODO: Here we call network actor to peel the onion packet. Indeed, this message is forwarded from
                     // the network actor when it handles `FiberMessage::ChannelNormalOperation`. A better alternative is
                     // peeling the onion packet there before forwarding the message to the channel actor.
-                    let peeled_packet = call!(self.network, |tx| NetworkActorMessage::Command(
+                    let packet = call!(self.network, |tx| NetworkActorMessage::Command(
                         NetworkActorCommand::PeelPaymentOnionPacket(
                             add_tlc.onion_packet.clone(),
                             add_tlc.payment_hash.clone(),
@@ -469,23 +579,31 @@ where
                     .map_err(|err| ProcessingChannelError::PeelingOnionPacketError(err))?;
 
                     // TODO: check the expiry time, if expired, we should return an error.
-                    if peeled_packet.is_last() {
+                    if packet.is_last() {
                         // check the payment hash and amount
-                        if peeled_packet.current.payment_hash != add_tlc.payment_hash
-                            || peeled_packet.current.amount != add_tlc.amount
+                        if packet.current.payment_hash != add_tlc.payment_hash
+                            || packet.current.amount != add_tlc.amount
                         {
                             return Err(ProcessingChannelError::InvalidParameter(
                                 "Payment hash or amount mismatch".to_string(),
                             ));
                         }
-                        // if this is the last hop, store the preimage.
-                        preimage = peeled_packet.current.preimage;
+                        if packet
+                            .current
+                            .total_amount
+                            .is_some_and(|total| total < add_tlc.amount)
+                        {
+                            return Err(ProcessingChannelError::InvalidParameter(
+                                "Total amount is less than the tlc amount".to_string(),
+                            ));
+                        }
                     } else {
-                        peeled_packet_bytes = Some(peeled_packet.serialize());
+                        peeled_packet_bytes = Some(packet.serialize());
                     }
+                    peeled_packet = Some(packet);
                 }
 
-                let tlc = state.create_inbounding_tlc(add_tlc.clone(), preimage)?;
+                let tlc = state.create_inbounding_tlc(add_tlc.clone(), peeled_packet.as_ref())?;
                 state.insert_tlc(tlc.clone())?;
                 if let Some(ref udt_type_script) = state.funding_udt_type_script {
                     self.subscribers
@@ -518,15 +636,17 @@ where
                 state.check_for_tlc_update(None)?;
                 let channel_id = state.get_id();
 
-                let tlc_details = state
-                    .remove_tlc_with_reason(TLCId::Offered(remove_tlc.tlc_id), remove_tlc.reason)?;
+                let tlc_details = state.remove_tlc_with_reason(
+                    TLCId::Offered(remove_tlc.tlc_id),
+                    remove_tlc.reason.clone(),
+                )?;
                 if let (
                     Some(ref udt_type_script),
                     RemoveTlcReason::RemoveTlcFulfill(RemoveTlcFulfill { payment_preimage }),
-                ) = (state.funding_udt_type_script.clone(), remove_tlc.reason)
+                ) = (state.funding_udt_type_script.clone(), &remove_tlc.reason)
                 {
                     let mut tlc = tlc_details.tlc.clone();
-                    tlc.payment_preimage = Some(payment_preimage);
+                    tlc.payment_preimage = Some(*payment_preimage);
                     self.subscribers
                         .settled_tlcs_subscribers
                         .send(TlcNotification {
@@ -537,6 +657,18 @@ where
                 }
                 if let Some((previous_channel_id, previous_tlc)) = tlc_details.tlc.previous_tlc {
                     assert!(previous_tlc.is_received());
+                    if let RemoveTlcReason::RemoveTlcFulfill(RemoveTlcFulfill {
+                        payment_preimage,
+                    }) = &remove_tlc.reason
+                    {
+                        emit_service_event(
+                            &self.network,
+                            NetworkServiceEvent::TlcPreimageReceived(
+                                tlc_details.tlc.payment_hash,
+                                *payment_preimage,
+                            ),
+                        );
+                    }
                     info!(
                         "begin to remove tlc from previous channel: {:?}",
                         &previous_tlc
@@ -550,6 +682,8 @@ where
                                 command: ChannelCommand::RemoveTlc(
                                     RemoveTlcCommand {
                                         id: previous_tlc.into(),
+                                        // The error packet is wrapped by the previous channel
+                                        // with the shared secret of the received tlc.
                                         reason: remove_tlc.reason,
                                     },
                                     port,
@@ -559,10 +693,40 @@ where
                         .expect(ASSUME_NETWORK_ACTOR_ALIVE);
                     let res = recv.await.expect("network actor is alive");
                     info!("remove tlc from previous channel: {:?}", &res);
+                } else {
+                    // We are the origin of this tlc, notify the network actor to update the payment session.
+                    self.network
+                        .send_message(NetworkActorMessage::new_event(
+                            NetworkActorEvent::TlcRemoveReceived(
+                                tlc_details.tlc.payment_hash,
+                                (channel_id, remove_tlc.tlc_id),
+                                remove_tlc.reason,
+                            ),
+                        ))
+                        .expect(ASSUME_NETWORK_ACTOR_ALIVE);
                 }
                 Ok(())
             }
             FiberChannelMessage::Shutdown(shutdown) => {
+                // A forced shutdown is sent by the peer which has lost its states, it can only
+                // be closed with our latest commitment transaction.
+                if shutdown.force {
+                    if !matches!(
+                        state.state,
+                        ChannelState::ChannelReady() | ChannelState::ShuttingDown(_)
+                    ) {
+                        return Err(ProcessingChannelError::InvalidState(format!(
+                            "received forced Shutdown message in state {:?}",
+                            state.state
+                        )));
+                    }
+                    warn!(
+                        "Peer {:?} asked to force close channel {:?}",
+                        state.get_remote_peer_id(),
+                        state.get_id()
+                    );
+                    return self.force_close(state);
+                }
                 let flags = match state.state {
                     ChannelState::ChannelReady() => ShuttingDownFlags::empty(),
                     ChannelState::ShuttingDown(flags)
@@ -655,40 +819,350 @@ where
                 state.handle_reestablish_channel_message(reestablish_channel, &self.network)?;
                 Ok(())
             }
-            FiberChannelMessage::TxAbort(_)
-            | FiberChannelMessage::TxInitRBF(_)
-            | FiberChannelMessage::TxAckRBF(_) => {
-                warn!("Received unsupported message: {:?}", &message);
+            FiberChannelMessage::TxInitRBF(tx_init_rbf) => {
+                // Both sides may try to replace the funding transaction at the same time,
+                // the acceptor gives up its own proposal in that case.
+                if state.pending_funding_fee_rate.is_some() && !state.is_acceptor {
+                    debug!(
+                        "Ignoring TxInitRBF {:?} while waiting for the TxAckRBF of our own",
+                        &tx_init_rbf
+                    );
+                    return Ok(());
+                }
+                if let Err(err) = state.check_funding_rbf(tx_init_rbf.fee_rate) {
+                    self.network
+                        .send_message(NetworkActorMessage::new_command(
+                            NetworkActorCommand::SendFiberMessage(FiberMessageWithPeerId::new(
+                                state.get_remote_peer_id(),
+                                FiberMessage::tx_abort(TxAbort {
+                                    channel_id: state.get_id(),
+                                    message: err.to_string().into_bytes(),
+                                }),
+                            )),
+                        ))
+                        .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+                    return Err(err);
+                }
+                self.network
+                    .send_message(NetworkActorMessage::new_command(
+                        NetworkActorCommand::SendFiberMessage(FiberMessageWithPeerId::new(
+                            state.get_remote_peer_id(),
+                            FiberMessage::tx_ack_rbf(TxAckRBF {
+                                channel_id: state.get_id(),
+                            }),
+                        )),
+                    ))
+                    .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+                state.start_funding_rbf(tx_init_rbf.fee_rate, &self.network);
+                Ok(())
+            }
+            FiberChannelMessage::TxAckRBF(_) => {
+                let funding_fee_rate =
+                    state
+                        .pending_funding_fee_rate
+                        .ok_or(ProcessingChannelError::InvalidState(
+                            "Received TxAckRBF without sending TxInitRBF".to_string(),
+                        ))?;
+                state.check_funding_rbf(funding_fee_rate)?;
+                state.start_funding_rbf(funding_fee_rate, &self.network);
+                Ok(())
+            }
+            FiberChannelMessage::TxAbort(tx_abort) => {
+                warn!(
+                    "Funding transaction collaboration aborted by peer: {}",
+                    String::from_utf8_lossy(&tx_abort.message)
+                );
+                state.abort_funding_rbf();
+                state.abort_splice();
+                Ok(())
+            }
+            FiberChannelMessage::SpliceInit(splice_init) => {
+                // Both sides may try to splice the channel at the same time,
+                // the acceptor gives up its own proposal in that case.
+                if state
+                    .splice
+                    .as_ref()
+                    .is_some_and(|splice| splice.is_initiator && splice.remote_nonce.is_none())
+                {
+                    if !state.is_acceptor {
+                        debug!(
+                            "Ignoring SpliceInit {:?} while waiting for the SpliceAck of our own",
+                            &splice_init
+                        );
+                        return Ok(());
+                    }
+                    state.abort_splice();
+                }
+                if let Err(err) = state.check_splice(
+                    splice_init.funding_amount,
+                    splice_init.funding_fee_rate,
+                    false,
+                ) {
+                    self.network
+                        .send_message(NetworkActorMessage::new_command(
+                            NetworkActorCommand::SendFiberMessage(FiberMessageWithPeerId::new(
+                                state.get_remote_peer_id(),
+                                FiberMessage::tx_abort(TxAbort {
+                                    channel_id: state.get_id(),
+                                    message: err.to_string().into_bytes(),
+                                }),
+                            )),
+                        ))
+                        .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+                    return Err(err);
+                }
+                state.start_splice(
+                    false,
+                    splice_init.funding_amount,
+                    splice_init.funding_fee_rate,
+                );
+                state.update_splice_remote_nonces(
+                    splice_init.funding_nonce,
+                    splice_init.local_nonce,
+                    splice_init.channel_announcement_nonce,
+                );
+                let spliced = state.get_spliced_state();
+                self.network
+                    .send_message(NetworkActorMessage::new_command(
+                        NetworkActorCommand::SendFiberMessage(FiberMessageWithPeerId::new(
+                            state.get_remote_peer_id(),
+                            FiberMessage::splice_ack(SpliceAck {
+                                channel_id: state.get_id(),
+                                funding_nonce: state.get_splice_funding_secnonce().public_nonce(),
+                                local_nonce: spliced.get_local_nonce(),
+                                channel_announcement_nonce: spliced
+                                    .get_channel_announcement_musig2_pubnonce(),
+                            }),
+                        )),
+                    ))
+                    .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+                Ok(())
+            }
+            FiberChannelMessage::SpliceAck(splice_ack) => {
+                if !state
+                    .splice
+                    .as_ref()
+                    .is_some_and(|splice| splice.is_initiator && splice.remote_nonce.is_none())
+                {
+                    return Err(ProcessingChannelError::InvalidState(
+                        "Received SpliceAck without sending SpliceInit".to_string(),
+                    ));
+                }
+                state.update_splice_remote_nonces(
+                    splice_ack.funding_nonce,
+                    splice_ack.local_nonce,
+                    splice_ack.channel_announcement_nonce,
+                );
+                self.network
+                    .send_message(NetworkActorMessage::new_command(
+                        NetworkActorCommand::UpdateChannelFunding(
+                            state.get_id(),
+                            Default::default(),
+                            state.get_splice_funding_request(),
+                        ),
+                    ))
+                    .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+                Ok(())
+            }
+            FiberChannelMessage::SpliceCommitmentSigned(commitment_signed) => {
+                state.handle_splice_commitment_signed_message(commitment_signed, &self.network)
+            }
+            FiberChannelMessage::SpliceRevokeAndAck(revoke_and_ack) => {
+                let splice = state
+                    .splice
+                    .as_mut()
+                    .ok_or(ProcessingChannelError::InvalidState(
+                        "Received SpliceRevokeAndAck without a pending splice".to_string(),
+                    ))?;
+                // Processed together with the following RevokeAndAck message.
+                splice.remote_revoke_and_ack = Some(revoke_and_ack.partial_signature);
+                Ok(())
+            }
+            FiberChannelMessage::SpliceLocked(splice_locked) => {
+                state.handle_splice_locked_message(splice_locked)?;
+                state.maybe_complete_splice(&self.network).await;
                 Ok(())
             }
         }
     }
 
+    pub fn handle_bump_funding_fee_command(
+        &self,
+        state: &mut ChannelActorState,
+        command: BumpFundingFeeCommand,
+    ) -> ProcessingChannelResult {
+        state.check_funding_rbf(command.funding_fee_rate)?;
+        if state.pending_funding_fee_rate.is_some() {
+            return Err(ProcessingChannelError::InvalidState(
+                "A funding transaction replacement is already in progress".to_string(),
+            ));
+        }
+        state.pending_funding_fee_rate = Some(command.funding_fee_rate);
+        self.network
+            .send_message(NetworkActorMessage::new_command(
+                NetworkActorCommand::SendFiberMessage(FiberMessageWithPeerId::new(
+                    state.get_remote_peer_id(),
+                    FiberMessage::tx_init_rbf(TxInitRBF {
+                        channel_id: state.get_id(),
+                        fee_rate: command.funding_fee_rate,
+                    }),
+                )),
+            ))
+            .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+        Ok(())
+    }
+
+    pub fn handle_splice_command(
+        &self,
+        state: &mut ChannelActorState,
+        command: SpliceCommand,
+    ) -> ProcessingChannelResult {
+        state.check_splice(command.funding_amount, command.funding_fee_rate, true)?;
+        state.start_splice(true, command.funding_amount, command.funding_fee_rate);
+        let spliced = state.get_spliced_state();
+        self.network
+            .send_message(NetworkActorMessage::new_command(
+                NetworkActorCommand::SendFiberMessage(FiberMessageWithPeerId::new(
+                    state.get_remote_peer_id(),
+                    FiberMessage::splice_init(SpliceInit {
+                        channel_id: state.get_id(),
+                        funding_amount: command.funding_amount,
+                        funding_fee_rate: command.funding_fee_rate,
+                        funding_nonce: state.get_splice_funding_secnonce().public_nonce(),
+                        local_nonce: spliced.get_local_nonce(),
+                        channel_announcement_nonce: spliced
+                            .get_channel_announcement_musig2_pubnonce(),
+                    }),
+                )),
+            ))
+            .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+        Ok(())
+    }
+
     fn try_to_settle_down_tlc(&self, state: &mut ChannelActorState) {
         let tlcs = state.get_tlcs_for_settle_down();
         info!("try_to_settle_down_tlc get tlcs: {:?}", &tlcs);
         for tlc_info in tlcs {
             let tlc = tlc_info.tlc.clone();
-            let preimage = if let Some(preimage) = tlc.payment_preimage {
-                preimage
-            } else if let Some(preimage) = self.store.get_invoice_preimage(&tlc.payment_hash) {
-                preimage
-            } else {
+            if tlc.is_last_hop {
+                if let Err(tlc_err) = self.check_invoice_for_received_tlc(&tlc) {
+                    let command = RemoveTlcCommand {
+                        id: tlc.get_id(),
+                        reason: RemoveTlcReason::RemoveTlcFail(RemoveTlcFail::new_plaintext(
+                            tlc_err,
+                        )),
+                    };
+                    let result = self.handle_remove_tlc_command(state, command);
+                    info!("reject tlc: {:?} result: {:?}", &tlc, &result);
+                    break;
+                }
+            }
+            let preimage = tlc
+                .payment_preimage
+                .or_else(|| self.store.get_invoice_preimage(&tlc.payment_hash));
+            // The preimage of a hold invoice is unknown until the invoice is settled, the tlc
+            // is held by the network actor until the invoice is settled or cancelled.
+            let is_held = preimage.is_none()
+                && tlc.is_last_hop
+                && self.store.get_invoice(&tlc.payment_hash).is_some();
+            if tlc.total_amount.is_some() || is_held {
+                // A part of a multi-part payment, the network actor settles all the parts
+                // together after their sum reaches the total amount.
+                let total_amount = tlc.total_amount.unwrap_or(tlc.amount);
+                self.network
+                    .send_message(NetworkActorMessage::new_event(
+                        NetworkActorEvent::MppTlcReceived(ReceivedMppTlc {
+                            payment_hash: tlc.payment_hash,
+                            channel_id: state.get_id(),
+                            tlc_id: tlc.get_id().into(),
+                            amount: tlc.amount,
+                            total_amount,
+                            preimage,
+                            expiry: tlc.lock_time.into(),
+                        }),
+                    ))
+                    .expect(ASSUME_NETWORK_ACTOR_ALIVE);
                 continue;
+            }
+            let reason = match preimage {
+                Some(preimage) => RemoveTlcReason::RemoveTlcFulfill(RemoveTlcFulfill {
+                    payment_preimage: preimage,
+                }),
+                // The payment hash is unknown to us and nothing else claims it, e.g. this is
+                // a probe, tell the sender that the payment reached the target. Otherwise the
+                // subscriber settles the tlc later, e.g. the cross-chain hub.
+                None if tlc.is_last_hop && !self.subscribers.may_claim_received_tlc(state) => {
+                    RemoveTlcReason::RemoveTlcFail(RemoveTlcFail::new_plaintext(TlcErr::new(
+                        TlcErr::INCORRECT_OR_UNKNOWN_PAYMENT_DETAILS,
+                    )))
+                }
+                None => continue,
             };
+            let is_fulfill = matches!(reason, RemoveTlcReason::RemoveTlcFulfill(_));
             let command = RemoveTlcCommand {
                 id: tlc.get_id(),
-                reason: RemoveTlcReason::RemoveTlcFulfill(RemoveTlcFulfill {
-                    payment_preimage: preimage,
-                }),
+                reason,
             };
             let result = self.handle_remove_tlc_command(state, command);
             info!("try to settle down tlc: {:?} result: {:?}", &tlc, &result);
+            if result.is_ok()
+                && is_fulfill
+                && tlc.is_last_hop
+                // The invoice may not exist, e.g. the preimage is carried in the onion packet.
+                && self
+                    .store
+                    .update_invoice_status(&tlc.payment_hash, CkbInvoiceStatus::Paid)
+                    .is_ok()
+            {
+                self.subscribers
+                    .node_events
+                    .notify_invoice(tlc.payment_hash, CkbInvoiceStatus::Paid);
+            }
             // we only handle one tlc at a time.
             break;
         }
     }
 
+    // Check the invoice paid by a tlc which is received as the last hop, the tlc should be
+    // rejected if the invoice is expired, cancelled or underpaid. The invoice is marked as
+    // received if the tlc is acceptable.
+    fn check_invoice_for_received_tlc(&self, tlc: &TLC) -> Result<(), TlcErr> {
+        let Some(invoice) = self.store.get_invoice(&tlc.payment_hash) else {
+            return Ok(());
+        };
+        let status = get_invoice_status_with_expiry(&self.store, &tlc.payment_hash);
+        if matches!(
+            status,
+            Some(CkbInvoiceStatus::Cancelled | CkbInvoiceStatus::Expired)
+        ) {
+            warn!(
+                "Rejecting tlc {:?} for invoice in status {:?}",
+                tlc.get_id(),
+                status
+            );
+            return Err(TlcErr::new(TlcErr::INCORRECT_OR_UNKNOWN_PAYMENT_DETAILS));
+        }
+        let paid_amount = tlc.total_amount.unwrap_or(tlc.amount);
+        if invoice.amount().is_some_and(|amount| paid_amount < amount) {
+            warn!(
+                "Rejecting tlc {:?} paying {} for invoice of amount {:?}",
+                tlc.get_id(),
+                paid_amount,
+                invoice.amount()
+            );
+            return Err(TlcErr::new(TlcErr::INCORRECT_OR_UNKNOWN_PAYMENT_DETAILS));
+        }
+        if status == Some(CkbInvoiceStatus::Open) {
+            self.store
+                .update_invoice_status(&tlc.payment_hash, CkbInvoiceStatus::Received)
+                .expect("invoice exists");
+            self.subscribers
+                .node_events
+                .notify_invoice(tlc.payment_hash, CkbInvoiceStatus::Received);
+        }
+        Ok(())
+    }
+
     pub fn handle_commitment_signed_command(
         &self,
         state: &mut ChannelActorState,
@@ -747,6 +1221,16 @@ where
             }
         };
 
+        // The commitment transaction spending the spliced funding cell is signed along
+        // with the current one until the splice is confirmed.
+        if state
+            .splice
+            .as_ref()
+            .is_some_and(|splice| splice.flags.contains(SpliceFlags::COMMITMENT_SIGNED_SENT))
+        {
+            state.send_splice_commitment_signed(&self.network)?;
+        }
+
         debug!(
             "Building and signing commitment tx for state {:?}",
             &state.state
@@ -853,13 +1337,23 @@ where
         command: RemoveTlcCommand,
     ) -> ProcessingChannelResult {
         state.check_for_tlc_update(None)?;
-        let tlc = state.remove_tlc_with_reason(TLCId::Received(command.id), command.reason)?;
+        let reason = match command.reason {
+            RemoveTlcReason::RemoveTlcFail(error_packet) => {
+                let shared_secret = state
+                    .get_received_tlc(command.id)
+                    .map(|tlc| tlc.tlc.shared_secret)
+                    .unwrap_or(NO_SHARED_SECRET);
+                RemoveTlcReason::RemoveTlcFail(error_packet.backward(&shared_secret))
+            }
+            reason => reason,
+        };
+        let tlc = state.remove_tlc_with_reason(TLCId::Received(command.id), reason.clone())?;
         let msg = FiberMessageWithPeerId::new(
             state.get_remote_peer_id(),
             FiberMessage::remove_tlc(RemoveTlc {
                 channel_id: state.get_id(),
                 tlc_id: command.id,
-                reason: command.reason,
+                reason,
             }),
         );
         self.network
@@ -914,24 +1408,7 @@ where
         state.check_shutdown_fee_rate(command.fee_rate, &command.close_script)?;
 
         if command.force {
-            if let Some(transaction) = &state.latest_commitment_transaction {
-                self.network
-                    .send_message(NetworkActorMessage::new_event(
-                        NetworkActorEvent::CommitmentTransactionPending(
-                            transaction.clone(),
-                            state.get_id(),
-                        ),
-                    ))
-                    .expect(ASSUME_NETWORK_ACTOR_ALIVE);
-
-                state.update_state(ChannelState::ShuttingDown(
-                    ShuttingDownFlags::WAITING_COMMITMENT_CONFIRMATION,
-                ));
-            } else {
-                return Err(ProcessingChannelError::InvalidState(
-                    "Force shutdown without a valid commitment transaction".to_string(),
-                ));
-            }
+            self.force_close(state)?;
         } else {
             self.network
                 .send_message(NetworkActorMessage::new_command(
@@ -966,6 +1443,34 @@ where
         Ok(())
     }
 
+    // Broadcast our latest commitment transaction to close the channel unilaterally.
+    fn force_close(&self, state: &mut ChannelActorState) -> ProcessingChannelResult {
+        if state.data_loss_detected {
+            return Err(ProcessingChannelError::InvalidState(
+                "Refusing to broadcast the stale commitment transaction after losing channel states"
+                    .to_string(),
+            ));
+        }
+        let Some(transaction) = &state.latest_commitment_transaction else {
+            return Err(ProcessingChannelError::InvalidState(
+                "Force shutdown without a valid commitment transaction".to_string(),
+            ));
+        };
+        self.network
+            .send_message(NetworkActorMessage::new_event(
+                NetworkActorEvent::CommitmentTransactionPending(
+                    transaction.clone(),
+                    state.get_id(),
+                ),
+            ))
+            .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+
+        state.update_state(ChannelState::ShuttingDown(
+            ShuttingDownFlags::WAITING_COMMITMENT_CONFIRMATION,
+        ));
+        Ok(())
+    }
+
     pub async fn handle_update_command(
         &self,
         state: &mut ChannelActorState,
@@ -1017,6 +1522,16 @@ where
         command: TxCollaborationCommand,
     ) -> Result<(), ProcessingChannelError> {
         debug!("Handling tx collaboration command: {:?}", &command);
+        if state.splice.is_some() {
+            return match command {
+                TxCollaborationCommand::TxUpdate(tx_update) => {
+                    state.handle_splice_tx_update_command(tx_update.transaction, &self.network)
+                }
+                TxCollaborationCommand::TxComplete() => Err(ProcessingChannelError::InvalidState(
+                    "The splice transaction is completed by the initiator alone".to_string(),
+                )),
+            };
+        }
         let is_complete_command = matches!(command, TxCollaborationCommand::TxComplete());
         let is_waiting_for_remote = match state.state {
             ChannelState::CollaboratingFundingTx(flags) => {
@@ -1172,6 +1687,32 @@ where
                     }
                 }
             }
+            ChannelCommand::BumpFundingFee(command, reply) => {
+                match self.handle_bump_funding_fee_command(state, command) {
+                    Ok(_) => {
+                        let _ = reply.send(Ok(()));
+                        Ok(())
+                    }
+                    Err(err) => {
+                        debug!("Error processing bump funding fee command: {:?}", &err);
+                        let _ = reply.send(Err(err.to_string()));
+                        Err(err)
+                    }
+                }
+            }
+            ChannelCommand::Splice(command, reply) => {
+                match self.handle_splice_command(state, command) {
+                    Ok(_) => {
+                        let _ = reply.send(Ok(()));
+                        Ok(())
+                    }
+                    Err(err) => {
+                        debug!("Error processing splice command: {:?}", &err);
+                        let _ = reply.send(Err(err.to_string()));
+                        Err(err)
+                    }
+                }
+            }
         }
     }
 
@@ -1182,8 +1723,13 @@ where
         event: ChannelEvent,
     ) -> Result<(), ProcessingChannelError> {
         match event {
-            ChannelEvent::FundingTransactionConfirmed => {
-                debug!("Funding transaction confirmed");
+            ChannelEvent::FundingTransactionConfirmed(outpoint) => {
+                debug!("Funding transaction confirmed: {:?}", &outpoint);
+                if state.on_splice_confirmed(&outpoint, &self.network) {
+                    state.maybe_complete_splice(&self.network).await;
+                    return Ok(());
+                }
+                state.on_funding_candidate_confirmed(&outpoint);
                 let flags = match state.state {
                     ChannelState::AwaitingChannelReady(flags) => flags,
                     ChannelState::AwaitingTxSignatures(f)
@@ -1225,6 +1771,20 @@ where
                 };
                 state.update_state(ChannelState::Closed(CloseFlags::UNCOOPERATIVE));
                 debug!("Channel closed with uncooperative close");
+                match self.get_sweep_output(state) {
+                    Some(sweep_output) => emit_service_event(
+                        &self.network,
+                        NetworkServiceEvent::CommitmentTransactionConfirmed(
+                            self.get_remote_peer_id(),
+                            state.get_id(),
+                            sweep_output,
+                        ),
+                    ),
+                    None => warn!(
+                        "No settlement data for the commitment transaction of channel {:?}, unable to sweep it",
+                        state.get_id()
+                    ),
+                }
             }
             ChannelEvent::PeerDisconnected => {
                 myself.stop(Some("PeerDisconnected".to_string()));
@@ -1544,11 +2104,21 @@ where
                     .get_channel_actor_state(&channel_id)
                     .expect("channel should exist");
                 channel.reestablishing = true;
+                // The splice negotiation can't be resumed after reconnecting.
+                channel.abort_splice();
 
                 let reestablish_channel = ReestablishChannel {
                     channel_id,
                     local_commitment_number: channel.get_current_commitment_number(true),
                     remote_commitment_number: channel.get_current_commitment_number(false),
+                    // Dropped by the network actor if the peer doesn't support DataLossProtect.
+                    data_loss_protect: Some(DataLossProtect {
+                        remote_commitment_secret: channel.get_last_remote_commitment_secret(),
+                        remote_commitment_point: channel
+                            .remote_commitment_points
+                            .get(channel.get_local_commitment_number() as usize)
+                            .copied(),
+                    }),
                 };
 
                 let command = FiberMessageWithPeerId::new(
@@ -1586,6 +2156,7 @@ where
         message: Self::Msg,
         state: &mut Self::State,
     ) -> Result<(), ActorProcessingErr> {
+        let previous_summary = state.get_summary();
         match message {
             ChannelActorMessage::PeerMessage(message) => {
                 if let Err(error) = self.handle_peer_message(state, message).await {
@@ -1605,6 +2176,9 @@ where
         }
 
         self.store.insert_channel_actor_state(state.clone());
+        if state.get_summary() != previous_summary {
+            self.subscribers.node_events.notify_channel(state);
+        }
         Ok(())
     }
 }
@@ -1821,6 +2395,29 @@ pub struct ChannelActorState {
     // The latest commitment transaction we're holding
     #[serde_as(as = "Option<EntityHex>")]
     pub latest_commitment_transaction: Option<Transaction>,
+    // The data to settle the commitment cell of the latest commitment transaction,
+    // which is handed over to the sweeper after the channel is force closed.
+    pub latest_commitment_settlement: Option<SettlementData>,
+
+    // The fee rate we proposed in TxInitRBF, and are waiting for the TxAckRBF from the peer.
+    pub pending_funding_fee_rate: Option<u64>,
+    // The funding transactions signed before the current one was replaced by fee.
+    // Any of them may be confirmed until one of them is confirmed.
+    #[serde(default)]
+    pub funding_tx_candidates: Vec<FundingTxCandidate>,
+    // The number of times the funding transaction has been replaced. The musig2 nonces
+    // for the commitment transactions are derived with this offset, as every replacement
+    // signs a new commitment transaction without advancing the commitment numbers.
+    // A confirmed splice moves this offset to the nonce domain of the splice.
+    #[serde(default)]
+    pub funding_rbf_rounds: u64,
+    // The number of splices started for this channel, used to derive the musig2 nonces
+    // for the commitment transactions of the spliced funding cell.
+    #[serde(default)]
+    pub splice_rounds: u64,
+    // The splice in progress, the commitment transactions spending the current funding
+    // cell and the spliced one are both signed until the splice transaction is confirmed.
+    pub splice: Option<SpliceInfo>,
 
     // All the commitment point that are sent from the counterparty.
     // We need to save all these points to derive the keys for the commitment transactions.
@@ -1833,13 +2430,66 @@ pub struct ChannelActorState {
 
     // A flag to indicate whether the channel is reestablishing, we won't process any messages until the channel is reestablished.
     pub reestablishing: bool,
+    // Set when the peer proves that our states are older than the ones it knows, e.g. we are
+    // restored from a backup. Our commitment transaction may be revoked and must not be broadcasted.
+    #[serde(default)]
+    pub data_loss_detected: bool,
+    // The last commitment secret revealed by the peer and its commitment number, which is sent
+    // back to the peer on reestablishing the channel to prove that the peer has lost some states.
+    #[serde(default)]
+    pub remote_commitment_secret: Option<(u64, Hash256)>,
 
     pub created_at: SystemTime,
 }
 
 #[serde_as]
-#[derive(Clone, Serialize, Deserialize)]
-pub struct ShutdownInfo {
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FundingTxCandidate {
+    #[serde_as(as = "EntityHex")]
+    pub funding_tx: Transaction,
+    #[serde_as(as = "Option<EntityHex>")]
+    pub commitment_tx: Option<Transaction>,
+    pub funding_fee_rate: u64,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SpliceInfo {
+    // Whether we sent the SpliceInit message, the initiator is the only party who
+    // provides or receives the spliced funds.
+    pub is_initiator: bool,
+    // The changes of `to_local_amount` and `to_remote_amount` after the splice. Deltas
+    // are saved instead of the amounts, as the tlcs resolved while the splice is pending
+    // are applied to the commitment transactions of both funding cells.
+    pub local_amount_delta: i128,
+    pub remote_amount_delta: i128,
+    pub funding_fee_rate: u64,
+    // The offset of the musig2 nonces for the commitment transactions spending
+    // the spliced funding cell.
+    pub nonce_offset: u64,
+    #[serde_as(as = "Option<EntityHex>")]
+    pub funding_tx: Option<Transaction>,
+    #[serde_as(as = "Option<EntityHex>")]
+    pub latest_commitment_transaction: Option<Transaction>,
+    pub latest_commitment_settlement: Option<SettlementData>,
+    // The nonce of the remote party to sign the current funding cell in the splice transaction.
+    pub remote_funding_nonce: Option<PubNonce>,
+    pub previous_remote_nonce: Option<PubNonce>,
+    pub remote_nonce: Option<PubNonce>,
+    pub remote_channel_announcement_nonce: Option<PubNonce>,
+    // The SpliceCommitmentSigned and SpliceRevokeAndAck messages are always followed by
+    // CommitmentSigned and RevokeAndAck messages, and are processed together with them.
+    pub remote_commitment_signed: Option<SpliceCommitmentSigned>,
+    pub remote_revoke_and_ack: Option<PartialSignature>,
+    // The revocation data of the latest revoked commitment transaction spending the spliced
+    // funding cell, it's sent to the watchtowers after the splice is completed.
+    pub remote_revocation: Option<RevocationData>,
+    pub flags: SpliceFlags,
+}
+
+#[serde_as]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ShutdownInfo {
     #[serde_as(as = "EntityHex")]
     pub close_script: Script,
     pub fee_rate: u64,
@@ -1898,7 +2548,7 @@ pub struct ClosedChannel {}
 #[derive(Debug)]
 pub enum ChannelEvent {
     PeerDisconnected,
-    FundingTransactionConfirmed,
+    FundingTransactionConfirmed(OutPoint),
     CommitmentTransactionConfirmed,
     ClosingTransactionConfirmed,
 }
@@ -1930,6 +2580,22 @@ bitflags! {
         const PUBLIC = 1;
     }
 
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(transparent)]
+    pub struct SpliceFlags: u32 {
+        const OUR_TX_COMPLETE_SENT = 1;
+        const THEIR_TX_COMPLETE_SENT = 1 << 1;
+        const TX_COMPLETE_SENT = SpliceFlags::OUR_TX_COMPLETE_SENT.bits() | SpliceFlags::THEIR_TX_COMPLETE_SENT.bits();
+        const OUR_COMMITMENT_SIGNED_SENT = 1 << 2;
+        const THEIR_COMMITMENT_SIGNED_SENT = 1 << 3;
+        const COMMITMENT_SIGNED_SENT = SpliceFlags::OUR_COMMITMENT_SIGNED_SENT.bits() | SpliceFlags::THEIR_COMMITMENT_SIGNED_SENT.bits();
+        // We have given our signature of the current funding cell to the remote party.
+        const TX_SIGNED = 1 << 4;
+        const OUR_SPLICE_LOCKED = 1 << 5;
+        const THEIR_SPLICE_LOCKED = 1 << 6;
+        const SPLICE_LOCKED = SpliceFlags::OUR_SPLICE_LOCKED.bits() | SpliceFlags::THEIR_SPLICE_LOCKED.bits();
+    }
+
     #[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(transparent)]
     pub struct NegotiatingFundingFlags: u32 {
@@ -2043,6 +2709,14 @@ impl ChannelState {
     }
 }
 
+// The change of a balance from `current_amount` to `funding_amount` in a splice,
+// None if it can't be represented.
+fn get_splice_amount_delta(funding_amount: u128, current_amount: u128) -> Option<i128> {
+    i128::try_from(funding_amount)
+        .ok()?
+        .checked_sub(i128::try_from(current_amount).ok()?)
+}
+
 pub fn new_channel_id_from_seed(seed: &[u8]) -> Hash256 {
     blake2b_256(seed).into()
 }
@@ -2309,10 +2983,18 @@ impl ChannelActorState {
             local_reserved_ckb_amount,
             remote_reserved_ckb_amount,
             latest_commitment_transaction: None,
+            latest_commitment_settlement: None,
+            pending_funding_fee_rate: None,
+            funding_tx_candidates: vec![],
+            funding_rbf_rounds: 0,
+            splice_rounds: 0,
+            splice: None,
             max_tlc_value_in_flight,
             max_num_of_accept_tlcs,
 
             reestablishing: false,
+            data_loss_detected: false,
+            remote_commitment_secret: None,
             created_at: SystemTime::now(),
         };
         if let Some(nonce) = remote_channel_announcement_nonce {
@@ -2375,8 +3057,16 @@ impl ChannelActorState {
             local_reserved_ckb_amount,
             remote_reserved_ckb_amount: 0,
             latest_commitment_transaction: None,
+            latest_commitment_settlement: None,
+            pending_funding_fee_rate: None,
+            funding_tx_candidates: vec![],
+            funding_rbf_rounds: 0,
+            splice_rounds: 0,
+            splice: None,
 
             reestablishing: false,
+            data_loss_detected: false,
+            remote_commitment_secret: None,
             created_at: SystemTime::now(),
         }
     }
@@ -2492,6 +3182,17 @@ impl ChannelActorState {
         Ok(())
     }
 
+    // The state and balances of the channel, the subscribers are notified when they change.
+    fn get_summary(&self) -> (ChannelState, u128, u128, u128, u128) {
+        (
+            self.state,
+            self.get_local_balance(),
+            self.get_remote_balance(),
+            self.get_offered_tlc_balance(),
+            self.get_received_tlc_balance(),
+        )
+    }
+
     pub fn get_local_balance(&self) -> u128 {
         self.to_local_amount
     }
@@ -2535,6 +3236,18 @@ impl ChannelActorState {
         self.local_pubkey < self.remote_pubkey
     }
 
+    // The capacity of the channel announced to the network, i.e. the capacity of the funding
+    // cell for ckb channels and the amount of udt in the funding cell for udt channels.
+    pub fn get_announced_capacity(&self) -> u128 {
+        if self.funding_udt_type_script.is_some() {
+            self.to_local_amount + self.to_remote_amount
+        } else {
+            self.to_local_amount
+                + self.to_remote_amount
+                + self.local_reserved_ckb_amount as u128
+                + self.remote_reserved_ckb_amount as u128
+        }
+    }
     fn get_or_create_channel_announcement_message(&mut self) -> ChannelAnnouncement {
         match self
             .public_channel_info
@@ -2546,14 +3259,7 @@ impl ChannelActorState {
         };
 
         let channel_outpoint = self.get_funding_transaction_outpoint();
-        let capacity = if self.funding_udt_type_script.is_some() {
-            self.to_local_amount + self.to_remote_amount
-        } else {
-            self.to_local_amount
-                + self.to_remote_amount
-                + self.local_reserved_ckb_amount as u128
-                + self.remote_reserved_ckb_amount as u128
-        };
+        let capacity = self.get_announced_capacity();
 
         let (node1_id, node2_id) = if self.local_is_node1() {
             (self.local_pubkey, self.remote_pubkey)
@@ -2806,6 +3512,66 @@ impl ChannelActorState {
     // Send RevokeAndAck message to the counterparty, and update the
     // channel state accordingly.
     fn send_revoke_and_ack_message(&mut self, network: &ActorRef<NetworkActorMessage>) {
+        // The commitment transaction spending the spliced funding cell is revoked
+        // along with the current one until the splice is confirmed.
+        if self
+            .splice
+            .as_ref()
+            .is_some_and(|splice| splice.flags.contains(SpliceFlags::COMMITMENT_SIGNED_SENT))
+        {
+            let partial_signature = self.get_spliced_state().sign_revoke_and_ack();
+            network
+                .send_message(NetworkActorMessage::new_command(
+                    NetworkActorCommand::SendFiberMessage(FiberMessageWithPeerId::new(
+                        self.get_remote_peer_id(),
+                        FiberMessage::splice_revoke_and_ack(SpliceRevokeAndAck {
+                            channel_id: self.get_id(),
+                            partial_signature,
+                        }),
+                    )),
+                ))
+                .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+        }
+        let signature = self.sign_revoke_and_ack();
+
+        // Note that we must update channel state here to update commitment number,
+        // so that next step will obtain the correct commitmen point.
+        self.update_state_on_raa_msg(false);
+        let point = self.get_current_local_commitment_point();
+
+        // Reveal the secret of the revoked commitment point, so that the peer can prove
+        // our data loss if we reestablish the channel with older states.
+        let revoked_commitment_number = self.get_remote_commitment_number() - 1;
+        network
+            .send_message(NetworkActorMessage::new_command(
+                NetworkActorCommand::SendFiberMessage(FiberMessageWithPeerId::new(
+                    self.get_remote_peer_id(),
+                    FiberMessage::commitment_secret(CommitmentSecret {
+                        channel_id: self.get_id(),
+                        commitment_number: revoked_commitment_number,
+                        secret: self
+                            .signer
+                            .get_commitment_secret(revoked_commitment_number)
+                            .into(),
+                    }),
+                )),
+            ))
+            .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+        network
+            .send_message(NetworkActorMessage::new_command(
+                NetworkActorCommand::SendFiberMessage(FiberMessageWithPeerId::new(
+                    self.get_remote_peer_id(),
+                    FiberMessage::revoke_and_ack(RevokeAndAck {
+                        channel_id: self.get_id(),
+                        partial_signature: signature,
+                        next_per_commitment_point: point,
+                    }),
+                )),
+            ))
+            .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+    }
+
+    fn sign_revoke_and_ack(&self) -> PartialSignature {
         let commitment_tx_fee =
             calculate_commitment_tx_fee(self.commitment_fee_rate, &self.funding_udt_type_script);
         let lock_script = self.get_remote_shutdown_script();
@@ -2865,25 +3631,7 @@ impl ChannelActorState {
             seckey: self.signer.funding_key.clone(),
             secnonce: self.get_local_musig2_secnonce(),
         };
-        let signature = sign_ctx.sign(message.as_slice()).expect("valid signature");
-
-        // Note that we must update channel state here to update commitment number,
-        // so that next step will obtain the correct commitmen point.
-        self.update_state_on_raa_msg(false);
-        let point = self.get_current_local_commitment_point();
-
-        network
-            .send_message(NetworkActorMessage::new_command(
-                NetworkActorCommand::SendFiberMessage(FiberMessageWithPeerId::new(
-                    self.get_remote_peer_id(),
-                    FiberMessage::revoke_and_ack(RevokeAndAck {
-                        channel_id: self.get_id(),
-                        partial_signature: signature,
-                        next_per_commitment_point: point,
-                    }),
-                )),
-            ))
-            .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+        sign_ctx.sign(message.as_slice()).expect("valid signature")
     }
 
     fn get_tlcs_for_settle_down(&self) -> Vec<DetailedTLCInfo> {
@@ -2933,7 +3681,7 @@ impl ChannelActorState {
                 );
                 tlc.creation_confirmed_at = Some(commitment_numbers);
             }
-            match (tlc.removed_at, tlc.removal_confirmed_at) {
+            match (tlc.removed_at.clone(), tlc.removal_confirmed_at) {
                 (Some((_removed_at, reason)), None) => {
                     tlc.removal_confirmed_at = Some(commitment_numbers);
                      match reason {
@@ -2987,9 +3735,13 @@ impl ChannelActorState {
         self.remote_pubkey.tentacle_peer_id()
     }
 
-    pub fn get_local_secnonce(&self) -> SecNonce {
+    fn derive_local_musig2_nonce(&self, commitment_number: u64) -> SecNonce {
         self.signer
-            .derive_musig2_nonce(self.get_local_commitment_number())
+            .derive_musig2_nonce(commitment_number + self.funding_rbf_rounds)
+    }
+
+    pub fn get_local_secnonce(&self) -> SecNonce {
+        self.derive_local_musig2_nonce(self.get_local_commitment_number())
     }
 
     pub fn get_local_nonce(&self) -> PubNonce {
@@ -2997,8 +3749,7 @@ impl ChannelActorState {
     }
 
     pub fn get_next_local_secnonce(&self) -> SecNonce {
-        self.signer
-            .derive_musig2_nonce(self.get_next_commitment_number(true))
+        self.derive_local_musig2_nonce(self.get_next_commitment_number(true))
     }
 
     pub fn get_next_local_nonce(&self) -> PubNonce {
@@ -3108,6 +3859,7 @@ impl ChannelActorState {
                 tlc.id
             )));
         }
+        let (local_balance, remote_balance) = self.get_spendable_balances();
         if tlc.is_offered() {
             // TODO: We should actually also consider all our fulfilled tlcs here.
             // Because this is also the amount that we can actually spend.
@@ -3115,12 +3867,12 @@ impl ChannelActorState {
             debug!("Value of local sent tlcs: {}", sent_tlc_value);
             debug_assert!(self.to_local_amount >= sent_tlc_value);
             // TODO: handle transaction fee here.
-            if sent_tlc_value + tlc.amount > self.to_local_amount {
+            if sent_tlc_value + tlc.amount > local_balance {
                 return Err(ProcessingChannelError::InvalidParameter(format!(
                     "Adding tlc {:?} with amount {} exceeds local balance {}",
                     tlc.id,
                     tlc.amount,
-                    self.to_local_amount - sent_tlc_value
+                    local_balance.saturating_sub(sent_tlc_value)
                 )));
             }
         } else {
@@ -3130,12 +3882,12 @@ impl ChannelActorState {
             debug!("Value of remote received tlcs: {}", received_tlc_value);
             debug_assert!(self.to_remote_amount >= received_tlc_value);
             // TODO: handle transaction fee here.
-            if received_tlc_value + tlc.amount > self.to_remote_amount {
+            if received_tlc_value + tlc.amount > remote_balance {
                 return Err(ProcessingChannelError::InvalidParameter(format!(
                     "Adding tlc {:?} with amount {} exceeds remote balance {}",
                     tlc.id,
                     tlc.amount,
-                    self.to_remote_amount - received_tlc_value
+                    remote_balance.saturating_sub(received_tlc_value)
                 )));
             }
         }
@@ -3180,9 +3932,10 @@ impl ChannelActorState {
                 )))
             }
             Some(current) => {
-                match current.removed_at {
+                match &current.removed_at {
                     Some((current_removed_at, current_remove_reason))
-                        if current_remove_reason == reason && removed_at == current_removed_at =>
+                        if current_remove_reason == &reason
+                            && &removed_at == current_removed_at =>
                     {
                         debug!(
                             "Skipping removing of tlc {:?} as it is already removed at {:?} with the same reason {:?}", tlc_id, removed_at, reason
@@ -3199,7 +3952,7 @@ impl ChannelActorState {
                             "Inserting remove reason {:?} at commitment number {:?} for tlc {:?} hash_algorithm: {:?}",
                             reason, removed_at, current, current.tlc.hash_algorithm
                         );
-                        if let RemoveTlcReason::RemoveTlcFulfill(fulfill) = reason {
+                        if let RemoveTlcReason::RemoveTlcFulfill(fulfill) = &reason {
                             let filled_payment_hash: Hash256 = current
                                 .tlc
                                 .hash_algorithm
@@ -3286,6 +4039,19 @@ impl ChannelActorState {
         self.get_local_commitment_point(self.get_remote_commitment_number())
     }
 
+    // The secret of the remote commitment point revoked by the last RevokeAndAck we received,
+    // zero if the peer didn't reveal it.
+    fn get_last_remote_commitment_secret(&self) -> Hash256 {
+        match self.remote_commitment_secret {
+            Some((commitment_number, secret))
+                if commitment_number + 1 == self.get_local_commitment_number() =>
+            {
+                secret
+            }
+            _ => Hash256::default(),
+        }
+    }
+
     pub fn get_funding_lock_script_xonly_key(&self) -> XOnlyPublicKey {
         let pubkey: secp256k1::PublicKey = self.get_musig2_agg_context().aggregated_pubkey();
         pubkey.into()
@@ -3312,6 +4078,33 @@ impl ChannelActorState {
             remote_amount: self.to_remote_amount as u64,
             local_reserved_ckb_amount: self.local_reserved_ckb_amount,
             remote_reserved_ckb_amount: self.remote_reserved_ckb_amount,
+            splice_input: None,
+        }
+    }
+
+    // The funding request of the splice transaction, which spends the current funding cell
+    // and creates a new one with the balances after the splice.
+    pub fn get_splice_funding_request(&self) -> FundingRequest {
+        let funding_tx = self.get_funding_transaction();
+        let capacity: u64 = funding_tx
+            .raw()
+            .outputs()
+            .get(0)
+            .expect("funding cell exists")
+            .capacity()
+            .unpack();
+        let udt_amount = if self.funding_udt_type_script.is_some() {
+            self.to_local_amount + self.to_remote_amount
+        } else {
+            0
+        };
+        FundingRequest {
+            splice_input: Some(SpliceInput {
+                out_point: self.get_funding_transaction_outpoint(),
+                capacity,
+                udt_amount,
+            }),
+            ..self.get_spliced_state().get_funding_request()
         }
     }
 
@@ -3327,10 +4120,14 @@ impl ChannelActorState {
     }
 
     pub fn get_channel_announcement_musig2_secnonce(&self) -> SecNonce {
-        let seckey = blake2b_hash_with_salt(
-            self.signer.musig2_base_nonce.as_ref(),
-            b"channel_announcement".as_slice(),
-        );
+        // The channel is announced again with the new funding cell after every splice,
+        // so the nonce must not be reused.
+        let splice_round = self.funding_rbf_rounds / SPLICE_NONCE_OFFSET;
+        let mut salt = b"channel_announcement".to_vec();
+        if splice_round > 0 {
+            salt.extend_from_slice(&splice_round.to_le_bytes());
+        }
+        let seckey = blake2b_hash_with_salt(self.signer.musig2_base_nonce.as_ref(), &salt);
         SecNonce::build(seckey).build()
     }
 
@@ -3340,8 +4137,7 @@ impl ChannelActorState {
     }
 
     pub fn get_local_musig2_secnonce(&self) -> SecNonce {
-        self.signer
-            .derive_musig2_nonce(self.get_local_commitment_number())
+        self.derive_local_musig2_nonce(self.get_local_commitment_number())
     }
 
     pub fn get_local_musig2_pubnonce(&self) -> PubNonce {
@@ -3484,50 +4280,83 @@ impl ChannelActorState {
         })
     }
 
-    fn get_active_htlcs(&self, local: bool) -> Vec<u8> {
-        // Build a sorted array of TLC so that both party can generate the same commitment transaction.
+    // Build a sorted array of TLC so that both party can generate the same commitment transaction.
+    // The tlcs are returned as we see them, the caller needs to flip them for the counterparty.
+    fn get_sorted_active_tlcs(&self, local: bool) -> Vec<(DetailedTLCInfo, Pubkey, Pubkey)> {
         debug!("All tlcs: {:?}", self.tlcs);
-        let tlcs = {
-            let (mut received_tlcs, mut offered_tlcs) = (
-                self.get_active_received_tlc_with_pubkeys(local)
-                    .map(|(tlc, local, remote)| (tlc.clone(), local, remote))
-                    .collect::<Vec<_>>(),
-                self.get_active_offered_tlc_with_pubkeys(local)
-                    .map(|(tlc, local, remote)| (tlc.clone(), local, remote))
-                    .collect::<Vec<_>>(),
-            );
-            debug!("Received tlcs: {:?}", &received_tlcs);
-            debug!("Offered tlcs: {:?}", &offered_tlcs);
-            let (mut a, mut b) = if local {
-                (received_tlcs, offered_tlcs)
-            } else {
-                for (tlc, _, _) in received_tlcs.iter_mut().chain(offered_tlcs.iter_mut()) {
-                    // Need to flip these fields for the counterparty.
-                    tlc.tlc.flip_mut();
-                }
-                (offered_tlcs, received_tlcs)
-            };
-            a.sort_by(|x, y| u64::from(x.0.tlc.id).cmp(&u64::from(y.0.tlc.id)));
-            b.sort_by(|x, y| u64::from(x.0.tlc.id).cmp(&u64::from(y.0.tlc.id)));
-            [a, b].concat()
+        let (received_tlcs, offered_tlcs) = (
+            self.get_active_received_tlc_with_pubkeys(local)
+                .map(|(tlc, local, remote)| (tlc.clone(), local, remote))
+                .collect::<Vec<_>>(),
+            self.get_active_offered_tlc_with_pubkeys(local)
+                .map(|(tlc, local, remote)| (tlc.clone(), local, remote))
+                .collect::<Vec<_>>(),
+        );
+        debug!("Received tlcs: {:?}", &received_tlcs);
+        debug!("Offered tlcs: {:?}", &offered_tlcs);
+        let (mut a, mut b) = if local {
+            (received_tlcs, offered_tlcs)
+        } else {
+            (offered_tlcs, received_tlcs)
         };
+        a.sort_by(|x, y| u64::from(x.0.tlc.id).cmp(&u64::from(y.0.tlc.id)));
+        b.sort_by(|x, y| u64::from(x.0.tlc.id).cmp(&u64::from(y.0.tlc.id)));
+        [a, b].concat()
+    }
+
+    fn get_active_htlcs(&self, local: bool) -> Vec<u8> {
+        let tlcs = self.get_sorted_active_tlcs(local);
         debug!("Sorted tlcs: {:?}", &tlcs);
         if tlcs.is_empty() {
             Vec::new()
         } else {
             let mut result = vec![tlcs.len() as u8];
-            for (tlc, local, remote) in tlcs {
-                result.extend_from_slice(&tlc.tlc.get_htlc_type().to_le_bytes());
-                result.extend_from_slice(&tlc.tlc.amount.to_le_bytes());
-                result.extend_from_slice(&tlc.tlc.get_hash());
-                result.extend_from_slice(&local.serialize());
-                result.extend_from_slice(&remote.serialize());
-                result.extend_from_slice(&Since::from(tlc.tlc.lock_time).value().to_le_bytes());
+            for (mut tlc, local_pubkey, remote_pubkey) in tlcs {
+                if !local {
+                    // Need to flip these fields for the counterparty.
+                    tlc.tlc.flip_mut();
+                }
+                result.extend(tlc.tlc.encode_htlc(&local_pubkey, &remote_pubkey));
             }
             result
         }
     }
 
+    // The tlcs in the commitment cell of our commitment transaction, which are claimed
+    // by the sweeper after the channel is force closed.
+    fn get_sweep_tlcs(&self) -> Vec<SweepTlc> {
+        self.get_sorted_active_tlcs(false)
+            .into_iter()
+            .map(|(mut tlc, local_pubkey, remote_pubkey)| {
+                let offered = tlc.is_offered();
+                let key_commitment_number = tlc.get_commitment_numbers(false).remote;
+                tlc.tlc.flip_mut();
+                SweepTlc {
+                    entry: tlc.tlc.encode_htlc(&local_pubkey, &remote_pubkey),
+                    payment_hash: tlc.tlc.payment_hash,
+                    amount: tlc.tlc.amount,
+                    offered,
+                    key_commitment_number,
+                    preimage: tlc.tlc.payment_preimage,
+                }
+            })
+            .collect()
+    }
+
+    // The balances that can be spent by new tlcs. The funds spliced out of the channel
+    // are excluded until the splice is confirmed, as they are not in the new funding cell.
+    fn get_spendable_balances(&self) -> (u128, u128) {
+        match self.splice {
+            Some(ref splice) => (
+                self.to_local_amount
+                    .saturating_sub(splice.local_amount_delta.min(0).unsigned_abs()),
+                self.to_remote_amount
+                    .saturating_sub(splice.remote_amount_delta.min(0).unsigned_abs()),
+            ),
+            None => (self.to_local_amount, self.to_remote_amount),
+        }
+    }
+
     fn any_tlc_pending(&self) -> bool {
         self.tlcs.values().any(|tlc| {
             tlc.creation_confirmed_at.is_none()
@@ -3577,6 +4406,15 @@ impl ChannelActorState {
                 )))
             }
         }
+        if self
+            .splice
+            .as_ref()
+            .is_some_and(|splice| !splice.flags.contains(SpliceFlags::COMMITMENT_SIGNED_SENT))
+        {
+            return Err(ProcessingChannelError::InvalidState(
+                "Unable to update tlcs while negotiating a splice".to_string(),
+            ));
+        }
 
         if let Some(add_amount) = add_tlc_amount {
             let active_tls_number = self.get_active_offered_tlcs(true).count()
@@ -3634,13 +4472,18 @@ impl ChannelActorState {
             previous_tlc: command
                 .previous_tlc
                 .map(|(channel_id, tlc_id)| (channel_id, TLCId::Received(tlc_id))),
+            shared_secret: NO_SHARED_SECRET,
+            total_amount: None,
+            is_last_hop: false,
         }
     }
 
+    // Creates a tlc from the AddTlc message, `peeled_packet` is the onion packet of the tlc
+    // peeled by us, it is None if the tlc is added without an onion packet.
     pub fn create_inbounding_tlc(
         &self,
         message: AddTlc,
-        payment_preimage: Option<Hash256>,
+        peeled_packet: Option<&PeeledPaymentOnionPacket>,
     ) -> Result<TLC, ProcessingChannelError> {
         if self.get_received_tlc(message.tlc_id).is_some() {
             return Err(ProcessingChannelError::InvalidParameter(format!(
@@ -3655,6 +4498,21 @@ impl ChannelActorState {
                 self.get_next_received_tlc_id()
             )));
         }
+        let (payment_preimage, shared_secret, total_amount, is_last_hop) = match peeled_packet {
+            // if this is the last hop, store the preimage, and the total amount if this tlc
+            // is a part of a multi-part payment which must be held until all the parts arrive.
+            Some(packet) if packet.is_last() => (
+                packet.current.preimage,
+                packet.shared_secret,
+                packet
+                    .current
+                    .total_amount
+                    .filter(|total| *total > message.amount),
+                true,
+            ),
+            Some(packet) => (None, packet.shared_secret, None, false),
+            None => (None, NO_SHARED_SECRET, None, false),
+        };
         Ok(TLC {
             id: TLCId::Received(message.tlc_id),
             amount: message.amount,
@@ -3664,6 +4522,9 @@ impl ChannelActorState {
             hash_algorithm: message.hash_algorithm,
             onion_packet: message.onion_packet,
             previous_tlc: None,
+            shared_secret,
+            total_amount,
+            is_last_hop,
         })
     }
 
@@ -4030,6 +4891,9 @@ impl ChannelActorState {
             commitment_signed.funding_tx_partial_signature,
             commitment_signed.commitment_tx_partial_signature,
         )?;
+        let settlement =
+            self.sign_settlement_tx(commitment_signed.commitment_tx_partial_signature)?;
+        self.verify_splice_commitment_signed()?;
         // This is the commitment transaction that both parties signed,
         // can be broadcasted to the network if necessary
         let num = self.get_current_commitment_number(false);
@@ -4061,6 +4925,7 @@ impl ChannelActorState {
         self.previous_remote_nonce = self.remote_nonce.clone();
         self.remote_nonce = Some(commitment_signed.next_local_nonce);
         self.latest_commitment_transaction = Some(tx.data());
+        self.latest_commitment_settlement = Some(settlement);
         match flags {
             CommitmentSignedFlags::SigningCommitment(flags) => {
                 let flags = flags | SigningCommitmentFlags::THEIR_COMMITMENT_SIGNED_SENT;
@@ -4240,74 +5105,1002 @@ impl ChannelActorState {
             .expect(ASSUME_NETWORK_ACTOR_ALIVE);
     }
 
-    pub async fn on_channel_ready(&mut self, network: &ActorRef<NetworkActorMessage>) {
-        self.update_state(ChannelState::ChannelReady());
-        self.increment_local_commitment_number();
-        self.increment_remote_commitment_number();
-        let peer_id = self.get_remote_peer_id();
-        network
-            .send_message(NetworkActorMessage::new_event(
-                NetworkActorEvent::ChannelReady(
-                    self.get_id(),
-                    peer_id.clone(),
-                    self.get_funding_transaction_outpoint(),
-                ),
-            ))
-            .expect(ASSUME_NETWORK_ACTOR_ALIVE);
-        self.maybe_broadcast_announcement_signatures(network).await;
+    pub fn check_funding_rbf(&self, funding_fee_rate: u64) -> ProcessingChannelResult {
+        match self.state {
+            ChannelState::AwaitingChannelReady(flags) if flags.is_empty() => {}
+            ChannelState::AwaitingTxSignatures(flags)
+                if flags.contains(AwaitingTxSignaturesFlags::TX_SIGNATURES_SENT) => {}
+            _ => {
+                return Err(ProcessingChannelError::InvalidState(format!(
+                    "Unable to replace funding transaction in state {:?}",
+                    &self.state
+                )));
+            }
+        }
+        if funding_fee_rate <= self.funding_fee_rate {
+            return Err(ProcessingChannelError::InvalidParameter(format!(
+                "Funding fee rate {} must be greater than the current fee rate {}",
+                funding_fee_rate, self.funding_fee_rate
+            )));
+        }
+        Ok(())
     }
 
-    pub fn append_remote_commitment_point(&mut self, commitment_point: Pubkey) {
+    // Start collaborating a new funding transaction with a higher fee rate. The current
+    // funding transaction is kept as a candidate, as it may still be confirmed.
+    pub fn start_funding_rbf(
+        &mut self,
+        funding_fee_rate: u64,
+        network: &ActorRef<NetworkActorMessage>,
+    ) {
         debug!(
-            "Setting remote commitment point #{} (counting from 0)): {:?}",
-            self.remote_commitment_points.len(),
-            commitment_point
-        );
-        assert_eq!(
-            self.remote_commitment_points.len() as u64,
-            self.get_local_commitment_number()
+            "Replacing funding transaction of channel {:?} with fee rate {}",
+            self.get_id(),
+            funding_fee_rate
         );
-        self.remote_commitment_points.push(commitment_point);
+        self.pending_funding_fee_rate = None;
+        if let Some(funding_tx) = self.funding_tx.take() {
+            self.funding_tx_candidates.push(FundingTxCandidate {
+                funding_tx,
+                commitment_tx: self.latest_commitment_transaction.clone(),
+                funding_fee_rate: self.funding_fee_rate,
+            });
+        }
+        self.funding_fee_rate = funding_fee_rate;
+        self.funding_rbf_rounds += 1;
+        if self.is_acceptor {
+            self.update_state(ChannelState::CollaboratingFundingTx(
+                CollaboratingFundingTxFlags::AWAITING_REMOTE_TX_COLLABORATION_MSG,
+            ));
+        } else {
+            self.update_state(ChannelState::CollaboratingFundingTx(
+                CollaboratingFundingTxFlags::empty(),
+            ));
+            network
+                .send_message(NetworkActorMessage::new_command(
+                    NetworkActorCommand::UpdateChannelFunding(
+                        self.get_id(),
+                        self.get_funding_rbf_seed_tx(),
+                        self.get_funding_request(),
+                    ),
+                ))
+                .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+        }
     }
 
-    pub fn handle_revoke_and_ack_message(
-        &mut self,
-        network: &ActorRef<NetworkActorMessage>,
-        revoke_and_ack: RevokeAndAck,
-    ) -> ProcessingChannelResult {
-        let commitment_tx_fee =
-            calculate_commitment_tx_fee(self.commitment_fee_rate, &self.funding_udt_type_script);
-        let lock_script = self.get_local_shutdown_script();
-        let (output, output_data) = if let Some(udt_type_script) = &self.funding_udt_type_script {
-            let capacity = self.local_reserved_ckb_amount + self.remote_reserved_ckb_amount
-                - commitment_tx_fee;
-            let output = CellOutput::new_builder()
-                .lock(lock_script)
-                .type_(Some(udt_type_script.clone()).pack())
-                .capacity(capacity.pack())
-                .build();
-
-            let output_data = (self.to_local_amount + self.to_remote_amount)
-                .to_le_bytes()
-                .pack();
-            (output, output_data)
-        } else {
-            let capacity =
-                (self.to_local_amount + self.to_remote_amount) as u64 - commitment_tx_fee;
-            let output = CellOutput::new_builder()
-                .lock(lock_script)
-                .capacity(capacity.pack())
-                .build();
-            let output_data = Bytes::default();
-            (output, output_data)
+    // The replacing funding transaction must spend an input of the replaced one, otherwise
+    // both of them may be confirmed. We are the initiator, so the first input of the
+    // replaced transaction is ours, while the inputs of the peer must not be reused here,
+    // as their capacity would be returned to our change output. The outputs and change
+    // are dropped, and will be rebuilt with the new fee rate.
+    fn get_funding_rbf_seed_tx(&self) -> Transaction {
+        let Some(replaced_tx) = self.funding_tx_candidates.last() else {
+            return Default::default();
         };
+        let replaced_tx = replaced_tx.funding_tx.clone().into_view();
+        Transaction::default()
+            .as_advanced_builder()
+            .set_inputs(replaced_tx.inputs().into_iter().take(1).collect())
+            .set_cell_deps(replaced_tx.cell_deps().into_iter().collect())
+            .build()
+            .data()
+    }
+
+    // Fall back to the latest signed funding transaction if the replacement is aborted
+    // before we have given our signatures of it.
+    pub fn abort_funding_rbf(&mut self) {
+        self.pending_funding_fee_rate = None;
+        let is_replacement_unsigned = match self.state {
+            ChannelState::CollaboratingFundingTx(_) | ChannelState::SigningCommitment(_) => true,
+            ChannelState::AwaitingTxSignatures(flags) => {
+                !flags.contains(AwaitingTxSignaturesFlags::OUR_TX_SIGNATURES_SENT)
+            }
+            _ => false,
+        };
+        if !is_replacement_unsigned {
+            return;
+        }
+        if let Some(candidate) = self.funding_tx_candidates.pop() {
+            self.funding_tx = Some(candidate.funding_tx);
+            self.latest_commitment_transaction = candidate.commitment_tx;
+            self.funding_fee_rate = candidate.funding_fee_rate;
+            self.update_state(ChannelState::AwaitingChannelReady(
+                AwaitingChannelReadyFlags::empty(),
+            ));
+        }
+    }
 
-        let local_pubkey = self.get_local_channel_parameters().pubkeys.funding_pubkey;
-        let remote_pubkey = self.get_remote_channel_parameters().pubkeys.funding_pubkey;
-        let key_agg_ctx = KeyAggContext::new([local_pubkey, remote_pubkey]).expect("Valid pubkeys");
-
-        let x_only_aggregated_pubkey = key_agg_ctx.aggregated_pubkey::<Point>().serialize_xonly();
-        let delay_epoch = self.get_local_channel_parameters().selected_contest_delay;
+    // Switch to the funding transaction that is actually confirmed, which may be
+    // any of the candidates if the funding transaction has been replaced.
+    pub fn on_funding_candidate_confirmed(&mut self, outpoint: &OutPoint) {
+        self.pending_funding_fee_rate = None;
+        let candidates = std::mem::take(&mut self.funding_tx_candidates);
+        let is_current = self
+            .funding_tx
+            .as_ref()
+            .is_some_and(|tx| &OutPoint::new(tx.calc_tx_hash(), 0) == outpoint);
+        if candidates.is_empty() || is_current {
+            return;
+        }
+        if let Some(candidate) = candidates
+            .into_iter()
+            .find(|c| &OutPoint::new(c.funding_tx.calc_tx_hash(), 0) == outpoint)
+        {
+            debug!(
+                "Replaced funding transaction {:?} of channel {:?} is confirmed",
+                outpoint,
+                self.get_id()
+            );
+            self.funding_tx = Some(candidate.funding_tx);
+            self.latest_commitment_transaction = candidate.commitment_tx;
+            self.funding_fee_rate = candidate.funding_fee_rate;
+            if matches!(
+                self.state,
+                ChannelState::CollaboratingFundingTx(_)
+                    | ChannelState::SigningCommitment(_)
+                    | ChannelState::AwaitingTxSignatures(_)
+            ) {
+                self.update_state(ChannelState::AwaitingChannelReady(
+                    AwaitingChannelReadyFlags::empty(),
+                ));
+            }
+        }
+    }
+
+    pub fn check_splice(
+        &self,
+        funding_amount: u128,
+        funding_fee_rate: u64,
+        is_initiator: bool,
+    ) -> ProcessingChannelResult {
+        if !matches!(self.state, ChannelState::ChannelReady()) {
+            return Err(ProcessingChannelError::InvalidState(format!(
+                "Unable to splice channel in state {:?}",
+                &self.state
+            )));
+        }
+        if self.splice.is_some() {
+            return Err(ProcessingChannelError::InvalidState(
+                "A splice is already in progress".to_string(),
+            ));
+        }
+        if self.any_tlc_pending() {
+            return Err(ProcessingChannelError::InvalidState(
+                "Unable to splice channel with pending tlcs".to_string(),
+            ));
+        }
+        if funding_fee_rate < DEFAULT_FEE_RATE {
+            return Err(ProcessingChannelError::InvalidParameter(format!(
+                "Funding fee rate {} is less than the minimal fee rate {}",
+                funding_fee_rate, DEFAULT_FEE_RATE
+            )));
+        }
+        // The funding amount comes from the peer in SpliceInit, reject the amounts
+        // which can't be held by the funding cell before doing any arithmetic with it.
+        let max_funding_amount = if self.funding_udt_type_script.is_none() {
+            u64::MAX as u128
+        } else {
+            i128::MAX as u128
+        };
+        if funding_amount > max_funding_amount {
+            return Err(ProcessingChannelError::InvalidParameter(format!(
+                "Funding amount {} exceeds the maximal amount {}",
+                funding_amount, max_funding_amount
+            )));
+        }
+        let current_amount = if is_initiator {
+            self.to_local_amount
+        } else {
+            self.to_remote_amount
+        };
+        if funding_amount == current_amount {
+            return Err(ProcessingChannelError::InvalidParameter(format!(
+                "Funding amount {} is the same as the current balance",
+                funding_amount
+            )));
+        }
+        if get_splice_amount_delta(funding_amount, current_amount).is_none() {
+            return Err(ProcessingChannelError::InvalidParameter(format!(
+                "Unable to splice the balance {} to funding amount {}",
+                current_amount, funding_amount
+            )));
+        }
+        if self.funding_udt_type_script.is_none() {
+            let capacity = [
+                self.to_local_amount,
+                self.to_remote_amount,
+                self.local_reserved_ckb_amount as u128,
+                self.remote_reserved_ckb_amount as u128,
+            ]
+            .into_iter()
+            .try_fold(funding_amount, |sum, amount| sum.checked_add(amount))
+            .and_then(|sum| sum.checked_sub(current_amount));
+            if capacity.map_or(true, |capacity| capacity > u64::MAX as u128) {
+                return Err(ProcessingChannelError::InvalidParameter(format!(
+                    "Funding amount {} exceeds the maximal capacity of the channel",
+                    funding_amount
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    // Start a splice which changes the balance of the initiator to `funding_amount`.
+    pub fn start_splice(
+        &mut self,
+        is_initiator: bool,
+        funding_amount: u128,
+        funding_fee_rate: u64,
+    ) {
+        debug!(
+            "Splicing channel {:?} with funding amount {} and fee rate {}",
+            self.get_id(),
+            funding_amount,
+            funding_fee_rate
+        );
+        self.splice_rounds += 1;
+        // The amounts are checked by `check_splice` before starting the splice.
+        let (local_amount_delta, remote_amount_delta) = if is_initiator {
+            let delta = get_splice_amount_delta(funding_amount, self.to_local_amount)
+                .expect("splice amount checked");
+            (delta, 0)
+        } else {
+            let delta = get_splice_amount_delta(funding_amount, self.to_remote_amount)
+                .expect("splice amount checked");
+            (0, delta)
+        };
+        self.splice = Some(SpliceInfo {
+            is_initiator,
+            local_amount_delta,
+            remote_amount_delta,
+            funding_fee_rate,
+            nonce_offset: SPLICE_NONCE_OFFSET * self.splice_rounds,
+            funding_tx: None,
+            latest_commitment_transaction: None,
+            latest_commitment_settlement: None,
+            remote_funding_nonce: None,
+            previous_remote_nonce: None,
+            remote_nonce: None,
+            remote_channel_announcement_nonce: None,
+            remote_commitment_signed: None,
+            remote_revoke_and_ack: None,
+            remote_revocation: None,
+            flags: SpliceFlags::empty(),
+        });
+    }
+
+    // Drop the splice if we haven't given our signature of the splice transaction,
+    // otherwise the splice transaction may still be confirmed.
+    pub fn abort_splice(&mut self) {
+        if self
+            .splice
+            .as_ref()
+            .is_some_and(|splice| !splice.flags.contains(SpliceFlags::TX_SIGNED))
+        {
+            debug!("Aborting splice of channel {:?}", self.get_id());
+            self.splice = None;
+        }
+    }
+
+    pub fn update_splice_remote_nonces(
+        &mut self,
+        funding_nonce: PubNonce,
+        local_nonce: PubNonce,
+        channel_announcement_nonce: PubNonce,
+    ) {
+        let splice = self.splice.as_mut().expect("splice in progress");
+        splice.remote_funding_nonce = Some(funding_nonce);
+        splice.remote_nonce = Some(local_nonce);
+        splice.remote_channel_announcement_nonce = Some(channel_announcement_nonce);
+    }
+
+    // The channel state after the splice is confirmed. The commitment transactions of
+    // the spliced funding cell are built, signed and verified with this state.
+    pub fn get_spliced_state(&self) -> ChannelActorState {
+        let splice = self.splice.as_ref().expect("splice in progress");
+        let mut state = self.clone();
+        state.splice = None;
+        state.funding_tx = splice.funding_tx.clone();
+        state.funding_fee_rate = splice.funding_fee_rate;
+        state.to_local_amount = self
+            .to_local_amount
+            .checked_add_signed(splice.local_amount_delta)
+            .expect("spliced local balance checked");
+        state.to_remote_amount = self
+            .to_remote_amount
+            .checked_add_signed(splice.remote_amount_delta)
+            .expect("spliced remote balance checked");
+        state.previous_remote_nonce = splice.previous_remote_nonce.clone();
+        state.remote_nonce = splice.remote_nonce.clone();
+        state.latest_commitment_transaction = splice.latest_commitment_transaction.clone();
+        state.latest_commitment_settlement = splice.latest_commitment_settlement.clone();
+        state.funding_rbf_rounds = splice.nonce_offset;
+        state
+    }
+
+    pub fn get_splice_funding_secnonce(&self) -> SecNonce {
+        let splice = self.splice.as_ref().expect("splice in progress");
+        self.signer.derive_musig2_nonce(splice.nonce_offset - 1)
+    }
+
+    // The musig2 contexts to sign the current funding cell spent by the splice transaction.
+    fn get_splice_funding_musig2_contexts(&self) -> (Musig2SignContext, Musig2VerifyContext) {
+        let splice = self.splice.as_ref().expect("splice in progress");
+        let secnonce = self.get_splice_funding_secnonce();
+        let remote_nonce = splice
+            .remote_funding_nonce
+            .clone()
+            .expect("remote funding nonce exists");
+        let agg_nonce = AggNonce::sum(
+            self.order_things_for_musig2(secnonce.public_nonce(), remote_nonce.clone()),
+        );
+        let key_agg_ctx = self.get_musig2_agg_context();
+        (
+            Musig2SignContext {
+                key_agg_ctx: key_agg_ctx.clone(),
+                agg_nonce: agg_nonce.clone(),
+                seckey: self.signer.funding_key.clone(),
+                secnonce,
+            },
+            Musig2VerifyContext {
+                key_agg_ctx,
+                agg_nonce,
+                pubkey: *self.get_remote_funding_pubkey(),
+                pubnonce: remote_nonce,
+            },
+        )
+    }
+
+    pub fn send_splice_commitment_signed(
+        &mut self,
+        network: &ActorRef<NetworkActorMessage>,
+    ) -> ProcessingChannelResult {
+        let splice = self.splice.as_ref().expect("splice in progress");
+        let is_first = !splice
+            .flags
+            .contains(SpliceFlags::OUR_COMMITMENT_SIGNED_SENT);
+        let spliced = self.get_spliced_state();
+        let PartiallySignedCommitmentTransaction {
+            funding_tx_partial_signature,
+            commitment_tx_partial_signature,
+            ..
+        } = spliced.build_and_sign_commitment_tx()?;
+        // No revoke_and_ack follows the first commitment transaction of the spliced
+        // funding cell, so the current nonce is used again for the next one.
+        let next_local_nonce = if is_first {
+            spliced.get_local_nonce()
+        } else {
+            spliced.get_next_local_nonce()
+        };
+        network
+            .send_message(NetworkActorMessage::new_command(
+                NetworkActorCommand::SendFiberMessage(FiberMessageWithPeerId::new(
+                    self.get_remote_peer_id(),
+                    FiberMessage::splice_commitment_signed(SpliceCommitmentSigned {
+                        channel_id: self.get_id(),
+                        funding_tx_partial_signature,
+                        commitment_tx_partial_signature,
+                        next_local_nonce,
+                    }),
+                )),
+            ))
+            .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+        let splice = self.splice.as_mut().expect("splice in progress");
+        splice.flags |= SpliceFlags::OUR_COMMITMENT_SIGNED_SENT;
+        Ok(())
+    }
+
+    // The splice transaction is built by the initiator alone, the acceptor only checks
+    // that the current funding cell is spent and the new funding cell is correct.
+    pub fn handle_splice_tx_collaboration_msg(
+        &mut self,
+        msg: TxCollaborationMsg,
+        network: &ActorRef<NetworkActorMessage>,
+    ) -> ProcessingChannelResult {
+        debug!("Processing splice tx collaboration message: {:?}", &msg);
+        let splice = self.splice.as_ref().expect("splice in progress");
+        if splice.flags.contains(SpliceFlags::THEIR_TX_COMPLETE_SENT) {
+            return Err(ProcessingChannelError::InvalidState(format!(
+                "Received a splice tx collaboration message {:?} after the remote has sent a complete message",
+                &msg
+            )));
+        }
+        match msg {
+            TxCollaborationMsg::TxUpdate(msg) => {
+                if splice.is_initiator {
+                    return Err(ProcessingChannelError::InvalidState(
+                        "Splice initiator received a tx collaboration message".to_string(),
+                    ));
+                }
+                let tx = msg.tx.clone().into_view();
+                if tx.input_pts_iter().next() != Some(self.get_funding_transaction_outpoint()) {
+                    return Err(ProcessingChannelError::InvalidParameter(
+                        "Splice transaction must spend the current funding cell first".to_string(),
+                    ));
+                }
+                let mut spliced = self.get_spliced_state();
+                spliced.funding_tx = Some(msg.tx.clone());
+                if !spliced.is_tx_final(&msg.tx)? {
+                    return Err(ProcessingChannelError::InvalidParameter(
+                        "Splice transaction does not create the correct funding cell".to_string(),
+                    ));
+                }
+                let splice = self.splice.as_mut().expect("splice in progress");
+                splice.funding_tx = Some(msg.tx);
+                self.send_splice_tx_complete(network);
+            }
+            TxCollaborationMsg::TxComplete(_) => {
+                if splice.funding_tx.is_none() {
+                    return Err(ProcessingChannelError::InvalidState(
+                        "Received TxComplete message without a splice transaction".to_string(),
+                    ));
+                }
+                let splice = self.splice.as_mut().expect("splice in progress");
+                splice.flags |= SpliceFlags::THEIR_TX_COMPLETE_SENT;
+            }
+        }
+        self.maybe_send_first_splice_commitment_signed(network)
+    }
+
+    pub fn handle_splice_tx_update_command(
+        &mut self,
+        tx: Transaction,
+        network: &ActorRef<NetworkActorMessage>,
+    ) -> ProcessingChannelResult {
+        let splice = self.splice.as_mut().expect("splice in progress");
+        if !splice.is_initiator || splice.flags.contains(SpliceFlags::OUR_TX_COMPLETE_SENT) {
+            return Err(ProcessingChannelError::InvalidState(
+                "Unexpected splice transaction update".to_string(),
+            ));
+        }
+        splice.funding_tx = Some(tx.clone());
+        network
+            .send_message(NetworkActorMessage::new_command(
+                NetworkActorCommand::SendFiberMessage(FiberMessageWithPeerId::new(
+                    self.get_remote_peer_id(),
+                    FiberMessage::tx_update(TxUpdate {
+                        channel_id: self.get_id(),
+                        tx,
+                    }),
+                )),
+            ))
+            .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+        self.send_splice_tx_complete(network);
+        self.maybe_send_first_splice_commitment_signed(network)
+    }
+
+    fn send_splice_tx_complete(&mut self, network: &ActorRef<NetworkActorMessage>) {
+        network
+            .send_message(NetworkActorMessage::new_command(
+                NetworkActorCommand::SendFiberMessage(FiberMessageWithPeerId::new(
+                    self.get_remote_peer_id(),
+                    FiberMessage::tx_complete(TxComplete {
+                        channel_id: self.get_id(),
+                    }),
+                )),
+            ))
+            .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+        let splice = self.splice.as_mut().expect("splice in progress");
+        splice.flags |= SpliceFlags::OUR_TX_COMPLETE_SENT;
+    }
+
+    fn maybe_send_first_splice_commitment_signed(
+        &mut self,
+        network: &ActorRef<NetworkActorMessage>,
+    ) -> ProcessingChannelResult {
+        let flags = self.splice.as_ref().expect("splice in progress").flags;
+        if flags.contains(SpliceFlags::TX_COMPLETE_SENT)
+            && !flags.contains(SpliceFlags::OUR_COMMITMENT_SIGNED_SENT)
+        {
+            self.send_splice_commitment_signed(network)?;
+            self.maybe_sign_splice_tx(network)?;
+        }
+        Ok(())
+    }
+
+    pub fn handle_splice_commitment_signed_message(
+        &mut self,
+        commitment_signed: SpliceCommitmentSigned,
+        network: &ActorRef<NetworkActorMessage>,
+    ) -> ProcessingChannelResult {
+        let splice = self
+            .splice
+            .as_mut()
+            .ok_or(ProcessingChannelError::InvalidState(
+                "Received SpliceCommitmentSigned without a pending splice".to_string(),
+            ))?;
+        if !splice.flags.contains(SpliceFlags::TX_COMPLETE_SENT) {
+            return Err(ProcessingChannelError::InvalidState(
+                "Received SpliceCommitmentSigned before the splice transaction is complete"
+                    .to_string(),
+            ));
+        }
+        if splice
+            .flags
+            .contains(SpliceFlags::THEIR_COMMITMENT_SIGNED_SENT)
+        {
+            // Processed together with the following CommitmentSigned message.
+            splice.remote_commitment_signed = Some(commitment_signed);
+            return Ok(());
+        }
+
+        let spliced = self.get_spliced_state();
+        let tx = spliced.verify_and_complete_tx(
+            commitment_signed.funding_tx_partial_signature,
+            commitment_signed.commitment_tx_partial_signature,
+        )?;
+        let settlement =
+            spliced.sign_settlement_tx(commitment_signed.commitment_tx_partial_signature)?;
+        debug!(
+            "Successfully handled the first splice commitment signed message: {:?}, tx: {:?}",
+            &commitment_signed, &tx
+        );
+        let splice = self.splice.as_mut().expect("splice in progress");
+        splice.remote_nonce = Some(commitment_signed.next_local_nonce);
+        splice.latest_commitment_transaction = Some(tx.data());
+        splice.latest_commitment_settlement = Some(settlement);
+        splice.flags |= SpliceFlags::THEIR_COMMITMENT_SIGNED_SENT;
+        self.maybe_sign_splice_tx(network)
+    }
+
+    // Verify the SpliceCommitmentSigned message received before a CommitmentSigned message.
+    fn verify_splice_commitment_signed(&mut self) -> ProcessingChannelResult {
+        if !self
+            .splice
+            .as_ref()
+            .is_some_and(|splice| splice.flags.contains(SpliceFlags::COMMITMENT_SIGNED_SENT))
+        {
+            return Ok(());
+        }
+        let commitment_signed = self
+            .splice
+            .as_mut()
+            .and_then(|splice| splice.remote_commitment_signed.take())
+            .ok_or(ProcessingChannelError::InvalidState(
+                "Missing SpliceCommitmentSigned for the pending splice".to_string(),
+            ))?;
+        let spliced = self.get_spliced_state();
+        let tx = spliced.verify_and_complete_tx(
+            commitment_signed.funding_tx_partial_signature,
+            commitment_signed.commitment_tx_partial_signature,
+        )?;
+        let settlement =
+            spliced.sign_settlement_tx(commitment_signed.commitment_tx_partial_signature)?;
+        let splice = self.splice.as_mut().expect("splice in progress");
+        splice.previous_remote_nonce = splice.remote_nonce.take();
+        splice.remote_nonce = Some(commitment_signed.next_local_nonce);
+        splice.latest_commitment_transaction = Some(tx.data());
+        splice.latest_commitment_settlement = Some(settlement);
+        Ok(())
+    }
+
+    // The acceptor sends its signature of the current funding cell first, after both
+    // parties have signed the commitment transactions of the spliced funding cell.
+    fn maybe_sign_splice_tx(
+        &mut self,
+        network: &ActorRef<NetworkActorMessage>,
+    ) -> ProcessingChannelResult {
+        let splice = self.splice.as_ref().expect("splice in progress");
+        if splice.is_initiator
+            || !splice.flags.contains(SpliceFlags::COMMITMENT_SIGNED_SENT)
+            || splice.flags.contains(SpliceFlags::TX_SIGNED)
+        {
+            return Ok(());
+        }
+        let tx = splice
+            .funding_tx
+            .clone()
+            .expect("splice transaction exists")
+            .into_view();
+        let (sign_ctx, _) = self.get_splice_funding_musig2_contexts();
+        let partial_signature = sign_ctx.sign(tx.hash().as_slice())?;
+        network
+            .send_message(NetworkActorMessage::new_command(
+                NetworkActorCommand::SendFiberMessage(FiberMessageWithPeerId::new(
+                    self.get_remote_peer_id(),
+                    FiberMessage::ChannelNormalOperation(FiberChannelMessage::TxSignatures(
+                        TxSignatures {
+                            channel_id: self.get_id(),
+                            witnesses: vec![partial_signature.serialize().to_vec()],
+                            tx_hash: tx.hash().into(),
+                        },
+                    )),
+                )),
+            ))
+            .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+        let splice = self.splice.as_mut().expect("splice in progress");
+        splice.flags |= SpliceFlags::TX_SIGNED;
+        Ok(())
+    }
+
+    pub fn handle_splice_tx_signatures(
+        &mut self,
+        tx_signatures: TxSignatures,
+        network: &ActorRef<NetworkActorMessage>,
+    ) -> ProcessingChannelResult {
+        let splice = self.splice.as_ref().expect("splice in progress");
+        let tx = match splice.funding_tx.clone() {
+            Some(tx) if splice.flags.contains(SpliceFlags::COMMITMENT_SIGNED_SENT) => {
+                tx.into_view()
+            }
+            _ => {
+                return Err(ProcessingChannelError::InvalidState(
+                    "Received TxSignatures before signing the splice commitments".to_string(),
+                ));
+            }
+        };
+        let tx_hash: Hash256 = tx.hash().into();
+        if tx_signatures.tx_hash != tx_hash {
+            return Err(ProcessingChannelError::InvalidParameter(format!(
+                "Received TxSignatures for unknown splice transaction {:?}",
+                tx_signatures.tx_hash
+            )));
+        }
+
+        if !splice.is_initiator {
+            if !splice.flags.contains(SpliceFlags::TX_SIGNED) {
+                return Err(ProcessingChannelError::InvalidState(
+                    "Received the signed splice transaction before signing it".to_string(),
+                ));
+            }
+            let funding_tx = tx
+                .as_advanced_builder()
+                .set_witnesses(
+                    tx_signatures
+                        .witnesses
+                        .into_iter()
+                        .map(|x| x.pack())
+                        .collect(),
+                )
+                .build();
+            let outpoint = OutPoint::new(funding_tx.hash(), 0);
+            self.splice.as_mut().expect("splice in progress").funding_tx = Some(funding_tx.data());
+            network
+                .send_message(NetworkActorMessage::new_event(
+                    NetworkActorEvent::FundingTransactionPending(
+                        funding_tx.data(),
+                        outpoint,
+                        self.get_id(),
+                    ),
+                ))
+                .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+            return Ok(());
+        }
+
+        if splice.flags.contains(SpliceFlags::TX_SIGNED) {
+            return Err(ProcessingChannelError::RepeatedProcessing(
+                "splice tx_signatures".to_string(),
+            ));
+        }
+        let partial_signature = tx_signatures
+            .witnesses
+            .first()
+            .and_then(|witness| PartialSignature::from_slice(witness).ok())
+            .ok_or(ProcessingChannelError::InvalidParameter(
+                "Invalid partial signature of the splice transaction".to_string(),
+            ))?;
+        let (sign_ctx, verify_ctx) = self.get_splice_funding_musig2_contexts();
+        let message = tx.hash();
+        verify_ctx.verify(partial_signature, message.as_slice())?;
+        let local_partial_signature = sign_ctx.sign(message.as_slice())?;
+        let signature = aggregate_partial_signatures_for_msg(
+            message.as_slice(),
+            verify_ctx,
+            [partial_signature, local_partial_signature],
+        )?;
+        let mut witnesses: Vec<Vec<u8>> = tx.witnesses().into_iter().map(|x| x.unpack()).collect();
+        witnesses[0] = self.create_witness_for_funding_cell(signature).to_vec();
+
+        self.splice.as_mut().expect("splice in progress").flags |= SpliceFlags::TX_SIGNED;
+        // The network actor signs our inputs, broadcasts the splice transaction
+        // and sends the fully signed transaction to the peer.
+        network
+            .send_message(NetworkActorMessage::new_command(
+                NetworkActorCommand::SignTx(
+                    self.get_remote_peer_id(),
+                    self.get_id(),
+                    tx.data(),
+                    Some(witnesses),
+                ),
+            ))
+            .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+        Ok(())
+    }
+
+    // The splice transaction is confirmed if the outpoint is the spliced funding cell.
+    pub fn on_splice_confirmed(
+        &mut self,
+        outpoint: &OutPoint,
+        network: &ActorRef<NetworkActorMessage>,
+    ) -> bool {
+        let Some(splice) = self.splice.as_mut() else {
+            return false;
+        };
+        if !splice
+            .funding_tx
+            .as_ref()
+            .is_some_and(|tx| &OutPoint::new(tx.calc_tx_hash(), 0) == outpoint)
+        {
+            return false;
+        }
+        splice.flags |= SpliceFlags::OUR_SPLICE_LOCKED;
+        network
+            .send_message(NetworkActorMessage::new_command(
+                NetworkActorCommand::SendFiberMessage(FiberMessageWithPeerId::new(
+                    self.get_remote_peer_id(),
+                    FiberMessage::splice_locked(SpliceLocked {
+                        channel_id: self.get_id(),
+                        funding_tx_hash: outpoint.tx_hash().into(),
+                    }),
+                )),
+            ))
+            .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+        true
+    }
+
+    pub fn handle_splice_locked_message(
+        &mut self,
+        splice_locked: SpliceLocked,
+    ) -> ProcessingChannelResult {
+        let splice = self
+            .splice
+            .as_mut()
+            .filter(|splice| splice.flags.contains(SpliceFlags::TX_SIGNED))
+            .ok_or(ProcessingChannelError::InvalidState(
+                "Received SpliceLocked without a signed splice".to_string(),
+            ))?;
+        let funding_tx_hash: Hash256 = splice
+            .funding_tx
+            .as_ref()
+            .expect("splice transaction exists")
+            .calc_tx_hash()
+            .into();
+        if splice_locked.funding_tx_hash != funding_tx_hash {
+            return Err(ProcessingChannelError::InvalidParameter(format!(
+                "Received SpliceLocked for unknown splice transaction {:?}",
+                splice_locked.funding_tx_hash
+            )));
+        }
+        splice.flags |= SpliceFlags::THEIR_SPLICE_LOCKED;
+        Ok(())
+    }
+
+    // Switch to the spliced funding cell after both parties have seen the splice
+    // transaction confirmed, and announce the channel again with the new capacity.
+    pub async fn maybe_complete_splice(&mut self, network: &ActorRef<NetworkActorMessage>) {
+        if !self
+            .splice
+            .as_ref()
+            .is_some_and(|splice| splice.flags.contains(SpliceFlags::SPLICE_LOCKED))
+        {
+            return;
+        }
+        let old_outpoint = self.get_funding_transaction_outpoint();
+        let remote_channel_announcement_nonce = self
+            .splice
+            .as_ref()
+            .and_then(|splice| splice.remote_channel_announcement_nonce.clone());
+        let remote_revocation = self
+            .splice
+            .as_ref()
+            .and_then(|splice| splice.remote_revocation.clone());
+        let spliced = self.get_spliced_state();
+        self.funding_tx = spliced.funding_tx;
+        self.funding_fee_rate = spliced.funding_fee_rate;
+        self.to_local_amount = spliced.to_local_amount;
+        self.to_remote_amount = spliced.to_remote_amount;
+        self.previous_remote_nonce = spliced.previous_remote_nonce;
+        self.remote_nonce = spliced.remote_nonce;
+        self.latest_commitment_transaction = spliced.latest_commitment_transaction;
+        self.latest_commitment_settlement = spliced.latest_commitment_settlement;
+        self.funding_rbf_rounds = spliced.funding_rbf_rounds;
+        self.splice = None;
+        if let Some(info) = self.public_channel_info.as_mut() {
+            info.channel_announcement = None;
+            info.local_channel_announcement_signature = None;
+            info.remote_channel_announcement_signature = None;
+            info.remote_channel_announcement_nonce = remote_channel_announcement_nonce;
+        }
+        info!(
+            "Channel {:?} is spliced from {:?} to {:?}",
+            self.get_id(),
+            old_outpoint,
+            self.get_funding_transaction_outpoint()
+        );
+        network
+            .send_message(NetworkActorMessage::new_event(
+                NetworkActorEvent::ChannelSpliced(
+                    self.get_id(),
+                    self.get_remote_peer_id(),
+                    old_outpoint,
+                    self.get_funding_transaction_outpoint(),
+                    self.get_announced_capacity(),
+                ),
+            ))
+            .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+        // The revoked commitment transactions of the old funding cell can't be committed
+        // anymore, the watchtowers should revoke the ones spending the spliced funding cell.
+        if let Some(revocation) = remote_revocation {
+            emit_service_event(
+                network,
+                NetworkServiceEvent::RevokeAndAckReceived(
+                    self.get_remote_peer_id(),
+                    self.get_id(),
+                    revocation.commitment_number,
+                    revocation.x_only_aggregated_pubkey,
+                    revocation.aggregated_signature,
+                    revocation.output,
+                    revocation.output_data,
+                ),
+            );
+        }
+        self.maybe_broadcast_announcement_signatures(network).await;
+    }
+
+    pub async fn on_channel_ready(&mut self, network: &ActorRef<NetworkActorMessage>) {
+        self.update_state(ChannelState::ChannelReady());
+        self.increment_local_commitment_number();
+        self.increment_remote_commitment_number();
+        let peer_id = self.get_remote_peer_id();
+        network
+            .send_message(NetworkActorMessage::new_event(
+                NetworkActorEvent::ChannelReady(
+                    self.get_id(),
+                    peer_id.clone(),
+                    self.get_funding_transaction_outpoint(),
+                ),
+            ))
+            .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+        self.maybe_broadcast_announcement_signatures(network).await;
+    }
+
+    pub fn append_remote_commitment_point(&mut self, commitment_point: Pubkey) {
+        debug!(
+            "Setting remote commitment point #{} (counting from 0)): {:?}",
+            self.remote_commitment_points.len(),
+            commitment_point
+        );
+        assert_eq!(
+            self.remote_commitment_points.len() as u64,
+            self.get_local_commitment_number()
+        );
+        self.remote_commitment_points.push(commitment_point);
+    }
+
+    // The peer reveals the secret of its commitment point revoked by the following RevokeAndAck.
+    fn handle_commitment_secret_message(
+        &mut self,
+        commitment_secret: CommitmentSecret,
+    ) -> ProcessingChannelResult {
+        let CommitmentSecret {
+            channel_id: _,
+            commitment_number,
+            secret,
+        } = commitment_secret;
+        let Some(commitment_point) = self
+            .remote_commitment_points
+            .get(commitment_number as usize)
+            .filter(|_| commitment_number == self.get_local_commitment_number())
+        else {
+            return Err(ProcessingChannelError::InvalidParameter(format!(
+                "Unexpected commitment secret for commitment number {}, our local commitment number is {}",
+                commitment_number,
+                self.get_local_commitment_number()
+            )));
+        };
+        if !SecretKey::from_slice(secret.as_ref())
+            .is_ok_and(|key| Privkey::from(key).pubkey() == *commitment_point)
+        {
+            return Err(ProcessingChannelError::InvalidParameter(format!(
+                "Invalid commitment secret for commitment number {}",
+                commitment_number
+            )));
+        }
+        self.remote_commitment_secret = Some((commitment_number, secret));
+        Ok(())
+    }
+
+    pub fn handle_revoke_and_ack_message(
+        &mut self,
+        network: &ActorRef<NetworkActorMessage>,
+        revoke_and_ack: RevokeAndAck,
+    ) -> ProcessingChannelResult {
+        let RevokeAndAck {
+            channel_id: _,
+            partial_signature,
+            next_per_commitment_point,
+        } = revoke_and_ack;
+        if self
+            .splice
+            .as_ref()
+            .is_some_and(|splice| splice.flags.contains(SpliceFlags::COMMITMENT_SIGNED_SENT))
+        {
+            let splice_partial_signature = self
+                .splice
+                .as_mut()
+                .and_then(|splice| splice.remote_revoke_and_ack.take())
+                .ok_or(ProcessingChannelError::InvalidState(
+                    "Missing SpliceRevokeAndAck for the pending splice".to_string(),
+                ))?;
+            let (
+                commitment_number,
+                x_only_aggregated_pubkey,
+                aggregated_signature,
+                output,
+                output_data,
+            ) = self
+                .get_spliced_state()
+                .verify_revoke_and_ack(splice_partial_signature)?;
+            if let Some(splice) = self.splice.as_mut() {
+                splice.remote_revocation = Some(RevocationData {
+                    commitment_number,
+                    x_only_aggregated_pubkey,
+                    aggregated_signature,
+                    output,
+                    output_data,
+                });
+            }
+        }
+        let (commitment_number, x_only_aggregated_pubkey, aggregate_signature, output, output_data) =
+            self.verify_revoke_and_ack(partial_signature)?;
+
+        self.update_state_on_raa_msg(true);
+        self.append_remote_commitment_point(next_per_commitment_point);
+
+        emit_service_event(
+            network,
+            NetworkServiceEvent::RevokeAndAckReceived(
+                self.get_remote_peer_id(),
+                self.get_id(),
+                commitment_number,
+                x_only_aggregated_pubkey,
+                aggregate_signature,
+                output,
+                output_data,
+            ),
+        );
+        Ok(())
+    }
+
+    // Verify the partial signature to revoke our current commitment transaction, returns
+    // the commitment number, the aggregated public key and signature, and the revocation output.
+    fn verify_revoke_and_ack(
+        &self,
+        partial_signature: PartialSignature,
+    ) -> Result<(u64, [u8; 32], CompactSignature, CellOutput, Bytes), ProcessingChannelError> {
+        let commitment_tx_fee =
+            calculate_commitment_tx_fee(self.commitment_fee_rate, &self.funding_udt_type_script);
+        let lock_script = self.get_local_shutdown_script();
+        let (output, output_data) = if let Some(udt_type_script) = &self.funding_udt_type_script {
+            let capacity = self.local_reserved_ckb_amount + self.remote_reserved_ckb_amount
+                - commitment_tx_fee;
+            let output = CellOutput::new_builder()
+                .lock(lock_script)
+                .type_(Some(udt_type_script.clone()).pack())
+                .capacity(capacity.pack())
+                .build();
+
+            let output_data = (self.to_local_amount + self.to_remote_amount)
+                .to_le_bytes()
+                .pack();
+            (output, output_data)
+        } else {
+            let capacity =
+                (self.to_local_amount + self.to_remote_amount) as u64 - commitment_tx_fee;
+            let output = CellOutput::new_builder()
+                .lock(lock_script)
+                .capacity(capacity.pack())
+                .build();
+            let output_data = Bytes::default();
+            (output, output_data)
+        };
+
+        let local_pubkey = self.get_local_channel_parameters().pubkeys.funding_pubkey;
+        let remote_pubkey = self.get_remote_channel_parameters().pubkeys.funding_pubkey;
+        let key_agg_ctx = KeyAggContext::new([local_pubkey, remote_pubkey]).expect("Valid pubkeys");
+
+        let x_only_aggregated_pubkey = key_agg_ctx.aggregated_pubkey::<Point>().serialize_xonly();
+        let delay_epoch = self.get_local_channel_parameters().selected_contest_delay;
         let commitment_number = self.get_local_commitment_number();
 
         let commitment_lock_script_args = [
@@ -4343,11 +6136,6 @@ impl ChannelActorState {
             pubnonce: self.get_remote_nonce(),
         };
 
-        let RevokeAndAck {
-            channel_id: _,
-            partial_signature,
-            next_per_commitment_point,
-        } = revoke_and_ack;
         verify_ctx.verify(partial_signature, message.as_slice())?;
 
         let sign_ctx: Musig2SignContext = Musig2SignContext {
@@ -4364,22 +6152,13 @@ impl ChannelActorState {
             [partial_signature, signature2],
         )?;
 
-        self.update_state_on_raa_msg(true);
-        self.append_remote_commitment_point(next_per_commitment_point);
-
-        emit_service_event(
-            network,
-            NetworkServiceEvent::RevokeAndAckReceived(
-                self.get_remote_peer_id(),
-                self.get_id(),
-                commitment_number,
-                x_only_aggregated_pubkey,
-                aggregate_signature,
-                output,
-                output_data,
-            ),
-        );
-        Ok(())
+        Ok((
+            commitment_number,
+            x_only_aggregated_pubkey,
+            aggregate_signature,
+            output,
+            output_data,
+        ))
     }
 
     fn handle_reestablish_channel_message(
@@ -4391,6 +6170,30 @@ impl ChannelActorState {
             "Handling reestablish channel message: {:?}, our commitment_numbers {:?}",
             reestablish_channel, self.commitment_numbers,
         );
+        if self.check_data_loss(reestablish_channel)? {
+            error!(
+                "Channel {:?} is behind the peer (our commitment numbers {:?}, the peer's {:?}), asking the peer to close it",
+                self.get_id(),
+                self.commitment_numbers,
+                reestablish_channel
+            );
+            self.data_loss_detected = true;
+            // Stay reestablishing so that no more messages are processed with the stale states.
+            network
+                .send_message(NetworkActorMessage::new_command(
+                    NetworkActorCommand::SendFiberMessage(FiberMessageWithPeerId::new(
+                        self.get_remote_peer_id(),
+                        FiberMessage::shutdown(Shutdown {
+                            channel_id: self.get_id(),
+                            close_script: self.get_local_shutdown_script(),
+                            fee_rate: FeeRate::from_u64(0),
+                            force: true,
+                        }),
+                    )),
+                ))
+                .expect(ASSUME_NETWORK_ACTOR_ALIVE);
+            return Ok(());
+        }
         self.reestablishing = false;
         match self.state {
             ChannelState::NegotiatingFunding(_flags) => {
@@ -4430,7 +6233,7 @@ impl ChannelActorState {
 
                                 need_resend_commitment_signed = true;
                             }
-                        } else if let Some((commitment_number, remove_reason)) = info.removed_at {
+                        } else if let Some((commitment_number, remove_reason)) = &info.removed_at {
                             if commitment_number.get_local() >= acutal_local_commitment_number {
                                 // resend RemoveTlc message
                                 network
@@ -4441,7 +6244,7 @@ impl ChannelActorState {
                                                 FiberMessage::remove_tlc(RemoveTlc {
                                                     channel_id: self.get_id(),
                                                     tlc_id: info.tlc.get_id(),
-                                                    reason: remove_reason,
+                                                    reason: remove_reason.clone(),
                                                 }),
                                             ),
                                         ),
@@ -4501,6 +6304,45 @@ impl ChannelActorState {
         Ok(())
     }
 
+    // Returns true if the peer has received a RevokeAndAck we don't remember sending, which
+    // is proven by the secret of our commitment point revoked by it. Only the peers supporting
+    // DataLossProtect can prove that.
+    fn check_data_loss(
+        &self,
+        reestablish_channel: &ReestablishChannel,
+    ) -> Result<bool, ProcessingChannelError> {
+        let Some(data_loss_protect) = &reestablish_channel.data_loss_protect else {
+            return Ok(false);
+        };
+        let remote_commitment_number = reestablish_channel.local_commitment_number;
+        if remote_commitment_number < self.get_remote_commitment_number() {
+            return Ok(false);
+        }
+        if remote_commitment_number == self.get_remote_commitment_number() {
+            if data_loss_protect
+                .remote_commitment_point
+                .is_some_and(|point| point != self.get_current_local_commitment_point())
+            {
+                return Err(ProcessingChannelError::InvalidParameter(format!(
+                    "Peer sent a wrong commitment point for our commitment number {}",
+                    remote_commitment_number
+                )));
+            }
+            return Ok(false);
+        }
+        let secret: Hash256 = self
+            .signer
+            .get_commitment_secret(remote_commitment_number - 1)
+            .into();
+        if data_loss_protect.remote_commitment_secret != secret {
+            return Err(ProcessingChannelError::InvalidParameter(format!(
+                "Peer claims our commitment number is {} without a valid commitment secret",
+                remote_commitment_number
+            )));
+        }
+        Ok(true)
+    }
+
     pub fn is_tx_final(&self, tx: &Transaction) -> Result<bool, ProcessingChannelError> {
         // TODO: check if the tx is valid
         let tx = tx.clone().into_view();
@@ -4979,27 +6821,7 @@ impl ChannelActorState {
         )?;
 
         let verify_ctx = Musig2VerifyContext::from((self, false));
-        let to_local_output = settlement_tx.outputs().get(0).unwrap();
-        let to_local_output_data = settlement_tx.outputs_data().get(0).unwrap();
-        let to_remote_output = settlement_tx.outputs().get(1).unwrap();
-        let to_remote_output_data = settlement_tx.outputs_data().get(1).unwrap();
-        let args = commitment_tx
-            .outputs()
-            .get(0)
-            .unwrap()
-            .lock()
-            .args()
-            .raw_data();
-        let message = blake2b_256(
-            [
-                to_local_output.as_slice(),
-                to_local_output_data.as_slice(),
-                to_remote_output.as_slice(),
-                to_remote_output_data.as_slice(),
-                &args[0..36],
-            ]
-            .concat(),
-        );
+        let message = get_settlement_tx_message(&commitment_tx, &settlement_tx);
         verify_ctx.verify(commitment_tx_partial_signature, message.as_slice())?;
 
         Ok(PartiallySignedCommitmentTransaction {
@@ -5019,27 +6841,7 @@ impl ChannelActorState {
         let funding_tx_partial_signature = sign_ctx.sign(commitment_tx.hash().as_slice())?;
 
         let sign_ctx = Musig2SignContext::from((self, true));
-        let to_local_output = settlement_tx.outputs().get(0).unwrap();
-        let to_local_output_data = settlement_tx.outputs_data().get(0).unwrap();
-        let to_remote_output = settlement_tx.outputs().get(1).unwrap();
-        let to_remote_output_data = settlement_tx.outputs_data().get(1).unwrap();
-        let args = commitment_tx
-            .outputs()
-            .get(0)
-            .unwrap()
-            .lock()
-            .args()
-            .raw_data();
-        let message = blake2b_256(
-            [
-                to_local_output.as_slice(),
-                to_local_output_data.as_slice(),
-                to_remote_output.as_slice(),
-                to_remote_output_data.as_slice(),
-                &args[0..36],
-            ]
-            .concat(),
-        );
+        let message = get_settlement_tx_message(&commitment_tx, &settlement_tx);
         let commitment_tx_partial_signature = sign_ctx.sign(message.as_slice())?;
 
         Ok(PartiallySignedCommitmentTransaction {
@@ -5061,10 +6863,71 @@ impl ChannelActorState {
             funding_tx_partial_signature,
             commitment_tx_partial_signature,
         )?;
-        self.sign_tx_to_consume_funding_cell(&tx)
+        self.sign_tx_to_consume_funding_cell(&tx)
+    }
+
+    /// Aggregate the partial signature from the peer with ours to sign the settlement
+    /// transaction of our commitment transaction, so that the commitment cell can be
+    /// settled without the peer after the channel is force closed.
+    pub fn sign_settlement_tx(
+        &self,
+        commitment_tx_partial_signature: PartialSignature,
+    ) -> Result<SettlementData, ProcessingChannelError> {
+        let (commitment_tx, settlement_tx) = self.build_commitment_and_settlement_tx(false);
+        let message = get_settlement_tx_message(&commitment_tx, &settlement_tx);
+
+        let sign_ctx = Musig2SignContext::from((self, false));
+        let partial_signature = sign_ctx.sign(message.as_slice())?;
+        let verify_ctx = Musig2VerifyContext::from((self, false));
+        let x_only_aggregated_pubkey = verify_ctx
+            .key_agg_ctx
+            .aggregated_pubkey::<Point>()
+            .serialize_xonly();
+        let aggregated_signature = aggregate_partial_signatures_for_msg(
+            message.as_slice(),
+            verify_ctx,
+            [commitment_tx_partial_signature, partial_signature],
+        )?;
+
+        Ok(SettlementData {
+            commitment_number: self.get_current_commitment_number(false),
+            x_only_aggregated_pubkey,
+            aggregated_signature,
+            outputs: settlement_tx.outputs().into_iter().collect(),
+            outputs_data: settlement_tx.outputs_data().into_iter().collect(),
+            tlcs: self.get_sweep_tlcs(),
+        })
     }
 }
 
+// The message signed by both parties to settle the commitment cell with the settlement transaction.
+fn get_settlement_tx_message(
+    commitment_tx: &TransactionView,
+    settlement_tx: &TransactionView,
+) -> [u8; 32] {
+    let to_local_output = settlement_tx.outputs().get(0).unwrap();
+    let to_local_output_data = settlement_tx.outputs_data().get(0).unwrap();
+    let to_remote_output = settlement_tx.outputs().get(1).unwrap();
+    let to_remote_output_data = settlement_tx.outputs_data().get(1).unwrap();
+    let args = commitment_tx
+        .outputs()
+        .get(0)
+        .unwrap()
+        .lock()
+        .args()
+        .raw_data();
+    blake2b_256(
+        [
+            to_local_output.as_slice(),
+            to_local_output_data.as_slice(),
+            to_remote_output.as_slice(),
+            to_remote_output_data.as_slice(),
+            &args[0..36],
+        ]
+        .concat(),
+    )
+}
+
 pub trait ChannelActorStateStore {
     fn get_channel_actor_state(&self, id: &Hash256) -> Option<ChannelActorState>;
     fn insert_channel_actor_state(&self, state: ChannelActorState);
@@ -5309,6 +7172,17 @@ pub struct TLC {
     pub onion_packet: Vec<u8>,
     /// The previous tlc id if this tlc is a part of a multi-tlc payment.
     pub previous_tlc: Option<(Hash256, TLCId)>,
+    /// The shared secret obtained while peeling the onion packet of a received tlc, which is
+    /// used to encrypt the error packet when this tlc is failed.
+    #[serde(default)]
+    pub shared_secret: [u8; 32],
+    /// The total amount of the multi-part payment this received tlc belongs to. It is only set
+    /// when this tlc is a part of the payment, the network actor settles it after all parts arrive.
+    pub total_amount: Option<u128>,
+    /// Whether this tlc is received as the last hop of a payment, it is failed by us
+    /// if the preimage of the payment is unknown.
+    #[serde(default)]
+    pub is_last_hop: bool,
 }
 
 impl TLC {
@@ -5339,6 +7213,19 @@ impl TLC {
         self.payment_hash.as_ref()[..20].try_into().unwrap()
     }
 
+    // Encode this tlc as an entry of the pending tlcs in the commitment lock args.
+    fn encode_htlc(&self, local_pubkey: &Pubkey, remote_pubkey: &Pubkey) -> Vec<u8> {
+        [
+            self.get_htlc_type().to_le_bytes().as_slice(),
+            self.amount.to_le_bytes().as_slice(),
+            self.get_hash().as_slice(),
+            local_pubkey.serialize().as_slice(),
+            remote_pubkey.serialize().as_slice(),
+            Since::from(self.lock_time).value().to_le_bytes().as_slice(),
+        ]
+        .concat()
+    }
+
     fn get_id(&self) -> u64 {
         match self.id {
             TLCId::Offered(id) => id,
@@ -5379,6 +7266,11 @@ impl DetailedTLCInfo {
         self.tlc.is_offered()
     }
 
+    /// The reason of removing this tlc, `None` if the tlc is not removed yet.
+    pub fn get_remove_reason(&self) -> Option<RemoveTlcReason> {
+        self.removed_at.as_ref().map(|(_, reason)| reason.clone())
+    }
+
     fn get_commitment_numbers(&self, local: bool) -> CommitmentNumbers {
         let am_i_sending_the_tlc = {
             if self.is_offered() {
@@ -5552,28 +7444,37 @@ impl InMemorySigner {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use crate::{
         ckb::contracts::{get_cell_deps, Contract},
         fiber::{
             channel::{
-                derive_revocation_pubkey, AddTlcCommand, ChannelCommand, ChannelCommandWithId,
-                RemoveTlcCommand, ShutdownCommand, DEFAULT_COMMITMENT_FEE_RATE,
+                derive_revocation_pubkey, AddTlcCommand, BumpFundingFeeCommand, ChannelCommand,
+                ChannelCommandWithId, RemoveTlcCommand, ShutdownCommand, SpliceCommand,
+                DEFAULT_COMMITMENT_FEE_RATE, DEFAULT_FEE_RATE,
             },
+            graph::NetworkGraphStateStore,
             hash_algorithm::HashAlgorithm,
             network::{AcceptChannelCommand, OpenChannelCommand},
             test_utils::{init_tracing, NetworkNode},
-            types::{Hash256, LockTime, RemoveTlcFulfill, RemoveTlcReason},
-            NetworkActorCommand, NetworkActorMessage,
+            types::{
+                FiberMessage, Hash256, LockTime, RemoveTlcFulfill, RemoveTlcReason, SpliceInit,
+            },
+            NetworkActorCommand, NetworkActorEvent, NetworkActorMessage,
         },
         NetworkServiceEvent,
     };
 
-    use super::{super::types::Privkey, derive_private_key, derive_tlc_pubkey, InMemorySigner};
+    use super::{
+        super::types::{DataLossProtect, Privkey, ReestablishChannel},
+        derive_private_key, derive_tlc_pubkey, AwaitingChannelReadyFlags, ChannelActorStateStore,
+        ChannelState, FundingTxCandidate, InMemorySigner, ShuttingDownFlags,
+        SigningCommitmentFlags, SpliceFlags,
+    };
     use ckb_jsonrpc_types::Status;
     use ckb_types::{
         core::FeeRate,
-        packed::{CellInput, Script, Transaction},
+        packed::{CellInput, OutPoint, Script, Transaction},
         prelude::{AsTransactionBuilder, Builder, Entity, Pack},
     };
     use ractor::call;
@@ -5692,80 +7593,289 @@ mod tests {
             })
             .await;
 
-        let message = |rpc_reply| {
-            NetworkActorMessage::Command(NetworkActorCommand::AcceptChannel(
-                AcceptChannelCommand {
-                    temp_channel_id: open_channel_result.channel_id,
-                    funding_amount: DEFAULT_CHANNEL_MINIMAL_CKB_AMOUNT as u128,
-                    shutdown_script: None,
-                },
-                rpc_reply,
-            ))
-        };
+        let message = |rpc_reply| {
+            NetworkActorMessage::Command(NetworkActorCommand::AcceptChannel(
+                AcceptChannelCommand {
+                    temp_channel_id: open_channel_result.channel_id,
+                    funding_amount: DEFAULT_CHANNEL_MINIMAL_CKB_AMOUNT as u128,
+                    shutdown_script: None,
+                },
+                rpc_reply,
+            ))
+        };
+
+        let _accept_channel_result = call!(node_b.network_actor, message)
+            .expect("node_b alive")
+            .expect("accept channel success");
+    }
+
+    #[tokio::test]
+    async fn test_create_public_channel() {
+        init_tracing();
+
+        let _span = tracing::info_span!("node", node = "test").entered();
+
+        let node_a_funding_amount = 100000000000;
+        let node_b_funding_amount = 6200000000;
+
+        let (_node_a, _node_b, _new_channel_id) = create_nodes_with_established_channel(
+            node_a_funding_amount,
+            node_b_funding_amount,
+            true,
+        )
+        .await;
+        // Wait for the channel announcement to be broadcasted
+        tokio::time::sleep(tokio::time::Duration::from_millis(5000)).await;
+        // FIXME: add assertion
+    }
+
+    #[tokio::test]
+    async fn test_stash_broadcast_messages() {
+        init_tracing();
+
+        let _span = tracing::info_span!("node", node = "test").entered();
+
+        let node_a_funding_amount = 100000000000;
+        let node_b_funding_amount = 6200000000;
+
+        let (node_a, _node_b, _new_channel_id) = create_nodes_with_established_channel(
+            node_a_funding_amount,
+            node_b_funding_amount,
+            true,
+        )
+        .await;
+
+        // Mark sync done for node_a after 1 second
+        node_a
+            .network_actor
+            .send_after(ractor::concurrency::Duration::from_secs(1), || {
+                NetworkActorMessage::new_command(NetworkActorCommand::MarkSyncingDone)
+            });
+
+        // Wait for the channel announcement to be broadcasted
+        tokio::time::sleep(tokio::time::Duration::from_millis(5000)).await;
+    }
+
+    async fn do_test_channel_commitment_tx_after_add_tlc(algorithm: HashAlgorithm) {
+        let [mut node_a, mut node_b] = NetworkNode::new_n_interconnected_nodes().await;
+
+        let node_a_funding_amount = 100000000000;
+        let node_b_funidng_amount = 6200000000;
+
+        let message = |rpc_reply| {
+            NetworkActorMessage::Command(NetworkActorCommand::OpenChannel(
+                OpenChannelCommand {
+                    peer_id: node_b.peer_id.clone(),
+                    public: false,
+                    shutdown_script: None,
+                    funding_amount: node_a_funding_amount,
+                    funding_udt_type_script: None,
+                    commitment_fee_rate: None,
+                    funding_fee_rate: None,
+                    tlc_locktime_expiry_delta: None,
+                    tlc_min_value: None,
+                    tlc_max_value: None,
+                    tlc_fee_proportional_millionths: None,
+                    max_num_of_accept_tlcs: None,
+                    max_tlc_value_in_flight: None,
+                },
+                rpc_reply,
+            ))
+        };
+        let open_channel_result = call!(node_a.network_actor, message)
+            .expect("node_a alive")
+            .expect("open channel success");
+
+        node_b
+            .expect_event(|event| match event {
+                NetworkServiceEvent::ChannelPendingToBeAccepted(peer_id, channel_id) => {
+                    println!("A channel ({:?}) to {:?} create", &channel_id, peer_id);
+                    assert_eq!(peer_id, &node_a.peer_id);
+                    true
+                }
+                _ => false,
+            })
+            .await;
+        let message = |rpc_reply| {
+            NetworkActorMessage::Command(NetworkActorCommand::AcceptChannel(
+                AcceptChannelCommand {
+                    temp_channel_id: open_channel_result.channel_id,
+                    funding_amount: node_b_funidng_amount,
+                    shutdown_script: None,
+                },
+                rpc_reply,
+            ))
+        };
+        let accept_channel_result = call!(node_b.network_actor, message)
+            .expect("node_b alive")
+            .expect("accept channel success");
+        accept_channel_result.new_channel_id
+    }
+
+    pub(crate) async fn create_nodes_with_established_channel(
+        node_a_funding_amount: u128,
+        node_b_funding_amount: u128,
+        public: bool,
+    ) -> (NetworkNode, NetworkNode, Hash256) {
+        let [mut node_a, mut node_b] = NetworkNode::new_n_interconnected_nodes().await;
+        let new_channel_id = open_and_accept_channel(
+            &mut node_a,
+            &mut node_b,
+            node_a_funding_amount,
+            node_b_funding_amount,
+            public,
+        )
+        .await;
+
+        node_a
+            .expect_event(|event| match event {
+                NetworkServiceEvent::ChannelReady(peer_id, channel_id, _funding_tx_hash) => {
+                    println!(
+                        "A channel ({:?}) to {:?} is now ready",
+                        &channel_id, &peer_id
+                    );
+                    assert_eq!(peer_id, &node_b.peer_id);
+                    assert_eq!(channel_id, &new_channel_id);
+                    true
+                }
+                _ => false,
+            })
+            .await;
+
+        node_b
+            .expect_event(|event| match event {
+                NetworkServiceEvent::ChannelReady(peer_id, channel_id, _funding_tx_hash) => {
+                    println!(
+                        "A channel ({:?}) to {:?} is now ready",
+                        &channel_id, &peer_id
+                    );
+                    assert_eq!(peer_id, &node_a.peer_id);
+                    assert_eq!(channel_id, &new_channel_id);
+                    true
+                }
+                _ => false,
+            })
+            .await;
+
+        let preimage = [1; 32];
+        let digest = algorithm.hash(&preimage);
+        let tlc_amount = 1000000000;
+
+        let add_tlc_result = call!(node_a.network_actor, |rpc_reply| {
+            NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
+                ChannelCommandWithId {
+                    channel_id: new_channel_id,
+                    command: ChannelCommand::AddTlc(
+                        AddTlcCommand {
+                            amount: tlc_amount,
+                            hash_algorithm: algorithm,
+                            payment_hash: Some(digest.into()),
+                            expiry: LockTime::new(100),
+                            preimage: None,
+                            onion_packet: vec![],
+                            previous_tlc: None,
+                        },
+                        rpc_reply,
+                    ),
+                },
+            ))
+        })
+        .expect("node_b alive")
+        .expect("successfully added tlc");
+
+        dbg!(&add_tlc_result);
+
+        // Since we currently automatically send a `CommitmentSigned` message
+        // after sending a `AddTlc` message, we can expect the `RemoteCommitmentSigned`
+        // to be received by node b.
+        let node_b_commitment_tx = node_b
+            .expect_to_process_event(|event| match event {
+                NetworkServiceEvent::RemoteCommitmentSigned(peer_id, channel_id, num, tx) => {
+                    println!(
+                        "Commitment tx (#{}) {:?} from {:?} for channel {:?} received",
+                        num, &tx, peer_id, channel_id
+                    );
+                    assert_eq!(peer_id, &node_a.peer_id);
+                    assert_eq!(channel_id, &new_channel_id);
+                    Some(tx.clone())
+                }
+                _ => None,
+            })
+            .await;
+
+        let remove_tlc_result = call!(node_b.network_actor, |rpc_reply| {
+            NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
+                ChannelCommandWithId {
+                    channel_id: new_channel_id,
+                    command: ChannelCommand::RemoveTlc(
+                        RemoveTlcCommand {
+                            id: add_tlc_result.tlc_id,
+                            reason: RemoveTlcReason::RemoveTlcFulfill(RemoveTlcFulfill {
+                                payment_preimage: preimage.into(),
+                            }),
+                        },
+                        rpc_reply,
+                    ),
+                },
+            ))
+        })
+        .expect("node_b alive")
+        .expect("successfully removed tlc");
+
+        dbg!(&remove_tlc_result);
+
+        // Since we currently automatically send a `CommitmentSigned` message
+        // after sending a `RemoveTlc` message, we can expect the `RemoteCommitmentSigned`
+        // to be received by node a.
+        let node_a_commitment_tx = node_a
+            .expect_to_process_event(|event| match event {
+                NetworkServiceEvent::RemoteCommitmentSigned(peer_id, channel_id, num, tx) => {
+                    println!(
+                        "Commitment tx (#{}) {:?} from {:?} for channel {:?} received",
+                        num, &tx, peer_id, channel_id
+                    );
+                    assert_eq!(peer_id, &node_b.peer_id);
+                    assert_eq!(channel_id, &new_channel_id);
+                    Some(tx.clone())
+                }
+                _ => None,
+            })
+            .await;
+
+        assert_eq!(
+            node_a.submit_tx(node_a_commitment_tx.clone()).await,
+            Status::Committed
+        );
 
-        let _accept_channel_result = call!(node_b.network_actor, message)
-            .expect("node_b alive")
-            .expect("accept channel success");
+        assert_eq!(
+            node_b.submit_tx(node_b_commitment_tx.clone()).await,
+            Status::Committed
+        );
     }
 
     #[tokio::test]
-    async fn test_create_public_channel() {
-        init_tracing();
-
-        let _span = tracing::info_span!("node", node = "test").entered();
-
-        let node_a_funding_amount = 100000000000;
-        let node_b_funding_amount = 6200000000;
-
-        let (_node_a, _node_b, _new_channel_id) = create_nodes_with_established_channel(
-            node_a_funding_amount,
-            node_b_funding_amount,
-            true,
-        )
-        .await;
-        // Wait for the channel announcement to be broadcasted
-        tokio::time::sleep(tokio::time::Duration::from_millis(5000)).await;
-        // FIXME: add assertion
+    async fn test_channel_commitment_tx_after_add_tlc_ckbhash() {
+        do_test_channel_commitment_tx_after_add_tlc(HashAlgorithm::CkbHash).await
     }
 
     #[tokio::test]
-    async fn test_stash_broadcast_messages() {
-        init_tracing();
-
-        let _span = tracing::info_span!("node", node = "test").entered();
-
-        let node_a_funding_amount = 100000000000;
-        let node_b_funding_amount = 6200000000;
-
-        let (node_a, _node_b, _new_channel_id) = create_nodes_with_established_channel(
-            node_a_funding_amount,
-            node_b_funding_amount,
-            true,
-        )
-        .await;
-
-        // Mark sync done for node_a after 1 second
-        node_a
-            .network_actor
-            .send_after(ractor::concurrency::Duration::from_secs(1), || {
-                NetworkActorMessage::new_command(NetworkActorCommand::MarkSyncingDone)
-            });
-
-        // Wait for the channel announcement to be broadcasted
-        tokio::time::sleep(tokio::time::Duration::from_millis(5000)).await;
+    async fn test_channel_commitment_tx_after_add_tlc_sha256() {
+        do_test_channel_commitment_tx_after_add_tlc(HashAlgorithm::Sha256).await
     }
 
-    async fn do_test_channel_commitment_tx_after_add_tlc(algorithm: HashAlgorithm) {
-        let [mut node_a, mut node_b] = NetworkNode::new_n_interconnected_nodes().await;
-
-        let node_a_funding_amount = 100000000000;
-        let node_b_funidng_amount = 6200000000;
-
+    // Open a channel from node_a to node_b, and return the channel id after it is accepted.
+    async fn open_and_accept_channel(
+        node_a: &mut NetworkNode,
+        node_b: &mut NetworkNode,
+        node_a_funding_amount: u128,
+        node_b_funding_amount: u128,
+        public: bool,
+    ) -> Hash256 {
         let message = |rpc_reply| {
             NetworkActorMessage::Command(NetworkActorCommand::OpenChannel(
                 OpenChannelCommand {
                     peer_id: node_b.peer_id.clone(),
-                    public: false,
+                    public,
                     shutdown_script: None,
                     funding_amount: node_a_funding_amount,
                     funding_udt_type_script: None,
@@ -5799,7 +7909,7 @@ mod tests {
             NetworkActorMessage::Command(NetworkActorCommand::AcceptChannel(
                 AcceptChannelCommand {
                     temp_channel_id: open_channel_result.channel_id,
-                    funding_amount: node_b_funidng_amount,
+                    funding_amount: node_b_funding_amount,
                     shutdown_script: None,
                 },
                 rpc_reply,
@@ -5839,11 +7949,442 @@ mod tests {
                 _ => false,
             })
             .await;
+        (node_a, node_b, new_channel_id)
+    }
 
-        let preimage = [1; 32];
-        let digest = algorithm.hash(&preimage);
+    #[tokio::test]
+    async fn test_bump_funding_fee_after_channel_ready() {
+        let node_a_funding_amount = 100000000000;
+        let node_b_funding_amount = 6200000000;
+
+        let (node_a, _node_b, new_channel_id) = create_nodes_with_established_channel(
+            node_a_funding_amount,
+            node_b_funding_amount,
+            false,
+        )
+        .await;
+
+        let result = call!(node_a.network_actor, |rpc_reply| {
+            NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
+                ChannelCommandWithId {
+                    channel_id: new_channel_id,
+                    command: ChannelCommand::BumpFundingFee(
+                        BumpFundingFeeCommand {
+                            funding_fee_rate: u64::MAX,
+                        },
+                        rpc_reply,
+                    ),
+                },
+            ))
+        })
+        .expect("node_a alive");
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bump_funding_fee_before_channel_ready() {
+        init_tracing();
+
+        let [mut node_a, mut node_b] = NetworkNode::new_n_interconnected_nodes().await;
+        // Keep the funding transaction pending so that it can be replaced.
+        node_a.hold_tx_confirmations();
+        node_b.hold_tx_confirmations();
+        let new_channel_id =
+            open_and_accept_channel(&mut node_a, &mut node_b, 100000000000, 6200000000, false)
+                .await;
+
+        // Both parties have signed the funding transaction and can replace it now.
+        let mut signed = false;
+        for _ in 0..50 {
+            signed = [&node_a, &node_b].iter().all(|node| {
+                node.store
+                    .get_channel_actor_state(&new_channel_id)
+                    .is_some_and(|state| state.check_funding_rbf(u64::MAX).is_ok())
+            });
+            if signed {
+                break;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        }
+        assert!(signed, "The funding transaction is not signed");
+        let old_funding_fee_rate = node_a
+            .store
+            .get_channel_actor_state(&new_channel_id)
+            .expect("channel state")
+            .funding_fee_rate;
+        let new_funding_fee_rate = old_funding_fee_rate * 2;
+
+        call!(node_a.network_actor, |rpc_reply| {
+            NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
+                ChannelCommandWithId {
+                    channel_id: new_channel_id,
+                    command: ChannelCommand::BumpFundingFee(
+                        BumpFundingFeeCommand {
+                            funding_fee_rate: new_funding_fee_rate,
+                        },
+                        rpc_reply,
+                    ),
+                },
+            ))
+        })
+        .expect("node_a alive")
+        .expect("successfully bumped funding fee");
+
+        // The replacement is signed by both parties, and the replaced funding
+        // transaction is kept as a candidate until one of them is confirmed.
+        let mut replaced = false;
+        for _ in 0..50 {
+            replaced = [&node_a, &node_b].iter().all(|node| {
+                node.store
+                    .get_channel_actor_state(&new_channel_id)
+                    .is_some_and(|state| {
+                        state.funding_fee_rate == new_funding_fee_rate
+                            && state.funding_tx_candidates.len() == 1
+                            && state.funding_tx_candidates[0].funding_fee_rate
+                                == old_funding_fee_rate
+                            && state.check_funding_rbf(u64::MAX).is_ok()
+                    })
+            });
+            if replaced {
+                break;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        }
+        assert!(replaced, "The funding transaction is not replaced");
+        let state = node_a
+            .store
+            .get_channel_actor_state(&new_channel_id)
+            .expect("channel state");
+        let old_funding_tx = state.funding_tx_candidates[0]
+            .funding_tx
+            .clone()
+            .into_view();
+        let new_funding_tx = state.get_funding_transaction().clone().into_view();
+        assert!(
+            new_funding_tx
+                .input_pts_iter()
+                .any(|input| old_funding_tx.input_pts_iter().any(|x| x == input)),
+            "The replacing funding transaction must conflict with the replaced one"
+        );
+
+        node_a.release_tx_confirmations();
+        node_b.release_tx_confirmations();
+        for node in [&mut node_a, &mut node_b] {
+            node.expect_event(|event| {
+                matches!(event, NetworkServiceEvent::ChannelReady(_, channel_id, _) if channel_id == &new_channel_id)
+            })
+            .await;
+            let state = node
+                .store
+                .get_channel_actor_state(&new_channel_id)
+                .expect("channel state");
+            assert_eq!(state.funding_fee_rate, new_funding_fee_rate);
+            assert!(state.funding_tx_candidates.is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replaced_funding_transaction_confirmed() {
+        init_tracing();
+
+        let (node_a, _node_b, new_channel_id) =
+            create_nodes_with_established_channel(100000000000, 6200000000, false).await;
+        let mut state = node_a
+            .store
+            .get_channel_actor_state(&new_channel_id)
+            .expect("channel state");
+        let old_funding_tx = state.funding_tx.clone().expect("funding tx");
+        let old_commitment_tx = state.latest_commitment_transaction.clone();
+        let old_funding_fee_rate = state.funding_fee_rate;
+        let old_outpoint = state.get_funding_transaction_outpoint();
+
+        // Pretend that the funding transaction has been replaced but not confirmed yet.
+        state.funding_tx_candidates.push(FundingTxCandidate {
+            funding_tx: old_funding_tx.clone(),
+            commitment_tx: old_commitment_tx.clone(),
+            funding_fee_rate: old_funding_fee_rate,
+        });
+        state.funding_tx = Some(
+            old_funding_tx
+                .clone()
+                .as_builder()
+                .raw(
+                    old_funding_tx
+                        .raw()
+                        .as_builder()
+                        .version(1u32.pack())
+                        .build(),
+                )
+                .build(),
+        );
+        state.latest_commitment_transaction = None;
+        state.funding_fee_rate = old_funding_fee_rate * 2;
+        state.state = ChannelState::AwaitingChannelReady(AwaitingChannelReadyFlags::empty());
+        assert_ne!(state.get_funding_transaction_outpoint(), old_outpoint);
+
+        // The replaced funding transaction is confirmed instead of the replacement.
+        state.on_funding_candidate_confirmed(&old_outpoint);
+        assert_eq!(state.get_funding_transaction_outpoint(), old_outpoint);
+        assert_eq!(
+            state
+                .latest_commitment_transaction
+                .map(|tx| tx.calc_tx_hash()),
+            old_commitment_tx.map(|tx| tx.calc_tx_hash())
+        );
+        assert_eq!(state.funding_fee_rate, old_funding_fee_rate);
+        assert!(state.funding_tx_candidates.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_abort_funding_rbf_while_signing_commitment() {
+        init_tracing();
+
+        let (node_a, _node_b, new_channel_id) =
+            create_nodes_with_established_channel(100000000000, 6200000000, false).await;
+        let mut state = node_a
+            .store
+            .get_channel_actor_state(&new_channel_id)
+            .expect("channel state");
+        let old_funding_tx = state.funding_tx.clone().expect("funding tx");
+        let old_commitment_tx = state.latest_commitment_transaction.clone();
+        let old_funding_fee_rate = state.funding_fee_rate;
+        let old_outpoint = state.get_funding_transaction_outpoint();
+
+        // The peer aborts the replacement after the commitment transaction is signed,
+        // but before the replacing funding transaction is signed.
+        state.funding_tx_candidates.push(FundingTxCandidate {
+            funding_tx: old_funding_tx,
+            commitment_tx: old_commitment_tx.clone(),
+            funding_fee_rate: old_funding_fee_rate,
+        });
+        state.funding_tx = Some(Default::default());
+        state.latest_commitment_transaction = None;
+        state.funding_fee_rate = old_funding_fee_rate * 2;
+        state.state =
+            ChannelState::SigningCommitment(SigningCommitmentFlags::OUR_COMMITMENT_SIGNED_SENT);
+
+        state.abort_funding_rbf();
+        assert_eq!(
+            state.state,
+            ChannelState::AwaitingChannelReady(AwaitingChannelReadyFlags::empty())
+        );
+        assert_eq!(state.get_funding_transaction_outpoint(), old_outpoint);
+        assert_eq!(
+            state
+                .latest_commitment_transaction
+                .map(|tx| tx.calc_tx_hash()),
+            old_commitment_tx.map(|tx| tx.calc_tx_hash())
+        );
+        assert_eq!(state.funding_fee_rate, old_funding_fee_rate);
+        assert!(state.funding_tx_candidates.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_splice_channel_after_channel_ready() {
+        let node_a_funding_amount = 100000000000;
+        let node_b_funding_amount = 6200000000;
+
+        let (node_a, _node_b, new_channel_id) = create_nodes_with_established_channel(
+            node_a_funding_amount,
+            node_b_funding_amount,
+            false,
+        )
+        .await;
+
+        // The fee rate is too low to splice the channel.
+        let result = call!(node_a.network_actor, |rpc_reply| {
+            NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
+                ChannelCommandWithId {
+                    channel_id: new_channel_id,
+                    command: ChannelCommand::Splice(
+                        SpliceCommand {
+                            funding_amount: node_a_funding_amount * 2,
+                            funding_fee_rate: 0,
+                        },
+                        rpc_reply,
+                    ),
+                },
+            ))
+        })
+        .expect("node_a alive");
+        assert!(result.is_err());
+
+        // The funding amount from the splice_channel RPC can't be held by the funding cell.
+        let result = call!(node_a.network_actor, |rpc_reply| {
+            NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
+                ChannelCommandWithId {
+                    channel_id: new_channel_id,
+                    command: ChannelCommand::Splice(
+                        SpliceCommand {
+                            funding_amount: u128::MAX,
+                            funding_fee_rate: DEFAULT_FEE_RATE,
+                        },
+                        rpc_reply,
+                    ),
+                },
+            ))
+        })
+        .expect("node_a alive");
+        assert!(result.is_err_and(|err| err.contains("exceeds the maximal amount")));
+
+        let result = call!(node_a.network_actor, |rpc_reply| {
+            NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
+                ChannelCommandWithId {
+                    channel_id: new_channel_id,
+                    command: ChannelCommand::Splice(
+                        SpliceCommand {
+                            funding_amount: node_a_funding_amount * 2,
+                            funding_fee_rate: DEFAULT_FEE_RATE,
+                        },
+                        rpc_reply,
+                    ),
+                },
+            ))
+        })
+        .expect("node_a alive");
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_splice_init_with_overflowing_funding_amount() {
+        let node_a_funding_amount = 100000000000;
+        let node_b_funding_amount = 6200000000;
+
+        let (node_a, node_b, new_channel_id) = create_nodes_with_established_channel(
+            node_a_funding_amount,
+            node_b_funding_amount,
+            false,
+        )
+        .await;
+
+        // node_b proposes a splice which node_a can't accept.
+        let state = node_a
+            .store
+            .get_channel_actor_state(&new_channel_id)
+            .expect("channel state exists");
+        node_a
+            .network_actor
+            .send_message(NetworkActorMessage::Event(NetworkActorEvent::PeerMessage(
+                node_b.peer_id.clone(),
+                FiberMessage::splice_init(SpliceInit {
+                    channel_id: new_channel_id,
+                    funding_amount: u128::MAX,
+                    funding_fee_rate: DEFAULT_FEE_RATE,
+                    funding_nonce: state.get_local_nonce(),
+                    local_nonce: state.get_local_nonce(),
+                    channel_announcement_nonce: state.get_local_nonce(),
+                }),
+            )))
+            .expect("node_a alive");
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+
+        let state = node_a
+            .store
+            .get_channel_actor_state(&new_channel_id)
+            .expect("channel state exists");
+        assert!(state.splice.is_none());
+        assert_eq!(state.state, ChannelState::ChannelReady());
+        assert_eq!(state.to_local_amount, node_a_funding_amount);
+
+        // The channel is still usable.
+        let result = call!(node_a.network_actor, |rpc_reply| {
+            NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
+                ChannelCommandWithId {
+                    channel_id: new_channel_id,
+                    command: ChannelCommand::Splice(
+                        SpliceCommand {
+                            funding_amount: node_a_funding_amount * 2,
+                            funding_fee_rate: DEFAULT_FEE_RATE,
+                        },
+                        rpc_reply,
+                    ),
+                },
+            ))
+        })
+        .expect("node_a alive");
+        assert!(result.is_ok());
+    }
+
+    async fn wait_for_graph_channel_capacity(node: &NetworkNode, outpoint: &OutPoint) -> u128 {
+        for _ in 0..50 {
+            if let Some(channel) = node.store.get_channels(Some(outpoint.clone())).first() {
+                return channel.capacity();
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        }
+        panic!("Channel {:?} is not found in the network graph", outpoint);
+    }
+
+    #[tokio::test]
+    async fn test_splice_channel_end_to_end() {
+        init_tracing();
+
+        let node_a_funding_amount = 100000000000;
+        let node_b_funding_amount = 6200000000;
         let tlc_amount = 1000000000;
 
+        let (mut node_a, mut node_b, new_channel_id) = create_nodes_with_established_channel(
+            node_a_funding_amount,
+            node_b_funding_amount,
+            true,
+        )
+        .await;
+        let old_state_a = node_a
+            .store
+            .get_channel_actor_state(&new_channel_id)
+            .expect("node_a channel state");
+        let old_state_b = node_b
+            .store
+            .get_channel_actor_state(&new_channel_id)
+            .expect("node_b channel state");
+        let old_outpoint = old_state_a.get_funding_transaction_outpoint();
+        let old_capacity = wait_for_graph_channel_capacity(&node_a, &old_outpoint).await;
+
+        // Keep the splice transaction pending until the tlc is settled.
+        node_a.hold_tx_confirmations();
+        node_b.hold_tx_confirmations();
+
+        let splice_amount = old_state_a.to_local_amount * 2;
+        call!(node_a.network_actor, |rpc_reply| {
+            NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
+                ChannelCommandWithId {
+                    channel_id: new_channel_id,
+                    command: ChannelCommand::Splice(
+                        SpliceCommand {
+                            funding_amount: splice_amount,
+                            funding_fee_rate: DEFAULT_FEE_RATE,
+                        },
+                        rpc_reply,
+                    ),
+                },
+            ))
+        })
+        .expect("node_a alive")
+        .expect("successfully spliced channel");
+
+        // Both parties have signed the splice transaction after TxUpdate and TxSignatures.
+        let mut splice_signed = false;
+        for _ in 0..50 {
+            splice_signed = [&node_a, &node_b].iter().all(|node| {
+                node.store
+                    .get_channel_actor_state(&new_channel_id)
+                    .and_then(|state| state.splice)
+                    .is_some_and(|splice| {
+                        splice
+                            .flags
+                            .contains(SpliceFlags::COMMITMENT_SIGNED_SENT | SpliceFlags::TX_SIGNED)
+                            && splice.funding_tx.is_some()
+                    })
+            });
+            if splice_signed {
+                break;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        }
+        assert!(splice_signed, "The splice transaction is not signed");
+
+        // The channel is still usable while the splice transaction is pending.
+        let preimage = [2; 32];
         let add_tlc_result = call!(node_a.network_actor, |rpc_reply| {
             NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
                 ChannelCommandWithId {
@@ -5851,8 +8392,8 @@ mod tests {
                     command: ChannelCommand::AddTlc(
                         AddTlcCommand {
                             amount: tlc_amount,
-                            hash_algorithm: algorithm,
-                            payment_hash: Some(digest.into()),
+                            hash_algorithm: HashAlgorithm::CkbHash,
+                            payment_hash: Some(HashAlgorithm::CkbHash.hash(preimage).into()),
                             expiry: LockTime::new(100),
                             preimage: None,
                             onion_packet: vec![],
@@ -5863,30 +8404,12 @@ mod tests {
                 },
             ))
         })
-        .expect("node_b alive")
+        .expect("node_a alive")
         .expect("successfully added tlc");
 
-        dbg!(&add_tlc_result);
-
-        // Since we currently automatically send a `CommitmentSigned` message
-        // after sending a `AddTlc` message, we can expect the `RemoteCommitmentSigned`
-        // to be received by node b.
-        let node_b_commitment_tx = node_b
-            .expect_to_process_event(|event| match event {
-                NetworkServiceEvent::RemoteCommitmentSigned(peer_id, channel_id, num, tx) => {
-                    println!(
-                        "Commitment tx (#{}) {:?} from {:?} for channel {:?} received",
-                        num, &tx, peer_id, channel_id
-                    );
-                    assert_eq!(peer_id, &node_a.peer_id);
-                    assert_eq!(channel_id, &new_channel_id);
-                    Some(tx.clone())
-                }
-                _ => None,
-            })
-            .await;
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
 
-        let remove_tlc_result = call!(node_b.network_actor, |rpc_reply| {
+        call!(node_b.network_actor, |rpc_reply| {
             NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
                 ChannelCommandWithId {
                     channel_id: new_channel_id,
@@ -5905,133 +8428,72 @@ mod tests {
         .expect("node_b alive")
         .expect("successfully removed tlc");
 
-        dbg!(&remove_tlc_result);
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
 
-        // Since we currently automatically send a `CommitmentSigned` message
-        // after sending a `RemoveTlc` message, we can expect the `RemoteCommitmentSigned`
-        // to be received by node a.
-        let node_a_commitment_tx = node_a
+        let state_a = node_a
+            .store
+            .get_channel_actor_state(&new_channel_id)
+            .expect("node_a channel state");
+        assert_eq!(state_a.get_funding_transaction_outpoint(), old_outpoint);
+        assert!(state_a.splice.is_some());
+        assert!(!state_a.any_tlc_pending());
+
+        // SpliceLocked is exchanged after the splice transaction is confirmed.
+        node_a.release_tx_confirmations();
+        node_b.release_tx_confirmations();
+
+        let new_outpoint = node_a
             .expect_to_process_event(|event| match event {
-                NetworkServiceEvent::RemoteCommitmentSigned(peer_id, channel_id, num, tx) => {
-                    println!(
-                        "Commitment tx (#{}) {:?} from {:?} for channel {:?} received",
-                        num, &tx, peer_id, channel_id
-                    );
+                NetworkServiceEvent::ChannelSpliced(peer_id, channel_id, outpoint) => {
                     assert_eq!(peer_id, &node_b.peer_id);
                     assert_eq!(channel_id, &new_channel_id);
-                    Some(tx.clone())
+                    Some(outpoint.clone())
                 }
                 _ => None,
             })
             .await;
-
-        assert_eq!(
-            node_a.submit_tx(node_a_commitment_tx.clone()).await,
-            Status::Committed
-        );
-
-        assert_eq!(
-            node_b.submit_tx(node_b_commitment_tx.clone()).await,
-            Status::Committed
-        );
-    }
-
-    #[tokio::test]
-    async fn test_channel_commitment_tx_after_add_tlc_ckbhash() {
-        do_test_channel_commitment_tx_after_add_tlc(HashAlgorithm::CkbHash).await
-    }
-
-    #[tokio::test]
-    async fn test_channel_commitment_tx_after_add_tlc_sha256() {
-        do_test_channel_commitment_tx_after_add_tlc(HashAlgorithm::Sha256).await
-    }
-
-    async fn create_nodes_with_established_channel(
-        node_a_funding_amount: u128,
-        node_b_funding_amount: u128,
-        public: bool,
-    ) -> (NetworkNode, NetworkNode, Hash256) {
-        let [mut node_a, mut node_b] = NetworkNode::new_n_interconnected_nodes().await;
-
-        let message = |rpc_reply| {
-            NetworkActorMessage::Command(NetworkActorCommand::OpenChannel(
-                OpenChannelCommand {
-                    peer_id: node_b.peer_id.clone(),
-                    public,
-                    shutdown_script: None,
-                    funding_amount: node_a_funding_amount,
-                    funding_udt_type_script: None,
-                    commitment_fee_rate: None,
-                    funding_fee_rate: None,
-                    tlc_locktime_expiry_delta: None,
-                    tlc_min_value: None,
-                    tlc_max_value: None,
-                    tlc_fee_proportional_millionths: None,
-                    max_num_of_accept_tlcs: None,
-                    max_tlc_value_in_flight: None,
-                },
-                rpc_reply,
-            ))
-        };
-        let open_channel_result = call!(node_a.network_actor, message)
-            .expect("node_a alive")
-            .expect("open channel success");
-
         node_b
             .expect_event(|event| match event {
-                NetworkServiceEvent::ChannelPendingToBeAccepted(peer_id, channel_id) => {
-                    println!("A channel ({:?}) to {:?} create", &channel_id, peer_id);
+                NetworkServiceEvent::ChannelSpliced(peer_id, channel_id, outpoint) => {
                     assert_eq!(peer_id, &node_a.peer_id);
-                    true
-                }
-                _ => false,
-            })
-            .await;
-        let message = |rpc_reply| {
-            NetworkActorMessage::Command(NetworkActorCommand::AcceptChannel(
-                AcceptChannelCommand {
-                    temp_channel_id: open_channel_result.channel_id,
-                    funding_amount: node_b_funding_amount,
-                    shutdown_script: None,
-                },
-                rpc_reply,
-            ))
-        };
-        let accept_channel_result = call!(node_b.network_actor, message)
-            .expect("node_b alive")
-            .expect("accept channel success");
-        let new_channel_id = accept_channel_result.new_channel_id;
-
-        node_a
-            .expect_event(|event| match event {
-                NetworkServiceEvent::ChannelReady(peer_id, channel_id, _funding_tx_hash) => {
-                    println!(
-                        "A channel ({:?}) to {:?} is now ready",
-                        &channel_id, &peer_id
-                    );
-                    assert_eq!(peer_id, &node_b.peer_id);
                     assert_eq!(channel_id, &new_channel_id);
+                    assert_eq!(outpoint, &new_outpoint);
                     true
                 }
                 _ => false,
             })
             .await;
+        assert_ne!(new_outpoint, old_outpoint);
+
+        // The tlc settled during the splice is carried over to the spliced funding cell.
+        let state_a = node_a
+            .store
+            .get_channel_actor_state(&new_channel_id)
+            .expect("node_a channel state");
+        let state_b = node_b
+            .store
+            .get_channel_actor_state(&new_channel_id)
+            .expect("node_b channel state");
+        assert!(state_a.splice.is_none());
+        assert!(state_b.splice.is_none());
+        assert_eq!(state_a.get_funding_transaction_outpoint(), new_outpoint);
+        assert_eq!(state_b.get_funding_transaction_outpoint(), new_outpoint);
+        assert_eq!(state_a.to_local_amount, splice_amount - tlc_amount);
+        assert_eq!(state_a.to_remote_amount, state_b.to_local_amount);
+        assert_eq!(
+            state_b.to_local_amount,
+            old_state_b.to_local_amount + tlc_amount
+        );
+        assert_eq!(state_b.to_remote_amount, state_a.to_local_amount);
 
-        node_b
-            .expect_event(|event| match event {
-                NetworkServiceEvent::ChannelReady(peer_id, channel_id, _funding_tx_hash) => {
-                    println!(
-                        "A channel ({:?}) to {:?} is now ready",
-                        &channel_id, &peer_id
-                    );
-                    assert_eq!(peer_id, &node_a.peer_id);
-                    assert_eq!(channel_id, &new_channel_id);
-                    true
-                }
-                _ => false,
-            })
-            .await;
-        (node_a, node_b, new_channel_id)
+        // The channel is moved to the spliced funding cell in the network graph,
+        // with the capacity of the spliced funding cell.
+        let new_capacity = wait_for_graph_channel_capacity(&node_a, &new_outpoint).await;
+        assert_eq!(
+            new_capacity,
+            old_capacity + splice_amount - old_state_a.to_local_amount
+        );
+        assert!(node_a.store.get_channels(Some(old_outpoint)).is_empty());
     }
 
     async fn do_test_remove_tlc_with_wrong_hash_algorithm(
@@ -6724,4 +9186,277 @@ mod tests {
             })
             .await;
     }
+
+    #[tokio::test]
+    async fn test_reconnect_peer_with_channel_automatically() {
+        init_tracing();
+
+        let (mut node_a, node_b, _new_channel_id) =
+            create_nodes_with_established_channel(100000000000, 6200000000, false).await;
+
+        // node_b drops the connection, node_a dialed node_b and knows its address.
+        node_b
+            .network_actor
+            .send_message(NetworkActorMessage::new_command(
+                NetworkActorCommand::DisconnectPeer(node_a.peer_id.clone()),
+            ))
+            .expect("node_b alive");
+        node_a
+            .expect_event(|event| {
+                matches!(event, NetworkServiceEvent::PeerDisConnected(peer_id, _) if peer_id == &node_b.peer_id)
+            })
+            .await;
+
+        // The peer is reconnected after the initial reconnection delay.
+        tokio::time::sleep(tokio::time::Duration::from_secs(6)).await;
+        node_a
+            .expect_event(|event| {
+                matches!(event, NetworkServiceEvent::PeerConnected(peer_id, _) if peer_id == &node_b.peer_id)
+            })
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_not_reconnect_peer_disconnected_by_operator() {
+        init_tracing();
+
+        let (mut node_a, node_b, _new_channel_id) =
+            create_nodes_with_established_channel(100000000000, 6200000000, false).await;
+
+        node_a
+            .network_actor
+            .send_message(NetworkActorMessage::new_command(
+                NetworkActorCommand::DisconnectPeer(node_b.peer_id.clone()),
+            ))
+            .expect("node_a alive");
+        node_a
+            .expect_event(|event| {
+                matches!(event, NetworkServiceEvent::PeerDisConnected(peer_id, _) if peer_id == &node_b.peer_id)
+            })
+            .await;
+
+        // The disconnection requested by the operator is not undone automatically.
+        tokio::time::sleep(tokio::time::Duration::from_secs(6)).await;
+        while let Ok(event) = node_a.event_emitter.try_recv() {
+            assert!(
+                !matches!(event, NetworkServiceEvent::PeerConnected(ref peer_id, _) if peer_id == &node_b.peer_id),
+                "Peer disconnected by the operator is reconnected"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reestablish_channel_from_stale_state() {
+        init_tracing();
+
+        let (mut node_a, mut node_b, new_channel_id) =
+            create_nodes_with_established_channel(100000000000, 6200000000, false).await;
+        let stale_state = node_a
+            .store
+            .get_channel_actor_state(&new_channel_id)
+            .expect("channel state");
+
+        // node_a revokes its commitment transaction after the backup is taken.
+        node_b
+            .network_actor
+            .send_message(NetworkActorMessage::Command(
+                NetworkActorCommand::ControlFiberChannel(ChannelCommandWithId {
+                    channel_id: new_channel_id,
+                    command: ChannelCommand::CommitmentSigned(),
+                }),
+            ))
+            .expect("node_b alive");
+        node_b
+            .expect_event(|event| {
+                matches!(event, NetworkServiceEvent::RevokeAndAckReceived(peer_id, channel_id, ..) if peer_id == &node_a.peer_id && channel_id == &new_channel_id)
+            })
+            .await;
+
+        // Restart node_a from the stale backup, it reconnects to node_b automatically.
+        node_a.stop().await;
+        node_a.store.insert_channel_actor_state(stale_state);
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        node_a.start().await;
+        node_a
+            .expect_event(|event| {
+                matches!(event, NetworkServiceEvent::PeerConnected(peer_id, _) if peer_id == &node_b.peer_id)
+            })
+            .await;
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+
+        // node_a finds itself behind and asks node_b to close the channel with its latest commitment transaction.
+        let state_a = node_a
+            .store
+            .get_channel_actor_state(&new_channel_id)
+            .expect("channel state");
+        assert!(state_a.data_loss_detected);
+        let state_b = node_b
+            .store
+            .get_channel_actor_state(&new_channel_id)
+            .expect("channel state");
+        assert!(matches!(
+            state_b.state,
+            ChannelState::ShuttingDown(flags)
+                if flags.contains(ShuttingDownFlags::WAITING_COMMITMENT_CONFIRMATION)
+        ));
+
+        // The stale commitment transaction of node_a must not be broadcasted.
+        let result = call!(node_a.network_actor, |rpc_reply| {
+            NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
+                ChannelCommandWithId {
+                    channel_id: new_channel_id,
+                    command: ChannelCommand::Shutdown(
+                        ShutdownCommand {
+                            close_script: Script::default().as_builder().build(),
+                            fee_rate: FeeRate::from_u64(DEFAULT_COMMITMENT_FEE_RATE),
+                            force: true,
+                        },
+                        rpc_reply,
+                    ),
+                },
+            ))
+        })
+        .expect("node_a alive");
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reestablish_channel_claiming_newer_state_without_secret() {
+        init_tracing();
+
+        let (node_a, _node_b, new_channel_id) =
+            create_nodes_with_established_channel(100000000000, 6200000000, false).await;
+        let state_a = node_a
+            .store
+            .get_channel_actor_state(&new_channel_id)
+            .expect("channel state");
+        let claimed_commitment_number = state_a.get_remote_commitment_number() + 1;
+        let reestablish_channel = |remote_commitment_secret| ReestablishChannel {
+            channel_id: new_channel_id,
+            local_commitment_number: claimed_commitment_number,
+            remote_commitment_number: state_a.get_local_commitment_number(),
+            data_loss_protect: Some(DataLossProtect {
+                remote_commitment_secret,
+                remote_commitment_point: Some(
+                    state_a.get_local_commitment_point(claimed_commitment_number),
+                ),
+            }),
+        };
+
+        // The peer claims to be one step ahead of us, which is not proven by a commitment point.
+        assert!(state_a
+            .check_data_loss(&reestablish_channel(Hash256::default()))
+            .is_err());
+        assert!(state_a
+            .check_data_loss(&reestablish_channel(
+                state_a
+                    .signer
+                    .get_commitment_secret(claimed_commitment_number)
+                    .into()
+            ))
+            .is_err());
+
+        // Only the secret of our commitment point revoked by the claimed RevokeAndAck proves it.
+        assert!(state_a
+            .check_data_loss(&reestablish_channel(
+                state_a
+                    .signer
+                    .get_commitment_secret(claimed_commitment_number - 1)
+                    .into()
+            ))
+            .expect("valid proof"));
+    }
+
+    #[tokio::test]
+    async fn test_load_channel_state_saved_by_older_versions() {
+        init_tracing();
+
+        // Removes the fields from all the nested objects, returns the number of removed fields.
+        fn remove_fields(value: &mut serde_json::Value, fields: &[&str]) -> usize {
+            match value {
+                serde_json::Value::Object(object) => {
+                    let removed = fields
+                        .iter()
+                        .filter(|field| object.remove(**field).is_some())
+                        .count();
+                    removed
+                        + object
+                            .values_mut()
+                            .map(|value| remove_fields(value, fields))
+                            .sum::<usize>()
+                }
+                serde_json::Value::Array(values) => values
+                    .iter_mut()
+                    .map(|value| remove_fields(value, fields))
+                    .sum(),
+                _ => 0,
+            }
+        }
+
+        let (node_a, node_b, new_channel_id) =
+            create_nodes_with_established_channel(100000000000, 6200000000, false).await;
+        call!(node_a.network_actor, |rpc_reply| {
+            NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
+                ChannelCommandWithId {
+                    channel_id: new_channel_id,
+                    command: ChannelCommand::AddTlc(
+                        AddTlcCommand {
+                            amount: 1000000000,
+                            hash_algorithm: HashAlgorithm::CkbHash,
+                            payment_hash: Some([1u8; 32].into()),
+                            expiry: LockTime::new(100),
+                            preimage: None,
+                            onion_packet: vec![],
+                            previous_tlc: None,
+                        },
+                        rpc_reply,
+                    ),
+                },
+            ))
+        })
+        .expect("node_a alive")
+        .expect("successfully added tlc");
+
+        // The tlc fields added after release are stripped from the stored received tlc.
+        let mut value = serde_json::Value::Null;
+        for _ in 0..50 {
+            let state = node_b
+                .store
+                .get_channel_actor_state(&new_channel_id)
+                .expect("channel state");
+            value = serde_json::to_value(&state).expect("serialize channel state");
+            if remove_fields(&mut value, &["shared_secret", "is_last_hop"]) > 0 {
+                break;
+            }
+            value = serde_json::Value::Null;
+            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        }
+        let fields = value.as_object_mut().expect("channel state with tlc");
+        for field in [
+            "funding_tx_candidates",
+            "funding_rbf_rounds",
+            "splice_rounds",
+            "data_loss_detected",
+            "remote_commitment_secret",
+        ] {
+            assert!(fields.remove(field).is_some(), "missing field {}", field);
+        }
+
+        let loaded: super::ChannelActorState =
+            serde_json::from_value(value).expect("deserialize channel state");
+        assert_eq!(loaded.get_id(), new_channel_id);
+        assert_eq!(
+            loaded
+                .tlcs
+                .values()
+                .map(|tlc| (tlc.tlc.shared_secret, tlc.tlc.is_last_hop))
+                .collect::<Vec<_>>(),
+            vec![([0u8; 32], false)]
+        );
+        assert!(loaded.funding_tx_candidates.is_empty());
+        assert_eq!(loaded.funding_rbf_rounds, 0);
+        assert_eq!(loaded.splice_rounds, 0);
+        assert!(!loaded.data_loss_detected);
+        assert!(loaded.remote_commitment_secret.is_none());
+    }
 }
@@ -1,3 +1,4 @@
+use crate::fiber::types::Hash256;
 use crate::Result;
 use ckb_sdk::NetworkType;
 use clap::ValueEnum;
@@ -55,6 +56,18 @@ pub const DEFAULT_ANNOUNCE_NODE_INTERVAL_SECONDS: u64 = 3600;
 /// Whether to sync the network graph from the network. true means syncing.
 pub const DEFAULT_SYNC_NETWORK_GRAPH: bool = true;
 
+/// The interval to prune closed and stale channels from the network graph, in seconds.
+pub const DEFAULT_PRUNE_NETWORK_GRAPH_INTERVAL_SECONDS: u64 = 3600;
+
+/// The time after which a channel without any ChannelUpdate is considered stale, in seconds. 1209600 means 2 weeks.
+pub const DEFAULT_STALE_CHANNEL_TIMEOUT_SECONDS: u64 = 1209600;
+
+/// The maximal number of inbound connections, peers we share channels with are always accepted.
+pub const DEFAULT_MAX_INBOUND_PEERS: usize = 32;
+
+/// The maximal number of outbound connections made automatically, peers we share channels with are always reconnected.
+pub const DEFAULT_MAX_OUTBOUND_PEERS: usize = 8;
+
 // See comment in `LdkConfig` for why do we need to specify both name and long,
 // and prefix them with `ckb-`/`CKB_`.
 #[derive(ClapSerde, Debug, Clone)]
@@ -88,6 +101,10 @@ pub struct FiberConfig {
     #[arg(name = "FIBER_BOOTNODES_ADDRS", long = "fiber-bootnodes-addrs", env, value_parser, num_args = 0.., value_delimiter = ',')]
     pub bootnode_addrs: Vec<String>,
 
+    /// json-rpc urls of the remote watchtowers to upload the revocation data to (separated by `,`)
+    #[arg(name = "FIBER_WATCHTOWER_URLS", long = "fiber-watchtower-urls", env, value_parser, num_args = 0.., value_delimiter = ',')]
+    pub watchtower_urls: Vec<String>,
+
     /// node name to be announced to fiber network
     #[arg(
         name = "FIBER_ANNOUNCED_NODE_NAME",
@@ -180,6 +197,42 @@ pub struct FiberConfig {
         help = "Whether to sync the network graph from the network. [default: true]"
     )]
     pub(crate) sync_network_graph: Option<bool>,
+
+    /// The interval to prune closed and stale channels from the network graph, in seconds. 0 means never prune. [default: 3600 (1 hour)]
+    #[arg(
+        name = "FIBER_PRUNE_NETWORK_GRAPH_INTERVAL_SECONDS",
+        long = "fiber-prune-network-graph-interval-seconds",
+        env,
+        help = "The interval to prune closed and stale channels from the network graph, in seconds. 0 means never prune. [default: 3600 (1 hour)]"
+    )]
+    pub(crate) prune_network_graph_interval_seconds: Option<u64>,
+
+    /// Channels without any ChannelUpdate for this long are removed from the network graph, in seconds. [default: 1209600 (2 weeks)]
+    #[arg(
+        name = "FIBER_STALE_CHANNEL_TIMEOUT_SECONDS",
+        long = "fiber-stale-channel-timeout-seconds",
+        env,
+        help = "Channels without any ChannelUpdate for this long are removed from the network graph, in seconds. [default: 1209600 (2 weeks)]"
+    )]
+    pub(crate) stale_channel_timeout_seconds: Option<u64>,
+
+    /// The maximal number of inbound connections, peers we share channels with are always accepted. [default: 32]
+    #[arg(
+        name = "FIBER_MAX_INBOUND_PEERS",
+        long = "fiber-max-inbound-peers",
+        env,
+        help = "The maximal number of inbound connections, peers we share channels with are always accepted. [default: 32]"
+    )]
+    pub(crate) max_inbound_peers: Option<usize>,
+
+    /// The maximal number of outbound connections, peers we share channels with are always connected. [default: 8]
+    #[arg(
+        name = "FIBER_MAX_OUTBOUND_PEERS",
+        long = "fiber-max-outbound-peers",
+        env,
+        help = "The maximal number of outbound connections, peers we share channels with are always connected. [default: 8]"
+    )]
+    pub(crate) max_outbound_peers: Option<usize>,
 }
 
 #[derive(PartialEq, Copy, Clone, Default)]
@@ -336,6 +389,25 @@ impl FiberConfig {
         self.sync_network_graph
             .unwrap_or(DEFAULT_SYNC_NETWORK_GRAPH)
     }
+
+    pub fn prune_network_graph_interval_seconds(&self) -> u64 {
+        self.prune_network_graph_interval_seconds
+            .unwrap_or(DEFAULT_PRUNE_NETWORK_GRAPH_INTERVAL_SECONDS)
+    }
+
+    pub fn stale_channel_timeout_seconds(&self) -> u64 {
+        self.stale_channel_timeout_seconds
+            .unwrap_or(DEFAULT_STALE_CHANNEL_TIMEOUT_SECONDS)
+    }
+
+    pub fn max_inbound_peers(&self) -> usize {
+        self.max_inbound_peers.unwrap_or(DEFAULT_MAX_INBOUND_PEERS)
+    }
+
+    pub fn max_outbound_peers(&self) -> usize {
+        self.max_outbound_peers
+            .unwrap_or(DEFAULT_MAX_OUTBOUND_PEERS)
+    }
 }
 
 // Basically ckb_sdk::types::NetworkType. But we added a `Mocknet` variant.
@@ -349,6 +421,24 @@ pub enum CkbNetwork {
     Dev,
 }
 
+// The genesis block hashes of the public CKB chains.
+const MAINNET_GENESIS_HASH: &str =
+    "0x92b197aa1fba0f63633922c61c92375c9c074a93e85963554f5499fe1450d0e5";
+const TESTNET_GENESIS_HASH: &str =
+    "0x10639e0895502b5688a6be8cf69460d76541bfa4821629d86d62ba0aade3f5b9";
+
+impl CkbNetwork {
+    /// The chain hash exchanged in the Init messages and signed in the broadcast messages.
+    /// The other networks, whose genesis blocks are not fixed, use the default chain hash.
+    pub fn chain_hash(&self) -> Hash256 {
+        match self {
+            CkbNetwork::Mainnet => MAINNET_GENESIS_HASH.parse().expect("valid genesis hash"),
+            CkbNetwork::Testnet => TESTNET_GENESIS_HASH.parse().expect("valid genesis hash"),
+            CkbNetwork::Mocknet | CkbNetwork::Staging | CkbNetwork::Dev => Default::default(),
+        }
+    }
+}
+
 impl From<CkbNetwork> for Option<NetworkType> {
     fn from(network: CkbNetwork) -> Self {
         match network {
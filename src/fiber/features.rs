@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use super::serde_utils::SliceHex;
+
+/// The features known by this node. Each feature takes a pair of bits in the feature vector,
+/// the even bit `2 * feature` means the feature is required, and the odd bit `2 * feature + 1`
+/// means it is optional ("it's ok to be odd").
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Feature {
+    /// Paying an invoice with multiple parts through different routes.
+    MultiPartPayment = 0,
+    /// Bumping the fee of the funding transaction with TxInitRBF.
+    FundingTxRbf = 1,
+    /// Tlcs locked by sha256 payment hashes in addition to ckb hash.
+    Sha256PaymentHash = 2,
+    /// Splicing funds into and out of ready channels.
+    Splicing = 3,
+    /// Revealing the revoked commitment secrets and sending them back on reestablishing,
+    /// which proves to a peer restored from stale states that it has lost some states.
+    DataLossProtect = 4,
+}
+
+impl Feature {
+    const ALL: [Feature; 5] = [
+        Feature::MultiPartPayment,
+        Feature::FundingTxRbf,
+        Feature::Sha256PaymentHash,
+        Feature::Splicing,
+        Feature::DataLossProtect,
+    ];
+
+    fn required_bit(&self) -> usize {
+        *self as usize * 2
+    }
+
+    fn optional_bit(&self) -> usize {
+        self.required_bit() + 1
+    }
+}
+
+/// A vector of feature bits in little endian, i.e. bit `i` is `bits[i / 8] & (1 << (i % 8))`.
+#[serde_as]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureVector(#[serde_as(as = "SliceHex")] Vec<u8>);
+
+impl FeatureVector {
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// The features supported by this node, all of them are optional so that older peers
+    /// are not disconnected.
+    pub fn local() -> Self {
+        let mut features = Self::default();
+        for feature in Feature::ALL {
+            features.set_optional(feature);
+        }
+        features
+    }
+
+    fn is_set(&self, bit: usize) -> bool {
+        self.0
+            .get(bit / 8)
+            .is_some_and(|byte| byte & (1 << (bit % 8)) != 0)
+    }
+
+    fn set(&mut self, bit: usize) {
+        if self.0.len() <= bit / 8 {
+            self.0.resize(bit / 8 + 1, 0);
+        }
+        self.0[bit / 8] |= 1 << (bit % 8);
+    }
+
+    pub fn set_required(&mut self, feature: Feature) {
+        self.set(feature.required_bit());
+    }
+
+    pub fn set_optional(&mut self, feature: Feature) {
+        self.set(feature.optional_bit());
+    }
+
+    pub fn requires(&self, feature: Feature) -> bool {
+        self.is_set(feature.required_bit())
+    }
+
+    pub fn supports(&self, feature: Feature) -> bool {
+        self.requires(feature) || self.is_set(feature.optional_bit())
+    }
+
+    /// Returns the required bits which are not known by this node.
+    pub fn unknown_required_bits(&self) -> Vec<usize> {
+        (0..self.0.len() * 8)
+            .step_by(2)
+            .filter(|bit| self.is_set(*bit))
+            .filter(|bit| {
+                !Feature::ALL
+                    .iter()
+                    .any(|feature| feature.required_bit() == *bit)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feature_vector() {
+        let local = FeatureVector::local();
+        for feature in Feature::ALL {
+            assert!(local.supports(feature));
+            assert!(!local.requires(feature));
+        }
+        assert!(local.unknown_required_bits().is_empty());
+
+        let mut features = FeatureVector::default();
+        assert!(!features.supports(Feature::Splicing));
+        features.set_required(Feature::Splicing);
+        assert!(features.requires(Feature::Splicing));
+        assert!(features.supports(Feature::Splicing));
+        assert_eq!(features.as_bytes(), &[0b0100_0000]);
+        assert!(features.unknown_required_bits().is_empty());
+
+        // Unknown optional bits are ignored, while unknown required bits are reported.
+        let features = FeatureVector::from_bytes(vec![0, 0b1000_0000, 0b0000_0010]);
+        assert!(features.unknown_required_bits().is_empty());
+        let features = FeatureVector::from_bytes(vec![0, 0b0100_0000, 0b0000_0001]);
+        assert_eq!(features.unknown_required_bits(), vec![14, 16]);
+    }
+}
@@ -7906,8 +7906,8 @@ impl molecule::prelude::Builder for TxAckRBFBuilder {
     }
 }
 #[derive(Clone)]
-pub struct Shutdown(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Shutdown {
+pub struct SpliceInit(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SpliceInit {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -7916,18 +7916,20 @@ impl ::core::fmt::LowerHex for Shutdown {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Shutdown {
+impl ::core::fmt::Debug for SpliceInit {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Shutdown {
+impl ::core::fmt::Display for SpliceInit {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "channel_id", self.channel_id())?;
-        write!(f, ", {}: {}", "fee_rate", self.fee_rate())?;
-        write!(f, ", {}: {}", "close_script", self.close_script())?;
-        write!(f, ", {}: {}", "force", self.force())?;
+        write!(f, ", {}: {}", "funding_amount", self.funding_amount())?;
+        write!(f, ", {}: {}", "funding_fee_rate", self.funding_fee_rate())?;
+        write!(f, ", {}: {}", "funding_nonce", self.funding_nonce())?;
+        write!(f, ", {}: {}", "local_nonce", self.local_nonce())?;
+        write!(f, ", {}: {}", "channel_announcement_nonce", self.channel_announcement_nonce())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -7935,20 +7937,26 @@ impl ::core::fmt::Display for Shutdown {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for Shutdown {
+impl ::core::default::Default for SpliceInit {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        Shutdown::new_unchecked(v)
+        SpliceInit::new_unchecked(v)
     }
 }
-impl Shutdown {
-    const DEFAULT_VALUE: [u8; 114] = [
-        114, 0, 0, 0, 20, 0, 0, 0, 52, 0, 0, 0, 60, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl SpliceInit {
+    const DEFAULT_VALUE: [u8; 282] = [
+        26, 1, 0, 0, 28, 0, 0, 0, 60, 0, 0, 0, 76, 0, 0, 0, 84, 0, 0, 0, 150, 0, 0, 0, 216, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 53, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_COUNT: usize = 6;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -7971,37 +7979,49 @@ impl Shutdown {
         let end = molecule::unpack_number(&slice[8..]) as usize;
         Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn fee_rate(&self) -> Uint64 {
+    pub fn funding_amount(&self) -> Uint128 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
+        Uint128::new_unchecked(self.0.slice(start..end))
     }
-    pub fn close_script(&self) -> Script {
+    pub fn funding_fee_rate(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        Script::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn force(&self) -> Byte {
+    pub fn funding_nonce(&self) -> PubNonce {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        PubNonce::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn local_nonce(&self) -> PubNonce {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        PubNonce::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn channel_announcement_nonce(&self) -> PubNonce {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            Byte::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            PubNonce::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte::new_unchecked(self.0.slice(start..))
+            PubNonce::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> ShutdownReader<'r> {
-        ShutdownReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> SpliceInitReader<'r> {
+        SpliceInitReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Shutdown {
-    type Builder = ShutdownBuilder;
-    const NAME: &'static str = "Shutdown";
+impl molecule::prelude::Entity for SpliceInit {
+    type Builder = SpliceInitBuilder;
+    const NAME: &'static str = "SpliceInit";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Shutdown(data)
+        SpliceInit(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -8010,10 +8030,10 @@ impl molecule::prelude::Entity for Shutdown {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ShutdownReader::from_slice(slice).map(|reader| reader.to_entity())
+        SpliceInitReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ShutdownReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        SpliceInitReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -8021,14 +8041,16 @@ impl molecule::prelude::Entity for Shutdown {
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
             .channel_id(self.channel_id())
-            .fee_rate(self.fee_rate())
-            .close_script(self.close_script())
-            .force(self.force())
+            .funding_amount(self.funding_amount())
+            .funding_fee_rate(self.funding_fee_rate())
+            .funding_nonce(self.funding_nonce())
+            .local_nonce(self.local_nonce())
+            .channel_announcement_nonce(self.channel_announcement_nonce())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ShutdownReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ShutdownReader<'r> {
+pub struct SpliceInitReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SpliceInitReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8037,18 +8059,20 @@ impl<'r> ::core::fmt::LowerHex for ShutdownReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ShutdownReader<'r> {
+impl<'r> ::core::fmt::Debug for SpliceInitReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ShutdownReader<'r> {
+impl<'r> ::core::fmt::Display for SpliceInitReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "channel_id", self.channel_id())?;
-        write!(f, ", {}: {}", "fee_rate", self.fee_rate())?;
-        write!(f, ", {}: {}", "close_script", self.close_script())?;
-        write!(f, ", {}: {}", "force", self.force())?;
+        write!(f, ", {}: {}", "funding_amount", self.funding_amount())?;
+        write!(f, ", {}: {}", "funding_fee_rate", self.funding_fee_rate())?;
+        write!(f, ", {}: {}", "funding_nonce", self.funding_nonce())?;
+        write!(f, ", {}: {}", "local_nonce", self.local_nonce())?;
+        write!(f, ", {}: {}", "channel_announcement_nonce", self.channel_announcement_nonce())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -8056,8 +8080,8 @@ impl<'r> ::core::fmt::Display for ShutdownReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> ShutdownReader<'r> {
-    pub const FIELD_COUNT: usize = 4;
+impl<'r> SpliceInitReader<'r> {
+    pub const FIELD_COUNT: usize = 6;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -8080,37 +8104,49 @@ impl<'r> ShutdownReader<'r> {
         let end = molecule::unpack_number(&slice[8..]) as usize;
         Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn fee_rate(&self) -> Uint64Reader<'r> {
+    pub fn funding_amount(&self) -> Uint128Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn close_script(&self) -> ScriptReader<'r> {
+    pub fn funding_fee_rate(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        ScriptReader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn force(&self) -> ByteReader<'r> {
+    pub fn funding_nonce(&self) -> PubNonceReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        PubNonceReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn local_nonce(&self) -> PubNonceReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        PubNonceReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn channel_announcement_nonce(&self) -> PubNonceReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            ByteReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            PubNonceReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            ByteReader::new_unchecked(&self.as_slice()[start..])
+            PubNonceReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ShutdownReader<'r> {
-    type Entity = Shutdown;
-    const NAME: &'static str = "ShutdownReader";
+impl<'r> molecule::prelude::Reader<'r> for SpliceInitReader<'r> {
+    type Entity = SpliceInit;
+    const NAME: &'static str = "SpliceInitReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ShutdownReader(slice)
+        SpliceInitReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -8150,47 +8186,61 @@ impl<'r> molecule::prelude::Reader<'r> for ShutdownReader<'r> {
             return ve!(Self, OffsetsNotMatch);
         }
         Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        ScriptReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        ByteReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        PubNonceReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        PubNonceReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        PubNonceReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct ShutdownBuilder {
+pub struct SpliceInitBuilder {
     pub(crate) channel_id: Byte32,
-    pub(crate) fee_rate: Uint64,
-    pub(crate) close_script: Script,
-    pub(crate) force: Byte,
+    pub(crate) funding_amount: Uint128,
+    pub(crate) funding_fee_rate: Uint64,
+    pub(crate) funding_nonce: PubNonce,
+    pub(crate) local_nonce: PubNonce,
+    pub(crate) channel_announcement_nonce: PubNonce,
 }
-impl ShutdownBuilder {
-    pub const FIELD_COUNT: usize = 4;
+impl SpliceInitBuilder {
+    pub const FIELD_COUNT: usize = 6;
     pub fn channel_id(mut self, v: Byte32) -> Self {
         self.channel_id = v;
         self
     }
-    pub fn fee_rate(mut self, v: Uint64) -> Self {
-        self.fee_rate = v;
+    pub fn funding_amount(mut self, v: Uint128) -> Self {
+        self.funding_amount = v;
         self
     }
-    pub fn close_script(mut self, v: Script) -> Self {
-        self.close_script = v;
+    pub fn funding_fee_rate(mut self, v: Uint64) -> Self {
+        self.funding_fee_rate = v;
         self
     }
-    pub fn force(mut self, v: Byte) -> Self {
-        self.force = v;
+    pub fn funding_nonce(mut self, v: PubNonce) -> Self {
+        self.funding_nonce = v;
+        self
+    }
+    pub fn local_nonce(mut self, v: PubNonce) -> Self {
+        self.local_nonce = v;
+        self
+    }
+    pub fn channel_announcement_nonce(mut self, v: PubNonce) -> Self {
+        self.channel_announcement_nonce = v;
         self
     }
 }
-impl molecule::prelude::Builder for ShutdownBuilder {
-    type Entity = Shutdown;
-    const NAME: &'static str = "ShutdownBuilder";
+impl molecule::prelude::Builder for SpliceInitBuilder {
+    type Entity = SpliceInit;
+    const NAME: &'static str = "SpliceInitBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.channel_id.as_slice().len()
-            + self.fee_rate.as_slice().len()
-            + self.close_script.as_slice().len()
-            + self.force.as_slice().len()
+            + self.funding_amount.as_slice().len()
+            + self.funding_fee_rate.as_slice().len()
+            + self.funding_nonce.as_slice().len()
+            + self.local_nonce.as_slice().len()
+            + self.channel_announcement_nonce.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -8198,31 +8248,37 @@ impl molecule::prelude::Builder for ShutdownBuilder {
         offsets.push(total_size);
         total_size += self.channel_id.as_slice().len();
         offsets.push(total_size);
-        total_size += self.fee_rate.as_slice().len();
+        total_size += self.funding_amount.as_slice().len();
         offsets.push(total_size);
-        total_size += self.close_script.as_slice().len();
+        total_size += self.funding_fee_rate.as_slice().len();
         offsets.push(total_size);
-        total_size += self.force.as_slice().len();
+        total_size += self.funding_nonce.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.local_nonce.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.channel_announcement_nonce.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
         writer.write_all(self.channel_id.as_slice())?;
-        writer.write_all(self.fee_rate.as_slice())?;
-        writer.write_all(self.close_script.as_slice())?;
-        writer.write_all(self.force.as_slice())?;
+        writer.write_all(self.funding_amount.as_slice())?;
+        writer.write_all(self.funding_fee_rate.as_slice())?;
+        writer.write_all(self.funding_nonce.as_slice())?;
+        writer.write_all(self.local_nonce.as_slice())?;
+        writer.write_all(self.channel_announcement_nonce.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Shutdown::new_unchecked(inner.into())
+        SpliceInit::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct ClosingSigned(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ClosingSigned {
+pub struct SpliceAck(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SpliceAck {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8231,16 +8287,18 @@ impl ::core::fmt::LowerHex for ClosingSigned {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ClosingSigned {
+impl ::core::fmt::Debug for SpliceAck {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ClosingSigned {
+impl ::core::fmt::Display for SpliceAck {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "channel_id", self.channel_id())?;
-        write!(f, ", {}: {}", "partial_signature", self.partial_signature())?;
+        write!(f, ", {}: {}", "funding_nonce", self.funding_nonce())?;
+        write!(f, ", {}: {}", "local_nonce", self.local_nonce())?;
+        write!(f, ", {}: {}", "channel_announcement_nonce", self.channel_announcement_nonce())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -8248,19 +8306,25 @@ impl ::core::fmt::Display for ClosingSigned {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for ClosingSigned {
+impl ::core::default::Default for SpliceAck {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        ClosingSigned::new_unchecked(v)
+        SpliceAck::new_unchecked(v)
     }
 }
-impl ClosingSigned {
-    const DEFAULT_VALUE: [u8; 76] = [
-        76, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl SpliceAck {
+    const DEFAULT_VALUE: [u8; 250] = [
+        250, 0, 0, 0, 20, 0, 0, 0, 52, 0, 0, 0, 118, 0, 0, 0, 184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -8283,25 +8347,37 @@ impl ClosingSigned {
         let end = molecule::unpack_number(&slice[8..]) as usize;
         Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn partial_signature(&self) -> Byte32 {
+    pub fn funding_nonce(&self) -> PubNonce {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        PubNonce::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn local_nonce(&self) -> PubNonce {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        PubNonce::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn channel_announcement_nonce(&self) -> PubNonce {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            Byte32::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            PubNonce::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte32::new_unchecked(self.0.slice(start..))
+            PubNonce::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> ClosingSignedReader<'r> {
-        ClosingSignedReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> SpliceAckReader<'r> {
+        SpliceAckReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ClosingSigned {
-    type Builder = ClosingSignedBuilder;
-    const NAME: &'static str = "ClosingSigned";
+impl molecule::prelude::Entity for SpliceAck {
+    type Builder = SpliceAckBuilder;
+    const NAME: &'static str = "SpliceAck";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ClosingSigned(data)
+        SpliceAck(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -8310,10 +8386,10 @@ impl molecule::prelude::Entity for ClosingSigned {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ClosingSignedReader::from_slice(slice).map(|reader| reader.to_entity())
+        SpliceAckReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ClosingSignedReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        SpliceAckReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -8321,12 +8397,14 @@ impl molecule::prelude::Entity for ClosingSigned {
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
             .channel_id(self.channel_id())
-            .partial_signature(self.partial_signature())
+            .funding_nonce(self.funding_nonce())
+            .local_nonce(self.local_nonce())
+            .channel_announcement_nonce(self.channel_announcement_nonce())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ClosingSignedReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ClosingSignedReader<'r> {
+pub struct SpliceAckReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SpliceAckReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8335,16 +8413,18 @@ impl<'r> ::core::fmt::LowerHex for ClosingSignedReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ClosingSignedReader<'r> {
+impl<'r> ::core::fmt::Debug for SpliceAckReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ClosingSignedReader<'r> {
+impl<'r> ::core::fmt::Display for SpliceAckReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "channel_id", self.channel_id())?;
-        write!(f, ", {}: {}", "partial_signature", self.partial_signature())?;
+        write!(f, ", {}: {}", "funding_nonce", self.funding_nonce())?;
+        write!(f, ", {}: {}", "local_nonce", self.local_nonce())?;
+        write!(f, ", {}: {}", "channel_announcement_nonce", self.channel_announcement_nonce())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -8352,8 +8432,8 @@ impl<'r> ::core::fmt::Display for ClosingSignedReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> ClosingSignedReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
+impl<'r> SpliceAckReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -8376,25 +8456,37 @@ impl<'r> ClosingSignedReader<'r> {
         let end = molecule::unpack_number(&slice[8..]) as usize;
         Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn partial_signature(&self) -> Byte32Reader<'r> {
+    pub fn funding_nonce(&self) -> PubNonceReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        PubNonceReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn local_nonce(&self) -> PubNonceReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        PubNonceReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn channel_announcement_nonce(&self) -> PubNonceReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            PubNonceReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Byte32Reader::new_unchecked(&self.as_slice()[start..])
+            PubNonceReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ClosingSignedReader<'r> {
-    type Entity = ClosingSigned;
-    const NAME: &'static str = "ClosingSignedReader";
+impl<'r> molecule::prelude::Reader<'r> for SpliceAckReader<'r> {
+    type Entity = SpliceAck;
+    const NAME: &'static str = "SpliceAckReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ClosingSignedReader(slice)
+        SpliceAckReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -8434,33 +8526,47 @@ impl<'r> molecule::prelude::Reader<'r> for ClosingSignedReader<'r> {
             return ve!(Self, OffsetsNotMatch);
         }
         Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        PubNonceReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        PubNonceReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        PubNonceReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct ClosingSignedBuilder {
+pub struct SpliceAckBuilder {
     pub(crate) channel_id: Byte32,
-    pub(crate) partial_signature: Byte32,
+    pub(crate) funding_nonce: PubNonce,
+    pub(crate) local_nonce: PubNonce,
+    pub(crate) channel_announcement_nonce: PubNonce,
 }
-impl ClosingSignedBuilder {
-    pub const FIELD_COUNT: usize = 2;
+impl SpliceAckBuilder {
+    pub const FIELD_COUNT: usize = 4;
     pub fn channel_id(mut self, v: Byte32) -> Self {
         self.channel_id = v;
         self
     }
-    pub fn partial_signature(mut self, v: Byte32) -> Self {
-        self.partial_signature = v;
+    pub fn funding_nonce(mut self, v: PubNonce) -> Self {
+        self.funding_nonce = v;
+        self
+    }
+    pub fn local_nonce(mut self, v: PubNonce) -> Self {
+        self.local_nonce = v;
+        self
+    }
+    pub fn channel_announcement_nonce(mut self, v: PubNonce) -> Self {
+        self.channel_announcement_nonce = v;
         self
     }
 }
-impl molecule::prelude::Builder for ClosingSignedBuilder {
-    type Entity = ClosingSigned;
-    const NAME: &'static str = "ClosingSignedBuilder";
+impl molecule::prelude::Builder for SpliceAckBuilder {
+    type Entity = SpliceAck;
+    const NAME: &'static str = "SpliceAckBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.channel_id.as_slice().len()
-            + self.partial_signature.as_slice().len()
+            + self.funding_nonce.as_slice().len()
+            + self.local_nonce.as_slice().len()
+            + self.channel_announcement_nonce.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -8468,25 +8574,31 @@ impl molecule::prelude::Builder for ClosingSignedBuilder {
         offsets.push(total_size);
         total_size += self.channel_id.as_slice().len();
         offsets.push(total_size);
-        total_size += self.partial_signature.as_slice().len();
+        total_size += self.funding_nonce.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.local_nonce.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.channel_announcement_nonce.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
         writer.write_all(self.channel_id.as_slice())?;
-        writer.write_all(self.partial_signature.as_slice())?;
+        writer.write_all(self.funding_nonce.as_slice())?;
+        writer.write_all(self.local_nonce.as_slice())?;
+        writer.write_all(self.channel_announcement_nonce.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ClosingSigned::new_unchecked(inner.into())
+        SpliceAck::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct AddTlc(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for AddTlc {
+pub struct SpliceCommitmentSigned(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SpliceCommitmentSigned {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8495,21 +8607,28 @@ impl ::core::fmt::LowerHex for AddTlc {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for AddTlc {
+impl ::core::fmt::Debug for SpliceCommitmentSigned {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for AddTlc {
+impl ::core::fmt::Display for SpliceCommitmentSigned {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "channel_id", self.channel_id())?;
-        write!(f, ", {}: {}", "tlc_id", self.tlc_id())?;
-        write!(f, ", {}: {}", "amount", self.amount())?;
-        write!(f, ", {}: {}", "payment_hash", self.payment_hash())?;
-        write!(f, ", {}: {}", "expiry", self.expiry())?;
-        write!(f, ", {}: {}", "hash_algorithm", self.hash_algorithm())?;
-        write!(f, ", {}: {}", "onion_packet", self.onion_packet())?;
+        write!(
+            f,
+            ", {}: {}",
+            "funding_tx_partial_signature",
+            self.funding_tx_partial_signature()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "commitment_tx_partial_signature",
+            self.commitment_tx_partial_signature()
+        )?;
+        write!(f, ", {}: {}", "next_local_nonce", self.next_local_nonce())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -8517,21 +8636,23 @@ impl ::core::fmt::Display for AddTlc {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for AddTlc {
+impl ::core::default::Default for SpliceCommitmentSigned {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        AddTlc::new_unchecked(v)
+        SpliceCommitmentSigned::new_unchecked(v)
     }
 }
-impl AddTlc {
-    const DEFAULT_VALUE: [u8; 133] = [
-        133, 0, 0, 0, 32, 0, 0, 0, 64, 0, 0, 0, 72, 0, 0, 0, 88, 0, 0, 0, 120, 0, 0, 0, 128, 0, 0,
-        0, 129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl SpliceCommitmentSigned {
+    const DEFAULT_VALUE: [u8; 182] = [
+        182, 0, 0, 0, 20, 0, 0, 0, 52, 0, 0, 0, 84, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 7;
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -8554,55 +8675,37 @@ impl AddTlc {
         let end = molecule::unpack_number(&slice[8..]) as usize;
         Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn tlc_id(&self) -> Uint64 {
+    pub fn funding_tx_partial_signature(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
+        Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn amount(&self) -> Uint128 {
+    pub fn commitment_tx_partial_signature(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        Uint128::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn payment_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
         Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn expiry(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn hash_algorithm(&self) -> Byte {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        Byte::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn onion_packet(&self) -> Bytes {
+    pub fn next_local_nonce(&self) -> PubNonce {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[32..]) as usize;
-            Bytes::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            PubNonce::new_unchecked(self.0.slice(start..end))
         } else {
-            Bytes::new_unchecked(self.0.slice(start..))
+            PubNonce::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> AddTlcReader<'r> {
-        AddTlcReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> SpliceCommitmentSignedReader<'r> {
+        SpliceCommitmentSignedReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for AddTlc {
-    type Builder = AddTlcBuilder;
-    const NAME: &'static str = "AddTlc";
+impl molecule::prelude::Entity for SpliceCommitmentSigned {
+    type Builder = SpliceCommitmentSignedBuilder;
+    const NAME: &'static str = "SpliceCommitmentSigned";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        AddTlc(data)
+        SpliceCommitmentSigned(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -8611,10 +8714,10 @@ impl molecule::prelude::Entity for AddTlc {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AddTlcReader::from_slice(slice).map(|reader| reader.to_entity())
+        SpliceCommitmentSignedReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AddTlcReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        SpliceCommitmentSignedReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -8622,17 +8725,14 @@ impl molecule::prelude::Entity for AddTlc {
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
             .channel_id(self.channel_id())
-            .tlc_id(self.tlc_id())
-            .amount(self.amount())
-            .payment_hash(self.payment_hash())
-            .expiry(self.expiry())
-            .hash_algorithm(self.hash_algorithm())
-            .onion_packet(self.onion_packet())
+            .funding_tx_partial_signature(self.funding_tx_partial_signature())
+            .commitment_tx_partial_signature(self.commitment_tx_partial_signature())
+            .next_local_nonce(self.next_local_nonce())
     }
 }
 #[derive(Clone, Copy)]
-pub struct AddTlcReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for AddTlcReader<'r> {
+pub struct SpliceCommitmentSignedReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SpliceCommitmentSignedReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8641,21 +8741,28 @@ impl<'r> ::core::fmt::LowerHex for AddTlcReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for AddTlcReader<'r> {
+impl<'r> ::core::fmt::Debug for SpliceCommitmentSignedReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for AddTlcReader<'r> {
+impl<'r> ::core::fmt::Display for SpliceCommitmentSignedReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "channel_id", self.channel_id())?;
-        write!(f, ", {}: {}", "tlc_id", self.tlc_id())?;
-        write!(f, ", {}: {}", "amount", self.amount())?;
-        write!(f, ", {}: {}", "payment_hash", self.payment_hash())?;
-        write!(f, ", {}: {}", "expiry", self.expiry())?;
-        write!(f, ", {}: {}", "hash_algorithm", self.hash_algorithm())?;
-        write!(f, ", {}: {}", "onion_packet", self.onion_packet())?;
+        write!(
+            f,
+            ", {}: {}",
+            "funding_tx_partial_signature",
+            self.funding_tx_partial_signature()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "commitment_tx_partial_signature",
+            self.commitment_tx_partial_signature()
+        )?;
+        write!(f, ", {}: {}", "next_local_nonce", self.next_local_nonce())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -8663,8 +8770,8 @@ impl<'r> ::core::fmt::Display for AddTlcReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> AddTlcReader<'r> {
-    pub const FIELD_COUNT: usize = 7;
+impl<'r> SpliceCommitmentSignedReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -8687,55 +8794,37 @@ impl<'r> AddTlcReader<'r> {
         let end = molecule::unpack_number(&slice[8..]) as usize;
         Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn tlc_id(&self) -> Uint64Reader<'r> {
+    pub fn funding_tx_partial_signature(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn amount(&self) -> Uint128Reader<'r> {
+    pub fn commitment_tx_partial_signature(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn payment_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
         Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn expiry(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn hash_algorithm(&self) -> ByteReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        ByteReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn onion_packet(&self) -> BytesReader<'r> {
+    pub fn next_local_nonce(&self) -> PubNonceReader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[32..]) as usize;
-            BytesReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            PubNonceReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            BytesReader::new_unchecked(&self.as_slice()[start..])
+            PubNonceReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for AddTlcReader<'r> {
-    type Entity = AddTlc;
-    const NAME: &'static str = "AddTlcReader";
+impl<'r> molecule::prelude::Reader<'r> for SpliceCommitmentSignedReader<'r> {
+    type Entity = SpliceCommitmentSigned;
+    const NAME: &'static str = "SpliceCommitmentSignedReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        AddTlcReader(slice)
+        SpliceCommitmentSignedReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -8775,68 +8864,47 @@ impl<'r> molecule::prelude::Reader<'r> for AddTlcReader<'r> {
             return ve!(Self, OffsetsNotMatch);
         }
         Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Uint128Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        ByteReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        BytesReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        PubNonceReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct AddTlcBuilder {
+pub struct SpliceCommitmentSignedBuilder {
     pub(crate) channel_id: Byte32,
-    pub(crate) tlc_id: Uint64,
-    pub(crate) amount: Uint128,
-    pub(crate) payment_hash: Byte32,
-    pub(crate) expiry: Uint64,
-    pub(crate) hash_algorithm: Byte,
-    pub(crate) onion_packet: Bytes,
+    pub(crate) funding_tx_partial_signature: Byte32,
+    pub(crate) commitment_tx_partial_signature: Byte32,
+    pub(crate) next_local_nonce: PubNonce,
 }
-impl AddTlcBuilder {
-    pub const FIELD_COUNT: usize = 7;
+impl SpliceCommitmentSignedBuilder {
+    pub const FIELD_COUNT: usize = 4;
     pub fn channel_id(mut self, v: Byte32) -> Self {
         self.channel_id = v;
         self
     }
-    pub fn tlc_id(mut self, v: Uint64) -> Self {
-        self.tlc_id = v;
-        self
-    }
-    pub fn amount(mut self, v: Uint128) -> Self {
-        self.amount = v;
-        self
-    }
-    pub fn payment_hash(mut self, v: Byte32) -> Self {
-        self.payment_hash = v;
-        self
-    }
-    pub fn expiry(mut self, v: Uint64) -> Self {
-        self.expiry = v;
+    pub fn funding_tx_partial_signature(mut self, v: Byte32) -> Self {
+        self.funding_tx_partial_signature = v;
         self
     }
-    pub fn hash_algorithm(mut self, v: Byte) -> Self {
-        self.hash_algorithm = v;
+    pub fn commitment_tx_partial_signature(mut self, v: Byte32) -> Self {
+        self.commitment_tx_partial_signature = v;
         self
     }
-    pub fn onion_packet(mut self, v: Bytes) -> Self {
-        self.onion_packet = v;
+    pub fn next_local_nonce(mut self, v: PubNonce) -> Self {
+        self.next_local_nonce = v;
         self
     }
 }
-impl molecule::prelude::Builder for AddTlcBuilder {
-    type Entity = AddTlc;
-    const NAME: &'static str = "AddTlcBuilder";
+impl molecule::prelude::Builder for SpliceCommitmentSignedBuilder {
+    type Entity = SpliceCommitmentSigned;
+    const NAME: &'static str = "SpliceCommitmentSignedBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.channel_id.as_slice().len()
-            + self.tlc_id.as_slice().len()
-            + self.amount.as_slice().len()
-            + self.payment_hash.as_slice().len()
-            + self.expiry.as_slice().len()
-            + self.hash_algorithm.as_slice().len()
-            + self.onion_packet.as_slice().len()
+            + self.funding_tx_partial_signature.as_slice().len()
+            + self.commitment_tx_partial_signature.as_slice().len()
+            + self.next_local_nonce.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -8844,40 +8912,31 @@ impl molecule::prelude::Builder for AddTlcBuilder {
         offsets.push(total_size);
         total_size += self.channel_id.as_slice().len();
         offsets.push(total_size);
-        total_size += self.tlc_id.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.amount.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.payment_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.expiry.as_slice().len();
+        total_size += self.funding_tx_partial_signature.as_slice().len();
         offsets.push(total_size);
-        total_size += self.hash_algorithm.as_slice().len();
+        total_size += self.commitment_tx_partial_signature.as_slice().len();
         offsets.push(total_size);
-        total_size += self.onion_packet.as_slice().len();
+        total_size += self.next_local_nonce.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
         writer.write_all(self.channel_id.as_slice())?;
-        writer.write_all(self.tlc_id.as_slice())?;
-        writer.write_all(self.amount.as_slice())?;
-        writer.write_all(self.payment_hash.as_slice())?;
-        writer.write_all(self.expiry.as_slice())?;
-        writer.write_all(self.hash_algorithm.as_slice())?;
-        writer.write_all(self.onion_packet.as_slice())?;
+        writer.write_all(self.funding_tx_partial_signature.as_slice())?;
+        writer.write_all(self.commitment_tx_partial_signature.as_slice())?;
+        writer.write_all(self.next_local_nonce.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        AddTlc::new_unchecked(inner.into())
+        SpliceCommitmentSigned::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct RevokeAndAck(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for RevokeAndAck {
+pub struct SpliceRevokeAndAck(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SpliceRevokeAndAck {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8886,22 +8945,16 @@ impl ::core::fmt::LowerHex for RevokeAndAck {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for RevokeAndAck {
+impl ::core::fmt::Debug for SpliceRevokeAndAck {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for RevokeAndAck {
+impl ::core::fmt::Display for SpliceRevokeAndAck {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "channel_id", self.channel_id())?;
         write!(f, ", {}: {}", "partial_signature", self.partial_signature())?;
-        write!(
-            f,
-            ", {}: {}",
-            "next_per_commitment_point",
-            self.next_per_commitment_point()
-        )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -8909,20 +8962,19 @@ impl ::core::fmt::Display for RevokeAndAck {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for RevokeAndAck {
+impl ::core::default::Default for SpliceRevokeAndAck {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        RevokeAndAck::new_unchecked(v)
+        SpliceRevokeAndAck::new_unchecked(v)
     }
 }
-impl RevokeAndAck {
-    const DEFAULT_VALUE: [u8; 113] = [
-        113, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl SpliceRevokeAndAck {
+    const DEFAULT_VALUE: [u8; 76] = [
+        76, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -8948,28 +9000,22 @@ impl RevokeAndAck {
     pub fn partial_signature(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn next_per_commitment_point(&self) -> Pubkey {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            Pubkey::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte32::new_unchecked(self.0.slice(start..end))
         } else {
-            Pubkey::new_unchecked(self.0.slice(start..))
+            Byte32::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> RevokeAndAckReader<'r> {
-        RevokeAndAckReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> SpliceRevokeAndAckReader<'r> {
+        SpliceRevokeAndAckReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for RevokeAndAck {
-    type Builder = RevokeAndAckBuilder;
-    const NAME: &'static str = "RevokeAndAck";
+impl molecule::prelude::Entity for SpliceRevokeAndAck {
+    type Builder = SpliceRevokeAndAckBuilder;
+    const NAME: &'static str = "SpliceRevokeAndAck";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        RevokeAndAck(data)
+        SpliceRevokeAndAck(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -8978,10 +9024,10 @@ impl molecule::prelude::Entity for RevokeAndAck {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        RevokeAndAckReader::from_slice(slice).map(|reader| reader.to_entity())
+        SpliceRevokeAndAckReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        RevokeAndAckReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        SpliceRevokeAndAckReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -8990,12 +9036,11 @@ impl molecule::prelude::Entity for RevokeAndAck {
         Self::new_builder()
             .channel_id(self.channel_id())
             .partial_signature(self.partial_signature())
-            .next_per_commitment_point(self.next_per_commitment_point())
     }
 }
 #[derive(Clone, Copy)]
-pub struct RevokeAndAckReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for RevokeAndAckReader<'r> {
+pub struct SpliceRevokeAndAckReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SpliceRevokeAndAckReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -9004,22 +9049,16 @@ impl<'r> ::core::fmt::LowerHex for RevokeAndAckReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for RevokeAndAckReader<'r> {
+impl<'r> ::core::fmt::Debug for SpliceRevokeAndAckReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for RevokeAndAckReader<'r> {
+impl<'r> ::core::fmt::Display for SpliceRevokeAndAckReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "channel_id", self.channel_id())?;
         write!(f, ", {}: {}", "partial_signature", self.partial_signature())?;
-        write!(
-            f,
-            ", {}: {}",
-            "next_per_commitment_point",
-            self.next_per_commitment_point()
-        )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -9027,8 +9066,8 @@ impl<'r> ::core::fmt::Display for RevokeAndAckReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> RevokeAndAckReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+impl<'r> SpliceRevokeAndAckReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -9054,28 +9093,22 @@ impl<'r> RevokeAndAckReader<'r> {
     pub fn partial_signature(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn next_per_commitment_point(&self) -> PubkeyReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            PubkeyReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            PubkeyReader::new_unchecked(&self.as_slice()[start..])
+            Byte32Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for RevokeAndAckReader<'r> {
-    type Entity = RevokeAndAck;
-    const NAME: &'static str = "RevokeAndAckReader";
+impl<'r> molecule::prelude::Reader<'r> for SpliceRevokeAndAckReader<'r> {
+    type Entity = SpliceRevokeAndAck;
+    const NAME: &'static str = "SpliceRevokeAndAckReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        RevokeAndAckReader(slice)
+        SpliceRevokeAndAckReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -9116,18 +9149,16 @@ impl<'r> molecule::prelude::Reader<'r> for RevokeAndAckReader<'r> {
         }
         Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        PubkeyReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct RevokeAndAckBuilder {
+pub struct SpliceRevokeAndAckBuilder {
     pub(crate) channel_id: Byte32,
     pub(crate) partial_signature: Byte32,
-    pub(crate) next_per_commitment_point: Pubkey,
 }
-impl RevokeAndAckBuilder {
-    pub const FIELD_COUNT: usize = 3;
+impl SpliceRevokeAndAckBuilder {
+    pub const FIELD_COUNT: usize = 2;
     pub fn channel_id(mut self, v: Byte32) -> Self {
         self.channel_id = v;
         self
@@ -9136,19 +9167,14 @@ impl RevokeAndAckBuilder {
         self.partial_signature = v;
         self
     }
-    pub fn next_per_commitment_point(mut self, v: Pubkey) -> Self {
-        self.next_per_commitment_point = v;
-        self
-    }
 }
-impl molecule::prelude::Builder for RevokeAndAckBuilder {
-    type Entity = RevokeAndAck;
-    const NAME: &'static str = "RevokeAndAckBuilder";
+impl molecule::prelude::Builder for SpliceRevokeAndAckBuilder {
+    type Entity = SpliceRevokeAndAck;
+    const NAME: &'static str = "SpliceRevokeAndAckBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.channel_id.as_slice().len()
             + self.partial_signature.as_slice().len()
-            + self.next_per_commitment_point.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -9157,27 +9183,24 @@ impl molecule::prelude::Builder for RevokeAndAckBuilder {
         total_size += self.channel_id.as_slice().len();
         offsets.push(total_size);
         total_size += self.partial_signature.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.next_per_commitment_point.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
         writer.write_all(self.channel_id.as_slice())?;
         writer.write_all(self.partial_signature.as_slice())?;
-        writer.write_all(self.next_per_commitment_point.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        RevokeAndAck::new_unchecked(inner.into())
+        SpliceRevokeAndAck::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct RemoveTlcFulfill(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for RemoveTlcFulfill {
+pub struct SpliceLocked(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SpliceLocked {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -9186,44 +9209,77 @@ impl ::core::fmt::LowerHex for RemoveTlcFulfill {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for RemoveTlcFulfill {
+impl ::core::fmt::Debug for SpliceLocked {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for RemoveTlcFulfill {
+impl ::core::fmt::Display for SpliceLocked {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "payment_preimage", self.payment_preimage())?;
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(f, ", {}: {}", "funding_tx_hash", self.funding_tx_hash())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for RemoveTlcFulfill {
+impl ::core::default::Default for SpliceLocked {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        RemoveTlcFulfill::new_unchecked(v)
+        SpliceLocked::new_unchecked(v)
     }
 }
-impl RemoveTlcFulfill {
-    const DEFAULT_VALUE: [u8; 32] = [
+impl SpliceLocked {
+    const DEFAULT_VALUE: [u8; 76] = [
+        76, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const TOTAL_SIZE: usize = 32;
-    pub const FIELD_SIZES: [usize; 1] = [32];
-    pub const FIELD_COUNT: usize = 1;
-    pub fn payment_preimage(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(0..32))
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn as_reader<'r>(&'r self) -> RemoveTlcFulfillReader<'r> {
-        RemoveTlcFulfillReader::new_unchecked(self.as_slice())
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn channel_id(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn funding_tx_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte32::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte32::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SpliceLockedReader<'r> {
+        SpliceLockedReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for RemoveTlcFulfill {
-    type Builder = RemoveTlcFulfillBuilder;
-    const NAME: &'static str = "RemoveTlcFulfill";
+impl molecule::prelude::Entity for SpliceLocked {
+    type Builder = SpliceLockedBuilder;
+    const NAME: &'static str = "SpliceLocked";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        RemoveTlcFulfill(data)
+        SpliceLocked(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -9232,21 +9288,23 @@ impl molecule::prelude::Entity for RemoveTlcFulfill {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        RemoveTlcFulfillReader::from_slice(slice).map(|reader| reader.to_entity())
+        SpliceLockedReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        RemoveTlcFulfillReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        SpliceLockedReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().payment_preimage(self.payment_preimage())
+        Self::new_builder()
+            .channel_id(self.channel_id())
+            .funding_tx_hash(self.funding_tx_hash())
     }
 }
 #[derive(Clone, Copy)]
-pub struct RemoveTlcFulfillReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for RemoveTlcFulfillReader<'r> {
+pub struct SpliceLockedReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SpliceLockedReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -9255,80 +9313,158 @@ impl<'r> ::core::fmt::LowerHex for RemoveTlcFulfillReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for RemoveTlcFulfillReader<'r> {
+impl<'r> ::core::fmt::Debug for SpliceLockedReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for RemoveTlcFulfillReader<'r> {
+impl<'r> ::core::fmt::Display for SpliceLockedReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "payment_preimage", self.payment_preimage())?;
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(f, ", {}: {}", "funding_tx_hash", self.funding_tx_hash())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
         write!(f, " }}")
     }
 }
-impl<'r> RemoveTlcFulfillReader<'r> {
-    pub const TOTAL_SIZE: usize = 32;
-    pub const FIELD_SIZES: [usize; 1] = [32];
-    pub const FIELD_COUNT: usize = 1;
-    pub fn payment_preimage(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+impl<'r> SpliceLockedReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn channel_id(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn funding_tx_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Byte32Reader::new_unchecked(&self.as_slice()[start..])
+        }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for RemoveTlcFulfillReader<'r> {
-    type Entity = RemoveTlcFulfill;
-    const NAME: &'static str = "RemoveTlcFulfillReader";
+impl<'r> molecule::prelude::Reader<'r> for SpliceLockedReader<'r> {
+    type Entity = SpliceLocked;
+    const NAME: &'static str = "SpliceLockedReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        RemoveTlcFulfillReader(slice)
+        SpliceLockedReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
         }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct RemoveTlcFulfillBuilder {
-    pub(crate) payment_preimage: Byte32,
+pub struct SpliceLockedBuilder {
+    pub(crate) channel_id: Byte32,
+    pub(crate) funding_tx_hash: Byte32,
 }
-impl RemoveTlcFulfillBuilder {
-    pub const TOTAL_SIZE: usize = 32;
-    pub const FIELD_SIZES: [usize; 1] = [32];
-    pub const FIELD_COUNT: usize = 1;
-    pub fn payment_preimage(mut self, v: Byte32) -> Self {
-        self.payment_preimage = v;
+impl SpliceLockedBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn channel_id(mut self, v: Byte32) -> Self {
+        self.channel_id = v;
+        self
+    }
+    pub fn funding_tx_hash(mut self, v: Byte32) -> Self {
+        self.funding_tx_hash = v;
         self
     }
 }
-impl molecule::prelude::Builder for RemoveTlcFulfillBuilder {
-    type Entity = RemoveTlcFulfill;
-    const NAME: &'static str = "RemoveTlcFulfillBuilder";
+impl molecule::prelude::Builder for SpliceLockedBuilder {
+    type Entity = SpliceLocked;
+    const NAME: &'static str = "SpliceLockedBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.channel_id.as_slice().len()
+            + self.funding_tx_hash.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.payment_preimage.as_slice())?;
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.channel_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.funding_tx_hash.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.channel_id.as_slice())?;
+        writer.write_all(self.funding_tx_hash.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        RemoveTlcFulfill::new_unchecked(inner.into())
+        SpliceLocked::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct RemoveTlcFail(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for RemoveTlcFail {
+pub struct Shutdown(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Shutdown {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -9337,41 +9473,92 @@ impl ::core::fmt::LowerHex for RemoveTlcFail {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for RemoveTlcFail {
+impl ::core::fmt::Debug for Shutdown {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for RemoveTlcFail {
+impl ::core::fmt::Display for Shutdown {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "error_code", self.error_code())?;
-        write!(f, " }}")
-    }
-}
-impl ::core::default::Default for RemoveTlcFail {
-    fn default() -> Self {
-        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        RemoveTlcFail::new_unchecked(v)
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(f, ", {}: {}", "fee_rate", self.fee_rate())?;
+        write!(f, ", {}: {}", "close_script", self.close_script())?;
+        write!(f, ", {}: {}", "force", self.force())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl RemoveTlcFail {
-    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
-    pub const TOTAL_SIZE: usize = 4;
-    pub const FIELD_SIZES: [usize; 1] = [4];
-    pub const FIELD_COUNT: usize = 1;
-    pub fn error_code(&self) -> Uint32 {
-        Uint32::new_unchecked(self.0.slice(0..4))
+impl ::core::default::Default for Shutdown {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        Shutdown::new_unchecked(v)
     }
-    pub fn as_reader<'r>(&'r self) -> RemoveTlcFailReader<'r> {
-        RemoveTlcFailReader::new_unchecked(self.as_slice())
+}
+impl Shutdown {
+    const DEFAULT_VALUE: [u8; 114] = [
+        114, 0, 0, 0, 20, 0, 0, 0, 52, 0, 0, 0, 60, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 53, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn channel_id(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn fee_rate(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn close_script(&self) -> Script {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Script::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn force(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Byte::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ShutdownReader<'r> {
+        ShutdownReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for RemoveTlcFail {
-    type Builder = RemoveTlcFailBuilder;
-    const NAME: &'static str = "RemoveTlcFail";
+impl molecule::prelude::Entity for Shutdown {
+    type Builder = ShutdownBuilder;
+    const NAME: &'static str = "Shutdown";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        RemoveTlcFail(data)
+        Shutdown(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -9380,21 +9567,25 @@ impl molecule::prelude::Entity for RemoveTlcFail {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        RemoveTlcFailReader::from_slice(slice).map(|reader| reader.to_entity())
+        ShutdownReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        RemoveTlcFailReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ShutdownReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().error_code(self.error_code())
+        Self::new_builder()
+            .channel_id(self.channel_id())
+            .fee_rate(self.fee_rate())
+            .close_script(self.close_script())
+            .force(self.force())
     }
 }
 #[derive(Clone, Copy)]
-pub struct RemoveTlcFailReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for RemoveTlcFailReader<'r> {
+pub struct ShutdownReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ShutdownReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -9403,80 +9594,192 @@ impl<'r> ::core::fmt::LowerHex for RemoveTlcFailReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for RemoveTlcFailReader<'r> {
+impl<'r> ::core::fmt::Debug for ShutdownReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for RemoveTlcFailReader<'r> {
+impl<'r> ::core::fmt::Display for ShutdownReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "error_code", self.error_code())?;
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(f, ", {}: {}", "fee_rate", self.fee_rate())?;
+        write!(f, ", {}: {}", "close_script", self.close_script())?;
+        write!(f, ", {}: {}", "force", self.force())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
         write!(f, " }}")
     }
 }
-impl<'r> RemoveTlcFailReader<'r> {
-    pub const TOTAL_SIZE: usize = 4;
-    pub const FIELD_SIZES: [usize; 1] = [4];
-    pub const FIELD_COUNT: usize = 1;
-    pub fn error_code(&self) -> Uint32Reader<'r> {
-        Uint32Reader::new_unchecked(&self.as_slice()[0..4])
+impl<'r> ShutdownReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn channel_id(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn fee_rate(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn close_script(&self) -> ScriptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        ScriptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn force(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            ByteReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            ByteReader::new_unchecked(&self.as_slice()[start..])
+        }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for RemoveTlcFailReader<'r> {
-    type Entity = RemoveTlcFail;
-    const NAME: &'static str = "RemoveTlcFailReader";
+impl<'r> molecule::prelude::Reader<'r> for ShutdownReader<'r> {
+    type Entity = Shutdown;
+    const NAME: &'static str = "ShutdownReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        RemoveTlcFailReader(slice)
+        ShutdownReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
         }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        ScriptReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        ByteReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct RemoveTlcFailBuilder {
-    pub(crate) error_code: Uint32,
+pub struct ShutdownBuilder {
+    pub(crate) channel_id: Byte32,
+    pub(crate) fee_rate: Uint64,
+    pub(crate) close_script: Script,
+    pub(crate) force: Byte,
 }
-impl RemoveTlcFailBuilder {
-    pub const TOTAL_SIZE: usize = 4;
-    pub const FIELD_SIZES: [usize; 1] = [4];
-    pub const FIELD_COUNT: usize = 1;
-    pub fn error_code(mut self, v: Uint32) -> Self {
-        self.error_code = v;
+impl ShutdownBuilder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn channel_id(mut self, v: Byte32) -> Self {
+        self.channel_id = v;
+        self
+    }
+    pub fn fee_rate(mut self, v: Uint64) -> Self {
+        self.fee_rate = v;
+        self
+    }
+    pub fn close_script(mut self, v: Script) -> Self {
+        self.close_script = v;
+        self
+    }
+    pub fn force(mut self, v: Byte) -> Self {
+        self.force = v;
         self
     }
 }
-impl molecule::prelude::Builder for RemoveTlcFailBuilder {
-    type Entity = RemoveTlcFail;
-    const NAME: &'static str = "RemoveTlcFailBuilder";
+impl molecule::prelude::Builder for ShutdownBuilder {
+    type Entity = Shutdown;
+    const NAME: &'static str = "ShutdownBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.channel_id.as_slice().len()
+            + self.fee_rate.as_slice().len()
+            + self.close_script.as_slice().len()
+            + self.force.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.error_code.as_slice())?;
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.channel_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.fee_rate.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.close_script.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.force.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.channel_id.as_slice())?;
+        writer.write_all(self.fee_rate.as_slice())?;
+        writer.write_all(self.close_script.as_slice())?;
+        writer.write_all(self.force.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        RemoveTlcFail::new_unchecked(inner.into())
+        Shutdown::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct RemoveTlcReason(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for RemoveTlcReason {
+pub struct ClosingSigned(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ClosingSigned {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -9485,50 +9788,77 @@ impl ::core::fmt::LowerHex for RemoveTlcReason {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for RemoveTlcReason {
+impl ::core::fmt::Debug for ClosingSigned {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for RemoveTlcReason {
+impl ::core::fmt::Display for ClosingSigned {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}(", Self::NAME)?;
-        self.to_enum().display_inner(f)?;
-        write!(f, ")")
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(f, ", {}: {}", "partial_signature", self.partial_signature())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl ::core::default::Default for RemoveTlcReason {
+impl ::core::default::Default for ClosingSigned {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        RemoveTlcReason::new_unchecked(v)
+        ClosingSigned::new_unchecked(v)
     }
 }
-impl RemoveTlcReason {
-    const DEFAULT_VALUE: [u8; 36] = [
+impl ClosingSigned {
+    const DEFAULT_VALUE: [u8; 76] = [
+        76, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const ITEMS_COUNT: usize = 2;
-    pub fn item_id(&self) -> molecule::Number {
-        molecule::unpack_number(self.as_slice())
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn to_enum(&self) -> RemoveTlcReasonUnion {
-        let inner = self.0.slice(molecule::NUMBER_SIZE..);
-        match self.item_id() {
-            0 => RemoveTlcFulfill::new_unchecked(inner).into(),
-            1 => RemoveTlcFail::new_unchecked(inner).into(),
-            _ => panic!("{}: invalid data", Self::NAME),
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn as_reader<'r>(&'r self) -> RemoveTlcReasonReader<'r> {
-        RemoveTlcReasonReader::new_unchecked(self.as_slice())
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn channel_id(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn partial_signature(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte32::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte32::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ClosingSignedReader<'r> {
+        ClosingSignedReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for RemoveTlcReason {
-    type Builder = RemoveTlcReasonBuilder;
-    const NAME: &'static str = "RemoveTlcReason";
+impl molecule::prelude::Entity for ClosingSigned {
+    type Builder = ClosingSignedBuilder;
+    const NAME: &'static str = "ClosingSigned";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        RemoveTlcReason(data)
+        ClosingSigned(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -9537,21 +9867,23 @@ impl molecule::prelude::Entity for RemoveTlcReason {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        RemoveTlcReasonReader::from_slice(slice).map(|reader| reader.to_entity())
+        ClosingSignedReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        RemoveTlcReasonReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ClosingSignedReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set(self.to_enum())
+        Self::new_builder()
+            .channel_id(self.channel_id())
+            .partial_signature(self.partial_signature())
     }
 }
 #[derive(Clone, Copy)]
-pub struct RemoveTlcReasonReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for RemoveTlcReasonReader<'r> {
+pub struct ClosingSignedReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ClosingSignedReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -9560,40 +9892,66 @@ impl<'r> ::core::fmt::LowerHex for RemoveTlcReasonReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for RemoveTlcReasonReader<'r> {
+impl<'r> ::core::fmt::Debug for ClosingSignedReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for RemoveTlcReasonReader<'r> {
+impl<'r> ::core::fmt::Display for ClosingSignedReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}(", Self::NAME)?;
-        self.to_enum().display_inner(f)?;
-        write!(f, ")")
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(f, ", {}: {}", "partial_signature", self.partial_signature())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl<'r> RemoveTlcReasonReader<'r> {
-    pub const ITEMS_COUNT: usize = 2;
-    pub fn item_id(&self) -> molecule::Number {
-        molecule::unpack_number(self.as_slice())
+impl<'r> ClosingSignedReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn to_enum(&self) -> RemoveTlcReasonUnionReader<'r> {
-        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
-        match self.item_id() {
-            0 => RemoveTlcFulfillReader::new_unchecked(inner).into(),
-            1 => RemoveTlcFailReader::new_unchecked(inner).into(),
-            _ => panic!("{}: invalid data", Self::NAME),
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn channel_id(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn partial_signature(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Byte32Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for RemoveTlcReasonReader<'r> {
-    type Entity = RemoveTlcReason;
-    const NAME: &'static str = "RemoveTlcReasonReader";
+impl<'r> molecule::prelude::Reader<'r> for ClosingSignedReader<'r> {
+    type Entity = ClosingSigned;
+    const NAME: &'static str = "ClosingSignedReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        RemoveTlcReasonReader(slice)
+        ClosingSignedReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -9604,206 +9962,111 @@ impl<'r> molecule::prelude::Reader<'r> for RemoveTlcReasonReader<'r> {
         if slice_len < molecule::NUMBER_SIZE {
             return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-        let item_id = molecule::unpack_number(slice);
-        let inner_slice = &slice[molecule::NUMBER_SIZE..];
-        match item_id {
-            0 => RemoveTlcFulfillReader::verify(inner_slice, compatible),
-            1 => RemoveTlcFailReader::verify(inner_slice, compatible),
-            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
-        }?;
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct RemoveTlcReasonBuilder(pub(crate) RemoveTlcReasonUnion);
-impl RemoveTlcReasonBuilder {
-    pub const ITEMS_COUNT: usize = 2;
-    pub fn set<I>(mut self, v: I) -> Self
-    where
-        I: ::core::convert::Into<RemoveTlcReasonUnion>,
-    {
-        self.0 = v.into();
+pub struct ClosingSignedBuilder {
+    pub(crate) channel_id: Byte32,
+    pub(crate) partial_signature: Byte32,
+}
+impl ClosingSignedBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn channel_id(mut self, v: Byte32) -> Self {
+        self.channel_id = v;
+        self
+    }
+    pub fn partial_signature(mut self, v: Byte32) -> Self {
+        self.partial_signature = v;
         self
     }
 }
-impl molecule::prelude::Builder for RemoveTlcReasonBuilder {
-    type Entity = RemoveTlcReason;
-    const NAME: &'static str = "RemoveTlcReasonBuilder";
+impl molecule::prelude::Builder for ClosingSignedBuilder {
+    type Entity = ClosingSigned;
+    const NAME: &'static str = "ClosingSignedBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + self.0.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.channel_id.as_slice().len()
+            + self.partial_signature.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
-        writer.write_all(self.0.as_slice())
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.channel_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.partial_signature.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.channel_id.as_slice())?;
+        writer.write_all(self.partial_signature.as_slice())?;
+        Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        RemoveTlcReason::new_unchecked(inner.into())
+        ClosingSigned::new_unchecked(inner.into())
     }
 }
-#[derive(Debug, Clone)]
-pub enum RemoveTlcReasonUnion {
-    RemoveTlcFulfill(RemoveTlcFulfill),
-    RemoveTlcFail(RemoveTlcFail),
+#[derive(Clone)]
+pub struct AddTlc(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for AddTlc {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
 }
-#[derive(Debug, Clone, Copy)]
-pub enum RemoveTlcReasonUnionReader<'r> {
-    RemoveTlcFulfill(RemoveTlcFulfillReader<'r>),
-    RemoveTlcFail(RemoveTlcFailReader<'r>),
-}
-impl ::core::default::Default for RemoveTlcReasonUnion {
-    fn default() -> Self {
-        RemoveTlcReasonUnion::RemoveTlcFulfill(::core::default::Default::default())
-    }
-}
-impl ::core::fmt::Display for RemoveTlcReasonUnion {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            RemoveTlcReasonUnion::RemoveTlcFulfill(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, RemoveTlcFulfill::NAME, item)
-            }
-            RemoveTlcReasonUnion::RemoveTlcFail(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, RemoveTlcFail::NAME, item)
-            }
-        }
-    }
-}
-impl<'r> ::core::fmt::Display for RemoveTlcReasonUnionReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            RemoveTlcReasonUnionReader::RemoveTlcFulfill(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, RemoveTlcFulfill::NAME, item)
-            }
-            RemoveTlcReasonUnionReader::RemoveTlcFail(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, RemoveTlcFail::NAME, item)
-            }
-        }
-    }
-}
-impl RemoveTlcReasonUnion {
-    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            RemoveTlcReasonUnion::RemoveTlcFulfill(ref item) => write!(f, "{}", item),
-            RemoveTlcReasonUnion::RemoveTlcFail(ref item) => write!(f, "{}", item),
-        }
-    }
-}
-impl<'r> RemoveTlcReasonUnionReader<'r> {
-    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            RemoveTlcReasonUnionReader::RemoveTlcFulfill(ref item) => write!(f, "{}", item),
-            RemoveTlcReasonUnionReader::RemoveTlcFail(ref item) => write!(f, "{}", item),
-        }
-    }
-}
-impl ::core::convert::From<RemoveTlcFulfill> for RemoveTlcReasonUnion {
-    fn from(item: RemoveTlcFulfill) -> Self {
-        RemoveTlcReasonUnion::RemoveTlcFulfill(item)
-    }
-}
-impl ::core::convert::From<RemoveTlcFail> for RemoveTlcReasonUnion {
-    fn from(item: RemoveTlcFail) -> Self {
-        RemoveTlcReasonUnion::RemoveTlcFail(item)
-    }
-}
-impl<'r> ::core::convert::From<RemoveTlcFulfillReader<'r>> for RemoveTlcReasonUnionReader<'r> {
-    fn from(item: RemoveTlcFulfillReader<'r>) -> Self {
-        RemoveTlcReasonUnionReader::RemoveTlcFulfill(item)
-    }
-}
-impl<'r> ::core::convert::From<RemoveTlcFailReader<'r>> for RemoveTlcReasonUnionReader<'r> {
-    fn from(item: RemoveTlcFailReader<'r>) -> Self {
-        RemoveTlcReasonUnionReader::RemoveTlcFail(item)
-    }
-}
-impl RemoveTlcReasonUnion {
-    pub const NAME: &'static str = "RemoveTlcReasonUnion";
-    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
-        match self {
-            RemoveTlcReasonUnion::RemoveTlcFulfill(item) => item.as_bytes(),
-            RemoveTlcReasonUnion::RemoveTlcFail(item) => item.as_bytes(),
-        }
-    }
-    pub fn as_slice(&self) -> &[u8] {
-        match self {
-            RemoveTlcReasonUnion::RemoveTlcFulfill(item) => item.as_slice(),
-            RemoveTlcReasonUnion::RemoveTlcFail(item) => item.as_slice(),
-        }
-    }
-    pub fn item_id(&self) -> molecule::Number {
-        match self {
-            RemoveTlcReasonUnion::RemoveTlcFulfill(_) => 0,
-            RemoveTlcReasonUnion::RemoveTlcFail(_) => 1,
-        }
-    }
-    pub fn item_name(&self) -> &str {
-        match self {
-            RemoveTlcReasonUnion::RemoveTlcFulfill(_) => "RemoveTlcFulfill",
-            RemoveTlcReasonUnion::RemoveTlcFail(_) => "RemoveTlcFail",
-        }
-    }
-    pub fn as_reader<'r>(&'r self) -> RemoveTlcReasonUnionReader<'r> {
-        match self {
-            RemoveTlcReasonUnion::RemoveTlcFulfill(item) => item.as_reader().into(),
-            RemoveTlcReasonUnion::RemoveTlcFail(item) => item.as_reader().into(),
-        }
-    }
-}
-impl<'r> RemoveTlcReasonUnionReader<'r> {
-    pub const NAME: &'r str = "RemoveTlcReasonUnionReader";
-    pub fn as_slice(&self) -> &'r [u8] {
-        match self {
-            RemoveTlcReasonUnionReader::RemoveTlcFulfill(item) => item.as_slice(),
-            RemoveTlcReasonUnionReader::RemoveTlcFail(item) => item.as_slice(),
-        }
-    }
-    pub fn item_id(&self) -> molecule::Number {
-        match self {
-            RemoveTlcReasonUnionReader::RemoveTlcFulfill(_) => 0,
-            RemoveTlcReasonUnionReader::RemoveTlcFail(_) => 1,
-        }
-    }
-    pub fn item_name(&self) -> &str {
-        match self {
-            RemoveTlcReasonUnionReader::RemoveTlcFulfill(_) => "RemoveTlcFulfill",
-            RemoveTlcReasonUnionReader::RemoveTlcFail(_) => "RemoveTlcFail",
-        }
-    }
-}
-impl From<RemoveTlcFulfill> for RemoveTlcReason {
-    fn from(value: RemoveTlcFulfill) -> Self {
-        Self::new_builder().set(value).build()
-    }
-}
-impl From<RemoveTlcFail> for RemoveTlcReason {
-    fn from(value: RemoveTlcFail) -> Self {
-        Self::new_builder().set(value).build()
-    }
-}
-#[derive(Clone)]
-pub struct RemoveTlc(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for RemoveTlc {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
-    }
-}
-impl ::core::fmt::Debug for RemoveTlc {
+impl ::core::fmt::Debug for AddTlc {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for RemoveTlc {
+impl ::core::fmt::Display for AddTlc {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "channel_id", self.channel_id())?;
         write!(f, ", {}: {}", "tlc_id", self.tlc_id())?;
-        write!(f, ", {}: {}", "reason", self.reason())?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, ", {}: {}", "payment_hash", self.payment_hash())?;
+        write!(f, ", {}: {}", "expiry", self.expiry())?;
+        write!(f, ", {}: {}", "hash_algorithm", self.hash_algorithm())?;
+        write!(f, ", {}: {}", "onion_packet", self.onion_packet())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -9811,20 +10074,21 @@ impl ::core::fmt::Display for RemoveTlc {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for RemoveTlc {
+impl ::core::default::Default for AddTlc {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        RemoveTlc::new_unchecked(v)
+        AddTlc::new_unchecked(v)
     }
 }
-impl RemoveTlc {
-    const DEFAULT_VALUE: [u8; 92] = [
-        92, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl AddTlc {
+    const DEFAULT_VALUE: [u8; 133] = [
+        133, 0, 0, 0, 32, 0, 0, 0, 64, 0, 0, 0, 72, 0, 0, 0, 88, 0, 0, 0, 120, 0, 0, 0, 128, 0, 0,
+        0, 129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 7;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -9853,25 +10117,49 @@ impl RemoveTlc {
         let end = molecule::unpack_number(&slice[12..]) as usize;
         Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn reason(&self) -> RemoveTlcReason {
+    pub fn amount(&self) -> Uint128 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint128::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn payment_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn expiry(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn hash_algorithm(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn onion_packet(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            RemoveTlcReason::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[32..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start..end))
         } else {
-            RemoveTlcReason::new_unchecked(self.0.slice(start..))
+            Bytes::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> RemoveTlcReader<'r> {
-        RemoveTlcReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> AddTlcReader<'r> {
+        AddTlcReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for RemoveTlc {
-    type Builder = RemoveTlcBuilder;
-    const NAME: &'static str = "RemoveTlc";
+impl molecule::prelude::Entity for AddTlc {
+    type Builder = AddTlcBuilder;
+    const NAME: &'static str = "AddTlc";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        RemoveTlc(data)
+        AddTlc(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -9880,10 +10168,10 @@ impl molecule::prelude::Entity for RemoveTlc {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        RemoveTlcReader::from_slice(slice).map(|reader| reader.to_entity())
+        AddTlcReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        RemoveTlcReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        AddTlcReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -9892,12 +10180,16 @@ impl molecule::prelude::Entity for RemoveTlc {
         Self::new_builder()
             .channel_id(self.channel_id())
             .tlc_id(self.tlc_id())
-            .reason(self.reason())
+            .amount(self.amount())
+            .payment_hash(self.payment_hash())
+            .expiry(self.expiry())
+            .hash_algorithm(self.hash_algorithm())
+            .onion_packet(self.onion_packet())
     }
 }
 #[derive(Clone, Copy)]
-pub struct RemoveTlcReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for RemoveTlcReader<'r> {
+pub struct AddTlcReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for AddTlcReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -9906,17 +10198,21 @@ impl<'r> ::core::fmt::LowerHex for RemoveTlcReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for RemoveTlcReader<'r> {
+impl<'r> ::core::fmt::Debug for AddTlcReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for RemoveTlcReader<'r> {
+impl<'r> ::core::fmt::Display for AddTlcReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "channel_id", self.channel_id())?;
         write!(f, ", {}: {}", "tlc_id", self.tlc_id())?;
-        write!(f, ", {}: {}", "reason", self.reason())?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, ", {}: {}", "payment_hash", self.payment_hash())?;
+        write!(f, ", {}: {}", "expiry", self.expiry())?;
+        write!(f, ", {}: {}", "hash_algorithm", self.hash_algorithm())?;
+        write!(f, ", {}: {}", "onion_packet", self.onion_packet())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -9924,8 +10220,8 @@ impl<'r> ::core::fmt::Display for RemoveTlcReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> RemoveTlcReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+impl<'r> AddTlcReader<'r> {
+    pub const FIELD_COUNT: usize = 7;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -9954,25 +10250,49 @@ impl<'r> RemoveTlcReader<'r> {
         let end = molecule::unpack_number(&slice[12..]) as usize;
         Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn reason(&self) -> RemoveTlcReasonReader<'r> {
+    pub fn amount(&self) -> Uint128Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            RemoveTlcReasonReader::new_unchecked(&self.as_slice()[start..end])
-        } else {
-            RemoveTlcReasonReader::new_unchecked(&self.as_slice()[start..])
-        }
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for RemoveTlcReader<'r> {
-    type Entity = RemoveTlc;
-    const NAME: &'static str = "RemoveTlcReader";
-    fn to_entity(&self) -> Self::Entity {
+    pub fn payment_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn expiry(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn hash_algorithm(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn onion_packet(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[32..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for AddTlcReader<'r> {
+    type Entity = AddTlc;
+    const NAME: &'static str = "AddTlcReader";
+    fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        RemoveTlcReader(slice)
+        AddTlcReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -10013,18 +10333,26 @@ impl<'r> molecule::prelude::Reader<'r> for RemoveTlcReader<'r> {
         }
         Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        RemoveTlcReasonReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        ByteReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        BytesReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct RemoveTlcBuilder {
+pub struct AddTlcBuilder {
     pub(crate) channel_id: Byte32,
     pub(crate) tlc_id: Uint64,
-    pub(crate) reason: RemoveTlcReason,
+    pub(crate) amount: Uint128,
+    pub(crate) payment_hash: Byte32,
+    pub(crate) expiry: Uint64,
+    pub(crate) hash_algorithm: Byte,
+    pub(crate) onion_packet: Bytes,
 }
-impl RemoveTlcBuilder {
-    pub const FIELD_COUNT: usize = 3;
+impl AddTlcBuilder {
+    pub const FIELD_COUNT: usize = 7;
     pub fn channel_id(mut self, v: Byte32) -> Self {
         self.channel_id = v;
         self
@@ -10033,19 +10361,39 @@ impl RemoveTlcBuilder {
         self.tlc_id = v;
         self
     }
-    pub fn reason(mut self, v: RemoveTlcReason) -> Self {
-        self.reason = v;
+    pub fn amount(mut self, v: Uint128) -> Self {
+        self.amount = v;
+        self
+    }
+    pub fn payment_hash(mut self, v: Byte32) -> Self {
+        self.payment_hash = v;
+        self
+    }
+    pub fn expiry(mut self, v: Uint64) -> Self {
+        self.expiry = v;
+        self
+    }
+    pub fn hash_algorithm(mut self, v: Byte) -> Self {
+        self.hash_algorithm = v;
+        self
+    }
+    pub fn onion_packet(mut self, v: Bytes) -> Self {
+        self.onion_packet = v;
         self
     }
 }
-impl molecule::prelude::Builder for RemoveTlcBuilder {
-    type Entity = RemoveTlc;
-    const NAME: &'static str = "RemoveTlcBuilder";
+impl molecule::prelude::Builder for AddTlcBuilder {
+    type Entity = AddTlc;
+    const NAME: &'static str = "AddTlcBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.channel_id.as_slice().len()
             + self.tlc_id.as_slice().len()
-            + self.reason.as_slice().len()
+            + self.amount.as_slice().len()
+            + self.payment_hash.as_slice().len()
+            + self.expiry.as_slice().len()
+            + self.hash_algorithm.as_slice().len()
+            + self.onion_packet.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -10055,26 +10403,38 @@ impl molecule::prelude::Builder for RemoveTlcBuilder {
         offsets.push(total_size);
         total_size += self.tlc_id.as_slice().len();
         offsets.push(total_size);
-        total_size += self.reason.as_slice().len();
+        total_size += self.amount.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.payment_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.expiry.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.hash_algorithm.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.onion_packet.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
         writer.write_all(self.channel_id.as_slice())?;
         writer.write_all(self.tlc_id.as_slice())?;
-        writer.write_all(self.reason.as_slice())?;
+        writer.write_all(self.amount.as_slice())?;
+        writer.write_all(self.payment_hash.as_slice())?;
+        writer.write_all(self.expiry.as_slice())?;
+        writer.write_all(self.hash_algorithm.as_slice())?;
+        writer.write_all(self.onion_packet.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        RemoveTlc::new_unchecked(inner.into())
+        AddTlc::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct ReestablishChannel(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ReestablishChannel {
+pub struct RevokeAndAck(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RevokeAndAck {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -10083,26 +10443,21 @@ impl ::core::fmt::LowerHex for ReestablishChannel {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ReestablishChannel {
+impl ::core::fmt::Debug for RevokeAndAck {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ReestablishChannel {
+impl ::core::fmt::Display for RevokeAndAck {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(f, ", {}: {}", "partial_signature", self.partial_signature())?;
         write!(
             f,
             ", {}: {}",
-            "local_commitment_number",
-            self.local_commitment_number()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "remote_commitment_number",
-            self.remote_commitment_number()
+            "next_per_commitment_point",
+            self.next_per_commitment_point()
         )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
@@ -10111,17 +10466,18 @@ impl ::core::fmt::Display for ReestablishChannel {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for ReestablishChannel {
+impl ::core::default::Default for RevokeAndAck {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        ReestablishChannel::new_unchecked(v)
+        RevokeAndAck::new_unchecked(v)
     }
 }
-impl ReestablishChannel {
-    const DEFAULT_VALUE: [u8; 64] = [
-        64, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl RevokeAndAck {
+    const DEFAULT_VALUE: [u8; 113] = [
+        113, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
     pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
@@ -10146,31 +10502,31 @@ impl ReestablishChannel {
         let end = molecule::unpack_number(&slice[8..]) as usize;
         Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn local_commitment_number(&self) -> Uint64 {
+    pub fn partial_signature(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
+        Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn remote_commitment_number(&self) -> Uint64 {
+    pub fn next_per_commitment_point(&self) -> Pubkey {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[16..]) as usize;
-            Uint64::new_unchecked(self.0.slice(start..end))
+            Pubkey::new_unchecked(self.0.slice(start..end))
         } else {
-            Uint64::new_unchecked(self.0.slice(start..))
+            Pubkey::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> ReestablishChannelReader<'r> {
-        ReestablishChannelReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> RevokeAndAckReader<'r> {
+        RevokeAndAckReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ReestablishChannel {
-    type Builder = ReestablishChannelBuilder;
-    const NAME: &'static str = "ReestablishChannel";
+impl molecule::prelude::Entity for RevokeAndAck {
+    type Builder = RevokeAndAckBuilder;
+    const NAME: &'static str = "RevokeAndAck";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ReestablishChannel(data)
+        RevokeAndAck(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -10179,10 +10535,10 @@ impl molecule::prelude::Entity for ReestablishChannel {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ReestablishChannelReader::from_slice(slice).map(|reader| reader.to_entity())
+        RevokeAndAckReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ReestablishChannelReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        RevokeAndAckReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -10190,13 +10546,13 @@ impl molecule::prelude::Entity for ReestablishChannel {
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
             .channel_id(self.channel_id())
-            .local_commitment_number(self.local_commitment_number())
-            .remote_commitment_number(self.remote_commitment_number())
+            .partial_signature(self.partial_signature())
+            .next_per_commitment_point(self.next_per_commitment_point())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ReestablishChannelReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ReestablishChannelReader<'r> {
+pub struct RevokeAndAckReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RevokeAndAckReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -10205,26 +10561,21 @@ impl<'r> ::core::fmt::LowerHex for ReestablishChannelReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ReestablishChannelReader<'r> {
+impl<'r> ::core::fmt::Debug for RevokeAndAckReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ReestablishChannelReader<'r> {
+impl<'r> ::core::fmt::Display for RevokeAndAckReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(f, ", {}: {}", "partial_signature", self.partial_signature())?;
         write!(
             f,
             ", {}: {}",
-            "local_commitment_number",
-            self.local_commitment_number()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "remote_commitment_number",
-            self.remote_commitment_number()
+            "next_per_commitment_point",
+            self.next_per_commitment_point()
         )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
@@ -10233,7 +10584,7 @@ impl<'r> ::core::fmt::Display for ReestablishChannelReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> ReestablishChannelReader<'r> {
+impl<'r> RevokeAndAckReader<'r> {
     pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -10257,31 +10608,31 @@ impl<'r> ReestablishChannelReader<'r> {
         let end = molecule::unpack_number(&slice[8..]) as usize;
         Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn local_commitment_number(&self) -> Uint64Reader<'r> {
+    pub fn partial_signature(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn remote_commitment_number(&self) -> Uint64Reader<'r> {
+    pub fn next_per_commitment_point(&self) -> PubkeyReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[16..]) as usize;
-            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+            PubkeyReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Uint64Reader::new_unchecked(&self.as_slice()[start..])
+            PubkeyReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ReestablishChannelReader<'r> {
-    type Entity = ReestablishChannel;
-    const NAME: &'static str = "ReestablishChannelReader";
+impl<'r> molecule::prelude::Reader<'r> for RevokeAndAckReader<'r> {
+    type Entity = RevokeAndAck;
+    const NAME: &'static str = "RevokeAndAckReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ReestablishChannelReader(slice)
+        RevokeAndAckReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -10321,40 +10672,40 @@ impl<'r> molecule::prelude::Reader<'r> for ReestablishChannelReader<'r> {
             return ve!(Self, OffsetsNotMatch);
         }
         Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        PubkeyReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct ReestablishChannelBuilder {
+pub struct RevokeAndAckBuilder {
     pub(crate) channel_id: Byte32,
-    pub(crate) local_commitment_number: Uint64,
-    pub(crate) remote_commitment_number: Uint64,
+    pub(crate) partial_signature: Byte32,
+    pub(crate) next_per_commitment_point: Pubkey,
 }
-impl ReestablishChannelBuilder {
+impl RevokeAndAckBuilder {
     pub const FIELD_COUNT: usize = 3;
     pub fn channel_id(mut self, v: Byte32) -> Self {
         self.channel_id = v;
         self
     }
-    pub fn local_commitment_number(mut self, v: Uint64) -> Self {
-        self.local_commitment_number = v;
+    pub fn partial_signature(mut self, v: Byte32) -> Self {
+        self.partial_signature = v;
         self
     }
-    pub fn remote_commitment_number(mut self, v: Uint64) -> Self {
-        self.remote_commitment_number = v;
+    pub fn next_per_commitment_point(mut self, v: Pubkey) -> Self {
+        self.next_per_commitment_point = v;
         self
     }
 }
-impl molecule::prelude::Builder for ReestablishChannelBuilder {
-    type Entity = ReestablishChannel;
-    const NAME: &'static str = "ReestablishChannelBuilder";
+impl molecule::prelude::Builder for RevokeAndAckBuilder {
+    type Entity = RevokeAndAck;
+    const NAME: &'static str = "RevokeAndAckBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.channel_id.as_slice().len()
-            + self.local_commitment_number.as_slice().len()
-            + self.remote_commitment_number.as_slice().len()
+            + self.partial_signature.as_slice().len()
+            + self.next_per_commitment_point.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -10362,28 +10713,28 @@ impl molecule::prelude::Builder for ReestablishChannelBuilder {
         offsets.push(total_size);
         total_size += self.channel_id.as_slice().len();
         offsets.push(total_size);
-        total_size += self.local_commitment_number.as_slice().len();
+        total_size += self.partial_signature.as_slice().len();
         offsets.push(total_size);
-        total_size += self.remote_commitment_number.as_slice().len();
+        total_size += self.next_per_commitment_point.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
         writer.write_all(self.channel_id.as_slice())?;
-        writer.write_all(self.local_commitment_number.as_slice())?;
-        writer.write_all(self.remote_commitment_number.as_slice())?;
+        writer.write_all(self.partial_signature.as_slice())?;
+        writer.write_all(self.next_per_commitment_point.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ReestablishChannel::new_unchecked(inner.into())
+        RevokeAndAck::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct AnnouncementSignatures(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for AnnouncementSignatures {
+pub struct RemoveTlcFulfill(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RemoveTlcFulfill {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -10392,18 +10743,166 @@ impl ::core::fmt::LowerHex for AnnouncementSignatures {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for AnnouncementSignatures {
+impl ::core::fmt::Debug for RemoveTlcFulfill {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for AnnouncementSignatures {
+impl ::core::fmt::Display for RemoveTlcFulfill {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "channel_id", self.channel_id())?;
-        write!(f, ", {}: {}", "channel_outpoint", self.channel_outpoint())?;
-        write!(f, ", {}: {}", "node_signature", self.node_signature())?;
-        write!(f, ", {}: {}", "partial_signature", self.partial_signature())?;
+        write!(f, "{}: {}", "payment_preimage", self.payment_preimage())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for RemoveTlcFulfill {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        RemoveTlcFulfill::new_unchecked(v)
+    }
+}
+impl RemoveTlcFulfill {
+    const DEFAULT_VALUE: [u8; 32] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 32;
+    pub const FIELD_SIZES: [usize; 1] = [32];
+    pub const FIELD_COUNT: usize = 1;
+    pub fn payment_preimage(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn as_reader<'r>(&'r self) -> RemoveTlcFulfillReader<'r> {
+        RemoveTlcFulfillReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for RemoveTlcFulfill {
+    type Builder = RemoveTlcFulfillBuilder;
+    const NAME: &'static str = "RemoveTlcFulfill";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        RemoveTlcFulfill(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RemoveTlcFulfillReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RemoveTlcFulfillReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().payment_preimage(self.payment_preimage())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct RemoveTlcFulfillReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RemoveTlcFulfillReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for RemoveTlcFulfillReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for RemoveTlcFulfillReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "payment_preimage", self.payment_preimage())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> RemoveTlcFulfillReader<'r> {
+    pub const TOTAL_SIZE: usize = 32;
+    pub const FIELD_SIZES: [usize; 1] = [32];
+    pub const FIELD_COUNT: usize = 1;
+    pub fn payment_preimage(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for RemoveTlcFulfillReader<'r> {
+    type Entity = RemoveTlcFulfill;
+    const NAME: &'static str = "RemoveTlcFulfillReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        RemoveTlcFulfillReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct RemoveTlcFulfillBuilder {
+    pub(crate) payment_preimage: Byte32,
+}
+impl RemoveTlcFulfillBuilder {
+    pub const TOTAL_SIZE: usize = 32;
+    pub const FIELD_SIZES: [usize; 1] = [32];
+    pub const FIELD_COUNT: usize = 1;
+    pub fn payment_preimage(mut self, v: Byte32) -> Self {
+        self.payment_preimage = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for RemoveTlcFulfillBuilder {
+    type Entity = RemoveTlcFulfill;
+    const NAME: &'static str = "RemoveTlcFulfillBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.payment_preimage.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        RemoveTlcFulfill::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct RemoveTlcFail(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RemoveTlcFail {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for RemoveTlcFail {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for RemoveTlcFail {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "onion_packet", self.onion_packet())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -10411,21 +10910,15 @@ impl ::core::fmt::Display for AnnouncementSignatures {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for AnnouncementSignatures {
+impl ::core::default::Default for RemoveTlcFail {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        AnnouncementSignatures::new_unchecked(v)
+        RemoveTlcFail::new_unchecked(v)
     }
 }
-impl AnnouncementSignatures {
-    const DEFAULT_VALUE: [u8; 124] = [
-        124, 0, 0, 0, 20, 0, 0, 0, 52, 0, 0, 0, 88, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0,
-    ];
-    pub const FIELD_COUNT: usize = 4;
+impl RemoveTlcFail {
+    const DEFAULT_VALUE: [u8; 12] = [12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0];
+    pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -10442,43 +10935,25 @@ impl AnnouncementSignatures {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn channel_id(&self) -> Byte32 {
+    pub fn onion_packet(&self) -> Bytes {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn channel_outpoint(&self) -> OutPoint {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        OutPoint::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn node_signature(&self) -> EcdsaSignature {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        EcdsaSignature::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn partial_signature(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            Byte32::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte32::new_unchecked(self.0.slice(start..))
+            Bytes::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> AnnouncementSignaturesReader<'r> {
-        AnnouncementSignaturesReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> RemoveTlcFailReader<'r> {
+        RemoveTlcFailReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for AnnouncementSignatures {
-    type Builder = AnnouncementSignaturesBuilder;
-    const NAME: &'static str = "AnnouncementSignatures";
+impl molecule::prelude::Entity for RemoveTlcFail {
+    type Builder = RemoveTlcFailBuilder;
+    const NAME: &'static str = "RemoveTlcFail";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        AnnouncementSignatures(data)
+        RemoveTlcFail(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -10487,25 +10962,21 @@ impl molecule::prelude::Entity for AnnouncementSignatures {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AnnouncementSignaturesReader::from_slice(slice).map(|reader| reader.to_entity())
+        RemoveTlcFailReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AnnouncementSignaturesReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        RemoveTlcFailReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .channel_id(self.channel_id())
-            .channel_outpoint(self.channel_outpoint())
-            .node_signature(self.node_signature())
-            .partial_signature(self.partial_signature())
+        Self::new_builder().onion_packet(self.onion_packet())
     }
 }
 #[derive(Clone, Copy)]
-pub struct AnnouncementSignaturesReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for AnnouncementSignaturesReader<'r> {
+pub struct RemoveTlcFailReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RemoveTlcFailReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -10514,18 +10985,15 @@ impl<'r> ::core::fmt::LowerHex for AnnouncementSignaturesReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for AnnouncementSignaturesReader<'r> {
+impl<'r> ::core::fmt::Debug for RemoveTlcFailReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for AnnouncementSignaturesReader<'r> {
+impl<'r> ::core::fmt::Display for RemoveTlcFailReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "channel_id", self.channel_id())?;
-        write!(f, ", {}: {}", "channel_outpoint", self.channel_outpoint())?;
-        write!(f, ", {}: {}", "node_signature", self.node_signature())?;
-        write!(f, ", {}: {}", "partial_signature", self.partial_signature())?;
+        write!(f, "{}: {}", "onion_packet", self.onion_packet())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -10533,8 +11001,8 @@ impl<'r> ::core::fmt::Display for AnnouncementSignaturesReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> AnnouncementSignaturesReader<'r> {
-    pub const FIELD_COUNT: usize = 4;
+impl<'r> RemoveTlcFailReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -10551,43 +11019,25 @@ impl<'r> AnnouncementSignaturesReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn channel_id(&self) -> Byte32Reader<'r> {
+    pub fn onion_packet(&self) -> BytesReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn channel_outpoint(&self) -> OutPointReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        OutPointReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn node_signature(&self) -> EcdsaSignatureReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        EcdsaSignatureReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn partial_signature(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Byte32Reader::new_unchecked(&self.as_slice()[start..])
+            BytesReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for AnnouncementSignaturesReader<'r> {
-    type Entity = AnnouncementSignatures;
-    const NAME: &'static str = "AnnouncementSignaturesReader";
+impl<'r> molecule::prelude::Reader<'r> for RemoveTlcFailReader<'r> {
+    type Entity = RemoveTlcFail;
+    const NAME: &'static str = "RemoveTlcFailReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        AnnouncementSignaturesReader(slice)
+        RemoveTlcFailReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -10626,80 +11076,49 @@ impl<'r> molecule::prelude::Reader<'r> for AnnouncementSignaturesReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        OutPointReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        EcdsaSignatureReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct AnnouncementSignaturesBuilder {
-    pub(crate) channel_id: Byte32,
-    pub(crate) channel_outpoint: OutPoint,
-    pub(crate) node_signature: EcdsaSignature,
-    pub(crate) partial_signature: Byte32,
+pub struct RemoveTlcFailBuilder {
+    pub(crate) onion_packet: Bytes,
 }
-impl AnnouncementSignaturesBuilder {
-    pub const FIELD_COUNT: usize = 4;
-    pub fn channel_id(mut self, v: Byte32) -> Self {
-        self.channel_id = v;
-        self
-    }
-    pub fn channel_outpoint(mut self, v: OutPoint) -> Self {
-        self.channel_outpoint = v;
-        self
-    }
-    pub fn node_signature(mut self, v: EcdsaSignature) -> Self {
-        self.node_signature = v;
-        self
-    }
-    pub fn partial_signature(mut self, v: Byte32) -> Self {
-        self.partial_signature = v;
+impl RemoveTlcFailBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn onion_packet(mut self, v: Bytes) -> Self {
+        self.onion_packet = v;
         self
     }
 }
-impl molecule::prelude::Builder for AnnouncementSignaturesBuilder {
-    type Entity = AnnouncementSignatures;
-    const NAME: &'static str = "AnnouncementSignaturesBuilder";
+impl molecule::prelude::Builder for RemoveTlcFailBuilder {
+    type Entity = RemoveTlcFail;
+    const NAME: &'static str = "RemoveTlcFailBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.channel_id.as_slice().len()
-            + self.channel_outpoint.as_slice().len()
-            + self.node_signature.as_slice().len()
-            + self.partial_signature.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.onion_packet.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.channel_id.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.channel_outpoint.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.node_signature.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.partial_signature.as_slice().len();
+        total_size += self.onion_packet.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.channel_id.as_slice())?;
-        writer.write_all(self.channel_outpoint.as_slice())?;
-        writer.write_all(self.node_signature.as_slice())?;
-        writer.write_all(self.partial_signature.as_slice())?;
+        writer.write_all(self.onion_packet.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        AnnouncementSignatures::new_unchecked(inner.into())
+        RemoveTlcFail::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct NodeAnnouncement(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for NodeAnnouncement {
+pub struct RemoveTlcReason(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RemoveTlcReason {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -10708,115 +11127,50 @@ impl ::core::fmt::LowerHex for NodeAnnouncement {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for NodeAnnouncement {
+impl ::core::fmt::Debug for RemoveTlcReason {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for NodeAnnouncement {
+impl ::core::fmt::Display for RemoveTlcReason {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "signature", self.signature())?;
-        write!(f, ", {}: {}", "features", self.features())?;
-        write!(f, ", {}: {}", "timestamp", self.timestamp())?;
-        write!(f, ", {}: {}", "node_id", self.node_id())?;
-        write!(f, ", {}: {}", "alias", self.alias())?;
-        write!(f, ", {}: {}", "address", self.address())?;
-        write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
     }
 }
-impl ::core::default::Default for NodeAnnouncement {
+impl ::core::default::Default for RemoveTlcReason {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        NodeAnnouncement::new_unchecked(v)
+        RemoveTlcReason::new_unchecked(v)
     }
 }
-impl NodeAnnouncement {
-    const DEFAULT_VALUE: [u8; 153] = [
-        153, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 44, 0, 0, 0, 52, 0, 0, 0, 85, 0, 0, 0, 117, 0, 0,
-        0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl RemoveTlcReason {
+    const DEFAULT_VALUE: [u8; 36] = [
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 7;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn signature(&self) -> EcdsaSignature {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        EcdsaSignature::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn features(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn timestamp(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn node_id(&self) -> Pubkey {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Pubkey::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn alias(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn address(&self) -> BytesVec {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        BytesVec::new_unchecked(self.0.slice(start..end))
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
     }
-    pub fn chain_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[32..]) as usize;
-            Byte32::new_unchecked(self.0.slice(start..end))
-        } else {
-            Byte32::new_unchecked(self.0.slice(start..))
+    pub fn to_enum(&self) -> RemoveTlcReasonUnion {
+        let inner = self.0.slice(molecule::NUMBER_SIZE..);
+        match self.item_id() {
+            0 => RemoveTlcFulfill::new_unchecked(inner).into(),
+            1 => RemoveTlcFail::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
         }
     }
-    pub fn as_reader<'r>(&'r self) -> NodeAnnouncementReader<'r> {
-        NodeAnnouncementReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> RemoveTlcReasonReader<'r> {
+        RemoveTlcReasonReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for NodeAnnouncement {
-    type Builder = NodeAnnouncementBuilder;
-    const NAME: &'static str = "NodeAnnouncement";
+impl molecule::prelude::Entity for RemoveTlcReason {
+    type Builder = RemoveTlcReasonBuilder;
+    const NAME: &'static str = "RemoveTlcReason";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        NodeAnnouncement(data)
+        RemoveTlcReason(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -10825,28 +11179,21 @@ impl molecule::prelude::Entity for NodeAnnouncement {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeAnnouncementReader::from_slice(slice).map(|reader| reader.to_entity())
+        RemoveTlcReasonReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeAnnouncementReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        RemoveTlcReasonReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .signature(self.signature())
-            .features(self.features())
-            .timestamp(self.timestamp())
-            .node_id(self.node_id())
-            .alias(self.alias())
-            .address(self.address())
-            .chain_hash(self.chain_hash())
+        Self::new_builder().set(self.to_enum())
     }
 }
 #[derive(Clone, Copy)]
-pub struct NodeAnnouncementReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for NodeAnnouncementReader<'r> {
+pub struct RemoveTlcReasonReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RemoveTlcReasonReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -10855,104 +11202,43 @@ impl<'r> ::core::fmt::LowerHex for NodeAnnouncementReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for NodeAnnouncementReader<'r> {
+impl<'r> ::core::fmt::Debug for RemoveTlcReasonReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for NodeAnnouncementReader<'r> {
+impl<'r> ::core::fmt::Display for RemoveTlcReasonReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "signature", self.signature())?;
-        write!(f, ", {}: {}", "features", self.features())?;
-        write!(f, ", {}: {}", "timestamp", self.timestamp())?;
-        write!(f, ", {}: {}", "node_id", self.node_id())?;
-        write!(f, ", {}: {}", "alias", self.alias())?;
-        write!(f, ", {}: {}", "address", self.address())?;
-        write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
     }
 }
-impl<'r> NodeAnnouncementReader<'r> {
-    pub const FIELD_COUNT: usize = 7;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+impl<'r> RemoveTlcReasonReader<'r> {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
     }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+    pub fn to_enum(&self) -> RemoveTlcReasonUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => RemoveTlcFulfillReader::new_unchecked(inner).into(),
+            1 => RemoveTlcFailReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
         }
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+}
+impl<'r> molecule::prelude::Reader<'r> for RemoveTlcReasonReader<'r> {
+    type Entity = RemoveTlcReason;
+    const NAME: &'static str = "RemoveTlcReasonReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        RemoveTlcReasonReader(slice)
     }
-    pub fn signature(&self) -> EcdsaSignatureReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        EcdsaSignatureReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn features(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn timestamp(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn node_id(&self) -> PubkeyReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        PubkeyReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn alias(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn address(&self) -> BytesVecReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        BytesVecReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn chain_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[32..]) as usize;
-            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-        } else {
-            Byte32Reader::new_unchecked(&self.as_slice()[start..])
-        }
-    }
-}
-impl<'r> molecule::prelude::Reader<'r> for NodeAnnouncementReader<'r> {
-    type Entity = NodeAnnouncement;
-    const NAME: &'static str = "NodeAnnouncementReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
-    }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        NodeAnnouncementReader(slice)
-    }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
     }
     fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
@@ -10960,193 +11246,227 @@ impl<'r> molecule::prelude::Reader<'r> for NodeAnnouncementReader<'r> {
         if slice_len < molecule::NUMBER_SIZE {
             return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        if slice_len < offset_first {
-            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
-        }
-        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
-            .chunks_exact(molecule::NUMBER_SIZE)
-            .map(|x| molecule::unpack_number(x) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        EcdsaSignatureReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        PubkeyReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        BytesVecReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => RemoveTlcFulfillReader::verify(inner_slice, compatible),
+            1 => RemoveTlcFailReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
+        }?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct NodeAnnouncementBuilder {
-    pub(crate) signature: EcdsaSignature,
-    pub(crate) features: Uint64,
-    pub(crate) timestamp: Uint64,
-    pub(crate) node_id: Pubkey,
-    pub(crate) alias: Byte32,
-    pub(crate) address: BytesVec,
-    pub(crate) chain_hash: Byte32,
-}
-impl NodeAnnouncementBuilder {
-    pub const FIELD_COUNT: usize = 7;
-    pub fn signature(mut self, v: EcdsaSignature) -> Self {
-        self.signature = v;
-        self
-    }
-    pub fn features(mut self, v: Uint64) -> Self {
-        self.features = v;
-        self
-    }
-    pub fn timestamp(mut self, v: Uint64) -> Self {
-        self.timestamp = v;
-        self
-    }
-    pub fn node_id(mut self, v: Pubkey) -> Self {
-        self.node_id = v;
-        self
-    }
-    pub fn alias(mut self, v: Byte32) -> Self {
-        self.alias = v;
-        self
-    }
-    pub fn address(mut self, v: BytesVec) -> Self {
-        self.address = v;
-        self
-    }
-    pub fn chain_hash(mut self, v: Byte32) -> Self {
-        self.chain_hash = v;
+pub struct RemoveTlcReasonBuilder(pub(crate) RemoveTlcReasonUnion);
+impl RemoveTlcReasonBuilder {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<RemoveTlcReasonUnion>,
+    {
+        self.0 = v.into();
         self
     }
 }
-impl molecule::prelude::Builder for NodeAnnouncementBuilder {
-    type Entity = NodeAnnouncement;
-    const NAME: &'static str = "NodeAnnouncementBuilder";
+impl molecule::prelude::Builder for RemoveTlcReasonBuilder {
+    type Entity = RemoveTlcReason;
+    const NAME: &'static str = "RemoveTlcReasonBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.signature.as_slice().len()
-            + self.features.as_slice().len()
-            + self.timestamp.as_slice().len()
-            + self.node_id.as_slice().len()
-            + self.alias.as_slice().len()
-            + self.address.as_slice().len()
-            + self.chain_hash.as_slice().len()
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.signature.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.features.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.timestamp.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.node_id.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.alias.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.address.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.chain_hash.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-        }
-        writer.write_all(self.signature.as_slice())?;
-        writer.write_all(self.features.as_slice())?;
-        writer.write_all(self.timestamp.as_slice())?;
-        writer.write_all(self.node_id.as_slice())?;
-        writer.write_all(self.alias.as_slice())?;
-        writer.write_all(self.address.as_slice())?;
-        writer.write_all(self.chain_hash.as_slice())?;
-        Ok(())
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        NodeAnnouncement::new_unchecked(inner.into())
+        RemoveTlcReason::new_unchecked(inner.into())
     }
 }
-#[derive(Clone)]
-pub struct ChannelAnnouncement(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ChannelAnnouncement {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+#[derive(Debug, Clone)]
+pub enum RemoveTlcReasonUnion {
+    RemoveTlcFulfill(RemoveTlcFulfill),
+    RemoveTlcFail(RemoveTlcFail),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum RemoveTlcReasonUnionReader<'r> {
+    RemoveTlcFulfill(RemoveTlcFulfillReader<'r>),
+    RemoveTlcFail(RemoveTlcFailReader<'r>),
+}
+impl ::core::default::Default for RemoveTlcReasonUnion {
+    fn default() -> Self {
+        RemoveTlcReasonUnion::RemoveTlcFulfill(::core::default::Default::default())
     }
 }
-impl ::core::fmt::Debug for ChannelAnnouncement {
+impl ::core::fmt::Display for RemoveTlcReasonUnion {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+        match self {
+            RemoveTlcReasonUnion::RemoveTlcFulfill(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, RemoveTlcFulfill::NAME, item)
+            }
+            RemoveTlcReasonUnion::RemoveTlcFail(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, RemoveTlcFail::NAME, item)
+            }
+        }
     }
 }
-impl ::core::fmt::Display for ChannelAnnouncement {
+impl<'r> ::core::fmt::Display for RemoveTlcReasonUnionReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "node1_signature", self.node1_signature())?;
-        write!(f, ", {}: {}", "node2_signature", self.node2_signature())?;
-        write!(f, ", {}: {}", "ckb_signature", self.ckb_signature())?;
-        write!(f, ", {}: {}", "features", self.features())?;
-        write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
-        write!(f, ", {}: {}", "channel_outpoint", self.channel_outpoint())?;
-        write!(f, ", {}: {}", "node1_id", self.node1_id())?;
-        write!(f, ", {}: {}", "node2_id", self.node2_id())?;
-        write!(f, ", {}: {}", "ckb_key", self.ckb_key())?;
-        write!(f, ", {}: {}", "capacity", self.capacity())?;
-        write!(f, ", {}: {}", "udt_type_script", self.udt_type_script())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        match self {
+            RemoveTlcReasonUnionReader::RemoveTlcFulfill(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, RemoveTlcFulfill::NAME, item)
+            }
+            RemoveTlcReasonUnionReader::RemoveTlcFail(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, RemoveTlcFail::NAME, item)
+            }
         }
-        write!(f, " }}")
     }
 }
-impl ::core::default::Default for ChannelAnnouncement {
-    fn default() -> Self {
-        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        ChannelAnnouncement::new_unchecked(v)
+impl RemoveTlcReasonUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            RemoveTlcReasonUnion::RemoveTlcFulfill(ref item) => write!(f, "{}", item),
+            RemoveTlcReasonUnion::RemoveTlcFail(ref item) => write!(f, "{}", item),
+        }
     }
 }
-impl ChannelAnnouncement {
-    const DEFAULT_VALUE: [u8; 310] = [
-        54, 1, 0, 0, 48, 0, 0, 0, 52, 0, 0, 0, 56, 0, 0, 0, 120, 0, 0, 0, 128, 0, 0, 0, 160, 0, 0,
-        0, 196, 0, 0, 0, 229, 0, 0, 0, 6, 1, 0, 0, 38, 1, 0, 0, 54, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl<'r> RemoveTlcReasonUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            RemoveTlcReasonUnionReader::RemoveTlcFulfill(ref item) => write!(f, "{}", item),
+            RemoveTlcReasonUnionReader::RemoveTlcFail(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl ::core::convert::From<RemoveTlcFulfill> for RemoveTlcReasonUnion {
+    fn from(item: RemoveTlcFulfill) -> Self {
+        RemoveTlcReasonUnion::RemoveTlcFulfill(item)
+    }
+}
+impl ::core::convert::From<RemoveTlcFail> for RemoveTlcReasonUnion {
+    fn from(item: RemoveTlcFail) -> Self {
+        RemoveTlcReasonUnion::RemoveTlcFail(item)
+    }
+}
+impl<'r> ::core::convert::From<RemoveTlcFulfillReader<'r>> for RemoveTlcReasonUnionReader<'r> {
+    fn from(item: RemoveTlcFulfillReader<'r>) -> Self {
+        RemoveTlcReasonUnionReader::RemoveTlcFulfill(item)
+    }
+}
+impl<'r> ::core::convert::From<RemoveTlcFailReader<'r>> for RemoveTlcReasonUnionReader<'r> {
+    fn from(item: RemoveTlcFailReader<'r>) -> Self {
+        RemoveTlcReasonUnionReader::RemoveTlcFail(item)
+    }
+}
+impl RemoveTlcReasonUnion {
+    pub const NAME: &'static str = "RemoveTlcReasonUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            RemoveTlcReasonUnion::RemoveTlcFulfill(item) => item.as_bytes(),
+            RemoveTlcReasonUnion::RemoveTlcFail(item) => item.as_bytes(),
+        }
+    }
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            RemoveTlcReasonUnion::RemoveTlcFulfill(item) => item.as_slice(),
+            RemoveTlcReasonUnion::RemoveTlcFail(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            RemoveTlcReasonUnion::RemoveTlcFulfill(_) => 0,
+            RemoveTlcReasonUnion::RemoveTlcFail(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            RemoveTlcReasonUnion::RemoveTlcFulfill(_) => "RemoveTlcFulfill",
+            RemoveTlcReasonUnion::RemoveTlcFail(_) => "RemoveTlcFail",
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> RemoveTlcReasonUnionReader<'r> {
+        match self {
+            RemoveTlcReasonUnion::RemoveTlcFulfill(item) => item.as_reader().into(),
+            RemoveTlcReasonUnion::RemoveTlcFail(item) => item.as_reader().into(),
+        }
+    }
+}
+impl<'r> RemoveTlcReasonUnionReader<'r> {
+    pub const NAME: &'r str = "RemoveTlcReasonUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            RemoveTlcReasonUnionReader::RemoveTlcFulfill(item) => item.as_slice(),
+            RemoveTlcReasonUnionReader::RemoveTlcFail(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            RemoveTlcReasonUnionReader::RemoveTlcFulfill(_) => 0,
+            RemoveTlcReasonUnionReader::RemoveTlcFail(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            RemoveTlcReasonUnionReader::RemoveTlcFulfill(_) => "RemoveTlcFulfill",
+            RemoveTlcReasonUnionReader::RemoveTlcFail(_) => "RemoveTlcFail",
+        }
+    }
+}
+impl From<RemoveTlcFulfill> for RemoveTlcReason {
+    fn from(value: RemoveTlcFulfill) -> Self {
+        Self::new_builder().set(value).build()
+    }
+}
+impl From<RemoveTlcFail> for RemoveTlcReason {
+    fn from(value: RemoveTlcFail) -> Self {
+        Self::new_builder().set(value).build()
+    }
+}
+#[derive(Clone)]
+pub struct RemoveTlc(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RemoveTlc {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for RemoveTlc {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for RemoveTlc {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(f, ", {}: {}", "tlc_id", self.tlc_id())?;
+        write!(f, ", {}: {}", "reason", self.reason())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for RemoveTlc {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        RemoveTlc::new_unchecked(v)
+    }
+}
+impl RemoveTlc {
+    const DEFAULT_VALUE: [u8; 92] = [
+        92, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 11;
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -11163,85 +11483,37 @@ impl ChannelAnnouncement {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn node1_signature(&self) -> EcdsaSignature {
+    pub fn channel_id(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        EcdsaSignature::new_unchecked(self.0.slice(start..end))
+        Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn node2_signature(&self) -> EcdsaSignature {
+    pub fn tlc_id(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        EcdsaSignature::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn ckb_signature(&self) -> SchnorrSignature {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        SchnorrSignature::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn features(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
         Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn chain_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn channel_outpoint(&self) -> OutPoint {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        OutPoint::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn node1_id(&self) -> Pubkey {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
-        let end = molecule::unpack_number(&slice[32..]) as usize;
-        Pubkey::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn node2_id(&self) -> Pubkey {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[32..]) as usize;
-        let end = molecule::unpack_number(&slice[36..]) as usize;
-        Pubkey::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn ckb_key(&self) -> SchnorrXOnlyPubkey {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[36..]) as usize;
-        let end = molecule::unpack_number(&slice[40..]) as usize;
-        SchnorrXOnlyPubkey::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn capacity(&self) -> Uint128 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[40..]) as usize;
-        let end = molecule::unpack_number(&slice[44..]) as usize;
-        Uint128::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn udt_type_script(&self) -> ScriptOpt {
+    pub fn reason(&self) -> RemoveTlcReason {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[48..]) as usize;
-            ScriptOpt::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            RemoveTlcReason::new_unchecked(self.0.slice(start..end))
         } else {
-            ScriptOpt::new_unchecked(self.0.slice(start..))
+            RemoveTlcReason::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> ChannelAnnouncementReader<'r> {
-        ChannelAnnouncementReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> RemoveTlcReader<'r> {
+        RemoveTlcReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ChannelAnnouncement {
-    type Builder = ChannelAnnouncementBuilder;
-    const NAME: &'static str = "ChannelAnnouncement";
+impl molecule::prelude::Entity for RemoveTlc {
+    type Builder = RemoveTlcBuilder;
+    const NAME: &'static str = "RemoveTlc";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ChannelAnnouncement(data)
+        RemoveTlc(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -11250,32 +11522,24 @@ impl molecule::prelude::Entity for ChannelAnnouncement {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ChannelAnnouncementReader::from_slice(slice).map(|reader| reader.to_entity())
+        RemoveTlcReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ChannelAnnouncementReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        RemoveTlcReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .node1_signature(self.node1_signature())
-            .node2_signature(self.node2_signature())
-            .ckb_signature(self.ckb_signature())
-            .features(self.features())
-            .chain_hash(self.chain_hash())
-            .channel_outpoint(self.channel_outpoint())
-            .node1_id(self.node1_id())
-            .node2_id(self.node2_id())
-            .ckb_key(self.ckb_key())
-            .capacity(self.capacity())
-            .udt_type_script(self.udt_type_script())
+            .channel_id(self.channel_id())
+            .tlc_id(self.tlc_id())
+            .reason(self.reason())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ChannelAnnouncementReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ChannelAnnouncementReader<'r> {
+pub struct RemoveTlcReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RemoveTlcReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -11284,25 +11548,17 @@ impl<'r> ::core::fmt::LowerHex for ChannelAnnouncementReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ChannelAnnouncementReader<'r> {
+impl<'r> ::core::fmt::Debug for RemoveTlcReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ChannelAnnouncementReader<'r> {
+impl<'r> ::core::fmt::Display for RemoveTlcReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "node1_signature", self.node1_signature())?;
-        write!(f, ", {}: {}", "node2_signature", self.node2_signature())?;
-        write!(f, ", {}: {}", "ckb_signature", self.ckb_signature())?;
-        write!(f, ", {}: {}", "features", self.features())?;
-        write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
-        write!(f, ", {}: {}", "channel_outpoint", self.channel_outpoint())?;
-        write!(f, ", {}: {}", "node1_id", self.node1_id())?;
-        write!(f, ", {}: {}", "node2_id", self.node2_id())?;
-        write!(f, ", {}: {}", "ckb_key", self.ckb_key())?;
-        write!(f, ", {}: {}", "capacity", self.capacity())?;
-        write!(f, ", {}: {}", "udt_type_script", self.udt_type_script())?;
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(f, ", {}: {}", "tlc_id", self.tlc_id())?;
+        write!(f, ", {}: {}", "reason", self.reason())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -11310,8 +11566,8 @@ impl<'r> ::core::fmt::Display for ChannelAnnouncementReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> ChannelAnnouncementReader<'r> {
-    pub const FIELD_COUNT: usize = 11;
+impl<'r> RemoveTlcReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -11328,85 +11584,37 @@ impl<'r> ChannelAnnouncementReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn node1_signature(&self) -> EcdsaSignatureReader<'r> {
+    pub fn channel_id(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        EcdsaSignatureReader::new_unchecked(&self.as_slice()[start..end])
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn node2_signature(&self) -> EcdsaSignatureReader<'r> {
+    pub fn tlc_id(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        EcdsaSignatureReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn ckb_signature(&self) -> SchnorrSignatureReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        SchnorrSignatureReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn features(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
         Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn chain_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn channel_outpoint(&self) -> OutPointReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        OutPointReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn node1_id(&self) -> PubkeyReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
-        let end = molecule::unpack_number(&slice[32..]) as usize;
-        PubkeyReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn node2_id(&self) -> PubkeyReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[32..]) as usize;
-        let end = molecule::unpack_number(&slice[36..]) as usize;
-        PubkeyReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn ckb_key(&self) -> SchnorrXOnlyPubkeyReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[36..]) as usize;
-        let end = molecule::unpack_number(&slice[40..]) as usize;
-        SchnorrXOnlyPubkeyReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn capacity(&self) -> Uint128Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[40..]) as usize;
-        let end = molecule::unpack_number(&slice[44..]) as usize;
-        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn udt_type_script(&self) -> ScriptOptReader<'r> {
+    pub fn reason(&self) -> RemoveTlcReasonReader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[48..]) as usize;
-            ScriptOptReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            RemoveTlcReasonReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            ScriptOptReader::new_unchecked(&self.as_slice()[start..])
+            RemoveTlcReasonReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ChannelAnnouncementReader<'r> {
-    type Entity = ChannelAnnouncement;
-    const NAME: &'static str = "ChannelAnnouncementReader";
+impl<'r> molecule::prelude::Reader<'r> for RemoveTlcReader<'r> {
+    type Entity = RemoveTlc;
+    const NAME: &'static str = "RemoveTlcReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ChannelAnnouncementReader(slice)
+        RemoveTlcReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -11445,150 +11653,70 @@ impl<'r> molecule::prelude::Reader<'r> for ChannelAnnouncementReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        EcdsaSignatureReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        EcdsaSignatureReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        SchnorrSignatureReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        OutPointReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        PubkeyReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
-        PubkeyReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
-        SchnorrXOnlyPubkeyReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
-        Uint128Reader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
-        ScriptOptReader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        RemoveTlcReasonReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct ChannelAnnouncementBuilder {
-    pub(crate) node1_signature: EcdsaSignature,
-    pub(crate) node2_signature: EcdsaSignature,
-    pub(crate) ckb_signature: SchnorrSignature,
-    pub(crate) features: Uint64,
-    pub(crate) chain_hash: Byte32,
-    pub(crate) channel_outpoint: OutPoint,
-    pub(crate) node1_id: Pubkey,
-    pub(crate) node2_id: Pubkey,
-    pub(crate) ckb_key: SchnorrXOnlyPubkey,
-    pub(crate) capacity: Uint128,
-    pub(crate) udt_type_script: ScriptOpt,
+pub struct RemoveTlcBuilder {
+    pub(crate) channel_id: Byte32,
+    pub(crate) tlc_id: Uint64,
+    pub(crate) reason: RemoveTlcReason,
 }
-impl ChannelAnnouncementBuilder {
-    pub const FIELD_COUNT: usize = 11;
-    pub fn node1_signature(mut self, v: EcdsaSignature) -> Self {
-        self.node1_signature = v;
-        self
-    }
-    pub fn node2_signature(mut self, v: EcdsaSignature) -> Self {
-        self.node2_signature = v;
-        self
-    }
-    pub fn ckb_signature(mut self, v: SchnorrSignature) -> Self {
-        self.ckb_signature = v;
-        self
-    }
-    pub fn features(mut self, v: Uint64) -> Self {
-        self.features = v;
-        self
-    }
-    pub fn chain_hash(mut self, v: Byte32) -> Self {
-        self.chain_hash = v;
-        self
-    }
-    pub fn channel_outpoint(mut self, v: OutPoint) -> Self {
-        self.channel_outpoint = v;
-        self
-    }
-    pub fn node1_id(mut self, v: Pubkey) -> Self {
-        self.node1_id = v;
-        self
-    }
-    pub fn node2_id(mut self, v: Pubkey) -> Self {
-        self.node2_id = v;
-        self
-    }
-    pub fn ckb_key(mut self, v: SchnorrXOnlyPubkey) -> Self {
-        self.ckb_key = v;
+impl RemoveTlcBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn channel_id(mut self, v: Byte32) -> Self {
+        self.channel_id = v;
         self
     }
-    pub fn capacity(mut self, v: Uint128) -> Self {
-        self.capacity = v;
+    pub fn tlc_id(mut self, v: Uint64) -> Self {
+        self.tlc_id = v;
         self
     }
-    pub fn udt_type_script(mut self, v: ScriptOpt) -> Self {
-        self.udt_type_script = v;
+    pub fn reason(mut self, v: RemoveTlcReason) -> Self {
+        self.reason = v;
         self
     }
 }
-impl molecule::prelude::Builder for ChannelAnnouncementBuilder {
-    type Entity = ChannelAnnouncement;
-    const NAME: &'static str = "ChannelAnnouncementBuilder";
+impl molecule::prelude::Builder for RemoveTlcBuilder {
+    type Entity = RemoveTlc;
+    const NAME: &'static str = "RemoveTlcBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.node1_signature.as_slice().len()
-            + self.node2_signature.as_slice().len()
-            + self.ckb_signature.as_slice().len()
-            + self.features.as_slice().len()
-            + self.chain_hash.as_slice().len()
-            + self.channel_outpoint.as_slice().len()
-            + self.node1_id.as_slice().len()
-            + self.node2_id.as_slice().len()
-            + self.ckb_key.as_slice().len()
-            + self.capacity.as_slice().len()
-            + self.udt_type_script.as_slice().len()
+            + self.channel_id.as_slice().len()
+            + self.tlc_id.as_slice().len()
+            + self.reason.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.node1_signature.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.node2_signature.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.ckb_signature.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.features.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.chain_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.channel_outpoint.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.node1_id.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.node2_id.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.ckb_key.as_slice().len();
+        total_size += self.channel_id.as_slice().len();
         offsets.push(total_size);
-        total_size += self.capacity.as_slice().len();
+        total_size += self.tlc_id.as_slice().len();
         offsets.push(total_size);
-        total_size += self.udt_type_script.as_slice().len();
+        total_size += self.reason.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.node1_signature.as_slice())?;
-        writer.write_all(self.node2_signature.as_slice())?;
-        writer.write_all(self.ckb_signature.as_slice())?;
-        writer.write_all(self.features.as_slice())?;
-        writer.write_all(self.chain_hash.as_slice())?;
-        writer.write_all(self.channel_outpoint.as_slice())?;
-        writer.write_all(self.node1_id.as_slice())?;
-        writer.write_all(self.node2_id.as_slice())?;
-        writer.write_all(self.ckb_key.as_slice())?;
-        writer.write_all(self.capacity.as_slice())?;
-        writer.write_all(self.udt_type_script.as_slice())?;
+        writer.write_all(self.channel_id.as_slice())?;
+        writer.write_all(self.tlc_id.as_slice())?;
+        writer.write_all(self.reason.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ChannelAnnouncement::new_unchecked(inner.into())
+        RemoveTlc::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct ChannelUpdate(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ChannelUpdate {
+pub struct ReestablishChannel(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ReestablishChannel {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -11597,33 +11725,26 @@ impl ::core::fmt::LowerHex for ChannelUpdate {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ChannelUpdate {
+impl ::core::fmt::Debug for ReestablishChannel {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ChannelUpdate {
+impl ::core::fmt::Display for ReestablishChannel {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "signature", self.signature())?;
-        write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
-        write!(f, ", {}: {}", "channel_outpoint", self.channel_outpoint())?;
-        write!(f, ", {}: {}", "timestamp", self.timestamp())?;
-        write!(f, ", {}: {}", "message_flags", self.message_flags())?;
-        write!(f, ", {}: {}", "channel_flags", self.channel_flags())?;
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
         write!(
             f,
             ", {}: {}",
-            "tlc_locktime_expiry_delta",
-            self.tlc_locktime_expiry_delta()
+            "local_commitment_number",
+            self.local_commitment_number()
         )?;
-        write!(f, ", {}: {}", "tlc_minimum_value", self.tlc_minimum_value())?;
-        write!(f, ", {}: {}", "tlc_maximum_value", self.tlc_maximum_value())?;
         write!(
             f,
             ", {}: {}",
-            "tlc_fee_proportional_millionths",
-            self.tlc_fee_proportional_millionths()
+            "remote_commitment_number",
+            self.remote_commitment_number()
         )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
@@ -11632,23 +11753,19 @@ impl ::core::fmt::Display for ChannelUpdate {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for ChannelUpdate {
+impl ::core::default::Default for ReestablishChannel {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        ChannelUpdate::new_unchecked(v)
+        ReestablishChannel::new_unchecked(v)
     }
 }
-impl ChannelUpdate {
-    const DEFAULT_VALUE: [u8; 188] = [
-        188, 0, 0, 0, 44, 0, 0, 0, 48, 0, 0, 0, 80, 0, 0, 0, 116, 0, 0, 0, 124, 0, 0, 0, 128, 0, 0,
-        0, 132, 0, 0, 0, 140, 0, 0, 0, 156, 0, 0, 0, 172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl ReestablishChannel {
+    const DEFAULT_VALUE: [u8; 64] = [
+        64, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 10;
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -11665,79 +11782,37 @@ impl ChannelUpdate {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn signature(&self) -> EcdsaSignature {
+    pub fn channel_id(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        EcdsaSignature::new_unchecked(self.0.slice(start..end))
+        Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn chain_hash(&self) -> Byte32 {
+    pub fn local_commitment_number(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn channel_outpoint(&self) -> OutPoint {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        OutPoint::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn timestamp(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn message_flags(&self) -> Uint32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn channel_flags(&self) -> Uint32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn tlc_locktime_expiry_delta(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
-        let end = molecule::unpack_number(&slice[32..]) as usize;
         Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn tlc_minimum_value(&self) -> Uint128 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[32..]) as usize;
-        let end = molecule::unpack_number(&slice[36..]) as usize;
-        Uint128::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn tlc_maximum_value(&self) -> Uint128 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[36..]) as usize;
-        let end = molecule::unpack_number(&slice[40..]) as usize;
-        Uint128::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn tlc_fee_proportional_millionths(&self) -> Uint128 {
+    pub fn remote_commitment_number(&self) -> Uint64 {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[44..]) as usize;
-            Uint128::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            Uint64::new_unchecked(self.0.slice(start..end))
         } else {
-            Uint128::new_unchecked(self.0.slice(start..))
+            Uint64::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> ChannelUpdateReader<'r> {
-        ChannelUpdateReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ReestablishChannelReader<'r> {
+        ReestablishChannelReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ChannelUpdate {
-    type Builder = ChannelUpdateBuilder;
-    const NAME: &'static str = "ChannelUpdate";
+impl molecule::prelude::Entity for ReestablishChannel {
+    type Builder = ReestablishChannelBuilder;
+    const NAME: &'static str = "ReestablishChannel";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ChannelUpdate(data)
+        ReestablishChannel(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -11746,31 +11821,24 @@ impl molecule::prelude::Entity for ChannelUpdate {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ChannelUpdateReader::from_slice(slice).map(|reader| reader.to_entity())
+        ReestablishChannelReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ChannelUpdateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ReestablishChannelReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .signature(self.signature())
-            .chain_hash(self.chain_hash())
-            .channel_outpoint(self.channel_outpoint())
-            .timestamp(self.timestamp())
-            .message_flags(self.message_flags())
-            .channel_flags(self.channel_flags())
-            .tlc_locktime_expiry_delta(self.tlc_locktime_expiry_delta())
-            .tlc_minimum_value(self.tlc_minimum_value())
-            .tlc_maximum_value(self.tlc_maximum_value())
-            .tlc_fee_proportional_millionths(self.tlc_fee_proportional_millionths())
+            .channel_id(self.channel_id())
+            .local_commitment_number(self.local_commitment_number())
+            .remote_commitment_number(self.remote_commitment_number())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ChannelUpdateReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ChannelUpdateReader<'r> {
+pub struct ReestablishChannelReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ReestablishChannelReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -11779,33 +11847,26 @@ impl<'r> ::core::fmt::LowerHex for ChannelUpdateReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ChannelUpdateReader<'r> {
+impl<'r> ::core::fmt::Debug for ReestablishChannelReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ChannelUpdateReader<'r> {
+impl<'r> ::core::fmt::Display for ReestablishChannelReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "signature", self.signature())?;
-        write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
-        write!(f, ", {}: {}", "channel_outpoint", self.channel_outpoint())?;
-        write!(f, ", {}: {}", "timestamp", self.timestamp())?;
-        write!(f, ", {}: {}", "message_flags", self.message_flags())?;
-        write!(f, ", {}: {}", "channel_flags", self.channel_flags())?;
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
         write!(
             f,
             ", {}: {}",
-            "tlc_locktime_expiry_delta",
-            self.tlc_locktime_expiry_delta()
+            "local_commitment_number",
+            self.local_commitment_number()
         )?;
-        write!(f, ", {}: {}", "tlc_minimum_value", self.tlc_minimum_value())?;
-        write!(f, ", {}: {}", "tlc_maximum_value", self.tlc_maximum_value())?;
         write!(
             f,
             ", {}: {}",
-            "tlc_fee_proportional_millionths",
-            self.tlc_fee_proportional_millionths()
+            "remote_commitment_number",
+            self.remote_commitment_number()
         )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
@@ -11814,8 +11875,8 @@ impl<'r> ::core::fmt::Display for ChannelUpdateReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> ChannelUpdateReader<'r> {
-    pub const FIELD_COUNT: usize = 10;
+impl<'r> ReestablishChannelReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -11832,79 +11893,37 @@ impl<'r> ChannelUpdateReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn signature(&self) -> EcdsaSignatureReader<'r> {
+    pub fn channel_id(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        EcdsaSignatureReader::new_unchecked(&self.as_slice()[start..end])
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn chain_hash(&self) -> Byte32Reader<'r> {
+    pub fn local_commitment_number(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn channel_outpoint(&self) -> OutPointReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        OutPointReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn timestamp(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn message_flags(&self) -> Uint32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn channel_flags(&self) -> Uint32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn tlc_locktime_expiry_delta(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
-        let end = molecule::unpack_number(&slice[32..]) as usize;
         Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn tlc_minimum_value(&self) -> Uint128Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[32..]) as usize;
-        let end = molecule::unpack_number(&slice[36..]) as usize;
-        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn tlc_maximum_value(&self) -> Uint128Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[36..]) as usize;
-        let end = molecule::unpack_number(&slice[40..]) as usize;
-        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn tlc_fee_proportional_millionths(&self) -> Uint128Reader<'r> {
+    pub fn remote_commitment_number(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[44..]) as usize;
-            Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Uint128Reader::new_unchecked(&self.as_slice()[start..])
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ChannelUpdateReader<'r> {
-    type Entity = ChannelUpdate;
-    const NAME: &'static str = "ChannelUpdateReader";
+impl<'r> molecule::prelude::Reader<'r> for ReestablishChannelReader<'r> {
+    type Entity = ReestablishChannel;
+    const NAME: &'static str = "ReestablishChannelReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ChannelUpdateReader(slice)
+        ReestablishChannelReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -11943,140 +11962,70 @@ impl<'r> molecule::prelude::Reader<'r> for ChannelUpdateReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        EcdsaSignatureReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        OutPointReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
-        Uint128Reader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
-        Uint128Reader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
-        Uint128Reader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct ChannelUpdateBuilder {
-    pub(crate) signature: EcdsaSignature,
-    pub(crate) chain_hash: Byte32,
-    pub(crate) channel_outpoint: OutPoint,
-    pub(crate) timestamp: Uint64,
-    pub(crate) message_flags: Uint32,
-    pub(crate) channel_flags: Uint32,
-    pub(crate) tlc_locktime_expiry_delta: Uint64,
-    pub(crate) tlc_minimum_value: Uint128,
-    pub(crate) tlc_maximum_value: Uint128,
-    pub(crate) tlc_fee_proportional_millionths: Uint128,
+pub struct ReestablishChannelBuilder {
+    pub(crate) channel_id: Byte32,
+    pub(crate) local_commitment_number: Uint64,
+    pub(crate) remote_commitment_number: Uint64,
 }
-impl ChannelUpdateBuilder {
-    pub const FIELD_COUNT: usize = 10;
-    pub fn signature(mut self, v: EcdsaSignature) -> Self {
-        self.signature = v;
-        self
-    }
-    pub fn chain_hash(mut self, v: Byte32) -> Self {
-        self.chain_hash = v;
-        self
-    }
-    pub fn channel_outpoint(mut self, v: OutPoint) -> Self {
-        self.channel_outpoint = v;
-        self
-    }
-    pub fn timestamp(mut self, v: Uint64) -> Self {
-        self.timestamp = v;
-        self
-    }
-    pub fn message_flags(mut self, v: Uint32) -> Self {
-        self.message_flags = v;
-        self
-    }
-    pub fn channel_flags(mut self, v: Uint32) -> Self {
-        self.channel_flags = v;
-        self
-    }
-    pub fn tlc_locktime_expiry_delta(mut self, v: Uint64) -> Self {
-        self.tlc_locktime_expiry_delta = v;
-        self
-    }
-    pub fn tlc_minimum_value(mut self, v: Uint128) -> Self {
-        self.tlc_minimum_value = v;
+impl ReestablishChannelBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn channel_id(mut self, v: Byte32) -> Self {
+        self.channel_id = v;
         self
     }
-    pub fn tlc_maximum_value(mut self, v: Uint128) -> Self {
-        self.tlc_maximum_value = v;
+    pub fn local_commitment_number(mut self, v: Uint64) -> Self {
+        self.local_commitment_number = v;
         self
     }
-    pub fn tlc_fee_proportional_millionths(mut self, v: Uint128) -> Self {
-        self.tlc_fee_proportional_millionths = v;
+    pub fn remote_commitment_number(mut self, v: Uint64) -> Self {
+        self.remote_commitment_number = v;
         self
     }
 }
-impl molecule::prelude::Builder for ChannelUpdateBuilder {
-    type Entity = ChannelUpdate;
-    const NAME: &'static str = "ChannelUpdateBuilder";
+impl molecule::prelude::Builder for ReestablishChannelBuilder {
+    type Entity = ReestablishChannel;
+    const NAME: &'static str = "ReestablishChannelBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.signature.as_slice().len()
-            + self.chain_hash.as_slice().len()
-            + self.channel_outpoint.as_slice().len()
-            + self.timestamp.as_slice().len()
-            + self.message_flags.as_slice().len()
-            + self.channel_flags.as_slice().len()
-            + self.tlc_locktime_expiry_delta.as_slice().len()
-            + self.tlc_minimum_value.as_slice().len()
-            + self.tlc_maximum_value.as_slice().len()
-            + self.tlc_fee_proportional_millionths.as_slice().len()
+            + self.channel_id.as_slice().len()
+            + self.local_commitment_number.as_slice().len()
+            + self.remote_commitment_number.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.signature.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.chain_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.channel_outpoint.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.timestamp.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.message_flags.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.channel_flags.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.tlc_locktime_expiry_delta.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.tlc_minimum_value.as_slice().len();
+        total_size += self.channel_id.as_slice().len();
         offsets.push(total_size);
-        total_size += self.tlc_maximum_value.as_slice().len();
+        total_size += self.local_commitment_number.as_slice().len();
         offsets.push(total_size);
-        total_size += self.tlc_fee_proportional_millionths.as_slice().len();
+        total_size += self.remote_commitment_number.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.signature.as_slice())?;
-        writer.write_all(self.chain_hash.as_slice())?;
-        writer.write_all(self.channel_outpoint.as_slice())?;
-        writer.write_all(self.timestamp.as_slice())?;
-        writer.write_all(self.message_flags.as_slice())?;
-        writer.write_all(self.channel_flags.as_slice())?;
-        writer.write_all(self.tlc_locktime_expiry_delta.as_slice())?;
-        writer.write_all(self.tlc_minimum_value.as_slice())?;
-        writer.write_all(self.tlc_maximum_value.as_slice())?;
-        writer.write_all(self.tlc_fee_proportional_millionths.as_slice())?;
+        writer.write_all(self.channel_id.as_slice())?;
+        writer.write_all(self.local_commitment_number.as_slice())?;
+        writer.write_all(self.remote_commitment_number.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ChannelUpdate::new_unchecked(inner.into())
+        ReestablishChannel::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct NodeAnnouncementQuery(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for NodeAnnouncementQuery {
+pub struct AnnouncementSignatures(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for AnnouncementSignatures {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -12085,16 +12034,18 @@ impl ::core::fmt::LowerHex for NodeAnnouncementQuery {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for NodeAnnouncementQuery {
+impl ::core::fmt::Debug for AnnouncementSignatures {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for NodeAnnouncementQuery {
+impl ::core::fmt::Display for AnnouncementSignatures {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "node_id", self.node_id())?;
-        write!(f, ", {}: {}", "flags", self.flags())?;
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(f, ", {}: {}", "channel_outpoint", self.channel_outpoint())?;
+        write!(f, ", {}: {}", "node_signature", self.node_signature())?;
+        write!(f, ", {}: {}", "partial_signature", self.partial_signature())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -12102,18 +12053,21 @@ impl ::core::fmt::Display for NodeAnnouncementQuery {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for NodeAnnouncementQuery {
+impl ::core::default::Default for AnnouncementSignatures {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        NodeAnnouncementQuery::new_unchecked(v)
+        AnnouncementSignatures::new_unchecked(v)
     }
 }
-impl NodeAnnouncementQuery {
-    const DEFAULT_VALUE: [u8; 46] = [
-        46, 0, 0, 0, 12, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl AnnouncementSignatures {
+    const DEFAULT_VALUE: [u8; 124] = [
+        124, 0, 0, 0, 20, 0, 0, 0, 52, 0, 0, 0, 88, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -12130,31 +12084,43 @@ impl NodeAnnouncementQuery {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn node_id(&self) -> Pubkey {
+    pub fn channel_id(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Pubkey::new_unchecked(self.0.slice(start..end))
+        Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn flags(&self) -> Byte {
+    pub fn channel_outpoint(&self) -> OutPoint {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        OutPoint::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn node_signature(&self) -> EcdsaSignature {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        EcdsaSignature::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn partial_signature(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            Byte::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Byte32::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte::new_unchecked(self.0.slice(start..))
+            Byte32::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> NodeAnnouncementQueryReader<'r> {
-        NodeAnnouncementQueryReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> AnnouncementSignaturesReader<'r> {
+        AnnouncementSignaturesReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for NodeAnnouncementQuery {
-    type Builder = NodeAnnouncementQueryBuilder;
-    const NAME: &'static str = "NodeAnnouncementQuery";
+impl molecule::prelude::Entity for AnnouncementSignatures {
+    type Builder = AnnouncementSignaturesBuilder;
+    const NAME: &'static str = "AnnouncementSignatures";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        NodeAnnouncementQuery(data)
+        AnnouncementSignatures(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -12163,23 +12129,25 @@ impl molecule::prelude::Entity for NodeAnnouncementQuery {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeAnnouncementQueryReader::from_slice(slice).map(|reader| reader.to_entity())
+        AnnouncementSignaturesReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        NodeAnnouncementQueryReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        AnnouncementSignaturesReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .node_id(self.node_id())
-            .flags(self.flags())
+            .channel_id(self.channel_id())
+            .channel_outpoint(self.channel_outpoint())
+            .node_signature(self.node_signature())
+            .partial_signature(self.partial_signature())
     }
 }
 #[derive(Clone, Copy)]
-pub struct NodeAnnouncementQueryReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for NodeAnnouncementQueryReader<'r> {
+pub struct AnnouncementSignaturesReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for AnnouncementSignaturesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -12188,16 +12156,18 @@ impl<'r> ::core::fmt::LowerHex for NodeAnnouncementQueryReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for NodeAnnouncementQueryReader<'r> {
+impl<'r> ::core::fmt::Debug for AnnouncementSignaturesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for NodeAnnouncementQueryReader<'r> {
+impl<'r> ::core::fmt::Display for AnnouncementSignaturesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "node_id", self.node_id())?;
-        write!(f, ", {}: {}", "flags", self.flags())?;
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(f, ", {}: {}", "channel_outpoint", self.channel_outpoint())?;
+        write!(f, ", {}: {}", "node_signature", self.node_signature())?;
+        write!(f, ", {}: {}", "partial_signature", self.partial_signature())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -12205,8 +12175,8 @@ impl<'r> ::core::fmt::Display for NodeAnnouncementQueryReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> NodeAnnouncementQueryReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
+impl<'r> AnnouncementSignaturesReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -12223,31 +12193,43 @@ impl<'r> NodeAnnouncementQueryReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn node_id(&self) -> PubkeyReader<'r> {
+    pub fn channel_id(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        PubkeyReader::new_unchecked(&self.as_slice()[start..end])
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn flags(&self) -> ByteReader<'r> {
+    pub fn channel_outpoint(&self) -> OutPointReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        OutPointReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn node_signature(&self) -> EcdsaSignatureReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        EcdsaSignatureReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn partial_signature(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            ByteReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            ByteReader::new_unchecked(&self.as_slice()[start..])
+            Byte32Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for NodeAnnouncementQueryReader<'r> {
-    type Entity = NodeAnnouncementQuery;
-    const NAME: &'static str = "NodeAnnouncementQueryReader";
+impl<'r> molecule::prelude::Reader<'r> for AnnouncementSignaturesReader<'r> {
+    type Entity = AnnouncementSignatures;
+    const NAME: &'static str = "AnnouncementSignaturesReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        NodeAnnouncementQueryReader(slice)
+        AnnouncementSignaturesReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -12286,60 +12268,80 @@ impl<'r> molecule::prelude::Reader<'r> for NodeAnnouncementQueryReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        PubkeyReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        OutPointReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        EcdsaSignatureReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct NodeAnnouncementQueryBuilder {
-    pub(crate) node_id: Pubkey,
-    pub(crate) flags: Byte,
+pub struct AnnouncementSignaturesBuilder {
+    pub(crate) channel_id: Byte32,
+    pub(crate) channel_outpoint: OutPoint,
+    pub(crate) node_signature: EcdsaSignature,
+    pub(crate) partial_signature: Byte32,
 }
-impl NodeAnnouncementQueryBuilder {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn node_id(mut self, v: Pubkey) -> Self {
-        self.node_id = v;
+impl AnnouncementSignaturesBuilder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn channel_id(mut self, v: Byte32) -> Self {
+        self.channel_id = v;
         self
     }
-    pub fn flags(mut self, v: Byte) -> Self {
-        self.flags = v;
+    pub fn channel_outpoint(mut self, v: OutPoint) -> Self {
+        self.channel_outpoint = v;
+        self
+    }
+    pub fn node_signature(mut self, v: EcdsaSignature) -> Self {
+        self.node_signature = v;
+        self
+    }
+    pub fn partial_signature(mut self, v: Byte32) -> Self {
+        self.partial_signature = v;
         self
     }
 }
-impl molecule::prelude::Builder for NodeAnnouncementQueryBuilder {
-    type Entity = NodeAnnouncementQuery;
-    const NAME: &'static str = "NodeAnnouncementQueryBuilder";
+impl molecule::prelude::Builder for AnnouncementSignaturesBuilder {
+    type Entity = AnnouncementSignatures;
+    const NAME: &'static str = "AnnouncementSignaturesBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.node_id.as_slice().len()
-            + self.flags.as_slice().len()
+            + self.channel_id.as_slice().len()
+            + self.channel_outpoint.as_slice().len()
+            + self.node_signature.as_slice().len()
+            + self.partial_signature.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.node_id.as_slice().len();
+        total_size += self.channel_id.as_slice().len();
         offsets.push(total_size);
-        total_size += self.flags.as_slice().len();
+        total_size += self.channel_outpoint.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.node_signature.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.partial_signature.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.node_id.as_slice())?;
-        writer.write_all(self.flags.as_slice())?;
+        writer.write_all(self.channel_id.as_slice())?;
+        writer.write_all(self.channel_outpoint.as_slice())?;
+        writer.write_all(self.node_signature.as_slice())?;
+        writer.write_all(self.partial_signature.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        NodeAnnouncementQuery::new_unchecked(inner.into())
+        AnnouncementSignatures::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct ChannelAnnouncementQuery(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ChannelAnnouncementQuery {
+pub struct NodeAnnouncement(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for NodeAnnouncement {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -12348,35 +12350,44 @@ impl ::core::fmt::LowerHex for ChannelAnnouncementQuery {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ChannelAnnouncementQuery {
+impl ::core::fmt::Debug for NodeAnnouncement {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ChannelAnnouncementQuery {
+impl ::core::fmt::Display for NodeAnnouncement {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "channel_outpoint", self.channel_outpoint())?;
-        write!(f, ", {}: {}", "flags", self.flags())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        write!(f, "{}: {}", "signature", self.signature())?;
+        write!(f, ", {}: {}", "features", self.features())?;
+        write!(f, ", {}: {}", "timestamp", self.timestamp())?;
+        write!(f, ", {}: {}", "node_id", self.node_id())?;
+        write!(f, ", {}: {}", "alias", self.alias())?;
+        write!(f, ", {}: {}", "address", self.address())?;
+        write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for ChannelAnnouncementQuery {
+impl ::core::default::Default for NodeAnnouncement {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        ChannelAnnouncementQuery::new_unchecked(v)
+        NodeAnnouncement::new_unchecked(v)
     }
 }
-impl ChannelAnnouncementQuery {
-    const DEFAULT_VALUE: [u8; 49] = [
-        49, 0, 0, 0, 12, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl NodeAnnouncement {
+    const DEFAULT_VALUE: [u8; 153] = [
+        153, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 44, 0, 0, 0, 52, 0, 0, 0, 85, 0, 0, 0, 117, 0, 0,
+        0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_COUNT: usize = 7;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -12393,31 +12404,61 @@ impl ChannelAnnouncementQuery {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn channel_outpoint(&self) -> OutPoint {
+    pub fn signature(&self) -> EcdsaSignature {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        OutPoint::new_unchecked(self.0.slice(start..end))
+        EcdsaSignature::new_unchecked(self.0.slice(start..end))
     }
-    pub fn flags(&self) -> Byte {
+    pub fn features(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn timestamp(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn node_id(&self) -> Pubkey {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Pubkey::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn alias(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn address(&self) -> BytesVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        BytesVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn chain_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            Byte::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[32..]) as usize;
+            Byte32::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte::new_unchecked(self.0.slice(start..))
+            Byte32::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> ChannelAnnouncementQueryReader<'r> {
-        ChannelAnnouncementQueryReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> NodeAnnouncementReader<'r> {
+        NodeAnnouncementReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ChannelAnnouncementQuery {
-    type Builder = ChannelAnnouncementQueryBuilder;
-    const NAME: &'static str = "ChannelAnnouncementQuery";
+impl molecule::prelude::Entity for NodeAnnouncement {
+    type Builder = NodeAnnouncementBuilder;
+    const NAME: &'static str = "NodeAnnouncement";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ChannelAnnouncementQuery(data)
+        NodeAnnouncement(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -12426,24 +12467,28 @@ impl molecule::prelude::Entity for ChannelAnnouncementQuery {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ChannelAnnouncementQueryReader::from_slice(slice).map(|reader| reader.to_entity())
+        NodeAnnouncementReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ChannelAnnouncementQueryReader::from_compatible_slice(slice)
-            .map(|reader| reader.to_entity())
+        NodeAnnouncementReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .channel_outpoint(self.channel_outpoint())
-            .flags(self.flags())
+            .signature(self.signature())
+            .features(self.features())
+            .timestamp(self.timestamp())
+            .node_id(self.node_id())
+            .alias(self.alias())
+            .address(self.address())
+            .chain_hash(self.chain_hash())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ChannelAnnouncementQueryReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ChannelAnnouncementQueryReader<'r> {
+pub struct NodeAnnouncementReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for NodeAnnouncementReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -12452,16 +12497,21 @@ impl<'r> ::core::fmt::LowerHex for ChannelAnnouncementQueryReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ChannelAnnouncementQueryReader<'r> {
+impl<'r> ::core::fmt::Debug for NodeAnnouncementReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ChannelAnnouncementQueryReader<'r> {
+impl<'r> ::core::fmt::Display for NodeAnnouncementReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "channel_outpoint", self.channel_outpoint())?;
-        write!(f, ", {}: {}", "flags", self.flags())?;
+        write!(f, "{}: {}", "signature", self.signature())?;
+        write!(f, ", {}: {}", "features", self.features())?;
+        write!(f, ", {}: {}", "timestamp", self.timestamp())?;
+        write!(f, ", {}: {}", "node_id", self.node_id())?;
+        write!(f, ", {}: {}", "alias", self.alias())?;
+        write!(f, ", {}: {}", "address", self.address())?;
+        write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -12469,8 +12519,8 @@ impl<'r> ::core::fmt::Display for ChannelAnnouncementQueryReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> ChannelAnnouncementQueryReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
+impl<'r> NodeAnnouncementReader<'r> {
+    pub const FIELD_COUNT: usize = 7;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -12487,31 +12537,61 @@ impl<'r> ChannelAnnouncementQueryReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn channel_outpoint(&self) -> OutPointReader<'r> {
+    pub fn signature(&self) -> EcdsaSignatureReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        OutPointReader::new_unchecked(&self.as_slice()[start..end])
+        EcdsaSignatureReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn flags(&self) -> ByteReader<'r> {
+    pub fn features(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn timestamp(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn node_id(&self) -> PubkeyReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        PubkeyReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn alias(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn address(&self) -> BytesVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        BytesVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn chain_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            ByteReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[32..]) as usize;
+            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            ByteReader::new_unchecked(&self.as_slice()[start..])
+            Byte32Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ChannelAnnouncementQueryReader<'r> {
-    type Entity = ChannelAnnouncementQuery;
-    const NAME: &'static str = "ChannelAnnouncementQueryReader";
+impl<'r> molecule::prelude::Reader<'r> for NodeAnnouncementReader<'r> {
+    type Entity = NodeAnnouncement;
+    const NAME: &'static str = "NodeAnnouncementReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ChannelAnnouncementQueryReader(slice)
+        NodeAnnouncementReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -12550,60 +12630,110 @@ impl<'r> molecule::prelude::Reader<'r> for ChannelAnnouncementQueryReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        OutPointReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        EcdsaSignatureReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        PubkeyReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        BytesVecReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct ChannelAnnouncementQueryBuilder {
-    pub(crate) channel_outpoint: OutPoint,
-    pub(crate) flags: Byte,
+pub struct NodeAnnouncementBuilder {
+    pub(crate) signature: EcdsaSignature,
+    pub(crate) features: Uint64,
+    pub(crate) timestamp: Uint64,
+    pub(crate) node_id: Pubkey,
+    pub(crate) alias: Byte32,
+    pub(crate) address: BytesVec,
+    pub(crate) chain_hash: Byte32,
 }
-impl ChannelAnnouncementQueryBuilder {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn channel_outpoint(mut self, v: OutPoint) -> Self {
-        self.channel_outpoint = v;
+impl NodeAnnouncementBuilder {
+    pub const FIELD_COUNT: usize = 7;
+    pub fn signature(mut self, v: EcdsaSignature) -> Self {
+        self.signature = v;
         self
     }
-    pub fn flags(mut self, v: Byte) -> Self {
-        self.flags = v;
+    pub fn features(mut self, v: Uint64) -> Self {
+        self.features = v;
+        self
+    }
+    pub fn timestamp(mut self, v: Uint64) -> Self {
+        self.timestamp = v;
+        self
+    }
+    pub fn node_id(mut self, v: Pubkey) -> Self {
+        self.node_id = v;
+        self
+    }
+    pub fn alias(mut self, v: Byte32) -> Self {
+        self.alias = v;
+        self
+    }
+    pub fn address(mut self, v: BytesVec) -> Self {
+        self.address = v;
+        self
+    }
+    pub fn chain_hash(mut self, v: Byte32) -> Self {
+        self.chain_hash = v;
         self
     }
 }
-impl molecule::prelude::Builder for ChannelAnnouncementQueryBuilder {
-    type Entity = ChannelAnnouncementQuery;
-    const NAME: &'static str = "ChannelAnnouncementQueryBuilder";
+impl molecule::prelude::Builder for NodeAnnouncementBuilder {
+    type Entity = NodeAnnouncement;
+    const NAME: &'static str = "NodeAnnouncementBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.channel_outpoint.as_slice().len()
-            + self.flags.as_slice().len()
+            + self.signature.as_slice().len()
+            + self.features.as_slice().len()
+            + self.timestamp.as_slice().len()
+            + self.node_id.as_slice().len()
+            + self.alias.as_slice().len()
+            + self.address.as_slice().len()
+            + self.chain_hash.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.channel_outpoint.as_slice().len();
+        total_size += self.signature.as_slice().len();
         offsets.push(total_size);
-        total_size += self.flags.as_slice().len();
+        total_size += self.features.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.timestamp.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.node_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.alias.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.address.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.chain_hash.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.channel_outpoint.as_slice())?;
-        writer.write_all(self.flags.as_slice())?;
-        Ok(())
-    }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ChannelAnnouncementQuery::new_unchecked(inner.into())
+        writer.write_all(self.signature.as_slice())?;
+        writer.write_all(self.features.as_slice())?;
+        writer.write_all(self.timestamp.as_slice())?;
+        writer.write_all(self.node_id.as_slice())?;
+        writer.write_all(self.alias.as_slice())?;
+        writer.write_all(self.address.as_slice())?;
+        writer.write_all(self.chain_hash.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        NodeAnnouncement::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct ChannelUpdateQuery(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ChannelUpdateQuery {
+pub struct ChannelAnnouncement(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ChannelAnnouncement {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -12612,16 +12742,25 @@ impl ::core::fmt::LowerHex for ChannelUpdateQuery {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ChannelUpdateQuery {
+impl ::core::fmt::Debug for ChannelAnnouncement {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ChannelUpdateQuery {
+impl ::core::fmt::Display for ChannelAnnouncement {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "channel_outpoint", self.channel_outpoint())?;
-        write!(f, ", {}: {}", "flags", self.flags())?;
+        write!(f, "{}: {}", "node1_signature", self.node1_signature())?;
+        write!(f, ", {}: {}", "node2_signature", self.node2_signature())?;
+        write!(f, ", {}: {}", "ckb_signature", self.ckb_signature())?;
+        write!(f, ", {}: {}", "features", self.features())?;
+        write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
+        write!(f, ", {}: {}", "channel_outpoint", self.channel_outpoint())?;
+        write!(f, ", {}: {}", "node1_id", self.node1_id())?;
+        write!(f, ", {}: {}", "node2_id", self.node2_id())?;
+        write!(f, ", {}: {}", "ckb_key", self.ckb_key())?;
+        write!(f, ", {}: {}", "capacity", self.capacity())?;
+        write!(f, ", {}: {}", "udt_type_script", self.udt_type_script())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -12629,18 +12768,27 @@ impl ::core::fmt::Display for ChannelUpdateQuery {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for ChannelUpdateQuery {
+impl ::core::default::Default for ChannelAnnouncement {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        ChannelUpdateQuery::new_unchecked(v)
+        ChannelAnnouncement::new_unchecked(v)
     }
 }
-impl ChannelUpdateQuery {
-    const DEFAULT_VALUE: [u8; 49] = [
-        49, 0, 0, 0, 12, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl ChannelAnnouncement {
+    const DEFAULT_VALUE: [u8; 310] = [
+        54, 1, 0, 0, 48, 0, 0, 0, 52, 0, 0, 0, 56, 0, 0, 0, 120, 0, 0, 0, 128, 0, 0, 0, 160, 0, 0,
+        0, 196, 0, 0, 0, 229, 0, 0, 0, 6, 1, 0, 0, 38, 1, 0, 0, 54, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_COUNT: usize = 11;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -12657,31 +12805,85 @@ impl ChannelUpdateQuery {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn channel_outpoint(&self) -> OutPoint {
+    pub fn node1_signature(&self) -> EcdsaSignature {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        OutPoint::new_unchecked(self.0.slice(start..end))
+        EcdsaSignature::new_unchecked(self.0.slice(start..end))
     }
-    pub fn flags(&self) -> Byte {
+    pub fn node2_signature(&self) -> EcdsaSignature {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        EcdsaSignature::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn ckb_signature(&self) -> SchnorrSignature {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        SchnorrSignature::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn features(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn chain_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn channel_outpoint(&self) -> OutPoint {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        OutPoint::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn node1_id(&self) -> Pubkey {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Pubkey::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn node2_id(&self) -> Pubkey {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Pubkey::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn ckb_key(&self) -> SchnorrXOnlyPubkey {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        SchnorrXOnlyPubkey::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn capacity(&self) -> Uint128 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        Uint128::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn udt_type_script(&self) -> ScriptOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            Byte::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[48..]) as usize;
+            ScriptOpt::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte::new_unchecked(self.0.slice(start..))
+            ScriptOpt::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> ChannelUpdateQueryReader<'r> {
-        ChannelUpdateQueryReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ChannelAnnouncementReader<'r> {
+        ChannelAnnouncementReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ChannelUpdateQuery {
-    type Builder = ChannelUpdateQueryBuilder;
-    const NAME: &'static str = "ChannelUpdateQuery";
+impl molecule::prelude::Entity for ChannelAnnouncement {
+    type Builder = ChannelAnnouncementBuilder;
+    const NAME: &'static str = "ChannelAnnouncement";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ChannelUpdateQuery(data)
+        ChannelAnnouncement(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -12690,23 +12892,32 @@ impl molecule::prelude::Entity for ChannelUpdateQuery {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ChannelUpdateQueryReader::from_slice(slice).map(|reader| reader.to_entity())
+        ChannelAnnouncementReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ChannelUpdateQueryReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ChannelAnnouncementReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
+            .node1_signature(self.node1_signature())
+            .node2_signature(self.node2_signature())
+            .ckb_signature(self.ckb_signature())
+            .features(self.features())
+            .chain_hash(self.chain_hash())
             .channel_outpoint(self.channel_outpoint())
-            .flags(self.flags())
+            .node1_id(self.node1_id())
+            .node2_id(self.node2_id())
+            .ckb_key(self.ckb_key())
+            .capacity(self.capacity())
+            .udt_type_script(self.udt_type_script())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ChannelUpdateQueryReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ChannelUpdateQueryReader<'r> {
+pub struct ChannelAnnouncementReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ChannelAnnouncementReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -12715,16 +12926,25 @@ impl<'r> ::core::fmt::LowerHex for ChannelUpdateQueryReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ChannelUpdateQueryReader<'r> {
+impl<'r> ::core::fmt::Debug for ChannelAnnouncementReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ChannelUpdateQueryReader<'r> {
+impl<'r> ::core::fmt::Display for ChannelAnnouncementReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "channel_outpoint", self.channel_outpoint())?;
-        write!(f, ", {}: {}", "flags", self.flags())?;
+        write!(f, "{}: {}", "node1_signature", self.node1_signature())?;
+        write!(f, ", {}: {}", "node2_signature", self.node2_signature())?;
+        write!(f, ", {}: {}", "ckb_signature", self.ckb_signature())?;
+        write!(f, ", {}: {}", "features", self.features())?;
+        write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
+        write!(f, ", {}: {}", "channel_outpoint", self.channel_outpoint())?;
+        write!(f, ", {}: {}", "node1_id", self.node1_id())?;
+        write!(f, ", {}: {}", "node2_id", self.node2_id())?;
+        write!(f, ", {}: {}", "ckb_key", self.ckb_key())?;
+        write!(f, ", {}: {}", "capacity", self.capacity())?;
+        write!(f, ", {}: {}", "udt_type_script", self.udt_type_script())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -12732,8 +12952,8 @@ impl<'r> ::core::fmt::Display for ChannelUpdateQueryReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> ChannelUpdateQueryReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
+impl<'r> ChannelAnnouncementReader<'r> {
+    pub const FIELD_COUNT: usize = 11;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -12750,31 +12970,85 @@ impl<'r> ChannelUpdateQueryReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn channel_outpoint(&self) -> OutPointReader<'r> {
+    pub fn node1_signature(&self) -> EcdsaSignatureReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        OutPointReader::new_unchecked(&self.as_slice()[start..end])
+        EcdsaSignatureReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn flags(&self) -> ByteReader<'r> {
+    pub fn node2_signature(&self) -> EcdsaSignatureReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        EcdsaSignatureReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn ckb_signature(&self) -> SchnorrSignatureReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        SchnorrSignatureReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn features(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn chain_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn channel_outpoint(&self) -> OutPointReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        OutPointReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn node1_id(&self) -> PubkeyReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        PubkeyReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn node2_id(&self) -> PubkeyReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        PubkeyReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn ckb_key(&self) -> SchnorrXOnlyPubkeyReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        SchnorrXOnlyPubkeyReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn capacity(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn udt_type_script(&self) -> ScriptOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            ByteReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[48..]) as usize;
+            ScriptOptReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            ByteReader::new_unchecked(&self.as_slice()[start..])
+            ScriptOptReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ChannelUpdateQueryReader<'r> {
-    type Entity = ChannelUpdateQuery;
-    const NAME: &'static str = "ChannelUpdateQueryReader";
+impl<'r> molecule::prelude::Reader<'r> for ChannelAnnouncementReader<'r> {
+    type Entity = ChannelAnnouncement;
+    const NAME: &'static str = "ChannelAnnouncementReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ChannelUpdateQueryReader(slice)
+        ChannelAnnouncementReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -12813,60 +13087,150 @@ impl<'r> molecule::prelude::Reader<'r> for ChannelUpdateQueryReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        OutPointReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Ok(())
-    }
-}
+        EcdsaSignatureReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        EcdsaSignatureReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        SchnorrSignatureReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        OutPointReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        PubkeyReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        PubkeyReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        SchnorrXOnlyPubkeyReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        ScriptOptReader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
+        Ok(())
+    }
+}
 #[derive(Clone, Debug, Default)]
-pub struct ChannelUpdateQueryBuilder {
+pub struct ChannelAnnouncementBuilder {
+    pub(crate) node1_signature: EcdsaSignature,
+    pub(crate) node2_signature: EcdsaSignature,
+    pub(crate) ckb_signature: SchnorrSignature,
+    pub(crate) features: Uint64,
+    pub(crate) chain_hash: Byte32,
     pub(crate) channel_outpoint: OutPoint,
-    pub(crate) flags: Byte,
+    pub(crate) node1_id: Pubkey,
+    pub(crate) node2_id: Pubkey,
+    pub(crate) ckb_key: SchnorrXOnlyPubkey,
+    pub(crate) capacity: Uint128,
+    pub(crate) udt_type_script: ScriptOpt,
 }
-impl ChannelUpdateQueryBuilder {
-    pub const FIELD_COUNT: usize = 2;
+impl ChannelAnnouncementBuilder {
+    pub const FIELD_COUNT: usize = 11;
+    pub fn node1_signature(mut self, v: EcdsaSignature) -> Self {
+        self.node1_signature = v;
+        self
+    }
+    pub fn node2_signature(mut self, v: EcdsaSignature) -> Self {
+        self.node2_signature = v;
+        self
+    }
+    pub fn ckb_signature(mut self, v: SchnorrSignature) -> Self {
+        self.ckb_signature = v;
+        self
+    }
+    pub fn features(mut self, v: Uint64) -> Self {
+        self.features = v;
+        self
+    }
+    pub fn chain_hash(mut self, v: Byte32) -> Self {
+        self.chain_hash = v;
+        self
+    }
     pub fn channel_outpoint(mut self, v: OutPoint) -> Self {
         self.channel_outpoint = v;
         self
     }
-    pub fn flags(mut self, v: Byte) -> Self {
-        self.flags = v;
+    pub fn node1_id(mut self, v: Pubkey) -> Self {
+        self.node1_id = v;
+        self
+    }
+    pub fn node2_id(mut self, v: Pubkey) -> Self {
+        self.node2_id = v;
+        self
+    }
+    pub fn ckb_key(mut self, v: SchnorrXOnlyPubkey) -> Self {
+        self.ckb_key = v;
+        self
+    }
+    pub fn capacity(mut self, v: Uint128) -> Self {
+        self.capacity = v;
+        self
+    }
+    pub fn udt_type_script(mut self, v: ScriptOpt) -> Self {
+        self.udt_type_script = v;
         self
     }
 }
-impl molecule::prelude::Builder for ChannelUpdateQueryBuilder {
-    type Entity = ChannelUpdateQuery;
-    const NAME: &'static str = "ChannelUpdateQueryBuilder";
+impl molecule::prelude::Builder for ChannelAnnouncementBuilder {
+    type Entity = ChannelAnnouncement;
+    const NAME: &'static str = "ChannelAnnouncementBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.node1_signature.as_slice().len()
+            + self.node2_signature.as_slice().len()
+            + self.ckb_signature.as_slice().len()
+            + self.features.as_slice().len()
+            + self.chain_hash.as_slice().len()
             + self.channel_outpoint.as_slice().len()
-            + self.flags.as_slice().len()
+            + self.node1_id.as_slice().len()
+            + self.node2_id.as_slice().len()
+            + self.ckb_key.as_slice().len()
+            + self.capacity.as_slice().len()
+            + self.udt_type_script.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
+        total_size += self.node1_signature.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.node2_signature.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.ckb_signature.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.features.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.chain_hash.as_slice().len();
+        offsets.push(total_size);
         total_size += self.channel_outpoint.as_slice().len();
         offsets.push(total_size);
-        total_size += self.flags.as_slice().len();
+        total_size += self.node1_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.node2_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.ckb_key.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.capacity.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.udt_type_script.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
+        writer.write_all(self.node1_signature.as_slice())?;
+        writer.write_all(self.node2_signature.as_slice())?;
+        writer.write_all(self.ckb_signature.as_slice())?;
+        writer.write_all(self.features.as_slice())?;
+        writer.write_all(self.chain_hash.as_slice())?;
         writer.write_all(self.channel_outpoint.as_slice())?;
-        writer.write_all(self.flags.as_slice())?;
+        writer.write_all(self.node1_id.as_slice())?;
+        writer.write_all(self.node2_id.as_slice())?;
+        writer.write_all(self.ckb_key.as_slice())?;
+        writer.write_all(self.capacity.as_slice())?;
+        writer.write_all(self.udt_type_script.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ChannelUpdateQuery::new_unchecked(inner.into())
+        ChannelAnnouncement::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct BroadcastMessageQuery(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for BroadcastMessageQuery {
+pub struct ChannelUpdate(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ChannelUpdate {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -12875,117 +13239,2135 @@ impl ::core::fmt::LowerHex for BroadcastMessageQuery {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for BroadcastMessageQuery {
+impl ::core::fmt::Debug for ChannelUpdate {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for BroadcastMessageQuery {
+impl ::core::fmt::Display for ChannelUpdate {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}(", Self::NAME)?;
-        self.to_enum().display_inner(f)?;
-        write!(f, ")")
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "signature", self.signature())?;
+        write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
+        write!(f, ", {}: {}", "channel_outpoint", self.channel_outpoint())?;
+        write!(f, ", {}: {}", "timestamp", self.timestamp())?;
+        write!(f, ", {}: {}", "message_flags", self.message_flags())?;
+        write!(f, ", {}: {}", "channel_flags", self.channel_flags())?;
+        write!(
+            f,
+            ", {}: {}",
+            "tlc_locktime_expiry_delta",
+            self.tlc_locktime_expiry_delta()
+        )?;
+        write!(f, ", {}: {}", "tlc_minimum_value", self.tlc_minimum_value())?;
+        write!(f, ", {}: {}", "tlc_maximum_value", self.tlc_maximum_value())?;
+        write!(
+            f,
+            ", {}: {}",
+            "tlc_fee_proportional_millionths",
+            self.tlc_fee_proportional_millionths()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl ::core::default::Default for BroadcastMessageQuery {
+impl ::core::default::Default for ChannelUpdate {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        BroadcastMessageQuery::new_unchecked(v)
+        ChannelUpdate::new_unchecked(v)
     }
 }
-impl BroadcastMessageQuery {
-    const DEFAULT_VALUE: [u8; 50] = [
-        0, 0, 0, 0, 46, 0, 0, 0, 12, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl ChannelUpdate {
+    const DEFAULT_VALUE: [u8; 188] = [
+        188, 0, 0, 0, 44, 0, 0, 0, 48, 0, 0, 0, 80, 0, 0, 0, 116, 0, 0, 0, 124, 0, 0, 0, 128, 0, 0,
+        0, 132, 0, 0, 0, 140, 0, 0, 0, 156, 0, 0, 0, 172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const ITEMS_COUNT: usize = 3;
-    pub fn item_id(&self) -> molecule::Number {
-        molecule::unpack_number(self.as_slice())
+    pub const FIELD_COUNT: usize = 10;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn to_enum(&self) -> BroadcastMessageQueryUnion {
-        let inner = self.0.slice(molecule::NUMBER_SIZE..);
-        match self.item_id() {
-            0 => NodeAnnouncementQuery::new_unchecked(inner).into(),
-            1 => ChannelAnnouncementQuery::new_unchecked(inner).into(),
-            2 => ChannelUpdateQuery::new_unchecked(inner).into(),
-            _ => panic!("{}: invalid data", Self::NAME),
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn as_reader<'r>(&'r self) -> BroadcastMessageQueryReader<'r> {
-        BroadcastMessageQueryReader::new_unchecked(self.as_slice())
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-}
-impl molecule::prelude::Entity for BroadcastMessageQuery {
-    type Builder = BroadcastMessageQueryBuilder;
-    const NAME: &'static str = "BroadcastMessageQuery";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        BroadcastMessageQuery(data)
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+    pub fn signature(&self) -> EcdsaSignature {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        EcdsaSignature::new_unchecked(self.0.slice(start..end))
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+    pub fn chain_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BroadcastMessageQueryReader::from_slice(slice).map(|reader| reader.to_entity())
+    pub fn channel_outpoint(&self) -> OutPoint {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        OutPoint::new_unchecked(self.0.slice(start..end))
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BroadcastMessageQueryReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    pub fn timestamp(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+    pub fn message_flags(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set(self.to_enum())
+    pub fn channel_flags(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
-}
-#[derive(Clone, Copy)]
-pub struct BroadcastMessageQueryReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for BroadcastMessageQueryReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
+    pub fn tlc_locktime_expiry_delta(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn tlc_minimum_value(&self) -> Uint128 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Uint128::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn tlc_maximum_value(&self) -> Uint128 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Uint128::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn tlc_fee_proportional_millionths(&self) -> Uint128 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[44..]) as usize;
+            Uint128::new_unchecked(self.0.slice(start..end))
+        } else {
+            Uint128::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ChannelUpdateReader<'r> {
+        ChannelUpdateReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ChannelUpdate {
+    type Builder = ChannelUpdateBuilder;
+    const NAME: &'static str = "ChannelUpdate";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ChannelUpdate(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ChannelUpdateReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ChannelUpdateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .signature(self.signature())
+            .chain_hash(self.chain_hash())
+            .channel_outpoint(self.channel_outpoint())
+            .timestamp(self.timestamp())
+            .message_flags(self.message_flags())
+            .channel_flags(self.channel_flags())
+            .tlc_locktime_expiry_delta(self.tlc_locktime_expiry_delta())
+            .tlc_minimum_value(self.tlc_minimum_value())
+            .tlc_maximum_value(self.tlc_maximum_value())
+            .tlc_fee_proportional_millionths(self.tlc_fee_proportional_millionths())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ChannelUpdateReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ChannelUpdateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ChannelUpdateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ChannelUpdateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "signature", self.signature())?;
+        write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
+        write!(f, ", {}: {}", "channel_outpoint", self.channel_outpoint())?;
+        write!(f, ", {}: {}", "timestamp", self.timestamp())?;
+        write!(f, ", {}: {}", "message_flags", self.message_flags())?;
+        write!(f, ", {}: {}", "channel_flags", self.channel_flags())?;
+        write!(
+            f,
+            ", {}: {}",
+            "tlc_locktime_expiry_delta",
+            self.tlc_locktime_expiry_delta()
+        )?;
+        write!(f, ", {}: {}", "tlc_minimum_value", self.tlc_minimum_value())?;
+        write!(f, ", {}: {}", "tlc_maximum_value", self.tlc_maximum_value())?;
+        write!(
+            f,
+            ", {}: {}",
+            "tlc_fee_proportional_millionths",
+            self.tlc_fee_proportional_millionths()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ChannelUpdateReader<'r> {
+    pub const FIELD_COUNT: usize = 10;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn signature(&self) -> EcdsaSignatureReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        EcdsaSignatureReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn chain_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn channel_outpoint(&self) -> OutPointReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        OutPointReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn timestamp(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn message_flags(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn channel_flags(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn tlc_locktime_expiry_delta(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn tlc_minimum_value(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn tlc_maximum_value(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn tlc_fee_proportional_millionths(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[44..]) as usize;
+            Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Uint128Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ChannelUpdateReader<'r> {
+    type Entity = ChannelUpdate;
+    const NAME: &'static str = "ChannelUpdateReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ChannelUpdateReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        EcdsaSignatureReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        OutPointReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct ChannelUpdateBuilder {
+    pub(crate) signature: EcdsaSignature,
+    pub(crate) chain_hash: Byte32,
+    pub(crate) channel_outpoint: OutPoint,
+    pub(crate) timestamp: Uint64,
+    pub(crate) message_flags: Uint32,
+    pub(crate) channel_flags: Uint32,
+    pub(crate) tlc_locktime_expiry_delta: Uint64,
+    pub(crate) tlc_minimum_value: Uint128,
+    pub(crate) tlc_maximum_value: Uint128,
+    pub(crate) tlc_fee_proportional_millionths: Uint128,
+}
+impl ChannelUpdateBuilder {
+    pub const FIELD_COUNT: usize = 10;
+    pub fn signature(mut self, v: EcdsaSignature) -> Self {
+        self.signature = v;
+        self
+    }
+    pub fn chain_hash(mut self, v: Byte32) -> Self {
+        self.chain_hash = v;
+        self
+    }
+    pub fn channel_outpoint(mut self, v: OutPoint) -> Self {
+        self.channel_outpoint = v;
+        self
+    }
+    pub fn timestamp(mut self, v: Uint64) -> Self {
+        self.timestamp = v;
+        self
+    }
+    pub fn message_flags(mut self, v: Uint32) -> Self {
+        self.message_flags = v;
+        self
+    }
+    pub fn channel_flags(mut self, v: Uint32) -> Self {
+        self.channel_flags = v;
+        self
+    }
+    pub fn tlc_locktime_expiry_delta(mut self, v: Uint64) -> Self {
+        self.tlc_locktime_expiry_delta = v;
+        self
+    }
+    pub fn tlc_minimum_value(mut self, v: Uint128) -> Self {
+        self.tlc_minimum_value = v;
+        self
+    }
+    pub fn tlc_maximum_value(mut self, v: Uint128) -> Self {
+        self.tlc_maximum_value = v;
+        self
+    }
+    pub fn tlc_fee_proportional_millionths(mut self, v: Uint128) -> Self {
+        self.tlc_fee_proportional_millionths = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ChannelUpdateBuilder {
+    type Entity = ChannelUpdate;
+    const NAME: &'static str = "ChannelUpdateBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.signature.as_slice().len()
+            + self.chain_hash.as_slice().len()
+            + self.channel_outpoint.as_slice().len()
+            + self.timestamp.as_slice().len()
+            + self.message_flags.as_slice().len()
+            + self.channel_flags.as_slice().len()
+            + self.tlc_locktime_expiry_delta.as_slice().len()
+            + self.tlc_minimum_value.as_slice().len()
+            + self.tlc_maximum_value.as_slice().len()
+            + self.tlc_fee_proportional_millionths.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.signature.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.chain_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.channel_outpoint.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.timestamp.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.message_flags.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.channel_flags.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.tlc_locktime_expiry_delta.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.tlc_minimum_value.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.tlc_maximum_value.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.tlc_fee_proportional_millionths.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.signature.as_slice())?;
+        writer.write_all(self.chain_hash.as_slice())?;
+        writer.write_all(self.channel_outpoint.as_slice())?;
+        writer.write_all(self.timestamp.as_slice())?;
+        writer.write_all(self.message_flags.as_slice())?;
+        writer.write_all(self.channel_flags.as_slice())?;
+        writer.write_all(self.tlc_locktime_expiry_delta.as_slice())?;
+        writer.write_all(self.tlc_minimum_value.as_slice())?;
+        writer.write_all(self.tlc_maximum_value.as_slice())?;
+        writer.write_all(self.tlc_fee_proportional_millionths.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ChannelUpdate::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct NodeAnnouncementQuery(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for NodeAnnouncementQuery {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for NodeAnnouncementQuery {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for NodeAnnouncementQuery {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "node_id", self.node_id())?;
+        write!(f, ", {}: {}", "flags", self.flags())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for NodeAnnouncementQuery {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        NodeAnnouncementQuery::new_unchecked(v)
+    }
+}
+impl NodeAnnouncementQuery {
+    const DEFAULT_VALUE: [u8; 46] = [
+        46, 0, 0, 0, 12, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn node_id(&self) -> Pubkey {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Pubkey::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn flags(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> NodeAnnouncementQueryReader<'r> {
+        NodeAnnouncementQueryReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for NodeAnnouncementQuery {
+    type Builder = NodeAnnouncementQueryBuilder;
+    const NAME: &'static str = "NodeAnnouncementQuery";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        NodeAnnouncementQuery(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        NodeAnnouncementQueryReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        NodeAnnouncementQueryReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .node_id(self.node_id())
+            .flags(self.flags())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct NodeAnnouncementQueryReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for NodeAnnouncementQueryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for NodeAnnouncementQueryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for NodeAnnouncementQueryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "node_id", self.node_id())?;
+        write!(f, ", {}: {}", "flags", self.flags())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> NodeAnnouncementQueryReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn node_id(&self) -> PubkeyReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        PubkeyReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn flags(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            ByteReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            ByteReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for NodeAnnouncementQueryReader<'r> {
+    type Entity = NodeAnnouncementQuery;
+    const NAME: &'static str = "NodeAnnouncementQueryReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        NodeAnnouncementQueryReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        PubkeyReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct NodeAnnouncementQueryBuilder {
+    pub(crate) node_id: Pubkey,
+    pub(crate) flags: Byte,
+}
+impl NodeAnnouncementQueryBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn node_id(mut self, v: Pubkey) -> Self {
+        self.node_id = v;
+        self
+    }
+    pub fn flags(mut self, v: Byte) -> Self {
+        self.flags = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for NodeAnnouncementQueryBuilder {
+    type Entity = NodeAnnouncementQuery;
+    const NAME: &'static str = "NodeAnnouncementQueryBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.node_id.as_slice().len()
+            + self.flags.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.node_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.flags.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.node_id.as_slice())?;
+        writer.write_all(self.flags.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        NodeAnnouncementQuery::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ChannelAnnouncementQuery(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ChannelAnnouncementQuery {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ChannelAnnouncementQuery {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ChannelAnnouncementQuery {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "channel_outpoint", self.channel_outpoint())?;
+        write!(f, ", {}: {}", "flags", self.flags())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ChannelAnnouncementQuery {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        ChannelAnnouncementQuery::new_unchecked(v)
+    }
+}
+impl ChannelAnnouncementQuery {
+    const DEFAULT_VALUE: [u8; 49] = [
+        49, 0, 0, 0, 12, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn channel_outpoint(&self) -> OutPoint {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        OutPoint::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn flags(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ChannelAnnouncementQueryReader<'r> {
+        ChannelAnnouncementQueryReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ChannelAnnouncementQuery {
+    type Builder = ChannelAnnouncementQueryBuilder;
+    const NAME: &'static str = "ChannelAnnouncementQuery";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ChannelAnnouncementQuery(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ChannelAnnouncementQueryReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ChannelAnnouncementQueryReader::from_compatible_slice(slice)
+            .map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .channel_outpoint(self.channel_outpoint())
+            .flags(self.flags())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ChannelAnnouncementQueryReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ChannelAnnouncementQueryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ChannelAnnouncementQueryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ChannelAnnouncementQueryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "channel_outpoint", self.channel_outpoint())?;
+        write!(f, ", {}: {}", "flags", self.flags())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ChannelAnnouncementQueryReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn channel_outpoint(&self) -> OutPointReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        OutPointReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn flags(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            ByteReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            ByteReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ChannelAnnouncementQueryReader<'r> {
+    type Entity = ChannelAnnouncementQuery;
+    const NAME: &'static str = "ChannelAnnouncementQueryReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ChannelAnnouncementQueryReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        OutPointReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct ChannelAnnouncementQueryBuilder {
+    pub(crate) channel_outpoint: OutPoint,
+    pub(crate) flags: Byte,
+}
+impl ChannelAnnouncementQueryBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn channel_outpoint(mut self, v: OutPoint) -> Self {
+        self.channel_outpoint = v;
+        self
+    }
+    pub fn flags(mut self, v: Byte) -> Self {
+        self.flags = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ChannelAnnouncementQueryBuilder {
+    type Entity = ChannelAnnouncementQuery;
+    const NAME: &'static str = "ChannelAnnouncementQueryBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.channel_outpoint.as_slice().len()
+            + self.flags.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.channel_outpoint.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.flags.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.channel_outpoint.as_slice())?;
+        writer.write_all(self.flags.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ChannelAnnouncementQuery::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ChannelUpdateQuery(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ChannelUpdateQuery {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ChannelUpdateQuery {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ChannelUpdateQuery {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "channel_outpoint", self.channel_outpoint())?;
+        write!(f, ", {}: {}", "flags", self.flags())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ChannelUpdateQuery {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        ChannelUpdateQuery::new_unchecked(v)
+    }
+}
+impl ChannelUpdateQuery {
+    const DEFAULT_VALUE: [u8; 49] = [
+        49, 0, 0, 0, 12, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn channel_outpoint(&self) -> OutPoint {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        OutPoint::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn flags(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ChannelUpdateQueryReader<'r> {
+        ChannelUpdateQueryReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ChannelUpdateQuery {
+    type Builder = ChannelUpdateQueryBuilder;
+    const NAME: &'static str = "ChannelUpdateQuery";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ChannelUpdateQuery(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ChannelUpdateQueryReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ChannelUpdateQueryReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .channel_outpoint(self.channel_outpoint())
+            .flags(self.flags())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ChannelUpdateQueryReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ChannelUpdateQueryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ChannelUpdateQueryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ChannelUpdateQueryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "channel_outpoint", self.channel_outpoint())?;
+        write!(f, ", {}: {}", "flags", self.flags())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ChannelUpdateQueryReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn channel_outpoint(&self) -> OutPointReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        OutPointReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn flags(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            ByteReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            ByteReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ChannelUpdateQueryReader<'r> {
+    type Entity = ChannelUpdateQuery;
+    const NAME: &'static str = "ChannelUpdateQueryReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ChannelUpdateQueryReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        OutPointReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct ChannelUpdateQueryBuilder {
+    pub(crate) channel_outpoint: OutPoint,
+    pub(crate) flags: Byte,
+}
+impl ChannelUpdateQueryBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn channel_outpoint(mut self, v: OutPoint) -> Self {
+        self.channel_outpoint = v;
+        self
+    }
+    pub fn flags(mut self, v: Byte) -> Self {
+        self.flags = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ChannelUpdateQueryBuilder {
+    type Entity = ChannelUpdateQuery;
+    const NAME: &'static str = "ChannelUpdateQueryBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.channel_outpoint.as_slice().len()
+            + self.flags.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.channel_outpoint.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.flags.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.channel_outpoint.as_slice())?;
+        writer.write_all(self.flags.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ChannelUpdateQuery::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct BroadcastMessageQuery(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BroadcastMessageQuery {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for BroadcastMessageQuery {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for BroadcastMessageQuery {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl ::core::default::Default for BroadcastMessageQuery {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        BroadcastMessageQuery::new_unchecked(v)
+    }
+}
+impl BroadcastMessageQuery {
+    const DEFAULT_VALUE: [u8; 50] = [
+        0, 0, 0, 0, 46, 0, 0, 0, 12, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const ITEMS_COUNT: usize = 3;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> BroadcastMessageQueryUnion {
+        let inner = self.0.slice(molecule::NUMBER_SIZE..);
+        match self.item_id() {
+            0 => NodeAnnouncementQuery::new_unchecked(inner).into(),
+            1 => ChannelAnnouncementQuery::new_unchecked(inner).into(),
+            2 => ChannelUpdateQuery::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BroadcastMessageQueryReader<'r> {
+        BroadcastMessageQueryReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for BroadcastMessageQuery {
+    type Builder = BroadcastMessageQueryBuilder;
+    const NAME: &'static str = "BroadcastMessageQuery";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        BroadcastMessageQuery(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BroadcastMessageQueryReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BroadcastMessageQueryReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_enum())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BroadcastMessageQueryReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BroadcastMessageQueryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BroadcastMessageQueryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BroadcastMessageQueryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl<'r> BroadcastMessageQueryReader<'r> {
+    pub const ITEMS_COUNT: usize = 3;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> BroadcastMessageQueryUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => NodeAnnouncementQueryReader::new_unchecked(inner).into(),
+            1 => ChannelAnnouncementQueryReader::new_unchecked(inner).into(),
+            2 => ChannelUpdateQueryReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BroadcastMessageQueryReader<'r> {
+    type Entity = BroadcastMessageQuery;
+    const NAME: &'static str = "BroadcastMessageQueryReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BroadcastMessageQueryReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => NodeAnnouncementQueryReader::verify(inner_slice, compatible),
+            1 => ChannelAnnouncementQueryReader::verify(inner_slice, compatible),
+            2 => ChannelUpdateQueryReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
+        }?;
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct BroadcastMessageQueryBuilder(pub(crate) BroadcastMessageQueryUnion);
+impl BroadcastMessageQueryBuilder {
+    pub const ITEMS_COUNT: usize = 3;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<BroadcastMessageQueryUnion>,
+    {
+        self.0 = v.into();
+        self
+    }
+}
+impl molecule::prelude::Builder for BroadcastMessageQueryBuilder {
+    type Entity = BroadcastMessageQuery;
+    const NAME: &'static str = "BroadcastMessageQueryBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        BroadcastMessageQuery::new_unchecked(inner.into())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum BroadcastMessageQueryUnion {
+    NodeAnnouncementQuery(NodeAnnouncementQuery),
+    ChannelAnnouncementQuery(ChannelAnnouncementQuery),
+    ChannelUpdateQuery(ChannelUpdateQuery),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum BroadcastMessageQueryUnionReader<'r> {
+    NodeAnnouncementQuery(NodeAnnouncementQueryReader<'r>),
+    ChannelAnnouncementQuery(ChannelAnnouncementQueryReader<'r>),
+    ChannelUpdateQuery(ChannelUpdateQueryReader<'r>),
+}
+impl ::core::default::Default for BroadcastMessageQueryUnion {
+    fn default() -> Self {
+        BroadcastMessageQueryUnion::NodeAnnouncementQuery(::core::default::Default::default())
+    }
+}
+impl ::core::fmt::Display for BroadcastMessageQueryUnion {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            BroadcastMessageQueryUnion::NodeAnnouncementQuery(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    NodeAnnouncementQuery::NAME,
+                    item
+                )
+            }
+            BroadcastMessageQueryUnion::ChannelAnnouncementQuery(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    ChannelAnnouncementQuery::NAME,
+                    item
+                )
+            }
+            BroadcastMessageQueryUnion::ChannelUpdateQuery(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ChannelUpdateQuery::NAME, item)
+            }
+        }
+    }
+}
+impl<'r> ::core::fmt::Display for BroadcastMessageQueryUnionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            BroadcastMessageQueryUnionReader::NodeAnnouncementQuery(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    NodeAnnouncementQuery::NAME,
+                    item
+                )
+            }
+            BroadcastMessageQueryUnionReader::ChannelAnnouncementQuery(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    ChannelAnnouncementQuery::NAME,
+                    item
+                )
+            }
+            BroadcastMessageQueryUnionReader::ChannelUpdateQuery(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ChannelUpdateQuery::NAME, item)
+            }
+        }
+    }
+}
+impl BroadcastMessageQueryUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            BroadcastMessageQueryUnion::NodeAnnouncementQuery(ref item) => write!(f, "{}", item),
+            BroadcastMessageQueryUnion::ChannelAnnouncementQuery(ref item) => write!(f, "{}", item),
+            BroadcastMessageQueryUnion::ChannelUpdateQuery(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl<'r> BroadcastMessageQueryUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            BroadcastMessageQueryUnionReader::NodeAnnouncementQuery(ref item) => {
+                write!(f, "{}", item)
+            }
+            BroadcastMessageQueryUnionReader::ChannelAnnouncementQuery(ref item) => {
+                write!(f, "{}", item)
+            }
+            BroadcastMessageQueryUnionReader::ChannelUpdateQuery(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl ::core::convert::From<NodeAnnouncementQuery> for BroadcastMessageQueryUnion {
+    fn from(item: NodeAnnouncementQuery) -> Self {
+        BroadcastMessageQueryUnion::NodeAnnouncementQuery(item)
+    }
+}
+impl ::core::convert::From<ChannelAnnouncementQuery> for BroadcastMessageQueryUnion {
+    fn from(item: ChannelAnnouncementQuery) -> Self {
+        BroadcastMessageQueryUnion::ChannelAnnouncementQuery(item)
+    }
+}
+impl ::core::convert::From<ChannelUpdateQuery> for BroadcastMessageQueryUnion {
+    fn from(item: ChannelUpdateQuery) -> Self {
+        BroadcastMessageQueryUnion::ChannelUpdateQuery(item)
+    }
+}
+impl<'r> ::core::convert::From<NodeAnnouncementQueryReader<'r>>
+    for BroadcastMessageQueryUnionReader<'r>
+{
+    fn from(item: NodeAnnouncementQueryReader<'r>) -> Self {
+        BroadcastMessageQueryUnionReader::NodeAnnouncementQuery(item)
+    }
+}
+impl<'r> ::core::convert::From<ChannelAnnouncementQueryReader<'r>>
+    for BroadcastMessageQueryUnionReader<'r>
+{
+    fn from(item: ChannelAnnouncementQueryReader<'r>) -> Self {
+        BroadcastMessageQueryUnionReader::ChannelAnnouncementQuery(item)
+    }
+}
+impl<'r> ::core::convert::From<ChannelUpdateQueryReader<'r>>
+    for BroadcastMessageQueryUnionReader<'r>
+{
+    fn from(item: ChannelUpdateQueryReader<'r>) -> Self {
+        BroadcastMessageQueryUnionReader::ChannelUpdateQuery(item)
+    }
+}
+impl BroadcastMessageQueryUnion {
+    pub const NAME: &'static str = "BroadcastMessageQueryUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            BroadcastMessageQueryUnion::NodeAnnouncementQuery(item) => item.as_bytes(),
+            BroadcastMessageQueryUnion::ChannelAnnouncementQuery(item) => item.as_bytes(),
+            BroadcastMessageQueryUnion::ChannelUpdateQuery(item) => item.as_bytes(),
+        }
+    }
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            BroadcastMessageQueryUnion::NodeAnnouncementQuery(item) => item.as_slice(),
+            BroadcastMessageQueryUnion::ChannelAnnouncementQuery(item) => item.as_slice(),
+            BroadcastMessageQueryUnion::ChannelUpdateQuery(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            BroadcastMessageQueryUnion::NodeAnnouncementQuery(_) => 0,
+            BroadcastMessageQueryUnion::ChannelAnnouncementQuery(_) => 1,
+            BroadcastMessageQueryUnion::ChannelUpdateQuery(_) => 2,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            BroadcastMessageQueryUnion::NodeAnnouncementQuery(_) => "NodeAnnouncementQuery",
+            BroadcastMessageQueryUnion::ChannelAnnouncementQuery(_) => "ChannelAnnouncementQuery",
+            BroadcastMessageQueryUnion::ChannelUpdateQuery(_) => "ChannelUpdateQuery",
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BroadcastMessageQueryUnionReader<'r> {
+        match self {
+            BroadcastMessageQueryUnion::NodeAnnouncementQuery(item) => item.as_reader().into(),
+            BroadcastMessageQueryUnion::ChannelAnnouncementQuery(item) => item.as_reader().into(),
+            BroadcastMessageQueryUnion::ChannelUpdateQuery(item) => item.as_reader().into(),
+        }
+    }
+}
+impl<'r> BroadcastMessageQueryUnionReader<'r> {
+    pub const NAME: &'r str = "BroadcastMessageQueryUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            BroadcastMessageQueryUnionReader::NodeAnnouncementQuery(item) => item.as_slice(),
+            BroadcastMessageQueryUnionReader::ChannelAnnouncementQuery(item) => item.as_slice(),
+            BroadcastMessageQueryUnionReader::ChannelUpdateQuery(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            BroadcastMessageQueryUnionReader::NodeAnnouncementQuery(_) => 0,
+            BroadcastMessageQueryUnionReader::ChannelAnnouncementQuery(_) => 1,
+            BroadcastMessageQueryUnionReader::ChannelUpdateQuery(_) => 2,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            BroadcastMessageQueryUnionReader::NodeAnnouncementQuery(_) => "NodeAnnouncementQuery",
+            BroadcastMessageQueryUnionReader::ChannelAnnouncementQuery(_) => {
+                "ChannelAnnouncementQuery"
+            }
+            BroadcastMessageQueryUnionReader::ChannelUpdateQuery(_) => "ChannelUpdateQuery",
+        }
+    }
+}
+impl From<NodeAnnouncementQuery> for BroadcastMessageQuery {
+    fn from(value: NodeAnnouncementQuery) -> Self {
+        Self::new_builder().set(value).build()
+    }
+}
+impl From<ChannelAnnouncementQuery> for BroadcastMessageQuery {
+    fn from(value: ChannelAnnouncementQuery) -> Self {
+        Self::new_builder().set(value).build()
+    }
+}
+impl From<ChannelUpdateQuery> for BroadcastMessageQuery {
+    fn from(value: ChannelUpdateQuery) -> Self {
+        Self::new_builder().set(value).build()
+    }
+}
+#[derive(Clone)]
+pub struct BroadcastMessageQueries(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BroadcastMessageQueries {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for BroadcastMessageQueries {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for BroadcastMessageQueries {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for BroadcastMessageQueries {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        BroadcastMessageQueries::new_unchecked(v)
+    }
+}
+impl BroadcastMessageQueries {
+    const DEFAULT_VALUE: [u8; 4] = [4, 0, 0, 0];
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<BroadcastMessageQuery> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> BroadcastMessageQuery {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            BroadcastMessageQuery::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            BroadcastMessageQuery::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BroadcastMessageQueriesReader<'r> {
+        BroadcastMessageQueriesReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for BroadcastMessageQueries {
+    type Builder = BroadcastMessageQueriesBuilder;
+    const NAME: &'static str = "BroadcastMessageQueries";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        BroadcastMessageQueries(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BroadcastMessageQueriesReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BroadcastMessageQueriesReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BroadcastMessageQueriesReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BroadcastMessageQueriesReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BroadcastMessageQueriesReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BroadcastMessageQueriesReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> BroadcastMessageQueriesReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<BroadcastMessageQueryReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> BroadcastMessageQueryReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            BroadcastMessageQueryReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            BroadcastMessageQueryReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BroadcastMessageQueriesReader<'r> {
+    type Entity = BroadcastMessageQueries;
+    const NAME: &'static str = "BroadcastMessageQueriesReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BroadcastMessageQueriesReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            BroadcastMessageQueryReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct BroadcastMessageQueriesBuilder(pub(crate) Vec<BroadcastMessageQuery>);
+impl BroadcastMessageQueriesBuilder {
+    pub fn set(mut self, v: Vec<BroadcastMessageQuery>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: BroadcastMessageQuery) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = BroadcastMessageQuery>>(
+        mut self,
+        iter: T,
+    ) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(
+        &mut self,
+        index: usize,
+        v: BroadcastMessageQuery,
+    ) -> Option<BroadcastMessageQuery> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for BroadcastMessageQueriesBuilder {
+    type Entity = BroadcastMessageQueries;
+    const NAME: &'static str = "BroadcastMessageQueriesBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        BroadcastMessageQueries::new_unchecked(inner.into())
+    }
+}
+pub struct BroadcastMessageQueriesIterator(BroadcastMessageQueries, usize, usize);
+impl ::core::iter::Iterator for BroadcastMessageQueriesIterator {
+    type Item = BroadcastMessageQuery;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for BroadcastMessageQueriesIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for BroadcastMessageQueries {
+    type Item = BroadcastMessageQuery;
+    type IntoIter = BroadcastMessageQueriesIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        BroadcastMessageQueriesIterator(self, 0, len)
+    }
+}
+impl<'r> BroadcastMessageQueriesReader<'r> {
+    pub fn iter<'t>(&'t self) -> BroadcastMessageQueriesReaderIterator<'t, 'r> {
+        BroadcastMessageQueriesReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct BroadcastMessageQueriesReaderIterator<'t, 'r>(
+    &'t BroadcastMessageQueriesReader<'r>,
+    usize,
+    usize,
+);
+impl<'t: 'r, 'r> ::core::iter::Iterator for BroadcastMessageQueriesReaderIterator<'t, 'r> {
+    type Item = BroadcastMessageQueryReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for BroadcastMessageQueriesReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::FromIterator<BroadcastMessageQuery> for BroadcastMessageQueries {
+    fn from_iter<T: IntoIterator<Item = BroadcastMessageQuery>>(iter: T) -> Self {
+        Self::new_builder().extend(iter).build()
+    }
+}
+#[derive(Clone)]
+pub struct BroadcastMessage(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BroadcastMessage {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for BroadcastMessageQueryReader<'r> {
+impl ::core::fmt::Debug for BroadcastMessage {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for BroadcastMessageQueryReader<'r> {
+impl ::core::fmt::Display for BroadcastMessage {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}(", Self::NAME)?;
         self.to_enum().display_inner(f)?;
         write!(f, ")")
     }
 }
-impl<'r> BroadcastMessageQueryReader<'r> {
+impl ::core::default::Default for BroadcastMessage {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        BroadcastMessage::new_unchecked(v)
+    }
+}
+impl BroadcastMessage {
+    const DEFAULT_VALUE: [u8; 157] = [
+        0, 0, 0, 0, 153, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 44, 0, 0, 0, 52, 0, 0, 0, 85, 0, 0, 0,
+        117, 0, 0, 0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
     pub const ITEMS_COUNT: usize = 3;
     pub fn item_id(&self) -> molecule::Number {
         molecule::unpack_number(self.as_slice())
     }
-    pub fn to_enum(&self) -> BroadcastMessageQueryUnionReader<'r> {
+    pub fn to_enum(&self) -> BroadcastMessageUnion {
+        let inner = self.0.slice(molecule::NUMBER_SIZE..);
+        match self.item_id() {
+            0 => NodeAnnouncement::new_unchecked(inner).into(),
+            1 => ChannelAnnouncement::new_unchecked(inner).into(),
+            2 => ChannelUpdate::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BroadcastMessageReader<'r> {
+        BroadcastMessageReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for BroadcastMessage {
+    type Builder = BroadcastMessageBuilder;
+    const NAME: &'static str = "BroadcastMessage";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        BroadcastMessage(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BroadcastMessageReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BroadcastMessageReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_enum())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BroadcastMessageReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BroadcastMessageReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BroadcastMessageReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BroadcastMessageReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl<'r> BroadcastMessageReader<'r> {
+    pub const ITEMS_COUNT: usize = 3;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> BroadcastMessageUnionReader<'r> {
         let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
         match self.item_id() {
-            0 => NodeAnnouncementQueryReader::new_unchecked(inner).into(),
-            1 => ChannelAnnouncementQueryReader::new_unchecked(inner).into(),
-            2 => ChannelUpdateQueryReader::new_unchecked(inner).into(),
+            0 => NodeAnnouncementReader::new_unchecked(inner).into(),
+            1 => ChannelAnnouncementReader::new_unchecked(inner).into(),
+            2 => ChannelUpdateReader::new_unchecked(inner).into(),
             _ => panic!("{}: invalid data", Self::NAME),
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for BroadcastMessageQueryReader<'r> {
-    type Entity = BroadcastMessageQuery;
-    const NAME: &'static str = "BroadcastMessageQueryReader";
+impl<'r> molecule::prelude::Reader<'r> for BroadcastMessageReader<'r> {
+    type Entity = BroadcastMessage;
+    const NAME: &'static str = "BroadcastMessageReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        BroadcastMessageQueryReader(slice)
+        BroadcastMessageReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -12999,29 +15381,29 @@ impl<'r> molecule::prelude::Reader<'r> for BroadcastMessageQueryReader<'r> {
         let item_id = molecule::unpack_number(slice);
         let inner_slice = &slice[molecule::NUMBER_SIZE..];
         match item_id {
-            0 => NodeAnnouncementQueryReader::verify(inner_slice, compatible),
-            1 => ChannelAnnouncementQueryReader::verify(inner_slice, compatible),
-            2 => ChannelUpdateQueryReader::verify(inner_slice, compatible),
+            0 => NodeAnnouncementReader::verify(inner_slice, compatible),
+            1 => ChannelAnnouncementReader::verify(inner_slice, compatible),
+            2 => ChannelUpdateReader::verify(inner_slice, compatible),
             _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
         }?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct BroadcastMessageQueryBuilder(pub(crate) BroadcastMessageQueryUnion);
-impl BroadcastMessageQueryBuilder {
+pub struct BroadcastMessageBuilder(pub(crate) BroadcastMessageUnion);
+impl BroadcastMessageBuilder {
     pub const ITEMS_COUNT: usize = 3;
     pub fn set<I>(mut self, v: I) -> Self
     where
-        I: ::core::convert::Into<BroadcastMessageQueryUnion>,
+        I: ::core::convert::Into<BroadcastMessageUnion>,
     {
         self.0 = v.into();
         self
     }
 }
-impl molecule::prelude::Builder for BroadcastMessageQueryBuilder {
-    type Entity = BroadcastMessageQuery;
-    const NAME: &'static str = "BroadcastMessageQueryBuilder";
+impl molecule::prelude::Builder for BroadcastMessageBuilder {
+    type Entity = BroadcastMessage;
+    const NAME: &'static str = "BroadcastMessageBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE + self.0.as_slice().len()
     }
@@ -13033,220 +15415,184 @@ impl molecule::prelude::Builder for BroadcastMessageQueryBuilder {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        BroadcastMessageQuery::new_unchecked(inner.into())
+        BroadcastMessage::new_unchecked(inner.into())
     }
 }
 #[derive(Debug, Clone)]
-pub enum BroadcastMessageQueryUnion {
-    NodeAnnouncementQuery(NodeAnnouncementQuery),
-    ChannelAnnouncementQuery(ChannelAnnouncementQuery),
-    ChannelUpdateQuery(ChannelUpdateQuery),
+pub enum BroadcastMessageUnion {
+    NodeAnnouncement(NodeAnnouncement),
+    ChannelAnnouncement(ChannelAnnouncement),
+    ChannelUpdate(ChannelUpdate),
 }
 #[derive(Debug, Clone, Copy)]
-pub enum BroadcastMessageQueryUnionReader<'r> {
-    NodeAnnouncementQuery(NodeAnnouncementQueryReader<'r>),
-    ChannelAnnouncementQuery(ChannelAnnouncementQueryReader<'r>),
-    ChannelUpdateQuery(ChannelUpdateQueryReader<'r>),
+pub enum BroadcastMessageUnionReader<'r> {
+    NodeAnnouncement(NodeAnnouncementReader<'r>),
+    ChannelAnnouncement(ChannelAnnouncementReader<'r>),
+    ChannelUpdate(ChannelUpdateReader<'r>),
 }
-impl ::core::default::Default for BroadcastMessageQueryUnion {
+impl ::core::default::Default for BroadcastMessageUnion {
     fn default() -> Self {
-        BroadcastMessageQueryUnion::NodeAnnouncementQuery(::core::default::Default::default())
+        BroadcastMessageUnion::NodeAnnouncement(::core::default::Default::default())
     }
 }
-impl ::core::fmt::Display for BroadcastMessageQueryUnion {
+impl ::core::fmt::Display for BroadcastMessageUnion {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         match self {
-            BroadcastMessageQueryUnion::NodeAnnouncementQuery(ref item) => {
-                write!(
-                    f,
-                    "{}::{}({})",
-                    Self::NAME,
-                    NodeAnnouncementQuery::NAME,
-                    item
-                )
+            BroadcastMessageUnion::NodeAnnouncement(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, NodeAnnouncement::NAME, item)
             }
-            BroadcastMessageQueryUnion::ChannelAnnouncementQuery(ref item) => {
-                write!(
-                    f,
-                    "{}::{}({})",
-                    Self::NAME,
-                    ChannelAnnouncementQuery::NAME,
-                    item
-                )
+            BroadcastMessageUnion::ChannelAnnouncement(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ChannelAnnouncement::NAME, item)
             }
-            BroadcastMessageQueryUnion::ChannelUpdateQuery(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, ChannelUpdateQuery::NAME, item)
+            BroadcastMessageUnion::ChannelUpdate(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ChannelUpdate::NAME, item)
             }
         }
     }
 }
-impl<'r> ::core::fmt::Display for BroadcastMessageQueryUnionReader<'r> {
+impl<'r> ::core::fmt::Display for BroadcastMessageUnionReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         match self {
-            BroadcastMessageQueryUnionReader::NodeAnnouncementQuery(ref item) => {
-                write!(
-                    f,
-                    "{}::{}({})",
-                    Self::NAME,
-                    NodeAnnouncementQuery::NAME,
-                    item
-                )
+            BroadcastMessageUnionReader::NodeAnnouncement(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, NodeAnnouncement::NAME, item)
             }
-            BroadcastMessageQueryUnionReader::ChannelAnnouncementQuery(ref item) => {
-                write!(
-                    f,
-                    "{}::{}({})",
-                    Self::NAME,
-                    ChannelAnnouncementQuery::NAME,
-                    item
-                )
+            BroadcastMessageUnionReader::ChannelAnnouncement(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ChannelAnnouncement::NAME, item)
             }
-            BroadcastMessageQueryUnionReader::ChannelUpdateQuery(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, ChannelUpdateQuery::NAME, item)
+            BroadcastMessageUnionReader::ChannelUpdate(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ChannelUpdate::NAME, item)
             }
         }
     }
 }
-impl BroadcastMessageQueryUnion {
+impl BroadcastMessageUnion {
     pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         match self {
-            BroadcastMessageQueryUnion::NodeAnnouncementQuery(ref item) => write!(f, "{}", item),
-            BroadcastMessageQueryUnion::ChannelAnnouncementQuery(ref item) => write!(f, "{}", item),
-            BroadcastMessageQueryUnion::ChannelUpdateQuery(ref item) => write!(f, "{}", item),
+            BroadcastMessageUnion::NodeAnnouncement(ref item) => write!(f, "{}", item),
+            BroadcastMessageUnion::ChannelAnnouncement(ref item) => write!(f, "{}", item),
+            BroadcastMessageUnion::ChannelUpdate(ref item) => write!(f, "{}", item),
         }
     }
 }
-impl<'r> BroadcastMessageQueryUnionReader<'r> {
+impl<'r> BroadcastMessageUnionReader<'r> {
     pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         match self {
-            BroadcastMessageQueryUnionReader::NodeAnnouncementQuery(ref item) => {
-                write!(f, "{}", item)
-            }
-            BroadcastMessageQueryUnionReader::ChannelAnnouncementQuery(ref item) => {
-                write!(f, "{}", item)
-            }
-            BroadcastMessageQueryUnionReader::ChannelUpdateQuery(ref item) => write!(f, "{}", item),
+            BroadcastMessageUnionReader::NodeAnnouncement(ref item) => write!(f, "{}", item),
+            BroadcastMessageUnionReader::ChannelAnnouncement(ref item) => write!(f, "{}", item),
+            BroadcastMessageUnionReader::ChannelUpdate(ref item) => write!(f, "{}", item),
         }
     }
 }
-impl ::core::convert::From<NodeAnnouncementQuery> for BroadcastMessageQueryUnion {
-    fn from(item: NodeAnnouncementQuery) -> Self {
-        BroadcastMessageQueryUnion::NodeAnnouncementQuery(item)
+impl ::core::convert::From<NodeAnnouncement> for BroadcastMessageUnion {
+    fn from(item: NodeAnnouncement) -> Self {
+        BroadcastMessageUnion::NodeAnnouncement(item)
     }
 }
-impl ::core::convert::From<ChannelAnnouncementQuery> for BroadcastMessageQueryUnion {
-    fn from(item: ChannelAnnouncementQuery) -> Self {
-        BroadcastMessageQueryUnion::ChannelAnnouncementQuery(item)
+impl ::core::convert::From<ChannelAnnouncement> for BroadcastMessageUnion {
+    fn from(item: ChannelAnnouncement) -> Self {
+        BroadcastMessageUnion::ChannelAnnouncement(item)
     }
 }
-impl ::core::convert::From<ChannelUpdateQuery> for BroadcastMessageQueryUnion {
-    fn from(item: ChannelUpdateQuery) -> Self {
-        BroadcastMessageQueryUnion::ChannelUpdateQuery(item)
+impl ::core::convert::From<ChannelUpdate> for BroadcastMessageUnion {
+    fn from(item: ChannelUpdate) -> Self {
+        BroadcastMessageUnion::ChannelUpdate(item)
     }
 }
-impl<'r> ::core::convert::From<NodeAnnouncementQueryReader<'r>>
-    for BroadcastMessageQueryUnionReader<'r>
-{
-    fn from(item: NodeAnnouncementQueryReader<'r>) -> Self {
-        BroadcastMessageQueryUnionReader::NodeAnnouncementQuery(item)
+impl<'r> ::core::convert::From<NodeAnnouncementReader<'r>> for BroadcastMessageUnionReader<'r> {
+    fn from(item: NodeAnnouncementReader<'r>) -> Self {
+        BroadcastMessageUnionReader::NodeAnnouncement(item)
     }
 }
-impl<'r> ::core::convert::From<ChannelAnnouncementQueryReader<'r>>
-    for BroadcastMessageQueryUnionReader<'r>
-{
-    fn from(item: ChannelAnnouncementQueryReader<'r>) -> Self {
-        BroadcastMessageQueryUnionReader::ChannelAnnouncementQuery(item)
+impl<'r> ::core::convert::From<ChannelAnnouncementReader<'r>> for BroadcastMessageUnionReader<'r> {
+    fn from(item: ChannelAnnouncementReader<'r>) -> Self {
+        BroadcastMessageUnionReader::ChannelAnnouncement(item)
     }
-}
-impl<'r> ::core::convert::From<ChannelUpdateQueryReader<'r>>
-    for BroadcastMessageQueryUnionReader<'r>
-{
-    fn from(item: ChannelUpdateQueryReader<'r>) -> Self {
-        BroadcastMessageQueryUnionReader::ChannelUpdateQuery(item)
+}
+impl<'r> ::core::convert::From<ChannelUpdateReader<'r>> for BroadcastMessageUnionReader<'r> {
+    fn from(item: ChannelUpdateReader<'r>) -> Self {
+        BroadcastMessageUnionReader::ChannelUpdate(item)
     }
 }
-impl BroadcastMessageQueryUnion {
-    pub const NAME: &'static str = "BroadcastMessageQueryUnion";
+impl BroadcastMessageUnion {
+    pub const NAME: &'static str = "BroadcastMessageUnion";
     pub fn as_bytes(&self) -> molecule::bytes::Bytes {
         match self {
-            BroadcastMessageQueryUnion::NodeAnnouncementQuery(item) => item.as_bytes(),
-            BroadcastMessageQueryUnion::ChannelAnnouncementQuery(item) => item.as_bytes(),
-            BroadcastMessageQueryUnion::ChannelUpdateQuery(item) => item.as_bytes(),
+            BroadcastMessageUnion::NodeAnnouncement(item) => item.as_bytes(),
+            BroadcastMessageUnion::ChannelAnnouncement(item) => item.as_bytes(),
+            BroadcastMessageUnion::ChannelUpdate(item) => item.as_bytes(),
         }
     }
     pub fn as_slice(&self) -> &[u8] {
         match self {
-            BroadcastMessageQueryUnion::NodeAnnouncementQuery(item) => item.as_slice(),
-            BroadcastMessageQueryUnion::ChannelAnnouncementQuery(item) => item.as_slice(),
-            BroadcastMessageQueryUnion::ChannelUpdateQuery(item) => item.as_slice(),
+            BroadcastMessageUnion::NodeAnnouncement(item) => item.as_slice(),
+            BroadcastMessageUnion::ChannelAnnouncement(item) => item.as_slice(),
+            BroadcastMessageUnion::ChannelUpdate(item) => item.as_slice(),
         }
     }
     pub fn item_id(&self) -> molecule::Number {
         match self {
-            BroadcastMessageQueryUnion::NodeAnnouncementQuery(_) => 0,
-            BroadcastMessageQueryUnion::ChannelAnnouncementQuery(_) => 1,
-            BroadcastMessageQueryUnion::ChannelUpdateQuery(_) => 2,
+            BroadcastMessageUnion::NodeAnnouncement(_) => 0,
+            BroadcastMessageUnion::ChannelAnnouncement(_) => 1,
+            BroadcastMessageUnion::ChannelUpdate(_) => 2,
         }
     }
     pub fn item_name(&self) -> &str {
         match self {
-            BroadcastMessageQueryUnion::NodeAnnouncementQuery(_) => "NodeAnnouncementQuery",
-            BroadcastMessageQueryUnion::ChannelAnnouncementQuery(_) => "ChannelAnnouncementQuery",
-            BroadcastMessageQueryUnion::ChannelUpdateQuery(_) => "ChannelUpdateQuery",
+            BroadcastMessageUnion::NodeAnnouncement(_) => "NodeAnnouncement",
+            BroadcastMessageUnion::ChannelAnnouncement(_) => "ChannelAnnouncement",
+            BroadcastMessageUnion::ChannelUpdate(_) => "ChannelUpdate",
         }
     }
-    pub fn as_reader<'r>(&'r self) -> BroadcastMessageQueryUnionReader<'r> {
+    pub fn as_reader<'r>(&'r self) -> BroadcastMessageUnionReader<'r> {
         match self {
-            BroadcastMessageQueryUnion::NodeAnnouncementQuery(item) => item.as_reader().into(),
-            BroadcastMessageQueryUnion::ChannelAnnouncementQuery(item) => item.as_reader().into(),
-            BroadcastMessageQueryUnion::ChannelUpdateQuery(item) => item.as_reader().into(),
+            BroadcastMessageUnion::NodeAnnouncement(item) => item.as_reader().into(),
+            BroadcastMessageUnion::ChannelAnnouncement(item) => item.as_reader().into(),
+            BroadcastMessageUnion::ChannelUpdate(item) => item.as_reader().into(),
         }
     }
 }
-impl<'r> BroadcastMessageQueryUnionReader<'r> {
-    pub const NAME: &'r str = "BroadcastMessageQueryUnionReader";
+impl<'r> BroadcastMessageUnionReader<'r> {
+    pub const NAME: &'r str = "BroadcastMessageUnionReader";
     pub fn as_slice(&self) -> &'r [u8] {
         match self {
-            BroadcastMessageQueryUnionReader::NodeAnnouncementQuery(item) => item.as_slice(),
-            BroadcastMessageQueryUnionReader::ChannelAnnouncementQuery(item) => item.as_slice(),
-            BroadcastMessageQueryUnionReader::ChannelUpdateQuery(item) => item.as_slice(),
+            BroadcastMessageUnionReader::NodeAnnouncement(item) => item.as_slice(),
+            BroadcastMessageUnionReader::ChannelAnnouncement(item) => item.as_slice(),
+            BroadcastMessageUnionReader::ChannelUpdate(item) => item.as_slice(),
         }
     }
     pub fn item_id(&self) -> molecule::Number {
         match self {
-            BroadcastMessageQueryUnionReader::NodeAnnouncementQuery(_) => 0,
-            BroadcastMessageQueryUnionReader::ChannelAnnouncementQuery(_) => 1,
-            BroadcastMessageQueryUnionReader::ChannelUpdateQuery(_) => 2,
+            BroadcastMessageUnionReader::NodeAnnouncement(_) => 0,
+            BroadcastMessageUnionReader::ChannelAnnouncement(_) => 1,
+            BroadcastMessageUnionReader::ChannelUpdate(_) => 2,
         }
     }
     pub fn item_name(&self) -> &str {
         match self {
-            BroadcastMessageQueryUnionReader::NodeAnnouncementQuery(_) => "NodeAnnouncementQuery",
-            BroadcastMessageQueryUnionReader::ChannelAnnouncementQuery(_) => {
-                "ChannelAnnouncementQuery"
-            }
-            BroadcastMessageQueryUnionReader::ChannelUpdateQuery(_) => "ChannelUpdateQuery",
+            BroadcastMessageUnionReader::NodeAnnouncement(_) => "NodeAnnouncement",
+            BroadcastMessageUnionReader::ChannelAnnouncement(_) => "ChannelAnnouncement",
+            BroadcastMessageUnionReader::ChannelUpdate(_) => "ChannelUpdate",
         }
     }
 }
-impl From<NodeAnnouncementQuery> for BroadcastMessageQuery {
-    fn from(value: NodeAnnouncementQuery) -> Self {
+impl From<NodeAnnouncement> for BroadcastMessage {
+    fn from(value: NodeAnnouncement) -> Self {
         Self::new_builder().set(value).build()
     }
 }
-impl From<ChannelAnnouncementQuery> for BroadcastMessageQuery {
-    fn from(value: ChannelAnnouncementQuery) -> Self {
+impl From<ChannelAnnouncement> for BroadcastMessage {
+    fn from(value: ChannelAnnouncement) -> Self {
         Self::new_builder().set(value).build()
     }
 }
-impl From<ChannelUpdateQuery> for BroadcastMessageQuery {
-    fn from(value: ChannelUpdateQuery) -> Self {
+impl From<ChannelUpdate> for BroadcastMessage {
+    fn from(value: ChannelUpdate) -> Self {
         Self::new_builder().set(value).build()
     }
 }
 #[derive(Clone)]
-pub struct BroadcastMessageQueries(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for BroadcastMessageQueries {
+pub struct BroadcastMessages(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BroadcastMessages {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -13255,12 +15601,12 @@ impl ::core::fmt::LowerHex for BroadcastMessageQueries {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for BroadcastMessageQueries {
+impl ::core::fmt::Debug for BroadcastMessages {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for BroadcastMessageQueries {
+impl ::core::fmt::Display for BroadcastMessages {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} [", Self::NAME)?;
         for i in 0..self.len() {
@@ -13273,13 +15619,13 @@ impl ::core::fmt::Display for BroadcastMessageQueries {
         write!(f, "]")
     }
 }
-impl ::core::default::Default for BroadcastMessageQueries {
+impl ::core::default::Default for BroadcastMessages {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        BroadcastMessageQueries::new_unchecked(v)
+        BroadcastMessages::new_unchecked(v)
     }
 }
-impl BroadcastMessageQueries {
+impl BroadcastMessages {
     const DEFAULT_VALUE: [u8; 4] = [4, 0, 0, 0];
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -13297,34 +15643,34 @@ impl BroadcastMessageQueries {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
-    pub fn get(&self, idx: usize) -> Option<BroadcastMessageQuery> {
+    pub fn get(&self, idx: usize) -> Option<BroadcastMessage> {
         if idx >= self.len() {
             None
         } else {
             Some(self.get_unchecked(idx))
         }
     }
-    pub fn get_unchecked(&self, idx: usize) -> BroadcastMessageQuery {
+    pub fn get_unchecked(&self, idx: usize) -> BroadcastMessage {
         let slice = self.as_slice();
         let start_idx = molecule::NUMBER_SIZE * (1 + idx);
         let start = molecule::unpack_number(&slice[start_idx..]) as usize;
         if idx == self.len() - 1 {
-            BroadcastMessageQuery::new_unchecked(self.0.slice(start..))
+            BroadcastMessage::new_unchecked(self.0.slice(start..))
         } else {
             let end_idx = start_idx + molecule::NUMBER_SIZE;
             let end = molecule::unpack_number(&slice[end_idx..]) as usize;
-            BroadcastMessageQuery::new_unchecked(self.0.slice(start..end))
+            BroadcastMessage::new_unchecked(self.0.slice(start..end))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> BroadcastMessageQueriesReader<'r> {
-        BroadcastMessageQueriesReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> BroadcastMessagesReader<'r> {
+        BroadcastMessagesReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for BroadcastMessageQueries {
-    type Builder = BroadcastMessageQueriesBuilder;
-    const NAME: &'static str = "BroadcastMessageQueries";
+impl molecule::prelude::Entity for BroadcastMessages {
+    type Builder = BroadcastMessagesBuilder;
+    const NAME: &'static str = "BroadcastMessages";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        BroadcastMessageQueries(data)
+        BroadcastMessages(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -13333,10 +15679,10 @@ impl molecule::prelude::Entity for BroadcastMessageQueries {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BroadcastMessageQueriesReader::from_slice(slice).map(|reader| reader.to_entity())
+        BroadcastMessagesReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BroadcastMessageQueriesReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        BroadcastMessagesReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -13346,8 +15692,8 @@ impl molecule::prelude::Entity for BroadcastMessageQueries {
     }
 }
 #[derive(Clone, Copy)]
-pub struct BroadcastMessageQueriesReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for BroadcastMessageQueriesReader<'r> {
+pub struct BroadcastMessagesReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BroadcastMessagesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -13356,12 +15702,12 @@ impl<'r> ::core::fmt::LowerHex for BroadcastMessageQueriesReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for BroadcastMessageQueriesReader<'r> {
+impl<'r> ::core::fmt::Debug for BroadcastMessagesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for BroadcastMessageQueriesReader<'r> {
+impl<'r> ::core::fmt::Display for BroadcastMessagesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} [", Self::NAME)?;
         for i in 0..self.len() {
@@ -13374,7 +15720,7 @@ impl<'r> ::core::fmt::Display for BroadcastMessageQueriesReader<'r> {
         write!(f, "]")
     }
 }
-impl<'r> BroadcastMessageQueriesReader<'r> {
+impl<'r> BroadcastMessagesReader<'r> {
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -13391,34 +15737,34 @@ impl<'r> BroadcastMessageQueriesReader<'r> {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
-    pub fn get(&self, idx: usize) -> Option<BroadcastMessageQueryReader<'r>> {
+    pub fn get(&self, idx: usize) -> Option<BroadcastMessageReader<'r>> {
         if idx >= self.len() {
             None
         } else {
             Some(self.get_unchecked(idx))
         }
     }
-    pub fn get_unchecked(&self, idx: usize) -> BroadcastMessageQueryReader<'r> {
+    pub fn get_unchecked(&self, idx: usize) -> BroadcastMessageReader<'r> {
         let slice = self.as_slice();
         let start_idx = molecule::NUMBER_SIZE * (1 + idx);
         let start = molecule::unpack_number(&slice[start_idx..]) as usize;
         if idx == self.len() - 1 {
-            BroadcastMessageQueryReader::new_unchecked(&self.as_slice()[start..])
+            BroadcastMessageReader::new_unchecked(&self.as_slice()[start..])
         } else {
             let end_idx = start_idx + molecule::NUMBER_SIZE;
             let end = molecule::unpack_number(&slice[end_idx..]) as usize;
-            BroadcastMessageQueryReader::new_unchecked(&self.as_slice()[start..end])
+            BroadcastMessageReader::new_unchecked(&self.as_slice()[start..end])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for BroadcastMessageQueriesReader<'r> {
-    type Entity = BroadcastMessageQueries;
-    const NAME: &'static str = "BroadcastMessageQueriesReader";
+impl<'r> molecule::prelude::Reader<'r> for BroadcastMessagesReader<'r> {
+    type Entity = BroadcastMessages;
+    const NAME: &'static str = "BroadcastMessagesReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        BroadcastMessageQueriesReader(slice)
+        BroadcastMessagesReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -13462,23 +15808,23 @@ impl<'r> molecule::prelude::Reader<'r> for BroadcastMessageQueriesReader<'r> {
         for pair in offsets.windows(2) {
             let start = pair[0];
             let end = pair[1];
-            BroadcastMessageQueryReader::verify(&slice[start..end], compatible)?;
+            BroadcastMessageReader::verify(&slice[start..end], compatible)?;
         }
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct BroadcastMessageQueriesBuilder(pub(crate) Vec<BroadcastMessageQuery>);
-impl BroadcastMessageQueriesBuilder {
-    pub fn set(mut self, v: Vec<BroadcastMessageQuery>) -> Self {
+pub struct BroadcastMessagesBuilder(pub(crate) Vec<BroadcastMessage>);
+impl BroadcastMessagesBuilder {
+    pub fn set(mut self, v: Vec<BroadcastMessage>) -> Self {
         self.0 = v;
         self
     }
-    pub fn push(mut self, v: BroadcastMessageQuery) -> Self {
+    pub fn push(mut self, v: BroadcastMessage) -> Self {
         self.0.push(v);
         self
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = BroadcastMessageQuery>>(
+    pub fn extend<T: ::core::iter::IntoIterator<Item = BroadcastMessage>>(
         mut self,
         iter: T,
     ) -> Self {
@@ -13487,19 +15833,15 @@ impl BroadcastMessageQueriesBuilder {
         }
         self
     }
-    pub fn replace(
-        &mut self,
-        index: usize,
-        v: BroadcastMessageQuery,
-    ) -> Option<BroadcastMessageQuery> {
+    pub fn replace(&mut self, index: usize, v: BroadcastMessage) -> Option<BroadcastMessage> {
         self.0
             .get_mut(index)
             .map(|item| ::core::mem::replace(item, v))
     }
 }
-impl molecule::prelude::Builder for BroadcastMessageQueriesBuilder {
-    type Entity = BroadcastMessageQueries;
-    const NAME: &'static str = "BroadcastMessageQueriesBuilder";
+impl molecule::prelude::Builder for BroadcastMessagesBuilder {
+    type Entity = BroadcastMessages;
+    const NAME: &'static str = "BroadcastMessagesBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (self.0.len() + 1)
             + self
@@ -13539,12 +15881,12 @@ impl molecule::prelude::Builder for BroadcastMessageQueriesBuilder {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        BroadcastMessageQueries::new_unchecked(inner.into())
+        BroadcastMessages::new_unchecked(inner.into())
     }
 }
-pub struct BroadcastMessageQueriesIterator(BroadcastMessageQueries, usize, usize);
-impl ::core::iter::Iterator for BroadcastMessageQueriesIterator {
-    type Item = BroadcastMessageQuery;
+pub struct BroadcastMessagesIterator(BroadcastMessages, usize, usize);
+impl ::core::iter::Iterator for BroadcastMessagesIterator {
+    type Item = BroadcastMessage;
     fn next(&mut self) -> Option<Self::Item> {
         if self.1 >= self.2 {
             None
@@ -13555,31 +15897,27 @@ impl ::core::iter::Iterator for BroadcastMessageQueriesIterator {
         }
     }
 }
-impl ::core::iter::ExactSizeIterator for BroadcastMessageQueriesIterator {
+impl ::core::iter::ExactSizeIterator for BroadcastMessagesIterator {
     fn len(&self) -> usize {
         self.2 - self.1
     }
 }
-impl ::core::iter::IntoIterator for BroadcastMessageQueries {
-    type Item = BroadcastMessageQuery;
-    type IntoIter = BroadcastMessageQueriesIterator;
+impl ::core::iter::IntoIterator for BroadcastMessages {
+    type Item = BroadcastMessage;
+    type IntoIter = BroadcastMessagesIterator;
     fn into_iter(self) -> Self::IntoIter {
         let len = self.len();
-        BroadcastMessageQueriesIterator(self, 0, len)
+        BroadcastMessagesIterator(self, 0, len)
     }
 }
-impl<'r> BroadcastMessageQueriesReader<'r> {
-    pub fn iter<'t>(&'t self) -> BroadcastMessageQueriesReaderIterator<'t, 'r> {
-        BroadcastMessageQueriesReaderIterator(&self, 0, self.len())
+impl<'r> BroadcastMessagesReader<'r> {
+    pub fn iter<'t>(&'t self) -> BroadcastMessagesReaderIterator<'t, 'r> {
+        BroadcastMessagesReaderIterator(&self, 0, self.len())
     }
 }
-pub struct BroadcastMessageQueriesReaderIterator<'t, 'r>(
-    &'t BroadcastMessageQueriesReader<'r>,
-    usize,
-    usize,
-);
-impl<'t: 'r, 'r> ::core::iter::Iterator for BroadcastMessageQueriesReaderIterator<'t, 'r> {
-    type Item = BroadcastMessageQueryReader<'t>;
+pub struct BroadcastMessagesReaderIterator<'t, 'r>(&'t BroadcastMessagesReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for BroadcastMessagesReaderIterator<'t, 'r> {
+    type Item = BroadcastMessageReader<'t>;
     fn next(&mut self) -> Option<Self::Item> {
         if self.1 >= self.2 {
             None
@@ -13590,19 +15928,19 @@ impl<'t: 'r, 'r> ::core::iter::Iterator for BroadcastMessageQueriesReaderIterato
         }
     }
 }
-impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for BroadcastMessageQueriesReaderIterator<'t, 'r> {
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for BroadcastMessagesReaderIterator<'t, 'r> {
     fn len(&self) -> usize {
         self.2 - self.1
     }
 }
-impl ::core::iter::FromIterator<BroadcastMessageQuery> for BroadcastMessageQueries {
-    fn from_iter<T: IntoIterator<Item = BroadcastMessageQuery>>(iter: T) -> Self {
+impl ::core::iter::FromIterator<BroadcastMessage> for BroadcastMessages {
+    fn from_iter<T: IntoIterator<Item = BroadcastMessage>>(iter: T) -> Self {
         Self::new_builder().extend(iter).build()
     }
 }
 #[derive(Clone)]
-pub struct BroadcastMessage(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for BroadcastMessage {
+pub struct GetBroadcastMessages(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for GetBroadcastMessages {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -13611,55 +15949,75 @@ impl ::core::fmt::LowerHex for BroadcastMessage {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for BroadcastMessage {
+impl ::core::fmt::Debug for GetBroadcastMessages {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for BroadcastMessage {
+impl ::core::fmt::Display for GetBroadcastMessages {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}(", Self::NAME)?;
-        self.to_enum().display_inner(f)?;
-        write!(f, ")")
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "queries", self.queries())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl ::core::default::Default for BroadcastMessage {
+impl ::core::default::Default for GetBroadcastMessages {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        BroadcastMessage::new_unchecked(v)
+        GetBroadcastMessages::new_unchecked(v)
     }
 }
-impl BroadcastMessage {
-    const DEFAULT_VALUE: [u8; 157] = [
-        0, 0, 0, 0, 153, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 44, 0, 0, 0, 52, 0, 0, 0, 85, 0, 0, 0,
-        117, 0, 0, 0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl GetBroadcastMessages {
+    const DEFAULT_VALUE: [u8; 24] = [
+        24, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
     ];
-    pub const ITEMS_COUNT: usize = 3;
-    pub fn item_id(&self) -> molecule::Number {
-        molecule::unpack_number(self.as_slice())
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn to_enum(&self) -> BroadcastMessageUnion {
-        let inner = self.0.slice(molecule::NUMBER_SIZE..);
-        match self.item_id() {
-            0 => NodeAnnouncement::new_unchecked(inner).into(),
-            1 => ChannelAnnouncement::new_unchecked(inner).into(),
-            2 => ChannelUpdate::new_unchecked(inner).into(),
-            _ => panic!("{}: invalid data", Self::NAME),
+    pub fn id(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn queries(&self) -> BroadcastMessageQueries {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            BroadcastMessageQueries::new_unchecked(self.0.slice(start..end))
+        } else {
+            BroadcastMessageQueries::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> BroadcastMessageReader<'r> {
-        BroadcastMessageReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> GetBroadcastMessagesReader<'r> {
+        GetBroadcastMessagesReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for BroadcastMessage {
-    type Builder = BroadcastMessageBuilder;
-    const NAME: &'static str = "BroadcastMessage";
+impl molecule::prelude::Entity for GetBroadcastMessages {
+    type Builder = GetBroadcastMessagesBuilder;
+    const NAME: &'static str = "GetBroadcastMessages";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        BroadcastMessage(data)
+        GetBroadcastMessages(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -13668,21 +16026,21 @@ impl molecule::prelude::Entity for BroadcastMessage {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BroadcastMessageReader::from_slice(slice).map(|reader| reader.to_entity())
+        GetBroadcastMessagesReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BroadcastMessageReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        GetBroadcastMessagesReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set(self.to_enum())
+        Self::new_builder().id(self.id()).queries(self.queries())
     }
 }
 #[derive(Clone, Copy)]
-pub struct BroadcastMessageReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for BroadcastMessageReader<'r> {
+pub struct GetBroadcastMessagesReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for GetBroadcastMessagesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -13691,41 +16049,66 @@ impl<'r> ::core::fmt::LowerHex for BroadcastMessageReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for BroadcastMessageReader<'r> {
+impl<'r> ::core::fmt::Debug for GetBroadcastMessagesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for BroadcastMessageReader<'r> {
+impl<'r> ::core::fmt::Display for GetBroadcastMessagesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}(", Self::NAME)?;
-        self.to_enum().display_inner(f)?;
-        write!(f, ")")
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "queries", self.queries())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl<'r> BroadcastMessageReader<'r> {
-    pub const ITEMS_COUNT: usize = 3;
-    pub fn item_id(&self) -> molecule::Number {
-        molecule::unpack_number(self.as_slice())
+impl<'r> GetBroadcastMessagesReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn to_enum(&self) -> BroadcastMessageUnionReader<'r> {
-        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
-        match self.item_id() {
-            0 => NodeAnnouncementReader::new_unchecked(inner).into(),
-            1 => ChannelAnnouncementReader::new_unchecked(inner).into(),
-            2 => ChannelUpdateReader::new_unchecked(inner).into(),
-            _ => panic!("{}: invalid data", Self::NAME),
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn id(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn queries(&self) -> BroadcastMessageQueriesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            BroadcastMessageQueriesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BroadcastMessageQueriesReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for BroadcastMessageReader<'r> {
-    type Entity = BroadcastMessage;
-    const NAME: &'static str = "BroadcastMessageReader";
+impl<'r> molecule::prelude::Reader<'r> for GetBroadcastMessagesReader<'r> {
+    type Entity = GetBroadcastMessages;
+    const NAME: &'static str = "GetBroadcastMessagesReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        BroadcastMessageReader(slice)
+        GetBroadcastMessagesReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -13736,221 +16119,349 @@ impl<'r> molecule::prelude::Reader<'r> for BroadcastMessageReader<'r> {
         if slice_len < molecule::NUMBER_SIZE {
             return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-        let item_id = molecule::unpack_number(slice);
-        let inner_slice = &slice[molecule::NUMBER_SIZE..];
-        match item_id {
-            0 => NodeAnnouncementReader::verify(inner_slice, compatible),
-            1 => ChannelAnnouncementReader::verify(inner_slice, compatible),
-            2 => ChannelUpdateReader::verify(inner_slice, compatible),
-            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
-        }?;
-        Ok(())
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BroadcastMessageQueriesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct GetBroadcastMessagesBuilder {
+    pub(crate) id: Uint64,
+    pub(crate) queries: BroadcastMessageQueries,
+}
+impl GetBroadcastMessagesBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn id(mut self, v: Uint64) -> Self {
+        self.id = v;
+        self
+    }
+    pub fn queries(mut self, v: BroadcastMessageQueries) -> Self {
+        self.queries = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for GetBroadcastMessagesBuilder {
+    type Entity = GetBroadcastMessages;
+    const NAME: &'static str = "GetBroadcastMessagesBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.id.as_slice().len()
+            + self.queries.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.queries.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.id.as_slice())?;
+        writer.write_all(self.queries.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        GetBroadcastMessages::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct GetBroadcastMessagesResult(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for GetBroadcastMessagesResult {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for GetBroadcastMessagesResult {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for GetBroadcastMessagesResult {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "messages", self.messages())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for GetBroadcastMessagesResult {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        GetBroadcastMessagesResult::new_unchecked(v)
+    }
+}
+impl GetBroadcastMessagesResult {
+    const DEFAULT_VALUE: [u8; 24] = [
+        24, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn id(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn messages(&self) -> BroadcastMessages {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            BroadcastMessages::new_unchecked(self.0.slice(start..end))
+        } else {
+            BroadcastMessages::new_unchecked(self.0.slice(start..))
+        }
     }
-}
-#[derive(Clone, Debug, Default)]
-pub struct BroadcastMessageBuilder(pub(crate) BroadcastMessageUnion);
-impl BroadcastMessageBuilder {
-    pub const ITEMS_COUNT: usize = 3;
-    pub fn set<I>(mut self, v: I) -> Self
-    where
-        I: ::core::convert::Into<BroadcastMessageUnion>,
-    {
-        self.0 = v.into();
-        self
+    pub fn as_reader<'r>(&'r self) -> GetBroadcastMessagesResultReader<'r> {
+        GetBroadcastMessagesResultReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Builder for BroadcastMessageBuilder {
-    type Entity = BroadcastMessage;
-    const NAME: &'static str = "BroadcastMessageBuilder";
-    fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + self.0.as_slice().len()
+impl molecule::prelude::Entity for GetBroadcastMessagesResult {
+    type Builder = GetBroadcastMessagesResultBuilder;
+    const NAME: &'static str = "GetBroadcastMessagesResult";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        GetBroadcastMessagesResult(data)
     }
-    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
-        writer.write_all(self.0.as_slice())
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        BroadcastMessage::new_unchecked(inner.into())
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
     }
-}
-#[derive(Debug, Clone)]
-pub enum BroadcastMessageUnion {
-    NodeAnnouncement(NodeAnnouncement),
-    ChannelAnnouncement(ChannelAnnouncement),
-    ChannelUpdate(ChannelUpdate),
-}
-#[derive(Debug, Clone, Copy)]
-pub enum BroadcastMessageUnionReader<'r> {
-    NodeAnnouncement(NodeAnnouncementReader<'r>),
-    ChannelAnnouncement(ChannelAnnouncementReader<'r>),
-    ChannelUpdate(ChannelUpdateReader<'r>),
-}
-impl ::core::default::Default for BroadcastMessageUnion {
-    fn default() -> Self {
-        BroadcastMessageUnion::NodeAnnouncement(::core::default::Default::default())
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        GetBroadcastMessagesResultReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        GetBroadcastMessagesResultReader::from_compatible_slice(slice)
+            .map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().id(self.id()).messages(self.messages())
     }
 }
-impl ::core::fmt::Display for BroadcastMessageUnion {
+#[derive(Clone, Copy)]
+pub struct GetBroadcastMessagesResultReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for GetBroadcastMessagesResultReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            BroadcastMessageUnion::NodeAnnouncement(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, NodeAnnouncement::NAME, item)
-            }
-            BroadcastMessageUnion::ChannelAnnouncement(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, ChannelAnnouncement::NAME, item)
-            }
-            BroadcastMessageUnion::ChannelUpdate(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, ChannelUpdate::NAME, item)
-            }
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
         }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Display for BroadcastMessageUnionReader<'r> {
+impl<'r> ::core::fmt::Debug for GetBroadcastMessagesResultReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            BroadcastMessageUnionReader::NodeAnnouncement(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, NodeAnnouncement::NAME, item)
-            }
-            BroadcastMessageUnionReader::ChannelAnnouncement(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, ChannelAnnouncement::NAME, item)
-            }
-            BroadcastMessageUnionReader::ChannelUpdate(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, ChannelUpdate::NAME, item)
-            }
-        }
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl BroadcastMessageUnion {
-    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            BroadcastMessageUnion::NodeAnnouncement(ref item) => write!(f, "{}", item),
-            BroadcastMessageUnion::ChannelAnnouncement(ref item) => write!(f, "{}", item),
-            BroadcastMessageUnion::ChannelUpdate(ref item) => write!(f, "{}", item),
+impl<'r> ::core::fmt::Display for GetBroadcastMessagesResultReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "messages", self.messages())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
+        write!(f, " }}")
     }
 }
-impl<'r> BroadcastMessageUnionReader<'r> {
-    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            BroadcastMessageUnionReader::NodeAnnouncement(ref item) => write!(f, "{}", item),
-            BroadcastMessageUnionReader::ChannelAnnouncement(ref item) => write!(f, "{}", item),
-            BroadcastMessageUnionReader::ChannelUpdate(ref item) => write!(f, "{}", item),
+impl<'r> GetBroadcastMessagesResultReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-}
-impl ::core::convert::From<NodeAnnouncement> for BroadcastMessageUnion {
-    fn from(item: NodeAnnouncement) -> Self {
-        BroadcastMessageUnion::NodeAnnouncement(item)
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-}
-impl ::core::convert::From<ChannelAnnouncement> for BroadcastMessageUnion {
-    fn from(item: ChannelAnnouncement) -> Self {
-        BroadcastMessageUnion::ChannelAnnouncement(item)
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-}
-impl ::core::convert::From<ChannelUpdate> for BroadcastMessageUnion {
-    fn from(item: ChannelUpdate) -> Self {
-        BroadcastMessageUnion::ChannelUpdate(item)
+    pub fn id(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-}
-impl<'r> ::core::convert::From<NodeAnnouncementReader<'r>> for BroadcastMessageUnionReader<'r> {
-    fn from(item: NodeAnnouncementReader<'r>) -> Self {
-        BroadcastMessageUnionReader::NodeAnnouncement(item)
+    pub fn messages(&self) -> BroadcastMessagesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            BroadcastMessagesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BroadcastMessagesReader::new_unchecked(&self.as_slice()[start..])
+        }
     }
 }
-impl<'r> ::core::convert::From<ChannelAnnouncementReader<'r>> for BroadcastMessageUnionReader<'r> {
-    fn from(item: ChannelAnnouncementReader<'r>) -> Self {
-        BroadcastMessageUnionReader::ChannelAnnouncement(item)
+impl<'r> molecule::prelude::Reader<'r> for GetBroadcastMessagesResultReader<'r> {
+    type Entity = GetBroadcastMessagesResult;
+    const NAME: &'static str = "GetBroadcastMessagesResultReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
-}
-impl<'r> ::core::convert::From<ChannelUpdateReader<'r>> for BroadcastMessageUnionReader<'r> {
-    fn from(item: ChannelUpdateReader<'r>) -> Self {
-        BroadcastMessageUnionReader::ChannelUpdate(item)
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        GetBroadcastMessagesResultReader(slice)
     }
-}
-impl BroadcastMessageUnion {
-    pub const NAME: &'static str = "BroadcastMessageUnion";
-    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
-        match self {
-            BroadcastMessageUnion::NodeAnnouncement(item) => item.as_bytes(),
-            BroadcastMessageUnion::ChannelAnnouncement(item) => item.as_bytes(),
-            BroadcastMessageUnion::ChannelUpdate(item) => item.as_bytes(),
-        }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
     }
-    pub fn as_slice(&self) -> &[u8] {
-        match self {
-            BroadcastMessageUnion::NodeAnnouncement(item) => item.as_slice(),
-            BroadcastMessageUnion::ChannelAnnouncement(item) => item.as_slice(),
-            BroadcastMessageUnion::ChannelUpdate(item) => item.as_slice(),
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-    }
-    pub fn item_id(&self) -> molecule::Number {
-        match self {
-            BroadcastMessageUnion::NodeAnnouncement(_) => 0,
-            BroadcastMessageUnion::ChannelAnnouncement(_) => 1,
-            BroadcastMessageUnion::ChannelUpdate(_) => 2,
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
-    }
-    pub fn item_name(&self) -> &str {
-        match self {
-            BroadcastMessageUnion::NodeAnnouncement(_) => "NodeAnnouncement",
-            BroadcastMessageUnion::ChannelAnnouncement(_) => "ChannelAnnouncement",
-            BroadcastMessageUnion::ChannelUpdate(_) => "ChannelUpdate",
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
         }
-    }
-    pub fn as_reader<'r>(&'r self) -> BroadcastMessageUnionReader<'r> {
-        match self {
-            BroadcastMessageUnion::NodeAnnouncement(item) => item.as_reader().into(),
-            BroadcastMessageUnion::ChannelAnnouncement(item) => item.as_reader().into(),
-            BroadcastMessageUnion::ChannelUpdate(item) => item.as_reader().into(),
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
         }
-    }
-}
-impl<'r> BroadcastMessageUnionReader<'r> {
-    pub const NAME: &'r str = "BroadcastMessageUnionReader";
-    pub fn as_slice(&self) -> &'r [u8] {
-        match self {
-            BroadcastMessageUnionReader::NodeAnnouncement(item) => item.as_slice(),
-            BroadcastMessageUnionReader::ChannelAnnouncement(item) => item.as_slice(),
-            BroadcastMessageUnionReader::ChannelUpdate(item) => item.as_slice(),
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
         }
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BroadcastMessagesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
     }
-    pub fn item_id(&self) -> molecule::Number {
-        match self {
-            BroadcastMessageUnionReader::NodeAnnouncement(_) => 0,
-            BroadcastMessageUnionReader::ChannelAnnouncement(_) => 1,
-            BroadcastMessageUnionReader::ChannelUpdate(_) => 2,
-        }
+}
+#[derive(Clone, Debug, Default)]
+pub struct GetBroadcastMessagesResultBuilder {
+    pub(crate) id: Uint64,
+    pub(crate) messages: BroadcastMessages,
+}
+impl GetBroadcastMessagesResultBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn id(mut self, v: Uint64) -> Self {
+        self.id = v;
+        self
     }
-    pub fn item_name(&self) -> &str {
-        match self {
-            BroadcastMessageUnionReader::NodeAnnouncement(_) => "NodeAnnouncement",
-            BroadcastMessageUnionReader::ChannelAnnouncement(_) => "ChannelAnnouncement",
-            BroadcastMessageUnionReader::ChannelUpdate(_) => "ChannelUpdate",
-        }
+    pub fn messages(mut self, v: BroadcastMessages) -> Self {
+        self.messages = v;
+        self
     }
 }
-impl From<NodeAnnouncement> for BroadcastMessage {
-    fn from(value: NodeAnnouncement) -> Self {
-        Self::new_builder().set(value).build()
+impl molecule::prelude::Builder for GetBroadcastMessagesResultBuilder {
+    type Entity = GetBroadcastMessagesResult;
+    const NAME: &'static str = "GetBroadcastMessagesResultBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.id.as_slice().len()
+            + self.messages.as_slice().len()
     }
-}
-impl From<ChannelAnnouncement> for BroadcastMessage {
-    fn from(value: ChannelAnnouncement) -> Self {
-        Self::new_builder().set(value).build()
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.messages.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.id.as_slice())?;
+        writer.write_all(self.messages.as_slice())?;
+        Ok(())
     }
-}
-impl From<ChannelUpdate> for BroadcastMessage {
-    fn from(value: ChannelUpdate) -> Self {
-        Self::new_builder().set(value).build()
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        GetBroadcastMessagesResult::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct BroadcastMessages(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for BroadcastMessages {
+pub struct QueryChannelsWithinBlockRange(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for QueryChannelsWithinBlockRange {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -13959,76 +16470,98 @@ impl ::core::fmt::LowerHex for BroadcastMessages {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for BroadcastMessages {
+impl ::core::fmt::Debug for QueryChannelsWithinBlockRange {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for BroadcastMessages {
+impl ::core::fmt::Display for QueryChannelsWithinBlockRange {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
+        write!(f, ", {}: {}", "start_block", self.start_block())?;
+        write!(f, ", {}: {}", "end_block", self.end_block())?;
+        write!(f, ", {}: {}", "count", self.count())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
-        write!(f, "]")
+        write!(f, " }}")
     }
 }
-impl ::core::default::Default for BroadcastMessages {
+impl ::core::default::Default for QueryChannelsWithinBlockRange {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        BroadcastMessages::new_unchecked(v)
+        QueryChannelsWithinBlockRange::new_unchecked(v)
     }
 }
-impl BroadcastMessages {
-    const DEFAULT_VALUE: [u8; 4] = [4, 0, 0, 0];
+impl QueryChannelsWithinBlockRange {
+    const DEFAULT_VALUE: [u8; 80] = [
+        80, 0, 0, 0, 24, 0, 0, 0, 32, 0, 0, 0, 64, 0, 0, 0, 72, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn item_count(&self) -> usize {
+    pub fn field_count(&self) -> usize {
         if self.total_size() == molecule::NUMBER_SIZE {
             0
         } else {
             (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn get(&self, idx: usize) -> Option<BroadcastMessage> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn id(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn get_unchecked(&self, idx: usize) -> BroadcastMessage {
+    pub fn chain_hash(&self) -> Byte32 {
         let slice = self.as_slice();
-        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
-        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
-        if idx == self.len() - 1 {
-            BroadcastMessage::new_unchecked(self.0.slice(start..))
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn start_block(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn end_block(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn count(&self) -> Uint64Opt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            Uint64Opt::new_unchecked(self.0.slice(start..end))
         } else {
-            let end_idx = start_idx + molecule::NUMBER_SIZE;
-            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
-            BroadcastMessage::new_unchecked(self.0.slice(start..end))
+            Uint64Opt::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> BroadcastMessagesReader<'r> {
-        BroadcastMessagesReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> QueryChannelsWithinBlockRangeReader<'r> {
+        QueryChannelsWithinBlockRangeReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for BroadcastMessages {
-    type Builder = BroadcastMessagesBuilder;
-    const NAME: &'static str = "BroadcastMessages";
+impl molecule::prelude::Entity for QueryChannelsWithinBlockRange {
+    type Builder = QueryChannelsWithinBlockRangeBuilder;
+    const NAME: &'static str = "QueryChannelsWithinBlockRange";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        BroadcastMessages(data)
+        QueryChannelsWithinBlockRange(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -14037,21 +16570,27 @@ impl molecule::prelude::Entity for BroadcastMessages {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BroadcastMessagesReader::from_slice(slice).map(|reader| reader.to_entity())
+        QueryChannelsWithinBlockRangeReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BroadcastMessagesReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        QueryChannelsWithinBlockRangeReader::from_compatible_slice(slice)
+            .map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().extend(self.into_iter())
+        Self::new_builder()
+            .id(self.id())
+            .chain_hash(self.chain_hash())
+            .start_block(self.start_block())
+            .end_block(self.end_block())
+            .count(self.count())
     }
 }
 #[derive(Clone, Copy)]
-pub struct BroadcastMessagesReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for BroadcastMessagesReader<'r> {
+pub struct QueryChannelsWithinBlockRangeReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for QueryChannelsWithinBlockRangeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -14060,69 +16599,87 @@ impl<'r> ::core::fmt::LowerHex for BroadcastMessagesReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for BroadcastMessagesReader<'r> {
+impl<'r> ::core::fmt::Debug for QueryChannelsWithinBlockRangeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for BroadcastMessagesReader<'r> {
+impl<'r> ::core::fmt::Display for QueryChannelsWithinBlockRangeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
+        write!(f, ", {}: {}", "start_block", self.start_block())?;
+        write!(f, ", {}: {}", "end_block", self.end_block())?;
+        write!(f, ", {}: {}", "count", self.count())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
-        write!(f, "]")
+        write!(f, " }}")
     }
 }
-impl<'r> BroadcastMessagesReader<'r> {
+impl<'r> QueryChannelsWithinBlockRangeReader<'r> {
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn item_count(&self) -> usize {
+    pub fn field_count(&self) -> usize {
         if self.total_size() == molecule::NUMBER_SIZE {
             0
         } else {
             (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn get(&self, idx: usize) -> Option<BroadcastMessageReader<'r>> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn id(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn get_unchecked(&self, idx: usize) -> BroadcastMessageReader<'r> {
+    pub fn chain_hash(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
-        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
-        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
-        if idx == self.len() - 1 {
-            BroadcastMessageReader::new_unchecked(&self.as_slice()[start..])
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn start_block(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn end_block(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn count(&self) -> Uint64OptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            Uint64OptReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            let end_idx = start_idx + molecule::NUMBER_SIZE;
-            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
-            BroadcastMessageReader::new_unchecked(&self.as_slice()[start..end])
+            Uint64OptReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for BroadcastMessagesReader<'r> {
-    type Entity = BroadcastMessages;
-    const NAME: &'static str = "BroadcastMessagesReader";
+impl<'r> molecule::prelude::Reader<'r> for QueryChannelsWithinBlockRangeReader<'r> {
+    type Entity = QueryChannelsWithinBlockRange;
+    const NAME: &'static str = "QueryChannelsWithinBlockRangeReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        BroadcastMessagesReader(slice)
+        QueryChannelsWithinBlockRangeReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -14137,16 +16694,8 @@ impl<'r> molecule::prelude::Reader<'r> for BroadcastMessagesReader<'r> {
         if slice_len != total_size {
             return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
-        if slice_len == molecule::NUMBER_SIZE {
-            return Ok(());
-        }
         if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(
-                Self,
-                TotalSizeNotMatch,
-                molecule::NUMBER_SIZE * 2,
-                slice_len
-            );
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
         }
         let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
         if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
@@ -14155,6 +16704,12 @@ impl<'r> molecule::prelude::Reader<'r> for BroadcastMessagesReader<'r> {
         if slice_len < offset_first {
             return ve!(Self, HeaderIsBroken, offset_first, slice_len);
         }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
         let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
             .chunks_exact(molecule::NUMBER_SIZE)
             .map(|x| molecule::unpack_number(x) as usize)
@@ -14163,142 +16718,90 @@ impl<'r> molecule::prelude::Reader<'r> for BroadcastMessagesReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        for pair in offsets.windows(2) {
-            let start = pair[0];
-            let end = pair[1];
-            BroadcastMessageReader::verify(&slice[start..end], compatible)?;
-        }
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Uint64OptReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct BroadcastMessagesBuilder(pub(crate) Vec<BroadcastMessage>);
-impl BroadcastMessagesBuilder {
-    pub fn set(mut self, v: Vec<BroadcastMessage>) -> Self {
-        self.0 = v;
+pub struct QueryChannelsWithinBlockRangeBuilder {
+    pub(crate) id: Uint64,
+    pub(crate) chain_hash: Byte32,
+    pub(crate) start_block: Uint64,
+    pub(crate) end_block: Uint64,
+    pub(crate) count: Uint64Opt,
+}
+impl QueryChannelsWithinBlockRangeBuilder {
+    pub const FIELD_COUNT: usize = 5;
+    pub fn id(mut self, v: Uint64) -> Self {
+        self.id = v;
         self
     }
-    pub fn push(mut self, v: BroadcastMessage) -> Self {
-        self.0.push(v);
+    pub fn chain_hash(mut self, v: Byte32) -> Self {
+        self.chain_hash = v;
         self
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = BroadcastMessage>>(
-        mut self,
-        iter: T,
-    ) -> Self {
-        for elem in iter {
-            self.0.push(elem);
-        }
+    pub fn start_block(mut self, v: Uint64) -> Self {
+        self.start_block = v;
         self
     }
-    pub fn replace(&mut self, index: usize, v: BroadcastMessage) -> Option<BroadcastMessage> {
-        self.0
-            .get_mut(index)
-            .map(|item| ::core::mem::replace(item, v))
+    pub fn end_block(mut self, v: Uint64) -> Self {
+        self.end_block = v;
+        self
+    }
+    pub fn count(mut self, v: Uint64Opt) -> Self {
+        self.count = v;
+        self
     }
 }
-impl molecule::prelude::Builder for BroadcastMessagesBuilder {
-    type Entity = BroadcastMessages;
-    const NAME: &'static str = "BroadcastMessagesBuilder";
+impl molecule::prelude::Builder for QueryChannelsWithinBlockRangeBuilder {
+    type Entity = QueryChannelsWithinBlockRange;
+    const NAME: &'static str = "QueryChannelsWithinBlockRangeBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (self.0.len() + 1)
-            + self
-                .0
-                .iter()
-                .map(|inner| inner.as_slice().len())
-                .sum::<usize>()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.id.as_slice().len()
+            + self.chain_hash.as_slice().len()
+            + self.start_block.as_slice().len()
+            + self.end_block.as_slice().len()
+            + self.count.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let item_count = self.0.len();
-        if item_count == 0 {
-            writer.write_all(&molecule::pack_number(
-                molecule::NUMBER_SIZE as molecule::Number,
-            ))?;
-        } else {
-            let (total_size, offsets) = self.0.iter().fold(
-                (
-                    molecule::NUMBER_SIZE * (item_count + 1),
-                    Vec::with_capacity(item_count),
-                ),
-                |(start, mut offsets), inner| {
-                    offsets.push(start);
-                    (start + inner.as_slice().len(), offsets)
-                },
-            );
-            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-            for offset in offsets.into_iter() {
-                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-            }
-            for inner in self.0.iter() {
-                writer.write_all(inner.as_slice())?;
-            }
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.chain_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.start_block.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.end_block.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.count.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
+        writer.write_all(self.id.as_slice())?;
+        writer.write_all(self.chain_hash.as_slice())?;
+        writer.write_all(self.start_block.as_slice())?;
+        writer.write_all(self.end_block.as_slice())?;
+        writer.write_all(self.count.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        BroadcastMessages::new_unchecked(inner.into())
-    }
-}
-pub struct BroadcastMessagesIterator(BroadcastMessages, usize, usize);
-impl ::core::iter::Iterator for BroadcastMessagesIterator {
-    type Item = BroadcastMessage;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl ::core::iter::ExactSizeIterator for BroadcastMessagesIterator {
-    fn len(&self) -> usize {
-        self.2 - self.1
-    }
-}
-impl ::core::iter::IntoIterator for BroadcastMessages {
-    type Item = BroadcastMessage;
-    type IntoIter = BroadcastMessagesIterator;
-    fn into_iter(self) -> Self::IntoIter {
-        let len = self.len();
-        BroadcastMessagesIterator(self, 0, len)
-    }
-}
-impl<'r> BroadcastMessagesReader<'r> {
-    pub fn iter<'t>(&'t self) -> BroadcastMessagesReaderIterator<'t, 'r> {
-        BroadcastMessagesReaderIterator(&self, 0, self.len())
-    }
-}
-pub struct BroadcastMessagesReaderIterator<'t, 'r>(&'t BroadcastMessagesReader<'r>, usize, usize);
-impl<'t: 'r, 'r> ::core::iter::Iterator for BroadcastMessagesReaderIterator<'t, 'r> {
-    type Item = BroadcastMessageReader<'t>;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for BroadcastMessagesReaderIterator<'t, 'r> {
-    fn len(&self) -> usize {
-        self.2 - self.1
-    }
-}
-impl ::core::iter::FromIterator<BroadcastMessage> for BroadcastMessages {
-    fn from_iter<T: IntoIterator<Item = BroadcastMessage>>(iter: T) -> Self {
-        Self::new_builder().extend(iter).build()
+        QueryChannelsWithinBlockRange::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct GetBroadcastMessages(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for GetBroadcastMessages {
+pub struct OutPoints(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for OutPoints {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -14307,75 +16810,66 @@ impl ::core::fmt::LowerHex for GetBroadcastMessages {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for GetBroadcastMessages {
+impl ::core::fmt::Debug for OutPoints {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for GetBroadcastMessages {
+impl ::core::fmt::Display for OutPoints {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "id", self.id())?;
-        write!(f, ", {}: {}", "queries", self.queries())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
         }
-        write!(f, " }}")
+        write!(f, "]")
     }
 }
-impl ::core::default::Default for GetBroadcastMessages {
+impl ::core::default::Default for OutPoints {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        GetBroadcastMessages::new_unchecked(v)
+        OutPoints::new_unchecked(v)
     }
 }
-impl GetBroadcastMessages {
-    const DEFAULT_VALUE: [u8; 24] = [
-        24, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
-    ];
-    pub const FIELD_COUNT: usize = 2;
+impl OutPoints {
+    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
+    pub const ITEM_SIZE: usize = 36;
     pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn id(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn queries(&self) -> BroadcastMessageQueries {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            BroadcastMessageQueries::new_unchecked(self.0.slice(start..end))
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<OutPoint> {
+        if idx >= self.len() {
+            None
         } else {
-            BroadcastMessageQueries::new_unchecked(self.0.slice(start..))
+            Some(self.get_unchecked(idx))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> GetBroadcastMessagesReader<'r> {
-        GetBroadcastMessagesReader::new_unchecked(self.as_slice())
+    pub fn get_unchecked(&self, idx: usize) -> OutPoint {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        OutPoint::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn as_reader<'r>(&'r self) -> OutPointsReader<'r> {
+        OutPointsReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for GetBroadcastMessages {
-    type Builder = GetBroadcastMessagesBuilder;
-    const NAME: &'static str = "GetBroadcastMessages";
+impl molecule::prelude::Entity for OutPoints {
+    type Builder = OutPointsBuilder;
+    const NAME: &'static str = "OutPoints";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        GetBroadcastMessages(data)
+        OutPoints(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -14384,21 +16878,21 @@ impl molecule::prelude::Entity for GetBroadcastMessages {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        GetBroadcastMessagesReader::from_slice(slice).map(|reader| reader.to_entity())
+        OutPointsReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        GetBroadcastMessagesReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        OutPointsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().id(self.id()).queries(self.queries())
+        Self::new_builder().extend(self.into_iter())
     }
 }
 #[derive(Clone, Copy)]
-pub struct GetBroadcastMessagesReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for GetBroadcastMessagesReader<'r> {
+pub struct OutPointsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for OutPointsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -14407,158 +16901,184 @@ impl<'r> ::core::fmt::LowerHex for GetBroadcastMessagesReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for GetBroadcastMessagesReader<'r> {
+impl<'r> ::core::fmt::Debug for OutPointsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for GetBroadcastMessagesReader<'r> {
+impl<'r> ::core::fmt::Display for OutPointsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "id", self.id())?;
-        write!(f, ", {}: {}", "queries", self.queries())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
         }
-        write!(f, " }}")
+        write!(f, "]")
     }
 }
-impl<'r> GetBroadcastMessagesReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
+impl<'r> OutPointsReader<'r> {
+    pub const ITEM_SIZE: usize = 36;
     pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn id(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn queries(&self) -> BroadcastMessageQueriesReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            BroadcastMessageQueriesReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn get(&self, idx: usize) -> Option<OutPointReader<'r>> {
+        if idx >= self.len() {
+            None
         } else {
-            BroadcastMessageQueriesReader::new_unchecked(&self.as_slice()[start..])
+            Some(self.get_unchecked(idx))
         }
     }
+    pub fn get_unchecked(&self, idx: usize) -> OutPointReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        OutPointReader::new_unchecked(&self.as_slice()[start..end])
+    }
 }
-impl<'r> molecule::prelude::Reader<'r> for GetBroadcastMessagesReader<'r> {
-    type Entity = GetBroadcastMessages;
-    const NAME: &'static str = "GetBroadcastMessagesReader";
+impl<'r> molecule::prelude::Reader<'r> for OutPointsReader<'r> {
+    type Entity = OutPoints;
+    const NAME: &'static str = "OutPointsReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        GetBroadcastMessagesReader(slice)
+        OutPointsReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
         if slice_len < molecule::NUMBER_SIZE {
             return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-        let total_size = molecule::unpack_number(slice) as usize;
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
         if slice_len != total_size {
             return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        if slice_len < offset_first {
-            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
-        }
-        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
-            .chunks_exact(molecule::NUMBER_SIZE)
-            .map(|x| molecule::unpack_number(x) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        BroadcastMessageQueriesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct GetBroadcastMessagesBuilder {
-    pub(crate) id: Uint64,
-    pub(crate) queries: BroadcastMessageQueries,
-}
-impl GetBroadcastMessagesBuilder {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn id(mut self, v: Uint64) -> Self {
-        self.id = v;
+pub struct OutPointsBuilder(pub(crate) Vec<OutPoint>);
+impl OutPointsBuilder {
+    pub const ITEM_SIZE: usize = 36;
+    pub fn set(mut self, v: Vec<OutPoint>) -> Self {
+        self.0 = v;
         self
     }
-    pub fn queries(mut self, v: BroadcastMessageQueries) -> Self {
-        self.queries = v;
+    pub fn push(mut self, v: OutPoint) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = OutPoint>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
         self
     }
+    pub fn replace(&mut self, index: usize, v: OutPoint) -> Option<OutPoint> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
 }
-impl molecule::prelude::Builder for GetBroadcastMessagesBuilder {
-    type Entity = GetBroadcastMessages;
-    const NAME: &'static str = "GetBroadcastMessagesBuilder";
+impl molecule::prelude::Builder for OutPointsBuilder {
+    type Entity = OutPoints;
+    const NAME: &'static str = "OutPointsBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.id.as_slice().len()
-            + self.queries.as_slice().len()
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.id.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.queries.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
         }
-        writer.write_all(self.id.as_slice())?;
-        writer.write_all(self.queries.as_slice())?;
         Ok(())
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        GetBroadcastMessages::new_unchecked(inner.into())
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        OutPoints::new_unchecked(inner.into())
+    }
+}
+pub struct OutPointsIterator(OutPoints, usize, usize);
+impl ::core::iter::Iterator for OutPointsIterator {
+    type Item = OutPoint;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for OutPointsIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for OutPoints {
+    type Item = OutPoint;
+    type IntoIter = OutPointsIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        OutPointsIterator(self, 0, len)
+    }
+}
+impl<'r> OutPointsReader<'r> {
+    pub fn iter<'t>(&'t self) -> OutPointsReaderIterator<'t, 'r> {
+        OutPointsReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct OutPointsReaderIterator<'t, 'r>(&'t OutPointsReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for OutPointsReaderIterator<'t, 'r> {
+    type Item = OutPointReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for OutPointsReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::FromIterator<OutPoint> for OutPoints {
+    fn from_iter<T: IntoIterator<Item = OutPoint>>(iter: T) -> Self {
+        Self::new_builder().extend(iter).build()
     }
 }
 #[derive(Clone)]
-pub struct GetBroadcastMessagesResult(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for GetBroadcastMessagesResult {
+pub struct QueryChannelsWithinBlockRangeResult(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for QueryChannelsWithinBlockRangeResult {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -14567,16 +17087,18 @@ impl ::core::fmt::LowerHex for GetBroadcastMessagesResult {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for GetBroadcastMessagesResult {
+impl ::core::fmt::Debug for QueryChannelsWithinBlockRangeResult {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for GetBroadcastMessagesResult {
+impl ::core::fmt::Display for QueryChannelsWithinBlockRangeResult {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "id", self.id())?;
-        write!(f, ", {}: {}", "messages", self.messages())?;
+        write!(f, ", {}: {}", "next_block", self.next_block())?;
+        write!(f, ", {}: {}", "is_finished", self.is_finished())?;
+        write!(f, ", {}: {}", "channels", self.channels())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -14584,17 +17106,18 @@ impl ::core::fmt::Display for GetBroadcastMessagesResult {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for GetBroadcastMessagesResult {
+impl ::core::default::Default for QueryChannelsWithinBlockRangeResult {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        GetBroadcastMessagesResult::new_unchecked(v)
+        QueryChannelsWithinBlockRangeResult::new_unchecked(v)
     }
 }
-impl GetBroadcastMessagesResult {
-    const DEFAULT_VALUE: [u8; 24] = [
-        24, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+impl QueryChannelsWithinBlockRangeResult {
+    const DEFAULT_VALUE: [u8; 41] = [
+        41, 0, 0, 0, 20, 0, 0, 0, 28, 0, 0, 0, 36, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -14617,25 +17140,37 @@ impl GetBroadcastMessagesResult {
         let end = molecule::unpack_number(&slice[8..]) as usize;
         Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn messages(&self) -> BroadcastMessages {
+    pub fn next_block(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn is_finished(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn channels(&self) -> OutPoints {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            BroadcastMessages::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            OutPoints::new_unchecked(self.0.slice(start..end))
         } else {
-            BroadcastMessages::new_unchecked(self.0.slice(start..))
+            OutPoints::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> GetBroadcastMessagesResultReader<'r> {
-        GetBroadcastMessagesResultReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> QueryChannelsWithinBlockRangeResultReader<'r> {
+        QueryChannelsWithinBlockRangeResultReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for GetBroadcastMessagesResult {
-    type Builder = GetBroadcastMessagesResultBuilder;
-    const NAME: &'static str = "GetBroadcastMessagesResult";
+impl molecule::prelude::Entity for QueryChannelsWithinBlockRangeResult {
+    type Builder = QueryChannelsWithinBlockRangeResultBuilder;
+    const NAME: &'static str = "QueryChannelsWithinBlockRangeResult";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        GetBroadcastMessagesResult(data)
+        QueryChannelsWithinBlockRangeResult(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -14644,22 +17179,27 @@ impl molecule::prelude::Entity for GetBroadcastMessagesResult {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        GetBroadcastMessagesResultReader::from_slice(slice).map(|reader| reader.to_entity())
+        QueryChannelsWithinBlockRangeResultReader::from_slice(slice)
+            .map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        GetBroadcastMessagesResultReader::from_compatible_slice(slice)
+        QueryChannelsWithinBlockRangeResultReader::from_compatible_slice(slice)
             .map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().id(self.id()).messages(self.messages())
+        Self::new_builder()
+            .id(self.id())
+            .next_block(self.next_block())
+            .is_finished(self.is_finished())
+            .channels(self.channels())
     }
 }
 #[derive(Clone, Copy)]
-pub struct GetBroadcastMessagesResultReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for GetBroadcastMessagesResultReader<'r> {
+pub struct QueryChannelsWithinBlockRangeResultReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for QueryChannelsWithinBlockRangeResultReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -14668,16 +17208,18 @@ impl<'r> ::core::fmt::LowerHex for GetBroadcastMessagesResultReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for GetBroadcastMessagesResultReader<'r> {
+impl<'r> ::core::fmt::Debug for QueryChannelsWithinBlockRangeResultReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for GetBroadcastMessagesResultReader<'r> {
+impl<'r> ::core::fmt::Display for QueryChannelsWithinBlockRangeResultReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "id", self.id())?;
-        write!(f, ", {}: {}", "messages", self.messages())?;
+        write!(f, ", {}: {}", "next_block", self.next_block())?;
+        write!(f, ", {}: {}", "is_finished", self.is_finished())?;
+        write!(f, ", {}: {}", "channels", self.channels())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -14685,8 +17227,8 @@ impl<'r> ::core::fmt::Display for GetBroadcastMessagesResultReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> GetBroadcastMessagesResultReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
+impl<'r> QueryChannelsWithinBlockRangeResultReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -14709,25 +17251,37 @@ impl<'r> GetBroadcastMessagesResultReader<'r> {
         let end = molecule::unpack_number(&slice[8..]) as usize;
         Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn messages(&self) -> BroadcastMessagesReader<'r> {
+    pub fn next_block(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn is_finished(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn channels(&self) -> OutPointsReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            BroadcastMessagesReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            OutPointsReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            BroadcastMessagesReader::new_unchecked(&self.as_slice()[start..])
+            OutPointsReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for GetBroadcastMessagesResultReader<'r> {
-    type Entity = GetBroadcastMessagesResult;
-    const NAME: &'static str = "GetBroadcastMessagesResultReader";
+impl<'r> molecule::prelude::Reader<'r> for QueryChannelsWithinBlockRangeResultReader<'r> {
+    type Entity = QueryChannelsWithinBlockRangeResult;
+    const NAME: &'static str = "QueryChannelsWithinBlockRangeResultReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        GetBroadcastMessagesResultReader(slice)
+        QueryChannelsWithinBlockRangeResultReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -14767,33 +17321,47 @@ impl<'r> molecule::prelude::Reader<'r> for GetBroadcastMessagesResultReader<'r>
             return ve!(Self, OffsetsNotMatch);
         }
         Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        BroadcastMessagesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        ByteReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        OutPointsReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct GetBroadcastMessagesResultBuilder {
+pub struct QueryChannelsWithinBlockRangeResultBuilder {
     pub(crate) id: Uint64,
-    pub(crate) messages: BroadcastMessages,
+    pub(crate) next_block: Uint64,
+    pub(crate) is_finished: Byte,
+    pub(crate) channels: OutPoints,
 }
-impl GetBroadcastMessagesResultBuilder {
-    pub const FIELD_COUNT: usize = 2;
+impl QueryChannelsWithinBlockRangeResultBuilder {
+    pub const FIELD_COUNT: usize = 4;
     pub fn id(mut self, v: Uint64) -> Self {
         self.id = v;
         self
     }
-    pub fn messages(mut self, v: BroadcastMessages) -> Self {
-        self.messages = v;
+    pub fn next_block(mut self, v: Uint64) -> Self {
+        self.next_block = v;
+        self
+    }
+    pub fn is_finished(mut self, v: Byte) -> Self {
+        self.is_finished = v;
+        self
+    }
+    pub fn channels(mut self, v: OutPoints) -> Self {
+        self.channels = v;
         self
     }
 }
-impl molecule::prelude::Builder for GetBroadcastMessagesResultBuilder {
-    type Entity = GetBroadcastMessagesResult;
-    const NAME: &'static str = "GetBroadcastMessagesResultBuilder";
+impl molecule::prelude::Builder for QueryChannelsWithinBlockRangeResultBuilder {
+    type Entity = QueryChannelsWithinBlockRangeResult;
+    const NAME: &'static str = "QueryChannelsWithinBlockRangeResultBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.id.as_slice().len()
-            + self.messages.as_slice().len()
+            + self.next_block.as_slice().len()
+            + self.is_finished.as_slice().len()
+            + self.channels.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -14801,25 +17369,31 @@ impl molecule::prelude::Builder for GetBroadcastMessagesResultBuilder {
         offsets.push(total_size);
         total_size += self.id.as_slice().len();
         offsets.push(total_size);
-        total_size += self.messages.as_slice().len();
+        total_size += self.next_block.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.is_finished.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.channels.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
         writer.write_all(self.id.as_slice())?;
-        writer.write_all(self.messages.as_slice())?;
+        writer.write_all(self.next_block.as_slice())?;
+        writer.write_all(self.is_finished.as_slice())?;
+        writer.write_all(self.channels.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        GetBroadcastMessagesResult::new_unchecked(inner.into())
+        QueryChannelsWithinBlockRangeResult::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct QueryChannelsWithinBlockRange(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for QueryChannelsWithinBlockRange {
+pub struct QueryBroadcastMessagesWithinTimeRange(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for QueryBroadcastMessagesWithinTimeRange {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -14828,18 +17402,18 @@ impl ::core::fmt::LowerHex for QueryChannelsWithinBlockRange {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for QueryChannelsWithinBlockRange {
+impl ::core::fmt::Debug for QueryBroadcastMessagesWithinTimeRange {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for QueryChannelsWithinBlockRange {
+impl ::core::fmt::Display for QueryBroadcastMessagesWithinTimeRange {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "id", self.id())?;
         write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
-        write!(f, ", {}: {}", "start_block", self.start_block())?;
-        write!(f, ", {}: {}", "end_block", self.end_block())?;
+        write!(f, ", {}: {}", "start_time", self.start_time())?;
+        write!(f, ", {}: {}", "end_time", self.end_time())?;
         write!(f, ", {}: {}", "count", self.count())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
@@ -14848,17 +17422,17 @@ impl ::core::fmt::Display for QueryChannelsWithinBlockRange {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for QueryChannelsWithinBlockRange {
+impl ::core::default::Default for QueryBroadcastMessagesWithinTimeRange {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        QueryChannelsWithinBlockRange::new_unchecked(v)
+        QueryBroadcastMessagesWithinTimeRange::new_unchecked(v)
     }
 }
-impl QueryChannelsWithinBlockRange {
-    const DEFAULT_VALUE: [u8; 80] = [
-        80, 0, 0, 0, 24, 0, 0, 0, 32, 0, 0, 0, 64, 0, 0, 0, 72, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0,
+impl QueryBroadcastMessagesWithinTimeRange {
+    const DEFAULT_VALUE: [u8; 88] = [
+        88, 0, 0, 0, 24, 0, 0, 0, 32, 0, 0, 0, 64, 0, 0, 0, 72, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
     pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
@@ -14889,37 +17463,37 @@ impl QueryChannelsWithinBlockRange {
         let end = molecule::unpack_number(&slice[12..]) as usize;
         Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn start_block(&self) -> Uint64 {
+    pub fn start_time(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
         Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn end_block(&self) -> Uint64 {
+    pub fn end_time(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
         let end = molecule::unpack_number(&slice[20..]) as usize;
         Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn count(&self) -> Uint64Opt {
+    pub fn count(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[24..]) as usize;
-            Uint64Opt::new_unchecked(self.0.slice(start..end))
+            Uint64::new_unchecked(self.0.slice(start..end))
         } else {
-            Uint64Opt::new_unchecked(self.0.slice(start..))
+            Uint64::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> QueryChannelsWithinBlockRangeReader<'r> {
-        QueryChannelsWithinBlockRangeReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> QueryBroadcastMessagesWithinTimeRangeReader<'r> {
+        QueryBroadcastMessagesWithinTimeRangeReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for QueryChannelsWithinBlockRange {
-    type Builder = QueryChannelsWithinBlockRangeBuilder;
-    const NAME: &'static str = "QueryChannelsWithinBlockRange";
+impl molecule::prelude::Entity for QueryBroadcastMessagesWithinTimeRange {
+    type Builder = QueryBroadcastMessagesWithinTimeRangeBuilder;
+    const NAME: &'static str = "QueryBroadcastMessagesWithinTimeRange";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        QueryChannelsWithinBlockRange(data)
+        QueryBroadcastMessagesWithinTimeRange(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -14928,10 +17502,11 @@ impl molecule::prelude::Entity for QueryChannelsWithinBlockRange {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        QueryChannelsWithinBlockRangeReader::from_slice(slice).map(|reader| reader.to_entity())
+        QueryBroadcastMessagesWithinTimeRangeReader::from_slice(slice)
+            .map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        QueryChannelsWithinBlockRangeReader::from_compatible_slice(slice)
+        QueryBroadcastMessagesWithinTimeRangeReader::from_compatible_slice(slice)
             .map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
@@ -14941,14 +17516,14 @@ impl molecule::prelude::Entity for QueryChannelsWithinBlockRange {
         Self::new_builder()
             .id(self.id())
             .chain_hash(self.chain_hash())
-            .start_block(self.start_block())
-            .end_block(self.end_block())
+            .start_time(self.start_time())
+            .end_time(self.end_time())
             .count(self.count())
     }
 }
 #[derive(Clone, Copy)]
-pub struct QueryChannelsWithinBlockRangeReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for QueryChannelsWithinBlockRangeReader<'r> {
+pub struct QueryBroadcastMessagesWithinTimeRangeReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for QueryBroadcastMessagesWithinTimeRangeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -14957,18 +17532,18 @@ impl<'r> ::core::fmt::LowerHex for QueryChannelsWithinBlockRangeReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for QueryChannelsWithinBlockRangeReader<'r> {
+impl<'r> ::core::fmt::Debug for QueryBroadcastMessagesWithinTimeRangeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for QueryChannelsWithinBlockRangeReader<'r> {
+impl<'r> ::core::fmt::Display for QueryBroadcastMessagesWithinTimeRangeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "id", self.id())?;
         write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
-        write!(f, ", {}: {}", "start_block", self.start_block())?;
-        write!(f, ", {}: {}", "end_block", self.end_block())?;
+        write!(f, ", {}: {}", "start_time", self.start_time())?;
+        write!(f, ", {}: {}", "end_time", self.end_time())?;
         write!(f, ", {}: {}", "count", self.count())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
@@ -14977,7 +17552,7 @@ impl<'r> ::core::fmt::Display for QueryChannelsWithinBlockRangeReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> QueryChannelsWithinBlockRangeReader<'r> {
+impl<'r> QueryBroadcastMessagesWithinTimeRangeReader<'r> {
     pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -15007,37 +17582,37 @@ impl<'r> QueryChannelsWithinBlockRangeReader<'r> {
         let end = molecule::unpack_number(&slice[12..]) as usize;
         Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn start_block(&self) -> Uint64Reader<'r> {
+    pub fn start_time(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
         Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn end_block(&self) -> Uint64Reader<'r> {
+    pub fn end_time(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
         let end = molecule::unpack_number(&slice[20..]) as usize;
         Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn count(&self) -> Uint64OptReader<'r> {
+    pub fn count(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[24..]) as usize;
-            Uint64OptReader::new_unchecked(&self.as_slice()[start..end])
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Uint64OptReader::new_unchecked(&self.as_slice()[start..])
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for QueryChannelsWithinBlockRangeReader<'r> {
-    type Entity = QueryChannelsWithinBlockRange;
-    const NAME: &'static str = "QueryChannelsWithinBlockRangeReader";
+impl<'r> molecule::prelude::Reader<'r> for QueryBroadcastMessagesWithinTimeRangeReader<'r> {
+    type Entity = QueryBroadcastMessagesWithinTimeRange;
+    const NAME: &'static str = "QueryBroadcastMessagesWithinTimeRangeReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        QueryChannelsWithinBlockRangeReader(slice)
+        QueryBroadcastMessagesWithinTimeRangeReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -15080,19 +17655,19 @@ impl<'r> molecule::prelude::Reader<'r> for QueryChannelsWithinBlockRangeReader<'
         Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        Uint64OptReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct QueryChannelsWithinBlockRangeBuilder {
+pub struct QueryBroadcastMessagesWithinTimeRangeBuilder {
     pub(crate) id: Uint64,
     pub(crate) chain_hash: Byte32,
-    pub(crate) start_block: Uint64,
-    pub(crate) end_block: Uint64,
-    pub(crate) count: Uint64Opt,
+    pub(crate) start_time: Uint64,
+    pub(crate) end_time: Uint64,
+    pub(crate) count: Uint64,
 }
-impl QueryChannelsWithinBlockRangeBuilder {
+impl QueryBroadcastMessagesWithinTimeRangeBuilder {
     pub const FIELD_COUNT: usize = 5;
     pub fn id(mut self, v: Uint64) -> Self {
         self.id = v;
@@ -15102,28 +17677,28 @@ impl QueryChannelsWithinBlockRangeBuilder {
         self.chain_hash = v;
         self
     }
-    pub fn start_block(mut self, v: Uint64) -> Self {
-        self.start_block = v;
+    pub fn start_time(mut self, v: Uint64) -> Self {
+        self.start_time = v;
         self
     }
-    pub fn end_block(mut self, v: Uint64) -> Self {
-        self.end_block = v;
+    pub fn end_time(mut self, v: Uint64) -> Self {
+        self.end_time = v;
         self
     }
-    pub fn count(mut self, v: Uint64Opt) -> Self {
+    pub fn count(mut self, v: Uint64) -> Self {
         self.count = v;
         self
     }
 }
-impl molecule::prelude::Builder for QueryChannelsWithinBlockRangeBuilder {
-    type Entity = QueryChannelsWithinBlockRange;
-    const NAME: &'static str = "QueryChannelsWithinBlockRangeBuilder";
+impl molecule::prelude::Builder for QueryBroadcastMessagesWithinTimeRangeBuilder {
+    type Entity = QueryBroadcastMessagesWithinTimeRange;
+    const NAME: &'static str = "QueryBroadcastMessagesWithinTimeRangeBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.id.as_slice().len()
             + self.chain_hash.as_slice().len()
-            + self.start_block.as_slice().len()
-            + self.end_block.as_slice().len()
+            + self.start_time.as_slice().len()
+            + self.end_time.as_slice().len()
             + self.count.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
@@ -15134,9 +17709,9 @@ impl molecule::prelude::Builder for QueryChannelsWithinBlockRangeBuilder {
         offsets.push(total_size);
         total_size += self.chain_hash.as_slice().len();
         offsets.push(total_size);
-        total_size += self.start_block.as_slice().len();
+        total_size += self.start_time.as_slice().len();
         offsets.push(total_size);
-        total_size += self.end_block.as_slice().len();
+        total_size += self.end_time.as_slice().len();
         offsets.push(total_size);
         total_size += self.count.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
@@ -15145,8 +17720,8 @@ impl molecule::prelude::Builder for QueryChannelsWithinBlockRangeBuilder {
         }
         writer.write_all(self.id.as_slice())?;
         writer.write_all(self.chain_hash.as_slice())?;
-        writer.write_all(self.start_block.as_slice())?;
-        writer.write_all(self.end_block.as_slice())?;
+        writer.write_all(self.start_time.as_slice())?;
+        writer.write_all(self.end_time.as_slice())?;
         writer.write_all(self.count.as_slice())?;
         Ok(())
     }
@@ -15154,12 +17729,12 @@ impl molecule::prelude::Builder for QueryChannelsWithinBlockRangeBuilder {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        QueryChannelsWithinBlockRange::new_unchecked(inner.into())
+        QueryBroadcastMessagesWithinTimeRange::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct OutPoints(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for OutPoints {
+pub struct QueryBroadcastMessagesWithinTimeRangeResult(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for QueryBroadcastMessagesWithinTimeRangeResult {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -15168,66 +17743,90 @@ impl ::core::fmt::LowerHex for OutPoints {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for OutPoints {
+impl ::core::fmt::Debug for QueryBroadcastMessagesWithinTimeRangeResult {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for OutPoints {
+impl ::core::fmt::Display for QueryBroadcastMessagesWithinTimeRangeResult {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "next_time", self.next_time())?;
+        write!(f, ", {}: {}", "is_finished", self.is_finished())?;
+        write!(f, ", {}: {}", "queries", self.queries())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
-        write!(f, "]")
+        write!(f, " }}")
     }
 }
-impl ::core::default::Default for OutPoints {
+impl ::core::default::Default for QueryBroadcastMessagesWithinTimeRangeResult {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        OutPoints::new_unchecked(v)
+        QueryBroadcastMessagesWithinTimeRangeResult::new_unchecked(v)
     }
 }
-impl OutPoints {
-    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
-    pub const ITEM_SIZE: usize = 36;
+impl QueryBroadcastMessagesWithinTimeRangeResult {
+    const DEFAULT_VALUE: [u8; 41] = [
+        41, 0, 0, 0, 20, 0, 0, 0, 28, 0, 0, 0, 36, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
-    }
-    pub fn item_count(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn get(&self, idx: usize) -> Option<OutPoint> {
-        if idx >= self.len() {
-            None
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn id(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn next_time(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn is_finished(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn queries(&self) -> BroadcastMessageQueries {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            BroadcastMessageQueries::new_unchecked(self.0.slice(start..end))
         } else {
-            Some(self.get_unchecked(idx))
+            BroadcastMessageQueries::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn get_unchecked(&self, idx: usize) -> OutPoint {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        OutPoint::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn as_reader<'r>(&'r self) -> OutPointsReader<'r> {
-        OutPointsReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> QueryBroadcastMessagesWithinTimeRangeResultReader<'r> {
+        QueryBroadcastMessagesWithinTimeRangeResultReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for OutPoints {
-    type Builder = OutPointsBuilder;
-    const NAME: &'static str = "OutPoints";
+impl molecule::prelude::Entity for QueryBroadcastMessagesWithinTimeRangeResult {
+    type Builder = QueryBroadcastMessagesWithinTimeRangeResultBuilder;
+    const NAME: &'static str = "QueryBroadcastMessagesWithinTimeRangeResult";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        OutPoints(data)
+        QueryBroadcastMessagesWithinTimeRangeResult(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -15236,21 +17835,27 @@ impl molecule::prelude::Entity for OutPoints {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        OutPointsReader::from_slice(slice).map(|reader| reader.to_entity())
+        QueryBroadcastMessagesWithinTimeRangeResultReader::from_slice(slice)
+            .map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        OutPointsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        QueryBroadcastMessagesWithinTimeRangeResultReader::from_compatible_slice(slice)
+            .map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().extend(self.into_iter())
+        Self::new_builder()
+            .id(self.id())
+            .next_time(self.next_time())
+            .is_finished(self.is_finished())
+            .queries(self.queries())
     }
 }
 #[derive(Clone, Copy)]
-pub struct OutPointsReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for OutPointsReader<'r> {
+pub struct QueryBroadcastMessagesWithinTimeRangeResultReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for QueryBroadcastMessagesWithinTimeRangeResultReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -15259,184 +17864,192 @@ impl<'r> ::core::fmt::LowerHex for OutPointsReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for OutPointsReader<'r> {
+impl<'r> ::core::fmt::Debug for QueryBroadcastMessagesWithinTimeRangeResultReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for OutPointsReader<'r> {
+impl<'r> ::core::fmt::Display for QueryBroadcastMessagesWithinTimeRangeResultReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "next_time", self.next_time())?;
+        write!(f, ", {}: {}", "is_finished", self.is_finished())?;
+        write!(f, ", {}: {}", "queries", self.queries())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
-        write!(f, "]")
+        write!(f, " }}")
     }
 }
-impl<'r> OutPointsReader<'r> {
-    pub const ITEM_SIZE: usize = 36;
+impl<'r> QueryBroadcastMessagesWithinTimeRangeResultReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
-    }
-    pub fn item_count(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn get(&self, idx: usize) -> Option<OutPointReader<'r>> {
-        if idx >= self.len() {
-            None
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn id(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn next_time(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn is_finished(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn queries(&self) -> BroadcastMessageQueriesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            BroadcastMessageQueriesReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Some(self.get_unchecked(idx))
+            BroadcastMessageQueriesReader::new_unchecked(&self.as_slice()[start..])
         }
     }
-    pub fn get_unchecked(&self, idx: usize) -> OutPointReader<'r> {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        OutPointReader::new_unchecked(&self.as_slice()[start..end])
-    }
 }
-impl<'r> molecule::prelude::Reader<'r> for OutPointsReader<'r> {
-    type Entity = OutPoints;
-    const NAME: &'static str = "OutPointsReader";
+impl<'r> molecule::prelude::Reader<'r> for QueryBroadcastMessagesWithinTimeRangeResultReader<'r> {
+    type Entity = QueryBroadcastMessagesWithinTimeRangeResult;
+    const NAME: &'static str = "QueryBroadcastMessagesWithinTimeRangeResultReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        OutPointsReader(slice)
+        QueryBroadcastMessagesWithinTimeRangeResultReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
         if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let item_count = molecule::unpack_number(slice) as usize;
-        if item_count == 0 {
-            if slice_len != molecule::NUMBER_SIZE {
-                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
-            }
-            return Ok(());
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        let total_size = molecule::unpack_number(slice) as usize;
         if slice_len != total_size {
             return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        ByteReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        BroadcastMessageQueriesReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct OutPointsBuilder(pub(crate) Vec<OutPoint>);
-impl OutPointsBuilder {
-    pub const ITEM_SIZE: usize = 36;
-    pub fn set(mut self, v: Vec<OutPoint>) -> Self {
-        self.0 = v;
+pub struct QueryBroadcastMessagesWithinTimeRangeResultBuilder {
+    pub(crate) id: Uint64,
+    pub(crate) next_time: Uint64,
+    pub(crate) is_finished: Byte,
+    pub(crate) queries: BroadcastMessageQueries,
+}
+impl QueryBroadcastMessagesWithinTimeRangeResultBuilder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn id(mut self, v: Uint64) -> Self {
+        self.id = v;
         self
     }
-    pub fn push(mut self, v: OutPoint) -> Self {
-        self.0.push(v);
+    pub fn next_time(mut self, v: Uint64) -> Self {
+        self.next_time = v;
         self
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = OutPoint>>(mut self, iter: T) -> Self {
-        for elem in iter {
-            self.0.push(elem);
-        }
+    pub fn is_finished(mut self, v: Byte) -> Self {
+        self.is_finished = v;
         self
     }
-    pub fn replace(&mut self, index: usize, v: OutPoint) -> Option<OutPoint> {
-        self.0
-            .get_mut(index)
-            .map(|item| ::core::mem::replace(item, v))
+    pub fn queries(mut self, v: BroadcastMessageQueries) -> Self {
+        self.queries = v;
+        self
     }
 }
-impl molecule::prelude::Builder for OutPointsBuilder {
-    type Entity = OutPoints;
-    const NAME: &'static str = "OutPointsBuilder";
+impl molecule::prelude::Builder for QueryBroadcastMessagesWithinTimeRangeResultBuilder {
+    type Entity = QueryBroadcastMessagesWithinTimeRangeResult;
+    const NAME: &'static str = "QueryBroadcastMessagesWithinTimeRangeResultBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.id.as_slice().len()
+            + self.next_time.as_slice().len()
+            + self.is_finished.as_slice().len()
+            + self.queries.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
-        for inner in &self.0[..] {
-            writer.write_all(inner.as_slice())?;
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.next_time.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.is_finished.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.queries.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
+        writer.write_all(self.id.as_slice())?;
+        writer.write_all(self.next_time.as_slice())?;
+        writer.write_all(self.is_finished.as_slice())?;
+        writer.write_all(self.queries.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        OutPoints::new_unchecked(inner.into())
-    }
-}
-pub struct OutPointsIterator(OutPoints, usize, usize);
-impl ::core::iter::Iterator for OutPointsIterator {
-    type Item = OutPoint;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl ::core::iter::ExactSizeIterator for OutPointsIterator {
-    fn len(&self) -> usize {
-        self.2 - self.1
-    }
-}
-impl ::core::iter::IntoIterator for OutPoints {
-    type Item = OutPoint;
-    type IntoIter = OutPointsIterator;
-    fn into_iter(self) -> Self::IntoIter {
-        let len = self.len();
-        OutPointsIterator(self, 0, len)
-    }
-}
-impl<'r> OutPointsReader<'r> {
-    pub fn iter<'t>(&'t self) -> OutPointsReaderIterator<'t, 'r> {
-        OutPointsReaderIterator(&self, 0, self.len())
-    }
-}
-pub struct OutPointsReaderIterator<'t, 'r>(&'t OutPointsReader<'r>, usize, usize);
-impl<'t: 'r, 'r> ::core::iter::Iterator for OutPointsReaderIterator<'t, 'r> {
-    type Item = OutPointReader<'t>;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for OutPointsReaderIterator<'t, 'r> {
-    fn len(&self) -> usize {
-        self.2 - self.1
-    }
-}
-impl ::core::iter::FromIterator<OutPoint> for OutPoints {
-    fn from_iter<T: IntoIterator<Item = OutPoint>>(iter: T) -> Self {
-        Self::new_builder().extend(iter).build()
+        QueryBroadcastMessagesWithinTimeRangeResult::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct QueryChannelsWithinBlockRangeResult(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for QueryChannelsWithinBlockRangeResult {
+pub struct Init(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Init {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -15445,18 +18058,16 @@ impl ::core::fmt::LowerHex for QueryChannelsWithinBlockRangeResult {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for QueryChannelsWithinBlockRangeResult {
+impl ::core::fmt::Debug for Init {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for QueryChannelsWithinBlockRangeResult {
+impl ::core::fmt::Display for Init {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "id", self.id())?;
-        write!(f, ", {}: {}", "next_block", self.next_block())?;
-        write!(f, ", {}: {}", "is_finished", self.is_finished())?;
-        write!(f, ", {}: {}", "channels", self.channels())?;
+        write!(f, "{}: {}", "chain_hash", self.chain_hash())?;
+        write!(f, ", {}: {}", "features", self.features())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -15464,18 +18075,18 @@ impl ::core::fmt::Display for QueryChannelsWithinBlockRangeResult {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for QueryChannelsWithinBlockRangeResult {
+impl ::core::default::Default for Init {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        QueryChannelsWithinBlockRangeResult::new_unchecked(v)
+        Init::new_unchecked(v)
     }
 }
-impl QueryChannelsWithinBlockRangeResult {
-    const DEFAULT_VALUE: [u8; 41] = [
-        41, 0, 0, 0, 20, 0, 0, 0, 28, 0, 0, 0, 36, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl Init {
+    const DEFAULT_VALUE: [u8; 48] = [
+        48, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -15492,43 +18103,31 @@ impl QueryChannelsWithinBlockRangeResult {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn id(&self) -> Uint64 {
+    pub fn chain_hash(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
+        Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn next_block(&self) -> Uint64 {
+    pub fn features(&self) -> Bytes {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn is_finished(&self) -> Byte {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Byte::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn channels(&self) -> OutPoints {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            OutPoints::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start..end))
         } else {
-            OutPoints::new_unchecked(self.0.slice(start..))
+            Bytes::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> QueryChannelsWithinBlockRangeResultReader<'r> {
-        QueryChannelsWithinBlockRangeResultReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> InitReader<'r> {
+        InitReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for QueryChannelsWithinBlockRangeResult {
-    type Builder = QueryChannelsWithinBlockRangeResultBuilder;
-    const NAME: &'static str = "QueryChannelsWithinBlockRangeResult";
+impl molecule::prelude::Entity for Init {
+    type Builder = InitBuilder;
+    const NAME: &'static str = "Init";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        QueryChannelsWithinBlockRangeResult(data)
+        Init(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -15537,27 +18136,23 @@ impl molecule::prelude::Entity for QueryChannelsWithinBlockRangeResult {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        QueryChannelsWithinBlockRangeResultReader::from_slice(slice)
-            .map(|reader| reader.to_entity())
+        InitReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        QueryChannelsWithinBlockRangeResultReader::from_compatible_slice(slice)
-            .map(|reader| reader.to_entity())
+        InitReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .id(self.id())
-            .next_block(self.next_block())
-            .is_finished(self.is_finished())
-            .channels(self.channels())
+            .chain_hash(self.chain_hash())
+            .features(self.features())
     }
 }
 #[derive(Clone, Copy)]
-pub struct QueryChannelsWithinBlockRangeResultReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for QueryChannelsWithinBlockRangeResultReader<'r> {
+pub struct InitReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for InitReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -15566,18 +18161,16 @@ impl<'r> ::core::fmt::LowerHex for QueryChannelsWithinBlockRangeResultReader<'r>
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for QueryChannelsWithinBlockRangeResultReader<'r> {
+impl<'r> ::core::fmt::Debug for InitReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for QueryChannelsWithinBlockRangeResultReader<'r> {
+impl<'r> ::core::fmt::Display for InitReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "id", self.id())?;
-        write!(f, ", {}: {}", "next_block", self.next_block())?;
-        write!(f, ", {}: {}", "is_finished", self.is_finished())?;
-        write!(f, ", {}: {}", "channels", self.channels())?;
+        write!(f, "{}: {}", "chain_hash", self.chain_hash())?;
+        write!(f, ", {}: {}", "features", self.features())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -15585,8 +18178,8 @@ impl<'r> ::core::fmt::Display for QueryChannelsWithinBlockRangeResultReader<'r>
         write!(f, " }}")
     }
 }
-impl<'r> QueryChannelsWithinBlockRangeResultReader<'r> {
-    pub const FIELD_COUNT: usize = 4;
+impl<'r> InitReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -15603,43 +18196,31 @@ impl<'r> QueryChannelsWithinBlockRangeResultReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn id(&self) -> Uint64Reader<'r> {
+    pub fn chain_hash(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn next_block(&self) -> Uint64Reader<'r> {
+    pub fn features(&self) -> BytesReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn is_finished(&self) -> ByteReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        ByteReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn channels(&self) -> OutPointsReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            OutPointsReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            OutPointsReader::new_unchecked(&self.as_slice()[start..])
+            BytesReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for QueryChannelsWithinBlockRangeResultReader<'r> {
-    type Entity = QueryChannelsWithinBlockRangeResult;
-    const NAME: &'static str = "QueryChannelsWithinBlockRangeResultReader";
+impl<'r> molecule::prelude::Reader<'r> for InitReader<'r> {
+    type Entity = Init;
+    const NAME: &'static str = "InitReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        QueryChannelsWithinBlockRangeResultReader(slice)
+        InitReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -15677,81 +18258,61 @@ impl<'r> molecule::prelude::Reader<'r> for QueryChannelsWithinBlockRangeResultRe
         offsets.push(total_size);
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
-        }
-        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        ByteReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        OutPointsReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct QueryChannelsWithinBlockRangeResultBuilder {
-    pub(crate) id: Uint64,
-    pub(crate) next_block: Uint64,
-    pub(crate) is_finished: Byte,
-    pub(crate) channels: OutPoints,
+pub struct InitBuilder {
+    pub(crate) chain_hash: Byte32,
+    pub(crate) features: Bytes,
 }
-impl QueryChannelsWithinBlockRangeResultBuilder {
-    pub const FIELD_COUNT: usize = 4;
-    pub fn id(mut self, v: Uint64) -> Self {
-        self.id = v;
-        self
-    }
-    pub fn next_block(mut self, v: Uint64) -> Self {
-        self.next_block = v;
-        self
-    }
-    pub fn is_finished(mut self, v: Byte) -> Self {
-        self.is_finished = v;
+impl InitBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn chain_hash(mut self, v: Byte32) -> Self {
+        self.chain_hash = v;
         self
     }
-    pub fn channels(mut self, v: OutPoints) -> Self {
-        self.channels = v;
+    pub fn features(mut self, v: Bytes) -> Self {
+        self.features = v;
         self
     }
 }
-impl molecule::prelude::Builder for QueryChannelsWithinBlockRangeResultBuilder {
-    type Entity = QueryChannelsWithinBlockRangeResult;
-    const NAME: &'static str = "QueryChannelsWithinBlockRangeResultBuilder";
+impl molecule::prelude::Builder for InitBuilder {
+    type Entity = Init;
+    const NAME: &'static str = "InitBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.id.as_slice().len()
-            + self.next_block.as_slice().len()
-            + self.is_finished.as_slice().len()
-            + self.channels.as_slice().len()
+            + self.chain_hash.as_slice().len()
+            + self.features.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.id.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.next_block.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.is_finished.as_slice().len();
+        total_size += self.chain_hash.as_slice().len();
         offsets.push(total_size);
-        total_size += self.channels.as_slice().len();
+        total_size += self.features.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.id.as_slice())?;
-        writer.write_all(self.next_block.as_slice())?;
-        writer.write_all(self.is_finished.as_slice())?;
-        writer.write_all(self.channels.as_slice())?;
+        writer.write_all(self.chain_hash.as_slice())?;
+        writer.write_all(self.features.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        QueryChannelsWithinBlockRangeResult::new_unchecked(inner.into())
+        Init::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct QueryBroadcastMessagesWithinTimeRange(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for QueryBroadcastMessagesWithinTimeRange {
+pub struct CommitmentSecret(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for CommitmentSecret {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -15760,19 +18321,17 @@ impl ::core::fmt::LowerHex for QueryBroadcastMessagesWithinTimeRange {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for QueryBroadcastMessagesWithinTimeRange {
+impl ::core::fmt::Debug for CommitmentSecret {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for QueryBroadcastMessagesWithinTimeRange {
+impl ::core::fmt::Display for CommitmentSecret {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "id", self.id())?;
-        write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
-        write!(f, ", {}: {}", "start_time", self.start_time())?;
-        write!(f, ", {}: {}", "end_time", self.end_time())?;
-        write!(f, ", {}: {}", "count", self.count())?;
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(f, ", {}: {}", "commitment_number", self.commitment_number())?;
+        write!(f, ", {}: {}", "secret", self.secret())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -15780,19 +18339,19 @@ impl ::core::fmt::Display for QueryBroadcastMessagesWithinTimeRange {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for QueryBroadcastMessagesWithinTimeRange {
+impl ::core::default::Default for CommitmentSecret {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        QueryBroadcastMessagesWithinTimeRange::new_unchecked(v)
+        CommitmentSecret::new_unchecked(v)
     }
 }
-impl QueryBroadcastMessagesWithinTimeRange {
+impl CommitmentSecret {
     const DEFAULT_VALUE: [u8; 88] = [
-        88, 0, 0, 0, 24, 0, 0, 0, 32, 0, 0, 0, 64, 0, 0, 0, 72, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        88, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 5;
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -15809,49 +18368,37 @@ impl QueryBroadcastMessagesWithinTimeRange {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn id(&self) -> Uint64 {
+    pub fn channel_id(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
+        Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn chain_hash(&self) -> Byte32 {
+    pub fn commitment_number(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn start_time(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn end_time(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
         Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn count(&self) -> Uint64 {
+    pub fn secret(&self) -> Byte32 {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[24..]) as usize;
-            Uint64::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            Byte32::new_unchecked(self.0.slice(start..end))
         } else {
-            Uint64::new_unchecked(self.0.slice(start..))
+            Byte32::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> QueryBroadcastMessagesWithinTimeRangeReader<'r> {
-        QueryBroadcastMessagesWithinTimeRangeReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> CommitmentSecretReader<'r> {
+        CommitmentSecretReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for QueryBroadcastMessagesWithinTimeRange {
-    type Builder = QueryBroadcastMessagesWithinTimeRangeBuilder;
-    const NAME: &'static str = "QueryBroadcastMessagesWithinTimeRange";
+impl molecule::prelude::Entity for CommitmentSecret {
+    type Builder = CommitmentSecretBuilder;
+    const NAME: &'static str = "CommitmentSecret";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        QueryBroadcastMessagesWithinTimeRange(data)
+        CommitmentSecret(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -15860,28 +18407,24 @@ impl molecule::prelude::Entity for QueryBroadcastMessagesWithinTimeRange {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        QueryBroadcastMessagesWithinTimeRangeReader::from_slice(slice)
-            .map(|reader| reader.to_entity())
+        CommitmentSecretReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        QueryBroadcastMessagesWithinTimeRangeReader::from_compatible_slice(slice)
-            .map(|reader| reader.to_entity())
+        CommitmentSecretReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .id(self.id())
-            .chain_hash(self.chain_hash())
-            .start_time(self.start_time())
-            .end_time(self.end_time())
-            .count(self.count())
+            .channel_id(self.channel_id())
+            .commitment_number(self.commitment_number())
+            .secret(self.secret())
     }
 }
 #[derive(Clone, Copy)]
-pub struct QueryBroadcastMessagesWithinTimeRangeReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for QueryBroadcastMessagesWithinTimeRangeReader<'r> {
+pub struct CommitmentSecretReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CommitmentSecretReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -15890,19 +18433,17 @@ impl<'r> ::core::fmt::LowerHex for QueryBroadcastMessagesWithinTimeRangeReader<'
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for QueryBroadcastMessagesWithinTimeRangeReader<'r> {
+impl<'r> ::core::fmt::Debug for CommitmentSecretReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for QueryBroadcastMessagesWithinTimeRangeReader<'r> {
+impl<'r> ::core::fmt::Display for CommitmentSecretReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "id", self.id())?;
-        write!(f, ", {}: {}", "chain_hash", self.chain_hash())?;
-        write!(f, ", {}: {}", "start_time", self.start_time())?;
-        write!(f, ", {}: {}", "end_time", self.end_time())?;
-        write!(f, ", {}: {}", "count", self.count())?;
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(f, ", {}: {}", "commitment_number", self.commitment_number())?;
+        write!(f, ", {}: {}", "secret", self.secret())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -15910,8 +18451,8 @@ impl<'r> ::core::fmt::Display for QueryBroadcastMessagesWithinTimeRangeReader<'r
         write!(f, " }}")
     }
 }
-impl<'r> QueryBroadcastMessagesWithinTimeRangeReader<'r> {
-    pub const FIELD_COUNT: usize = 5;
+impl<'r> CommitmentSecretReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -15928,49 +18469,37 @@ impl<'r> QueryBroadcastMessagesWithinTimeRangeReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn id(&self) -> Uint64Reader<'r> {
+    pub fn channel_id(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn chain_hash(&self) -> Byte32Reader<'r> {
+    pub fn commitment_number(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn start_time(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn end_time(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
         Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn count(&self) -> Uint64Reader<'r> {
+    pub fn secret(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[24..]) as usize;
-            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Uint64Reader::new_unchecked(&self.as_slice()[start..])
+            Byte32Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for QueryBroadcastMessagesWithinTimeRangeReader<'r> {
-    type Entity = QueryBroadcastMessagesWithinTimeRange;
-    const NAME: &'static str = "QueryBroadcastMessagesWithinTimeRangeReader";
+impl<'r> molecule::prelude::Reader<'r> for CommitmentSecretReader<'r> {
+    type Entity = CommitmentSecret;
+    const NAME: &'static str = "CommitmentSecretReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        QueryBroadcastMessagesWithinTimeRangeReader(slice)
+        CommitmentSecretReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -16009,90 +18538,70 @@ impl<'r> molecule::prelude::Reader<'r> for QueryBroadcastMessagesWithinTimeRange
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct QueryBroadcastMessagesWithinTimeRangeBuilder {
-    pub(crate) id: Uint64,
-    pub(crate) chain_hash: Byte32,
-    pub(crate) start_time: Uint64,
-    pub(crate) end_time: Uint64,
-    pub(crate) count: Uint64,
+pub struct CommitmentSecretBuilder {
+    pub(crate) channel_id: Byte32,
+    pub(crate) commitment_number: Uint64,
+    pub(crate) secret: Byte32,
 }
-impl QueryBroadcastMessagesWithinTimeRangeBuilder {
-    pub const FIELD_COUNT: usize = 5;
-    pub fn id(mut self, v: Uint64) -> Self {
-        self.id = v;
-        self
-    }
-    pub fn chain_hash(mut self, v: Byte32) -> Self {
-        self.chain_hash = v;
-        self
-    }
-    pub fn start_time(mut self, v: Uint64) -> Self {
-        self.start_time = v;
+impl CommitmentSecretBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn channel_id(mut self, v: Byte32) -> Self {
+        self.channel_id = v;
         self
     }
-    pub fn end_time(mut self, v: Uint64) -> Self {
-        self.end_time = v;
+    pub fn commitment_number(mut self, v: Uint64) -> Self {
+        self.commitment_number = v;
         self
     }
-    pub fn count(mut self, v: Uint64) -> Self {
-        self.count = v;
+    pub fn secret(mut self, v: Byte32) -> Self {
+        self.secret = v;
         self
     }
 }
-impl molecule::prelude::Builder for QueryBroadcastMessagesWithinTimeRangeBuilder {
-    type Entity = QueryBroadcastMessagesWithinTimeRange;
-    const NAME: &'static str = "QueryBroadcastMessagesWithinTimeRangeBuilder";
+impl molecule::prelude::Builder for CommitmentSecretBuilder {
+    type Entity = CommitmentSecret;
+    const NAME: &'static str = "CommitmentSecretBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.id.as_slice().len()
-            + self.chain_hash.as_slice().len()
-            + self.start_time.as_slice().len()
-            + self.end_time.as_slice().len()
-            + self.count.as_slice().len()
+            + self.channel_id.as_slice().len()
+            + self.commitment_number.as_slice().len()
+            + self.secret.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.id.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.chain_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.start_time.as_slice().len();
+        total_size += self.channel_id.as_slice().len();
         offsets.push(total_size);
-        total_size += self.end_time.as_slice().len();
+        total_size += self.commitment_number.as_slice().len();
         offsets.push(total_size);
-        total_size += self.count.as_slice().len();
+        total_size += self.secret.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.id.as_slice())?;
-        writer.write_all(self.chain_hash.as_slice())?;
-        writer.write_all(self.start_time.as_slice())?;
-        writer.write_all(self.end_time.as_slice())?;
-        writer.write_all(self.count.as_slice())?;
+        writer.write_all(self.channel_id.as_slice())?;
+        writer.write_all(self.commitment_number.as_slice())?;
+        writer.write_all(self.secret.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        QueryBroadcastMessagesWithinTimeRange::new_unchecked(inner.into())
+        CommitmentSecret::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct QueryBroadcastMessagesWithinTimeRangeResult(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for QueryBroadcastMessagesWithinTimeRangeResult {
+pub struct ReestablishChannelWithDataLossProtect(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ReestablishChannelWithDataLossProtect {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -16101,18 +18610,39 @@ impl ::core::fmt::LowerHex for QueryBroadcastMessagesWithinTimeRangeResult {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for QueryBroadcastMessagesWithinTimeRangeResult {
+impl ::core::fmt::Debug for ReestablishChannelWithDataLossProtect {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for QueryBroadcastMessagesWithinTimeRangeResult {
+impl ::core::fmt::Display for ReestablishChannelWithDataLossProtect {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "id", self.id())?;
-        write!(f, ", {}: {}", "next_time", self.next_time())?;
-        write!(f, ", {}: {}", "is_finished", self.is_finished())?;
-        write!(f, ", {}: {}", "queries", self.queries())?;
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(
+            f,
+            ", {}: {}",
+            "local_commitment_number",
+            self.local_commitment_number()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "remote_commitment_number",
+            self.remote_commitment_number()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "remote_commitment_secret",
+            self.remote_commitment_secret()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "remote_commitment_point",
+            self.remote_commitment_point()
+        )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -16120,18 +18650,21 @@ impl ::core::fmt::Display for QueryBroadcastMessagesWithinTimeRangeResult {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for QueryBroadcastMessagesWithinTimeRangeResult {
+impl ::core::default::Default for ReestablishChannelWithDataLossProtect {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        QueryBroadcastMessagesWithinTimeRangeResult::new_unchecked(v)
+        ReestablishChannelWithDataLossProtect::new_unchecked(v)
     }
 }
-impl QueryBroadcastMessagesWithinTimeRangeResult {
-    const DEFAULT_VALUE: [u8; 41] = [
-        41, 0, 0, 0, 20, 0, 0, 0, 28, 0, 0, 0, 36, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+impl ReestablishChannelWithDataLossProtect {
+    const DEFAULT_VALUE: [u8; 137] = [
+        137, 0, 0, 0, 24, 0, 0, 0, 56, 0, 0, 0, 64, 0, 0, 0, 72, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -16148,43 +18681,49 @@ impl QueryBroadcastMessagesWithinTimeRangeResult {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn id(&self) -> Uint64 {
+    pub fn channel_id(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
+        Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn next_time(&self) -> Uint64 {
+    pub fn local_commitment_number(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
         Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn is_finished(&self) -> Byte {
+    pub fn remote_commitment_number(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        Byte::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn queries(&self) -> BroadcastMessageQueries {
+    pub fn remote_commitment_secret(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn remote_commitment_point(&self) -> Pubkey {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            BroadcastMessageQueries::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            Pubkey::new_unchecked(self.0.slice(start..end))
         } else {
-            BroadcastMessageQueries::new_unchecked(self.0.slice(start..))
+            Pubkey::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> QueryBroadcastMessagesWithinTimeRangeResultReader<'r> {
-        QueryBroadcastMessagesWithinTimeRangeResultReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ReestablishChannelWithDataLossProtectReader<'r> {
+        ReestablishChannelWithDataLossProtectReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for QueryBroadcastMessagesWithinTimeRangeResult {
-    type Builder = QueryBroadcastMessagesWithinTimeRangeResultBuilder;
-    const NAME: &'static str = "QueryBroadcastMessagesWithinTimeRangeResult";
+impl molecule::prelude::Entity for ReestablishChannelWithDataLossProtect {
+    type Builder = ReestablishChannelWithDataLossProtectBuilder;
+    const NAME: &'static str = "ReestablishChannelWithDataLossProtect";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        QueryBroadcastMessagesWithinTimeRangeResult(data)
+        ReestablishChannelWithDataLossProtect(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -16193,11 +18732,11 @@ impl molecule::prelude::Entity for QueryBroadcastMessagesWithinTimeRangeResult {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        QueryBroadcastMessagesWithinTimeRangeResultReader::from_slice(slice)
+        ReestablishChannelWithDataLossProtectReader::from_slice(slice)
             .map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        QueryBroadcastMessagesWithinTimeRangeResultReader::from_compatible_slice(slice)
+        ReestablishChannelWithDataLossProtectReader::from_compatible_slice(slice)
             .map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
@@ -16205,15 +18744,16 @@ impl molecule::prelude::Entity for QueryBroadcastMessagesWithinTimeRangeResult {
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .id(self.id())
-            .next_time(self.next_time())
-            .is_finished(self.is_finished())
-            .queries(self.queries())
+            .channel_id(self.channel_id())
+            .local_commitment_number(self.local_commitment_number())
+            .remote_commitment_number(self.remote_commitment_number())
+            .remote_commitment_secret(self.remote_commitment_secret())
+            .remote_commitment_point(self.remote_commitment_point())
     }
 }
 #[derive(Clone, Copy)]
-pub struct QueryBroadcastMessagesWithinTimeRangeResultReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for QueryBroadcastMessagesWithinTimeRangeResultReader<'r> {
+pub struct ReestablishChannelWithDataLossProtectReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ReestablishChannelWithDataLossProtectReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -16222,18 +18762,39 @@ impl<'r> ::core::fmt::LowerHex for QueryBroadcastMessagesWithinTimeRangeResultRe
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for QueryBroadcastMessagesWithinTimeRangeResultReader<'r> {
+impl<'r> ::core::fmt::Debug for ReestablishChannelWithDataLossProtectReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for QueryBroadcastMessagesWithinTimeRangeResultReader<'r> {
+impl<'r> ::core::fmt::Display for ReestablishChannelWithDataLossProtectReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "id", self.id())?;
-        write!(f, ", {}: {}", "next_time", self.next_time())?;
-        write!(f, ", {}: {}", "is_finished", self.is_finished())?;
-        write!(f, ", {}: {}", "queries", self.queries())?;
+        write!(f, "{}: {}", "channel_id", self.channel_id())?;
+        write!(
+            f,
+            ", {}: {}",
+            "local_commitment_number",
+            self.local_commitment_number()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "remote_commitment_number",
+            self.remote_commitment_number()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "remote_commitment_secret",
+            self.remote_commitment_secret()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "remote_commitment_point",
+            self.remote_commitment_point()
+        )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -16241,8 +18802,8 @@ impl<'r> ::core::fmt::Display for QueryBroadcastMessagesWithinTimeRangeResultRea
         write!(f, " }}")
     }
 }
-impl<'r> QueryBroadcastMessagesWithinTimeRangeResultReader<'r> {
-    pub const FIELD_COUNT: usize = 4;
+impl<'r> ReestablishChannelWithDataLossProtectReader<'r> {
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -16259,43 +18820,49 @@ impl<'r> QueryBroadcastMessagesWithinTimeRangeResultReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn id(&self) -> Uint64Reader<'r> {
+    pub fn channel_id(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn next_time(&self) -> Uint64Reader<'r> {
+    pub fn local_commitment_number(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
         Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn is_finished(&self) -> ByteReader<'r> {
+    pub fn remote_commitment_number(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        ByteReader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn queries(&self) -> BroadcastMessageQueriesReader<'r> {
+    pub fn remote_commitment_secret(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn remote_commitment_point(&self) -> PubkeyReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            BroadcastMessageQueriesReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            PubkeyReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            BroadcastMessageQueriesReader::new_unchecked(&self.as_slice()[start..])
+            PubkeyReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for QueryBroadcastMessagesWithinTimeRangeResultReader<'r> {
-    type Entity = QueryBroadcastMessagesWithinTimeRangeResult;
-    const NAME: &'static str = "QueryBroadcastMessagesWithinTimeRangeResultReader";
+impl<'r> molecule::prelude::Reader<'r> for ReestablishChannelWithDataLossProtectReader<'r> {
+    type Entity = ReestablishChannelWithDataLossProtect;
+    const NAME: &'static str = "ReestablishChannelWithDataLossProtectReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        QueryBroadcastMessagesWithinTimeRangeResultReader(slice)
+        ReestablishChannelWithDataLossProtectReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -16334,75 +18901,85 @@ impl<'r> molecule::prelude::Reader<'r> for QueryBroadcastMessagesWithinTimeRange
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        ByteReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        BroadcastMessageQueriesReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        PubkeyReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct QueryBroadcastMessagesWithinTimeRangeResultBuilder {
-    pub(crate) id: Uint64,
-    pub(crate) next_time: Uint64,
-    pub(crate) is_finished: Byte,
-    pub(crate) queries: BroadcastMessageQueries,
+pub struct ReestablishChannelWithDataLossProtectBuilder {
+    pub(crate) channel_id: Byte32,
+    pub(crate) local_commitment_number: Uint64,
+    pub(crate) remote_commitment_number: Uint64,
+    pub(crate) remote_commitment_secret: Byte32,
+    pub(crate) remote_commitment_point: Pubkey,
 }
-impl QueryBroadcastMessagesWithinTimeRangeResultBuilder {
-    pub const FIELD_COUNT: usize = 4;
-    pub fn id(mut self, v: Uint64) -> Self {
-        self.id = v;
+impl ReestablishChannelWithDataLossProtectBuilder {
+    pub const FIELD_COUNT: usize = 5;
+    pub fn channel_id(mut self, v: Byte32) -> Self {
+        self.channel_id = v;
         self
     }
-    pub fn next_time(mut self, v: Uint64) -> Self {
-        self.next_time = v;
+    pub fn local_commitment_number(mut self, v: Uint64) -> Self {
+        self.local_commitment_number = v;
         self
     }
-    pub fn is_finished(mut self, v: Byte) -> Self {
-        self.is_finished = v;
+    pub fn remote_commitment_number(mut self, v: Uint64) -> Self {
+        self.remote_commitment_number = v;
         self
     }
-    pub fn queries(mut self, v: BroadcastMessageQueries) -> Self {
-        self.queries = v;
+    pub fn remote_commitment_secret(mut self, v: Byte32) -> Self {
+        self.remote_commitment_secret = v;
+        self
+    }
+    pub fn remote_commitment_point(mut self, v: Pubkey) -> Self {
+        self.remote_commitment_point = v;
         self
     }
 }
-impl molecule::prelude::Builder for QueryBroadcastMessagesWithinTimeRangeResultBuilder {
-    type Entity = QueryBroadcastMessagesWithinTimeRangeResult;
-    const NAME: &'static str = "QueryBroadcastMessagesWithinTimeRangeResultBuilder";
+impl molecule::prelude::Builder for ReestablishChannelWithDataLossProtectBuilder {
+    type Entity = ReestablishChannelWithDataLossProtect;
+    const NAME: &'static str = "ReestablishChannelWithDataLossProtectBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.id.as_slice().len()
-            + self.next_time.as_slice().len()
-            + self.is_finished.as_slice().len()
-            + self.queries.as_slice().len()
+            + self.channel_id.as_slice().len()
+            + self.local_commitment_number.as_slice().len()
+            + self.remote_commitment_number.as_slice().len()
+            + self.remote_commitment_secret.as_slice().len()
+            + self.remote_commitment_point.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.id.as_slice().len();
+        total_size += self.channel_id.as_slice().len();
         offsets.push(total_size);
-        total_size += self.next_time.as_slice().len();
+        total_size += self.local_commitment_number.as_slice().len();
         offsets.push(total_size);
-        total_size += self.is_finished.as_slice().len();
+        total_size += self.remote_commitment_number.as_slice().len();
         offsets.push(total_size);
-        total_size += self.queries.as_slice().len();
+        total_size += self.remote_commitment_secret.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.remote_commitment_point.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.id.as_slice())?;
-        writer.write_all(self.next_time.as_slice())?;
-        writer.write_all(self.is_finished.as_slice())?;
-        writer.write_all(self.queries.as_slice())?;
+        writer.write_all(self.channel_id.as_slice())?;
+        writer.write_all(self.local_commitment_number.as_slice())?;
+        writer.write_all(self.remote_commitment_number.as_slice())?;
+        writer.write_all(self.remote_commitment_secret.as_slice())?;
+        writer.write_all(self.remote_commitment_point.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        QueryBroadcastMessagesWithinTimeRangeResult::new_unchecked(inner.into())
+        ReestablishChannelWithDataLossProtect::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
@@ -16458,7 +19035,7 @@ impl FiberMessage {
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const ITEMS_COUNT: usize = 26;
+    pub const ITEMS_COUNT: usize = 34;
     pub fn item_id(&self) -> molecule::Number {
         molecule::unpack_number(self.as_slice())
     }
@@ -16491,6 +19068,14 @@ impl FiberMessage {
             23 => QueryChannelsWithinBlockRangeResult::new_unchecked(inner).into(),
             24 => QueryBroadcastMessagesWithinTimeRange::new_unchecked(inner).into(),
             25 => QueryBroadcastMessagesWithinTimeRangeResult::new_unchecked(inner).into(),
+            26 => SpliceInit::new_unchecked(inner).into(),
+            27 => SpliceAck::new_unchecked(inner).into(),
+            28 => SpliceCommitmentSigned::new_unchecked(inner).into(),
+            29 => SpliceRevokeAndAck::new_unchecked(inner).into(),
+            30 => SpliceLocked::new_unchecked(inner).into(),
+            31 => Init::new_unchecked(inner).into(),
+            32 => CommitmentSecret::new_unchecked(inner).into(),
+            33 => ReestablishChannelWithDataLossProtect::new_unchecked(inner).into(),
             _ => panic!("{}: invalid data", Self::NAME),
         }
     }
@@ -16547,7 +19132,7 @@ impl<'r> ::core::fmt::Display for FiberMessageReader<'r> {
     }
 }
 impl<'r> FiberMessageReader<'r> {
-    pub const ITEMS_COUNT: usize = 26;
+    pub const ITEMS_COUNT: usize = 34;
     pub fn item_id(&self) -> molecule::Number {
         molecule::unpack_number(self.as_slice())
     }
@@ -16580,6 +19165,14 @@ impl<'r> FiberMessageReader<'r> {
             23 => QueryChannelsWithinBlockRangeResultReader::new_unchecked(inner).into(),
             24 => QueryBroadcastMessagesWithinTimeRangeReader::new_unchecked(inner).into(),
             25 => QueryBroadcastMessagesWithinTimeRangeResultReader::new_unchecked(inner).into(),
+            26 => SpliceInitReader::new_unchecked(inner).into(),
+            27 => SpliceAckReader::new_unchecked(inner).into(),
+            28 => SpliceCommitmentSignedReader::new_unchecked(inner).into(),
+            29 => SpliceRevokeAndAckReader::new_unchecked(inner).into(),
+            30 => SpliceLockedReader::new_unchecked(inner).into(),
+            31 => InitReader::new_unchecked(inner).into(),
+            32 => CommitmentSecretReader::new_unchecked(inner).into(),
+            33 => ReestablishChannelWithDataLossProtectReader::new_unchecked(inner).into(),
             _ => panic!("{}: invalid data", Self::NAME),
         }
     }
@@ -16633,6 +19226,14 @@ impl<'r> molecule::prelude::Reader<'r> for FiberMessageReader<'r> {
             25 => {
                 QueryBroadcastMessagesWithinTimeRangeResultReader::verify(inner_slice, compatible)
             }
+            26 => SpliceInitReader::verify(inner_slice, compatible),
+            27 => SpliceAckReader::verify(inner_slice, compatible),
+            28 => SpliceCommitmentSignedReader::verify(inner_slice, compatible),
+            29 => SpliceRevokeAndAckReader::verify(inner_slice, compatible),
+            30 => SpliceLockedReader::verify(inner_slice, compatible),
+            31 => InitReader::verify(inner_slice, compatible),
+            32 => CommitmentSecretReader::verify(inner_slice, compatible),
+            33 => ReestablishChannelWithDataLossProtectReader::verify(inner_slice, compatible),
             _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
         }?;
         Ok(())
@@ -16641,7 +19242,7 @@ impl<'r> molecule::prelude::Reader<'r> for FiberMessageReader<'r> {
 #[derive(Clone, Debug, Default)]
 pub struct FiberMessageBuilder(pub(crate) FiberMessageUnion);
 impl FiberMessageBuilder {
-    pub const ITEMS_COUNT: usize = 26;
+    pub const ITEMS_COUNT: usize = 34;
     pub fn set<I>(mut self, v: I) -> Self
     where
         I: ::core::convert::Into<FiberMessageUnion>,
@@ -16695,6 +19296,14 @@ pub enum FiberMessageUnion {
     QueryChannelsWithinBlockRangeResult(QueryChannelsWithinBlockRangeResult),
     QueryBroadcastMessagesWithinTimeRange(QueryBroadcastMessagesWithinTimeRange),
     QueryBroadcastMessagesWithinTimeRangeResult(QueryBroadcastMessagesWithinTimeRangeResult),
+    SpliceInit(SpliceInit),
+    SpliceAck(SpliceAck),
+    SpliceCommitmentSigned(SpliceCommitmentSigned),
+    SpliceRevokeAndAck(SpliceRevokeAndAck),
+    SpliceLocked(SpliceLocked),
+    Init(Init),
+    CommitmentSecret(CommitmentSecret),
+    ReestablishChannelWithDataLossProtect(ReestablishChannelWithDataLossProtect),
 }
 #[derive(Debug, Clone, Copy)]
 pub enum FiberMessageUnionReader<'r> {
@@ -16726,6 +19335,14 @@ pub enum FiberMessageUnionReader<'r> {
     QueryBroadcastMessagesWithinTimeRangeResult(
         QueryBroadcastMessagesWithinTimeRangeResultReader<'r>,
     ),
+    SpliceInit(SpliceInitReader<'r>),
+    SpliceAck(SpliceAckReader<'r>),
+    SpliceCommitmentSigned(SpliceCommitmentSignedReader<'r>),
+    SpliceRevokeAndAck(SpliceRevokeAndAckReader<'r>),
+    SpliceLocked(SpliceLockedReader<'r>),
+    Init(InitReader<'r>),
+    CommitmentSecret(CommitmentSecretReader<'r>),
+    ReestablishChannelWithDataLossProtect(ReestablishChannelWithDataLossProtectReader<'r>),
 }
 impl ::core::default::Default for FiberMessageUnion {
     fn default() -> Self {
@@ -16855,6 +19472,42 @@ impl ::core::fmt::Display for FiberMessageUnion {
                     item
                 )
             }
+            FiberMessageUnion::SpliceInit(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SpliceInit::NAME, item)
+            }
+            FiberMessageUnion::SpliceAck(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SpliceAck::NAME, item)
+            }
+            FiberMessageUnion::SpliceCommitmentSigned(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    SpliceCommitmentSigned::NAME,
+                    item
+                )
+            }
+            FiberMessageUnion::SpliceRevokeAndAck(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SpliceRevokeAndAck::NAME, item)
+            }
+            FiberMessageUnion::SpliceLocked(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SpliceLocked::NAME, item)
+            }
+            FiberMessageUnion::Init(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Init::NAME, item)
+            }
+            FiberMessageUnion::CommitmentSecret(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, CommitmentSecret::NAME, item)
+            }
+            FiberMessageUnion::ReestablishChannelWithDataLossProtect(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    ReestablishChannelWithDataLossProtect::NAME,
+                    item
+                )
+            }
         }
     }
 }
@@ -16981,6 +19634,42 @@ impl<'r> ::core::fmt::Display for FiberMessageUnionReader<'r> {
                     item
                 )
             }
+            FiberMessageUnionReader::SpliceInit(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SpliceInit::NAME, item)
+            }
+            FiberMessageUnionReader::SpliceAck(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SpliceAck::NAME, item)
+            }
+            FiberMessageUnionReader::SpliceCommitmentSigned(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    SpliceCommitmentSigned::NAME,
+                    item
+                )
+            }
+            FiberMessageUnionReader::SpliceRevokeAndAck(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SpliceRevokeAndAck::NAME, item)
+            }
+            FiberMessageUnionReader::SpliceLocked(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SpliceLocked::NAME, item)
+            }
+            FiberMessageUnionReader::Init(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Init::NAME, item)
+            }
+            FiberMessageUnionReader::CommitmentSecret(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, CommitmentSecret::NAME, item)
+            }
+            FiberMessageUnionReader::ReestablishChannelWithDataLossProtect(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    ReestablishChannelWithDataLossProtect::NAME,
+                    item
+                )
+            }
         }
     }
 }
@@ -17019,6 +19708,16 @@ impl FiberMessageUnion {
             FiberMessageUnion::QueryBroadcastMessagesWithinTimeRangeResult(ref item) => {
                 write!(f, "{}", item)
             }
+            FiberMessageUnion::SpliceInit(ref item) => write!(f, "{}", item),
+            FiberMessageUnion::SpliceAck(ref item) => write!(f, "{}", item),
+            FiberMessageUnion::SpliceCommitmentSigned(ref item) => write!(f, "{}", item),
+            FiberMessageUnion::SpliceRevokeAndAck(ref item) => write!(f, "{}", item),
+            FiberMessageUnion::SpliceLocked(ref item) => write!(f, "{}", item),
+            FiberMessageUnion::Init(ref item) => write!(f, "{}", item),
+            FiberMessageUnion::CommitmentSecret(ref item) => write!(f, "{}", item),
+            FiberMessageUnion::ReestablishChannelWithDataLossProtect(ref item) => {
+                write!(f, "{}", item)
+            }
         }
     }
 }
@@ -17059,6 +19758,16 @@ impl<'r> FiberMessageUnionReader<'r> {
             FiberMessageUnionReader::QueryBroadcastMessagesWithinTimeRangeResult(ref item) => {
                 write!(f, "{}", item)
             }
+            FiberMessageUnionReader::SpliceInit(ref item) => write!(f, "{}", item),
+            FiberMessageUnionReader::SpliceAck(ref item) => write!(f, "{}", item),
+            FiberMessageUnionReader::SpliceCommitmentSigned(ref item) => write!(f, "{}", item),
+            FiberMessageUnionReader::SpliceRevokeAndAck(ref item) => write!(f, "{}", item),
+            FiberMessageUnionReader::SpliceLocked(ref item) => write!(f, "{}", item),
+            FiberMessageUnionReader::Init(ref item) => write!(f, "{}", item),
+            FiberMessageUnionReader::CommitmentSecret(ref item) => write!(f, "{}", item),
+            FiberMessageUnionReader::ReestablishChannelWithDataLossProtect(ref item) => {
+                write!(f, "{}", item)
+            }
         }
     }
 }
@@ -17192,6 +19901,46 @@ impl ::core::convert::From<QueryBroadcastMessagesWithinTimeRangeResult> for Fibe
         FiberMessageUnion::QueryBroadcastMessagesWithinTimeRangeResult(item)
     }
 }
+impl ::core::convert::From<SpliceInit> for FiberMessageUnion {
+    fn from(item: SpliceInit) -> Self {
+        FiberMessageUnion::SpliceInit(item)
+    }
+}
+impl ::core::convert::From<SpliceAck> for FiberMessageUnion {
+    fn from(item: SpliceAck) -> Self {
+        FiberMessageUnion::SpliceAck(item)
+    }
+}
+impl ::core::convert::From<SpliceCommitmentSigned> for FiberMessageUnion {
+    fn from(item: SpliceCommitmentSigned) -> Self {
+        FiberMessageUnion::SpliceCommitmentSigned(item)
+    }
+}
+impl ::core::convert::From<SpliceRevokeAndAck> for FiberMessageUnion {
+    fn from(item: SpliceRevokeAndAck) -> Self {
+        FiberMessageUnion::SpliceRevokeAndAck(item)
+    }
+}
+impl ::core::convert::From<SpliceLocked> for FiberMessageUnion {
+    fn from(item: SpliceLocked) -> Self {
+        FiberMessageUnion::SpliceLocked(item)
+    }
+}
+impl ::core::convert::From<Init> for FiberMessageUnion {
+    fn from(item: Init) -> Self {
+        FiberMessageUnion::Init(item)
+    }
+}
+impl ::core::convert::From<CommitmentSecret> for FiberMessageUnion {
+    fn from(item: CommitmentSecret) -> Self {
+        FiberMessageUnion::CommitmentSecret(item)
+    }
+}
+impl ::core::convert::From<ReestablishChannelWithDataLossProtect> for FiberMessageUnion {
+    fn from(item: ReestablishChannelWithDataLossProtect) -> Self {
+        FiberMessageUnion::ReestablishChannelWithDataLossProtect(item)
+    }
+}
 impl<'r> ::core::convert::From<OpenChannelReader<'r>> for FiberMessageUnionReader<'r> {
     fn from(item: OpenChannelReader<'r>) -> Self {
         FiberMessageUnionReader::OpenChannel(item)
@@ -17332,6 +20081,48 @@ impl<'r> ::core::convert::From<QueryBroadcastMessagesWithinTimeRangeResultReader
         FiberMessageUnionReader::QueryBroadcastMessagesWithinTimeRangeResult(item)
     }
 }
+impl<'r> ::core::convert::From<SpliceInitReader<'r>> for FiberMessageUnionReader<'r> {
+    fn from(item: SpliceInitReader<'r>) -> Self {
+        FiberMessageUnionReader::SpliceInit(item)
+    }
+}
+impl<'r> ::core::convert::From<SpliceAckReader<'r>> for FiberMessageUnionReader<'r> {
+    fn from(item: SpliceAckReader<'r>) -> Self {
+        FiberMessageUnionReader::SpliceAck(item)
+    }
+}
+impl<'r> ::core::convert::From<SpliceCommitmentSignedReader<'r>> for FiberMessageUnionReader<'r> {
+    fn from(item: SpliceCommitmentSignedReader<'r>) -> Self {
+        FiberMessageUnionReader::SpliceCommitmentSigned(item)
+    }
+}
+impl<'r> ::core::convert::From<SpliceRevokeAndAckReader<'r>> for FiberMessageUnionReader<'r> {
+    fn from(item: SpliceRevokeAndAckReader<'r>) -> Self {
+        FiberMessageUnionReader::SpliceRevokeAndAck(item)
+    }
+}
+impl<'r> ::core::convert::From<SpliceLockedReader<'r>> for FiberMessageUnionReader<'r> {
+    fn from(item: SpliceLockedReader<'r>) -> Self {
+        FiberMessageUnionReader::SpliceLocked(item)
+    }
+}
+impl<'r> ::core::convert::From<InitReader<'r>> for FiberMessageUnionReader<'r> {
+    fn from(item: InitReader<'r>) -> Self {
+        FiberMessageUnionReader::Init(item)
+    }
+}
+impl<'r> ::core::convert::From<CommitmentSecretReader<'r>> for FiberMessageUnionReader<'r> {
+    fn from(item: CommitmentSecretReader<'r>) -> Self {
+        FiberMessageUnionReader::CommitmentSecret(item)
+    }
+}
+impl<'r> ::core::convert::From<ReestablishChannelWithDataLossProtectReader<'r>>
+    for FiberMessageUnionReader<'r>
+{
+    fn from(item: ReestablishChannelWithDataLossProtectReader<'r>) -> Self {
+        FiberMessageUnionReader::ReestablishChannelWithDataLossProtect(item)
+    }
+}
 impl FiberMessageUnion {
     pub const NAME: &'static str = "FiberMessageUnion";
     pub fn as_bytes(&self) -> molecule::bytes::Bytes {
@@ -17362,6 +20153,14 @@ impl FiberMessageUnion {
             FiberMessageUnion::QueryChannelsWithinBlockRangeResult(item) => item.as_bytes(),
             FiberMessageUnion::QueryBroadcastMessagesWithinTimeRange(item) => item.as_bytes(),
             FiberMessageUnion::QueryBroadcastMessagesWithinTimeRangeResult(item) => item.as_bytes(),
+            FiberMessageUnion::SpliceInit(item) => item.as_bytes(),
+            FiberMessageUnion::SpliceAck(item) => item.as_bytes(),
+            FiberMessageUnion::SpliceCommitmentSigned(item) => item.as_bytes(),
+            FiberMessageUnion::SpliceRevokeAndAck(item) => item.as_bytes(),
+            FiberMessageUnion::SpliceLocked(item) => item.as_bytes(),
+            FiberMessageUnion::Init(item) => item.as_bytes(),
+            FiberMessageUnion::CommitmentSecret(item) => item.as_bytes(),
+            FiberMessageUnion::ReestablishChannelWithDataLossProtect(item) => item.as_bytes(),
         }
     }
     pub fn as_slice(&self) -> &[u8] {
@@ -17392,6 +20191,14 @@ impl FiberMessageUnion {
             FiberMessageUnion::QueryChannelsWithinBlockRangeResult(item) => item.as_slice(),
             FiberMessageUnion::QueryBroadcastMessagesWithinTimeRange(item) => item.as_slice(),
             FiberMessageUnion::QueryBroadcastMessagesWithinTimeRangeResult(item) => item.as_slice(),
+            FiberMessageUnion::SpliceInit(item) => item.as_slice(),
+            FiberMessageUnion::SpliceAck(item) => item.as_slice(),
+            FiberMessageUnion::SpliceCommitmentSigned(item) => item.as_slice(),
+            FiberMessageUnion::SpliceRevokeAndAck(item) => item.as_slice(),
+            FiberMessageUnion::SpliceLocked(item) => item.as_slice(),
+            FiberMessageUnion::Init(item) => item.as_slice(),
+            FiberMessageUnion::CommitmentSecret(item) => item.as_slice(),
+            FiberMessageUnion::ReestablishChannelWithDataLossProtect(item) => item.as_slice(),
         }
     }
     pub fn item_id(&self) -> molecule::Number {
@@ -17422,6 +20229,14 @@ impl FiberMessageUnion {
             FiberMessageUnion::QueryChannelsWithinBlockRangeResult(_) => 23,
             FiberMessageUnion::QueryBroadcastMessagesWithinTimeRange(_) => 24,
             FiberMessageUnion::QueryBroadcastMessagesWithinTimeRangeResult(_) => 25,
+            FiberMessageUnion::SpliceInit(_) => 26,
+            FiberMessageUnion::SpliceAck(_) => 27,
+            FiberMessageUnion::SpliceCommitmentSigned(_) => 28,
+            FiberMessageUnion::SpliceRevokeAndAck(_) => 29,
+            FiberMessageUnion::SpliceLocked(_) => 30,
+            FiberMessageUnion::Init(_) => 31,
+            FiberMessageUnion::CommitmentSecret(_) => 32,
+            FiberMessageUnion::ReestablishChannelWithDataLossProtect(_) => 33,
         }
     }
     pub fn item_name(&self) -> &str {
@@ -17458,6 +20273,16 @@ impl FiberMessageUnion {
             FiberMessageUnion::QueryBroadcastMessagesWithinTimeRangeResult(_) => {
                 "QueryBroadcastMessagesWithinTimeRangeResult"
             }
+            FiberMessageUnion::SpliceInit(_) => "SpliceInit",
+            FiberMessageUnion::SpliceAck(_) => "SpliceAck",
+            FiberMessageUnion::SpliceCommitmentSigned(_) => "SpliceCommitmentSigned",
+            FiberMessageUnion::SpliceRevokeAndAck(_) => "SpliceRevokeAndAck",
+            FiberMessageUnion::SpliceLocked(_) => "SpliceLocked",
+            FiberMessageUnion::Init(_) => "Init",
+            FiberMessageUnion::CommitmentSecret(_) => "CommitmentSecret",
+            FiberMessageUnion::ReestablishChannelWithDataLossProtect(_) => {
+                "ReestablishChannelWithDataLossProtect"
+            }
         }
     }
     pub fn as_reader<'r>(&'r self) -> FiberMessageUnionReader<'r> {
@@ -17492,6 +20317,16 @@ impl FiberMessageUnion {
             FiberMessageUnion::QueryBroadcastMessagesWithinTimeRangeResult(item) => {
                 item.as_reader().into()
             }
+            FiberMessageUnion::SpliceInit(item) => item.as_reader().into(),
+            FiberMessageUnion::SpliceAck(item) => item.as_reader().into(),
+            FiberMessageUnion::SpliceCommitmentSigned(item) => item.as_reader().into(),
+            FiberMessageUnion::SpliceRevokeAndAck(item) => item.as_reader().into(),
+            FiberMessageUnion::SpliceLocked(item) => item.as_reader().into(),
+            FiberMessageUnion::Init(item) => item.as_reader().into(),
+            FiberMessageUnion::CommitmentSecret(item) => item.as_reader().into(),
+            FiberMessageUnion::ReestablishChannelWithDataLossProtect(item) => {
+                item.as_reader().into()
+            }
         }
     }
 }
@@ -17527,6 +20362,14 @@ impl<'r> FiberMessageUnionReader<'r> {
             FiberMessageUnionReader::QueryBroadcastMessagesWithinTimeRangeResult(item) => {
                 item.as_slice()
             }
+            FiberMessageUnionReader::SpliceInit(item) => item.as_slice(),
+            FiberMessageUnionReader::SpliceAck(item) => item.as_slice(),
+            FiberMessageUnionReader::SpliceCommitmentSigned(item) => item.as_slice(),
+            FiberMessageUnionReader::SpliceRevokeAndAck(item) => item.as_slice(),
+            FiberMessageUnionReader::SpliceLocked(item) => item.as_slice(),
+            FiberMessageUnionReader::Init(item) => item.as_slice(),
+            FiberMessageUnionReader::CommitmentSecret(item) => item.as_slice(),
+            FiberMessageUnionReader::ReestablishChannelWithDataLossProtect(item) => item.as_slice(),
         }
     }
     pub fn item_id(&self) -> molecule::Number {
@@ -17557,6 +20400,14 @@ impl<'r> FiberMessageUnionReader<'r> {
             FiberMessageUnionReader::QueryChannelsWithinBlockRangeResult(_) => 23,
             FiberMessageUnionReader::QueryBroadcastMessagesWithinTimeRange(_) => 24,
             FiberMessageUnionReader::QueryBroadcastMessagesWithinTimeRangeResult(_) => 25,
+            FiberMessageUnionReader::SpliceInit(_) => 26,
+            FiberMessageUnionReader::SpliceAck(_) => 27,
+            FiberMessageUnionReader::SpliceCommitmentSigned(_) => 28,
+            FiberMessageUnionReader::SpliceRevokeAndAck(_) => 29,
+            FiberMessageUnionReader::SpliceLocked(_) => 30,
+            FiberMessageUnionReader::Init(_) => 31,
+            FiberMessageUnionReader::CommitmentSecret(_) => 32,
+            FiberMessageUnionReader::ReestablishChannelWithDataLossProtect(_) => 33,
         }
     }
     pub fn item_name(&self) -> &str {
@@ -17595,6 +20446,16 @@ impl<'r> FiberMessageUnionReader<'r> {
             FiberMessageUnionReader::QueryBroadcastMessagesWithinTimeRangeResult(_) => {
                 "QueryBroadcastMessagesWithinTimeRangeResult"
             }
+            FiberMessageUnionReader::SpliceInit(_) => "SpliceInit",
+            FiberMessageUnionReader::SpliceAck(_) => "SpliceAck",
+            FiberMessageUnionReader::SpliceCommitmentSigned(_) => "SpliceCommitmentSigned",
+            FiberMessageUnionReader::SpliceRevokeAndAck(_) => "SpliceRevokeAndAck",
+            FiberMessageUnionReader::SpliceLocked(_) => "SpliceLocked",
+            FiberMessageUnionReader::Init(_) => "Init",
+            FiberMessageUnionReader::CommitmentSecret(_) => "CommitmentSecret",
+            FiberMessageUnionReader::ReestablishChannelWithDataLossProtect(_) => {
+                "ReestablishChannelWithDataLossProtect"
+            }
         }
     }
 }
@@ -17728,3 +20589,43 @@ impl From<QueryBroadcastMessagesWithinTimeRangeResult> for FiberMessage {
         Self::new_builder().set(value).build()
     }
 }
+impl From<SpliceInit> for FiberMessage {
+    fn from(value: SpliceInit) -> Self {
+        Self::new_builder().set(value).build()
+    }
+}
+impl From<SpliceAck> for FiberMessage {
+    fn from(value: SpliceAck) -> Self {
+        Self::new_builder().set(value).build()
+    }
+}
+impl From<SpliceCommitmentSigned> for FiberMessage {
+    fn from(value: SpliceCommitmentSigned) -> Self {
+        Self::new_builder().set(value).build()
+    }
+}
+impl From<SpliceRevokeAndAck> for FiberMessage {
+    fn from(value: SpliceRevokeAndAck) -> Self {
+        Self::new_builder().set(value).build()
+    }
+}
+impl From<SpliceLocked> for FiberMessage {
+    fn from(value: SpliceLocked) -> Self {
+        Self::new_builder().set(value).build()
+    }
+}
+impl From<Init> for FiberMessage {
+    fn from(value: Init) -> Self {
+        Self::new_builder().set(value).build()
+    }
+}
+impl From<CommitmentSecret> for FiberMessage {
+    fn from(value: CommitmentSecret) -> Self {
+        Self::new_builder().set(value).build()
+    }
+}
+impl From<ReestablishChannelWithDataLossProtect> for FiberMessage {
+    fn from(value: ReestablishChannelWithDataLossProtect) -> Self {
+        Self::new_builder().set(value).build()
+    }
+}
@@ -0,0 +1,312 @@
+use std::collections::HashMap;
+
+use ckb_types::packed::OutPoint;
+use tentacle::secio::PeerId;
+
+use super::types::{FiberBroadcastMessage, Pubkey};
+
+// The token buckets of each broadcast message type, the first value is the number of messages
+// allowed in a burst, the second value is the interval in milliseconds to refill one token.
+// A node reannounces itself every hour by default, and a channel is only announced once.
+const NODE_ANNOUNCEMENT_BUCKET: (u64, u64) = (5, 10 * 60 * 1000);
+const CHANNEL_ANNOUNCEMENT_BUCKET: (u64, u64) = (2, 60 * 60 * 1000);
+const CHANNEL_UPDATE_BUCKET: (u64, u64) = (10, 60 * 1000);
+
+// Full buckets carry no information, they are removed once we track too many origins.
+const MAX_TRACKED_ORIGINS: usize = 100_000;
+
+/// The maximal number of broadcast messages stashed while we are syncing the network graph.
+pub const MAX_STASHED_BROADCAST_MESSAGES: usize = 10_000;
+/// The maximal number of broadcast messages stashed from a single peer.
+pub const MAX_STASHED_BROADCAST_MESSAGES_PER_PEER: usize = 1_000;
+
+// A peer is banned once its penalty reaches this score, the penalty decreases by one point
+// every PENALTY_DECAY_INTERVAL milliseconds.
+const BAN_SCORE_THRESHOLD: u64 = 100;
+const PENALTY_DECAY_INTERVAL: u64 = 60 * 1000;
+const BAN_DURATION: u64 = 60 * 60 * 1000;
+
+/// The origin of a broadcast message, each origin has its own token bucket.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum GossipOrigin {
+    Node(Pubkey),
+    Channel(OutPoint),
+    // The channel update is sent by either side of the channel, the bool is the direction.
+    ChannelDirection(OutPoint, bool),
+}
+
+impl GossipOrigin {
+    fn new(message: &FiberBroadcastMessage) -> Self {
+        match message {
+            FiberBroadcastMessage::NodeAnnouncement(node_announcement) => {
+                GossipOrigin::Node(node_announcement.node_id)
+            }
+            FiberBroadcastMessage::ChannelAnnouncement(channel_announcement) => {
+                GossipOrigin::Channel(channel_announcement.channel_outpoint.clone())
+            }
+            FiberBroadcastMessage::ChannelUpdate(channel_update) => GossipOrigin::ChannelDirection(
+                channel_update.channel_outpoint.clone(),
+                channel_update.message_flags & 1 == 1,
+            ),
+        }
+    }
+
+    fn bucket_config(&self) -> (u64, u64) {
+        match self {
+            GossipOrigin::Node(_) => NODE_ANNOUNCEMENT_BUCKET,
+            GossipOrigin::Channel(_) => CHANNEL_ANNOUNCEMENT_BUCKET,
+            GossipOrigin::ChannelDirection(_, _) => CHANNEL_UPDATE_BUCKET,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct TokenBucket {
+    capacity: u64,
+    refill_interval: u64,
+    tokens: u64,
+    last_refill: u64,
+}
+
+impl TokenBucket {
+    fn new((capacity, refill_interval): (u64, u64), now: u64) -> Self {
+        Self {
+            capacity,
+            refill_interval,
+            tokens: capacity,
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, now: u64) {
+        let refilled = now.saturating_sub(self.last_refill) / self.refill_interval;
+        if refilled > 0 {
+            self.tokens = self.capacity.min(self.tokens.saturating_add(refilled));
+            self.last_refill += refilled * self.refill_interval;
+        }
+        if self.tokens == self.capacity {
+            self.last_refill = now;
+        }
+    }
+
+    fn has_tokens(&mut self, now: u64) -> bool {
+        self.refill(now);
+        self.tokens > 0
+    }
+
+    fn consume(&mut self, now: u64) {
+        self.refill(now);
+        self.tokens = self.tokens.saturating_sub(1);
+    }
+
+    fn is_full(&mut self, now: u64) -> bool {
+        self.refill(now);
+        self.tokens == self.capacity
+    }
+}
+
+/// Limits how often the broadcast messages of the same origin are processed, so a node can't
+/// flood the network with `NodeAnnouncement`s or `ChannelUpdate`s of ever-increasing versions.
+#[derive(Debug, Default)]
+pub struct GossipRateLimiter {
+    buckets: HashMap<GossipOrigin, TokenBucket>,
+}
+
+impl GossipRateLimiter {
+    /// Returns false if the origin of the message has exceeded its rate limit. No token is
+    /// consumed, as anyone can forge a message of any origin before it is verified.
+    /// `now` is the current time in milliseconds.
+    pub fn check(&mut self, message: &FiberBroadcastMessage, now: u64) -> bool {
+        self.buckets
+            .get_mut(&GossipOrigin::new(message))
+            .map_or(true, |bucket| bucket.has_tokens(now))
+    }
+
+    /// Consumes a token of the origin of the message, which must have been verified.
+    pub fn consume(&mut self, message: &FiberBroadcastMessage, now: u64) {
+        if self.buckets.len() >= MAX_TRACKED_ORIGINS {
+            self.buckets.retain(|_, bucket| !bucket.is_full(now));
+        }
+        let origin = GossipOrigin::new(message);
+        self.buckets
+            .entry(origin.clone())
+            .or_insert_with(|| TokenBucket::new(origin.bucket_config(), now))
+            .consume(now);
+    }
+}
+
+/// Why a peer is penalized for the gossip messages it sent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GossipPenalty {
+    // The message is malformed or not correctly signed.
+    Invalid,
+    // The message can't be processed now, e.g. the funding transaction is not found.
+    Unprocessable,
+}
+
+impl GossipPenalty {
+    fn score(&self) -> u64 {
+        match self {
+            GossipPenalty::Invalid => 20,
+            GossipPenalty::Unprocessable => 5,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct PeerScore {
+    penalty: u64,
+    last_decay: u64,
+}
+
+impl PeerScore {
+    fn decay(&mut self, now: u64) {
+        let decayed = now.saturating_sub(self.last_decay) / PENALTY_DECAY_INTERVAL;
+        self.penalty = self.penalty.saturating_sub(decayed);
+        self.last_decay += decayed * PENALTY_DECAY_INTERVAL;
+        if self.penalty == 0 {
+            self.last_decay = now;
+        }
+    }
+}
+
+/// Scores the peers by the gossip messages they sent, peers keeping sending invalid messages
+/// are banned for a while. Messages exceeding the rate limits are not penalized, as the peers
+/// may just relay them for other origins.
+#[derive(Debug, Default)]
+pub struct PeerScores {
+    scores: HashMap<PeerId, PeerScore>,
+    // The banned peers and the time in milliseconds until which they are banned.
+    banned_peers: HashMap<PeerId, u64>,
+}
+
+impl PeerScores {
+    /// Penalizes the peer, returns true if the peer is banned from now on.
+    pub fn penalize(&mut self, peer_id: &PeerId, penalty: GossipPenalty, now: u64) -> bool {
+        let score = self
+            .scores
+            .entry(peer_id.clone())
+            .or_insert_with(|| PeerScore {
+                penalty: 0,
+                last_decay: now,
+            });
+        score.decay(now);
+        score.penalty += penalty.score();
+        if score.penalty < BAN_SCORE_THRESHOLD {
+            return false;
+        }
+        self.scores.remove(peer_id);
+        self.ban(peer_id, now + BAN_DURATION);
+        true
+    }
+
+    /// Bans the peer until `until` in milliseconds.
+    pub fn ban(&mut self, peer_id: &PeerId, until: u64) {
+        self.banned_peers.insert(peer_id.clone(), until);
+    }
+
+    /// Unbans the peer, returns false if the peer is not banned.
+    pub fn unban(&mut self, peer_id: &PeerId) -> bool {
+        self.banned_peers.remove(peer_id).is_some()
+    }
+
+    /// Returns the time until which the peer is banned, the ban may have already expired.
+    pub fn banned_until(&self, peer_id: &PeerId) -> Option<u64> {
+        self.banned_peers.get(peer_id).copied()
+    }
+
+    pub fn is_banned(&self, peer_id: &PeerId, now: u64) -> bool {
+        self.banned_until(peer_id).is_some_and(|until| until > now)
+    }
+
+    pub fn get_banned_peers(&self, now: u64) -> Vec<(PeerId, u64)> {
+        self.banned_peers
+            .iter()
+            .filter(|(_, until)| **until > now)
+            .map(|(peer_id, until)| (peer_id.clone(), *until))
+            .collect()
+    }
+}
+
+/// Counters of the broadcast messages dropped by the spam protection.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GossipMetrics {
+    /// Messages whose origins exceeded the rate limits.
+    pub rate_limited: u64,
+    /// Messages which are malformed, not correctly signed or can't be processed.
+    pub invalid: u64,
+    /// Messages dropped because the stash of messages received while syncing is full.
+    pub stash_full: u64,
+    /// Messages received from the banned peers.
+    pub from_banned_peers: u64,
+    /// The number of peers banned right now.
+    pub banned_peers: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fiber::types::{NodeAnnouncement, Privkey};
+
+    fn node_announcement(privkey: &Privkey, version: u64) -> FiberBroadcastMessage {
+        FiberBroadcastMessage::NodeAnnouncement(NodeAnnouncement::new(
+            "node".into(),
+            vec![],
+            privkey,
+            version,
+        ))
+    }
+
+    #[test]
+    fn test_gossip_rate_limiter() {
+        let mut limiter = GossipRateLimiter::default();
+        let privkey1 = Privkey::from([1u8; 32]);
+        let privkey2 = Privkey::from([2u8; 32]);
+        let (burst, refill_interval) = NODE_ANNOUNCEMENT_BUCKET;
+        let now = 1_000_000;
+
+        for version in 0..burst {
+            let message = node_announcement(&privkey1, version);
+            assert!(limiter.check(&message, now));
+            // Checking the rate limit alone doesn't consume any token.
+            assert!(limiter.check(&message, now));
+            limiter.consume(&message, now);
+        }
+        assert!(!limiter.check(&node_announcement(&privkey1, burst), now));
+        // Other origins are not affected.
+        assert!(limiter.check(&node_announcement(&privkey2, 0), now));
+
+        // One token is refilled after the interval.
+        let now = now + refill_interval;
+        assert!(limiter.check(&node_announcement(&privkey1, burst), now));
+        limiter.consume(&node_announcement(&privkey1, burst), now);
+        assert!(!limiter.check(&node_announcement(&privkey1, burst + 1), now));
+    }
+
+    #[test]
+    fn test_peer_scores_ban_and_decay() {
+        let mut scores = PeerScores::default();
+        let peer_id = PeerId::random();
+        let now = 1_000_000;
+
+        for _ in 0..BAN_SCORE_THRESHOLD / GossipPenalty::Invalid.score() - 1 {
+            assert!(!scores.penalize(&peer_id, GossipPenalty::Invalid, now));
+        }
+        // The penalty decays over time.
+        let later = now + PENALTY_DECAY_INTERVAL * GossipPenalty::Invalid.score();
+        assert!(!scores.penalize(&peer_id, GossipPenalty::Invalid, later));
+        assert!(!scores.is_banned(&peer_id, later));
+
+        assert!(scores.penalize(&peer_id, GossipPenalty::Invalid, later));
+        assert!(scores.is_banned(&peer_id, later));
+        assert_eq!(scores.get_banned_peers(later).len(), 1);
+
+        // The score is reset once the peer is banned.
+        assert!(!scores.penalize(&peer_id, GossipPenalty::Invalid, later));
+
+        assert!(!scores.is_banned(&peer_id, later + BAN_DURATION));
+        assert!(scores.get_banned_peers(later + BAN_DURATION).is_empty());
+        assert!(scores.unban(&peer_id));
+        assert!(!scores.unban(&peer_id));
+    }
+}
@@ -1,15 +1,17 @@
-use super::network::{get_chain_hash, SendPaymentCommand};
+use super::history::{PaymentHistory, TimedResult};
+use super::network::{get_chain_hash, SendPaymentData};
 use super::path::NodeHeap;
+use super::serde_utils::EntityHex;
 use super::types::Pubkey;
 use super::types::{ChannelAnnouncement, ChannelUpdate, Hash256, NodeAnnouncement};
-use crate::fiber::path::{NodeHeapElement, ProbabilityEvaluator};
-use crate::fiber::types::PaymentHopData;
-use crate::invoice::CkbInvoice;
+use crate::fiber::path::NodeHeapElement;
+use crate::fiber::types::{PaymentHopData, TlcErr};
+use crate::invoice::{CkbInvoice, RouteHintHop};
 use ckb_jsonrpc_types::JsonBytes;
 use ckb_types::packed::{OutPoint, Script};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use tentacle::multiaddr::Multiaddr;
 use tentacle::secio::PeerId;
 use thiserror::Error;
@@ -18,6 +20,10 @@ use tracing::{debug, info, warn};
 
 const DEFAULT_MIN_PROBABILITY: f64 = 0.01;
 
+// The capacity of the private channels in route hints is unknown, we assume they are large enough,
+// the payee will fail the tlc if it is not.
+const ROUTE_HINT_CHANNEL_CAPACITY: u128 = u64::MAX as u128;
+
 #[serde_as]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 /// Details about a node in the network, known from the network announcement.
@@ -93,6 +99,67 @@ impl ChannelInfo {
     pub fn funding_tx_block_number(&self) -> u64 {
         self.funding_tx_block_number
     }
+
+    // Creates a private channel from the route hint hop, from `hop.pubkey` to `next_node`.
+    fn from_route_hint_hop(
+        hop: &RouteHintHop,
+        next_node: Pubkey,
+        udt_type_script: Option<Script>,
+    ) -> Self {
+        let chain_hash = get_chain_hash();
+        let (ckb_key, _) = hop.pubkey.x_only_public_key();
+        Self {
+            funding_tx_block_number: 0,
+            funding_tx_index: 0,
+            announcement_msg: ChannelAnnouncement::new_unsigned(
+                &hop.pubkey.into(),
+                &next_node,
+                hop.channel_outpoint.clone(),
+                chain_hash,
+                &ckb_key,
+                ROUTE_HINT_CHANNEL_CAPACITY,
+                udt_type_script,
+            ),
+            node1_to_node2: Some(ChannelUpdateInfo {
+                version: 0,
+                timestamp: 0,
+                enabled: true,
+                cltv_expiry_delta: hop.tlc_expiry_delta,
+                htlc_minimum_value: 0,
+                htlc_maximum_value: 0,
+                fee_rate: hop.fee_rate,
+                last_update_message: ChannelUpdate::new_unsigned(
+                    chain_hash,
+                    hop.channel_outpoint.clone(),
+                    0,
+                    0,
+                    0,
+                    hop.tlc_expiry_delta,
+                    0,
+                    0,
+                    hop.fee_rate as u128,
+                ),
+            }),
+            node2_to_node1: None,
+            timestamp: 0,
+        }
+    }
+
+    // Returns the node sending tlcs to `node_id` through this channel and the channel update
+    // of that direction, if the channel is enabled in that direction.
+    fn inbound_to(&self, node_id: Pubkey) -> Option<(Pubkey, &ChannelUpdateInfo)> {
+        if let Some(info) = self.node1_to_node2.as_ref() {
+            if info.enabled && self.node2() == node_id {
+                return Some((self.node1(), info));
+            }
+        }
+        if let Some(info) = self.node2_to_node1.as_ref() {
+            if info.enabled && self.node1() == node_id {
+                return Some((self.node2(), info));
+            }
+        }
+        None
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -129,11 +196,14 @@ pub struct NetworkGraph<S> {
     // Similar to the best_height, this is the last update time of the network graph.
     // We assume that we have already synced the graph up to this time - ASSUME_MAX_MESSAGE_TIMESTAMP_GAP.
     last_update_timestamp: u64,
-    // when we restarting a node, we will reconnect to these peers
-    connected_peer_addresses: HashMap<PeerId, Multiaddr>,
     nodes: HashMap<Pubkey, NodeInfo>,
+    // The channels pruned for being spent or stale, their announcements are rejected afterwards,
+    // so that they are not added back by graph syncing or rebroadcasting.
+    pruned_channels: HashSet<OutPoint>,
     store: S,
     chain_hash: Hash256,
+    // The results of the previous payments, used to estimate the probability of the channels.
+    history: PaymentHistory<S>,
 }
 
 #[derive(Error, Debug)]
@@ -163,7 +233,8 @@ where
             last_update_timestamp: 0,
             channels: HashMap::new(),
             nodes: HashMap::new(),
-            connected_peer_addresses: HashMap::new(),
+            pruned_channels: HashSet::new(),
+            history: PaymentHistory::new(store.clone()),
             store,
             chain_hash: get_chain_hash(),
         };
@@ -177,7 +248,11 @@ where
 
     fn load_from_store(&mut self) {
         let channels = self.store.get_channels(None);
-        for channel in channels.iter() {
+        // The messages of another chain are dropped, they are synced again from the peers.
+        for channel in channels
+            .iter()
+            .filter(|channel| self.check_chain_hash(channel.announcement_msg.chain_hash))
+        {
             if self.best_height < channel.funding_tx_block_number() {
                 self.best_height = channel.funding_tx_block_number();
             }
@@ -197,15 +272,15 @@ where
             self.channels.insert(channel.out_point(), channel.clone());
         }
         let nodes = self.store.get_nodes(None);
-        for node in nodes.iter() {
+        for node in nodes
+            .iter()
+            .filter(|node| self.check_chain_hash(node.anouncement_msg.chain_hash))
+        {
             if self.last_update_timestamp < node.timestamp {
                 self.last_update_timestamp = node.timestamp;
             }
             self.nodes.insert(node.node_id, node.clone());
         }
-        for (peer, addr) in self.store.get_connected_peer(None) {
-            self.connected_peer_addresses.insert(peer, addr);
-        }
     }
 
     pub fn get_best_height(&self) -> u64 {
@@ -244,7 +319,7 @@ where
     // not process channels here. Because if the node may restart while syncing is
     // is still ongoing, the next time when the node starts, it may falsely believe
     // that we have already processed channels before the height of this channel.
-    pub fn add_channel(&mut self, channel_info: ChannelInfo) {
+    pub fn add_channel(&mut self, mut channel_info: ChannelInfo) {
         assert_ne!(channel_info.node1(), channel_info.node2());
         debug!("Adding channel to network graph: {:?}", channel_info);
         if self.best_height < channel_info.funding_tx_block_number {
@@ -254,9 +329,15 @@ where
             self.last_update_timestamp = channel_info.timestamp;
         }
         match self.channels.get(&channel_info.out_point()) {
+            // The announcement of a spliced channel is replaced by the one signed for the new
+            // funding cell, while the channel updates are kept.
+            Some(channel) if channel.announcement_msg != channel_info.announcement_msg => {
+                debug!("Channel is announced again: {:?}", &channel_info);
+                channel_info.node1_to_node2 = channel.node1_to_node2.clone();
+                channel_info.node2_to_node1 = channel.node2_to_node1.clone();
+            }
             Some(channel) => {
                 // If the channel already exists, we don't need to update it
-                // FIXME: if other fields is different, we should consider it as malioucious and ban the node?
                 if channel.node1_to_node2.is_some() || channel.node2_to_node1.is_some() {
                     debug!("channel already exists, ignoring: {:?}", &channel_info);
                     return;
@@ -288,6 +369,67 @@ where
         debug!("Successfully added channel {:?}", outpoint);
     }
 
+    pub fn remove_channel(&mut self, outpoint: &OutPoint) -> Option<ChannelInfo> {
+        debug!("Removing channel from network graph: {:?}", outpoint);
+        self.store.delete_channel(outpoint);
+        self.channels.remove(outpoint)
+    }
+
+    /// Moves a spliced channel to its new funding cell with the new capacity in place, so that
+    /// the channel stays routable until the announcement of the new funding cell is received.
+    pub fn splice_channel(
+        &mut self,
+        old_outpoint: &OutPoint,
+        new_outpoint: OutPoint,
+        capacity: u128,
+    ) {
+        let Some(mut channel) = self.channels.remove(old_outpoint) else {
+            return;
+        };
+        debug!(
+            "Splicing channel in network graph from {:?} to {:?} with capacity {}",
+            old_outpoint, &new_outpoint, capacity
+        );
+        self.store.delete_channel(old_outpoint);
+        channel.announcement_msg.channel_outpoint = new_outpoint.clone();
+        channel.announcement_msg.capacity = capacity;
+        self.store.insert_channel(channel.clone());
+        self.channels.insert(new_outpoint, channel);
+    }
+
+    /// Removes the channel and remembers it, so that the channel is never added back.
+    pub fn prune_channel(&mut self, outpoint: &OutPoint) -> Option<ChannelInfo> {
+        self.pruned_channels.insert(outpoint.clone());
+        self.remove_channel(outpoint)
+    }
+
+    pub fn is_channel_pruned(&self, outpoint: &OutPoint) -> bool {
+        self.pruned_channels.contains(outpoint)
+    }
+
+    // Removes the channels which have not been updated since `stale_before` (in milliseconds),
+    // channels without any ChannelUpdate are judged by the time they were announced.
+    // Our own channels are kept, their liveness is known from the channel actors.
+    pub fn prune_stale_channels(&mut self, stale_before: u64) -> Vec<OutPoint> {
+        let stale_channels: Vec<OutPoint> = self
+            .channels
+            .values()
+            .filter(|channel| channel.node1() != self.source && channel.node2() != self.source)
+            .filter(|channel| {
+                channel
+                    .channel_last_update_time()
+                    .unwrap_or(channel.timestamp)
+                    < stale_before
+            })
+            .map(|channel| channel.out_point())
+            .collect();
+        for outpoint in stale_channels.iter() {
+            info!("Pruning stale channel from network graph: {:?}", outpoint);
+            self.prune_channel(outpoint);
+        }
+        stale_channels
+    }
+
     pub fn nodes(&self) -> impl Iterator<Item = &NodeInfo> {
         self.nodes.values()
     }
@@ -397,42 +539,14 @@ where
         self.chain_hash == chain_hash
     }
 
-    pub fn add_connected_peer(&mut self, peer_id: &PeerId, address: Multiaddr) {
-        self.connected_peer_addresses
-            .insert(peer_id.clone(), address.clone());
-        self.store.insert_connected_peer(peer_id.clone(), address);
-    }
-
-    pub fn get_connected_peers(&self) -> Vec<(&PeerId, &Multiaddr)> {
-        self.connected_peer_addresses.iter().collect()
-    }
-
-    pub fn get_peers_to_sync_network_graph(&self) -> Vec<(&PeerId, &Multiaddr)> {
-        self.connected_peer_addresses.iter().take(3).collect()
-    }
-
-    pub fn remove_connected_peer(&mut self, peer_id: &PeerId) {
-        self.connected_peer_addresses.remove(peer_id);
-        self.store.remove_connected_peer(peer_id);
-    }
-
     pub fn get_node_inbounds(
         &self,
         node_id: Pubkey,
     ) -> impl Iterator<Item = (Pubkey, &ChannelInfo, &ChannelUpdateInfo)> {
         self.channels.values().filter_map(move |channel| {
-            if let Some(info) = channel.node1_to_node2.as_ref() {
-                if info.enabled && channel.node2() == node_id {
-                    return Some((channel.node1(), channel, info));
-                }
-            }
-
-            if let Some(info) = channel.node2_to_node1.as_ref() {
-                if info.enabled && channel.node1() == node_id {
-                    return Some((channel.node2(), channel, info));
-                }
-            }
-            None
+            channel
+                .inbound_to(node_id)
+                .map(|(from, info)| (from, channel, info))
         })
     }
 
@@ -455,38 +569,136 @@ where
     pub fn reset(&mut self) {
         self.channels.clear();
         self.nodes.clear();
-        self.connected_peer_addresses.clear();
     }
 
-    pub fn init_payment_session(&self, payment_request: SendPaymentCommand) -> PaymentSession {
-        let payment_session = PaymentSession::new(payment_request, 3);
-        if let Some(session) = self
-            .store
-            .get_payment_session(payment_session.payment_hash())
-        {
-            return session;
-        } else {
-            self.store.insert_payment_session(payment_session.clone());
-            payment_session
+    /// Returns a list of `PaymentHopData` for all nodes in the route, including the origin and the target node.
+    /// Channels in `excluded_channels` will not be used in the route.
+    pub fn build_route(
+        &self,
+        payment_data: &SendPaymentData,
+        excluded_channels: &HashSet<OutPoint>,
+    ) -> Result<Vec<PaymentHopData>, GraphError> {
+        self.build_part_route(
+            payment_data,
+            payment_data.amount,
+            payment_data.max_fee_amount,
+            excluded_channels,
+            &HashMap::new(),
+        )
+    }
+
+    /// Returns at most `max_routes` candidate routes for the payment, ordered from the best one.
+    /// The routes do not share any channel, each one is found after excluding the channels of
+    /// the previous ones. Returns an error only if no route is found at all.
+    pub fn build_candidate_routes(
+        &self,
+        payment_data: &SendPaymentData,
+        max_routes: usize,
+    ) -> Result<Vec<Vec<PaymentHopData>>, GraphError> {
+        let mut routes = vec![];
+        let mut excluded_channels = HashSet::new();
+        while routes.len() < max_routes {
+            match self.build_route(payment_data, &excluded_channels) {
+                Ok(route) => {
+                    excluded_channels
+                        .extend(route.iter().filter_map(|hop| hop.channel_outpoint.clone()));
+                    routes.push(route);
+                }
+                Err(err) if routes.is_empty() => return Err(err),
+                Err(_) => break,
+            }
         }
+        Ok(routes)
     }
 
-    /// Returns a list of `PaymentHopData` for all nodes in the route, including the origin and the target node.
-    pub fn build_route(
+    /// Splits `amount` of the payment into at most `max_parts` parts and returns the route of each part.
+    /// A part is halved whenever no route can carry it, the amount already allocated to a channel by
+    /// the previous parts is deducted from its capacity. The maximum fee is shared by the parts in
+    /// proportion to their amounts.
+    pub fn build_routes(
+        &self,
+        payment_data: &SendPaymentData,
+        amount: u128,
+        max_parts: u64,
+        excluded_channels: &HashSet<OutPoint>,
+    ) -> Result<Vec<Vec<PaymentHopData>>, GraphError> {
+        if amount == 0 {
+            return Err(GraphError::Amount(
+                "Amount must be greater than 0".to_string(),
+            ));
+        }
+        let mut routes = vec![];
+        let mut channel_usage = HashMap::<OutPoint, u128>::new();
+        let mut remaining = amount;
+        while remaining > 0 {
+            let parts_left = max_parts.saturating_sub(routes.len() as u64) as u128;
+            if parts_left == 0 {
+                return Err(GraphError::PathFind(format!(
+                    "can not split amount {} into at most {} parts",
+                    amount, max_parts
+                )));
+            }
+            // the remaining parts must be large enough to carry the remaining amount
+            let min_part_amount = remaining.div_ceil(parts_left);
+            let mut part_amount = remaining;
+            let route = loop {
+                let max_fee_amount = payment_data
+                    .max_fee_amount
+                    .map(|max_fee| max_fee * part_amount / payment_data.amount);
+                match self.build_part_route(
+                    payment_data,
+                    part_amount,
+                    max_fee_amount,
+                    excluded_channels,
+                    &channel_usage,
+                ) {
+                    Ok(route) => break route,
+                    Err(err) => {
+                        if part_amount / 2 < min_part_amount.max(1) {
+                            return Err(err);
+                        }
+                        part_amount /= 2;
+                    }
+                }
+            };
+            for hop in &route {
+                if let Some(channel_outpoint) = &hop.channel_outpoint {
+                    *channel_usage.entry(channel_outpoint.clone()).or_default() += hop.amount;
+                }
+            }
+            remaining -= part_amount;
+            routes.push(route);
+        }
+        Ok(routes)
+    }
+
+    fn build_part_route(
         &self,
-        payment_request: SendPaymentCommand,
+        payment_data: &SendPaymentData,
+        amount: u128,
+        max_fee_amount: Option<u128>,
+        excluded_channels: &HashSet<OutPoint>,
+        channel_usage: &HashMap<OutPoint, u128>,
     ) -> Result<Vec<PaymentHopData>, GraphError> {
         let source = self.get_source_pubkey();
-        let (target, amount, payment_hash, preimage, udt_type_script) = payment_request
-            .check_valid()
-            .map_err(|e| GraphError::Other(format!("payment request is invalid {:?}", e)))?;
-        let invoice = payment_request
+        let target = payment_data.target_pubkey;
+        let payment_hash = payment_data.payment_hash;
+        let preimage = payment_data.preimage;
+        let udt_type_script = payment_data.udt_type_script.clone();
+        let invoice = payment_data
             .invoice
+            .as_ref()
             .map(|x| x.parse::<CkbInvoice>().unwrap());
         let hash_algorithm = invoice
             .as_ref()
             .and_then(|x| x.hash_algorithm().copied())
             .unwrap_or_default();
+        // the target needs the total amount to know when all the parts are received
+        let total_amount = payment_data.allow_mpp().then_some(payment_data.amount);
+        let hint_channels = invoice
+            .as_ref()
+            .map(|invoice| Self::route_hint_channels(invoice, target, udt_type_script.clone()))
+            .unwrap_or_default();
 
         info!(
             "build_route source: {:?} target: {:?} amount: {:?}, payment_hash: {:?}",
@@ -497,8 +709,11 @@ where
             source,
             target,
             amount,
-            payment_request.max_fee_amount,
+            max_fee_amount,
             udt_type_script,
+            excluded_channels,
+            channel_usage,
+            &hint_channels,
         )?;
         assert!(!route.is_empty());
 
@@ -518,8 +733,14 @@ where
             let (fee, expiry) = if is_last {
                 (0, 0)
             } else {
+                let channel_outpoint = &route[i + 1].channel_outpoint;
                 let channel_info = self
-                    .get_channel(&route[i + 1].channel_outpoint)
+                    .get_channel(channel_outpoint)
+                    .or_else(|| {
+                        hint_channels
+                            .iter()
+                            .find(|channel| channel.out_point() == *channel_outpoint)
+                    })
                     .expect("channel not found");
                 let channel_update = &if channel_info.node1() == route[i + 1].target {
                     channel_info.node2_to_node1.as_ref()
@@ -543,6 +764,7 @@ where
                 expiry: current_expiry,
                 channel_outpoint: next_channel_outpoint,
                 preimage: if is_last { preimage } else { None },
+                total_amount: if is_last { total_amount } else { None },
             });
             current_amount += fee;
             current_expiry += expiry;
@@ -561,6 +783,7 @@ where
             expiry: current_expiry,
             channel_outpoint: Some(route[0].channel_outpoint.clone()),
             preimage: None,
+            total_amount: None,
         });
         onion_infos.reverse();
         assert_eq!(onion_infos.len(), route.len() + 1);
@@ -568,6 +791,77 @@ where
         Ok(onion_infos)
     }
 
+    // The private channels in the route hints of the invoice, they are only used to find
+    // the routes to the payee of this invoice.
+    fn route_hint_channels(
+        invoice: &CkbInvoice,
+        payee: Pubkey,
+        udt_type_script: Option<Script>,
+    ) -> Vec<ChannelInfo> {
+        let mut channels = vec![];
+        for hops in invoice.route_hints() {
+            let mut next_node = payee;
+            for hop in hops.iter().rev() {
+                channels.push(ChannelInfo::from_route_hint_hop(
+                    hop,
+                    next_node,
+                    udt_type_script.clone(),
+                ));
+                next_node = hop.pubkey.into();
+            }
+        }
+        channels
+    }
+
+    pub fn get_payment_history(&self) -> &PaymentHistory<S> {
+        &self.history
+    }
+
+    /// Forgets the payment results of the channel `channel_outpoint`, or all of them if it is None.
+    pub fn reset_payment_history(&mut self, channel_outpoint: Option<&OutPoint>) {
+        self.history.reset(channel_outpoint);
+    }
+
+    /// Learns from a fulfilled payment, all the channels in the route forwarded it successfully.
+    pub fn record_payment_success(&mut self, route: &[PaymentHopData]) {
+        let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+        for hop in route {
+            if let (Some(channel_outpoint), Some(target)) = (&hop.channel_outpoint, hop.next_hop) {
+                self.history
+                    .apply_success(channel_outpoint.clone(), target, hop.amount, now);
+            }
+        }
+    }
+
+    /// Learns from a failed payment whose error is returned by the `erring_index`-th node
+    /// of the route. The channels to the erring node forwarded the payment successfully,
+    /// while the channel to the next node failed if the error is specific to it.
+    pub fn record_payment_fail(
+        &mut self,
+        route: &[PaymentHopData],
+        erring_index: usize,
+        tlc_err: &TlcErr,
+    ) {
+        let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+        for hop in route.iter().take(erring_index + 1) {
+            if let (Some(channel_outpoint), Some(target)) = (&hop.channel_outpoint, hop.next_hop) {
+                self.history
+                    .apply_success(channel_outpoint.clone(), target, hop.amount, now);
+            }
+        }
+        let Some(erring_channel) = &tlc_err.erring_channel else {
+            return;
+        };
+        if let Some(hop) = route.get(erring_index + 1) {
+            if let (Some(channel_outpoint), Some(target)) = (&hop.channel_outpoint, hop.next_hop) {
+                if channel_outpoint == erring_channel {
+                    self.history
+                        .apply_failure(channel_outpoint.clone(), target, hop.amount, now);
+                }
+            }
+        }
+    }
+
     // the algorithm works from target-to-source to find the shortest path
     pub fn find_route(
         &self,
@@ -576,6 +870,9 @@ where
         amount: u128,
         max_fee_amount: Option<u128>,
         udt_type_script: Option<Script>,
+        excluded_channels: &HashSet<OutPoint>,
+        channel_usage: &HashMap<OutPoint, u128>,
+        hint_channels: &[ChannelInfo],
     ) -> Result<Vec<PathEdge>, GraphError> {
         let started_time = std::time::Instant::now();
         let nodes_len = self.nodes.len();
@@ -584,6 +881,7 @@ where
         let mut edges_expanded = 0;
         let mut nodes_heap = NodeHeap::new(nodes_len);
         let mut distances = HashMap::<Pubkey, NodeHeapElement>::new();
+        let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
 
         if amount == 0 {
             return Err(GraphError::Amount(
@@ -599,9 +897,14 @@ where
         let Some(source_node) = self.nodes.get(&source) else {
             return Err(GraphError::PathFind("source node not found".to_string()));
         };
-        let Some(_target_node) = self.nodes.get(&target) else {
+        // a node with only private channels is reachable through the route hints
+        if !self.nodes.contains_key(&target)
+            && !hint_channels
+                .iter()
+                .any(|channel| channel.node2() == target)
+        {
             return Err(GraphError::PathFind("target node not found".to_string()));
-        };
+        }
         // initialize the target node
         nodes_heap.push(NodeHeapElement {
             node_id: target,
@@ -623,7 +926,14 @@ where
                 break;
             }
 
-            for (from, channel_info, channel_update) in self.get_node_inbounds(cur_hop.node_id) {
+            let hint_inbounds = hint_channels.iter().filter_map(|channel| {
+                channel
+                    .inbound_to(cur_hop.node_id)
+                    .map(|(from, info)| (from, channel, info))
+            });
+            for (from, channel_info, channel_update) in
+                self.get_node_inbounds(cur_hop.node_id).chain(hint_inbounds)
+            {
                 edges_expanded += 1;
                 // if charge inbound fees for exit hop
                 let fee_rate = channel_update.fee_rate;
@@ -640,6 +950,14 @@ where
                     continue;
                 }
 
+                if excluded_channels.contains(&channel_info.out_point()) {
+                    debug!(
+                        "skip channel {:?} which is excluded from route finding",
+                        channel_info.out_point()
+                    );
+                    continue;
+                }
+
                 // if the amount to send is greater than the amount we have, skip this edge
                 if let Some(max_fee_amount) = max_fee_amount {
                     if amount_to_send > amount + max_fee_amount {
@@ -653,7 +971,12 @@ where
                 }
                 // check to make sure the current hop can send the amount
                 // if `htlc_maximum_value` equals 0, it means there is no limit
-                if amount_to_send > channel_info.capacity()
+                // the amount already allocated to other parts of the same payment is not available
+                let used_amount = channel_usage
+                    .get(&channel_info.out_point())
+                    .copied()
+                    .unwrap_or_default();
+                if amount_to_send + used_amount > channel_info.capacity()
                     || (channel_update.htlc_maximum_value != 0
                         && amount_to_send > channel_update.htlc_maximum_value)
                 {
@@ -680,11 +1003,11 @@ where
                     };
 
                 let probability = cur_hop.probability
-                    * ProbabilityEvaluator::evaluate_probability(
-                        from,
-                        cur_hop.node_id,
+                    * self.history.eval_probability(
+                        &channel_info.out_point(),
+                        &cur_hop.node_id,
                         amount_to_send,
-                        channel_info.capacity(),
+                        now,
                     );
 
                 if probability < DEFAULT_MIN_PROBABILITY {
@@ -777,41 +1100,236 @@ pub trait NetworkGraphStateStore {
         outpoint: Option<OutPoint>,
     ) -> (Vec<ChannelInfo>, JsonBytes);
     fn insert_channel(&self, channel: ChannelInfo);
+    fn delete_channel(&self, outpoint: &OutPoint);
     fn insert_node(&self, node: NodeInfo);
     fn insert_connected_peer(&self, peer_id: PeerId, multiaddr: Multiaddr);
     fn get_connected_peer(&self, peer_id: Option<PeerId>) -> Vec<(PeerId, Multiaddr)>;
     fn remove_connected_peer(&self, peer_id: &PeerId);
+    fn insert_banned_peer(&self, peer_id: PeerId, until: u64);
+    fn get_banned_peers(&self) -> Vec<(PeerId, u64)>;
+    fn remove_banned_peer(&self, peer_id: &PeerId);
     fn get_payment_session(&self, payment_hash: Hash256) -> Option<PaymentSession>;
+    fn get_payment_sessions(&self) -> Vec<PaymentSession>;
     fn insert_payment_session(&self, session: PaymentSession);
+    fn get_payment_history_results(&self) -> Vec<(OutPoint, Pubkey, TimedResult)>;
+    fn insert_payment_history_result(
+        &self,
+        channel_outpoint: OutPoint,
+        target: Pubkey,
+        result: TimedResult,
+    );
+    fn remove_payment_history_results(&self, channel_outpoint: Option<OutPoint>);
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaymentSessionStatus {
+    // The payment session is created, but no tlc has been accepted by the first hop yet.
+    Created,
+    // The first hop tlc is added, we are waiting for the tlc to be fulfilled or failed.
+    Inflight,
+    // The first hop tlc is fulfilled, the payment is finished.
+    Success,
+    // The payment is failed, and all the retries are exhausted.
+    Failed,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaymentPartStatus {
+    // The tlc of this part is added to the first hop, waiting for it to be fulfilled or failed.
+    Inflight,
+    // The tlc of this part is fulfilled by the target.
+    Success,
+    // The tlc of this part is failed, its amount needs to be sent again through another route.
+    Failed,
+}
+
+/// A part of a payment sent through a single route. A payment without multi-part
+/// enabled has only one inflight part in each attempt.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PaymentPart {
+    // The amount received by the payment target through this part.
+    pub amount: u128,
+    // The route of this part, the first element is the instruction for the origin node.
+    pub route: Vec<PaymentHopData>,
+    // The channel id and tlc id of the tlc added to the first hop.
+    pub first_hop_tlc: (Hash256, u64),
+    // The session key of the onion packet, which is used to decrypt
+    // the error packet returned by the erring node.
+    pub session_key: [u8; 32],
+    pub status: PaymentPartStatus,
+}
+
+impl PaymentPart {
+    pub fn new(
+        route: Vec<PaymentHopData>,
+        first_hop_tlc: (Hash256, u64),
+        session_key: [u8; 32],
+    ) -> Self {
+        Self {
+            amount: route.last().map(|hop| hop.amount).unwrap_or_default(),
+            route,
+            first_hop_tlc,
+            session_key,
+            status: PaymentPartStatus::Inflight,
+        }
+    }
+
+    /// The channel outpoint used by the first hop of this part.
+    pub fn first_hop_channel_outpoint(&self) -> Option<OutPoint> {
+        self.route
+            .first()
+            .and_then(|hop| hop.channel_outpoint.clone())
+    }
+
+    /// The public keys of the nodes which receive the onion packet of this part,
+    /// the last one is the payment target.
+    pub fn hops_path(&self) -> Vec<Pubkey> {
+        self.route.iter().filter_map(|hop| hop.next_hop).collect()
+    }
+
+    /// The fee paid to the intermediate nodes, which is the amount of the first
+    /// hop minus the amount received by the target.
+    pub fn fee(&self) -> u128 {
+        self.route
+            .first()
+            .map(|hop| hop.amount.saturating_sub(self.amount))
+            .unwrap_or(0)
+    }
+}
+
+#[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PaymentSession {
-    pub command: SendPaymentCommand,
+    pub request: SendPaymentData,
     pub retried_times: u32,
     pub last_error: Option<String>,
     pub try_limit: u32,
+    pub status: PaymentSessionStatus,
+    pub created_at: u64,
+    pub last_updated_at: u64,
+    // All the parts sent in the attempts of this payment, including the failed ones.
+    pub parts: Vec<PaymentPart>,
+    // Channels which failed to forward this payment in previous attempts,
+    // they are excluded while building routes for the following attempts.
+    #[serde_as(as = "Vec<EntityHex>")]
+    pub failed_channels: Vec<OutPoint>,
+    // The preimage of the payment, only available when the payment is succeeded.
+    pub preimage: Option<Hash256>,
 }
 
 impl PaymentSession {
-    pub fn new(command: SendPaymentCommand, try_limit: u32) -> Self {
+    pub fn new(request: SendPaymentData, try_limit: u32) -> Self {
+        let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
         Self {
-            command,
+            request,
             retried_times: 0,
             last_error: None,
             try_limit,
+            status: PaymentSessionStatus::Created,
+            created_at: now,
+            last_updated_at: now,
+            parts: vec![],
+            failed_channels: vec![],
+            preimage: None,
         }
     }
 
     pub fn payment_hash(&self) -> Hash256 {
-        self.command.payment_hash()
+        self.request.payment_hash
+    }
+
+    pub fn can_retry(&self) -> bool {
+        self.retried_times < self.try_limit
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self.status,
+            PaymentSessionStatus::Success | PaymentSessionStatus::Failed
+        )
+    }
+
+    pub fn excluded_channels(&self) -> HashSet<OutPoint> {
+        self.failed_channels.iter().cloned().collect()
+    }
+
+    pub fn active_parts(&self) -> impl Iterator<Item = &PaymentPart> {
+        self.parts
+            .iter()
+            .filter(|part| part.status != PaymentPartStatus::Failed)
+    }
+
+    pub fn inflight_parts(&self) -> impl Iterator<Item = &PaymentPart> {
+        self.parts
+            .iter()
+            .filter(|part| part.status == PaymentPartStatus::Inflight)
+    }
+
+    pub fn get_part_mut(&mut self, first_hop_tlc: (Hash256, u64)) -> Option<&mut PaymentPart> {
+        self.parts
+            .iter_mut()
+            .find(|part| part.first_hop_tlc == first_hop_tlc)
+    }
+
+    /// The amount which is not covered by the inflight or succeeded parts yet.
+    pub fn remaining_amount(&self) -> u128 {
+        let sent_amount: u128 = self.active_parts().map(|part| part.amount).sum();
+        self.request.amount.saturating_sub(sent_amount)
+    }
+
+    /// The number of parts which can still be sent without exceeding `max_parts` of the request.
+    pub fn remaining_parts(&self) -> u64 {
+        self.request
+            .max_parts()
+            .saturating_sub(self.active_parts().count() as u64)
+    }
+
+    /// The total fee paid to the intermediate nodes by the parts which are not failed.
+    pub fn fee(&self) -> u128 {
+        self.active_parts().map(|part| part.fee()).sum()
+    }
+
+    pub fn mark_channel_failed(&mut self, channel_outpoint: OutPoint) {
+        if !self.failed_channels.contains(&channel_outpoint) {
+            self.failed_channels.push(channel_outpoint);
+        }
+    }
+
+    pub fn add_inflight_part(&mut self, part: PaymentPart) {
+        self.parts.push(part);
+        self.set_status(PaymentSessionStatus::Inflight);
+    }
+
+    pub fn set_success_status(&mut self, preimage: Hash256) {
+        self.preimage = Some(preimage);
+        self.last_error = None;
+        self.set_status(PaymentSessionStatus::Success);
+    }
+
+    /// A probe payment succeeds when it is failed by the target for the unknown payment hash,
+    /// there is no preimage for it.
+    pub fn set_probe_success_status(&mut self) {
+        self.last_error = None;
+        self.set_status(PaymentSessionStatus::Success);
+    }
+
+    pub fn set_failed_status(&mut self, error: &str) {
+        self.last_error = Some(error.to_string());
+        self.set_status(PaymentSessionStatus::Failed);
+    }
+
+    fn set_status(&mut self, status: PaymentSessionStatus) {
+        self.status = status;
+        self.last_updated_at = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fiber::network::SendPaymentCommand;
     use crate::fiber::test_utils::{generate_keypair, generate_pubkey};
+    use crate::invoice::{Currency, InvoiceBuilder};
     use crate::store::Store;
     use ckb_types::prelude::Entity;
     use secp256k1::{PublicKey, SecretKey, XOnlyPublicKey};
@@ -973,8 +1491,16 @@ mod tests {
         ) -> Result<Vec<PathEdge>, GraphError> {
             let source = self.keys[source].into();
             let target = self.keys[target].into();
-            self.graph
-                .find_route(source, target, amount, Some(max_fee), None)
+            self.graph.find_route(
+                source,
+                target,
+                amount,
+                Some(max_fee),
+                None,
+                &HashSet::new(),
+                &HashMap::new(),
+                &[],
+            )
         }
 
         pub fn find_route_udt(
@@ -987,46 +1513,19 @@ mod tests {
         ) -> Result<Vec<PathEdge>, GraphError> {
             let source = self.keys[source].into();
             let target = self.keys[target].into();
-            self.graph
-                .find_route(source, target, amount, Some(max_fee), Some(udt_type_script))
+            self.graph.find_route(
+                source,
+                target,
+                amount,
+                Some(max_fee),
+                Some(udt_type_script),
+                &HashSet::new(),
+                &HashMap::new(),
+                &[],
+            )
         }
     }
 
-    #[test]
-    fn test_graph_connected_peers() {
-        let temp_path = tempfile::tempdir().unwrap();
-        let store = Store::new(temp_path.path());
-        let keys = generate_keys(1);
-        let public_key1 = keys[0];
-        let mut network_graph = NetworkGraph::new(store, public_key1.into());
-
-        let peer_id = PeerId::random();
-        let address: Multiaddr = "/ip4/127.0.0.1/tcp/10000".parse().unwrap();
-        network_graph.add_connected_peer(&peer_id, address.clone());
-
-        let connected_peers = network_graph.get_connected_peers();
-        assert_eq!(connected_peers.len(), 1);
-        assert_eq!(connected_peers[0], (&peer_id, &address));
-
-        network_graph.reset();
-        let connected_peers = network_graph.get_connected_peers();
-        assert_eq!(connected_peers.len(), 0);
-
-        // load from db
-        network_graph.load_from_store();
-        let connected_peers = network_graph.get_connected_peers();
-        assert_eq!(connected_peers.len(), 1);
-        assert_eq!(connected_peers[0], (&peer_id, &address));
-
-        network_graph.remove_connected_peer(&peer_id);
-        let connected_peers = network_graph.get_connected_peers();
-        assert_eq!(connected_peers.len(), 0);
-
-        network_graph.load_from_store();
-        let connected_peers = network_graph.get_connected_peers();
-        assert_eq!(connected_peers.len(), 0);
-    }
-
     #[test]
     fn test_graph_channel_info() {
         let mut mock_network = MockNetworkGraph::new(1);
@@ -1044,6 +1543,76 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_graph_splice_channel() {
+        let mut mock_network = MockNetworkGraph::new(2);
+        mock_network.add_edge(1, 2, Some(1000), Some(1));
+        let old_outpoint = mock_network.edges[0].2.clone();
+        let old_channel = mock_network
+            .graph
+            .get_channel(&old_outpoint)
+            .cloned()
+            .unwrap();
+        let new_outpoint = OutPoint::from_slice(&[42u8; 36]).unwrap();
+
+        mock_network
+            .graph
+            .splice_channel(&old_outpoint, new_outpoint.clone(), 3000);
+        assert!(mock_network.graph.get_channel(&old_outpoint).is_none());
+        let channel = mock_network.graph.get_channel(&new_outpoint).unwrap();
+        assert_eq!(channel.capacity(), 3000);
+        assert_eq!(channel.node1_to_node2, old_channel.node1_to_node2);
+        assert_eq!(channel.node2_to_node1, old_channel.node2_to_node1);
+
+        // The spliced channel is also updated in the store.
+        mock_network.graph.reset();
+        mock_network.graph.load_from_store();
+        assert!(mock_network.graph.get_channel(&old_outpoint).is_none());
+        assert_eq!(
+            mock_network
+                .graph
+                .get_channel(&new_outpoint)
+                .map(|channel| channel.capacity()),
+            Some(3000)
+        );
+    }
+
+    #[test]
+    fn test_graph_prune_stale_channels() {
+        let mut mock_network = MockNetworkGraph::new(3);
+        mock_network.add_edge(0, 1, Some(1000), Some(1));
+        mock_network.add_edge(1, 2, Some(1000), Some(1));
+        mock_network.add_edge(2, 3, Some(1000), Some(1));
+        let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+
+        // All the channels are updated just now.
+        let pruned = mock_network.graph.prune_stale_channels(now - 1000 * 3600);
+        assert!(pruned.is_empty());
+        assert_eq!(mock_network.graph.channels().count(), 3);
+
+        let pruned = mock_network.graph.prune_stale_channels(now + 1);
+        assert_eq!(pruned.len(), 2);
+        // Our own channel is kept.
+        let own_channel = &mock_network.edges[0].2;
+        assert!(!pruned.contains(own_channel));
+        assert!(mock_network.graph.get_channel(own_channel).is_some());
+        for outpoint in pruned.iter() {
+            assert!(mock_network.graph.get_channel(outpoint).is_none());
+            assert!(mock_network.graph.is_channel_pruned(outpoint));
+        }
+        assert!(!mock_network.graph.is_channel_pruned(own_channel));
+
+        // The pruned channels are also removed from the store.
+        mock_network.graph.reset();
+        mock_network.graph.load_from_store();
+        let channels: Vec<OutPoint> = mock_network
+            .graph
+            .channels()
+            .map(|channel| channel.out_point())
+            .collect();
+        assert_eq!(channels, vec![own_channel.clone()]);
+    }
+
     #[test]
     fn test_graph_graph_apis() {
         let mut mock_network = MockNetworkGraph::new(4);
@@ -1294,6 +1863,9 @@ mod tests {
             100,
             Some(1000),
             None,
+            &HashSet::new(),
+            &HashMap::new(),
+            &[],
         );
         assert!(route.is_err());
 
@@ -1303,6 +1875,9 @@ mod tests {
             100,
             Some(1000),
             None,
+            &HashSet::new(),
+            &HashMap::new(),
+            &[],
         );
         assert!(route.is_err());
     }
@@ -1316,18 +1891,22 @@ mod tests {
         let node2 = network.keys[2];
         let node3 = network.keys[3];
         // Test build route from node1 to node3
-        let route = network.graph.build_route(SendPaymentCommand {
-            target_pubkey: Some(node3.into()),
-            amount: Some(100),
-            payment_hash: Some(Hash256::default()),
-            invoice: None,
-            final_cltv_delta: Some(100),
-            timeout: Some(10),
-            max_fee_amount: Some(1000),
-            max_parts: None,
-            keysend: None,
-            udt_type_script: None,
-        });
+        let route = network.graph.build_route(
+            &SendPaymentData::new(SendPaymentCommand {
+                target_pubkey: Some(node3.into()),
+                amount: Some(100),
+                payment_hash: Some(Hash256::default()),
+                invoice: None,
+                final_cltv_delta: Some(100),
+                timeout: Some(10),
+                max_fee_amount: Some(1000),
+                max_parts: None,
+                keysend: None,
+                udt_type_script: None,
+            })
+            .unwrap(),
+            &HashSet::new(),
+        );
         eprintln!("return {:?}", route);
         assert!(route.is_ok());
         let route = route.unwrap();
@@ -1353,9 +1932,85 @@ mod tests {
         let node3 = network.keys[3];
 
         // Test build route from node1 to node3 with amount exceeding max_htlc_value
-        let route = network.graph.build_route(SendPaymentCommand {
+        let route = network.graph.build_route(
+            &SendPaymentData::new(SendPaymentCommand {
+                target_pubkey: Some(node3.into()),
+                amount: Some(100), // Exceeds max_htlc_value of 50
+                payment_hash: Some(Hash256::default()),
+                invoice: None,
+                final_cltv_delta: Some(100),
+                timeout: Some(10),
+                max_fee_amount: Some(1000),
+                max_parts: None,
+                keysend: None,
+                udt_type_script: None,
+            })
+            .unwrap(),
+            &HashSet::new(),
+        );
+        assert!(route.is_err());
+    }
+
+    #[test]
+    fn test_graph_build_route_below_min_htlc_value() {
+        let mut network = MockNetworkGraph::new(3);
+        // Add edges with min_htlc_value set to 50
+        network.add_edge_with_config(0, 2, Some(500), Some(2), Some(50), None, None);
+        network.add_edge_with_config(2, 3, Some(500), Some(2), Some(50), None, None);
+        let node3 = network.keys[3];
+
+        // Test build route from node1 to node3 with amount below min_htlc_value
+        let route = network.graph.build_route(
+            &SendPaymentData::new(SendPaymentCommand {
+                target_pubkey: Some(node3.into()),
+                amount: Some(10), // Below min_htlc_value of 50
+                payment_hash: Some(Hash256::default()),
+                invoice: None,
+                final_cltv_delta: Some(100),
+                timeout: Some(10),
+                max_fee_amount: Some(1000),
+                max_parts: None,
+                keysend: None,
+                udt_type_script: None,
+            })
+            .unwrap(),
+            &HashSet::new(),
+        );
+        assert!(route.is_err());
+    }
+
+    #[test]
+    fn test_graph_find_path_udt() {
+        let mut network = MockNetworkGraph::new(3);
+        let udt_type_script = Script::default();
+        network.add_edge_udt(1, 2, Some(1000), Some(1), udt_type_script.clone());
+        let node2 = network.keys[2];
+
+        let route = network.find_route_udt(1, 2, 100, 1000, udt_type_script.clone());
+        assert!(route.is_ok());
+
+        let route = route.unwrap();
+        assert_eq!(route.len(), 1);
+        assert_eq!(route[0].target, node2.into());
+        assert_eq!(route[0].channel_outpoint, network.edges[0].2);
+
+        let route = network.find_route(1, 3, 10, 100);
+        assert!(route.is_err());
+    }
+
+    #[test]
+    fn test_graph_build_route_with_excluded_channels() {
+        let mut network = MockNetworkGraph::new(3);
+        network.add_edge(0, 2, Some(500), Some(1));
+        network.add_edge(2, 3, Some(500), Some(1));
+        network.add_edge(0, 1, Some(500), Some(2));
+        network.add_edge(1, 3, Some(500), Some(2));
+        let node1 = network.keys[1];
+        let node2 = network.keys[2];
+        let node3 = network.keys[3];
+        let payment_data = SendPaymentData::new(SendPaymentCommand {
             target_pubkey: Some(node3.into()),
-            amount: Some(100), // Exceeds max_htlc_value of 50
+            amount: Some(100),
             payment_hash: Some(Hash256::default()),
             invoice: None,
             final_cltv_delta: Some(100),
@@ -1364,22 +2019,90 @@ mod tests {
             max_parts: None,
             keysend: None,
             udt_type_script: None,
-        });
+        })
+        .unwrap();
+
+        let route = network
+            .graph
+            .build_route(&payment_data, &HashSet::new())
+            .unwrap();
+        assert_eq!(route[0].next_hop, Some(node2.into()));
+
+        let mut session = PaymentSession::new(payment_data.clone(), 3);
+        session.mark_channel_failed(network.edges[0].2.clone());
+        let route = network
+            .graph
+            .build_route(&payment_data, &session.excluded_channels())
+            .unwrap();
+        assert_eq!(route[0].next_hop, Some(node1.into()));
+        assert_eq!(route[0].channel_outpoint, Some(network.edges[2].2.clone()));
+
+        session.mark_channel_failed(network.edges[3].2.clone());
+        let route = network
+            .graph
+            .build_route(&payment_data, &session.excluded_channels());
         assert!(route.is_err());
     }
 
     #[test]
-    fn test_graph_build_route_below_min_htlc_value() {
+    fn test_graph_build_routes_split_by_capacity() {
         let mut network = MockNetworkGraph::new(3);
-        // Add edges with min_htlc_value set to 50
-        network.add_edge_with_config(0, 2, Some(500), Some(2), Some(50), None, None);
-        network.add_edge_with_config(2, 3, Some(500), Some(2), Some(50), None, None);
+        network.add_edge(0, 2, Some(60), Some(1));
+        network.add_edge(2, 3, Some(60), Some(1));
+        network.add_edge(0, 1, Some(70), Some(100000));
+        network.add_edge(1, 3, Some(70), Some(100000));
+        let node1 = network.keys[1];
+        let node2 = network.keys[2];
         let node3 = network.keys[3];
+        let payment_data = SendPaymentData::new(SendPaymentCommand {
+            target_pubkey: Some(node3.into()),
+            amount: Some(100),
+            payment_hash: Some(Hash256::default()),
+            invoice: None,
+            final_cltv_delta: Some(100),
+            timeout: Some(10),
+            max_fee_amount: Some(1000),
+            max_parts: Some(2),
+            keysend: None,
+            udt_type_script: None,
+        })
+        .unwrap();
 
-        // Test build route from node1 to node3 with amount below min_htlc_value
-        let route = network.graph.build_route(SendPaymentCommand {
+        // no single channel can carry the whole amount
+        let route = network.graph.build_route(&payment_data, &HashSet::new());
+        assert!(route.is_err());
+        let routes = network
+            .graph
+            .build_routes(&payment_data, 100, 1, &HashSet::new());
+        assert!(routes.is_err());
+
+        let routes = network
+            .graph
+            .build_routes(&payment_data, 100, 2, &HashSet::new())
+            .unwrap();
+        assert_eq!(routes.len(), 2);
+        assert_eq!(routes[0][0].next_hop, Some(node2.into()));
+        assert_eq!(routes[1][0].next_hop, Some(node1.into()));
+        for route in &routes {
+            let last_hop = route.last().unwrap();
+            assert_eq!(last_hop.amount, 50);
+            assert_eq!(last_hop.total_amount, Some(100));
+        }
+    }
+
+    #[test]
+    fn test_graph_build_candidate_routes() {
+        let mut network = MockNetworkGraph::new(3);
+        network.add_edge(0, 2, Some(500), Some(1));
+        network.add_edge(2, 3, Some(500), Some(1));
+        network.add_edge(0, 1, Some(500), Some(100000));
+        network.add_edge(1, 3, Some(500), Some(100000));
+        let node1 = network.keys[1];
+        let node2 = network.keys[2];
+        let node3 = network.keys[3];
+        let payment_data = SendPaymentData::new(SendPaymentCommand {
             target_pubkey: Some(node3.into()),
-            amount: Some(10), // Below min_htlc_value of 50
+            amount: Some(100),
             payment_hash: Some(Hash256::default()),
             invoice: None,
             final_cltv_delta: Some(100),
@@ -1388,26 +2111,163 @@ mod tests {
             max_parts: None,
             keysend: None,
             udt_type_script: None,
-        });
+        })
+        .unwrap();
+
+        let routes = network
+            .graph
+            .build_candidate_routes(&payment_data, 1)
+            .unwrap();
+        assert_eq!(routes.len(), 1);
+
+        // only two disjoint routes exist, the cheaper one comes first
+        let routes = network
+            .graph
+            .build_candidate_routes(&payment_data, 3)
+            .unwrap();
+        assert_eq!(routes.len(), 2);
+        assert_eq!(routes[0][0].next_hop, Some(node2.into()));
+        assert_eq!(routes[1][0].next_hop, Some(node1.into()));
+        assert!(routes[0][0].amount < routes[1][0].amount);
+        for route in &routes {
+            assert_eq!(route.last().unwrap().amount, 100);
+        }
+
+        let payment_data = SendPaymentData {
+            amount: 1000,
+            ..payment_data
+        };
+        assert!(network
+            .graph
+            .build_candidate_routes(&payment_data, 3)
+            .is_err());
+    }
+
+    #[test]
+    fn test_graph_build_route_with_route_hints() {
+        let mut network = MockNetworkGraph::new(2);
+        network.add_edge(0, 1, Some(500), Some(2));
+        network.add_edge(1, 2, Some(500), Some(2));
+        let node2 = network.keys[2];
+        // the payee only has a private channel with node2
+        let payee = generate_pubkey();
+        let private_channel = OutPoint::from_slice(&[0xff; 36]).unwrap();
+        let payment_data = |invoice: CkbInvoice| {
+            SendPaymentData::new(SendPaymentCommand {
+                target_pubkey: None,
+                amount: None,
+                payment_hash: None,
+                invoice: Some(invoice.to_string()),
+                final_cltv_delta: Some(100),
+                timeout: Some(10),
+                max_fee_amount: Some(1000),
+                max_parts: None,
+                keysend: None,
+                udt_type_script: None,
+            })
+            .unwrap()
+        };
+        let invoice_builder = || {
+            InvoiceBuilder::new(Currency::Fibb)
+                .amount(Some(100))
+                .payment_hash(Hash256::default())
+                .payee_pub_key(payee)
+        };
+
+        let invoice = invoice_builder().build().unwrap();
+        let route = network
+            .graph
+            .build_route(&payment_data(invoice), &HashSet::new());
         assert!(route.is_err());
+
+        let invoice = invoice_builder()
+            .route_hint(vec![RouteHintHop {
+                pubkey: node2,
+                channel_outpoint: private_channel.clone(),
+                fee_rate: 1000,
+                tlc_expiry_delta: 100,
+            }])
+            .build()
+            .unwrap();
+        let route = network
+            .graph
+            .build_route(&payment_data(invoice), &HashSet::new())
+            .unwrap();
+        assert_eq!(route.len(), 4);
+        assert_eq!(route[2].next_hop, Some(payee.into()));
+        assert_eq!(route[2].channel_outpoint, Some(private_channel));
+        assert_eq!(route[2].amount, 100);
+        // node2 charges the fee of the private channel in the route hint
+        assert_eq!(route[1].amount, 101);
+        assert_eq!(route[1].expiry, 100);
+        assert_eq!(route[3].amount, 100);
     }
 
     #[test]
-    fn test_graph_find_path_udt() {
+    fn test_graph_build_route_with_payment_history() {
         let mut network = MockNetworkGraph::new(3);
-        let udt_type_script = Script::default();
-        network.add_edge_udt(1, 2, Some(1000), Some(1), udt_type_script.clone());
+        network.add_edge(0, 2, Some(500), Some(1));
+        network.add_edge(2, 3, Some(500), Some(1));
+        network.add_edge(0, 1, Some(500), Some(2));
+        network.add_edge(1, 3, Some(500), Some(2));
+        let node1 = network.keys[1];
         let node2 = network.keys[2];
+        let node3 = network.keys[3];
+        let payment_data = SendPaymentData::new(SendPaymentCommand {
+            target_pubkey: Some(node3.into()),
+            amount: Some(100),
+            payment_hash: Some(Hash256::default()),
+            invoice: None,
+            final_cltv_delta: Some(100),
+            timeout: Some(10),
+            max_fee_amount: Some(1000),
+            max_parts: None,
+            keysend: None,
+            udt_type_script: None,
+        })
+        .unwrap();
 
-        let route = network.find_route_udt(1, 2, 100, 1000, udt_type_script.clone());
-        assert!(route.is_ok());
+        let route = network
+            .graph
+            .build_route(&payment_data, &HashSet::new())
+            .unwrap();
+        assert_eq!(route[0].next_hop, Some(node2.into()));
 
-        let route = route.unwrap();
-        assert_eq!(route.len(), 1);
-        assert_eq!(route[0].target, node2.into());
-        assert_eq!(route[0].channel_outpoint, network.edges[0].2);
+        // node2 failed to forward the payment to node3
+        let tlc_err = TlcErr::new_channel_fail(
+            TlcErr::TEMPORARY_CHANNEL_FAILURE,
+            network.edges[1].2.clone(),
+            None,
+        );
+        network.graph.record_payment_fail(&route, 0, &tlc_err);
+        let history = network.graph.get_payment_history();
+        assert_eq!(history.get_results().len(), 2);
+        assert_eq!(
+            history
+                .get_result(&network.edges[0].2, &node2.into())
+                .unwrap()
+                .success_amount,
+            route[0].amount
+        );
+        assert_eq!(
+            history
+                .get_result(&network.edges[1].2, &node3.into())
+                .unwrap()
+                .fail_amount,
+            route[1].amount
+        );
 
-        let route = network.find_route(1, 3, 10, 100);
-        assert!(route.is_err());
+        let route = network
+            .graph
+            .build_route(&payment_data, &HashSet::new())
+            .unwrap();
+        assert_eq!(route[0].next_hop, Some(node1.into()));
+
+        network.graph.reset_payment_history(None);
+        let route = network
+            .graph
+            .build_route(&payment_data, &HashSet::new())
+            .unwrap();
+        assert_eq!(route[0].next_hop, Some(node2.into()));
     }
 }
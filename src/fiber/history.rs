@@ -0,0 +1,220 @@
+use super::graph::NetworkGraphStateStore;
+use super::types::Pubkey;
+use ckb_types::packed::OutPoint;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// The probability of a channel to forward a payment if we know nothing about it.
+pub(crate) const DEFAULT_APRIORI_PROBABILITY: f64 = 0.5;
+
+// The weight of a payment result is halved every time this period elapses,
+// so that the stale results are gradually forgotten.
+const DEFAULT_HALF_LIFE_MILLIS: u64 = 60 * 60 * 1000;
+
+/// The latest results of sending payments through a channel in the direction to a node.
+/// A timestamp of 0 means no such result is recorded.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimedResult {
+    // The largest amount which was forwarded successfully, and when.
+    pub success_amount: u128,
+    pub success_time: u64,
+    // The smallest amount which failed to be forwarded, and when.
+    pub fail_amount: u128,
+    pub fail_time: u64,
+}
+
+/// Mission control of the payments, it learns from the results of the previous payments
+/// and estimates the success probability of the channels while finding routes.
+#[derive(Clone, Debug)]
+pub struct PaymentHistory<S> {
+    // The results keyed by the channel outpoint and the node receiving the payment.
+    results: HashMap<(OutPoint, Pubkey), TimedResult>,
+    store: S,
+}
+
+impl<S> PaymentHistory<S>
+where
+    S: NetworkGraphStateStore,
+{
+    pub fn new(store: S) -> Self {
+        let results = store
+            .get_payment_history_results()
+            .into_iter()
+            .map(|(channel_outpoint, target, result)| ((channel_outpoint, target), result))
+            .collect();
+        Self { results, store }
+    }
+
+    pub fn get_result(&self, channel_outpoint: &OutPoint, target: &Pubkey) -> Option<&TimedResult> {
+        self.results.get(&(channel_outpoint.clone(), *target))
+    }
+
+    pub fn get_results(&self) -> Vec<(OutPoint, Pubkey, TimedResult)> {
+        self.results
+            .iter()
+            .map(|((channel_outpoint, target), result)| {
+                (channel_outpoint.clone(), *target, *result)
+            })
+            .collect()
+    }
+
+    /// Records that `amount` was forwarded through the channel to `target` at `time`.
+    pub fn apply_success(
+        &mut self,
+        channel_outpoint: OutPoint,
+        target: Pubkey,
+        amount: u128,
+        time: u64,
+    ) {
+        let result = self
+            .results
+            .entry((channel_outpoint.clone(), target))
+            .or_default();
+        result.success_amount = result.success_amount.max(amount);
+        result.success_time = time;
+        // A smaller amount failed before is no longer an upper bound of the liquidity.
+        if result.fail_time != 0 && result.fail_amount <= amount {
+            result.fail_amount = amount + 1;
+        }
+        self.store
+            .insert_payment_history_result(channel_outpoint, target, *result);
+    }
+
+    /// Records that `amount` failed to be forwarded through the channel to `target` at `time`.
+    pub fn apply_failure(
+        &mut self,
+        channel_outpoint: OutPoint,
+        target: Pubkey,
+        amount: u128,
+        time: u64,
+    ) {
+        let result = self
+            .results
+            .entry((channel_outpoint.clone(), target))
+            .or_default();
+        result.fail_amount = amount;
+        result.fail_time = time;
+        // A larger amount succeeded before is no longer a lower bound of the liquidity.
+        if result.success_amount >= amount {
+            result.success_amount = amount.saturating_sub(1);
+        }
+        self.store
+            .insert_payment_history_result(channel_outpoint, target, *result);
+    }
+
+    /// Estimates the probability of forwarding `amount` through the channel to `target` at `now`.
+    /// A recent failure of a smaller or equal amount lowers the apriori probability, while a recent
+    /// success of a larger or equal amount raises it. Both effects decay as time goes by.
+    pub fn eval_probability(
+        &self,
+        channel_outpoint: &OutPoint,
+        target: &Pubkey,
+        amount: u128,
+        now: u64,
+    ) -> f64 {
+        let probability = DEFAULT_APRIORI_PROBABILITY;
+        let Some(result) = self.get_result(channel_outpoint, target) else {
+            return probability;
+        };
+        if result.fail_time != 0 && amount >= result.fail_amount {
+            probability * (1.0 - Self::time_decay(result.fail_time, now))
+        } else if result.success_time != 0 && amount <= result.success_amount {
+            probability + (1.0 - probability) * Self::time_decay(result.success_time, now)
+        } else {
+            probability
+        }
+    }
+
+    /// Forgets the results of the channel `channel_outpoint`, or all the results if it is None.
+    pub fn reset(&mut self, channel_outpoint: Option<&OutPoint>) {
+        match channel_outpoint {
+            Some(channel_outpoint) => self
+                .results
+                .retain(|(outpoint, _), _| outpoint != channel_outpoint),
+            None => self.results.clear(),
+        }
+        self.store
+            .remove_payment_history_results(channel_outpoint.cloned());
+    }
+
+    fn time_decay(time: u64, now: u64) -> f64 {
+        let elapsed = now.saturating_sub(time);
+        0.5_f64.powf(elapsed as f64 / DEFAULT_HALF_LIFE_MILLIS as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fiber::test_utils::{generate_pubkey, MemoryStore};
+    use ckb_types::packed::OutPointBuilder;
+    use ckb_types::prelude::{Builder, Pack};
+
+    fn gen_outpoint(index: u32) -> OutPoint {
+        OutPointBuilder::default().index(index.pack()).build()
+    }
+
+    #[test]
+    fn test_payment_history_eval_probability() {
+        let mut history = PaymentHistory::new(MemoryStore::default());
+        let channel = gen_outpoint(0);
+        let target: Pubkey = generate_pubkey().into();
+        let now = 1_000_000_000;
+
+        assert_eq!(
+            history.eval_probability(&channel, &target, 100, now),
+            DEFAULT_APRIORI_PROBABILITY
+        );
+
+        history.apply_failure(channel.clone(), target, 100, now);
+        assert_eq!(history.eval_probability(&channel, &target, 100, now), 0.0);
+        assert_eq!(
+            history.eval_probability(&channel, &target, 50, now),
+            DEFAULT_APRIORI_PROBABILITY
+        );
+        // the failure is half forgotten after the half life
+        let later = now + DEFAULT_HALF_LIFE_MILLIS;
+        assert_eq!(
+            history.eval_probability(&channel, &target, 100, later),
+            DEFAULT_APRIORI_PROBABILITY / 2.0
+        );
+
+        history.apply_success(channel.clone(), target, 50, now);
+        assert_eq!(history.eval_probability(&channel, &target, 50, now), 1.0);
+        assert_eq!(
+            history.eval_probability(&channel, &target, 80, now),
+            DEFAULT_APRIORI_PROBABILITY
+        );
+
+        history.apply_success(channel.clone(), target, 120, now);
+        let result = history.get_result(&channel, &target).unwrap();
+        assert_eq!(result.success_amount, 120);
+        assert_eq!(result.fail_amount, 121);
+    }
+
+    #[test]
+    fn test_payment_history_persisted() {
+        let store = MemoryStore::default();
+        let mut history = PaymentHistory::new(store.clone());
+        let channel = gen_outpoint(1);
+        let target: Pubkey = generate_pubkey().into();
+        history.apply_failure(channel.clone(), target, 100, 1);
+
+        history.apply_failure(gen_outpoint(2), target, 100, 1);
+
+        let mut history = PaymentHistory::new(store.clone());
+        assert_eq!(history.get_results().len(), 2);
+        assert_eq!(
+            history.get_result(&channel, &target).unwrap().fail_amount,
+            100
+        );
+
+        history.reset(Some(&channel));
+        assert!(history.get_result(&channel, &target).is_none());
+        assert_eq!(PaymentHistory::new(store.clone()).get_results().len(), 1);
+
+        history.reset(None);
+        assert!(history.get_results().is_empty());
+        assert!(PaymentHistory::new(store).get_results().is_empty());
+    }
+}
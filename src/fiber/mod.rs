@@ -8,9 +8,13 @@ pub use network::{
 };
 
 mod fee;
+pub mod features;
+pub mod gossip;
 pub mod graph;
+pub mod history;
 mod key;
 mod path;
+pub mod peer_book;
 
 pub use key::KeyPair;
 
@@ -24,6 +28,8 @@ pub mod hash_algorithm;
 
 pub mod serde_utils;
 
+pub mod subscription;
+
 mod graph_syncer;
 
 #[cfg(test)]
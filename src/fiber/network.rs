@@ -33,7 +33,7 @@ use tentacle::{
     secio::PeerId,
     secio::SecioKeyPair,
     service::{
-        ProtocolHandle, ProtocolMeta, ServiceAsyncControl, ServiceError, ServiceEvent,
+        ProtocolHandle, ProtocolMeta, ServiceAsyncControl, ServiceError, ServiceEvent, SessionType,
         TargetProtocol,
     },
     traits::{ServiceHandle, ServiceProtocol},
@@ -50,32 +50,42 @@ use super::channel::{
     PublicChannelInfo, DEFAULT_COMMITMENT_FEE_RATE, DEFAULT_FEE_RATE,
 };
 use super::config::AnnouncedNodeName;
+use super::features::{Feature, FeatureVector};
 use super::fee::{calculate_commitment_tx_fee, default_minimal_ckb_amount};
+use super::gossip::{
+    GossipMetrics, GossipPenalty, GossipRateLimiter, MAX_STASHED_BROADCAST_MESSAGES,
+    MAX_STASHED_BROADCAST_MESSAGES_PER_PEER,
+};
 use super::graph::{NetworkGraph, NetworkGraphStateStore};
 use super::graph_syncer::{GraphSyncer, GraphSyncerMessage};
 use super::key::blake2b_hash_with_salt;
+use super::peer_book::{ConnectedPeer, PeerBook, BANNED_FOREVER};
 use super::types::{
     ChannelAnnouncementQuery, ChannelUpdateQuery, EcdsaSignature, FiberBroadcastMessage,
-    FiberBroadcastMessageQuery, FiberMessage, FiberQueryInformation, GetBroadcastMessages,
-    GetBroadcastMessagesResult, Hash256, NodeAnnouncement, NodeAnnouncementQuery, OpenChannel,
-    Privkey, Pubkey, QueryBroadcastMessagesWithinTimeRange,
-    QueryBroadcastMessagesWithinTimeRangeResult, QueryChannelsWithinBlockRange,
-    QueryChannelsWithinBlockRangeResult,
+    FiberBroadcastMessageQuery, FiberChannelMessage, FiberMessage, FiberQueryInformation,
+    GetBroadcastMessages, GetBroadcastMessagesResult, Hash256, Init, NodeAnnouncement,
+    NodeAnnouncementQuery, OpenChannel, PaymentHopData, Privkey, Pubkey,
+    QueryBroadcastMessagesWithinTimeRange, QueryBroadcastMessagesWithinTimeRangeResult,
+    QueryChannelsWithinBlockRange, QueryChannelsWithinBlockRangeResult,
 };
 use super::FiberConfig;
 
+use super::subscription::PeerEvent;
 use crate::ckb::contracts::{check_udt_script, is_udt_type_auto_accept};
 use crate::ckb::{CkbChainMessage, FundingRequest, FundingTx, TraceTxRequest, TraceTxResponse};
 use crate::fiber::channel::{
-    AddTlcCommand, AddTlcResponse, TxCollaborationCommand, TxUpdateCommand,
+    AddTlcCommand, AddTlcResponse, RemoveTlcCommand, TxCollaborationCommand, TxUpdateCommand,
+};
+use crate::fiber::graph::{
+    ChannelInfo, NodeInfo, PaymentPart, PaymentPartStatus, PaymentSession, PaymentSessionStatus,
 };
-use crate::fiber::graph::{ChannelInfo, NodeInfo, PaymentSession};
 use crate::fiber::types::{
     secp256k1_instance, FiberChannelMessage, PaymentOnionPacket, PeeledPaymentOnionPacket,
-    TxSignatures,
+    RemoveTlcFail, RemoveTlcFulfill, RemoveTlcReason, TlcErr, TxSignatures,
 };
 use crate::fiber::KeyPair;
-use crate::invoice::{CkbInvoice, InvoiceStore};
+use crate::invoice::{CkbInvoice, CkbInvoiceStatus, HeldPayment, InvoiceStore, PendingMppPayment};
+use crate::sweeper::SweepOutput;
 use crate::{unwrap_or_return, Error};
 
 pub const FIBER_PROTOCOL_ID: ProtocolId = ProtocolId::new(42);
@@ -91,8 +101,38 @@ const ASSUME_CHAIN_ACTOR_ALWAYS_ALIVE_FOR_NOW: &str =
 
 const ASSUME_NETWORK_MYSELF_ALIVE: &str = "network actor myself alive";
 
+// The maximum number of attempts to send a payment, each attempt uses a different route.
+const DEFAULT_PAYMENT_TRY_LIMIT: u32 = 3;
+
+// The received parts of a multi-part payment are failed if the total amount
+// is not reached within this time after the first part arrives.
+const MPP_TIMEOUT_MILLIS: u64 = 60 * 1000;
+
+// The interval to check whether the pending multi-part payments are timed out.
+const MPP_TIMEOUT_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+// The held tlcs of a hold invoice are failed this long before they expire, so that the
+// channels are not force closed because of the expired tlcs.
+const HELD_PAYMENT_EXPIRY_MARGIN_MILLIS: u64 = 60 * 60 * 1000;
+
+// The interval to check whether the held tlcs of the hold invoices are about to expire.
+const HELD_PAYMENT_EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+// The interval to reconnect to the disconnected peers we share channels with,
+// each peer is reconnected with an exponential backoff.
+const RECONNECT_PEERS_INTERVAL: Duration = Duration::from_secs(5);
+
+static CHAIN_HASH_INSTANCE: once_cell::sync::OnceCell<Hash256> = once_cell::sync::OnceCell::new();
+
+/// Set the chain hash of the CKB network the node runs on, see `CkbNetwork::chain_hash`.
+/// It must be called before the network actor is started and only the first call takes effect.
+pub fn init_chain_hash(chain_hash: Hash256) {
+    let _ = CHAIN_HASH_INSTANCE.set(chain_hash);
+}
+
 pub(crate) fn get_chain_hash() -> Hash256 {
-    Default::default()
+    // The chain hash is never initialized in unit tests, all the test nodes use the default one.
+    CHAIN_HASH_INSTANCE.get().copied().unwrap_or_default()
 }
 
 #[derive(Debug)]
@@ -111,6 +151,14 @@ pub struct SendPaymentResponse {
     pub payment_hash: Hash256,
 }
 
+#[derive(Debug)]
+pub struct BuildRouteResponse {
+    // The candidate routes, each route contains the hops from us to the target.
+    pub routes: Vec<Vec<PaymentHopData>>,
+    // The payment hash of the probe payment, only set in probe mode.
+    pub probe_payment_hash: Option<Hash256>,
+}
+
 /// What kind of local information should be broadcasted to the network.
 #[derive(Debug)]
 pub enum LocalInfoKind {
@@ -127,6 +175,16 @@ pub enum NetworkActorCommand {
     /// Network commands
     ConnectPeer(Multiaddr),
     DisconnectPeer(PeerId),
+    // Get the peers we are connected to.
+    ListPeers(RpcReplyPort<Vec<ConnectedPeer>>),
+    // Ban a peer for the duration in seconds, or forever if the duration is not set.
+    BanPeer(PeerId, Option<u64>),
+    UnbanPeer(PeerId),
+    // Save the addresses of a peer learned from its NodeAnnouncement to the peer book, and connect
+    // to it if we don't have enough outbound connections.
+    SavePeerAddresses(PeerId, Vec<Multiaddr>),
+    // Reconnect to the disconnected peers we share channels with.
+    ReconnectPeers,
     // For internal use and debugging only. Most of the messages requires some
     // changes to local state. Even if we can send a message to a peer, some
     // part of the local state is not changed.
@@ -168,6 +226,11 @@ pub enum NetworkActorCommand {
         SendPaymentCommand,
         RpcReplyPort<Result<SendPaymentResponse, String>>,
     ),
+    // Build candidate routes to a node without sending anything, or probe the best route.
+    BuildRoute(
+        BuildRouteCommand,
+        RpcReplyPort<Result<BuildRouteResponse, String>>,
+    ),
     GetAndProcessChannelsWithinBlockRangeFromPeer(
         (PeerId, u64, u64),
         RpcReplyPort<Result<(u64, bool), Error>>,
@@ -179,6 +242,16 @@ pub enum NetworkActorCommand {
     StartSyncing,
     StopSyncing,
     MarkSyncingDone,
+    // Fail the received parts of the multi-part payments which are timed out.
+    CheckMppTimeout,
+    // Settle or fail the held tlcs paying a hold invoice after it is settled or cancelled.
+    ResolveHeldPayment(Hash256),
+    // Fail the held tlcs of the hold invoices which are about to expire.
+    CheckHeldPaymentsExpiry,
+    // Remove the channels whose funding cells are spent and the stale channels from the network graph.
+    PruneNetworkGraph,
+    // Get the counters of the broadcast messages dropped by the spam protection.
+    GetGossipMetrics(RpcReplyPort<GossipMetrics>),
 }
 
 pub async fn sign_network_message(
@@ -280,6 +353,10 @@ impl SendPaymentCommand {
             "amount",
         )?;
 
+        if self.max_parts == Some(0) {
+            return Err("max_parts should be greater than 0".to_string());
+        }
+
         let udt_type_script = match validate_field(
             self.udt_type_script.clone(),
             invoice.as_ref().and_then(|i| i.udt_type_script().cloned()),
@@ -326,6 +403,115 @@ impl SendPaymentCommand {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct BuildRouteCommand {
+    // the target node of the routes
+    pub target_pubkey: Pubkey,
+    // the amount to be received by the target
+    pub amount: u128,
+    // udt type script
+    pub udt_type_script: Option<Script>,
+    // the maximum fee amounts in shannons that the sender is willing to pay
+    pub max_fee_amount: Option<u128>,
+    // the maximum number of candidate routes to return, default is 1
+    pub max_routes: Option<u64>,
+    // send a tlc with a random payment hash along the best route to check its liquidity,
+    // the target will fail the tlc since it can not settle it.
+    pub probe: bool,
+}
+
+impl From<BuildRouteCommand> for SendPaymentCommand {
+    fn from(command: BuildRouteCommand) -> Self {
+        // a random payment hash which is unknown to the target
+        let mut rng = rand::thread_rng();
+        let mut payment_hash = [0u8; 32];
+        rng.fill(&mut payment_hash[..]);
+        SendPaymentCommand {
+            target_pubkey: Some(command.target_pubkey),
+            amount: Some(command.amount),
+            payment_hash: Some(payment_hash.into()),
+            invoice: None,
+            final_cltv_delta: None,
+            timeout: None,
+            max_fee_amount: command.max_fee_amount,
+            max_parts: None,
+            keysend: None,
+            udt_type_script: command.udt_type_script,
+        }
+    }
+}
+
+/// The validated data of a `SendPaymentCommand`. It is resolved only once when the payment
+/// is created, so that all the attempts of a payment share the same payment hash
+/// (and the same preimage in keysend mode).
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SendPaymentData {
+    pub target_pubkey: Pubkey,
+    pub amount: u128,
+    pub payment_hash: Hash256,
+    pub invoice: Option<String>,
+    pub final_cltv_delta: Option<u64>,
+    pub timeout: Option<u64>,
+    pub max_fee_amount: Option<u128>,
+    pub max_parts: Option<u64>,
+    pub keysend: bool,
+    #[serde_as(as = "Option<EntityHex>")]
+    pub udt_type_script: Option<Script>,
+    // only set in keysend mode, the preimage will be sent to the target in the onion packet
+    pub preimage: Option<Hash256>,
+    // a probe payment is expected to be failed by the target, which proves the route is usable
+    #[serde(default)]
+    pub probe: bool,
+}
+
+impl SendPaymentData {
+    pub fn new(command: SendPaymentCommand) -> Result<Self, String> {
+        let (target_pubkey, amount, payment_hash, preimage, udt_type_script) =
+            command.check_valid()?;
+        Ok(Self {
+            target_pubkey,
+            amount,
+            payment_hash,
+            invoice: command.invoice,
+            final_cltv_delta: command.final_cltv_delta,
+            timeout: command.timeout,
+            max_fee_amount: command.max_fee_amount,
+            max_parts: command.max_parts,
+            keysend: command.keysend.unwrap_or(false),
+            udt_type_script,
+            preimage,
+            probe: false,
+        })
+    }
+
+    /// The maximum number of parts the payment can be split into, default is 1.
+    pub fn max_parts(&self) -> u64 {
+        self.max_parts.unwrap_or(1)
+    }
+
+    /// Whether the payment may be split into multiple parts, the target must then wait
+    /// for all the parts to arrive before settling any of them.
+    pub fn allow_mpp(&self) -> bool {
+        self.max_parts() > 1
+    }
+}
+
+/// A received tlc which is a part of a multi-part payment to us, or a tlc paying a hold
+/// invoice, which is a single part payment whose total amount is the tlc amount.
+#[derive(Clone, Debug)]
+pub struct ReceivedMppTlc {
+    pub payment_hash: Hash256,
+    pub channel_id: Hash256,
+    pub tlc_id: u64,
+    pub amount: u128,
+    pub total_amount: u128,
+    // The preimage to settle the payment, None if the payment hash is unknown to us.
+    pub preimage: Option<Hash256>,
+    // The locktime expiry of the tlc in seconds.
+    pub expiry: u64,
+}
+
 #[derive(Debug)]
 pub struct AcceptChannelCommand {
     pub temp_channel_id: Hash256,
@@ -358,6 +544,8 @@ pub enum NetworkServiceEvent {
     // The channel is ready to use (with funding transaction confirmed
     // and both parties sent ChannelReady messages).
     ChannelReady(PeerId, Hash256, OutPoint),
+    // The funding cell of the channel is replaced by the confirmed splice transaction.
+    ChannelSpliced(PeerId, Hash256, OutPoint),
     ChannelClosed(PeerId, Hash256, Byte32),
     // We should sign a commitment transaction and send it to the other party.
     CommitmentSignaturePending(PeerId, Hash256, u64),
@@ -383,6 +571,12 @@ pub enum NetworkServiceEvent {
     // and we successfully assemble the partial signature from other party
     // to create a complete commitment transaction.
     RemoteCommitmentSigned(PeerId, Hash256, u64, TransactionView),
+    // Our commitment transaction of a force closed channel is confirmed,
+    // the sweeper claims our funds from the commitment cell.
+    CommitmentTransactionConfirmed(PeerId, Hash256, SweepOutput),
+    // The preimage of a forwarded tlc is received from the next hop, the sweeper claims
+    // the tlc received from the previous hop with it if that channel is force closed.
+    TlcPreimageReceived(Hash256 /* Payment hash */, Hash256 /* Preimage */),
     // The syncing of network information has completed.
     SyncingCompleted,
 }
@@ -416,6 +610,9 @@ pub enum NetworkActorEvent {
     ),
     /// A channel is ready to use.
     ChannelReady(Hash256, PeerId, OutPoint),
+    /// The splice transaction of a channel is confirmed, the two outpoints are respectively
+    /// the old and the new funding cell of the channel, followed by the new channel capacity.
+    ChannelSpliced(Hash256, PeerId, OutPoint, OutPoint, u128),
     /// A channel is already closed.
     ClosingTransactionPending(Hash256, PeerId, TransactionView),
 
@@ -446,6 +643,13 @@ pub enum NetworkActorEvent {
     /// A closing transaction has failed (either because of invalid transaction or timeout)
     ClosingTransactionFailed(PeerId, Hash256, Byte32),
 
+    /// A tlc offered by us as the origin of a payment is removed by the peer.
+    /// The Hash256 is the payment hash of the tlc, followed by the channel id and tlc id.
+    TlcRemoveReceived(Hash256, (Hash256, u64), RemoveTlcReason),
+
+    /// A part of a multi-part payment to us is received and confirmed in a channel.
+    MppTlcReceived(ReceivedMppTlc),
+
     // The graph syncer to the peer has exited with some reason.
     GraphSyncerExited(PeerId, GraphSyncerExitStatus),
 
@@ -537,6 +741,9 @@ where
         message: FiberMessage,
     ) -> crate::Result<()> {
         match message {
+            FiberMessage::Init(init) => {
+                state.on_init_msg(&peer_id, init).await;
+            }
             // We should process OpenChannel message here because there is no channel corresponding
             // to the channel id in the message yet.
             FiberMessage::ChannelInitialization(open_channel) => {
@@ -581,6 +788,18 @@ where
                     error!("Failed to process broadcasted message: {:?}", e);
                 }
             }
+            FiberMessage::ChannelNormalOperation(FiberChannelMessage::ReestablishChannel(
+                reestablish_channel,
+            )) if reestablish_channel.data_loss_protect.is_none()
+                && state
+                    .peer_book
+                    .peer_supports(&peer_id, Feature::DataLossProtect) =>
+            {
+                error!(
+                    "Peer {:?} supports DataLossProtect but reestablished channel {:?} without it",
+                    peer_id, reestablish_channel.channel_id
+                );
+            }
             FiberMessage::ChannelNormalOperation(m) => {
                 let channel_id = m.get_channel_id();
                 state
@@ -972,11 +1191,36 @@ where
                 self.on_service_event(e).await;
             }
             NetworkActorEvent::PeerConnected(id, pubkey, session) => {
-                self.network_graph
-                    .write()
-                    .await
-                    .add_connected_peer(&id, session.address.clone());
+                let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+                if state.peer_book.is_banned(&id, now) {
+                    warn!("Disconnecting banned peer {:?}", &id);
+                    state.control.disconnect(session.id).await?;
+                    return Ok(());
+                }
+                // Peers we share channels with are always accepted.
+                if session.ty.is_inbound()
+                    && !state.peer_book.has_free_slot(SessionType::Inbound)
+                    && state.store.get_active_channel_ids_by_peer(&id).is_empty()
+                {
+                    info!(
+                        "Disconnecting peer {:?} as we have too many inbound connections",
+                        &id
+                    );
+                    state.control.disconnect(session.id).await?;
+                    return Ok(());
+                }
+                state.peer_book.on_peer_connected(ConnectedPeer {
+                    peer_id: id.clone(),
+                    pubkey,
+                    address: session.address.clone(),
+                    session_type: session.ty,
+                    features: None,
+                });
                 state.on_peer_connected(&id, pubkey, &session).await;
+                state
+                    .channel_subscribers
+                    .node_events
+                    .notify_peer(PeerEvent::Connected(id.clone(), session.address.clone()));
                 // Notify outside observers.
                 myself
                     .send_message(NetworkActorMessage::new_event(
@@ -988,8 +1232,13 @@ where
                     .expect(ASSUME_NETWORK_MYSELF_ALIVE);
             }
             NetworkActorEvent::PeerDisconnected(id, session) => {
-                self.network_graph.write().await.remove_connected_peer(&id);
+                let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+                state.peer_book.on_peer_disconnected(&id, now);
                 state.on_peer_disconnected(&id);
+                state
+                    .channel_subscribers
+                    .node_events
+                    .notify_peer(PeerEvent::Disconnected(id.clone(), session.address.clone()));
                 // Notify outside observers.
                 myself
                     .send_message(NetworkActorMessage::new_event(
@@ -1043,6 +1292,7 @@ where
                                         remote_amount: remote as u64,
                                         local_reserved_ckb_amount,
                                         remote_reserved_ckb_amount,
+                                        splice_input: None,
                                     },
                                 ),
                             ))
@@ -1072,6 +1322,36 @@ where
                     ))
                     .expect(ASSUME_NETWORK_MYSELF_ALIVE);
             }
+            NetworkActorEvent::ChannelSpliced(
+                channel_id,
+                peer_id,
+                old_outpoint,
+                new_outpoint,
+                capacity,
+            ) => {
+                info!(
+                    "Channel ({:?}) to peer {:?} is spliced to {:?}",
+                    channel_id, peer_id, new_outpoint
+                );
+                state.outpoint_channel_map.remove(&old_outpoint);
+                state
+                    .outpoint_channel_map
+                    .insert(new_outpoint.clone(), channel_id);
+                self.network_graph.write().await.splice_channel(
+                    &old_outpoint,
+                    new_outpoint.clone(),
+                    capacity,
+                );
+
+                // Notify outside observers.
+                myself
+                    .send_message(NetworkActorMessage::new_event(
+                        NetworkActorEvent::NetworkServiceEvent(
+                            NetworkServiceEvent::ChannelSpliced(peer_id, channel_id, new_outpoint),
+                        ),
+                    ))
+                    .expect(ASSUME_NETWORK_MYSELF_ALIVE);
+            }
             NetworkActorEvent::PeerMessage(peer_id, message) => {
                 self.handle_peer_message(state, peer_id, message).await?
             }
@@ -1118,6 +1398,17 @@ where
                     &channel_id, &tx_hash, &peer_id
                 );
             }
+            NetworkActorEvent::TlcRemoveReceived(
+                payment_hash,
+                first_hop_tlc,
+                remove_tlc_reason,
+            ) => {
+                self.on_remove_tlc_event(state, payment_hash, first_hop_tlc, remove_tlc_reason)
+                    .await;
+            }
+            NetworkActorEvent::MppTlcReceived(tlc) => {
+                state.on_mpp_tlc_received(tlc).await;
+            }
             NetworkActorEvent::LocalCommitmentSigned(peer_id, channel_id, version, tx) => {
                 // Notify outside observers.
                 myself
@@ -1162,7 +1453,27 @@ where
         debug!("Handling command: {:?}", command);
         match command {
             NetworkActorCommand::SendFiberMessage(FiberMessageWithPeerId { peer_id, message }) => {
-                state.send_message_to_peer(&peer_id, message).await?;
+                // The messages and fields of DataLossProtect are only sent to peers supporting it.
+                let supports_data_loss_protect = state
+                    .peer_book
+                    .peer_supports(&peer_id, Feature::DataLossProtect);
+                match message {
+                    FiberMessage::ChannelNormalOperation(
+                        FiberChannelMessage::CommitmentSecret(_),
+                    ) if !supports_data_loss_protect => {}
+                    FiberMessage::ChannelNormalOperation(
+                        FiberChannelMessage::ReestablishChannel(mut reestablish_channel),
+                    ) if !supports_data_loss_protect => {
+                        reestablish_channel.data_loss_protect = None;
+                        state
+                            .send_message_to_peer(
+                                &peer_id,
+                                FiberMessage::reestablish_channel(reestablish_channel),
+                            )
+                            .await?;
+                    }
+                    message => state.send_message_to_peer(&peer_id, message).await?,
+                }
             }
 
             NetworkActorCommand::ConnectPeer(addr) => {
@@ -1179,11 +1490,39 @@ where
             }
 
             NetworkActorCommand::DisconnectPeer(peer_id) => {
+                state.peer_book.on_peer_disconnected_by_operator(&peer_id);
                 if let Some(session) = state.get_peer_session(&peer_id) {
                     state.control.disconnect(session).await?;
                 }
             }
 
+            NetworkActorCommand::ListPeers(reply) => {
+                let _ = reply.send(state.peer_book.get_connected_peers());
+            }
+
+            NetworkActorCommand::BanPeer(peer_id, duration_seconds) => {
+                let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+                let until = match duration_seconds {
+                    Some(seconds) => now.saturating_add(seconds.saturating_mul(1000)),
+                    None => BANNED_FOREVER,
+                };
+                state.ban_peer(&peer_id, until).await;
+            }
+
+            NetworkActorCommand::UnbanPeer(peer_id) => {
+                if !state.peer_book.unban(&peer_id) {
+                    debug!("Peer {:?} is not banned", &peer_id);
+                }
+            }
+
+            NetworkActorCommand::SavePeerAddresses(peer_id, addresses) => {
+                state.save_peer_addresses(&peer_id, addresses).await?;
+            }
+
+            NetworkActorCommand::ReconnectPeers => {
+                state.reconnect_peers().await;
+            }
+
             NetworkActorCommand::OpenChannel(open_channel, reply) => {
                 match state.create_outbound_channel(open_channel).await {
                     Ok((_, channel_id)) => {
@@ -1212,48 +1551,69 @@ where
 
             NetworkActorCommand::ControlFiberChannel(c) => {
                 info!("send command to channel: {:?}", c);
-                state
-                    .send_command_to_channel(c.channel_id, c.command)
-                    .await?
+                // Peers not sending the Init message are older nodes which may support the features.
+                let required_feature = match c.command {
+                    ChannelCommand::Splice(..) => Some(Feature::Splicing),
+                    ChannelCommand::BumpFundingFee(..) => Some(Feature::FundingTxRbf),
+                    _ => None,
+                };
+                let feature_unsupported = required_feature.is_some_and(|feature| {
+                    state
+                        .store
+                        .get_channel_actor_state(&c.channel_id)
+                        .and_then(|channel| {
+                            state
+                                .peer_book
+                                .get_features(&channel.get_remote_peer_id())
+                                .cloned()
+                        })
+                        .is_some_and(|features| !features.supports(feature))
+                });
+                match c.command {
+                    ChannelCommand::Splice(_, reply) if feature_unsupported => {
+                        let _ = reply.send(Err("Peer does not support splicing".to_string()));
+                    }
+                    ChannelCommand::BumpFundingFee(_, reply) if feature_unsupported => {
+                        let error = "Peer does not support funding transaction replacement";
+                        let _ = reply.send(Err(error.to_string()));
+                    }
+                    command => state.send_command_to_channel(c.channel_id, command).await?,
+                }
             }
 
             // TODO: we should check the OnionPacket is valid or not, only the current node can decrypt it.
             NetworkActorCommand::SendPaymentOnionPacket(peeled_packet_buf, previous_tlc) => {
                 if let Ok(peeled_packet) = PeeledPaymentOnionPacket::deserialize(&peeled_packet_buf)
                 {
-                    let current_hop_info = peeled_packet.current;
-                    debug!("Processing onion packet info: {:?}", current_hop_info);
-                    let channel_outpoint = &current_hop_info
-                        .channel_outpoint
-                        .expect("valid onion packet contains channel outpoint");
-                    let channel_id = match state.outpoint_channel_map.get(channel_outpoint) {
-                        Some(channel_id) => channel_id,
-                        None => {
-                            // TODO: Return error to the sender. This is a payment failure.
-                            error!("Failed to process onion packet: channel id not found for channel outpoint {:?}. Are we connected to the peer?", channel_outpoint);
-                            return Ok(());
+                    let channel_outpoint = peeled_packet.current.channel_outpoint.clone();
+                    match state
+                        .send_payment_onion_packet(peeled_packet, previous_tlc)
+                        .await
+                    {
+                        Ok(res) => {
+                            info!("send onion packet: {:?}", res);
                         }
-                    };
-                    let (send, recv) = oneshot::channel::<Result<AddTlcResponse, String>>();
-                    let rpc_reply = RpcReplyPort::from(send);
-                    let command = ChannelCommand::AddTlc(
-                        AddTlcCommand {
-                            amount: current_hop_info.amount,
-                            preimage: None,
-                            payment_hash: Some(current_hop_info.payment_hash),
-                            expiry: current_hop_info.expiry.into(),
-                            hash_algorithm: current_hop_info.tlc_hash_algorithm,
-                            onion_packet: peeled_packet
-                                .next
-                                .map(|next| next.data)
-                                .unwrap_or_default(),
-                            previous_tlc,
-                        },
-                        rpc_reply,
-                    );
-                    state.send_command_to_channel(*channel_id, command).await?;
-                    let res = recv.await.expect("recv add tlc response");
-                    info!("send onion packet: {:?}", res);
+                        Err(err) => {
+                            error!("Failed to forward onion packet: {}", err);
+                            // Fail the tlc from the previous hop, so that the payment origin
+                            // could retry the payment with another route.
+                            if let Some((previous_channel_id, previous_tlc_id)) = previous_tlc {
+                                let tlc_err = match channel_outpoint {
+                                    Some(channel_outpoint) => {
+                                        self.get_forwarding_tlc_err(state, channel_outpoint).await
+                                    }
+                                    None => TlcErr::new(TlcErr::TEMPORARY_CHANNEL_FAILURE),
+                                };
+                                state
+                                    .fail_received_tlc(
+                                        previous_channel_id,
+                                        previous_tlc_id,
+                                        tlc_err,
+                                    )
+                                    .await?;
+                            }
+                        }
+                    }
                 }
             }
             NetworkActorCommand::PeelPaymentOnionPacket(onion_packet, payment_hash, reply) => {
@@ -1464,7 +1824,7 @@ where
                 let _ = reply.send(signature);
             }
             NetworkActorCommand::SendPayment(payment_request, reply) => {
-                match self.on_send_payment(myself, payment_request).await {
+                match self.on_send_payment(state, payment_request).await {
                     Ok(payment_hash) => {
                         let _ = reply.send(Ok(SendPaymentResponse { payment_hash }));
                     }
@@ -1474,6 +1834,17 @@ where
                     }
                 }
             }
+            NetworkActorCommand::BuildRoute(command, reply) => {
+                match self.on_build_route(state, command).await {
+                    Ok(response) => {
+                        let _ = reply.send(Ok(response));
+                    }
+                    Err(e) => {
+                        error!("Failed to build route: {:?}", e);
+                        let _ = reply.send(Err(e.to_string()));
+                    }
+                }
+            }
             NetworkActorCommand::BroadcastLocalInfo(kind) => match kind {
                 LocalInfoKind::NodeAnnouncement => {
                     let message = state.get_or_create_new_node_announcement_message();
@@ -1487,6 +1858,65 @@ where
                         .expect(ASSUME_NETWORK_MYSELF_ALIVE);
                 }
             },
+            NetworkActorCommand::CheckMppTimeout => {
+                state.check_mpp_timeout().await;
+            }
+            NetworkActorCommand::ResolveHeldPayment(payment_hash) => {
+                state.on_resolve_held_payment(payment_hash).await;
+            }
+            NetworkActorCommand::CheckHeldPaymentsExpiry => {
+                state.check_held_payments_expiry().await;
+            }
+            NetworkActorCommand::GetGossipMetrics(reply) => {
+                let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+                let mut metrics = state.gossip_metrics.clone();
+                metrics.banned_peers = state.peer_book.get_banned_peers(now).len() as u64;
+                let _ = reply.send(metrics);
+            }
+            NetworkActorCommand::PruneNetworkGraph => {
+                let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+                let stale_before =
+                    now.saturating_sub(state.stale_channel_timeout_seconds.saturating_mul(1000));
+                let outpoints: Vec<OutPoint> = {
+                    let mut graph = self.network_graph.write().await;
+                    graph.prune_stale_channels(stale_before);
+                    graph
+                        .channels()
+                        .map(|channel| channel.out_point())
+                        .collect()
+                };
+
+                // Checking the funding cells of all the channels may take a while,
+                // spawn a new task to avoid blocking current actor message processing.
+                let chain_actor = self.chain_actor.clone();
+                let network_graph = self.network_graph.clone();
+                ractor::concurrency::tokio_primatives::spawn(async move {
+                    for outpoint in outpoints {
+                        match call_t!(
+                            chain_actor,
+                            CkbChainMessage::IsCellLive,
+                            DEFAULT_CHAIN_ACTOR_TIMEOUT,
+                            outpoint.clone()
+                        ) {
+                            Ok(Ok(true)) => {}
+                            Ok(Ok(false)) => {
+                                info!(
+                                    "Funding cell of channel {:?} is spent, pruning it from network graph",
+                                    &outpoint
+                                );
+                                network_graph.write().await.prune_channel(&outpoint);
+                            }
+                            Ok(Err(err)) => {
+                                error!("Failed to check funding cell {:?}: {}", &outpoint, err);
+                            }
+                            Err(err) => {
+                                error!("Failed to check funding cell {:?}: {}", &outpoint, err);
+                                break;
+                            }
+                        }
+                    }
+                });
+            }
             NetworkActorCommand::MarkSyncingDone => {
                 info!("Syncing network information finished");
                 state.sync_status = NetworkSyncStatus::Done;
@@ -1604,7 +2034,31 @@ where
         peer_id: PeerId,
         message: FiberBroadcastMessage,
     ) -> Result<(), Error> {
+        let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+        if state.peer_book.is_banned(&peer_id, now) {
+            debug!(
+                "Dropping broadcasted message from banned peer {:?}: {:?}",
+                &peer_id, &message
+            );
+            state.gossip_metrics.from_banned_peers += 1;
+            return Ok(());
+        }
         if state.sync_status.is_syncing() {
+            if state.broadcasted_message_queue.len() >= MAX_STASHED_BROADCAST_MESSAGES
+                || state
+                    .broadcasted_message_queue
+                    .iter()
+                    .filter(|(stashed_peer_id, _)| stashed_peer_id == &peer_id)
+                    .count()
+                    >= MAX_STASHED_BROADCAST_MESSAGES_PER_PEER
+            {
+                debug!(
+                    "Dropping broadcasted message from peer {:?} as the queue is full: {:?}",
+                    &peer_id, &message
+                );
+                state.gossip_metrics.stash_full += 1;
+                return Ok(());
+            }
             debug!(
                 "Saving broadcasted message to queue as we are syncing: {:?}",
                 &message
@@ -1612,15 +2066,51 @@ where
             state.broadcasted_message_queue.push((peer_id, message));
             return Ok(());
         }
+        // The message is already processed and rebroadcasted, we receive it again
+        // because it is relayed by multiple peers.
+        if state.broadcasted_messages.contains(&message.id()) {
+            return Ok(());
+        }
+        // The peer is not penalized, it may be an honest peer relaying the messages of the origin.
+        if !state.gossip_rate_limiter.check(&message, now) {
+            warn!(
+                "Dropping broadcasted message from peer {:?} as its origin exceeded the rate limit: {:?}",
+                &peer_id, &message
+            );
+            state.gossip_metrics.rate_limited += 1;
+            return Ok(());
+        }
+        if let Err(err) = self
+            .process_broadcasted_message(&state.network, message.clone())
+            .await
+        {
+            state.gossip_metrics.invalid += 1;
+            let penalty = match err {
+                Error::InvalidPeerMessage(_) => GossipPenalty::Invalid,
+                _ => GossipPenalty::Unprocessable,
+            };
+            state.penalize_peer(&peer_id, penalty, now).await;
+            return Err(err);
+        }
+        state.gossip_rate_limiter.consume(&message, now);
+        if let FiberBroadcastMessage::ChannelAnnouncement(ref channel_announcement) = message {
+            if self
+                .network_graph
+                .read()
+                .await
+                .is_channel_pruned(&channel_announcement.channel_outpoint)
+            {
+                return Ok(());
+            }
+        }
         // Rebroadcast the message to other peers if necessary.
         state
             .network
             .send_message(NetworkActorMessage::new_command(
-                NetworkActorCommand::BroadcastMessage(vec![], message.clone()),
+                NetworkActorCommand::BroadcastMessage(vec![], message),
             ))
             .expect(ASSUME_NETWORK_MYSELF_ALIVE);
-        self.process_broadcasted_message(&state.network, message)
-            .await
+        Ok(())
     }
 
     async fn process_broadcasted_message(
@@ -1637,7 +2127,7 @@ where
                     .await
                     .check_chain_hash(node_announcement.chain_hash)
                 {
-                    return Err(Error::InvalidParameter(format!(
+                    return Err(Error::InvalidPeerMessage(format!(
                         "Node announcement chain hash mismatched: {:?}",
                         &node_announcement
                     )));
@@ -1659,16 +2149,16 @@ where
                         };
                         self.network_graph.write().await.add_node(node_info);
 
-                        // TODO: bookkeeping how many nodes we have connected to. Stop connnecting once we surpass a threshold.
-                        for addr in &node_announcement.addresses {
-                            network.send_message(NetworkActorMessage::new_command(
-                                NetworkActorCommand::ConnectPeer(addr.clone()),
-                            ))?;
-                        }
+                        network.send_message(NetworkActorMessage::new_command(
+                            NetworkActorCommand::SavePeerAddresses(
+                                node_announcement.node_id.tentacle_peer_id(),
+                                node_announcement.addresses.clone(),
+                            ),
+                        ))?;
                         Ok(())
                     }
                     _ => {
-                        return Err(Error::InvalidParameter(format!(
+                        return Err(Error::InvalidPeerMessage(format!(
                             "Node announcement message signature verification failed: {:?}",
                             &node_announcement
                         )));
@@ -1683,7 +2173,7 @@ where
                 );
                 let message = channel_announcement.message_to_sign();
                 if channel_announcement.node1_id == channel_announcement.node2_id {
-                    return Err(Error::InvalidParameter(format!(
+                    return Err(Error::InvalidPeerMessage(format!(
                         "Channel announcement node had a channel with itself: {:?}",
                         &channel_announcement
                     )));
@@ -1694,11 +2184,24 @@ where
                     .await
                     .check_chain_hash(channel_announcement.chain_hash)
                 {
-                    return Err(Error::InvalidParameter(format!(
+                    return Err(Error::InvalidPeerMessage(format!(
                         "Channel announcement chain hash mismatched: {:?}",
                         &channel_announcement
                     )));
                 }
+                // Honest peers may still relay the channels we have pruned, just ignore them.
+                if self
+                    .network_graph
+                    .read()
+                    .await
+                    .is_channel_pruned(&channel_announcement.channel_outpoint)
+                {
+                    debug!(
+                        "Ignoring channel announcement of pruned channel: {:?}",
+                        &channel_announcement
+                    );
+                    return Ok(());
+                }
                 let (node1_signature, node2_signature, ckb_signature) = match (
                     &channel_announcement.node1_signature,
                     &channel_announcement.node2_signature,
@@ -1708,7 +2211,7 @@ where
                         (node1_signature, node2_signature, ckb_signature)
                     }
                     _ => {
-                        return Err(Error::InvalidParameter(format!(
+                        return Err(Error::InvalidPeerMessage(format!(
                             "Channel announcement message signature verification failed, some signatures are missing: {:?}",
                             &channel_announcement
                         )));
@@ -1716,7 +2219,7 @@ where
                 };
 
                 if !node1_signature.verify(&channel_announcement.node1_id, &message) {
-                    return Err(Error::InvalidParameter(format!(
+                    return Err(Error::InvalidPeerMessage(format!(
                         "Channel announcement message signature verification failed for node 1: {:?}, message: {:?}, signature: {:?}, pubkey: {:?}",
                         &channel_announcement,
                         &message,
@@ -1726,7 +2229,7 @@ where
                 }
 
                 if !node2_signature.verify(&channel_announcement.node2_id, &message) {
-                    return Err(Error::InvalidParameter(format!(
+                    return Err(Error::InvalidPeerMessage(format!(
                         "Channel announcement message signature verification failed for node 2: {:?}, message: {:?}, signature: {:?}, pubkey: {:?}",
                         &channel_announcement,
                         &message,
@@ -1769,18 +2272,44 @@ where
 
                 debug!("Channel announcement transaction found: {:?}", &tx);
 
+                match call_t!(
+                    self.chain_actor,
+                    CkbChainMessage::IsCellLive,
+                    DEFAULT_CHAIN_ACTOR_TIMEOUT,
+                    channel_announcement.channel_outpoint.clone()
+                ) {
+                    Ok(Ok(true)) => {}
+                    Ok(Ok(false)) => {
+                        info!(
+                            "Funding cell of announced channel {:?} is spent, ignoring it",
+                            &channel_announcement.channel_outpoint
+                        );
+                        self.network_graph
+                            .write()
+                            .await
+                            .prune_channel(&channel_announcement.channel_outpoint);
+                        return Ok(());
+                    }
+                    err => {
+                        return Err(Error::InvalidParameter(format!(
+                            "Failed to check funding cell {:?} of channel announcement, result is: {:?}",
+                            &channel_announcement.channel_outpoint, err
+                        )));
+                    }
+                }
+
                 let pubkey = channel_announcement.ckb_key.serialize();
                 let pubkey_hash = &blake2b_256(pubkey.as_slice())[0..20];
                 match tx.inner.outputs.first() {
                     None => {
-                        return Err(Error::InvalidParameter(format!(
+                        return Err(Error::InvalidPeerMessage(format!(
                             "On-chain transaction found but no output: {:?}",
                             &channel_announcement
                         )));
                     }
                     Some(output) => {
                         if output.lock.args.as_bytes() != pubkey_hash {
-                            return Err(Error::InvalidParameter(format!(
+                            return Err(Error::InvalidPeerMessage(format!(
                                 "On-chain transaction found but pubkey hash mismatched: on chain hash {:?}, pub key ({:?}) hash {:?}",
                                 &output.lock.args.as_bytes(),
                                 hex::encode(pubkey),
@@ -1791,7 +2320,7 @@ where
                         if channel_announcement.udt_type_script.is_none()
                             && capacity != channel_announcement.capacity
                         {
-                            return Err(Error::InvalidParameter(format!(
+                            return Err(Error::InvalidPeerMessage(format!(
                                 "On-chain transaction found but capacity mismatched: on chain capacity {:?}, channel capacity {:?}",
                                 &output.capacity, &channel_announcement.capacity
                             )));
@@ -1805,7 +2334,7 @@ where
                     &Message::from_digest(message),
                     &channel_announcement.ckb_key,
                 ) {
-                    return Err(Error::InvalidParameter(format!(
+                    return Err(Error::InvalidPeerMessage(format!(
                         "Channel announcement message signature verification failed for ckb: {:?}, message: {:?}, signature: {:?}, pubkey: {:?}, error: {:?}",
                         &channel_announcement,
                         &message,
@@ -1839,7 +2368,7 @@ where
                 let signature = match channel_update.signature {
                     Some(ref signature) => signature,
                     None => {
-                        return Err(Error::InvalidParameter(format!(
+                        return Err(Error::InvalidPeerMessage(format!(
                             "Channel update message signature verification failed (signature not found): {:?}",
                             &channel_update
                         )));
@@ -1858,7 +2387,7 @@ where
                         &channel_update, &pubkey, &message
                     );
                     if !signature.verify(&pubkey, &message) {
-                        return Err(Error::InvalidParameter(format!(
+                        return Err(Error::InvalidPeerMessage(format!(
                             "Channel update message signature verification failed (invalid signature): {:?}",
                             &channel_update
                         )));
@@ -1887,40 +2416,441 @@ where
 
     async fn on_send_payment(
         &self,
-        my_self: ActorRef<NetworkActorMessage>,
+        state: &mut NetworkActorState<S>,
         payment_request: SendPaymentCommand,
     ) -> Result<Hash256, Error> {
-        let graph = self.network_graph.read().await;
-        // initialize the payment session in db and begin the payment process in a statemachine to
-        // handle the payment process
-        let payment_session = PaymentSession::new(payment_request.clone(), 3);
+        let payment_data = SendPaymentData::new(payment_request).map_err(|e| {
+            error!("Failed to validate payment request: {:?}", e);
+            Error::InvalidParameter(format!("Failed to validate payment request: {:?}", e))
+        })?;
+        // Only the target reassembles the parts, a connected target which has not announced
+        // the feature would fail every part of the payment.
+        if payment_data.allow_mpp()
+            && state
+                .peer_book
+                .get_features(&payment_data.target_pubkey.tentacle_peer_id())
+                .is_some_and(|features| !features.supports(Feature::MultiPartPayment))
+        {
+            return Err(Error::InvalidParameter(
+                "Payment target does not support multi-part payments".to_string(),
+            ));
+        }
+        self.start_payment_session(state, payment_data).await
+    }
+
+    async fn on_build_route(
+        &self,
+        state: &mut NetworkActorState<S>,
+        command: BuildRouteCommand,
+    ) -> Result<BuildRouteResponse, Error> {
+        let max_routes = command.max_routes.unwrap_or(1);
+        if max_routes == 0 {
+            return Err(Error::InvalidParameter(
+                "max_routes should be greater than 0".to_string(),
+            ));
+        }
+        let probe = command.probe;
+        let mut payment_data = SendPaymentData::new(command.into()).map_err(|e| {
+            Error::InvalidParameter(format!("Failed to validate build route request: {:?}", e))
+        })?;
+        let routes = self
+            .network_graph
+            .read()
+            .await
+            .build_candidate_routes(&payment_data, max_routes as usize)
+            .map_err(|e| Error::SendPaymentError(format!("Failed to build route: {}", e)))?;
 
-        let hops_infos = graph.build_route(payment_request.clone())?;
-        assert!(!hops_infos.is_empty());
+        let probe_payment_hash = if probe {
+            payment_data.probe = true;
+            Some(self.start_payment_session(state, payment_data).await?)
+        } else {
+            None
+        };
+        Ok(BuildRouteResponse {
+            routes,
+            probe_payment_hash,
+        })
+    }
 
-        // generate session key
-        let session_key = Privkey::from_slice(KeyPair::generate_random_key().as_ref());
-        let peeled_packet =
-            PeeledPaymentOnionPacket::create(session_key, hops_infos, &Secp256k1::signing_only())
-                .map_err(|err| Error::InvalidOnionPacket(err))?;
+    // Persist the payment session and notify the subscribers of the payment events.
+    fn store_payment_session(
+        &self,
+        state: &NetworkActorState<S>,
+        payment_session: &PaymentSession,
+    ) {
+        self.store.insert_payment_session(payment_session.clone());
+        state
+            .channel_subscribers
+            .node_events
+            .notify_payment(payment_session);
+    }
 
-        let res = my_self.send_message(NetworkActorMessage::Command(
-            NetworkActorCommand::SendPaymentOnionPacket(peeled_packet.serialize(), None),
-        ));
-        info!("send_payment: {:?} => result: {:?}", payment_request, res);
+    async fn start_payment_session(
+        &self,
+        state: &mut NetworkActorState<S>,
+        payment_data: SendPaymentData,
+    ) -> Result<Hash256, Error> {
+        // for a payment session which is not failed, we should not send the payment again
+        if let Some(session) = self.store.get_payment_session(payment_data.payment_hash) {
+            if session.status != PaymentSessionStatus::Failed {
+                return Err(Error::InvalidParameter(format!(
+                    "Payment session already exists: {:?} with status {:?}",
+                    payment_data.payment_hash, session.status
+                )));
+            }
+        }
+
+        // initialize the payment session in db and begin the payment process in a statemachine to
+        // handle the payment process
+        let mut payment_session = PaymentSession::new(payment_data, DEFAULT_PAYMENT_TRY_LIMIT);
+        self.store_payment_session(state, &payment_session);
+        self.try_payment_session(state, &mut payment_session)
+            .await?;
         Ok(payment_session.payment_hash())
     }
-}
 
-#[derive(Debug, Clone)]
-struct NetworkSyncState {
-    // The block number we are syncing from.
-    starting_height: u64,
-    // The block number we are syncing up to.
-    // This is normally the tip block number when we startup. We will only actively sync
-    // channel announcement up to this number (other info will be broadcasted by peers).
-    ending_height: u64,
-    // The timestamp we started syncing.
+    // Try to send the remaining amount of the payment with new routes until the first hops
+    // accept the tlcs or the retry limit is reached. The amount may be split into several parts
+    // if `max_parts` allows. The session is persisted after every attempt.
+    async fn try_payment_session(
+        &self,
+        state: &mut NetworkActorState<S>,
+        payment_session: &mut PaymentSession,
+    ) -> Result<(), Error> {
+        while payment_session.can_retry() {
+            payment_session.retried_times += 1;
+
+            let remaining_amount = payment_session.remaining_amount();
+            let routes = self.network_graph.read().await.build_routes(
+                &payment_session.request,
+                remaining_amount,
+                payment_session.remaining_parts(),
+                &payment_session.excluded_channels(),
+            );
+            let routes = match routes {
+                Ok(routes) => routes,
+                Err(e) => {
+                    let error = format!("Failed to build route: {}", e);
+                    payment_session.set_failed_status(&error);
+                    self.store_payment_session(state, &payment_session);
+                    return Err(Error::SendPaymentError(error));
+                }
+            };
+
+            for hops_infos in routes {
+                assert!(!hops_infos.is_empty());
+
+                // generate session key
+                let session_key = Privkey::from_slice(KeyPair::generate_random_key().as_ref());
+                let peeled_packet = match PeeledPaymentOnionPacket::create(
+                    session_key.clone(),
+                    hops_infos.clone(),
+                    &Secp256k1::signing_only(),
+                ) {
+                    Ok(packet) => packet,
+                    Err(e) => {
+                        let error = format!("Failed to create onion packet: {}", e);
+                        payment_session.set_failed_status(&error);
+                        self.store_payment_session(state, &payment_session);
+                        return Err(Error::SendPaymentError(error));
+                    }
+                };
+
+                match state.send_payment_onion_packet(peeled_packet, None).await {
+                    Ok(first_hop_tlc) => {
+                        info!(
+                            "Payment {:?} is inflight with tlc {:?}, attempt: {}",
+                            payment_session.payment_hash(),
+                            first_hop_tlc,
+                            payment_session.retried_times
+                        );
+                        payment_session.add_inflight_part(PaymentPart::new(
+                            hops_infos,
+                            first_hop_tlc,
+                            *session_key.as_ref(),
+                        ));
+                    }
+                    Err(e) => {
+                        warn!(
+                            "Failed to send payment {:?} to the first hop: {}",
+                            payment_session.payment_hash(),
+                            e
+                        );
+                        if let Some(channel_outpoint) = hops_infos[0].channel_outpoint.clone() {
+                            payment_session.mark_channel_failed(channel_outpoint);
+                        }
+                        payment_session.last_error = Some(e);
+                    }
+                }
+                self.store_payment_session(state, &payment_session);
+            }
+
+            if payment_session.remaining_amount() == 0 {
+                return Ok(());
+            }
+        }
+
+        let error = format!(
+            "Failed to send payment after {} attempts, last error: {:?}",
+            payment_session.retried_times, payment_session.last_error
+        );
+        payment_session.set_failed_status(&error);
+        self.store_payment_session(state, &payment_session);
+        Err(Error::SendPaymentError(error))
+    }
+
+    async fn on_remove_tlc_event(
+        &self,
+        state: &mut NetworkActorState<S>,
+        payment_hash: Hash256,
+        first_hop_tlc: (Hash256, u64),
+        reason: RemoveTlcReason,
+    ) {
+        let Some(mut payment_session) = self.store.get_payment_session(payment_hash) else {
+            // The tlc may be added manually (e.g. through `add_tlc` RPC) without a payment session.
+            return;
+        };
+        let Some(part) = payment_session.get_part_mut(first_hop_tlc) else {
+            debug!(
+                "Ignoring removed tlc {:?} which is not a part of payment {:?}",
+                first_hop_tlc, payment_hash
+            );
+            return;
+        };
+        if part.status != PaymentPartStatus::Inflight {
+            debug!(
+                "Ignoring removed tlc {:?} for payment {:?} with part status {:?}",
+                first_hop_tlc, payment_hash, part.status
+            );
+            return;
+        }
+        match reason {
+            RemoveTlcReason::RemoveTlcFulfill(RemoveTlcFulfill { payment_preimage }) => {
+                info!("Payment {:?} succeeded", payment_hash);
+                part.status = PaymentPartStatus::Success;
+                self.network_graph
+                    .write()
+                    .await
+                    .record_payment_success(&part.route);
+                payment_session.set_success_status(payment_preimage);
+                self.store_payment_session(state, &payment_session);
+            }
+            RemoveTlcReason::RemoveTlcFail(error_packet) => {
+                part.status = PaymentPartStatus::Failed;
+                let part = part.clone();
+                if payment_session.status != PaymentSessionStatus::Inflight {
+                    // The payment is already finished, no need to send this part again.
+                    self.store_payment_session(state, &payment_session);
+                    return;
+                }
+                let hops_path = part.hops_path();
+                let mut is_mpp_timeout = false;
+                match error_packet.decode(&part.session_key, hops_path.clone()) {
+                    Some((tlc_err, erring_index)) => {
+                        let erring_node = hops_path[erring_index];
+                        warn!(
+                            "Payment {:?} failed at node {:?} with error {:?}, attempt: {}",
+                            payment_hash, erring_node, tlc_err, payment_session.retried_times
+                        );
+                        self.network_graph.write().await.record_payment_fail(
+                            &part.route,
+                            erring_index,
+                            &tlc_err,
+                        );
+                        if let Some(channel_update) = tlc_err.channel_update.clone() {
+                            // Apply the latest channel update from the erring node, so that the
+                            // following payments are routed with the up-to-date parameters.
+                            if let Err(e) = self
+                                .process_broadcasted_message(
+                                    &state.network,
+                                    FiberBroadcastMessage::ChannelUpdate(channel_update),
+                                )
+                                .await
+                            {
+                                debug!("Failed to process channel update in tlc error: {}", e);
+                            }
+                        }
+                        let error = format!(
+                            "Tlc failed at node {:?} with error code {}",
+                            erring_node, tlc_err.error_code
+                        );
+                        if payment_session.request.probe
+                            && erring_node == payment_session.request.target_pubkey
+                            && tlc_err.error_code == TlcErr::INCORRECT_OR_UNKNOWN_PAYMENT_DETAILS
+                        {
+                            // The probe reached the target, all the channels on the route
+                            // have enough liquidity to forward the amount.
+                            info!("Probe payment {:?} reached the target", payment_hash);
+                            payment_session.set_probe_success_status();
+                            self.store_payment_session(state, &payment_session);
+                            return;
+                        }
+                        if tlc_err.is_permanent()
+                            && erring_node == payment_session.request.target_pubkey
+                        {
+                            // The payment target rejected the payment, retrying with
+                            // another route won't help.
+                            payment_session.set_failed_status(&error);
+                            self.store_payment_session(state, &payment_session);
+                            return;
+                        }
+                        // A timed out part doesn't indicate any channel failure, the other parts
+                        // are failed by the target too, so they are sent again together.
+                        is_mpp_timeout = tlc_err.error_code == TlcErr::MPP_TIMEOUT;
+                        if !is_mpp_timeout {
+                            // Exclude the channel which the erring node failed to forward the tlc
+                            // to, or the channel to the erring node if the failure is not
+                            // channel specific.
+                            if let Some(channel_outpoint) = tlc_err
+                                .erring_channel
+                                .or_else(|| part.route[erring_index].channel_outpoint.clone())
+                            {
+                                payment_session.mark_channel_failed(channel_outpoint);
+                            }
+                        }
+                        payment_session.last_error = Some(error);
+                    }
+                    None => {
+                        warn!(
+                            "Payment {:?} failed with an undecodable error, attempt: {}",
+                            payment_hash, payment_session.retried_times
+                        );
+                        // The erring node is unknown, we blame the first hop channel on which
+                        // the failure is reported, so the next attempt will use another route.
+                        if let Some(channel_outpoint) = part.first_hop_channel_outpoint() {
+                            payment_session.mark_channel_failed(channel_outpoint);
+                        }
+                        payment_session.last_error =
+                            Some("Tlc failed with an undecodable error".to_string());
+                    }
+                }
+                self.store_payment_session(state, &payment_session);
+                // Retry once for all the parts of a timed out payment, instead of spending
+                // one attempt on every failed part.
+                if is_mpp_timeout && payment_session.inflight_parts().next().is_some() {
+                    debug!(
+                        "Waiting for the other timed out parts of payment {:?} before retrying",
+                        payment_hash
+                    );
+                    return;
+                }
+                if let Err(e) = self.try_payment_session(state, &mut payment_session).await {
+                    error!("Failed to retry payment {:?}: {}", payment_hash, e);
+                }
+            }
+        }
+    }
+
+    // Resume or finalize the payment sessions which are not finished before the node is stopped.
+    fn resume_payment_sessions(&self) {
+        for mut payment_session in self.store.get_payment_sessions() {
+            match payment_session.status {
+                PaymentSessionStatus::Created => {
+                    // The node is stopped before the first hop accepts the tlc, we can't retry
+                    // now since the channels are not reestablished yet.
+                    payment_session.set_failed_status("Payment is interrupted by node restarting");
+                    self.store.insert_payment_session(payment_session);
+                }
+                PaymentSessionStatus::Inflight => {
+                    let mut last_error = None;
+                    for part in payment_session
+                        .parts
+                        .iter_mut()
+                        .filter(|part| part.status == PaymentPartStatus::Inflight)
+                    {
+                        let (channel_id, tlc_id) = part.first_hop_tlc;
+                        let reason =
+                            self.store
+                                .get_channel_actor_state(&channel_id)
+                                .and_then(|channel| {
+                                    channel
+                                        .get_offered_tlc(tlc_id)
+                                        .map(|tlc| tlc.get_remove_reason())
+                                });
+                        match reason {
+                            // The tlc is still pending, we will be notified after it is removed.
+                            Some(None) => {}
+                            Some(Some(RemoveTlcReason::RemoveTlcFulfill(RemoveTlcFulfill {
+                                payment_preimage,
+                            }))) => {
+                                part.status = PaymentPartStatus::Success;
+                                payment_session.preimage = Some(payment_preimage);
+                            }
+                            Some(Some(RemoveTlcReason::RemoveTlcFail(_))) => {
+                                part.status = PaymentPartStatus::Failed;
+                                last_error = Some("Tlc failed before node restarting");
+                            }
+                            None => {
+                                part.status = PaymentPartStatus::Failed;
+                                last_error = Some("First hop tlc not found");
+                            }
+                        }
+                    }
+                    if let Some(preimage) = payment_session.preimage {
+                        payment_session.set_success_status(preimage);
+                    } else if payment_session.inflight_parts().next().is_none() {
+                        payment_session
+                            .set_failed_status(last_error.unwrap_or("First hop tlc not found"));
+                    } else if let Some(error) = last_error {
+                        // Some parts are still pending, the failed amount is sent again
+                        // after they are removed.
+                        payment_session.last_error = Some(error.to_string());
+                    }
+                    self.store.insert_payment_session(payment_session);
+                }
+                PaymentSessionStatus::Success | PaymentSessionStatus::Failed => {}
+            }
+        }
+    }
+
+    // Builds the error returned to the payment origin when we fail to forward a tlc to the
+    // channel `channel_outpoint`. Our latest channel update is attached if it is known.
+    async fn get_forwarding_tlc_err(
+        &self,
+        state: &NetworkActorState<S>,
+        channel_outpoint: OutPoint,
+    ) -> TlcErr {
+        if !state.outpoint_channel_map.contains_key(&channel_outpoint) {
+            return TlcErr::new_channel_fail(TlcErr::UNKNOWN_NEXT_PEER, channel_outpoint, None);
+        }
+        let our_pubkey = state.get_public_key();
+        let channel_update = self
+            .network_graph
+            .read()
+            .await
+            .get_channel(&channel_outpoint)
+            .and_then(|channel| {
+                [&channel.node1_to_node2, &channel.node2_to_node1]
+                    .into_iter()
+                    .flatten()
+                    .map(|info| &info.last_update_message)
+                    .find(|update| {
+                        let signer = if update.message_flags & 1 == 0 {
+                            channel.node1()
+                        } else {
+                            channel.node2()
+                        };
+                        signer == our_pubkey
+                    })
+                    .cloned()
+            });
+        TlcErr::new_channel_fail(
+            TlcErr::TEMPORARY_CHANNEL_FAILURE,
+            channel_outpoint,
+            channel_update,
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+struct NetworkSyncState {
+    // The block number we are syncing from.
+    starting_height: u64,
+    // The block number we are syncing up to.
+    // This is normally the tip block number when we startup. We will only actively sync
+    // channel announcement up to this number (other info will be broadcasted by peers).
+    ending_height: u64,
+    // The timestamp we started syncing.
     starting_time: u64,
     // All the pinned peers that we are going to sync with.
     pinned_syncing_peers: Vec<(PeerId, Multiaddr)>,
@@ -2126,6 +3056,21 @@ pub struct NetworkActorState<S> {
     // A queue of messages that are received while we are syncing network messages.
     // Need to be processed after the sync is done.
     broadcasted_message_queue: Vec<(PeerId, FiberBroadcastMessage)>,
+    // The multi-part payments to us which are not fully received yet, keyed by the payment hash.
+    pending_mpp_payments: HashMap<Hash256, PendingMppPayment>,
+    // The received tlcs paying the hold invoices, they are held until the invoices are
+    // settled or cancelled. Keyed by the payment hash, also saved in the store so that
+    // they are still resolved after restarting.
+    held_payments: HashMap<Hash256, HeldPayment>,
+    // Channels of other nodes without any ChannelUpdate for this long are pruned from the network graph.
+    stale_channel_timeout_seconds: u64,
+    // Rate limits the broadcast messages by their origins.
+    gossip_rate_limiter: GossipRateLimiter,
+    gossip_metrics: GossipMetrics,
+    // The addresses of the known peers, the banned peers and the connections. Peers sending
+    // invalid broadcast messages are penalized, and banned for a while once their penalties
+    // reach the threshold.
+    peer_book: PeerBook<S>,
 }
 
 static CHANNEL_ACTOR_NAME_PREFIX: AtomicU64 = AtomicU64::new(0u64);
@@ -2619,6 +3564,336 @@ where
         }
     }
 
+    // Add a tlc to the channel specified by the current hop of the peeled onion packet.
+    // Returns the channel id and the tlc id of the added tlc.
+    async fn send_payment_onion_packet(
+        &self,
+        peeled_packet: PeeledPaymentOnionPacket,
+        previous_tlc: Option<(Hash256, u64)>,
+    ) -> Result<(Hash256, u64), String> {
+        let current_hop_info = peeled_packet.current;
+        debug!("Processing onion packet info: {:?}", current_hop_info);
+        let channel_outpoint = current_hop_info
+            .channel_outpoint
+            .expect("valid onion packet contains channel outpoint");
+        let channel_id = match self.outpoint_channel_map.get(&channel_outpoint) {
+            Some(channel_id) => *channel_id,
+            None => {
+                return Err(format!(
+                    "channel id not found for channel outpoint {:?}, are we connected to the peer?",
+                    &channel_outpoint
+                ));
+            }
+        };
+        let (send, recv) = oneshot::channel::<Result<AddTlcResponse, String>>();
+        let rpc_reply = RpcReplyPort::from(send);
+        let command = ChannelCommand::AddTlc(
+            AddTlcCommand {
+                amount: current_hop_info.amount,
+                preimage: None,
+                payment_hash: Some(current_hop_info.payment_hash),
+                expiry: current_hop_info.expiry.into(),
+                hash_algorithm: current_hop_info.tlc_hash_algorithm,
+                onion_packet: peeled_packet.next.map(|next| next.data).unwrap_or_default(),
+                previous_tlc,
+            },
+            rpc_reply,
+        );
+        self.send_command_to_channel(channel_id, command)
+            .await
+            .map_err(|err| err.to_string())?;
+        // The channel actor may stop before replying, e.g. when the channel is closed
+        // concurrently, the payment attempt fails then instead of panicking the network actor.
+        let response = recv.await.map_err(|err| {
+            format!(
+                "Failed to receive the add tlc response from channel {:?}: {}",
+                &channel_id, err
+            )
+        })??;
+        Ok((channel_id, response.tlc_id))
+    }
+
+    async fn fail_received_tlc(
+        &self,
+        channel_id: Hash256,
+        tlc_id: u64,
+        tlc_err: TlcErr,
+    ) -> crate::Result<()> {
+        let (send, _recv) = oneshot::channel::<Result<(), String>>();
+        let port = RpcReplyPort::from(send);
+        self.send_command_to_channel(
+            channel_id,
+            ChannelCommand::RemoveTlc(
+                RemoveTlcCommand {
+                    id: tlc_id,
+                    // The error is encrypted by the channel actor with the shared secret of the tlc.
+                    reason: RemoveTlcReason::RemoveTlcFail(RemoveTlcFail::new_plaintext(tlc_err)),
+                },
+                port,
+            ),
+        )
+        .await
+    }
+
+    async fn fulfill_received_tlc(
+        &self,
+        channel_id: Hash256,
+        tlc_id: u64,
+        preimage: Hash256,
+    ) -> crate::Result<()> {
+        let (send, _recv) = oneshot::channel::<Result<(), String>>();
+        let port = RpcReplyPort::from(send);
+        self.send_command_to_channel(
+            channel_id,
+            ChannelCommand::RemoveTlc(
+                RemoveTlcCommand {
+                    id: tlc_id,
+                    reason: RemoveTlcReason::RemoveTlcFulfill(RemoveTlcFulfill {
+                        payment_preimage: preimage,
+                    }),
+                },
+                port,
+            ),
+        )
+        .await
+    }
+
+    // Hold the received part of a multi-part payment, all the parts are settled together
+    // after their sum reaches the total amount.
+    async fn on_mpp_tlc_received(&mut self, tlc: ReceivedMppTlc) {
+        if self
+            .held_payments
+            .get(&tlc.payment_hash)
+            .is_some_and(|held| held.tlcs.contains(&(tlc.channel_id, tlc.tlc_id)))
+        {
+            return;
+        }
+        let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+        let payment = self
+            .pending_mpp_payments
+            .entry(tlc.payment_hash)
+            .or_insert_with(|| PendingMppPayment {
+                payment_hash: tlc.payment_hash,
+                total_amount: tlc.total_amount,
+                preimage: tlc.preimage,
+                parts: vec![],
+                first_received_at: now,
+                expires_at: u64::MAX,
+            });
+        // The channel actor reports the pending parts every time a commitment is signed.
+        if payment
+            .parts
+            .iter()
+            .any(|(channel_id, tlc_id, _)| *channel_id == tlc.channel_id && *tlc_id == tlc.tlc_id)
+        {
+            return;
+        }
+        if payment.total_amount != tlc.total_amount {
+            warn!(
+                "Total amount {} of tlc {:?} mismatches the other parts of payment {:?}",
+                tlc.total_amount, tlc.tlc_id, tlc.payment_hash
+            );
+            let tlc_err = TlcErr::new(TlcErr::INCORRECT_OR_UNKNOWN_PAYMENT_DETAILS);
+            if let Err(err) = self
+                .fail_received_tlc(tlc.channel_id, tlc.tlc_id, tlc_err)
+                .await
+            {
+                error!("Failed to fail tlc {:?}: {}", tlc.tlc_id, err);
+            }
+            return;
+        }
+        payment.parts.push((tlc.channel_id, tlc.tlc_id, tlc.amount));
+        payment.expires_at = payment
+            .expires_at
+            .min(now.saturating_add(tlc.expiry.saturating_mul(1000)));
+        // The received parts are persisted, so that they are still settled or timed out
+        // together after the node is restarted.
+        self.store.insert_pending_mpp_payment(payment.clone());
+        if payment.received_amount() < payment.total_amount {
+            debug!(
+                "Received {} of {} for multi-part payment {:?}",
+                payment.received_amount(),
+                payment.total_amount,
+                tlc.payment_hash
+            );
+            return;
+        }
+
+        let payment = self
+            .pending_mpp_payments
+            .remove(&tlc.payment_hash)
+            .expect("pending mpp payment exists");
+        self.store.remove_pending_mpp_payment(&tlc.payment_hash);
+        info!(
+            "All {} parts of payment {:?} are received",
+            payment.parts.len(),
+            tlc.payment_hash
+        );
+        let parts = payment
+            .parts
+            .into_iter()
+            .map(|(channel_id, tlc_id, _)| (channel_id, tlc_id))
+            .collect();
+        // The hold invoice may be settled after the parts are reported.
+        let preimage = payment
+            .preimage
+            .or_else(|| self.store.get_invoice_preimage(&tlc.payment_hash));
+        let is_cancelled =
+            self.store.get_invoice_status(&tlc.payment_hash) == Some(CkbInvoiceStatus::Cancelled);
+        if preimage.is_none()
+            && !is_cancelled
+            && self.store.get_invoice(&tlc.payment_hash).is_some()
+        {
+            info!(
+                "Holding payment {:?} until the invoice is settled or cancelled",
+                tlc.payment_hash
+            );
+            let held_payment = HeldPayment {
+                payment_hash: tlc.payment_hash,
+                tlcs: parts,
+                expires_at: payment
+                    .expires_at
+                    .saturating_sub(HELD_PAYMENT_EXPIRY_MARGIN_MILLIS),
+            };
+            self.store.insert_held_payment(held_payment.clone());
+            self.held_payments.insert(tlc.payment_hash, held_payment);
+            return;
+        }
+        self.settle_received_tlcs(tlc.payment_hash, parts, preimage.filter(|_| !is_cancelled))
+            .await;
+    }
+
+    // Fulfill the received tlcs of a payment with the preimage, or fail them if the preimage
+    // is None. The invoice of the payment is marked as paid once the tlcs are fulfilled.
+    async fn settle_received_tlcs(
+        &self,
+        payment_hash: Hash256,
+        parts: Vec<(Hash256, u64)>,
+        preimage: Option<Hash256>,
+    ) {
+        for (channel_id, tlc_id) in parts {
+            let result = match preimage {
+                Some(preimage) => {
+                    self.fulfill_received_tlc(channel_id, tlc_id, preimage)
+                        .await
+                }
+                None => {
+                    let tlc_err = TlcErr::new(TlcErr::INCORRECT_OR_UNKNOWN_PAYMENT_DETAILS);
+                    self.fail_received_tlc(channel_id, tlc_id, tlc_err).await
+                }
+            };
+            if let Err(err) = result {
+                error!("Failed to settle tlc {:?}: {}", tlc_id, err);
+            }
+        }
+        if preimage.is_some()
+            // The invoice may not exist, e.g. the preimage is carried in the onion packet.
+            && self
+                .store
+                .update_invoice_status(&payment_hash, CkbInvoiceStatus::Paid)
+                .is_ok()
+        {
+            self.channel_subscribers
+                .node_events
+                .notify_invoice(payment_hash, CkbInvoiceStatus::Paid);
+        }
+    }
+
+    async fn on_resolve_held_payment(&mut self, payment_hash: Hash256) {
+        if !self.held_payments.contains_key(&payment_hash) {
+            return;
+        }
+        let is_cancelled =
+            self.store.get_invoice_status(&payment_hash) == Some(CkbInvoiceStatus::Cancelled);
+        let preimage = self.store.get_invoice_preimage(&payment_hash);
+        if !is_cancelled && preimage.is_none() {
+            return;
+        }
+        let held_payment = self
+            .held_payments
+            .remove(&payment_hash)
+            .expect("held payment exists");
+        self.store.remove_held_payment(&payment_hash);
+        info!(
+            "Resolving held payment {:?}, cancelled: {}",
+            payment_hash, is_cancelled
+        );
+        self.settle_received_tlcs(
+            payment_hash,
+            held_payment.tlcs,
+            preimage.filter(|_| !is_cancelled),
+        )
+        .await;
+    }
+
+    // Cancel the hold invoices which are not settled before their held tlcs expire,
+    // and fail the held tlcs.
+    async fn check_held_payments_expiry(&mut self) {
+        let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+        let expired: Vec<Hash256> = self
+            .held_payments
+            .values()
+            .filter(|held_payment| held_payment.expires_at <= now)
+            .map(|held_payment| held_payment.payment_hash)
+            .collect();
+        for payment_hash in expired {
+            let held_payment = self
+                .held_payments
+                .remove(&payment_hash)
+                .expect("held payment exists");
+            self.store.remove_held_payment(&payment_hash);
+            warn!(
+                "Failing held payment {:?} as its tlcs are about to expire",
+                payment_hash
+            );
+            if self
+                .store
+                .update_invoice_status(&payment_hash, CkbInvoiceStatus::Cancelled)
+                .is_ok()
+            {
+                self.channel_subscribers
+                    .node_events
+                    .notify_invoice(payment_hash, CkbInvoiceStatus::Cancelled);
+            }
+            self.settle_received_tlcs(payment_hash, held_payment.tlcs, None)
+                .await;
+        }
+    }
+
+    // Fail all the received parts of the multi-part payments which are not completed in time.
+    async fn check_mpp_timeout(&mut self) {
+        let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+        let expired: Vec<Hash256> = self
+            .pending_mpp_payments
+            .iter()
+            .filter(|(_, payment)| {
+                now.saturating_sub(payment.first_received_at) > MPP_TIMEOUT_MILLIS
+            })
+            .map(|(payment_hash, _)| *payment_hash)
+            .collect();
+        for payment_hash in expired {
+            let payment = self
+                .pending_mpp_payments
+                .remove(&payment_hash)
+                .expect("pending mpp payment exists");
+            self.store.remove_pending_mpp_payment(&payment_hash);
+            warn!(
+                "Multi-part payment {:?} timed out with {} of {} received",
+                payment_hash,
+                payment.received_amount(),
+                payment.total_amount
+            );
+            for (channel_id, tlc_id, _) in payment.parts {
+                if let Err(err) = self
+                    .fail_received_tlc(channel_id, tlc_id, TlcErr::new(TlcErr::MPP_TIMEOUT))
+                    .await
+                {
+                    error!("Failed to fail tlc {:?}: {}", tlc_id, err);
+                }
+            }
+        }
+    }
+
     async fn reestablish_channel(
         &mut self,
         peer_id: &PeerId,
@@ -2663,12 +3938,26 @@ where
         remote_pubkey: Pubkey,
         session: &SessionContext,
     ) {
-        let store = self.store.clone();
         self.peer_session_map
             .insert(remote_peer_id.clone(), session.id);
         self.peer_pubkey_map
             .insert(remote_peer_id.clone(), remote_pubkey);
 
+        // The Init message must be the first message sent to the peer.
+        let init = Init {
+            chain_hash: get_chain_hash(),
+            features: FeatureVector::local(),
+        };
+        if let Err(e) = self
+            .send_message_to_session(session.id, FiberMessage::init(init))
+            .await
+        {
+            error!(
+                "Failed to send Init message to peer {:?}: {:?}",
+                remote_peer_id, e
+            );
+        }
+
         if self.auto_announce {
             let message = self.get_or_create_new_node_announcement_message();
             debug!(
@@ -2696,12 +3985,120 @@ where
             );
         }
 
-        for channel_id in store.get_active_channel_ids_by_peer(remote_peer_id) {
-            if let Err(e) = self.reestablish_channel(remote_peer_id, channel_id).await {
-                error!("Failed to reestablish channel {:x}: {:?}", &channel_id, &e);
+        self.maybe_sync_network_graph(remote_peer_id).await;
+    }
+
+    // Disconnect the peer if it is on another chain or requires features we don't know,
+    // otherwise save the features it supports.
+    async fn on_init_msg(&mut self, peer_id: &PeerId, init: Init) {
+        let unknown_required_bits = init.features.unknown_required_bits();
+        if init.chain_hash != get_chain_hash() {
+            warn!(
+                "Disconnecting peer {:?} on another chain {:?}",
+                peer_id, init.chain_hash
+            );
+        } else if !unknown_required_bits.is_empty() {
+            warn!(
+                "Disconnecting peer {:?} requiring unknown features {:?}",
+                peer_id, unknown_required_bits
+            );
+        } else {
+            debug!("Peer {:?} supports features {:?}", peer_id, init.features);
+            self.peer_book.set_features(peer_id, init.features);
+            // The channels are reestablished after knowing the features of the peer,
+            // which decide the fields sent in ReestablishChannel.
+            for channel_id in self.store.get_active_channel_ids_by_peer(peer_id) {
+                if let Err(e) = self.reestablish_channel(peer_id, channel_id).await {
+                    error!("Failed to reestablish channel {:x}: {:?}", &channel_id, &e);
+                }
+            }
+            return;
+        }
+        if let Some(session) = self.get_peer_session(peer_id) {
+            if let Err(err) = self.control.disconnect(session).await {
+                error!("Failed to disconnect peer {:?}: {:?}", peer_id, err);
+            }
+        }
+    }
+
+    // Penalize the peer for the broadcasted message it sent, ban it once the penalty is too high.
+    async fn penalize_peer(&mut self, peer_id: &PeerId, penalty: GossipPenalty, now: u64) {
+        if !self.peer_book.penalize(peer_id, penalty, now) {
+            return;
+        }
+        warn!(
+            "Banning peer {:?} for sending invalid broadcasted messages",
+            peer_id
+        );
+        self.disconnect_banned_peer(peer_id).await;
+    }
+
+    // Ban the peer until `until` in milliseconds, and disconnect it if it is connected.
+    async fn ban_peer(&mut self, peer_id: &PeerId, until: u64) {
+        self.peer_book.ban(peer_id, until);
+        self.disconnect_banned_peer(peer_id).await;
+    }
+
+    async fn disconnect_banned_peer(&mut self, peer_id: &PeerId) {
+        if let Some(session) = self.get_peer_session(peer_id) {
+            if let Err(err) = self.control.disconnect(session).await {
+                error!("Failed to disconnect banned peer {:?}: {:?}", peer_id, err);
+            }
+        }
+    }
+
+    async fn save_peer_addresses(
+        &mut self,
+        peer_id: &PeerId,
+        addresses: Vec<Multiaddr>,
+    ) -> crate::Result<()> {
+        // Only one address is kept for each peer, the first one is the preferred one.
+        let Some(address) = addresses.into_iter().next() else {
+            return Ok(());
+        };
+        if peer_id == &self.peer_id {
+            return Ok(());
+        }
+        self.peer_book.add_address(peer_id, address.clone());
+        let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+        if self.peer_book.is_connected(peer_id)
+            || self.peer_book.is_banned(peer_id, now)
+            || !self.peer_book.has_free_slot(SessionType::Outbound)
+        {
+            return Ok(());
+        }
+        // Pending dials are not counted, so we may slightly exceed the outbound limit.
+        debug!(
+            "Connecting to announced peer {:?} at {:?}",
+            peer_id, &address
+        );
+        self.control.dial(address, TargetProtocol::All).await?;
+        Ok(())
+    }
+
+    async fn reconnect_peers(&mut self) {
+        let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as u64;
+        let peers: HashSet<PeerId> = self
+            .store
+            .get_active_channel_states(None)
+            .into_iter()
+            .map(|(peer_id, _, _)| peer_id)
+            .collect();
+        for peer_id in peers {
+            if self.peer_book.is_connected(&peer_id) || self.peer_book.is_banned(&peer_id, now) {
+                continue;
+            }
+            let Some(address) = self.peer_book.get_address(&peer_id).cloned() else {
+                continue;
+            };
+            if !self.peer_book.should_reconnect(&peer_id, now) {
+                continue;
+            }
+            debug!("Reconnecting to peer {:?} at {:?}", &peer_id, &address);
+            if let Err(err) = self.control.dial(address, TargetProtocol::All).await {
+                error!("Failed to reconnect to peer {:?}: {:?}", &peer_id, err);
             }
         }
-        self.maybe_sync_network_graph(remote_peer_id).await;
     }
 
     fn on_peer_disconnected(&mut self, id: &PeerId) {
@@ -3030,10 +4427,13 @@ where
                 return;
             }
         };
+        // The funding transaction may have been replaced by fee, the other candidates
+        // of this channel are double spent now.
+        self.pending_channels.retain(|_, id| *id != channel_id);
         self.send_message_to_channel_actor(
             channel_id,
             None,
-            ChannelActorMessage::Event(ChannelEvent::FundingTransactionConfirmed),
+            ChannelActorMessage::Event(ChannelEvent::FundingTransactionConfirmed(outpoint)),
         )
         .await;
     }
@@ -3192,8 +4592,13 @@ where
             debug!("Tentacle service shutdown");
         });
 
+        let peer_book = PeerBook::new(
+            self.store.clone(),
+            config.max_inbound_peers(),
+            config.max_outbound_peers(),
+        );
         let graph = self.network_graph.read().await;
-        let peers_to_sync_network_graph = graph
+        let peers_to_sync_network_graph = peer_book
             .get_peers_to_sync_network_graph()
             .into_iter()
             .map(|(a, b)| (a.clone(), b.clone()))
@@ -3250,19 +4655,54 @@ where
             original_requests: Default::default(),
             sync_status,
             broadcasted_message_queue: Default::default(),
+            pending_mpp_payments: self
+                .store
+                .get_pending_mpp_payments()
+                .into_iter()
+                .map(|payment| (payment.payment_hash, payment))
+                .collect(),
+            held_payments: self
+                .store
+                .get_held_payments()
+                .into_iter()
+                .map(|held_payment| (held_payment.payment_hash, held_payment))
+                .collect(),
+            stale_channel_timeout_seconds: config.stale_channel_timeout_seconds(),
+            gossip_rate_limiter: Default::default(),
+            gossip_metrics: Default::default(),
+            peer_book,
         };
 
-        // load the connected peers from the network graph
-        let peers = graph.get_connected_peers();
-        // TODO: we need to bootstrap the network if no peers are connected.
+        self.resume_payment_sessions();
+
+        // Peers we share channels with are reconnected by ReconnectPeers, other known peers
+        // are connected until we have enough outbound connections.
+        let channel_peers: HashSet<PeerId> = self
+            .store
+            .get_active_channel_states(None)
+            .into_iter()
+            .map(|(peer_id, _, _)| peer_id)
+            .collect();
+        let peers = state.peer_book.get_addresses();
+        // TODO: we need to bootstrap the network if no peers are known.
         if peers.is_empty() {
-            warn!("No connected peers found in the network graph");
+            warn!("No known peers found in the peer book");
         }
-        for (_peer_id, addr) in peers {
+        for (_peer_id, addr) in peers
+            .into_iter()
+            .filter(|(peer_id, _)| !channel_peers.contains(peer_id))
+            .take(config.max_outbound_peers())
+        {
             myself.send_message(NetworkActorMessage::new_command(
                 NetworkActorCommand::ConnectPeer(addr.clone()),
             ))?;
         }
+        myself.send_message(NetworkActorMessage::new_command(
+            NetworkActorCommand::ReconnectPeers,
+        ))?;
+        myself.send_interval(RECONNECT_PEERS_INTERVAL, || {
+            NetworkActorMessage::new_command(NetworkActorCommand::ReconnectPeers)
+        });
 
         let announce_node_interval_seconds = config.announce_node_interval_seconds();
         if announce_node_interval_seconds > 0 {
@@ -3273,6 +4713,22 @@ where
             });
         }
 
+        myself.send_interval(MPP_TIMEOUT_CHECK_INTERVAL, || {
+            NetworkActorMessage::new_command(NetworkActorCommand::CheckMppTimeout)
+        });
+
+        myself.send_interval(HELD_PAYMENT_EXPIRY_CHECK_INTERVAL, || {
+            NetworkActorMessage::new_command(NetworkActorCommand::CheckHeldPaymentsExpiry)
+        });
+
+        let prune_network_graph_interval_seconds = config.prune_network_graph_interval_seconds();
+        if prune_network_graph_interval_seconds > 0 {
+            myself.send_interval(
+                Duration::from_secs(prune_network_graph_interval_seconds),
+                || NetworkActorMessage::new_command(NetworkActorCommand::PruneNetworkGraph),
+            );
+        }
+
         Ok(state)
     }
 
@@ -3488,12 +4944,14 @@ pub async fn start_network<
 #[cfg(test)]
 mod tests {
     use crate::{
+        ckb::contracts::{get_cell_deps_by_contracts, get_script_by_contract, Contract},
         fiber::{
+            features::FeatureVector,
             graph::{ChannelInfo, NetworkGraphStateStore},
             network::{get_chain_hash, PeerId},
             test_utils::{init_tracing, NetworkNode},
             types::{
-                ChannelAnnouncement, ChannelUpdate, FiberBroadcastMessage, FiberMessage,
+                ChannelAnnouncement, ChannelUpdate, FiberBroadcastMessage, FiberMessage, Init,
                 NodeAnnouncement, Privkey, Pubkey,
             },
             NetworkActorCommand, NetworkActorMessage,
@@ -3504,12 +4962,13 @@ mod tests {
     use ckb_jsonrpc_types::Status;
     use ckb_types::{
         core::TransactionView,
-        packed::{CellOutput, ScriptBuilder},
+        packed::{CellInput, CellOutput, ScriptBuilder},
     };
     use ckb_types::{
         packed::OutPoint,
         prelude::{Builder, Entity, Pack},
     };
+    use ractor::call;
     use std::str::FromStr;
     use tentacle::multiaddr::MultiAddr;
 
@@ -3652,36 +5111,33 @@ mod tests {
         assert!(!channels.is_empty());
     }
 
-    async fn create_a_channel() -> (NetworkNode, ChannelInfo, Privkey, Privkey, Privkey) {
-        init_tracing();
-
-        let mut node1 = new_synced_node("node1").await;
+    // Creates a funding transaction and the signed announcement of the channel funded by it.
+    fn create_channel_announcement(
+        priv_key: &Privkey,
+        sk1: &Privkey,
+        sk2: &Privkey,
+    ) -> (TransactionView, ChannelAnnouncement) {
         let capacity = 42;
-        let priv_key: Privkey = get_test_priv_key();
         let pubkey = priv_key.x_only_pub_key().serialize();
         let pubkey_hash = &blake2b_256(pubkey.as_slice())[0..20];
+        // The funding cell is locked by the always success script, so that it can be spent in tests.
         let tx = TransactionView::new_advanced_builder()
             .output(
                 CellOutput::new_builder()
                     .capacity(capacity.pack())
-                    .lock(ScriptBuilder::default().args(pubkey_hash.pack()).build())
+                    .lock(get_script_by_contract(Contract::AlwaysSuccess, pubkey_hash))
                     .build(),
             )
             .output_data(vec![0u8; 8].pack())
             .build();
         let outpoint = tx.output_pts()[0].clone();
-        let x_only_pub_key = priv_key.x_only_pub_key();
-        let sk1 = Privkey::from([1u8; 32]);
-        let pk1 = sk1.pubkey();
-        let sk2 = Privkey::from([2u8; 32]);
-        let pk2 = sk2.pubkey();
 
         let mut channel_announcement = ChannelAnnouncement::new_unsigned(
-            &pk1,
-            &pk2,
+            &sk1.pubkey(),
+            &sk2.pubkey(),
             outpoint,
             get_chain_hash(),
-            &x_only_pub_key,
+            &priv_key.x_only_pub_key(),
             capacity as u128,
             None,
         );
@@ -3690,6 +5146,32 @@ mod tests {
         channel_announcement.ckb_signature = Some(priv_key.sign_schnorr(message));
         channel_announcement.node1_signature = Some(sk1.sign(message));
         channel_announcement.node2_signature = Some(sk2.sign(message));
+        (tx, channel_announcement)
+    }
+
+    // Creates a transaction spending the funding cell locked by the always success script.
+    fn create_spending_tx(outpoint: OutPoint) -> TransactionView {
+        TransactionView::new_advanced_builder()
+            .cell_deps(get_cell_deps_by_contracts(vec![Contract::AlwaysSuccess]))
+            .input(CellInput::new_builder().previous_output(outpoint).build())
+            .output(
+                CellOutput::new_builder()
+                    .capacity(42u64.pack())
+                    .lock(get_script_by_contract(Contract::AlwaysSuccess, &[]))
+                    .build(),
+            )
+            .output_data(Default::default())
+            .build()
+    }
+
+    async fn create_a_channel() -> (NetworkNode, ChannelInfo, Privkey, Privkey, Privkey) {
+        init_tracing();
+
+        let mut node1 = new_synced_node("node1").await;
+        let priv_key: Privkey = get_test_priv_key();
+        let sk1 = Privkey::from([1u8; 32]);
+        let sk2 = Privkey::from([2u8; 32]);
+        let (tx, channel_announcement) = create_channel_announcement(&priv_key, &sk1, &sk2);
         node1
             .network_actor
             .send_message(NetworkActorMessage::Event(NetworkActorEvent::PeerMessage(
@@ -3857,6 +5339,244 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_prune_channel_with_spent_funding_cell() {
+        let (mut node, channel_info, _priv_key, _sk1, _sk2) = create_a_channel().await;
+        let outpoint = channel_info.out_point();
+
+        let prune_network_graph = |node: &NetworkNode| {
+            node.network_actor
+                .send_message(NetworkActorMessage::Command(
+                    NetworkActorCommand::PruneNetworkGraph,
+                ))
+                .expect("send message to network actor");
+        };
+
+        // The funding cell is still live, the channel should be kept.
+        prune_network_graph(&node);
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        assert_eq!(node.store.get_channels(Some(outpoint.clone())).len(), 1);
+
+        let spending_tx = create_spending_tx(outpoint.clone());
+        assert_eq!(node.submit_tx(spending_tx).await, Status::Committed);
+
+        prune_network_graph(&node);
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        assert!(node.store.get_channels(Some(outpoint)).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_channel_announcement_with_spent_funding_cell() {
+        init_tracing();
+
+        let mut node = new_synced_node("node").await;
+        let (tx, channel_announcement) = create_channel_announcement(
+            &get_test_priv_key(),
+            &Privkey::from([1u8; 32]),
+            &Privkey::from([2u8; 32]),
+        );
+        let outpoint = channel_announcement.channel_outpoint.clone();
+        assert_eq!(node.submit_tx(tx).await, Status::Committed);
+        let spending_tx = create_spending_tx(outpoint.clone());
+        assert_eq!(node.submit_tx(spending_tx).await, Status::Committed);
+
+        // The announcement is ignored without penalizing the relaying peer, and so is
+        // the same announcement relayed again, as it is not rebroadcasted.
+        for _ in 0..2 {
+            node.network_actor
+                .send_message(NetworkActorMessage::Event(NetworkActorEvent::PeerMessage(
+                    get_test_peer_id(),
+                    FiberMessage::BroadcastMessage(FiberBroadcastMessage::ChannelAnnouncement(
+                        channel_announcement.clone(),
+                    )),
+                )))
+                .expect("send message to network actor");
+            tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+            assert!(node.store.get_channels(Some(outpoint.clone())).is_empty());
+        }
+        let metrics = call!(node.network_actor, |reply| NetworkActorMessage::Command(
+            NetworkActorCommand::GetGossipMetrics(reply)
+        ))
+        .expect("network actor alive");
+        assert_eq!(metrics.invalid, 0);
+    }
+
+    #[tokio::test]
+    async fn test_gossip_rate_limit_and_ban_peer() {
+        init_tracing();
+
+        let node = new_synced_node("node").await;
+        let peer_id = get_test_peer_id();
+        let send_broadcast_message = |message: FiberBroadcastMessage| {
+            node.network_actor
+                .send_message(NetworkActorMessage::Event(NetworkActorEvent::PeerMessage(
+                    peer_id.clone(),
+                    FiberMessage::BroadcastMessage(message),
+                )))
+                .expect("send message to network actor");
+        };
+        let get_gossip_metrics = || async {
+            call!(node.network_actor, |reply| NetworkActorMessage::Command(
+                NetworkActorCommand::GetGossipMetrics(reply)
+            ))
+            .expect("network actor alive")
+        };
+
+        // The forged node announcements don't consume the tokens of the node.
+        let priv_key = get_test_priv_key();
+        let wrong_key = Privkey::from([9u8; 32]);
+        for version in 100..104 {
+            let mut node_announcement =
+                NodeAnnouncement::new("fake node".into(), vec![], &priv_key, version);
+            node_announcement.signature = Some(wrong_key.sign(node_announcement.message_to_sign()));
+            node.network_actor
+                .send_message(NetworkActorMessage::Event(NetworkActorEvent::PeerMessage(
+                    PeerId::random(),
+                    FiberMessage::BroadcastMessage(FiberBroadcastMessage::NodeAnnouncement(
+                        node_announcement,
+                    )),
+                )))
+                .expect("send message to network actor");
+        }
+
+        // The node announcements of the same node exceeding the burst are dropped,
+        // while the relaying peer is not penalized for them.
+        for version in 1..=10 {
+            send_broadcast_message(FiberBroadcastMessage::NodeAnnouncement(
+                NodeAnnouncement::new("fake node".into(), vec![], &priv_key, version),
+            ));
+        }
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        let metrics = get_gossip_metrics().await;
+        assert_eq!(metrics.rate_limited, 5);
+        assert_eq!(metrics.banned_peers, 0);
+        let node_info = node.store.get_nodes(Some(get_test_pub_key()));
+        assert_eq!(node_info[0].anouncement_msg.version, 5);
+
+        // The peer is banned after sending a few messages with invalid signatures.
+        for i in 10..15 {
+            let mut node_announcement =
+                NodeAnnouncement::new("fake node".into(), vec![], &Privkey::from([i; 32]), 1);
+            node_announcement.signature = Some(wrong_key.sign(node_announcement.message_to_sign()));
+            send_broadcast_message(FiberBroadcastMessage::NodeAnnouncement(node_announcement));
+        }
+        send_broadcast_message(FiberBroadcastMessage::NodeAnnouncement(
+            NodeAnnouncement::new("fake node".into(), vec![], &Privkey::from([20u8; 32]), 1),
+        ));
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        let metrics = get_gossip_metrics().await;
+        assert_eq!(metrics.invalid, 9);
+        assert_eq!(metrics.from_banned_peers, 1);
+        assert_eq!(metrics.banned_peers, 1);
+        assert!(node
+            .store
+            .get_nodes(Some(Privkey::from([20u8; 32]).pubkey()))
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_ban_and_unban_peer() {
+        init_tracing();
+
+        let [mut node_a, mut node_b] = NetworkNode::new_n_interconnected_nodes().await;
+        let list_peers = |node: &NetworkNode| {
+            let network_actor = node.network_actor.clone();
+            async move {
+                call!(network_actor, |reply| NetworkActorMessage::Command(
+                    NetworkActorCommand::ListPeers(reply)
+                ))
+                .expect("network actor alive")
+            }
+        };
+        let peers = list_peers(&node_a).await;
+        assert_eq!(peers.len(), 1);
+        assert_eq!(peers[0].peer_id, node_b.peer_id);
+        assert!(peers[0].session_type.is_outbound());
+
+        node_a
+            .network_actor
+            .send_message(NetworkActorMessage::new_command(
+                NetworkActorCommand::BanPeer(node_b.peer_id.clone(), None),
+            ))
+            .expect("node_a alive");
+        node_a
+            .expect_event(|event| {
+                matches!(event, NetworkServiceEvent::PeerDisConnected(peer_id, _) if peer_id == &node_b.peer_id)
+            })
+            .await;
+        assert!(list_peers(&node_a).await.is_empty());
+        assert_eq!(node_a.store.get_banned_peers().len(), 1);
+
+        // The banned peer can't connect to us.
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        node_b.connect_to_nonblocking(&node_a).await;
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        assert!(list_peers(&node_a).await.is_empty());
+
+        node_a
+            .network_actor
+            .send_message(NetworkActorMessage::new_command(
+                NetworkActorCommand::UnbanPeer(node_b.peer_id.clone()),
+            ))
+            .expect("node_a alive");
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        assert!(node_a.store.get_banned_peers().is_empty());
+        node_b.connect_to_nonblocking(&node_a).await;
+        node_a
+            .expect_event(|event| {
+                matches!(event, NetworkServiceEvent::PeerConnected(peer_id, _) if peer_id == &node_b.peer_id)
+            })
+            .await;
+        let peers = list_peers(&node_a).await;
+        assert_eq!(peers.len(), 1);
+        assert!(peers[0].session_type.is_inbound());
+    }
+
+    #[tokio::test]
+    async fn test_init_message_feature_negotiation() {
+        init_tracing();
+
+        let [mut node_a, mut node_b] = NetworkNode::new_n_interconnected_nodes().await;
+        // Wait for the Init messages to be exchanged.
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        let peers = call!(node_a.network_actor, |reply| NetworkActorMessage::Command(
+            NetworkActorCommand::ListPeers(reply)
+        ))
+        .expect("network actor alive");
+        assert_eq!(peers.len(), 1);
+        assert_eq!(peers[0].features, Some(FeatureVector::local()));
+
+        // The appended bit is the required bit of an unknown feature.
+        let mut bytes = FeatureVector::local().as_bytes().to_vec();
+        bytes.extend([0, 0, 0b0000_0001]);
+        let invalid_inits = [
+            Init {
+                chain_hash: [1u8; 32].into(),
+                features: FeatureVector::local(),
+            },
+            Init {
+                chain_hash: get_chain_hash(),
+                features: FeatureVector::from_bytes(bytes),
+            },
+        ];
+        for init in invalid_inits {
+            node_a
+                .network_actor
+                .send_message(NetworkActorMessage::Event(NetworkActorEvent::PeerMessage(
+                    node_b.peer_id.clone(),
+                    FiberMessage::init(init),
+                )))
+                .expect("send message to network actor");
+            node_a
+                .expect_event(|event| {
+                    matches!(event, NetworkServiceEvent::PeerDisConnected(peer_id, _) if peer_id == &node_b.peer_id)
+                })
+                .await;
+            tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+            node_b.connect_to(&node_a).await;
+        }
+    }
+
     #[tokio::test]
     async fn test_sync_node_announcement_version() {
         init_tracing();
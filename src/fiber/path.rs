@@ -104,20 +104,6 @@ impl NodeHeap {
     }
 }
 
-pub(crate) struct ProbabilityEvaluator {}
-
-impl ProbabilityEvaluator {
-    pub fn evaluate_probability(
-        _from: Pubkey,
-        _to: Pubkey,
-        _amount_sent: u128,
-        _capacity: u128,
-    ) -> f64 {
-        //FIXME(yukang): to because
-        return 0.5;
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,376 @@
+use std::collections::{HashMap, HashSet};
+
+use tentacle::{multiaddr::Multiaddr, secio::PeerId, service::SessionType};
+
+use super::features::{Feature, FeatureVector};
+use super::gossip::{GossipPenalty, PeerScores};
+use super::graph::NetworkGraphStateStore;
+use super::types::Pubkey;
+
+// The delay before reconnecting to a disconnected peer, it is doubled after each attempt
+// until it reaches MAX_RECONNECT_DELAY. Both are in milliseconds.
+const INITIAL_RECONNECT_DELAY: u64 = 5 * 1000;
+const MAX_RECONNECT_DELAY: u64 = 10 * 60 * 1000;
+
+/// The time until which a peer banned without a duration is banned.
+pub const BANNED_FOREVER: u64 = u64::MAX;
+
+/// A peer we are connected to.
+#[derive(Clone, Debug)]
+pub struct ConnectedPeer {
+    pub peer_id: PeerId,
+    pub pubkey: Pubkey,
+    pub address: Multiaddr,
+    pub session_type: SessionType,
+    // The features negotiated in the Init message, None if the peer has not sent it yet.
+    pub features: Option<FeatureVector>,
+}
+
+#[derive(Clone, Debug)]
+struct Reconnection {
+    attempts: u32,
+    next_attempt_at: u64,
+}
+
+/// The peer book keeps the addresses of the known peers and the banned peers in the store,
+/// so that we can reconnect to the peers after restarting. It also tracks the current
+/// connections, the reconnection attempts to the disconnected peers, and the gossip scores
+/// of the peers which ban the peers sending invalid broadcast messages.
+pub struct PeerBook<S> {
+    store: S,
+    max_inbound_peers: usize,
+    max_outbound_peers: usize,
+    // The dialable addresses of the peers, learned from outbound connections and NodeAnnouncements.
+    addresses: HashMap<PeerId, Multiaddr>,
+    // The gossip scores and the bans of the peers, the bans are also persisted in the store.
+    peer_scores: PeerScores,
+    connected_peers: HashMap<PeerId, ConnectedPeer>,
+    reconnections: HashMap<PeerId, Reconnection>,
+    // The peers disconnected by the operator, they are not reconnected automatically
+    // until they are connected again.
+    disconnected_by_operator: HashSet<PeerId>,
+}
+
+impl<S> PeerBook<S>
+where
+    S: NetworkGraphStateStore,
+{
+    pub fn new(store: S, max_inbound_peers: usize, max_outbound_peers: usize) -> Self {
+        let addresses = store.get_connected_peer(None).into_iter().collect();
+        let mut peer_scores = PeerScores::default();
+        for (peer_id, until) in store.get_banned_peers() {
+            peer_scores.ban(&peer_id, until);
+        }
+        Self {
+            store,
+            max_inbound_peers,
+            max_outbound_peers,
+            addresses,
+            peer_scores,
+            connected_peers: Default::default(),
+            reconnections: Default::default(),
+            disconnected_by_operator: Default::default(),
+        }
+    }
+
+    pub fn add_address(&mut self, peer_id: &PeerId, address: Multiaddr) {
+        if self.addresses.get(peer_id) == Some(&address) {
+            return;
+        }
+        self.addresses.insert(peer_id.clone(), address.clone());
+        self.store.insert_connected_peer(peer_id.clone(), address);
+    }
+
+    pub fn get_address(&self, peer_id: &PeerId) -> Option<&Multiaddr> {
+        self.addresses.get(peer_id)
+    }
+
+    pub fn get_addresses(&self) -> Vec<(&PeerId, &Multiaddr)> {
+        self.addresses.iter().collect()
+    }
+
+    pub fn get_peers_to_sync_network_graph(&self) -> Vec<(&PeerId, &Multiaddr)> {
+        self.addresses.iter().take(3).collect()
+    }
+
+    pub fn remove_address(&mut self, peer_id: &PeerId) {
+        self.addresses.remove(peer_id);
+        self.store.remove_connected_peer(peer_id);
+    }
+
+    /// Bans the peer until `until` in milliseconds.
+    pub fn ban(&mut self, peer_id: &PeerId, until: u64) {
+        self.peer_scores.ban(peer_id, until);
+        self.store.insert_banned_peer(peer_id.clone(), until);
+    }
+
+    /// Unbans the peer, returns false if the peer is not banned.
+    pub fn unban(&mut self, peer_id: &PeerId) -> bool {
+        if !self.peer_scores.unban(peer_id) {
+            return false;
+        }
+        self.store.remove_banned_peer(peer_id);
+        true
+    }
+
+    /// Penalizes the peer for the broadcast message it sent, returns true if the peer is banned
+    /// from now on.
+    pub fn penalize(&mut self, peer_id: &PeerId, penalty: GossipPenalty, now: u64) -> bool {
+        if !self.peer_scores.penalize(peer_id, penalty, now) {
+            return false;
+        }
+        if let Some(until) = self.peer_scores.banned_until(peer_id) {
+            self.store.insert_banned_peer(peer_id.clone(), until);
+        }
+        true
+    }
+
+    pub fn is_banned(&mut self, peer_id: &PeerId, now: u64) -> bool {
+        if self.peer_scores.is_banned(peer_id, now) {
+            return true;
+        }
+        // Remove the expired ban from the store.
+        self.unban(peer_id);
+        false
+    }
+
+    pub fn get_banned_peers(&self, now: u64) -> Vec<(PeerId, u64)> {
+        self.peer_scores.get_banned_peers(now)
+    }
+
+    pub fn on_peer_connected(&mut self, peer: ConnectedPeer) {
+        self.reconnections.remove(&peer.peer_id);
+        self.disconnected_by_operator.remove(&peer.peer_id);
+        // The address of an inbound session is the ephemeral address of the remote side,
+        // only the addresses we dialed are known to be reachable.
+        if peer.session_type.is_outbound() {
+            self.add_address(&peer.peer_id, peer.address.clone());
+        }
+        self.connected_peers.insert(peer.peer_id.clone(), peer);
+    }
+
+    /// The peer is reconnected no earlier than INITIAL_RECONNECT_DELAY after it is disconnected,
+    /// which gives the peer a chance to reconnect to us first.
+    pub fn on_peer_disconnected(&mut self, peer_id: &PeerId, now: u64) {
+        if self.connected_peers.remove(peer_id).is_some() {
+            self.reconnections.insert(
+                peer_id.clone(),
+                Reconnection {
+                    attempts: 0,
+                    next_attempt_at: now + INITIAL_RECONNECT_DELAY,
+                },
+            );
+        }
+    }
+
+    /// The peer is about to be disconnected on the operator's request, which should not
+    /// be undone by the automatic reconnection.
+    pub fn on_peer_disconnected_by_operator(&mut self, peer_id: &PeerId) {
+        self.disconnected_by_operator.insert(peer_id.clone());
+    }
+
+    pub fn is_connected(&self, peer_id: &PeerId) -> bool {
+        self.connected_peers.contains_key(peer_id)
+    }
+
+    pub fn set_features(&mut self, peer_id: &PeerId, features: FeatureVector) {
+        if let Some(peer) = self.connected_peers.get_mut(peer_id) {
+            peer.features = Some(features);
+        }
+    }
+
+    pub fn get_features(&self, peer_id: &PeerId) -> Option<&FeatureVector> {
+        self.connected_peers
+            .get(peer_id)
+            .and_then(|peer| peer.features.as_ref())
+    }
+
+    /// Returns true if the peer is connected and has announced the feature in its Init message.
+    pub fn peer_supports(&self, peer_id: &PeerId, feature: Feature) -> bool {
+        self.get_features(peer_id)
+            .is_some_and(|features| features.supports(feature))
+    }
+
+    pub fn get_connected_peers(&self) -> Vec<ConnectedPeer> {
+        self.connected_peers.values().cloned().collect()
+    }
+
+    /// Returns false if we already have the maximal number of connections of the session type.
+    pub fn has_free_slot(&self, session_type: SessionType) -> bool {
+        let max_peers = if session_type.is_inbound() {
+            self.max_inbound_peers
+        } else {
+            self.max_outbound_peers
+        };
+        self.connected_peers
+            .values()
+            .filter(|peer| peer.session_type == session_type)
+            .count()
+            < max_peers
+    }
+
+    /// Returns true if it is time to reconnect to the peer, the next attempt is delayed
+    /// exponentially until the peer is connected again.
+    pub fn should_reconnect(&mut self, peer_id: &PeerId, now: u64) -> bool {
+        if self.disconnected_by_operator.contains(peer_id) {
+            return false;
+        }
+        let reconnection = self
+            .reconnections
+            .entry(peer_id.clone())
+            .or_insert(Reconnection {
+                attempts: 0,
+                next_attempt_at: now,
+            });
+        if reconnection.next_attempt_at > now {
+            return false;
+        }
+        let delay = INITIAL_RECONNECT_DELAY
+            .saturating_mul(1 << reconnection.attempts.min(16))
+            .min(MAX_RECONNECT_DELAY);
+        reconnection.attempts += 1;
+        reconnection.next_attempt_at = now + delay;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fiber::test_utils::MemoryStore;
+    use crate::fiber::types::Privkey;
+
+    fn connected_peer(session_type: SessionType) -> ConnectedPeer {
+        let pubkey = Privkey::from([1u8; 32]).pubkey();
+        ConnectedPeer {
+            peer_id: PeerId::random(),
+            pubkey,
+            address: "/ip4/127.0.0.1/tcp/10000".parse().unwrap(),
+            session_type,
+            features: None,
+        }
+    }
+
+    #[test]
+    fn test_peer_book_addresses() {
+        let store = MemoryStore::default();
+        let mut peer_book = PeerBook::new(store.clone(), 1, 1);
+
+        let inbound = connected_peer(SessionType::Inbound);
+        let outbound = connected_peer(SessionType::Outbound);
+        peer_book.on_peer_connected(inbound.clone());
+        peer_book.on_peer_connected(outbound.clone());
+        assert_eq!(
+            peer_book.get_addresses(),
+            vec![(&outbound.peer_id, &outbound.address)]
+        );
+
+        // The addresses are kept after the peers are disconnected.
+        peer_book.on_peer_disconnected(&outbound.peer_id, 0);
+        assert!(!peer_book.is_connected(&outbound.peer_id));
+        let peer_book = PeerBook::new(store.clone(), 1, 1);
+        assert_eq!(
+            peer_book.get_address(&outbound.peer_id),
+            Some(&outbound.address)
+        );
+
+        let mut peer_book = peer_book;
+        peer_book.remove_address(&outbound.peer_id);
+        let peer_book = PeerBook::new(store, 1, 1);
+        assert!(peer_book.get_addresses().is_empty());
+    }
+
+    #[test]
+    fn test_peer_book_bans() {
+        let store = MemoryStore::default();
+        let mut peer_book = PeerBook::new(store.clone(), 1, 1);
+        let peer_id1 = PeerId::random();
+        let peer_id2 = PeerId::random();
+        let now = 1_000_000;
+
+        peer_book.ban(&peer_id1, now + 1000);
+        peer_book.ban(&peer_id2, BANNED_FOREVER);
+        assert!(peer_book.is_banned(&peer_id1, now));
+        assert_eq!(peer_book.get_banned_peers(now).len(), 2);
+
+        // The bans are persisted.
+        let mut peer_book = PeerBook::new(store.clone(), 1, 1);
+        assert!(peer_book.is_banned(&peer_id2, now));
+        assert!(!peer_book.is_banned(&peer_id1, now + 1000));
+        assert!(peer_book.unban(&peer_id2));
+        assert!(!peer_book.unban(&peer_id2));
+
+        let mut peer_book = PeerBook::new(store.clone(), 1, 1);
+        assert!(!peer_book.is_banned(&peer_id1, now));
+        assert!(!peer_book.is_banned(&peer_id2, now));
+
+        // The bans for the gossip messages are persisted too.
+        while !peer_book.penalize(&peer_id1, GossipPenalty::Invalid, now) {}
+        let mut peer_book = PeerBook::new(store, 1, 1);
+        assert!(peer_book.is_banned(&peer_id1, now));
+    }
+
+    #[test]
+    fn test_peer_book_connection_limits_and_reconnect_backoff() {
+        let mut peer_book = PeerBook::new(MemoryStore::default(), 1, 2);
+        peer_book.on_peer_connected(connected_peer(SessionType::Inbound));
+        peer_book.on_peer_connected(connected_peer(SessionType::Outbound));
+        assert!(!peer_book.has_free_slot(SessionType::Inbound));
+        assert!(peer_book.has_free_slot(SessionType::Outbound));
+
+        let peer = connected_peer(SessionType::Outbound);
+        let now = 1_000_000;
+        assert!(peer_book.should_reconnect(&peer.peer_id, now));
+        assert!(!peer_book.should_reconnect(&peer.peer_id, now + INITIAL_RECONNECT_DELAY - 1));
+        assert!(peer_book.should_reconnect(&peer.peer_id, now + INITIAL_RECONNECT_DELAY));
+        // The delay is doubled after each attempt.
+        let now = now + INITIAL_RECONNECT_DELAY;
+        assert!(!peer_book.should_reconnect(&peer.peer_id, now + 2 * INITIAL_RECONNECT_DELAY - 1));
+        assert!(peer_book.should_reconnect(&peer.peer_id, now + 2 * INITIAL_RECONNECT_DELAY));
+
+        // The backoff is reset once the peer is connected.
+        peer_book.on_peer_connected(peer.clone());
+        assert!(!peer_book.has_free_slot(SessionType::Outbound));
+        peer_book.on_peer_disconnected(&peer.peer_id, now);
+        assert!(!peer_book.should_reconnect(&peer.peer_id, now));
+        assert!(peer_book.should_reconnect(&peer.peer_id, now + INITIAL_RECONNECT_DELAY));
+    }
+
+    #[test]
+    fn test_peer_book_not_reconnect_peer_disconnected_by_operator() {
+        let mut peer_book = PeerBook::new(MemoryStore::default(), 1, 1);
+        let peer = connected_peer(SessionType::Outbound);
+        let now = 1_000_000;
+        peer_book.on_peer_connected(peer.clone());
+        peer_book.on_peer_disconnected_by_operator(&peer.peer_id);
+        peer_book.on_peer_disconnected(&peer.peer_id, now);
+        assert!(!peer_book.should_reconnect(&peer.peer_id, now + MAX_RECONNECT_DELAY));
+
+        // The peer is reconnected automatically again after it is connected.
+        peer_book.on_peer_connected(peer.clone());
+        peer_book.on_peer_disconnected(&peer.peer_id, now);
+        assert!(peer_book.should_reconnect(&peer.peer_id, now + INITIAL_RECONNECT_DELAY));
+    }
+
+    #[test]
+    fn test_peer_book_features() {
+        let mut peer_book = PeerBook::new(MemoryStore::default(), 1, 1);
+        let peer = connected_peer(SessionType::Outbound);
+        let mut features = FeatureVector::default();
+        features.set_optional(Feature::Splicing);
+
+        // The features of a disconnected peer are not kept.
+        peer_book.set_features(&peer.peer_id, features.clone());
+        assert!(peer_book.get_features(&peer.peer_id).is_none());
+
+        peer_book.on_peer_connected(peer.clone());
+        assert!(!peer_book.peer_supports(&peer.peer_id, Feature::Splicing));
+        peer_book.set_features(&peer.peer_id, features.clone());
+        assert_eq!(peer_book.get_features(&peer.peer_id), Some(&features));
+        assert!(peer_book.peer_supports(&peer.peer_id, Feature::Splicing));
+        assert!(!peer_book.peer_supports(&peer.peer_id, Feature::MultiPartPayment));
+
+        peer_book.on_peer_disconnected(&peer.peer_id, 0);
+        assert!(!peer_book.peer_supports(&peer.peer_id, Feature::Splicing));
+    }
+}
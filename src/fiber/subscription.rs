@@ -0,0 +1,120 @@
+use tentacle::{multiaddr::Multiaddr, secio::PeerId};
+use tokio::sync::broadcast;
+
+use super::{channel::ChannelActorState, graph::PaymentSession, types::Hash256};
+use crate::{cch::CchOrderEvent, invoice::CkbInvoiceStatus};
+
+// The number of events buffered for each subscriber, a subscriber lagging behind
+// more than this number of events misses the oldest ones.
+const NODE_EVENT_CHANNEL_SIZE: usize = 1024;
+
+#[derive(Clone, Debug)]
+pub enum PeerEvent {
+    Connected(PeerId, Multiaddr),
+    Disconnected(PeerId, Multiaddr),
+}
+
+/// Broadcast channels of the node events, which are streamed to the rpc subscribers.
+/// Sending an event is a no-op if there is no subscriber.
+#[derive(Clone)]
+pub struct NodeEventSubscribers {
+    // The channel state after its state or balances are changed.
+    channel_events: broadcast::Sender<ChannelActorState>,
+    // The payment session after its status is updated.
+    payment_events: broadcast::Sender<PaymentSession>,
+    // The payment hash and the new status of an invoice.
+    invoice_events: broadcast::Sender<(Hash256, CkbInvoiceStatus)>,
+    peer_events: broadcast::Sender<PeerEvent>,
+    // The cross-chain hub order after it is created or updated.
+    cch_order_events: broadcast::Sender<CchOrderEvent>,
+}
+
+impl Default for NodeEventSubscribers {
+    fn default() -> Self {
+        Self {
+            channel_events: broadcast::channel(NODE_EVENT_CHANNEL_SIZE).0,
+            payment_events: broadcast::channel(NODE_EVENT_CHANNEL_SIZE).0,
+            invoice_events: broadcast::channel(NODE_EVENT_CHANNEL_SIZE).0,
+            peer_events: broadcast::channel(NODE_EVENT_CHANNEL_SIZE).0,
+            cch_order_events: broadcast::channel(NODE_EVENT_CHANNEL_SIZE).0,
+        }
+    }
+}
+
+impl NodeEventSubscribers {
+    // The channel states and payment sessions are only cloned if there are subscribers,
+    // as they are notified on every update.
+    pub fn notify_channel(&self, state: &ChannelActorState) {
+        if self.channel_events.receiver_count() > 0 {
+            let _ = self.channel_events.send(state.clone());
+        }
+    }
+
+    pub fn notify_payment(&self, session: &PaymentSession) {
+        if self.payment_events.receiver_count() > 0 {
+            let _ = self.payment_events.send(session.clone());
+        }
+    }
+
+    pub fn notify_invoice(&self, payment_hash: Hash256, status: CkbInvoiceStatus) {
+        let _ = self.invoice_events.send((payment_hash, status));
+    }
+
+    pub fn notify_peer(&self, event: PeerEvent) {
+        let _ = self.peer_events.send(event);
+    }
+
+    pub fn notify_cch_order(&self, event: CchOrderEvent) {
+        let _ = self.cch_order_events.send(event);
+    }
+
+    pub fn subscribe_channel_events(&self) -> broadcast::Receiver<ChannelActorState> {
+        self.channel_events.subscribe()
+    }
+
+    pub fn subscribe_payment_events(&self) -> broadcast::Receiver<PaymentSession> {
+        self.payment_events.subscribe()
+    }
+
+    pub fn subscribe_invoice_events(&self) -> broadcast::Receiver<(Hash256, CkbInvoiceStatus)> {
+        self.invoice_events.subscribe()
+    }
+
+    pub fn subscribe_peer_events(&self) -> broadcast::Receiver<PeerEvent> {
+        self.peer_events.subscribe()
+    }
+
+    pub fn subscribe_cch_order_events(&self) -> broadcast::Receiver<CchOrderEvent> {
+        self.cch_order_events.subscribe()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fiber::test_utils::gen_sha256_hash;
+
+    #[tokio::test]
+    async fn test_node_event_subscribers() {
+        let node_events = NodeEventSubscribers::default();
+        // Notifying without any subscriber is a no-op.
+        node_events.notify_invoice(gen_sha256_hash(), CkbInvoiceStatus::Paid);
+
+        let mut invoice_receiver = node_events.subscribe_invoice_events();
+        let mut peer_receiver = node_events.clone().subscribe_peer_events();
+        let payment_hash = gen_sha256_hash();
+        node_events.notify_invoice(payment_hash, CkbInvoiceStatus::Received);
+        assert_eq!(
+            invoice_receiver.recv().await.unwrap(),
+            (payment_hash, CkbInvoiceStatus::Received)
+        );
+
+        let peer_id = PeerId::random();
+        let address: Multiaddr = "/ip4/127.0.0.1/tcp/8344".parse().unwrap();
+        node_events.notify_peer(PeerEvent::Connected(peer_id.clone(), address.clone()));
+        assert!(matches!(
+            peer_receiver.recv().await.unwrap(),
+            PeerEvent::Connected(id, addr) if id == peer_id && addr == address
+        ));
+    }
+}
@@ -1,6 +1,8 @@
 use crate::fiber::graph::{ChannelInfo, NetworkGraph, NodeInfo};
 use crate::fiber::types::Pubkey;
-use crate::invoice::{CkbInvoice, InvoiceError, InvoiceStore};
+use crate::invoice::{
+    CkbInvoice, CkbInvoiceStatus, HeldPayment, InvoiceError, InvoiceStore, PendingMppPayment,
+};
 use ckb_jsonrpc_types::JsonBytes;
 use ckb_types::packed::OutPoint;
 use ckb_types::{core::TransactionView, packed::Byte32};
@@ -13,7 +15,10 @@ use std::{
     ffi::OsStr,
     mem::ManuallyDrop,
     path::{Path, PathBuf},
-    sync::{Arc, RwLock},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock,
+    },
     time::Duration,
 };
 use tempfile::TempDir as OldTempDir;
@@ -35,6 +40,7 @@ use crate::{
 };
 
 use super::graph::PaymentSession;
+use super::history::TimedResult;
 use super::{
     channel::{ChannelActorState, ChannelActorStateStore, ChannelState},
     types::Hash256,
@@ -154,6 +160,7 @@ pub struct NetworkNode {
     pub listening_addrs: Vec<MultiAddr>,
     pub network_actor: ActorRef<NetworkActorMessage>,
     pub chain_actor: ActorRef<CkbChainMessage>,
+    pub chain_confirmations_held: Arc<AtomicBool>,
     pub peer_id: PeerId,
     pub event_emitter: mpsc::Receiver<NetworkServiceEvent>,
 }
@@ -267,10 +274,16 @@ impl NetworkNode {
         let root = ROOT_ACTOR.get_or_init(get_test_root_actor).await.clone();
         let (event_sender, mut event_receiver) = mpsc::channel(10000);
 
-        let chain_actor = Actor::spawn_linked(None, MockChainActor::new(), (), root.get_cell())
-            .await
-            .expect("start mock chain actor")
-            .0;
+        let chain_confirmations_held = Arc::new(AtomicBool::new(false));
+        let chain_actor = Actor::spawn_linked(
+            None,
+            MockChainActor::with_confirmations_held(chain_confirmations_held.clone()),
+            (),
+            root.get_cell(),
+        )
+        .await
+        .expect("start mock chain actor")
+        .0;
 
         let secp = Secp256k1::new();
         let secret_key = SecretKey::from_slice(&[0xcd; 32]).expect("32 bytes, within curve order");
@@ -325,6 +338,7 @@ impl NetworkNode {
             listening_addrs: announced_addrs,
             network_actor,
             chain_actor,
+            chain_confirmations_held,
             peer_id,
             event_emitter: event_receiver,
         }
@@ -448,6 +462,15 @@ impl NetworkNode {
             .await;
     }
 
+    // Transactions sent to the mock chain stay pending until the confirmations are released.
+    pub fn hold_tx_confirmations(&self) {
+        self.chain_confirmations_held.store(true, Ordering::SeqCst);
+    }
+
+    pub fn release_tx_confirmations(&self) {
+        self.chain_confirmations_held.store(false, Ordering::SeqCst);
+    }
+
     pub async fn submit_tx(&mut self, tx: TransactionView) -> ckb_jsonrpc_types::Status {
         submit_tx(self.chain_actor.clone(), tx).await
     }
@@ -467,9 +490,14 @@ pub struct MemoryStore {
     channels_map: Arc<RwLock<HashMap<OutPoint, ChannelInfo>>>,
     pub nodes_map: Arc<RwLock<HashMap<Pubkey, NodeInfo>>>,
     connected_peer_addresses: Arc<RwLock<HashMap<PeerId, Multiaddr>>>,
+    banned_peers: Arc<RwLock<HashMap<PeerId, u64>>>,
     payment_sessions: Arc<RwLock<HashMap<Hash256, PaymentSession>>>,
+    payment_history_results: Arc<RwLock<HashMap<(OutPoint, Pubkey), TimedResult>>>,
     invoice_store: Arc<RwLock<HashMap<Hash256, CkbInvoice>>>,
     invoice_hash_to_preimage: Arc<RwLock<HashMap<Hash256, Hash256>>>,
+    invoice_status: Arc<RwLock<HashMap<Hash256, CkbInvoiceStatus>>>,
+    held_payments: Arc<RwLock<HashMap<Hash256, HeldPayment>>>,
+    pending_mpp_payments: Arc<RwLock<HashMap<Hash256, PendingMppPayment>>>,
 }
 
 impl NetworkGraphStateStore for MemoryStore {
@@ -498,6 +526,10 @@ impl NetworkGraphStateStore for MemoryStore {
             .insert(channel.out_point(), channel);
     }
 
+    fn delete_channel(&self, outpoint: &OutPoint) {
+        self.channels_map.write().unwrap().remove(outpoint);
+    }
+
     fn get_nodes(&self, node_id: Option<Pubkey>) -> Vec<NodeInfo> {
         if let Some(node_id) = node_id {
             let mut res = vec![];
@@ -568,16 +600,76 @@ impl NetworkGraphStateStore for MemoryStore {
             .remove(peer_id);
     }
 
+    fn insert_banned_peer(&self, peer_id: PeerId, until: u64) {
+        self.banned_peers.write().unwrap().insert(peer_id, until);
+    }
+
+    fn get_banned_peers(&self) -> Vec<(PeerId, u64)> {
+        self.banned_peers
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(peer_id, until)| (peer_id.clone(), *until))
+            .collect()
+    }
+
+    fn remove_banned_peer(&self, peer_id: &PeerId) {
+        self.banned_peers.write().unwrap().remove(peer_id);
+    }
+
     fn get_payment_session(&self, id: Hash256) -> Option<PaymentSession> {
         self.payment_sessions.read().unwrap().get(&id).cloned()
     }
 
+    fn get_payment_sessions(&self) -> Vec<PaymentSession> {
+        self.payment_sessions
+            .read()
+            .unwrap()
+            .values()
+            .cloned()
+            .collect()
+    }
+
     fn insert_payment_session(&self, session: PaymentSession) {
         self.payment_sessions
             .write()
             .unwrap()
             .insert(session.payment_hash(), session);
     }
+
+    fn get_payment_history_results(&self) -> Vec<(OutPoint, Pubkey, TimedResult)> {
+        self.payment_history_results
+            .read()
+            .unwrap()
+            .iter()
+            .map(|((channel_outpoint, target), result)| {
+                (channel_outpoint.clone(), *target, *result)
+            })
+            .collect()
+    }
+
+    fn insert_payment_history_result(
+        &self,
+        channel_outpoint: OutPoint,
+        target: Pubkey,
+        result: TimedResult,
+    ) {
+        self.payment_history_results
+            .write()
+            .unwrap()
+            .insert((channel_outpoint, target), result);
+    }
+
+    fn remove_payment_history_results(&self, channel_outpoint: Option<OutPoint>) {
+        self.payment_history_results
+            .write()
+            .unwrap()
+            .retain(|(outpoint, _), _| {
+                channel_outpoint
+                    .as_ref()
+                    .is_some_and(|channel_outpoint| outpoint != channel_outpoint)
+            });
+    }
 }
 
 impl ChannelActorStateStore for MemoryStore {
@@ -658,6 +750,10 @@ impl InvoiceStore for MemoryStore {
                 .unwrap()
                 .insert(*id, preimage);
         }
+        self.invoice_status
+            .write()
+            .unwrap()
+            .insert(*id, CkbInvoiceStatus::Open);
         self.invoice_store.write().unwrap().insert(*id, invoice);
         Ok(())
     }
@@ -669,6 +765,85 @@ impl InvoiceStore for MemoryStore {
             .get(hash)
             .cloned()
     }
+
+    fn insert_invoice_preimage(&self, id: &Hash256, preimage: Hash256) -> Result<(), InvoiceError> {
+        if !self.invoice_store.read().unwrap().contains_key(id) {
+            return Err(InvoiceError::InvoiceNotFound(id.to_string()));
+        }
+        self.invoice_hash_to_preimage
+            .write()
+            .unwrap()
+            .insert(*id, preimage);
+        Ok(())
+    }
+
+    fn get_invoices(&self) -> Vec<CkbInvoice> {
+        self.invoice_store
+            .read()
+            .unwrap()
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    fn get_invoice_status(&self, id: &Hash256) -> Option<CkbInvoiceStatus> {
+        self.invoice_status.read().unwrap().get(id).cloned()
+    }
+
+    fn update_invoice_status(
+        &self,
+        id: &Hash256,
+        status: CkbInvoiceStatus,
+    ) -> Result<(), InvoiceError> {
+        if !self.invoice_store.read().unwrap().contains_key(id) {
+            return Err(InvoiceError::InvoiceNotFound(id.to_string()));
+        }
+        self.invoice_status.write().unwrap().insert(*id, status);
+        Ok(())
+    }
+
+    fn get_held_payments(&self) -> Vec<HeldPayment> {
+        self.held_payments
+            .read()
+            .unwrap()
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    fn insert_held_payment(&self, held_payment: HeldPayment) {
+        self.held_payments
+            .write()
+            .unwrap()
+            .insert(held_payment.payment_hash, held_payment);
+    }
+
+    fn remove_held_payment(&self, payment_hash: &Hash256) {
+        self.held_payments.write().unwrap().remove(payment_hash);
+    }
+
+    fn get_pending_mpp_payments(&self) -> Vec<PendingMppPayment> {
+        self.pending_mpp_payments
+            .read()
+            .unwrap()
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    fn insert_pending_mpp_payment(&self, payment: PendingMppPayment) {
+        self.pending_mpp_payments
+            .write()
+            .unwrap()
+            .insert(payment.payment_hash, payment);
+    }
+
+    fn remove_pending_mpp_payment(&self, payment_hash: &Hash256) {
+        self.pending_mpp_payments
+            .write()
+            .unwrap()
+            .remove(payment_hash);
+    }
 }
 
 #[cfg(test)]
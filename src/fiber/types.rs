@@ -1,5 +1,6 @@
 use super::channel::ChannelFlags;
 use super::config::AnnouncedNodeName;
+use super::features::FeatureVector;
 use super::gen::fiber::{self as molecule_fiber, BroadcastMessageQueries, PubNonce as Byte66};
 use super::hash_algorithm::{HashAlgorithm, UnknownHashAlgorithmError};
 use super::network::get_chain_hash;
@@ -13,7 +14,7 @@ use ckb_types::{
     packed::{Byte32 as MByte32, BytesVec, Script, Transaction},
     prelude::{Pack, Unpack},
 };
-use fiber_sphinx::SphinxError;
+use fiber_sphinx::{OnionErrorPacket, SphinxError};
 use molecule::prelude::{Builder, Byte, Entity};
 use musig2::errors::DecodeError;
 use musig2::secp::{Point, Scalar};
@@ -1079,6 +1080,236 @@ impl TryFrom<molecule_fiber::TxAckRBF> for TxAckRBF {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpliceInit {
+    pub channel_id: Hash256,
+    // The balance of the splice initiator after the splice.
+    pub funding_amount: u128,
+    pub funding_fee_rate: u64,
+    pub funding_nonce: PubNonce,
+    pub local_nonce: PubNonce,
+    pub channel_announcement_nonce: PubNonce,
+}
+
+impl From<SpliceInit> for molecule_fiber::SpliceInit {
+    fn from(splice_init: SpliceInit) -> Self {
+        molecule_fiber::SpliceInit::new_builder()
+            .channel_id(splice_init.channel_id.into())
+            .funding_amount(splice_init.funding_amount.pack())
+            .funding_fee_rate(splice_init.funding_fee_rate.pack())
+            .funding_nonce((&splice_init.funding_nonce).into())
+            .local_nonce((&splice_init.local_nonce).into())
+            .channel_announcement_nonce((&splice_init.channel_announcement_nonce).into())
+            .build()
+    }
+}
+
+impl TryFrom<molecule_fiber::SpliceInit> for SpliceInit {
+    type Error = Error;
+
+    fn try_from(splice_init: molecule_fiber::SpliceInit) -> Result<Self, Self::Error> {
+        Ok(SpliceInit {
+            channel_id: splice_init.channel_id().into(),
+            funding_amount: splice_init.funding_amount().unpack(),
+            funding_fee_rate: splice_init.funding_fee_rate().unpack(),
+            funding_nonce: splice_init
+                .funding_nonce()
+                .try_into()
+                .map_err(|err| Error::Musig2(format!("{err}")))?,
+            local_nonce: splice_init
+                .local_nonce()
+                .try_into()
+                .map_err(|err| Error::Musig2(format!("{err}")))?,
+            channel_announcement_nonce: splice_init
+                .channel_announcement_nonce()
+                .try_into()
+                .map_err(|err| Error::Musig2(format!("{err}")))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpliceAck {
+    pub channel_id: Hash256,
+    pub funding_nonce: PubNonce,
+    pub local_nonce: PubNonce,
+    pub channel_announcement_nonce: PubNonce,
+}
+
+impl From<SpliceAck> for molecule_fiber::SpliceAck {
+    fn from(splice_ack: SpliceAck) -> Self {
+        molecule_fiber::SpliceAck::new_builder()
+            .channel_id(splice_ack.channel_id.into())
+            .funding_nonce((&splice_ack.funding_nonce).into())
+            .local_nonce((&splice_ack.local_nonce).into())
+            .channel_announcement_nonce((&splice_ack.channel_announcement_nonce).into())
+            .build()
+    }
+}
+
+impl TryFrom<molecule_fiber::SpliceAck> for SpliceAck {
+    type Error = Error;
+
+    fn try_from(splice_ack: molecule_fiber::SpliceAck) -> Result<Self, Self::Error> {
+        Ok(SpliceAck {
+            channel_id: splice_ack.channel_id().into(),
+            funding_nonce: splice_ack
+                .funding_nonce()
+                .try_into()
+                .map_err(|err| Error::Musig2(format!("{err}")))?,
+            local_nonce: splice_ack
+                .local_nonce()
+                .try_into()
+                .map_err(|err| Error::Musig2(format!("{err}")))?,
+            channel_announcement_nonce: splice_ack
+                .channel_announcement_nonce()
+                .try_into()
+                .map_err(|err| Error::Musig2(format!("{err}")))?,
+        })
+    }
+}
+
+// Signatures for the commitment transaction spending the funding cell of a pending splice.
+// It is sent along with every `CommitmentSigned` until the splice is locked.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpliceCommitmentSigned {
+    pub channel_id: Hash256,
+    pub funding_tx_partial_signature: PartialSignature,
+    pub commitment_tx_partial_signature: PartialSignature,
+    pub next_local_nonce: PubNonce,
+}
+
+impl From<SpliceCommitmentSigned> for molecule_fiber::SpliceCommitmentSigned {
+    fn from(commitment_signed: SpliceCommitmentSigned) -> Self {
+        molecule_fiber::SpliceCommitmentSigned::new_builder()
+            .channel_id(commitment_signed.channel_id.into())
+            .funding_tx_partial_signature(partial_signature_to_molecule(
+                commitment_signed.funding_tx_partial_signature,
+            ))
+            .commitment_tx_partial_signature(partial_signature_to_molecule(
+                commitment_signed.commitment_tx_partial_signature,
+            ))
+            .next_local_nonce((&commitment_signed.next_local_nonce).into())
+            .build()
+    }
+}
+
+impl TryFrom<molecule_fiber::SpliceCommitmentSigned> for SpliceCommitmentSigned {
+    type Error = Error;
+
+    fn try_from(
+        commitment_signed: molecule_fiber::SpliceCommitmentSigned,
+    ) -> Result<Self, Self::Error> {
+        Ok(SpliceCommitmentSigned {
+            channel_id: commitment_signed.channel_id().into(),
+            funding_tx_partial_signature: PartialSignature::from_slice(
+                commitment_signed.funding_tx_partial_signature().as_slice(),
+            )
+            .map_err(|e| anyhow!(e))?,
+            commitment_tx_partial_signature: PartialSignature::from_slice(
+                commitment_signed
+                    .commitment_tx_partial_signature()
+                    .as_slice(),
+            )
+            .map_err(|e| anyhow!(e))?,
+            next_local_nonce: commitment_signed
+                .next_local_nonce()
+                .try_into()
+                .map_err(|e| anyhow!(format!("{e:?}")))?,
+        })
+    }
+}
+
+// The revocation signature for the commitment transaction spending the funding cell of a
+// pending splice. It is sent along with every `RevokeAndAck` until the splice is locked.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpliceRevokeAndAck {
+    pub channel_id: Hash256,
+    pub partial_signature: PartialSignature,
+}
+
+impl From<SpliceRevokeAndAck> for molecule_fiber::SpliceRevokeAndAck {
+    fn from(revoke_and_ack: SpliceRevokeAndAck) -> Self {
+        molecule_fiber::SpliceRevokeAndAck::new_builder()
+            .channel_id(revoke_and_ack.channel_id.into())
+            .partial_signature(partial_signature_to_molecule(
+                revoke_and_ack.partial_signature,
+            ))
+            .build()
+    }
+}
+
+impl TryFrom<molecule_fiber::SpliceRevokeAndAck> for SpliceRevokeAndAck {
+    type Error = Error;
+
+    fn try_from(revoke_and_ack: molecule_fiber::SpliceRevokeAndAck) -> Result<Self, Self::Error> {
+        Ok(SpliceRevokeAndAck {
+            channel_id: revoke_and_ack.channel_id().into(),
+            partial_signature: PartialSignature::from_slice(
+                revoke_and_ack.partial_signature().as_slice(),
+            )
+            .map_err(|e| anyhow!(e))?,
+        })
+    }
+}
+
+// The secret of our commitment point revoked by a `RevokeAndAck`, it is sent along with the
+// `RevokeAndAck` to the peers supporting the DataLossProtect feature.
+#[derive(Debug, Clone)]
+pub struct CommitmentSecret {
+    pub channel_id: Hash256,
+    pub commitment_number: u64,
+    pub secret: Hash256,
+}
+
+impl From<CommitmentSecret> for molecule_fiber::CommitmentSecret {
+    fn from(commitment_secret: CommitmentSecret) -> Self {
+        molecule_fiber::CommitmentSecret::new_builder()
+            .channel_id(commitment_secret.channel_id.into())
+            .commitment_number(commitment_secret.commitment_number.pack())
+            .secret(commitment_secret.secret.into())
+            .build()
+    }
+}
+
+impl TryFrom<molecule_fiber::CommitmentSecret> for CommitmentSecret {
+    type Error = Error;
+
+    fn try_from(commitment_secret: molecule_fiber::CommitmentSecret) -> Result<Self, Self::Error> {
+        Ok(CommitmentSecret {
+            channel_id: commitment_secret.channel_id().into(),
+            commitment_number: commitment_secret.commitment_number().unpack(),
+            secret: commitment_secret.secret().into(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpliceLocked {
+    pub channel_id: Hash256,
+    pub funding_tx_hash: Hash256,
+}
+
+impl From<SpliceLocked> for molecule_fiber::SpliceLocked {
+    fn from(splice_locked: SpliceLocked) -> Self {
+        molecule_fiber::SpliceLocked::new_builder()
+            .channel_id(splice_locked.channel_id.into())
+            .funding_tx_hash(splice_locked.funding_tx_hash.into())
+            .build()
+    }
+}
+
+impl TryFrom<molecule_fiber::SpliceLocked> for SpliceLocked {
+    type Error = Error;
+
+    fn try_from(splice_locked: molecule_fiber::SpliceLocked) -> Result<Self, Self::Error> {
+        Ok(SpliceLocked {
+            channel_id: splice_locked.channel_id().into(),
+            funding_tx_hash: splice_locked.funding_tx_hash().into(),
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Shutdown {
     pub channel_id: Hash256,
@@ -1244,15 +1475,181 @@ impl TryFrom<molecule_fiber::RemoveTlcFulfill> for RemoveTlcFulfill {
     }
 }
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub struct RemoveTlcFail {
+/// The shared secret of the tlcs which are not created from an onion packet, e.g. the tlcs
+/// offered by the payment origin or added manually through RPC.
+pub const NO_SHARED_SECRET: [u8; 32] = [0u8; 32];
+
+// The hmac of an error packet which is not encrypted yet.
+const NO_ERROR_PACKET_HMAC: [u8; 32] = [0u8; 32];
+
+/// The failure reported by the node where a tlc fails. It is encrypted in `RemoveTlcFail`
+/// so that only the payment origin can read it.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TlcErr {
     pub error_code: u32,
+    // The channel which the erring node failed to forward the tlc to. `None` if the failure
+    // is not related to an outgoing channel, e.g. the erring node is the payment target.
+    #[serde_as(as = "Option<EntityHex>")]
+    pub erring_channel: Option<OutPoint>,
+    // The latest channel update of `erring_channel`, so that the payment origin could
+    // retry with the up-to-date channel parameters.
+    pub channel_update: Option<ChannelUpdate>,
+}
+
+impl TlcErr {
+    // The flags and error codes follow the failure messages in BOLT-4.
+    pub const BADONION: u32 = 0x8000;
+    pub const PERM: u32 = 0x4000;
+    pub const NODE: u32 = 0x2000;
+    pub const UPDATE: u32 = 0x1000;
+
+    // The tlc can't be forwarded to the next hop, e.g. the channel to the next hop
+    // is not ready or it rejected the tlc.
+    pub const TEMPORARY_CHANNEL_FAILURE: u32 = Self::UPDATE | 7;
+    // The outgoing channel specified in the onion packet is unknown to the erring node.
+    pub const UNKNOWN_NEXT_PEER: u32 = Self::PERM | 10;
+    // The payment hash is unknown or the amount is incorrect for the payment target.
+    pub const INCORRECT_OR_UNKNOWN_PAYMENT_DETAILS: u32 = Self::PERM | 15;
+    // Not all the parts of a multi-part payment are received before the timeout.
+    pub const MPP_TIMEOUT: u32 = 23;
+
+    pub fn new(error_code: u32) -> Self {
+        TlcErr {
+            error_code,
+            erring_channel: None,
+            channel_update: None,
+        }
+    }
+
+    pub fn new_channel_fail(
+        error_code: u32,
+        erring_channel: OutPoint,
+        channel_update: Option<ChannelUpdate>,
+    ) -> Self {
+        TlcErr {
+            error_code,
+            erring_channel: Some(erring_channel),
+            channel_update,
+        }
+    }
+
+    pub fn is_permanent(&self) -> bool {
+        self.error_code & Self::PERM != 0
+    }
+
+    // Prepend the length as the header, so that the padding of the error packet is ignored.
+    fn serialize(&self) -> Vec<u8> {
+        let mut serialized = deterministically_serialize(self);
+        let mut packed = (serialized.len() as u64).to_be_bytes().to_vec();
+        packed.append(&mut serialized);
+        packed
+    }
+
+    fn deserialize(data: &[u8]) -> Option<Self> {
+        let len = get_hop_data_len(data)?;
+        if data.len() < len {
+            return None;
+        }
+        serde_json::from_slice(&data[HOP_DATA_HEAD_LEN..len]).ok()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RemoveTlcFail {
+    // The error packet which is encrypted by the erring node and then wrapped again by each
+    // hop on the way back, only the payment origin is able to decrypt it.
+    pub onion_packet: Vec<u8>,
+    // Whether the packet is received from the peer. Such a packet is never encrypted as our own
+    // error in `backward`, even if it looks like a plaintext one.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub received_from_peer: bool,
+}
+
+impl RemoveTlcFail {
+    /// Creates the error packet on the node where the tlc fails. The `shared_secret` is the one
+    /// obtained while peeling the onion packet of the failed tlc.
+    pub fn new(tlc_err: TlcErr, shared_secret: &[u8; 32]) -> Self {
+        if shared_secret == &NO_SHARED_SECRET {
+            return Self::new_plaintext(tlc_err);
+        }
+        let onion_packet = OnionErrorPacket::create(shared_secret, tlc_err.serialize());
+        RemoveTlcFail {
+            onion_packet: onion_packet.into_bytes(),
+            received_from_peer: false,
+        }
+    }
+
+    /// Creates an error packet which is not encrypted yet. The channel actor encrypts it with
+    /// the shared secret of the tlc before sending it to the peer, see `backward`.
+    pub fn new_plaintext(tlc_err: TlcErr) -> Self {
+        let mut onion_packet = NO_ERROR_PACKET_HMAC.to_vec();
+        onion_packet.append(&mut tlc_err.serialize());
+        RemoveTlcFail {
+            onion_packet,
+            received_from_peer: false,
+        }
+    }
+
+    pub fn is_plaintext(&self) -> bool {
+        self.onion_packet.starts_with(&NO_ERROR_PACKET_HMAC)
+    }
+
+    /// Returns the error if the packet is not encrypted.
+    pub fn decode_plaintext(&self) -> Option<TlcErr> {
+        if !self.is_plaintext() {
+            return None;
+        }
+        TlcErr::deserialize(&self.onion_packet[NO_ERROR_PACKET_HMAC.len()..])
+    }
+
+    /// Adds the encryption layer of the current hop with the shared secret of the received tlc,
+    /// before sending the error packet to the previous hop. A plaintext packet created by the
+    /// current node is encrypted as the erring node. A packet received from the next hop is
+    /// always wrapped again, so that the failure is attributed to the node which created it.
+    /// The packet is returned as is if there is no shared secret.
+    pub fn backward(self, shared_secret: &[u8; 32]) -> Self {
+        if shared_secret == &NO_SHARED_SECRET {
+            return self;
+        }
+        if self.is_plaintext() && !self.received_from_peer {
+            return match self.decode_plaintext() {
+                Some(tlc_err) => Self::new(tlc_err, shared_secret),
+                None => self,
+            };
+        }
+        let onion_packet =
+            OnionErrorPacket::from_bytes(self.onion_packet).xor_cipher_stream(shared_secret);
+        RemoveTlcFail {
+            onion_packet: onion_packet.into_bytes(),
+            received_from_peer: false,
+        }
+    }
+
+    /// Decrypts the error packet on the payment origin with the session key of the onion packet
+    /// and the public keys of the nodes in the route. Returns the error and the index of the
+    /// erring node in `hops_path`.
+    pub fn decode(
+        &self,
+        session_key: &[u8; 32],
+        hops_path: Vec<Pubkey>,
+    ) -> Option<(TlcErr, usize)> {
+        if self.is_plaintext() {
+            return None;
+        }
+        let session_key = SecretKey::from_slice(session_key).ok()?;
+        OnionErrorPacket::from_bytes(self.onion_packet.clone()).parse(
+            hops_path.into_iter().map(Into::into).collect(),
+            session_key,
+            TlcErr::deserialize,
+        )
+    }
 }
 
 impl From<RemoveTlcFail> for molecule_fiber::RemoveTlcFail {
     fn from(remove_tlc_fail: RemoveTlcFail) -> Self {
         molecule_fiber::RemoveTlcFail::new_builder()
-            .error_code(remove_tlc_fail.error_code.pack())
+            .onion_packet(remove_tlc_fail.onion_packet.pack())
             .build()
     }
 }
@@ -1262,12 +1659,13 @@ impl TryFrom<molecule_fiber::RemoveTlcFail> for RemoveTlcFail {
 
     fn try_from(remove_tlc_fail: molecule_fiber::RemoveTlcFail) -> Result<Self, Self::Error> {
         Ok(RemoveTlcFail {
-            error_code: remove_tlc_fail.error_code().unpack(),
+            onion_packet: remove_tlc_fail.onion_packet().unpack(),
+            received_from_peer: true,
         })
     }
 }
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum RemoveTlcReason {
     RemoveTlcFulfill(RemoveTlcFulfill),
     RemoveTlcFail(RemoveTlcFail),
@@ -1347,15 +1745,54 @@ pub struct ReestablishChannel {
     pub channel_id: Hash256,
     pub local_commitment_number: u64,
     pub remote_commitment_number: u64,
+    // Only sent to peers supporting the DataLossProtect feature.
+    pub data_loss_protect: Option<DataLossProtect>,
 }
 
-impl From<ReestablishChannel> for molecule_fiber::ReestablishChannel {
+#[derive(Debug, Clone)]
+pub struct DataLossProtect {
+    // The last commitment secret revealed by the receiver, zero if there is none.
+    pub remote_commitment_secret: Hash256,
+    // The current commitment point of the receiver, None if the sender doesn't know it.
+    pub remote_commitment_point: Option<Pubkey>,
+}
+
+// The message is encoded as ReestablishChannelWithDataLossProtect if it carries the data loss
+// protection fields, so that peers without the feature can still parse the plain one.
+impl From<ReestablishChannel> for molecule_fiber::FiberMessageUnion {
     fn from(reestablish_channel: ReestablishChannel) -> Self {
-        molecule_fiber::ReestablishChannel::new_builder()
-            .channel_id(reestablish_channel.channel_id.into())
-            .local_commitment_number(reestablish_channel.local_commitment_number.pack())
-            .remote_commitment_number(reestablish_channel.remote_commitment_number.pack())
-            .build()
+        let ReestablishChannel {
+            channel_id,
+            local_commitment_number,
+            remote_commitment_number,
+            data_loss_protect,
+        } = reestablish_channel;
+        match data_loss_protect {
+            Some(data_loss_protect) => {
+                molecule_fiber::FiberMessageUnion::ReestablishChannelWithDataLossProtect(
+                    molecule_fiber::ReestablishChannelWithDataLossProtect::new_builder()
+                        .channel_id(channel_id.into())
+                        .local_commitment_number(local_commitment_number.pack())
+                        .remote_commitment_number(remote_commitment_number.pack())
+                        .remote_commitment_secret(data_loss_protect.remote_commitment_secret.into())
+                        // An unknown point is sent as all zeros.
+                        .remote_commitment_point(
+                            data_loss_protect
+                                .remote_commitment_point
+                                .map(Into::into)
+                                .unwrap_or_default(),
+                        )
+                        .build(),
+                )
+            }
+            None => molecule_fiber::FiberMessageUnion::ReestablishChannel(
+                molecule_fiber::ReestablishChannel::new_builder()
+                    .channel_id(channel_id.into())
+                    .local_commitment_number(local_commitment_number.pack())
+                    .remote_commitment_number(remote_commitment_number.pack())
+                    .build(),
+            ),
+        }
     }
 }
 
@@ -1369,6 +1806,32 @@ impl TryFrom<molecule_fiber::ReestablishChannel> for ReestablishChannel {
             channel_id: reestablish_channel.channel_id().into(),
             local_commitment_number: reestablish_channel.local_commitment_number().unpack(),
             remote_commitment_number: reestablish_channel.remote_commitment_number().unpack(),
+            data_loss_protect: None,
+        })
+    }
+}
+
+impl TryFrom<molecule_fiber::ReestablishChannelWithDataLossProtect> for ReestablishChannel {
+    type Error = Error;
+
+    fn try_from(
+        reestablish_channel: molecule_fiber::ReestablishChannelWithDataLossProtect,
+    ) -> Result<Self, Self::Error> {
+        let remote_commitment_point = reestablish_channel.remote_commitment_point();
+        let remote_commitment_point = if remote_commitment_point.as_slice().iter().all(|b| *b == 0)
+        {
+            None
+        } else {
+            Some(remote_commitment_point.try_into()?)
+        };
+        Ok(ReestablishChannel {
+            channel_id: reestablish_channel.channel_id().into(),
+            local_commitment_number: reestablish_channel.local_commitment_number().unpack(),
+            remote_commitment_number: reestablish_channel.remote_commitment_number().unpack(),
+            data_loss_protect: Some(DataLossProtect {
+                remote_commitment_secret: reestablish_channel.remote_commitment_secret().into(),
+                remote_commitment_point,
+            }),
         })
     }
 }
@@ -1767,8 +2230,37 @@ pub enum FiberQueryInformation {
     QueryBroadcastMessagesWithinTimeRangeResult(QueryBroadcastMessagesWithinTimeRangeResult),
 }
 
+/// The first message sent by both sides once a connection is established, peers on another
+/// chain or requiring features we don't know are disconnected.
+#[derive(Debug, Clone)]
+pub struct Init {
+    pub chain_hash: Hash256,
+    pub features: FeatureVector,
+}
+
+impl From<Init> for molecule_fiber::Init {
+    fn from(init: Init) -> Self {
+        molecule_fiber::Init::new_builder()
+            .chain_hash(init.chain_hash.into())
+            .features(init.features.as_bytes().pack())
+            .build()
+    }
+}
+
+impl TryFrom<molecule_fiber::Init> for Init {
+    type Error = Error;
+
+    fn try_from(init: molecule_fiber::Init) -> Result<Self, Self::Error> {
+        Ok(Init {
+            chain_hash: init.chain_hash().into(),
+            features: FeatureVector::from_bytes(init.features().raw_data().to_vec()),
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum FiberMessage {
+    Init(Init),
     ChannelInitialization(OpenChannel),
     ChannelNormalOperation(FiberChannelMessage),
     BroadcastMessage(FiberBroadcastMessage),
@@ -1776,6 +2268,10 @@ pub enum FiberMessage {
 }
 
 impl FiberMessage {
+    pub fn init(init: Init) -> Self {
+        FiberMessage::Init(init)
+    }
+
     pub fn open_channel(open_channel: OpenChannel) -> Self {
         FiberMessage::ChannelInitialization(open_channel)
     }
@@ -1818,6 +2314,30 @@ impl FiberMessage {
         FiberMessage::ChannelNormalOperation(FiberChannelMessage::TxAckRBF(tx_ack_rbf))
     }
 
+    pub fn splice_init(splice_init: SpliceInit) -> Self {
+        FiberMessage::ChannelNormalOperation(FiberChannelMessage::SpliceInit(splice_init))
+    }
+
+    pub fn splice_ack(splice_ack: SpliceAck) -> Self {
+        FiberMessage::ChannelNormalOperation(FiberChannelMessage::SpliceAck(splice_ack))
+    }
+
+    pub fn splice_commitment_signed(commitment_signed: SpliceCommitmentSigned) -> Self {
+        FiberMessage::ChannelNormalOperation(FiberChannelMessage::SpliceCommitmentSigned(
+            commitment_signed,
+        ))
+    }
+
+    pub fn splice_revoke_and_ack(revoke_and_ack: SpliceRevokeAndAck) -> Self {
+        FiberMessage::ChannelNormalOperation(FiberChannelMessage::SpliceRevokeAndAck(
+            revoke_and_ack,
+        ))
+    }
+
+    pub fn splice_locked(splice_locked: SpliceLocked) -> Self {
+        FiberMessage::ChannelNormalOperation(FiberChannelMessage::SpliceLocked(splice_locked))
+    }
+
     pub fn shutdown(shutdown: Shutdown) -> Self {
         FiberMessage::ChannelNormalOperation(FiberChannelMessage::Shutdown(shutdown))
     }
@@ -1834,6 +2354,12 @@ impl FiberMessage {
         FiberMessage::ChannelNormalOperation(FiberChannelMessage::RevokeAndAck(revoke_and_ack))
     }
 
+    pub fn commitment_secret(commitment_secret: CommitmentSecret) -> Self {
+        FiberMessage::ChannelNormalOperation(FiberChannelMessage::CommitmentSecret(
+            commitment_secret,
+        ))
+    }
+
     pub fn remove_tlc(remove_tlc: RemoveTlc) -> Self {
         FiberMessage::ChannelNormalOperation(FiberChannelMessage::RemoveTlc(remove_tlc))
     }
@@ -1876,10 +2402,16 @@ pub enum FiberChannelMessage {
     TxAbort(TxAbort),
     TxInitRBF(TxInitRBF),
     TxAckRBF(TxAckRBF),
+    SpliceInit(SpliceInit),
+    SpliceAck(SpliceAck),
+    SpliceCommitmentSigned(SpliceCommitmentSigned),
+    SpliceRevokeAndAck(SpliceRevokeAndAck),
+    SpliceLocked(SpliceLocked),
     Shutdown(Shutdown),
     ClosingSigned(ClosingSigned),
     AddTlc(AddTlc),
     RevokeAndAck(RevokeAndAck),
+    CommitmentSecret(CommitmentSecret),
     RemoveTlc(RemoveTlc),
     ReestablishChannel(ReestablishChannel),
     AnnouncementSignatures(AnnouncementSignatures),
@@ -1899,10 +2431,20 @@ impl FiberChannelMessage {
             FiberChannelMessage::TxAbort(tx_abort) => tx_abort.channel_id,
             FiberChannelMessage::TxInitRBF(tx_init_rbf) => tx_init_rbf.channel_id,
             FiberChannelMessage::TxAckRBF(tx_ack_rbf) => tx_ack_rbf.channel_id,
+            FiberChannelMessage::SpliceInit(splice_init) => splice_init.channel_id,
+            FiberChannelMessage::SpliceAck(splice_ack) => splice_ack.channel_id,
+            FiberChannelMessage::SpliceCommitmentSigned(commitment_signed) => {
+                commitment_signed.channel_id
+            }
+            FiberChannelMessage::SpliceRevokeAndAck(revoke_and_ack) => revoke_and_ack.channel_id,
+            FiberChannelMessage::SpliceLocked(splice_locked) => splice_locked.channel_id,
             FiberChannelMessage::Shutdown(shutdown) => shutdown.channel_id,
             FiberChannelMessage::ClosingSigned(closing_signed) => closing_signed.channel_id,
             FiberChannelMessage::AddTlc(add_tlc) => add_tlc.channel_id,
             FiberChannelMessage::RevokeAndAck(revoke_and_ack) => revoke_and_ack.channel_id,
+            FiberChannelMessage::CommitmentSecret(commitment_secret) => {
+                commitment_secret.channel_id
+            }
             FiberChannelMessage::RemoveTlc(remove_tlc) => remove_tlc.channel_id,
             FiberChannelMessage::ReestablishChannel(reestablish_channel) => {
                 reestablish_channel.channel_id
@@ -2447,6 +2989,7 @@ impl TryFrom<molecule_fiber::QueryBroadcastMessagesWithinTimeRangeResult>
 impl From<FiberMessage> for molecule_fiber::FiberMessageUnion {
     fn from(fiber_message: FiberMessage) -> Self {
         match fiber_message {
+            FiberMessage::Init(init) => molecule_fiber::FiberMessageUnion::Init(init.into()),
             FiberMessage::ChannelInitialization(open_channel) => {
                 molecule_fiber::FiberMessageUnion::OpenChannel(open_channel.into())
             }
@@ -2478,6 +3021,23 @@ impl From<FiberMessage> for molecule_fiber::FiberMessageUnion {
                 FiberChannelMessage::TxAckRBF(tx_ack_rbf) => {
                     molecule_fiber::FiberMessageUnion::TxAckRBF(tx_ack_rbf.into())
                 }
+                FiberChannelMessage::SpliceInit(splice_init) => {
+                    molecule_fiber::FiberMessageUnion::SpliceInit(splice_init.into())
+                }
+                FiberChannelMessage::SpliceAck(splice_ack) => {
+                    molecule_fiber::FiberMessageUnion::SpliceAck(splice_ack.into())
+                }
+                FiberChannelMessage::SpliceCommitmentSigned(commitment_signed) => {
+                    molecule_fiber::FiberMessageUnion::SpliceCommitmentSigned(
+                        commitment_signed.into(),
+                    )
+                }
+                FiberChannelMessage::SpliceRevokeAndAck(revoke_and_ack) => {
+                    molecule_fiber::FiberMessageUnion::SpliceRevokeAndAck(revoke_and_ack.into())
+                }
+                FiberChannelMessage::SpliceLocked(splice_locked) => {
+                    molecule_fiber::FiberMessageUnion::SpliceLocked(splice_locked.into())
+                }
                 FiberChannelMessage::Shutdown(shutdown) => {
                     molecule_fiber::FiberMessageUnion::Shutdown(shutdown.into())
                 }
@@ -2493,10 +3053,11 @@ impl From<FiberMessage> for molecule_fiber::FiberMessageUnion {
                 FiberChannelMessage::RevokeAndAck(revoke_and_ack) => {
                     molecule_fiber::FiberMessageUnion::RevokeAndAck(revoke_and_ack.into())
                 }
+                FiberChannelMessage::CommitmentSecret(commitment_secret) => {
+                    molecule_fiber::FiberMessageUnion::CommitmentSecret(commitment_secret.into())
+                }
                 FiberChannelMessage::ReestablishChannel(reestablish_channel) => {
-                    molecule_fiber::FiberMessageUnion::ReestablishChannel(
-                        reestablish_channel.into(),
-                    )
+                    reestablish_channel.into()
                 }
                 FiberChannelMessage::AnnouncementSignatures(announcement_signatures) => {
                     molecule_fiber::FiberMessageUnion::AnnouncementSignatures(
@@ -2560,6 +3121,7 @@ impl TryFrom<molecule_fiber::FiberMessageUnion> for FiberMessage {
 
     fn try_from(fiber_message: molecule_fiber::FiberMessageUnion) -> Result<Self, Self::Error> {
         Ok(match fiber_message {
+            molecule_fiber::FiberMessageUnion::Init(init) => FiberMessage::Init(init.try_into()?),
             molecule_fiber::FiberMessageUnion::OpenChannel(open_channel) => {
                 FiberMessage::ChannelInitialization(open_channel.try_into()?)
             }
@@ -2608,6 +3170,31 @@ impl TryFrom<molecule_fiber::FiberMessageUnion> for FiberMessage {
                     tx_ack_rbf.try_into()?,
                 ))
             }
+            molecule_fiber::FiberMessageUnion::SpliceInit(splice_init) => {
+                FiberMessage::ChannelNormalOperation(FiberChannelMessage::SpliceInit(
+                    splice_init.try_into()?,
+                ))
+            }
+            molecule_fiber::FiberMessageUnion::SpliceAck(splice_ack) => {
+                FiberMessage::ChannelNormalOperation(FiberChannelMessage::SpliceAck(
+                    splice_ack.try_into()?,
+                ))
+            }
+            molecule_fiber::FiberMessageUnion::SpliceCommitmentSigned(commitment_signed) => {
+                FiberMessage::ChannelNormalOperation(FiberChannelMessage::SpliceCommitmentSigned(
+                    commitment_signed.try_into()?,
+                ))
+            }
+            molecule_fiber::FiberMessageUnion::SpliceRevokeAndAck(revoke_and_ack) => {
+                FiberMessage::ChannelNormalOperation(FiberChannelMessage::SpliceRevokeAndAck(
+                    revoke_and_ack.try_into()?,
+                ))
+            }
+            molecule_fiber::FiberMessageUnion::SpliceLocked(splice_locked) => {
+                FiberMessage::ChannelNormalOperation(FiberChannelMessage::SpliceLocked(
+                    splice_locked.try_into()?,
+                ))
+            }
             molecule_fiber::FiberMessageUnion::Shutdown(shutdown) => {
                 FiberMessage::ChannelNormalOperation(FiberChannelMessage::Shutdown(
                     shutdown.try_into()?,
@@ -2633,11 +3220,21 @@ impl TryFrom<molecule_fiber::FiberMessageUnion> for FiberMessage {
                     revoke_and_ack.try_into()?,
                 ))
             }
+            molecule_fiber::FiberMessageUnion::CommitmentSecret(commitment_secret) => {
+                FiberMessage::ChannelNormalOperation(FiberChannelMessage::CommitmentSecret(
+                    commitment_secret.try_into()?,
+                ))
+            }
             molecule_fiber::FiberMessageUnion::ReestablishChannel(reestablish_channel) => {
                 FiberMessage::ChannelNormalOperation(FiberChannelMessage::ReestablishChannel(
                     reestablish_channel.try_into()?,
                 ))
             }
+            molecule_fiber::FiberMessageUnion::ReestablishChannelWithDataLossProtect(
+                reestablish_channel,
+            ) => FiberMessage::ChannelNormalOperation(FiberChannelMessage::ReestablishChannel(
+                reestablish_channel.try_into()?,
+            )),
             molecule_fiber::FiberMessageUnion::AnnouncementSignatures(announcement_signatures) => {
                 FiberMessage::ChannelNormalOperation(FiberChannelMessage::AnnouncementSignatures(
                     announcement_signatures.try_into()?,
@@ -2756,6 +3353,10 @@ pub struct PaymentHopData {
     pub next_hop: Option<Pubkey>,
     #[serde_as(as = "Option<EntityHex>")]
     pub channel_outpoint: Option<OutPoint>,
+    // this is only specified in the last hop of a multi-part payment,
+    // the target holds the received parts until their sum reaches this amount
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_amount: Option<u128>,
 }
 
 /// Trait for hop data
@@ -2798,6 +3399,9 @@ pub struct OnionPacket<T> {
 pub struct PeeledOnionPacket<T> {
     // The decrypted hop data for the current hop
     pub current: T,
+    // The shared secret of the current hop, which is used to encrypt the error packet
+    // returned to the payment origin. It is `NO_SHARED_SECRET` for the payment origin.
+    pub shared_secret: [u8; 32],
     // The packet for the next hop
     pub next: Option<OnionPacket<T>>,
 }
@@ -2828,6 +3432,7 @@ impl<T: HopData> OnionPacket<T> {
     ) -> Result<PeeledOnionPacket<T>, Error> {
         let sphinx_packet = fiber_sphinx::OnionPacket::from_bytes(self.data)
             .map_err(|err| Error::OnionPacket(err.into()))?;
+        let shared_secret = sphinx_packet.shared_secret(&privkey.0);
 
         let (new_current, new_next) = sphinx_packet
             .peel(&privkey.0, assoc_data, secp_ctx, get_hop_data_len)
@@ -2842,7 +3447,11 @@ impl<T: HopData> OnionPacket<T> {
             .any(|b| *b != 0)
             .then(|| OnionPacket::new(new_next.into_bytes()));
 
-        Ok(PeeledOnionPacket { current, next })
+        Ok(PeeledOnionPacket {
+            current,
+            shared_secret,
+            next,
+        })
     }
 }
 
@@ -2887,7 +3496,11 @@ impl<T: HopData> PeeledOnionPacket<T> {
             None
         };
 
-        Ok(PeeledOnionPacket { current, next })
+        Ok(PeeledOnionPacket {
+            current,
+            shared_secret: NO_SHARED_SECRET,
+            next,
+        })
     }
 
     /// Returns true if this is the peeled packet for the last destination.
@@ -2914,6 +3527,7 @@ impl<T: HopData> PeeledOnionPacket<T> {
 
     pub fn serialize(&self) -> Vec<u8> {
         let mut res = pack_hop_data(&self.current);
+        res.extend_from_slice(&self.shared_secret);
         if let Some(ref next) = self.next {
             res.append(&mut (next.data.clone()));
         }
@@ -2925,12 +3539,23 @@ impl<T: HopData> PeeledOnionPacket<T> {
             .ok_or_else(|| Error::OnionPacket(OnionPacketError::InvalidHopData))?;
         let current = unpack_hop_data(data)
             .ok_or_else(|| Error::OnionPacket(OnionPacketError::InvalidHopData))?;
-        let next = if current_len < data.len() {
-            Some(OnionPacket::new(data[current_len..].to_vec()))
+        let next_offset = current_len + 32;
+        if data.len() < next_offset {
+            return Err(Error::OnionPacket(OnionPacketError::InvalidHopData));
+        }
+        let shared_secret: [u8; 32] = data[current_len..next_offset]
+            .try_into()
+            .expect("shared secret is 32 bytes");
+        let next = if next_offset < data.len() {
+            Some(OnionPacket::new(data[next_offset..].to_vec()))
         } else {
             None
         };
-        Ok(Self { current, next })
+        Ok(Self {
+            current,
+            shared_secret,
+            next,
+        })
     }
 }
 
@@ -2967,11 +3592,12 @@ fn get_hop_data_len(buf: &[u8]) -> Option<usize> {
 
 #[cfg(test)]
 mod tests {
-    use super::{secp256k1_instance, Pubkey};
+    use super::{secp256k1_instance, Pubkey, RemoveTlcFail, TlcErr, NO_SHARED_SECRET};
     use crate::fiber::test_utils::generate_seckey;
     use crate::fiber::types::Privkey;
     use ckb_types::packed::OutPointBuilder;
     use ckb_types::prelude::Builder;
+    use molecule::prelude::Entity;
     use secp256k1::{Secp256k1, SecretKey};
 
     #[test]
@@ -3019,6 +3645,7 @@ mod tests {
                 channel_outpoint: Some(OutPointBuilder::default().build().into()),
                 tlc_hash_algorithm: super::HashAlgorithm::Sha256,
                 preimage: None,
+                total_amount: None,
             },
             super::PaymentHopData {
                 payment_hash,
@@ -3028,6 +3655,7 @@ mod tests {
                 channel_outpoint: Some(OutPointBuilder::default().build().into()),
                 tlc_hash_algorithm: super::HashAlgorithm::Sha256,
                 preimage: None,
+                total_amount: None,
             },
             super::PaymentHopData {
                 payment_hash,
@@ -3037,6 +3665,7 @@ mod tests {
                 channel_outpoint: Some(OutPointBuilder::default().build().into()),
                 tlc_hash_algorithm: super::HashAlgorithm::Sha256,
                 preimage: None,
+                total_amount: None,
             },
         ];
         let packet =
@@ -3059,4 +3688,164 @@ mod tests {
         assert_eq!(packet.current, hops_infos[2]);
         assert!(packet.is_last());
     }
+
+    #[test]
+    fn test_tlc_error_packet() {
+        let secp = Secp256k1::new();
+        let keys: Vec<Privkey> = std::iter::repeat_with(|| generate_seckey().into())
+            .take(3)
+            .collect();
+        let hops_path: Vec<Pubkey> = keys[1..].iter().map(|key| key.pubkey()).collect();
+        let payment_hash = [1; 32].into();
+        let channel_outpoint = OutPointBuilder::default().build();
+        let hops_infos = vec![
+            super::PaymentHopData {
+                payment_hash,
+                amount: 5,
+                expiry: 6,
+                next_hop: Some(hops_path[0]),
+                channel_outpoint: Some(channel_outpoint.clone()),
+                tlc_hash_algorithm: super::HashAlgorithm::Sha256,
+                preimage: None,
+                total_amount: None,
+            },
+            super::PaymentHopData {
+                payment_hash,
+                amount: 5,
+                expiry: 3,
+                next_hop: Some(hops_path[1]),
+                channel_outpoint: Some(channel_outpoint.clone()),
+                tlc_hash_algorithm: super::HashAlgorithm::Sha256,
+                preimage: None,
+                total_amount: None,
+            },
+            super::PaymentHopData {
+                payment_hash,
+                amount: 5,
+                expiry: 0,
+                next_hop: None,
+                channel_outpoint: None,
+                tlc_hash_algorithm: super::HashAlgorithm::Sha256,
+                preimage: None,
+                total_amount: None,
+            },
+        ];
+        let session_key: Privkey = generate_seckey().into();
+        let packet = super::PeeledOnionPacket::create(session_key.clone(), hops_infos, &secp)
+            .expect("create peeled packet");
+        assert_eq!(packet.shared_secret, NO_SHARED_SECRET);
+
+        let first_hop_packet = packet.peel(&keys[1], &secp).expect("peel");
+        let second_hop_packet = first_hop_packet
+            .clone()
+            .peel(&keys[2], &secp)
+            .expect("peel");
+        assert_ne!(first_hop_packet.shared_secret, NO_SHARED_SECRET);
+        assert_ne!(
+            first_hop_packet.shared_secret,
+            second_hop_packet.shared_secret
+        );
+
+        // The error is created by the payment target, and relayed by the first hop.
+        let tlc_err = TlcErr::new(TlcErr::INCORRECT_OR_UNKNOWN_PAYMENT_DETAILS);
+        let error_packet = RemoveTlcFail::new_plaintext(tlc_err.clone());
+        assert!(error_packet.is_plaintext());
+        assert_eq!(error_packet.decode_plaintext(), Some(tlc_err.clone()));
+
+        let error_packet = error_packet.backward(&second_hop_packet.shared_secret);
+        assert!(!error_packet.is_plaintext());
+        let error_packet = error_packet.backward(&first_hop_packet.shared_secret);
+
+        let (decoded, erring_index) = error_packet
+            .decode(session_key.as_ref(), hops_path.clone())
+            .expect("decode error packet");
+        assert_eq!(decoded, tlc_err);
+        assert_eq!(erring_index, 1);
+
+        // The error is created by the first hop, which failed to forward the tlc.
+        let tlc_err =
+            TlcErr::new_channel_fail(TlcErr::TEMPORARY_CHANNEL_FAILURE, channel_outpoint, None);
+        let error_packet = RemoveTlcFail::new(tlc_err.clone(), &first_hop_packet.shared_secret);
+        let (decoded, erring_index) = error_packet
+            .decode(session_key.as_ref(), hops_path.clone())
+            .expect("decode error packet");
+        assert_eq!(decoded, tlc_err);
+        assert_eq!(erring_index, 0);
+        assert!(!decoded.is_permanent());
+
+        // A plaintext packet sent by the payment target is wrapped by the first hop as it is,
+        // instead of being claimed as the error of the first hop.
+        let error_packet = RemoveTlcFail {
+            received_from_peer: true,
+            ..RemoveTlcFail::new_plaintext(tlc_err)
+        };
+        let error_packet = error_packet.backward(&first_hop_packet.shared_secret);
+        assert!(!error_packet.is_plaintext());
+        assert_eq!(error_packet.decode(session_key.as_ref(), hops_path), None);
+    }
+
+    #[test]
+    fn test_remove_tlc_fail_serialization() {
+        let remove_tlc = super::RemoveTlc {
+            channel_id: [42; 32].into(),
+            tlc_id: 42,
+            reason: super::RemoveTlcReason::RemoveTlcFail(RemoveTlcFail::new_plaintext(
+                TlcErr::new(TlcErr::TEMPORARY_CHANNEL_FAILURE),
+            )),
+        };
+        let remove_tlc_mol: super::molecule_fiber::RemoveTlc = remove_tlc.clone().into();
+        let remove_tlc2: super::RemoveTlc = remove_tlc_mol.try_into().expect("decode");
+        let (
+            super::RemoveTlcReason::RemoveTlcFail(error_packet),
+            super::RemoveTlcReason::RemoveTlcFail(error_packet2),
+        ) = (remove_tlc.reason, remove_tlc2.reason)
+        else {
+            panic!("expect RemoveTlcFail");
+        };
+        assert_eq!(error_packet.onion_packet, error_packet2.onion_packet);
+        assert!(!error_packet.received_from_peer);
+        assert!(error_packet2.received_from_peer);
+    }
+
+    #[test]
+    fn test_reestablish_channel_serialization() {
+        let reestablish_channel = super::ReestablishChannel {
+            channel_id: [42; 32].into(),
+            local_commitment_number: 2,
+            remote_commitment_number: 3,
+            data_loss_protect: None,
+        };
+        // Peers without DataLossProtect can still parse the plain message.
+        let bytes = super::FiberMessage::reestablish_channel(reestablish_channel.clone())
+            .to_molecule_bytes();
+        let message = super::molecule_fiber::FiberMessage::from_slice(&bytes).expect("decode");
+        assert!(matches!(
+            message.to_enum(),
+            super::molecule_fiber::FiberMessageUnion::ReestablishChannel(_)
+        ));
+
+        let reestablish_channel = super::ReestablishChannel {
+            data_loss_protect: Some(super::DataLossProtect {
+                remote_commitment_secret: [7; 32].into(),
+                remote_commitment_point: None,
+            }),
+            ..reestablish_channel
+        };
+        let bytes =
+            super::FiberMessage::reestablish_channel(reestablish_channel).to_molecule_bytes();
+        let super::FiberMessage::ChannelNormalOperation(
+            super::FiberChannelMessage::ReestablishChannel(decoded),
+        ) = super::FiberMessage::from_molecule_slice(&bytes).expect("decode")
+        else {
+            panic!("ReestablishChannel expected");
+        };
+        let data_loss_protect = decoded.data_loss_protect.expect("data loss protect");
+        assert_eq!(
+            data_loss_protect.remote_commitment_secret,
+            super::Hash256::from([7; 32])
+        );
+        assert!(data_loss_protect.remote_commitment_point.is_none());
+        assert_eq!(decoded.local_commitment_number, 2);
+        assert_eq!(decoded.remote_commitment_number, 3);
+    }
 }
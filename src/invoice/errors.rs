@@ -2,6 +2,8 @@ use std::fmt::Display;
 use std::num::ParseIntError;
 use thiserror::Error;
 
+use super::CkbInvoiceStatus;
+
 #[derive(Error, Debug)]
 pub struct VerificationError(pub molecule::error::VerificationError);
 
@@ -55,4 +57,10 @@ pub enum InvoiceError {
     HexDecodeError(#[from] hex::FromHexError),
     #[error("Duplicated inovice found: {0}")]
     DuplicatedInvoice(String),
+    #[error("Invoice not found: {0}")]
+    InvoiceNotFound(String),
+    #[error("Invoice {0} can not be cancelled in status {1:?}")]
+    InvoiceNotCancellable(String, CkbInvoiceStatus),
+    #[error("Invoice {0} can not be settled in status {1:?}, or it is not a hold invoice")]
+    InvoiceNotSettleable(String, CkbInvoiceStatus),
 }
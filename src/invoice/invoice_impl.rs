@@ -9,7 +9,7 @@ use crate::invoice::InvoiceError;
 use bech32::{encode, u5, FromBase32, ToBase32, Variant, WriteBase32};
 use bitcoin::hashes::{sha256::Hash as Sha256, Hash as _};
 use ckb_types::{
-    packed::{Byte, Script},
+    packed::{Byte, OutPoint, Script},
     prelude::{Pack, Unpack},
 };
 use core::time::Duration;
@@ -74,6 +74,20 @@ impl FromStr for Currency {
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct CkbScript(#[serde_as(as = "EntityHex")] pub Script);
 
+/// A hop of a route hint, which describes a channel not announced to the network.
+/// The channel is from the node `pubkey` to the next hop, the channel of the last hop is to the payee.
+#[serde_as]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RouteHintHop {
+    pub pubkey: PublicKey,
+    #[serde_as(as = "EntityHex")]
+    pub channel_outpoint: OutPoint,
+    // the fee rate charged by `pubkey` for forwarding tlcs through the channel
+    pub fee_rate: u64,
+    // the expiry delta required by `pubkey` for forwarding tlcs through the channel
+    pub tlc_expiry_delta: u64,
+}
+
 #[serde_as]
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Attribute {
@@ -86,6 +100,8 @@ pub enum Attribute {
     PayeePublicKey(PublicKey),
     HashAlgorithm(HashAlgorithm),
     Feature(u64),
+    // Can be set multiple times, one for each route to the payee.
+    RouteHint(Vec<RouteHintHop>),
 }
 
 #[serde_as]
@@ -113,6 +129,23 @@ pub struct CkbInvoice {
     pub data: InvoiceData,
 }
 
+/// The status of an invoice created by us, see [`InvoiceStore::get_invoice_status`].
+///
+/// [`InvoiceStore::get_invoice_status`]: crate::invoice::InvoiceStore::get_invoice_status
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum CkbInvoiceStatus {
+    /// The invoice is waiting to be paid.
+    Open,
+    /// The tlcs paying the invoice are received, but not settled yet.
+    Received,
+    /// The tlcs paying the invoice are fulfilled.
+    Paid,
+    /// The invoice is cancelled, tlcs paying it are rejected.
+    Cancelled,
+    /// The invoice is expired before it is paid, tlcs paying it are rejected.
+    Expired,
+}
+
 macro_rules! attr_getter {
     ($name:ident, $attr_name:ident, $attr:ty) => {
         pub fn $name(&self) -> Option<&$attr> {
@@ -247,6 +280,15 @@ impl CkbInvoice {
         self.amount
     }
 
+    /// Whether the expiry time of the invoice has passed, an invoice without expiry time
+    /// never expires.
+    pub fn is_expired(&self) -> bool {
+        self.expiry_time().is_some_and(|expiry| {
+            let now = std::time::UNIX_EPOCH.elapsed().unwrap().as_millis();
+            self.data.timestamp + expiry.as_millis() < now
+        })
+    }
+
     pub fn udt_type_script(&self) -> Option<&Script> {
         self.data
             .attrs
@@ -269,6 +311,17 @@ impl CkbInvoice {
     );
     attr_getter!(fallback_address, FallbackAddr, String);
     attr_getter!(hash_algorithm, HashAlgorithm, HashAlgorithm);
+
+    pub fn route_hints(&self) -> Vec<&Vec<RouteHintHop>> {
+        self.data
+            .attrs
+            .iter()
+            .filter_map(|attr| match attr {
+                Attribute::RouteHint(hops) => Some(hops),
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 /// Recoverable signature
@@ -456,6 +509,15 @@ impl From<Attribute> for InvoiceAttr {
                     .value(Byte::new(hash_algorithm as u8))
                     .build(),
             ),
+            Attribute::RouteHint(hops) => InvoiceAttrUnion::RouteHint(
+                RouteHint::new_builder()
+                    .value(
+                        RouteHintHops::new_builder()
+                            .set(hops.into_iter().map(Into::into).collect())
+                            .build(),
+                    )
+                    .build(),
+            ),
         };
         InvoiceAttr::new_builder().set(a).build()
     }
@@ -497,6 +559,32 @@ impl From<InvoiceAttr> for Attribute {
                 let hash_algorithm = value.try_into().unwrap_or_default();
                 Attribute::HashAlgorithm(hash_algorithm)
             }
+            InvoiceAttrUnion::RouteHint(x) => {
+                Attribute::RouteHint(x.value().into_iter().map(Into::into).collect())
+            }
+        }
+    }
+}
+
+impl From<RouteHintHop> for gen_invoice::RouteHintHop {
+    fn from(hop: RouteHintHop) -> Self {
+        gen_invoice::RouteHintHop::new_builder()
+            .pubkey(hop.pubkey.serialize().pack())
+            .channel_outpoint(hop.channel_outpoint)
+            .fee_rate(hop.fee_rate.pack())
+            .tlc_expiry_delta(hop.tlc_expiry_delta.pack())
+            .build()
+    }
+}
+
+impl From<gen_invoice::RouteHintHop> for RouteHintHop {
+    fn from(hop: gen_invoice::RouteHintHop) -> Self {
+        let pubkey: Vec<u8> = hop.pubkey().unpack();
+        RouteHintHop {
+            pubkey: PublicKey::from_slice(&pubkey).unwrap(),
+            channel_outpoint: hop.channel_outpoint(),
+            fee_rate: hop.fee_rate().unpack(),
+            tlc_expiry_delta: hop.tlc_expiry_delta().unpack(),
         }
     }
 }
@@ -572,6 +660,7 @@ impl InvoiceBuilder {
     attr_setter!(expiry_time, ExpiryTime, Duration);
     attr_setter!(fallback_address, FallbackAddr, String);
     attr_setter!(final_cltv, FinalHtlcMinimumCltvExpiry, u64);
+    attr_setter!(route_hint, RouteHint, Vec<RouteHintHop>);
 
     pub fn build(self) -> Result<CkbInvoice, InvoiceError> {
         let preimage = self.payment_preimage;
@@ -623,6 +712,10 @@ impl InvoiceBuilder {
     fn check_duplicated_attrs(&self) -> Result<(), InvoiceError> {
         // check is there any duplicate attribute key set
         for (i, attr) in self.attrs.iter().enumerate() {
+            // multiple route hints are allowed
+            if matches!(attr, Attribute::RouteHint(_)) {
+                continue;
+            }
             for other in self.attrs.iter().skip(i + 1) {
                 if std::mem::discriminant(attr) == std::mem::discriminant(other) {
                     return Err(InvoiceError::DuplicatedAttributeKey(format!("{:?}", attr)));
@@ -1029,6 +1122,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_invoice_is_expired() {
+        let invoice = InvoiceBuilder::new(Currency::Fibb)
+            .amount(Some(1280))
+            .payment_preimage(rand_sha256_hash())
+            .expiry_time(Duration::from_secs(1024))
+            .build()
+            .unwrap();
+        assert!(!invoice.is_expired());
+
+        let mut expired_invoice = invoice.clone();
+        expired_invoice.data.timestamp -= 1025 * 1000;
+        assert!(expired_invoice.is_expired());
+
+        let invoice = InvoiceBuilder::new(Currency::Fibb)
+            .amount(Some(1280))
+            .payment_preimage(rand_sha256_hash())
+            .build()
+            .unwrap();
+        assert!(!invoice.is_expired());
+    }
+
     #[test]
     fn test_invoice_serialize() {
         let invoice = mock_invoice();
@@ -1098,4 +1213,30 @@ mod tests {
         let decoded = serde_json::from_str::<CkbInvoice>(&res.unwrap()).unwrap();
         assert_eq!(decoded, invoice);
     }
+
+    #[test]
+    fn test_invoice_route_hints() {
+        let private_key = gen_rand_private_key();
+        let gen_hop = |index: u32| RouteHintHop {
+            pubkey: gen_rand_public_key(),
+            channel_outpoint: OutPoint::new_builder().index(index.pack()).build(),
+            fee_rate: 1000,
+            tlc_expiry_delta: 100,
+        };
+        let hint1 = vec![gen_hop(0)];
+        let hint2 = vec![gen_hop(1), gen_hop(2)];
+        let invoice = InvoiceBuilder::new(Currency::Fibb)
+            .amount(Some(1280))
+            .payment_hash(rand_sha256_hash())
+            .route_hint(hint1.clone())
+            .route_hint(hint2.clone())
+            .build_with_sign(|hash| Secp256k1::new().sign_ecdsa_recoverable(hash, &private_key))
+            .unwrap();
+        assert_eq!(invoice.route_hints(), vec![&hint1, &hint2]);
+
+        let address = invoice.to_string();
+        let decoded = address.parse::<CkbInvoice>().unwrap();
+        assert_eq!(decoded, invoice);
+        assert_eq!(decoded.route_hints(), vec![&hint1, &hint2]);
+    }
 }
@@ -6,5 +6,8 @@ mod utils;
 
 pub use command::*;
 pub use errors::InvoiceError;
-pub use invoice_impl::{Attribute, CkbInvoice, Currency, InvoiceBuilder, InvoiceSignature};
+pub use invoice_impl::{
+    Attribute, CkbInvoice, CkbInvoiceStatus, Currency, InvoiceBuilder, InvoiceSignature,
+    RouteHintHop,
+};
 pub use store::*;
@@ -1,4 +1,9 @@
-use crate::{fiber::types::Hash256, invoice::CkbInvoice};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    fiber::types::Hash256,
+    invoice::{CkbInvoice, CkbInvoiceStatus},
+};
 
 use super::InvoiceError;
 
@@ -10,4 +15,71 @@ pub trait InvoiceStore {
         preimage: Option<Hash256>,
     ) -> Result<(), InvoiceError>;
     fn get_invoice_preimage(&self, id: &Hash256) -> Option<Hash256>;
+    fn insert_invoice_preimage(&self, id: &Hash256, preimage: Hash256) -> Result<(), InvoiceError>;
+    fn get_invoices(&self) -> Vec<CkbInvoice>;
+    fn get_invoice_status(&self, id: &Hash256) -> Option<CkbInvoiceStatus>;
+    fn update_invoice_status(
+        &self,
+        id: &Hash256,
+        status: CkbInvoiceStatus,
+    ) -> Result<(), InvoiceError>;
+    fn get_held_payments(&self) -> Vec<HeldPayment>;
+    fn insert_held_payment(&self, held_payment: HeldPayment);
+    fn remove_held_payment(&self, payment_hash: &Hash256);
+    fn get_pending_mpp_payments(&self) -> Vec<PendingMppPayment>;
+    fn insert_pending_mpp_payment(&self, payment: PendingMppPayment);
+    fn remove_pending_mpp_payment(&self, payment_hash: &Hash256);
+}
+
+/// The received tlcs paying a hold invoice, they are held until the invoice is settled or
+/// cancelled, and are failed if the invoice is not resolved before the tlcs expire.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HeldPayment {
+    pub payment_hash: Hash256,
+    /// The channel ids and tlc ids of the held tlcs.
+    pub tlcs: Vec<(Hash256, u64)>,
+    /// The time in milliseconds after which the held tlcs are failed.
+    pub expires_at: u64,
+}
+
+/// The received parts of a multi-part payment, they are held until their sum reaches
+/// the total amount or the payment is timed out.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingMppPayment {
+    pub payment_hash: Hash256,
+    pub total_amount: u128,
+    /// The preimage to settle the payment, None if the payment hash is unknown to us.
+    pub preimage: Option<Hash256>,
+    /// The channel ids and tlc ids of the received parts, along with their amounts.
+    pub parts: Vec<(Hash256, u64, u128)>,
+    /// The time in milliseconds when the first part is received.
+    pub first_received_at: u64,
+    /// The time in milliseconds when the earliest expiring part expires.
+    pub expires_at: u64,
+}
+
+impl PendingMppPayment {
+    pub fn received_amount(&self) -> u128 {
+        self.parts.iter().map(|(_, _, amount)| amount).sum()
+    }
+}
+
+/// Get the status of the invoice, an open invoice whose expiry time has passed is
+/// marked as expired.
+pub fn get_invoice_status_with_expiry<S: InvoiceStore>(
+    store: &S,
+    id: &Hash256,
+) -> Option<CkbInvoiceStatus> {
+    let status = store.get_invoice_status(id)?;
+    if status == CkbInvoiceStatus::Open
+        && store
+            .get_invoice(id)
+            .is_some_and(|invoice| invoice.is_expired())
+    {
+        store
+            .update_invoice_status(id, CkbInvoiceStatus::Expired)
+            .expect("invoice exists");
+        return Some(CkbInvoiceStatus::Expired);
+    }
+    Some(status)
 }
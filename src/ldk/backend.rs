@@ -0,0 +1,270 @@
+use super::disk::{FilesystemLogger, OUTBOUND_PAYMENTS_FNAME};
+use super::{ChannelManager, HTLCStatus, MillisatAmount, OutboundPaymentInfoStorage, PaymentInfo};
+use crate::cch::{
+    CchError, CchOrderStatus, InvoiceUpdate, InvoiceUpdateStream, LightningBackend, PaymentUpdate,
+    PaymentUpdateStream,
+};
+use crate::fiber::types::Hash256;
+use bitcoin::hashes::Hash;
+use bitcoin::Network;
+use futures::{stream, StreamExt as _};
+use lightning::ln::channelmanager::{PaymentId, Retry};
+use lightning::ln::{PaymentHash, PaymentPreimage};
+use lightning::sign::KeysManager;
+use lightning::util::persist::KVStore;
+use lightning::util::ser::Writeable;
+use lightning_invoice::payment::payment_parameters_from_invoice;
+use lightning_invoice::{utils, Bolt11Invoice, Currency};
+use lightning_persister::fs_store::FilesystemStore;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+// The time to retry an outgoing payment before it's failed.
+const PAYMENT_RETRY_TIMEOUT: Duration = Duration::from_secs(60);
+const BACKEND_EVENT_CHANNEL_SIZE: usize = 1024;
+
+/// The payment and invoice updates reported by the LDK event handler.
+pub(crate) struct LdkBackendEvents {
+    // The latest status of the hold invoices, the accepted HTLCs are only known by the events.
+    invoices: Mutex<HashMap<Hash256, InvoiceUpdate>>,
+    invoice_sender: broadcast::Sender<InvoiceUpdate>,
+    payment_sender: broadcast::Sender<PaymentUpdate>,
+}
+
+impl LdkBackendEvents {
+    pub(crate) fn new() -> Self {
+        Self {
+            invoices: Mutex::new(HashMap::new()),
+            invoice_sender: broadcast::channel(BACKEND_EVENT_CHANNEL_SIZE).0,
+            payment_sender: broadcast::channel(BACKEND_EVENT_CHANNEL_SIZE).0,
+        }
+    }
+
+    pub(crate) fn notify_invoice(
+        &self,
+        payment_hash: PaymentHash,
+        preimage: Option<PaymentPreimage>,
+        status: CchOrderStatus,
+    ) {
+        let update = InvoiceUpdate {
+            payment_hash: payment_hash.0.into(),
+            preimage: preimage.map(|preimage| preimage.0.into()),
+            status,
+        };
+        self.invoices
+            .lock()
+            .unwrap()
+            .insert(update.payment_hash, update.clone());
+        let _ = self.invoice_sender.send(update);
+    }
+
+    pub(crate) fn notify_payment(
+        &self,
+        payment_hash: PaymentHash,
+        preimage: Option<PaymentPreimage>,
+        status: CchOrderStatus,
+    ) {
+        let _ = self.payment_sender.send(PaymentUpdate {
+            payment_hash: payment_hash.0.into(),
+            preimage: preimage.map(|preimage| preimage.0.into()),
+            status,
+        });
+    }
+}
+
+/// The Lightning backend using the LDK node running in the same process.
+#[derive(Clone)]
+pub struct LdkBackend {
+    pub(crate) channel_manager: Arc<ChannelManager>,
+    pub(crate) keys_manager: Arc<KeysManager>,
+    pub(crate) logger: Arc<FilesystemLogger>,
+    pub(crate) network: Network,
+    pub(crate) outbound_payments: Arc<Mutex<OutboundPaymentInfoStorage>>,
+    pub(crate) fs_store: Arc<FilesystemStore>,
+    pub(crate) events: Arc<LdkBackendEvents>,
+}
+
+fn hash_bytes(hash: &Hash256) -> [u8; 32] {
+    hash.as_ref().try_into().expect("hash is 32 bytes")
+}
+
+fn backend_error(err: impl std::fmt::Debug) -> CchError {
+    CchError::LightningBackendError(format!("{:?}", err))
+}
+
+// Stream the updates from the broadcast channel, the lagged updates are skipped.
+fn stream_updates<T, F>(
+    receiver: broadcast::Receiver<T>,
+    filter: F,
+) -> stream::BoxStream<'static, T>
+where
+    T: Clone + Send + 'static,
+    F: Fn(&T) -> bool + Send + Sync + 'static,
+{
+    let filter = Arc::new(filter);
+    stream::unfold(receiver, move |mut receiver| {
+        let filter = filter.clone();
+        async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(update) if filter(&update) => return Some((update, receiver)),
+                    Ok(_) => continue,
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::warn!("[LdkBackend] {} updates are skipped", skipped);
+                    }
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        }
+    })
+    .boxed()
+}
+
+#[ractor::async_trait]
+impl LightningBackend for LdkBackend {
+    async fn pay_invoice(&self, btc_pay_req: &str) -> Result<PaymentUpdate, CchError> {
+        let invoice = Bolt11Invoice::from_str(btc_pay_req)?;
+        let payment_hash = (*invoice.payment_hash()).to_byte_array();
+        let payment_id = PaymentId(payment_hash);
+        let (payment_hash, recipient_onion, route_params) =
+            payment_parameters_from_invoice(&invoice)
+                .map_err(|_| CchError::BTCInvoiceMissingAmount)?;
+
+        let mut outbound = self.outbound_payments.lock().unwrap();
+        outbound.payments.insert(
+            payment_id,
+            PaymentInfo {
+                preimage: None,
+                secret: Some(*invoice.payment_secret()),
+                status: HTLCStatus::Pending,
+                amt_msat: MillisatAmount(invoice.amount_milli_satoshis()),
+            },
+        );
+        let result = self.channel_manager.send_payment(
+            payment_hash,
+            recipient_onion,
+            payment_id,
+            route_params,
+            Retry::Timeout(PAYMENT_RETRY_TIMEOUT),
+        );
+        let status = match result {
+            Ok(_) => CchOrderStatus::InFlight,
+            Err(ref err) => {
+                tracing::error!("[LdkBackend] failed to send payment: {:?}", err);
+                outbound.payments.get_mut(&payment_id).unwrap().status = HTLCStatus::Failed;
+                CchOrderStatus::Failed
+            }
+        };
+        self.fs_store
+            .write("", "", OUTBOUND_PAYMENTS_FNAME, &outbound.encode())
+            .map_err(backend_error)?;
+        result.map_err(backend_error)?;
+
+        Ok(PaymentUpdate {
+            payment_hash: payment_hash.0.into(),
+            preimage: None,
+            status,
+        })
+    }
+
+    async fn get_payment(&self, payment_hash: Hash256) -> Result<PaymentUpdate, CchError> {
+        let outbound = self.outbound_payments.lock().unwrap();
+        let payment = outbound
+            .payments
+            .get(&PaymentId(hash_bytes(&payment_hash)))
+            .ok_or_else(|| {
+                CchError::LightningBackendError(format!("payment {:#x} not found", payment_hash))
+            })?;
+        let status = match payment.status {
+            HTLCStatus::Pending => CchOrderStatus::InFlight,
+            HTLCStatus::Succeeded => CchOrderStatus::Succeeded,
+            HTLCStatus::Failed => CchOrderStatus::Failed,
+        };
+        Ok(PaymentUpdate {
+            payment_hash,
+            preimage: payment.preimage.map(|preimage| preimage.0.into()),
+            status,
+        })
+    }
+
+    async fn track_payments(&self) -> Result<PaymentUpdateStream, CchError> {
+        let receiver = self.events.payment_sender.subscribe();
+        Ok(stream_updates(receiver, |payment: &PaymentUpdate| {
+            payment.status.is_final()
+        })
+        .map(Ok)
+        .boxed())
+    }
+
+    async fn add_hold_invoice(
+        &self,
+        payment_hash: Hash256,
+        amount_msat: u64,
+        expiry_seconds: u64,
+        cltv_expiry: u64,
+    ) -> Result<String, CchError> {
+        let currency = match self.network {
+            Network::Bitcoin => Currency::Bitcoin,
+            Network::Regtest => Currency::Regtest,
+            Network::Signet => Currency::Signet,
+            Network::Testnet | _ => Currency::BitcoinTestnet,
+        };
+        let duration_since_epoch = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        // The preimage is unknown, so the payment is held as claimable until it's settled.
+        let invoice =
+            utils::create_invoice_from_channelmanager_and_duration_since_epoch_with_payment_hash(
+                &self.channel_manager,
+                self.keys_manager.clone(),
+                self.logger.clone(),
+                currency,
+                Some(amount_msat),
+                "cross-chain hub".to_string(),
+                duration_since_epoch,
+                expiry_seconds as u32,
+                PaymentHash(hash_bytes(&payment_hash)),
+                Some(cltv_expiry as u16),
+            )
+            .map_err(backend_error)?;
+        self.events.notify_invoice(
+            PaymentHash(hash_bytes(&payment_hash)),
+            None,
+            CchOrderStatus::Pending,
+        );
+        Ok(invoice.to_string())
+    }
+
+    async fn track_invoice(&self, payment_hash: Hash256) -> Result<InvoiceUpdateStream, CchError> {
+        // Subscribe before reading the current status to not miss any update.
+        let receiver = self.events.invoice_sender.subscribe();
+        // The status is unknown after restarting until the HTLC is accepted again.
+        let current = self
+            .events
+            .invoices
+            .lock()
+            .unwrap()
+            .get(&payment_hash)
+            .cloned();
+        let updates = stream_updates(receiver, move |invoice: &InvoiceUpdate| {
+            invoice.payment_hash == payment_hash
+        });
+        Ok(stream::iter(current).chain(updates).map(Ok).boxed())
+    }
+
+    async fn settle_invoice(&self, preimage: Hash256) -> Result<(), CchError> {
+        // The invoice is reported as succeeded by the `PaymentClaimed` event.
+        self.channel_manager
+            .claim_funds(PaymentPreimage(hash_bytes(&preimage)));
+        Ok(())
+    }
+
+    async fn cancel_invoice(&self, payment_hash: Hash256) -> Result<(), CchError> {
+        let payment_hash = PaymentHash(hash_bytes(&payment_hash));
+        self.channel_manager.fail_htlc_backwards(&payment_hash);
+        self.events
+            .notify_invoice(payment_hash, None, CchOrderStatus::Failed);
+        Ok(())
+    }
+}
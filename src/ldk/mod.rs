@@ -1,4 +1,5 @@
 #![allow(clippy::all)]
+mod backend;
 pub mod bitcoind_client;
 mod cli;
 mod config;
@@ -7,6 +8,9 @@ mod disk;
 mod hex_utils;
 mod sweep;
 
+use crate::cch::CchOrderStatus;
+pub use backend::LdkBackend;
+use backend::LdkBackendEvents;
 use bitcoin::blockdata::transaction::Transaction;
 use bitcoin::consensus::encode;
 use bitcoin::network::constants::Network as BitcoinNetwork;
@@ -185,6 +189,7 @@ async fn handle_ldk_events(
     inbound_payments: Arc<Mutex<InboundPaymentInfoStorage>>,
     outbound_payments: Arc<Mutex<OutboundPaymentInfoStorage>>,
     fs_store: Arc<FilesystemStore>,
+    backend_events: Arc<LdkBackendEvents>,
     network: BitcoinNetwork,
     event: Event,
 ) {
@@ -263,7 +268,11 @@ async fn handle_ldk_events(
                 } => payment_preimage,
                 PaymentPurpose::SpontaneousPayment(preimage) => Some(preimage),
             };
-            channel_manager.claim_funds(payment_preimage.unwrap());
+            match payment_preimage {
+                Some(payment_preimage) => channel_manager.claim_funds(payment_preimage),
+                // The payment of a hold invoice, which is claimed once the preimage is known.
+                None => backend_events.notify_invoice(payment_hash, None, CchOrderStatus::Accepted),
+            }
         }
         Event::PaymentClaimed {
             payment_hash,
@@ -287,6 +296,11 @@ async fn handle_ldk_events(
                 } => (payment_preimage, Some(payment_secret)),
                 PaymentPurpose::SpontaneousPayment(preimage) => (Some(preimage), None),
             };
+            backend_events.notify_invoice(
+                payment_hash,
+                payment_preimage,
+                CchOrderStatus::Succeeded,
+            );
             let mut inbound = inbound_payments.lock().unwrap();
             match inbound.payments.entry(payment_hash) {
                 Entry::Occupied(mut e) => {
@@ -315,6 +329,11 @@ async fn handle_ldk_events(
             payment_id,
             ..
         } => {
+            backend_events.notify_payment(
+                payment_hash,
+                Some(payment_preimage),
+                CchOrderStatus::Succeeded,
+            );
             let mut outbound = outbound_payments.lock().unwrap();
             for (id, payment) in outbound.payments.iter_mut() {
                 if *id == payment_id.unwrap() {
@@ -401,6 +420,7 @@ async fn handle_ldk_events(
             fs_store
                 .write("", "", OUTBOUND_PAYMENTS_FNAME, &outbound.encode())
                 .unwrap();
+            backend_events.notify_payment(payment_hash, None, CchOrderStatus::Failed);
         }
         Event::InvoiceRequestFailed { payment_id } => {
             print!(
@@ -591,7 +611,9 @@ async fn handle_ldk_events(
     }
 }
 
-pub async fn start_ldk(config: LdkConfig) {
+/// Start the LDK node, returns the backend for the cross-chain hub to send and receive payments
+/// through it. The CLI and the background processor keep running in a spawned task.
+pub async fn start_ldk(config: LdkConfig) -> Option<LdkBackend> {
     // Initialize the LDK data directory if necessary.
     let ldk_data_dir = config.base_dir().join("data");
     fs::create_dir_all(ldk_data_dir.clone()).unwrap();
@@ -615,7 +637,7 @@ pub async fn start_ldk(config: LdkConfig) {
         Ok(client) => Arc::new(client),
         Err(e) => {
             println!("Failed to connect to bitcoind client: {}", e);
-            return;
+            return None;
         }
     };
 
@@ -634,7 +656,7 @@ pub async fn start_ldk(config: LdkConfig) {
             "Chain argument ({}) didn't match bitcoind chain ({})",
             config.bitcoin_network.0, bitcoind_chain
         );
-        return;
+        return None;
     }
 
     // Step 2: Initialize the FeeEstimator
@@ -671,7 +693,7 @@ pub async fn start_ldk(config: LdkConfig) {
                     "ERROR: Unable to create keys seed file {:?}: {}",
                     keys_seed_path, e
                 );
-                return;
+                return None;
             }
         }
         key
@@ -996,6 +1018,8 @@ pub async fn start_ldk(config: LdkConfig) {
     let outbound_payments_event_listener = Arc::clone(&outbound_payments);
     let fs_store_event_listener = Arc::clone(&fs_store);
     let peer_manager_event_listener = Arc::clone(&peer_manager);
+    let backend_events = Arc::new(LdkBackendEvents::new());
+    let backend_events_listener = Arc::clone(&backend_events);
     let network = config.bitcoin_network.0;
     let event_handler = move |event: Event| {
         let channel_manager_event_listener = Arc::clone(&channel_manager_event_listener);
@@ -1007,6 +1031,7 @@ pub async fn start_ldk(config: LdkConfig) {
         let outbound_payments_event_listener = Arc::clone(&outbound_payments_event_listener);
         let fs_store_event_listener = Arc::clone(&fs_store_event_listener);
         let peer_manager_event_listener = Arc::clone(&peer_manager_event_listener);
+        let backend_events_listener = Arc::clone(&backend_events_listener);
         async move {
             handle_ldk_events(
                 channel_manager_event_listener,
@@ -1018,6 +1043,7 @@ pub async fn start_ldk(config: LdkConfig) {
                 inbound_payments_event_listener,
                 outbound_payments_event_listener,
                 fs_store_event_listener,
+                backend_events_listener,
                 network,
                 event,
             )
@@ -1137,6 +1163,16 @@ pub async fn start_ldk(config: LdkConfig) {
         Arc::clone(&channel_manager),
     ));
 
+    let backend = LdkBackend {
+        channel_manager: Arc::clone(&channel_manager),
+        keys_manager: Arc::clone(&keys_manager),
+        logger: Arc::clone(&logger),
+        network,
+        outbound_payments: Arc::clone(&outbound_payments),
+        fs_store: Arc::clone(&fs_store),
+        events: backend_events,
+    };
+
     // Start the CLI.
     let cli_channel_manager = Arc::clone(&channel_manager);
     let cli_persister = Arc::clone(&persister);
@@ -1158,44 +1194,48 @@ pub async fn start_ldk(config: LdkConfig) {
         )
     });
 
-    // Exit if either CLI polling exits or the background processor exits (which shouldn't happen
-    // unless we fail to write to the filesystem).
-    let mut bg_res = Ok(Ok(()));
-    tokio::select! {
-        _ = cli_poll => {},
-        bg_exit = &mut background_processor => {
-            bg_res = bg_exit;
-        },
-    }
+    tokio::spawn(async move {
+        // Exit if either CLI polling exits or the background processor exits (which shouldn't happen
+        // unless we fail to write to the filesystem).
+        let mut bg_res = Ok(Ok(()));
+        tokio::select! {
+            _ = cli_poll => {},
+            bg_exit = &mut background_processor => {
+                bg_res = bg_exit;
+            },
+        }
 
-    // Disconnect our peers and stop accepting new connections. This ensures we don't continue
-    // updating our channel data after we've stopped the background processor.
-    stop_listen_connect.store(true, Ordering::Release);
-    peer_manager.disconnect_all_peers();
-
-    if let Err(e) = bg_res {
-        let persist_res = persister.write(
-            persist::CHANNEL_MANAGER_PERSISTENCE_PRIMARY_NAMESPACE,
-            persist::CHANNEL_MANAGER_PERSISTENCE_SECONDARY_NAMESPACE,
-            persist::CHANNEL_MANAGER_PERSISTENCE_KEY,
-            &channel_manager.encode(),
-        );
-        use lightning::util::logger::Logger;
-        lightning::log_error!(
-            &*logger,
-            "Last-ditch ChannelManager persistence result: {:?}",
-            persist_res
-        );
-        panic!(
-            "ERR: background processing stopped with result {:?}, exiting.\n\
+        // Disconnect our peers and stop accepting new connections. This ensures we don't continue
+        // updating our channel data after we've stopped the background processor.
+        stop_listen_connect.store(true, Ordering::Release);
+        peer_manager.disconnect_all_peers();
+
+        if let Err(e) = bg_res {
+            let persist_res = persister.write(
+                persist::CHANNEL_MANAGER_PERSISTENCE_PRIMARY_NAMESPACE,
+                persist::CHANNEL_MANAGER_PERSISTENCE_SECONDARY_NAMESPACE,
+                persist::CHANNEL_MANAGER_PERSISTENCE_KEY,
+                &channel_manager.encode(),
+            );
+            use lightning::util::logger::Logger;
+            lightning::log_error!(
+                &*logger,
+                "Last-ditch ChannelManager persistence result: {:?}",
+                persist_res
+            );
+            panic!(
+                "ERR: background processing stopped with result {:?}, exiting.\n\
 			Last-ditch ChannelManager persistence result {:?}",
-            e, persist_res
-        );
-    }
+                e, persist_res
+            );
+        }
 
-    // Stop the background processor.
-    if !bp_exit.is_closed() {
-        bp_exit.send(()).unwrap();
-        background_processor.await.unwrap().unwrap();
-    }
+        // Stop the background processor.
+        if !bp_exit.is_closed() {
+            bp_exit.send(()).unwrap();
+            background_processor.await.unwrap().unwrap();
+        }
+    });
+
+    Some(backend)
 }
@@ -3,7 +3,7 @@ pub use config::Config;
 
 pub mod ckb;
 pub mod ldk;
-pub use ldk::{start_ldk, LdkConfig};
+pub use ldk::{start_ldk, LdkBackend, LdkConfig};
 pub mod fiber;
 pub use fiber::{start_network, FiberConfig, NetworkServiceEvent};
 pub mod cch;
@@ -13,6 +13,7 @@ pub mod rpc;
 pub use rpc::{start_rpc, RpcConfig};
 pub mod invoice;
 pub mod store;
+pub mod sweeper;
 pub mod watchtower;
 
 mod errors;
@@ -2,8 +2,14 @@ use ckb_hash::blake2b_256;
 use fnn::cch::CchMessage;
 use fnn::ckb::contracts::{get_script_by_contract, init_contracts_context, Contract};
 use fnn::fiber::graph::NetworkGraph;
+use fnn::fiber::network::init_chain_hash;
+use fnn::fiber::types::Privkey;
 use fnn::store::Store;
-use fnn::watchtower::{WatchtowerActor, WatchtowerMessage};
+use fnn::sweeper::{SweeperActor, SweeperMessage};
+use fnn::watchtower::{
+    TowerActor, TowerClientActor, TowerClientMessage, TowerMessage, WatchtowerActor,
+    WatchtowerMessage,
+};
 use ractor::Actor;
 use secp256k1::Secp256k1;
 use tentacle::multiaddr::Multiaddr;
@@ -22,7 +28,7 @@ use fnn::fiber::{channel::ChannelSubscribers, NetworkActorCommand, NetworkActorM
 use fnn::tasks::{
     cancel_tasks_and_wait_for_completion, new_tokio_cancellation_token, new_tokio_task_tracker,
 };
-use fnn::{start_cch, start_ldk, start_network, start_rpc, Config};
+use fnn::{start_cch, start_ldk, start_network, start_rpc, Config, NetworkServiceEvent};
 use std::sync::Arc;
 use std::time::Duration;
 use tracing_subscriber::fmt::format;
@@ -60,28 +66,46 @@ pub async fn main() {
     let config = Config::parse();
     debug!("Parsed config: {:?}", &config);
 
-    if let Some(ldk_config) = config.ldk {
-        info!("Starting ldk");
-        start_ldk(ldk_config).await;
-    }
+    let ldk_backend = match config.ldk {
+        Some(ldk_config) => {
+            info!("Starting ldk");
+            start_ldk(ldk_config).await
+        }
+        None => None,
+    };
 
     let tracker = new_tokio_task_tracker();
     let token = new_tokio_cancellation_token();
     let root_actor = RootActor::start(tracker, token).await;
 
-    let store = Store::new(config.fiber.as_ref().unwrap().store_path());
+    let store = Store::new(
+        config
+            .fiber
+            .as_ref()
+            .map(|c| c.store_path())
+            .or_else(|| config.watchtower.as_ref().map(|c| c.store_path()))
+            .expect("fiber or watchtower service is required"),
+    );
     let subscribers = ChannelSubscribers::default();
 
-    let (fiber_command_sender, network_graph, public_key) = match config.fiber {
+    let (fiber_command_sender, ckb_actor, network_graph, public_key) = match config.fiber {
         Some(fiber_config) => {
             // TODO: this is not a super user friendly error message which has actionable information
             // for the user to fix the error and start the node.
-            let ckb_config = config.ckb.expect("ckb service is required for ckb service. \
+            let ckb_config = config.ckb.clone().expect("ckb service is required for ckb service. \
             Add ckb service to the services list in the config file and relevant configuration to the ckb section of the config file.");
             let node_public_key = fiber_config.public_key();
 
             let _ = init_contracts_context(fiber_config.network, Some(&ckb_config));
 
+            // Never talk to peers running on a different chain.
+            init_chain_hash(
+                fiber_config
+                    .network
+                    .map(|network| network.chain_hash())
+                    .unwrap_or_default(),
+            );
+
             let ckb_actor = Actor::spawn_linked(
                 Some("ckb".to_string()),
                 CkbChainActor {},
@@ -96,6 +120,15 @@ pub async fn main() {
             let (event_sender, mut event_receiver) = mpsc::channel(CHANNEL_SIZE);
 
             let bootnodes = fiber_config.bootnode_addrs.clone();
+            let watchtower_urls = fiber_config.watchtower_urls.clone();
+            let node_privkey: Privkey = <[u8; 32]>::try_from(
+                fiber_config
+                    .read_or_generate_secret_key()
+                    .expect("read or generate secret key")
+                    .as_ref(),
+            )
+            .expect("valid length for key")
+            .into();
 
             let network_graph = Arc::new(RwLock::new(NetworkGraph::new(
                 store.clone(),
@@ -111,7 +144,7 @@ pub async fn main() {
             info!("Starting fiber");
             let network_actor = start_network(
                 fiber_config,
-                ckb_actor,
+                ckb_actor.clone(),
                 event_sender,
                 new_tokio_task_tracker(),
                 root_actor.get_cell(),
@@ -132,8 +165,8 @@ pub async fn main() {
 
             let watchtower_actor = Actor::spawn_linked(
                 Some("watchtower".to_string()),
-                WatchtowerActor::new(store.clone()),
-                ckb_config,
+                WatchtowerActor::new(store.clone(), ckb_actor.clone()),
+                (),
                 root_actor.get_cell(),
             )
             .await
@@ -145,6 +178,39 @@ pub async fn main() {
             watchtower_actor
                 .send_interval(Duration::from_secs(60), || WatchtowerMessage::PeriodicCheck);
 
+            let sweeper_actor = Actor::spawn_linked(
+                Some("sweeper".to_string()),
+                SweeperActor::new(store.clone(), ckb_actor.clone()),
+                ckb_config.sweep_fee_rate(),
+                root_actor.get_cell(),
+            )
+            .await
+            .expect("start sweeper actor")
+            .0;
+
+            // every 60 seconds, try to claim our funds from the commitment cells of force closed channels
+            sweeper_actor.send_interval(Duration::from_secs(60), || SweeperMessage::PeriodicCheck);
+
+            let tower_client_actor = if watchtower_urls.is_empty() {
+                None
+            } else {
+                info!(
+                    "Uploading revocation data to watchtowers: {:?}",
+                    watchtower_urls
+                );
+                Some(
+                    Actor::spawn_linked(
+                        Some("tower client".to_string()),
+                        TowerClientActor::new(store.clone()),
+                        (watchtower_urls, node_privkey),
+                        root_actor.get_cell(),
+                    )
+                    .await
+                    .expect("start tower client actor")
+                    .0,
+                )
+            };
+
             new_tokio_task_tracker().spawn(async move {
                 let token = new_tokio_cancellation_token();
                 loop {
@@ -155,7 +221,15 @@ pub async fn main() {
                                     trace!("Event receiver completed, stopping event processing service");
                                     break;
                                 }
+                                Some(event @ (NetworkServiceEvent::CommitmentTransactionConfirmed(..) | NetworkServiceEvent::TlcPreimageReceived(..))) => {
+                                    let _ = sweeper_actor.send_message(SweeperMessage::NetworkServiceEvent(event));
+                                }
                                 Some(event) => {
+                                    if let Some(tower_client_actor) = &tower_client_actor {
+                                        if let Some(message) = TowerClientMessage::from_event(&event) {
+                                            let _ = tower_client_actor.send_message(message);
+                                        }
+                                    }
                                     let _ = watchtower_actor.send_message(WatchtowerMessage::NetworkServiceEvent(event));
                                 }
                             }
@@ -171,11 +245,52 @@ pub async fn main() {
 
             (
                 Some(network_actor),
+                Some(ckb_actor),
                 Some(network_graph),
                 Some(node_public_key),
             )
         }
-        None => (None, None, None),
+        None => (None, None, None, None),
+    };
+
+    let tower_actor = match config.watchtower {
+        Some(watchtower_config) => {
+            let ckb_config = config.ckb.clone().expect("ckb service is required for watchtower service. \
+            Add ckb service to the services list in the config file and relevant configuration to the ckb section of the config file.");
+            let _ = init_contracts_context(watchtower_config.network, Some(&ckb_config));
+            // The tower funds the fee of the revocation transactions with the ckb wallet.
+            let ckb_actor = match ckb_actor.clone() {
+                Some(ckb_actor) => ckb_actor,
+                None => {
+                    Actor::spawn_linked(
+                        Some("ckb".to_string()),
+                        CkbChainActor {},
+                        ckb_config,
+                        root_actor.get_cell(),
+                    )
+                    .await
+                    .expect("start ckb actor")
+                    .0
+                }
+            };
+
+            info!("Starting watchtower");
+            let tower_actor = Actor::spawn_linked(
+                Some("tower".to_string()),
+                TowerActor::new(store.clone(), ckb_actor),
+                (),
+                root_actor.get_cell(),
+            )
+            .await
+            .expect("start tower actor")
+            .0;
+            tower_actor.send_interval(
+                Duration::from_secs(watchtower_config.check_interval_seconds()),
+                || TowerMessage::PeriodicCheck,
+            );
+            Some(tower_actor)
+        }
+        None => None,
     };
 
     let cch_actor = match config.cch {
@@ -188,6 +303,9 @@ pub async fn main() {
                 new_tokio_cancellation_token(),
                 root_actor.get_cell(),
                 fiber_command_sender.clone(),
+                store.clone(),
+                subscribers.node_events.clone(),
+                ldk_backend,
             )
             .await
             {
@@ -201,7 +319,7 @@ pub async fn main() {
                     }
                 }
                 Ok(actor) => {
-                    subscribers.pending_received_tlcs_subscribers.subscribe(
+                    subscribers.subscribe_pending_received_tlcs(
                         actor.clone(),
                         |tlc_notification| {
                             Some(CchMessage::PendingReceivedTlcNotification(tlc_notification))
@@ -224,8 +342,10 @@ pub async fn main() {
     // Start rpc service
     let rpc_server_handle = match config.rpc {
         Some(config) => {
-            if fiber_command_sender.is_none() && cch_actor.is_none() {
-                error!("Rpc service requires ckb and cch service to be started. Exiting.");
+            if fiber_command_sender.is_none() && cch_actor.is_none() && tower_actor.is_none() {
+                error!(
+                    "Rpc service requires ckb, cch or watchtower service to be started. Exiting."
+                );
                 return;
             }
 
@@ -234,9 +354,12 @@ pub async fn main() {
                 config,
                 fiber_command_sender,
                 cch_actor,
+                ckb_actor,
+                tower_actor,
                 store,
-                network_graph.unwrap(),
+                network_graph,
                 public_key,
+                subscribers.node_events.clone(),
             )
             .await;
             Some(handle)
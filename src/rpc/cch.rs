@@ -1,5 +1,8 @@
 use crate::{
-    cch::{CchMessage, CchOrderStatus, ReceiveBTCOrder},
+    cch::{
+        CchMessage, CchOrderStatus, CchOrders, ReceiveBTCOrder, SendBTCOrder, SwapDirection,
+        SwapQuote,
+    },
     fiber::{
         serde_utils::{U128Hex, U64Hex},
         types::Hash256,
@@ -19,6 +22,8 @@ use serde_with::serde_as;
 pub struct SendBtcParams {
     pub btc_pay_req: String,
     pub currency: Currency,
+    /// Charge the fee of the quote returned by `quote_swap`.
+    pub quote_id: Option<Hash256>,
 }
 
 #[serde_as]
@@ -40,6 +45,9 @@ pub struct SendBTCResponse {
     pub btc_pay_req: String,
     pub ckb_pay_req: String,
     pub payment_hash: String,
+    pub channel_id: Option<Hash256>,
+    #[serde_as(as = "Option<U64Hex>")]
+    pub tlc_id: Option<u64>,
 
     #[serde_as(as = "U128Hex")]
     // Amount required to pay in Satoshis, including fee
@@ -62,6 +70,39 @@ pub struct ReceiveBtcParams {
     /// Expiry set for the HTLC for the CKB payment to the payee.
     #[serde_as(as = "U64Hex")]
     pub final_tlc_expiry: u64,
+    /// Charge the fee of the quote returned by `quote_swap`.
+    pub quote_id: Option<Hash256>,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+pub struct QuoteSwapParams {
+    pub direction: SwapDirection,
+    /// The BTC invoice amount for `send_btc`, or the `amount_sats` of `receive_btc`.
+    #[serde_as(as = "U128Hex")]
+    pub amount_sats: u128,
+    /// The channel to pay the CKB payee for `receive_btc`.
+    pub channel_id: Option<Hash256>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuoteSwapResult {
+    pub quote_id: Hash256,
+    pub direction: SwapDirection,
+    #[serde_as(as = "U128Hex")]
+    pub amount_sats: u128,
+    #[serde_as(as = "U128Hex")]
+    pub fee_sats: u128,
+    // Seconds since epoch when the quote expires
+    #[serde_as(as = "U64Hex")]
+    pub expires_at: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct GetSendBtcOrderParams {
+    /// Payment hash for the HTLC for both CKB and BTC.
+    pub payment_hash: String,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -100,6 +141,18 @@ pub struct ReceiveBTCResponse {
     pub status: CchOrderStatus,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct ListCchOrdersParams {
+    /// Only list the orders in this status, all orders are listed if it's not set.
+    pub status: Option<CchOrderStatus>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ListCchOrdersResult {
+    pub send_btc_orders: Vec<SendBTCResponse>,
+    pub receive_btc_orders: Vec<ReceiveBTCResponse>,
+}
+
 #[rpc(server)]
 pub trait CchRpc {
     #[method(name = "send_btc")]
@@ -111,11 +164,29 @@ pub trait CchRpc {
         params: ReceiveBtcParams,
     ) -> Result<ReceiveBTCResponse, ErrorObjectOwned>;
 
+    #[method(name = "quote_swap")]
+    async fn quote_swap(
+        &self,
+        params: QuoteSwapParams,
+    ) -> Result<QuoteSwapResult, ErrorObjectOwned>;
+
+    #[method(name = "get_send_btc_order")]
+    async fn get_send_btc_order(
+        &self,
+        params: GetSendBtcOrderParams,
+    ) -> Result<SendBTCResponse, ErrorObjectOwned>;
+
     #[method(name = "get_receive_btc_order")]
     async fn get_receive_btc_order(
         &self,
         params: GetReceiveBtcOrderParams,
     ) -> Result<ReceiveBTCResponse, ErrorObjectOwned>;
+
+    #[method(name = "list_cch_orders")]
+    async fn list_cch_orders(
+        &self,
+        params: ListCchOrdersParams,
+    ) -> Result<ListCchOrdersResult, ErrorObjectOwned>;
 }
 
 pub struct CchRpcServerImpl {
@@ -140,6 +211,7 @@ impl CchRpcServer for CchRpcServerImpl {
             crate::cch::SendBTC {
                 btc_pay_req: params.btc_pay_req,
                 currency: params.currency,
+                quote_id: params.quote_id,
             }
         )
         .map_err(|ractor_error| {
@@ -150,21 +222,7 @@ impl CchRpcServer for CchRpcServerImpl {
             )
         })?;
 
-        result
-            .map(|order| SendBTCResponse {
-                timestamp: order.created_at,
-                expiry: order.expires_after,
-                ckb_final_tlc_expiry: order.ckb_final_tlc_expiry,
-                currency: order.currency,
-                wrapped_btc_type_script: order.wrapped_btc_type_script,
-                btc_pay_req: order.btc_pay_req,
-                ckb_pay_req: order.ckb_pay_req,
-                payment_hash: order.payment_hash,
-                amount_sats: order.amount_sats,
-                fee_sats: order.fee_sats,
-                status: order.status,
-            })
-            .map_err(Into::into)
+        result.map(Into::into).map_err(Into::into)
     }
 
     async fn receive_btc(
@@ -180,6 +238,32 @@ impl CchRpcServer for CchRpcServerImpl {
                 channel_id: params.channel_id,
                 amount_sats: params.amount_sats,
                 final_tlc_expiry: params.final_tlc_expiry,
+                quote_id: params.quote_id,
+            }
+        )
+        .map_err(|ractor_error| {
+            ErrorObjectOwned::owned(
+                CALL_EXECUTION_FAILED_CODE,
+                ractor_error.to_string(),
+                Option::<()>::None,
+            )
+        })?;
+
+        result.map(Into::into).map_err(Into::into)
+    }
+
+    async fn quote_swap(
+        &self,
+        params: QuoteSwapParams,
+    ) -> Result<QuoteSwapResult, ErrorObjectOwned> {
+        let result = call_t!(
+            self.cch_actor,
+            CchMessage::QuoteSwap,
+            TIMEOUT,
+            crate::cch::QuoteSwap {
+                direction: params.direction,
+                amount_sats: params.amount_sats,
+                channel_id: params.channel_id,
             }
         )
         .map_err(|ractor_error| {
@@ -193,6 +277,27 @@ impl CchRpcServer for CchRpcServerImpl {
         result.map(Into::into).map_err(Into::into)
     }
 
+    async fn get_send_btc_order(
+        &self,
+        params: GetSendBtcOrderParams,
+    ) -> Result<SendBTCResponse, ErrorObjectOwned> {
+        let result = call_t!(
+            self.cch_actor,
+            CchMessage::GetSendBTCOrder,
+            TIMEOUT,
+            params.payment_hash
+        )
+        .map_err(|ractor_error| {
+            ErrorObjectOwned::owned(
+                CALL_EXECUTION_FAILED_CODE,
+                ractor_error.to_string(),
+                Option::<()>::None,
+            )
+        })?;
+
+        result.map(Into::into).map_err(Into::into)
+    }
+
     async fn get_receive_btc_order(
         &self,
         params: GetReceiveBtcOrderParams,
@@ -213,6 +318,54 @@ impl CchRpcServer for CchRpcServerImpl {
 
         result.map(Into::into).map_err(Into::into)
     }
+
+    async fn list_cch_orders(
+        &self,
+        params: ListCchOrdersParams,
+    ) -> Result<ListCchOrdersResult, ErrorObjectOwned> {
+        let orders: CchOrders = call_t!(
+            self.cch_actor,
+            CchMessage::ListOrders,
+            TIMEOUT,
+            params.status
+        )
+        .map_err(|ractor_error| {
+            ErrorObjectOwned::owned(
+                CALL_EXECUTION_FAILED_CODE,
+                ractor_error.to_string(),
+                Option::<()>::None,
+            )
+        })?;
+
+        Ok(ListCchOrdersResult {
+            send_btc_orders: orders.send_btc_orders.into_iter().map(Into::into).collect(),
+            receive_btc_orders: orders
+                .receive_btc_orders
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
+}
+
+impl From<SendBTCOrder> for SendBTCResponse {
+    fn from(value: SendBTCOrder) -> Self {
+        Self {
+            timestamp: value.created_at,
+            expiry: value.expires_after,
+            ckb_final_tlc_expiry: value.ckb_final_tlc_expiry,
+            currency: value.currency,
+            wrapped_btc_type_script: value.wrapped_btc_type_script,
+            btc_pay_req: value.btc_pay_req,
+            ckb_pay_req: value.ckb_pay_req,
+            payment_hash: value.payment_hash,
+            channel_id: value.channel_id,
+            tlc_id: value.tlc_id,
+            amount_sats: value.amount_sats,
+            fee_sats: value.fee_sats,
+            status: value.status,
+        }
+    }
 }
 
 impl From<ReceiveBTCOrder> for ReceiveBTCResponse {
@@ -232,3 +385,15 @@ impl From<ReceiveBTCOrder> for ReceiveBTCResponse {
         }
     }
 }
+
+impl From<SwapQuote> for QuoteSwapResult {
+    fn from(value: SwapQuote) -> Self {
+        Self {
+            quote_id: value.quote_id,
+            direction: value.direction,
+            amount_sats: value.amount_sats,
+            fee_sats: value.fee_sats,
+            expires_at: value.expires_at,
+        }
+    }
+}
@@ -1,17 +1,23 @@
 use crate::fiber::{
     channel::{
-        AddTlcCommand, ChannelActorStateStore, ChannelCommand, ChannelCommandWithId, ChannelState,
-        RemoveTlcCommand, ShutdownCommand, UpdateCommand,
+        AddTlcCommand, BumpFundingFeeCommand, ChannelActorState, ChannelActorStateStore,
+        ChannelCommand, ChannelCommandWithId, ChannelState, RemoveTlcCommand, ShutdownCommand,
+        SpliceCommand, UpdateCommand, DEFAULT_FEE_RATE,
+    },
+    graph::{
+        NetworkGraphStateStore, PaymentPart, PaymentPartStatus, PaymentSession,
+        PaymentSessionStatus,
     },
     hash_algorithm::HashAlgorithm,
-    network::{AcceptChannelCommand, OpenChannelCommand, SendPaymentCommand},
-    serde_utils::{U128Hex, U32Hex, U64Hex},
-    types::{Hash256, LockTime, Pubkey, RemoveTlcFail, RemoveTlcFulfill},
+    network::{AcceptChannelCommand, BuildRouteCommand, OpenChannelCommand, SendPaymentCommand},
+    serde_utils::{EntityHex, U128Hex, U32Hex, U64Hex},
+    types::{Hash256, LockTime, PaymentHopData, Pubkey, RemoveTlcFail, RemoveTlcFulfill, TlcErr},
     NetworkActorCommand, NetworkActorMessage,
 };
 use crate::{handle_actor_call, handle_actor_cast, log_and_error};
 use ckb_jsonrpc_types::Script;
 use ckb_types::core::FeeRate;
+use ckb_types::packed::OutPoint;
 use jsonrpsee::{
     core::async_trait,
     proc_macros::rpc,
@@ -108,6 +114,22 @@ pub struct Channel {
     pub created_at: u64,
 }
 
+impl From<&ChannelActorState> for Channel {
+    fn from(state: &ChannelActorState) -> Self {
+        Channel {
+            channel_id: state.get_id(),
+            peer_id: state.get_remote_peer_id(),
+            funding_udt_type_script: state.funding_udt_type_script.clone().map(Into::into),
+            state: state.state,
+            local_balance: state.get_local_balance(),
+            remote_balance: state.get_remote_balance(),
+            offered_tlc_balance: state.get_offered_tlc_balance(),
+            received_tlc_balance: state.get_received_tlc_balance(),
+            created_at: state.get_created_at_in_microseconds(),
+        }
+    }
+}
+
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug)]
 pub struct AddTlcParams {
@@ -172,6 +194,25 @@ pub struct UpdateChannelParams {
     pub tlc_fee_proportional_millionths: Option<u128>,
 }
 
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BumpFundingFeeParams {
+    pub channel_id: Hash256,
+    #[serde_as(as = "U64Hex")]
+    pub funding_fee_rate: u64,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SpliceChannelParams {
+    pub channel_id: Hash256,
+    // Our balance in the channel after the splice, funds are added from or withdrawn to our wallet
+    #[serde_as(as = "U128Hex")]
+    pub funding_amount: u128,
+    #[serde_as(as = "Option<U64Hex>")]
+    pub funding_fee_rate: Option<u64>,
+}
+
 #[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SendPaymentCommandParams {
@@ -216,6 +257,203 @@ pub struct SendPaymentCommandParams {
 pub struct SendPaymentResult {
     pub payment_hash: Hash256,
 }
+
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BuildRouteParams {
+    // the identifier of the route target
+    pub target_pubkey: Pubkey,
+
+    // the amount to be received by the target
+    #[serde_as(as = "U128Hex")]
+    pub amount: u128,
+
+    // udt type script for the payment
+    pub udt_type_script: Option<Script>,
+
+    // the maximum fee amounts in shannons that the sender is willing to pay
+    #[serde_as(as = "Option<U128Hex>")]
+    pub max_fee_amount: Option<u128>,
+
+    // the maximum number of candidate routes to return, default is 1
+    #[serde_as(as = "Option<U64Hex>")]
+    pub max_routes: Option<u64>,
+
+    // send a tlc with a random payment hash along the best route to check its liquidity
+    pub probe: Option<bool>,
+}
+
+#[serde_as]
+#[derive(Clone, Serialize)]
+pub struct RouteHopResult {
+    // the node which receives the tlc of this hop
+    pub target: Option<Pubkey>,
+    #[serde_as(as = "Option<EntityHex>")]
+    pub channel_outpoint: Option<OutPoint>,
+    // the amount of the tlc sent through this hop, including the fees of the following hops
+    #[serde_as(as = "U128Hex")]
+    pub amount: u128,
+    // the fee charged by the target for forwarding the tlc, 0 for the route target
+    #[serde_as(as = "U128Hex")]
+    pub fee: u128,
+    // the expiry delta required by the target for forwarding the tlc, 0 for the route target
+    #[serde_as(as = "U64Hex")]
+    pub expiry_delta: u64,
+}
+
+#[serde_as]
+#[derive(Clone, Serialize)]
+pub struct RouteResult {
+    pub hops: Vec<RouteHopResult>,
+    // the amount to be sent by us, including the total fee
+    #[serde_as(as = "U128Hex")]
+    pub total_amount: u128,
+    #[serde_as(as = "U128Hex")]
+    pub total_fee: u128,
+    #[serde_as(as = "U64Hex")]
+    pub total_expiry: u64,
+}
+
+impl From<&[PaymentHopData]> for RouteResult {
+    fn from(route: &[PaymentHopData]) -> Self {
+        // The last element of the route is the instruction for the target node, the fee and
+        // expiry delta of a hop are the differences between its tlc and the next one.
+        let hops = route
+            .windows(2)
+            .map(|hops| RouteHopResult {
+                target: hops[0].next_hop,
+                channel_outpoint: hops[0].channel_outpoint.clone(),
+                amount: hops[0].amount,
+                fee: hops[0].amount - hops[1].amount,
+                expiry_delta: hops[0].expiry - hops[1].expiry,
+            })
+            .collect();
+        let (first, last) = (&route[0], &route[route.len() - 1]);
+        RouteResult {
+            hops,
+            total_amount: first.amount,
+            total_fee: first.amount - last.amount,
+            total_expiry: first.expiry,
+        }
+    }
+}
+
+#[derive(Clone, Serialize)]
+pub struct BuildRouteResult {
+    pub routes: Vec<RouteResult>,
+    // the payment hash of the probe payment, its result can be queried by `get_payment`
+    pub probe_payment_hash: Option<Hash256>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetPaymentCommandParams {
+    pub payment_hash: Hash256,
+}
+
+#[serde_as]
+#[derive(Clone, Serialize)]
+pub struct PaymentRouteHop {
+    // the node which receives the tlc of this hop
+    pub target: Option<Pubkey>,
+    #[serde_as(as = "Option<EntityHex>")]
+    pub channel_outpoint: Option<OutPoint>,
+    // the amount of the tlc sent through this hop, including the fees of the following hops
+    #[serde_as(as = "U128Hex")]
+    pub amount: u128,
+    #[serde_as(as = "U64Hex")]
+    pub expiry: u64,
+}
+
+#[serde_as]
+#[derive(Clone, Serialize)]
+pub struct PaymentPartResult {
+    // the amount received by the target through this part
+    #[serde_as(as = "U128Hex")]
+    pub amount: u128,
+    pub status: PaymentPartStatus,
+    pub route: Vec<PaymentRouteHop>,
+}
+
+impl From<&PaymentPart> for PaymentPartResult {
+    fn from(part: &PaymentPart) -> Self {
+        // The last element of the route is the instruction for the target node, which is
+        // not a channel hop.
+        let route = part
+            .route
+            .iter()
+            .filter(|hop| hop.channel_outpoint.is_some())
+            .map(|hop| PaymentRouteHop {
+                target: hop.next_hop,
+                channel_outpoint: hop.channel_outpoint.clone(),
+                amount: hop.amount,
+                expiry: hop.expiry,
+            })
+            .collect();
+        PaymentPartResult {
+            amount: part.amount,
+            status: part.status,
+            route,
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Clone, Serialize)]
+pub struct GetPaymentCommandResult {
+    pub payment_hash: Hash256,
+    pub status: PaymentSessionStatus,
+    #[serde_as(as = "U128Hex")]
+    pub amount: u128,
+    #[serde_as(as = "U128Hex")]
+    pub fee: u128,
+    pub parts: Vec<PaymentPartResult>,
+    pub preimage: Option<Hash256>,
+    pub failed_error: Option<String>,
+    #[serde_as(as = "U32Hex")]
+    pub retried_times: u32,
+    #[serde_as(as = "U64Hex")]
+    pub created_at: u64,
+    #[serde_as(as = "U64Hex")]
+    pub last_updated_at: u64,
+}
+
+impl From<PaymentSession> for GetPaymentCommandResult {
+    fn from(session: PaymentSession) -> Self {
+        let failed_error = match session.status {
+            PaymentSessionStatus::Success => None,
+            _ => session.last_error.clone(),
+        };
+        GetPaymentCommandResult {
+            payment_hash: session.payment_hash(),
+            status: session.status,
+            amount: session.request.amount,
+            fee: session.fee(),
+            parts: session.parts.iter().map(Into::into).collect(),
+            preimage: session.preimage,
+            failed_error,
+            retried_times: session.retried_times,
+            created_at: session.created_at,
+            last_updated_at: session.last_updated_at,
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ListPaymentsParams {
+    // only list payments with this status
+    pub status: Option<PaymentSessionStatus>,
+    #[serde_as(as = "Option<U64Hex>")]
+    pub limit: Option<u64>,
+    // the payment hash of the last payment in the previous page
+    pub after: Option<Hash256>,
+}
+
+#[derive(Clone, Serialize)]
+pub struct ListPaymentsResult {
+    pub payments: Vec<GetPaymentCommandResult>,
+    pub last_cursor: Option<Hash256>,
+}
 #[rpc(server)]
 pub trait ChannelRpc {
     #[method(name = "open_channel")]
@@ -255,11 +493,35 @@ pub trait ChannelRpc {
     #[method(name = "update_channel")]
     async fn update_channel(&self, params: UpdateChannelParams) -> Result<(), ErrorObjectOwned>;
 
+    #[method(name = "bump_funding_fee")]
+    async fn bump_funding_fee(&self, params: BumpFundingFeeParams) -> Result<(), ErrorObjectOwned>;
+
+    #[method(name = "splice_channel")]
+    async fn splice_channel(&self, params: SpliceChannelParams) -> Result<(), ErrorObjectOwned>;
+
     #[method(name = "send_payment")]
     async fn send_payment(
         &self,
         params: SendPaymentCommandParams,
     ) -> Result<SendPaymentResult, ErrorObjectOwned>;
+
+    #[method(name = "build_route")]
+    async fn build_route(
+        &self,
+        params: BuildRouteParams,
+    ) -> Result<BuildRouteResult, ErrorObjectOwned>;
+
+    #[method(name = "get_payment")]
+    async fn get_payment(
+        &self,
+        params: GetPaymentCommandParams,
+    ) -> Result<GetPaymentCommandResult, ErrorObjectOwned>;
+
+    #[method(name = "list_payments")]
+    async fn list_payments(
+        &self,
+        params: ListPaymentsParams,
+    ) -> Result<ListPaymentsResult, ErrorObjectOwned>;
 }
 
 pub struct ChannelRpcServerImpl<S> {
@@ -276,7 +538,7 @@ impl<S> ChannelRpcServerImpl<S> {
 #[async_trait]
 impl<S> ChannelRpcServer for ChannelRpcServerImpl<S>
 where
-    S: ChannelActorStateStore + Send + Sync + 'static,
+    S: ChannelActorStateStore + NetworkGraphStateStore + Send + Sync + 'static,
 {
     async fn open_channel(
         &self,
@@ -338,23 +600,10 @@ where
             .store
             .get_active_channel_states(params.peer_id)
             .into_iter()
-            .filter_map(|(peer_id, channel_id, _state)| {
+            .filter_map(|(_peer_id, channel_id, _state)| {
                 self.store
                     .get_channel_actor_state(&channel_id)
-                    .map(|state| Channel {
-                        channel_id,
-                        peer_id,
-                        funding_udt_type_script: state
-                            .funding_udt_type_script
-                            .clone()
-                            .map(Into::into),
-                        state: state.state,
-                        local_balance: state.get_local_balance(),
-                        remote_balance: state.get_remote_balance(),
-                        offered_tlc_balance: state.get_offered_tlc_balance(),
-                        received_tlc_balance: state.get_received_tlc_balance(),
-                        created_at: state.get_created_at_in_microseconds(),
-                    })
+                    .map(|state| Channel::from(&state))
             })
             .collect();
         // Sort by created_at in descending order
@@ -416,7 +665,7 @@ where
                                 }
                                 RemoveTlcReason::RemoveTlcFail { error_code } => {
                                     crate::fiber::types::RemoveTlcReason::RemoveTlcFail(
-                                        RemoveTlcFail { error_code },
+                                        RemoveTlcFail::new_plaintext(TlcErr::new(error_code)),
                                     )
                                 }
                             },
@@ -472,6 +721,41 @@ where
         handle_actor_call!(self.actor, message, params)
     }
 
+    async fn bump_funding_fee(&self, params: BumpFundingFeeParams) -> Result<(), ErrorObjectOwned> {
+        let message = |rpc_reply| -> NetworkActorMessage {
+            NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
+                ChannelCommandWithId {
+                    channel_id: params.channel_id,
+                    command: ChannelCommand::BumpFundingFee(
+                        BumpFundingFeeCommand {
+                            funding_fee_rate: params.funding_fee_rate,
+                        },
+                        rpc_reply,
+                    ),
+                },
+            ))
+        };
+        handle_actor_call!(self.actor, message, params)
+    }
+
+    async fn splice_channel(&self, params: SpliceChannelParams) -> Result<(), ErrorObjectOwned> {
+        let message = |rpc_reply| -> NetworkActorMessage {
+            NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
+                ChannelCommandWithId {
+                    channel_id: params.channel_id,
+                    command: ChannelCommand::Splice(
+                        SpliceCommand {
+                            funding_amount: params.funding_amount,
+                            funding_fee_rate: params.funding_fee_rate.unwrap_or(DEFAULT_FEE_RATE),
+                        },
+                        rpc_reply,
+                    ),
+                },
+            ))
+        };
+        handle_actor_call!(self.actor, message, params)
+    }
+
     async fn send_payment(
         &self,
         params: SendPaymentCommandParams,
@@ -497,4 +781,83 @@ where
             payment_hash: response.payment_hash,
         })
     }
+
+    async fn build_route(
+        &self,
+        params: BuildRouteParams,
+    ) -> Result<BuildRouteResult, ErrorObjectOwned> {
+        let message = |rpc_reply| -> NetworkActorMessage {
+            NetworkActorMessage::Command(NetworkActorCommand::BuildRoute(
+                BuildRouteCommand {
+                    target_pubkey: params.target_pubkey,
+                    amount: params.amount,
+                    udt_type_script: params.udt_type_script.clone().map(|s| s.into()),
+                    max_fee_amount: params.max_fee_amount,
+                    max_routes: params.max_routes,
+                    probe: params.probe.unwrap_or(false),
+                },
+                rpc_reply,
+            ))
+        };
+        handle_actor_call!(self.actor, message, params).map(|response| BuildRouteResult {
+            routes: response
+                .routes
+                .iter()
+                .map(|route| route.as_slice().into())
+                .collect(),
+            probe_payment_hash: response.probe_payment_hash,
+        })
+    }
+
+    async fn get_payment(
+        &self,
+        params: GetPaymentCommandParams,
+    ) -> Result<GetPaymentCommandResult, ErrorObjectOwned> {
+        match self.store.get_payment_session(params.payment_hash) {
+            Some(session) => Ok(session.into()),
+            None => log_and_error!(params, "Payment session not found".to_string()),
+        }
+    }
+
+    async fn list_payments(
+        &self,
+        params: ListPaymentsParams,
+    ) -> Result<ListPaymentsResult, ErrorObjectOwned> {
+        let default_max_limit = 500;
+        let limit = params.limit.unwrap_or(default_max_limit) as usize;
+        let mut sessions: Vec<_> = self
+            .store
+            .get_payment_sessions()
+            .into_iter()
+            .filter(|session| {
+                params
+                    .status
+                    .map_or(true, |status| session.status == status)
+            })
+            .collect();
+        // Sort by created_at in descending order, the payment hash is used to break ties
+        sessions.sort_by(|a, b| {
+            b.created_at
+                .cmp(&a.created_at)
+                .then_with(|| a.payment_hash().as_ref().cmp(b.payment_hash().as_ref()))
+        });
+        let start = match params.after {
+            Some(after) => sessions
+                .iter()
+                .position(|session| session.payment_hash() == after)
+                .map_or(sessions.len(), |index| index + 1),
+            None => 0,
+        };
+        let payments: Vec<GetPaymentCommandResult> = sessions
+            .into_iter()
+            .skip(start)
+            .take(limit)
+            .map(Into::into)
+            .collect();
+        let last_cursor = payments.last().map(|payment| payment.payment_hash);
+        Ok(ListPaymentsResult {
+            payments,
+            last_cursor,
+        })
+    }
 }
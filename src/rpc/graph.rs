@@ -69,6 +69,36 @@ pub struct GraphChannelsResult {
     pub last_cursor: JsonBytes,
 }
 
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PaymentHistoryParams {
+    // only return the results of this channel
+    #[serde_as(as = "Option<EntityHex>")]
+    channel_outpoint: Option<OutPoint>,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PaymentHistoryResult {
+    #[serde_as(as = "EntityHex")]
+    pub channel_outpoint: OutPoint,
+    // the node receiving the payments through the channel
+    pub target: Pubkey,
+    #[serde_as(as = "U128Hex")]
+    pub success_amount: u128,
+    #[serde_as(as = "U64Hex")]
+    pub success_time: u64,
+    #[serde_as(as = "U128Hex")]
+    pub fail_amount: u128,
+    #[serde_as(as = "U64Hex")]
+    pub fail_time: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PaymentHistoryResults {
+    pub results: Vec<PaymentHistoryResult>,
+}
+
 #[rpc(server)]
 pub trait GraphRpc {
     #[method(name = "graph_nodes")]
@@ -82,6 +112,18 @@ pub trait GraphRpc {
         &self,
         params: GraphChannelsParams,
     ) -> Result<GraphChannelsResult, ErrorObjectOwned>;
+
+    #[method(name = "payment_history")]
+    async fn payment_history(
+        &self,
+        params: PaymentHistoryParams,
+    ) -> Result<PaymentHistoryResults, ErrorObjectOwned>;
+
+    #[method(name = "reset_payment_history")]
+    async fn reset_payment_history(
+        &self,
+        params: PaymentHistoryParams,
+    ) -> Result<(), ErrorObjectOwned>;
 }
 
 pub struct GraphRpcServerImpl<S>
@@ -162,4 +204,42 @@ where
             last_cursor,
         })
     }
+
+    async fn payment_history(
+        &self,
+        params: PaymentHistoryParams,
+    ) -> Result<PaymentHistoryResults, ErrorObjectOwned> {
+        let network_graph = self.network_graph.read().await;
+        let results = network_graph
+            .get_payment_history()
+            .get_results()
+            .into_iter()
+            .filter(|(channel_outpoint, _, _)| {
+                params
+                    .channel_outpoint
+                    .as_ref()
+                    .map_or(true, |outpoint| outpoint == channel_outpoint)
+            })
+            .map(|(channel_outpoint, target, result)| PaymentHistoryResult {
+                channel_outpoint,
+                target,
+                success_amount: result.success_amount,
+                success_time: result.success_time,
+                fail_amount: result.fail_amount,
+                fail_time: result.fail_time,
+            })
+            .collect();
+        Ok(PaymentHistoryResults { results })
+    }
+
+    async fn reset_payment_history(
+        &self,
+        params: PaymentHistoryParams,
+    ) -> Result<(), ErrorObjectOwned> {
+        self.network_graph
+            .write()
+            .await
+            .reset_payment_history(params.channel_outpoint.as_ref());
+        Ok(())
+    }
 }
@@ -1,12 +1,18 @@
 use std::time::Duration;
 
 use crate::fiber::hash_algorithm::HashAlgorithm;
+use crate::fiber::network::{NetworkActorCommand, NetworkActorMessage};
 use crate::fiber::serde_utils::{U128Hex, U64Hex};
+use crate::fiber::subscription::NodeEventSubscribers;
 use crate::fiber::types::Hash256;
-use crate::invoice::{CkbInvoice, Currency, InvoiceBuilder, InvoiceStore};
+use crate::invoice::{
+    get_invoice_status_with_expiry, CkbInvoice, CkbInvoiceStatus, Currency, InvoiceBuilder,
+    InvoiceError, InvoiceStore, RouteHintHop,
+};
 use ckb_jsonrpc_types::Script;
 use jsonrpsee::types::error::CALL_EXECUTION_FAILED_CODE;
 use jsonrpsee::{core::async_trait, proc_macros::rpc, types::ErrorObjectOwned};
+use ractor::ActorRef;
 use secp256k1::PublicKey as Publickey;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
@@ -19,7 +25,10 @@ pub struct NewInvoiceParams {
     pub amount: u128,
     pub description: Option<String>,
     pub currency: Currency,
-    pub payment_preimage: Hash256,
+    pub payment_preimage: Option<Hash256>,
+    // Create a hold invoice with only the payment hash, the preimage is provided by
+    // `settle_invoice` after the payment is received.
+    pub payment_hash: Option<Hash256>,
     #[serde_as(as = "Option<U64Hex>")]
     pub expiry: Option<u64>,
     pub fallback_address: Option<String>,
@@ -29,6 +38,7 @@ pub struct NewInvoiceParams {
     pub final_htlc_timeout: Option<u64>,
     pub udt_type_script: Option<Script>,
     pub hash_algorithm: Option<HashAlgorithm>,
+    pub route_hints: Option<Vec<Vec<RouteHintHop>>>,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -47,6 +57,33 @@ pub struct ParseInvoiceResult {
     pub invoice: CkbInvoice,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct InvoiceParams {
+    pub payment_hash: Hash256,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SettleInvoiceParams {
+    pub payment_preimage: Hash256,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GetInvoiceResult {
+    pub invoice_address: String,
+    pub invoice: CkbInvoice,
+    pub status: CkbInvoiceStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ListInvoicesParams {
+    pub status: Option<CkbInvoiceStatus>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ListInvoicesResult {
+    pub invoices: Vec<GetInvoiceResult>,
+}
+
 #[rpc(server)]
 pub trait InvoiceRpc {
     #[method(name = "new_invoice")]
@@ -60,16 +97,90 @@ pub trait InvoiceRpc {
         &self,
         params: ParseInvoiceParams,
     ) -> Result<ParseInvoiceResult, ErrorObjectOwned>;
+
+    #[method(name = "get_invoice")]
+    async fn get_invoice(
+        &self,
+        params: InvoiceParams,
+    ) -> Result<GetInvoiceResult, ErrorObjectOwned>;
+
+    #[method(name = "list_invoices")]
+    async fn list_invoices(
+        &self,
+        params: ListInvoicesParams,
+    ) -> Result<ListInvoicesResult, ErrorObjectOwned>;
+
+    #[method(name = "cancel_invoice")]
+    async fn cancel_invoice(
+        &self,
+        params: InvoiceParams,
+    ) -> Result<GetInvoiceResult, ErrorObjectOwned>;
+
+    #[method(name = "settle_invoice")]
+    async fn settle_invoice(
+        &self,
+        params: SettleInvoiceParams,
+    ) -> Result<GetInvoiceResult, ErrorObjectOwned>;
 }
 
 pub struct InvoiceRpcServerImpl<S> {
     pub store: S,
+    pub network_actor: Option<ActorRef<NetworkActorMessage>>,
+    pub node_events: NodeEventSubscribers,
     pub public_key: Option<PublicKey>,
 }
 
 impl<S> InvoiceRpcServerImpl<S> {
-    pub fn new(store: S, public_key: Option<PublicKey>) -> Self {
-        Self { store, public_key }
+    pub fn new(
+        store: S,
+        network_actor: Option<ActorRef<NetworkActorMessage>>,
+        node_events: NodeEventSubscribers,
+        public_key: Option<PublicKey>,
+    ) -> Self {
+        Self {
+            store,
+            network_actor,
+            node_events,
+            public_key,
+        }
+    }
+
+    // Let the network actor fulfill or fail the tlcs held for a hold invoice.
+    fn resolve_held_payment(&self, payment_hash: Hash256) {
+        if let Some(network_actor) = &self.network_actor {
+            network_actor
+                .send_message(NetworkActorMessage::new_command(
+                    NetworkActorCommand::ResolveHeldPayment(payment_hash),
+                ))
+                .expect("network actor is alive");
+        }
+    }
+}
+
+impl<S> InvoiceRpcServerImpl<S>
+where
+    S: InvoiceStore,
+{
+    fn get_invoice_result(&self, payment_hash: &Hash256) -> Result<GetInvoiceResult, InvoiceError> {
+        match get_invoice_status_with_expiry(&self.store, payment_hash) {
+            Some(status) => get_invoice_result(&self.store, payment_hash, status),
+            None => Err(InvoiceError::InvoiceNotFound(payment_hash.to_string())),
+        }
+    }
+}
+
+pub(super) fn get_invoice_result<S: InvoiceStore>(
+    store: &S,
+    payment_hash: &Hash256,
+    status: CkbInvoiceStatus,
+) -> Result<GetInvoiceResult, InvoiceError> {
+    match store.get_invoice(payment_hash) {
+        Some(invoice) => Ok(GetInvoiceResult {
+            invoice_address: invoice.to_string(),
+            invoice,
+            status,
+        }),
+        None => Err(InvoiceError::InvoiceNotFound(payment_hash.to_string())),
     }
 }
 
@@ -82,9 +193,22 @@ where
         &self,
         params: NewInvoiceParams,
     ) -> Result<NewInvoiceResult, ErrorObjectOwned> {
-        let mut invoice_builder = InvoiceBuilder::new(params.currency)
-            .amount(Some(params.amount))
-            .payment_preimage(params.payment_preimage);
+        let mut invoice_builder = InvoiceBuilder::new(params.currency).amount(Some(params.amount));
+        match (params.payment_preimage, params.payment_hash) {
+            (Some(payment_preimage), None) => {
+                invoice_builder = invoice_builder.payment_preimage(payment_preimage);
+            }
+            (None, Some(payment_hash)) => {
+                invoice_builder = invoice_builder.payment_hash(payment_hash);
+            }
+            _ => {
+                return Err(ErrorObjectOwned::owned(
+                    CALL_EXECUTION_FAILED_CODE,
+                    "Exactly one of payment_preimage and payment_hash should be set",
+                    Some(params),
+                ));
+            }
+        }
         if let Some(description) = params.description.clone() {
             invoice_builder = invoice_builder.description(description);
         };
@@ -104,6 +228,9 @@ where
         if let Some(hash_algorithm) = params.hash_algorithm {
             invoice_builder = invoice_builder.hash_algorithm(hash_algorithm);
         };
+        for route_hint in params.route_hints.clone().unwrap_or_default() {
+            invoice_builder = invoice_builder.route_hint(route_hint);
+        }
 
         if let Some(public_key) = &self.public_key {
             invoice_builder = invoice_builder.payee_pub_key(
@@ -114,7 +241,7 @@ where
         match invoice_builder.build() {
             Ok(invoice) => match self
                 .store
-                .insert_invoice(invoice.clone(), Some(params.payment_preimage))
+                .insert_invoice(invoice.clone(), params.payment_preimage)
             {
                 Ok(_) => Ok(NewInvoiceResult {
                     invoice_address: invoice.to_string(),
@@ -150,4 +277,102 @@ where
             )),
         }
     }
+
+    async fn get_invoice(
+        &self,
+        params: InvoiceParams,
+    ) -> Result<GetInvoiceResult, ErrorObjectOwned> {
+        self.get_invoice_result(&params.payment_hash).map_err(|e| {
+            ErrorObjectOwned::owned(CALL_EXECUTION_FAILED_CODE, e.to_string(), Some(params))
+        })
+    }
+
+    async fn list_invoices(
+        &self,
+        params: ListInvoicesParams,
+    ) -> Result<ListInvoicesResult, ErrorObjectOwned> {
+        let invoices = self
+            .store
+            .get_invoices()
+            .iter()
+            .filter_map(|invoice| self.get_invoice_result(invoice.payment_hash()).ok())
+            .filter(|result| params.status.map_or(true, |status| result.status == status))
+            .collect();
+        Ok(ListInvoicesResult { invoices })
+    }
+
+    async fn cancel_invoice(
+        &self,
+        params: InvoiceParams,
+    ) -> Result<GetInvoiceResult, ErrorObjectOwned> {
+        let result = self
+            .get_invoice_result(&params.payment_hash)
+            .and_then(|mut result| match result.status {
+                // The received tlcs of the invoice are rejected when they are being settled.
+                CkbInvoiceStatus::Open | CkbInvoiceStatus::Received => {
+                    self.store
+                        .update_invoice_status(&params.payment_hash, CkbInvoiceStatus::Cancelled)?;
+                    result.status = CkbInvoiceStatus::Cancelled;
+                    self.node_events
+                        .notify_invoice(params.payment_hash, CkbInvoiceStatus::Cancelled);
+                    self.resolve_held_payment(params.payment_hash);
+                    Ok(result)
+                }
+                status => Err(InvoiceError::InvoiceNotCancellable(
+                    params.payment_hash.to_string(),
+                    status,
+                )),
+            });
+        result.map_err(|e| {
+            ErrorObjectOwned::owned(CALL_EXECUTION_FAILED_CODE, e.to_string(), Some(params))
+        })
+    }
+
+    async fn settle_invoice(
+        &self,
+        params: SettleInvoiceParams,
+    ) -> Result<GetInvoiceResult, ErrorObjectOwned> {
+        // The hash algorithm is specified by the invoice, try all the supported ones.
+        let payment_hash = HashAlgorithm::supported_algorithms()
+            .into_iter()
+            .map(|algorithm| {
+                (
+                    algorithm,
+                    algorithm.hash(params.payment_preimage.as_ref()).into(),
+                )
+            })
+            .find_map(|(algorithm, payment_hash): (HashAlgorithm, Hash256)| {
+                self.store.get_invoice(&payment_hash).and_then(|invoice| {
+                    (invoice.hash_algorithm().copied().unwrap_or_default() == algorithm)
+                        .then_some(payment_hash)
+                })
+            });
+        let result = match payment_hash {
+            Some(payment_hash) => {
+                self.get_invoice_result(&payment_hash)
+                    .and_then(|result| match result.status {
+                        CkbInvoiceStatus::Open | CkbInvoiceStatus::Received
+                            if self.store.get_invoice_preimage(&payment_hash).is_none() =>
+                        {
+                            self.store
+                                .insert_invoice_preimage(&payment_hash, params.payment_preimage)?;
+                            // The invoice is marked as paid once the held tlcs are fulfilled.
+                            self.resolve_held_payment(payment_hash);
+                            Ok(result)
+                        }
+                        status => Err(InvoiceError::InvoiceNotSettleable(
+                            payment_hash.to_string(),
+                            status,
+                        )),
+                    })
+            }
+            None => Err(InvoiceError::InvoiceNotFound(format!(
+                "preimage {}",
+                params.payment_preimage
+            ))),
+        };
+        result.map_err(|e| {
+            ErrorObjectOwned::owned(CALL_EXECUTION_FAILED_CODE, e.to_string(), Some(params))
+        })
+    }
 }
@@ -4,18 +4,24 @@ mod config;
 mod graph;
 mod invoice;
 mod peer;
+mod subscription;
 mod utils;
+mod wallet;
+mod watchtower;
 
 use std::sync::Arc;
 
 use crate::{
     cch::CchMessage,
+    ckb::CkbChainMessage,
     fiber::{
         channel::ChannelActorStateStore,
         graph::{NetworkGraph, NetworkGraphStateStore},
+        subscription::NodeEventSubscribers,
         NetworkActorMessage,
     },
     invoice::{InvoiceCommand, InvoiceStore},
+    watchtower::TowerMessage,
 };
 use cch::{CchRpcServer, CchRpcServerImpl};
 use channel::{ChannelRpcServer, ChannelRpcServerImpl};
@@ -25,8 +31,11 @@ use invoice::{InvoiceRpcServer, InvoiceRpcServerImpl};
 use jsonrpsee::server::{Server, ServerHandle};
 use peer::{PeerRpcServer, PeerRpcServerImpl};
 use ractor::ActorRef;
+use subscription::{SubscriptionRpcServer, SubscriptionRpcServerImpl};
 use tentacle::secio::PublicKey;
 use tokio::sync::{mpsc::Sender, RwLock};
+use wallet::{WalletRpcServer, WalletRpcServerImpl};
+use watchtower::{WatchtowerRpcServer, WatchtowerRpcServerImpl};
 
 pub type InvoiceCommandWithReply = (InvoiceCommand, Sender<crate::Result<String>>);
 
@@ -57,30 +66,50 @@ fn build_server(addr: &str) -> Server {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn start_rpc<
     S: ChannelActorStateStore + InvoiceStore + NetworkGraphStateStore + Clone + Send + Sync + 'static,
 >(
     config: RpcConfig,
     network_actor: Option<ActorRef<NetworkActorMessage>>,
     cch_actor: Option<ActorRef<CchMessage>>,
+    ckb_actor: Option<ActorRef<CkbChainMessage>>,
+    tower_actor: Option<ActorRef<TowerMessage>>,
     store: S,
-    network_graph: Arc<RwLock<NetworkGraph<S>>>,
+    network_graph: Option<Arc<RwLock<NetworkGraph<S>>>>,
     node_publick_key: Option<PublicKey>,
+    node_events: NodeEventSubscribers,
 ) -> ServerHandle {
     let listening_addr = config.listening_addr.as_deref().unwrap_or("[::]:0");
     let server = build_server(listening_addr);
-    let mut methods = InvoiceRpcServerImpl::new(store.clone(), node_publick_key).into_rpc();
-    if let Some(network_actor) = network_actor {
+    let mut methods = InvoiceRpcServerImpl::new(
+        store.clone(),
+        network_actor.clone(),
+        node_events.clone(),
+        node_publick_key,
+    )
+    .into_rpc();
+    if let (Some(network_actor), Some(network_graph)) = (network_actor, network_graph) {
         let peer = PeerRpcServerImpl::new(network_actor.clone());
         let channel = ChannelRpcServerImpl::new(network_actor, store.clone());
+        let subscription = SubscriptionRpcServerImpl::new(store.clone(), node_events);
         let network_graph = GraphRpcServerImpl::new(network_graph, store);
         methods.merge(peer.into_rpc()).unwrap();
         methods.merge(channel.into_rpc()).unwrap();
+        methods.merge(subscription.into_rpc()).unwrap();
         methods.merge(network_graph.into_rpc()).unwrap();
     }
+    if let Some(ckb_actor) = ckb_actor {
+        let wallet = WalletRpcServerImpl::new(ckb_actor);
+        methods.merge(wallet.into_rpc()).unwrap();
+    }
     if let Some(cch_actor) = cch_actor {
         let cch = CchRpcServerImpl::new(cch_actor);
         methods.merge(cch.into_rpc()).unwrap();
     }
+    if let Some(tower_actor) = tower_actor {
+        let watchtower = WatchtowerRpcServerImpl::new(tower_actor);
+        methods.merge(watchtower.into_rpc()).unwrap();
+    }
     server.start(methods)
 }
@@ -1,10 +1,15 @@
+use crate::fiber::features::FeatureVector;
+use crate::fiber::gossip::GossipMetrics;
+use crate::fiber::peer_book::ConnectedPeer;
+use crate::fiber::serde_utils::U64Hex;
+use crate::fiber::types::Pubkey;
 use crate::fiber::{NetworkActorCommand, NetworkActorMessage};
 use crate::log_and_error;
 use jsonrpsee::{
     core::async_trait, proc_macros::rpc, types::error::CALL_EXECUTION_FAILED_CODE,
     types::ErrorObjectOwned,
 };
-use ractor::ActorRef;
+use ractor::{call, ActorRef};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use tentacle::{multiaddr::MultiAddr, secio::PeerId};
@@ -21,6 +26,78 @@ pub struct DisconnectPeerParams {
     pub peer_id: PeerId,
 }
 
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PeerInfo {
+    #[serde_as(as = "DisplayFromStr")]
+    pub peer_id: PeerId,
+    pub pubkey: Pubkey,
+    pub address: MultiAddr,
+    pub inbound: bool,
+    pub features: Option<FeatureVector>,
+}
+
+impl From<ConnectedPeer> for PeerInfo {
+    fn from(peer: ConnectedPeer) -> Self {
+        PeerInfo {
+            peer_id: peer.peer_id,
+            pubkey: peer.pubkey,
+            address: peer.address,
+            inbound: peer.session_type.is_inbound(),
+            features: peer.features,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ListPeersResult {
+    pub peers: Vec<PeerInfo>,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BanPeerParams {
+    #[serde_as(as = "DisplayFromStr")]
+    pub peer_id: PeerId,
+    #[serde_as(as = "Option<U64Hex>")]
+    #[serde(default)]
+    pub duration_seconds: Option<u64>,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UnbanPeerParams {
+    #[serde_as(as = "DisplayFromStr")]
+    pub peer_id: PeerId,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GossipMetricsResult {
+    #[serde_as(as = "U64Hex")]
+    pub rate_limited: u64,
+    #[serde_as(as = "U64Hex")]
+    pub invalid: u64,
+    #[serde_as(as = "U64Hex")]
+    pub stash_full: u64,
+    #[serde_as(as = "U64Hex")]
+    pub from_banned_peers: u64,
+    #[serde_as(as = "U64Hex")]
+    pub banned_peers: u64,
+}
+
+impl From<GossipMetrics> for GossipMetricsResult {
+    fn from(metrics: GossipMetrics) -> Self {
+        GossipMetricsResult {
+            rate_limited: metrics.rate_limited,
+            invalid: metrics.invalid,
+            stash_full: metrics.stash_full,
+            from_banned_peers: metrics.from_banned_peers,
+            banned_peers: metrics.banned_peers,
+        }
+    }
+}
+
 #[rpc(server)]
 pub trait PeerRpc {
     #[method(name = "connect_peer")]
@@ -28,6 +105,18 @@ pub trait PeerRpc {
 
     #[method(name = "disconnect_peer")]
     async fn disconnect_peer(&self, params: DisconnectPeerParams) -> Result<(), ErrorObjectOwned>;
+
+    #[method(name = "list_peers")]
+    async fn list_peers(&self) -> Result<ListPeersResult, ErrorObjectOwned>;
+
+    #[method(name = "ban_peer")]
+    async fn ban_peer(&self, params: BanPeerParams) -> Result<(), ErrorObjectOwned>;
+
+    #[method(name = "unban_peer")]
+    async fn unban_peer(&self, params: UnbanPeerParams) -> Result<(), ErrorObjectOwned>;
+
+    #[method(name = "gossip_metrics")]
+    async fn gossip_metrics(&self) -> Result<GossipMetricsResult, ErrorObjectOwned>;
 }
 
 pub struct PeerRpcServerImpl {
@@ -54,4 +143,39 @@ impl PeerRpcServer for PeerRpcServerImpl {
         ));
         crate::handle_actor_cast!(self.actor, message, params)
     }
+
+    async fn list_peers(&self) -> Result<ListPeersResult, ErrorObjectOwned> {
+        let message =
+            |rpc_reply| NetworkActorMessage::Command(NetworkActorCommand::ListPeers(rpc_reply));
+        match call!(self.actor, message) {
+            Ok(peers) => Ok(ListPeersResult {
+                peers: peers.into_iter().map(Into::into).collect(),
+            }),
+            Err(e) => log_and_error!((), e.to_string()),
+        }
+    }
+
+    async fn ban_peer(&self, params: BanPeerParams) -> Result<(), ErrorObjectOwned> {
+        let message = NetworkActorMessage::Command(NetworkActorCommand::BanPeer(
+            params.peer_id.clone(),
+            params.duration_seconds,
+        ));
+        crate::handle_actor_cast!(self.actor, message, params)
+    }
+
+    async fn unban_peer(&self, params: UnbanPeerParams) -> Result<(), ErrorObjectOwned> {
+        let message =
+            NetworkActorMessage::Command(NetworkActorCommand::UnbanPeer(params.peer_id.clone()));
+        crate::handle_actor_cast!(self.actor, message, params)
+    }
+
+    async fn gossip_metrics(&self) -> Result<GossipMetricsResult, ErrorObjectOwned> {
+        let message = |rpc_reply| {
+            NetworkActorMessage::Command(NetworkActorCommand::GetGossipMetrics(rpc_reply))
+        };
+        match call!(self.actor, message) {
+            Ok(metrics) => Ok(metrics.into()),
+            Err(e) => log_and_error!((), e.to_string()),
+        }
+    }
 }
@@ -0,0 +1,214 @@
+use super::cch::{ReceiveBTCResponse, SendBTCResponse};
+use super::channel::{Channel, GetPaymentCommandResult};
+use super::invoice::{get_invoice_result, GetInvoiceResult};
+use crate::cch::CchOrderEvent;
+use crate::fiber::subscription::{NodeEventSubscribers, PeerEvent};
+use crate::invoice::InvoiceStore;
+use jsonrpsee::{
+    core::{async_trait, SubscriptionResult},
+    proc_macros::rpc,
+    PendingSubscriptionSink, SubscriptionMessage,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tentacle::{multiaddr::Multiaddr, secio::PeerId};
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+use tracing::warn;
+
+#[serde_as]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "event")]
+pub enum PeerEventResult {
+    Connected {
+        #[serde_as(as = "DisplayFromStr")]
+        peer_id: PeerId,
+        address: Multiaddr,
+    },
+    Disconnected {
+        #[serde_as(as = "DisplayFromStr")]
+        peer_id: PeerId,
+        address: Multiaddr,
+    },
+}
+
+impl From<PeerEvent> for PeerEventResult {
+    fn from(event: PeerEvent) -> Self {
+        match event {
+            PeerEvent::Connected(peer_id, address) => {
+                PeerEventResult::Connected { peer_id, address }
+            }
+            PeerEvent::Disconnected(peer_id, address) => {
+                PeerEventResult::Disconnected { peer_id, address }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "order_type")]
+pub enum CchOrderEventResult {
+    SendBTC(SendBTCResponse),
+    ReceiveBTC(ReceiveBTCResponse),
+}
+
+impl From<CchOrderEvent> for CchOrderEventResult {
+    fn from(event: CchOrderEvent) -> Self {
+        match event {
+            CchOrderEvent::SendBTC(order) => CchOrderEventResult::SendBTC(order.into()),
+            CchOrderEvent::ReceiveBTC(order) => CchOrderEventResult::ReceiveBTC(order.into()),
+        }
+    }
+}
+
+/// RPC module for subscribing to the node events, the subscriptions are only available
+/// over websocket.
+#[rpc(server)]
+pub trait SubscriptionRpc {
+    #[subscription(
+        name = "subscribe_channel_events",
+        unsubscribe = "unsubscribe_channel_events",
+        item = Channel
+    )]
+    async fn subscribe_channel_events(&self) -> SubscriptionResult;
+
+    #[subscription(
+        name = "subscribe_payment_events",
+        unsubscribe = "unsubscribe_payment_events",
+        item = GetPaymentCommandResult
+    )]
+    async fn subscribe_payment_events(&self) -> SubscriptionResult;
+
+    #[subscription(
+        name = "subscribe_invoice_events",
+        unsubscribe = "unsubscribe_invoice_events",
+        item = GetInvoiceResult
+    )]
+    async fn subscribe_invoice_events(&self) -> SubscriptionResult;
+
+    #[subscription(
+        name = "subscribe_peer_events",
+        unsubscribe = "unsubscribe_peer_events",
+        item = PeerEventResult
+    )]
+    async fn subscribe_peer_events(&self) -> SubscriptionResult;
+
+    #[subscription(
+        name = "subscribe_cch_order_events",
+        unsubscribe = "unsubscribe_cch_order_events",
+        item = CchOrderEventResult
+    )]
+    async fn subscribe_cch_order_events(&self) -> SubscriptionResult;
+}
+
+pub struct SubscriptionRpcServerImpl<S> {
+    store: S,
+    node_events: NodeEventSubscribers,
+}
+
+impl<S> SubscriptionRpcServerImpl<S> {
+    pub fn new(store: S, node_events: NodeEventSubscribers) -> Self {
+        SubscriptionRpcServerImpl { store, node_events }
+    }
+}
+
+// Forward the events to the subscriber until either the subscriber or the event sender is gone,
+// the events which can't be converted to the subscription items are skipped.
+async fn forward_events<T, R, F>(
+    pending: PendingSubscriptionSink,
+    mut receiver: Receiver<T>,
+    convert: F,
+) -> SubscriptionResult
+where
+    T: Clone,
+    R: Serialize,
+    F: Fn(T) -> Option<R>,
+{
+    let sink = pending.accept().await?;
+    loop {
+        tokio::select! {
+            _ = sink.closed() => break,
+            event = receiver.recv() => {
+                match event {
+                    Ok(event) => {
+                        let Some(item) = convert(event) else {
+                            continue;
+                        };
+                        if sink.send(SubscriptionMessage::from_json(&item)?).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!(
+                            "Subscription {:?} lagged behind, {} events are skipped",
+                            sink.method_name(),
+                            skipped
+                        );
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl<S> SubscriptionRpcServer for SubscriptionRpcServerImpl<S>
+where
+    S: InvoiceStore + Send + Sync + 'static,
+{
+    async fn subscribe_channel_events(
+        &self,
+        pending: PendingSubscriptionSink,
+    ) -> SubscriptionResult {
+        forward_events(
+            pending,
+            self.node_events.subscribe_channel_events(),
+            |state| Some(Channel::from(&state)),
+        )
+        .await
+    }
+
+    async fn subscribe_payment_events(
+        &self,
+        pending: PendingSubscriptionSink,
+    ) -> SubscriptionResult {
+        forward_events(
+            pending,
+            self.node_events.subscribe_payment_events(),
+            |session| Some(GetPaymentCommandResult::from(session)),
+        )
+        .await
+    }
+
+    async fn subscribe_invoice_events(
+        &self,
+        pending: PendingSubscriptionSink,
+    ) -> SubscriptionResult {
+        forward_events(
+            pending,
+            self.node_events.subscribe_invoice_events(),
+            |(payment_hash, status)| get_invoice_result(&self.store, &payment_hash, status).ok(),
+        )
+        .await
+    }
+
+    async fn subscribe_peer_events(&self, pending: PendingSubscriptionSink) -> SubscriptionResult {
+        forward_events(pending, self.node_events.subscribe_peer_events(), |event| {
+            Some(PeerEventResult::from(event))
+        })
+        .await
+    }
+
+    async fn subscribe_cch_order_events(
+        &self,
+        pending: PendingSubscriptionSink,
+    ) -> SubscriptionResult {
+        forward_events(
+            pending,
+            self.node_events.subscribe_cch_order_events(),
+            |event| Some(CchOrderEventResult::from(event)),
+        )
+        .await
+    }
+}
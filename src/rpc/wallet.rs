@@ -0,0 +1,187 @@
+use crate::ckb::{CkbChainMessage, SendToAddressRequest, UdtBalance, UnspentCell, WalletBalance};
+use crate::fiber::{
+    channel::DEFAULT_FEE_RATE,
+    serde_utils::{EntityHex, U128Hex, U64Hex},
+    types::Hash256,
+};
+use crate::log_and_error;
+use ckb_jsonrpc_types::Script;
+use ckb_types::packed::OutPoint;
+use jsonrpsee::{
+    core::async_trait,
+    proc_macros::rpc,
+    types::{error::CALL_EXECUTION_FAILED_CODE, ErrorObjectOwned},
+};
+use ractor::{call, ActorRef};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+#[serde_as]
+#[derive(Clone, Serialize)]
+pub struct UdtBalanceResult {
+    pub name: String,
+    pub udt_type_script: Script,
+    #[serde_as(as = "U128Hex")]
+    pub amount: u128,
+}
+
+impl From<UdtBalance> for UdtBalanceResult {
+    fn from(balance: UdtBalance) -> Self {
+        Self {
+            name: balance.name,
+            udt_type_script: balance.type_script.into(),
+            amount: balance.amount,
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Clone, Serialize)]
+pub struct WalletBalanceResult {
+    /// Capacity of the cells which can be used to fund channels or pay fees.
+    #[serde_as(as = "U64Hex")]
+    pub ckb_amount: u64,
+    /// Capacity of all the cells, including the capacity occupied by UDT cells.
+    #[serde_as(as = "U64Hex")]
+    pub total_capacity: u64,
+    pub udt_balances: Vec<UdtBalanceResult>,
+}
+
+impl From<WalletBalance> for WalletBalanceResult {
+    fn from(balance: WalletBalance) -> Self {
+        Self {
+            ckb_amount: balance.ckb_amount,
+            total_capacity: balance.total_capacity,
+            udt_balances: balance.udt_balances.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Clone, Serialize)]
+pub struct UnspentCellResult {
+    #[serde_as(as = "EntityHex")]
+    pub out_point: OutPoint,
+    #[serde_as(as = "U64Hex")]
+    pub capacity: u64,
+    pub type_script: Option<Script>,
+    #[serde_as(as = "Option<U128Hex>")]
+    pub udt_amount: Option<u128>,
+    #[serde_as(as = "U64Hex")]
+    pub block_number: u64,
+}
+
+impl From<UnspentCell> for UnspentCellResult {
+    fn from(cell: UnspentCell) -> Self {
+        Self {
+            out_point: cell.out_point,
+            capacity: cell.capacity,
+            type_script: cell.type_script.map(Into::into),
+            udt_amount: cell.udt_amount,
+            block_number: cell.block_number,
+        }
+    }
+}
+
+#[derive(Clone, Serialize)]
+pub struct ListUnspentResult {
+    pub cells: Vec<UnspentCellResult>,
+}
+
+#[derive(Clone, Serialize)]
+pub struct NewAddressResult {
+    pub address: String,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SendToAddressParams {
+    pub address: String,
+    /// CKB amount in shannons, or the UDT amount if `udt_type_script` is set.
+    #[serde_as(as = "U128Hex")]
+    pub amount: u128,
+    pub udt_type_script: Option<Script>,
+    #[serde_as(as = "Option<U64Hex>")]
+    pub fee_rate: Option<u64>,
+}
+
+#[derive(Clone, Serialize)]
+pub struct SendToAddressResult {
+    pub tx_hash: Hash256,
+}
+
+#[rpc(server)]
+pub trait WalletRpc {
+    #[method(name = "wallet_balance")]
+    async fn wallet_balance(&self) -> Result<WalletBalanceResult, ErrorObjectOwned>;
+
+    #[method(name = "list_unspent")]
+    async fn list_unspent(&self) -> Result<ListUnspentResult, ErrorObjectOwned>;
+
+    #[method(name = "new_address")]
+    async fn new_address(&self) -> Result<NewAddressResult, ErrorObjectOwned>;
+
+    #[method(name = "send_to_address")]
+    async fn send_to_address(
+        &self,
+        params: SendToAddressParams,
+    ) -> Result<SendToAddressResult, ErrorObjectOwned>;
+}
+
+pub struct WalletRpcServerImpl {
+    actor: ActorRef<CkbChainMessage>,
+}
+
+impl WalletRpcServerImpl {
+    pub fn new(actor: ActorRef<CkbChainMessage>) -> Self {
+        WalletRpcServerImpl { actor }
+    }
+}
+
+macro_rules! call_wallet {
+    ($actor:expr, $message:expr, $arg:expr, $params:expr) => {
+        match call!($actor, $message, $arg) {
+            Ok(Ok(res)) => Ok(res),
+            Ok(Err(e)) => log_and_error!($params, e.to_string()),
+            Err(e) => log_and_error!($params, e.to_string()),
+        }
+    };
+}
+
+#[async_trait]
+impl WalletRpcServer for WalletRpcServerImpl {
+    async fn wallet_balance(&self) -> Result<WalletBalanceResult, ErrorObjectOwned> {
+        call_wallet!(self.actor, CkbChainMessage::GetWalletBalance, (), ())
+            .map(WalletBalanceResult::from)
+    }
+
+    async fn list_unspent(&self) -> Result<ListUnspentResult, ErrorObjectOwned> {
+        call_wallet!(self.actor, CkbChainMessage::ListUnspent, (), ()).map(
+            |cells: Vec<UnspentCell>| ListUnspentResult {
+                cells: cells.into_iter().map(Into::into).collect(),
+            },
+        )
+    }
+
+    async fn new_address(&self) -> Result<NewAddressResult, ErrorObjectOwned> {
+        call_wallet!(self.actor, CkbChainMessage::GetWalletAddress, (), ())
+            .map(|address| NewAddressResult { address })
+    }
+
+    async fn send_to_address(
+        &self,
+        params: SendToAddressParams,
+    ) -> Result<SendToAddressResult, ErrorObjectOwned> {
+        let request = SendToAddressRequest {
+            address: params.address.clone(),
+            amount: params.amount,
+            udt_type_script: params.udt_type_script.clone().map(Into::into),
+            fee_rate: params.fee_rate.unwrap_or(DEFAULT_FEE_RATE),
+        };
+        call_wallet!(self.actor, CkbChainMessage::SendToAddress, request, params).map(
+            |tx_hash: ckb_types::packed::Byte32| SendToAddressResult {
+                tx_hash: tx_hash.into(),
+            },
+        )
+    }
+}
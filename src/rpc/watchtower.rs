@@ -0,0 +1,116 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::fiber::types::Pubkey;
+use crate::log_and_error;
+use crate::watchtower::{
+    RemoveChannelRequest, TowerMessage, TowerRequest, TowerRequestBody, UpdateRevocationRequest,
+    WatchChannelRequest,
+};
+use jsonrpsee::{
+    core::async_trait,
+    proc_macros::rpc,
+    types::{error::CALL_EXECUTION_FAILED_CODE, ErrorObjectOwned},
+};
+use ractor::ActorRef;
+
+/// The rpc methods of the standalone watchtower, the requests are signed by the client nodes.
+#[rpc(server)]
+pub trait WatchtowerRpc {
+    #[method(name = "create_watch_channel")]
+    async fn create_watch_channel(
+        &self,
+        params: TowerRequest<WatchChannelRequest>,
+    ) -> Result<(), ErrorObjectOwned>;
+
+    #[method(name = "remove_watch_channel")]
+    async fn remove_watch_channel(
+        &self,
+        params: TowerRequest<RemoveChannelRequest>,
+    ) -> Result<(), ErrorObjectOwned>;
+
+    #[method(name = "update_revocation")]
+    async fn update_revocation(
+        &self,
+        params: TowerRequest<UpdateRevocationRequest>,
+    ) -> Result<(), ErrorObjectOwned>;
+}
+
+/// The requests whose timestamps are too far away from the tower's clock are rejected.
+const MAX_REQUEST_TIME_DRIFT_MS: u64 = 10 * 60 * 1000;
+
+pub struct WatchtowerRpcServerImpl {
+    actor: ActorRef<TowerMessage>,
+    /// The timestamp of the last accepted request of each client.
+    last_timestamps: Mutex<HashMap<Pubkey, u64>>,
+}
+
+impl WatchtowerRpcServerImpl {
+    pub fn new(actor: ActorRef<TowerMessage>) -> Self {
+        WatchtowerRpcServerImpl {
+            actor,
+            last_timestamps: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Checks the signature of the request, and rejects the replayed ones, i.e. the requests
+    /// which are not recent or not newer than the last accepted request of the client.
+    fn check_request<T: TowerRequestBody>(&self, params: &TowerRequest<T>) -> Result<(), String> {
+        if !params.verify() {
+            return Err("Invalid signature".to_string());
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("SystemTime::now() should after UNIX_EPOCH")
+            .as_millis() as u64;
+        if params.timestamp.abs_diff(now) > MAX_REQUEST_TIME_DRIFT_MS {
+            return Err(format!("Expired request timestamp {}", params.timestamp));
+        }
+        let mut last_timestamps = self.last_timestamps.lock().expect("lock last timestamps");
+        let last_timestamp = last_timestamps.entry(params.client).or_default();
+        if params.timestamp <= *last_timestamp {
+            return Err(format!("Replayed request timestamp {}", params.timestamp));
+        }
+        *last_timestamp = params.timestamp;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl WatchtowerRpcServer for WatchtowerRpcServerImpl {
+    async fn create_watch_channel(
+        &self,
+        params: TowerRequest<WatchChannelRequest>,
+    ) -> Result<(), ErrorObjectOwned> {
+        if let Err(err) = self.check_request(&params) {
+            return log_and_error!(params, err);
+        }
+        let message = TowerMessage::WatchChannel(params.client, params.body.clone());
+        crate::handle_actor_cast!(self.actor, message, params)
+    }
+
+    async fn remove_watch_channel(
+        &self,
+        params: TowerRequest<RemoveChannelRequest>,
+    ) -> Result<(), ErrorObjectOwned> {
+        if let Err(err) = self.check_request(&params) {
+            return log_and_error!(params, err);
+        }
+        let message = TowerMessage::RemoveChannel(params.client, params.body.clone());
+        crate::handle_actor_cast!(self.actor, message, params)
+    }
+
+    async fn update_revocation(
+        &self,
+        params: TowerRequest<UpdateRevocationRequest>,
+    ) -> Result<(), ErrorObjectOwned> {
+        if let Err(err) = self.check_request(&params) {
+            return log_and_error!(params, err);
+        }
+        let message = TowerMessage::UpdateRevocation(params.client, params.body.clone());
+        crate::handle_actor_cast!(self.actor, message, params)
+    }
+}
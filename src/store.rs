@@ -1,11 +1,19 @@
 use crate::{
+    cch::{CchDbError, CchOrderStore, ReceiveBTCOrder, SendBTCOrder},
     fiber::{
         channel::{ChannelActorState, ChannelActorStateStore, ChannelState},
         graph::{ChannelInfo, NetworkGraphStateStore, NodeInfo, PaymentSession},
+        history::TimedResult,
         types::{Hash256, Pubkey},
     },
-    invoice::{CkbInvoice, InvoiceError, InvoiceStore},
-    watchtower::{ChannelData, RevocationData, WatchtowerStore},
+    invoice::{
+        CkbInvoice, CkbInvoiceStatus, HeldPayment, InvoiceError, InvoiceStore, PendingMppPayment,
+    },
+    sweeper::{SweepOutput, SweeperStore},
+    watchtower::{
+        ChannelData, EncryptedRevocationData, RevocationData, TowerChannelData, TowerStore,
+        WatchtowerStore,
+    },
 };
 use ckb_jsonrpc_types::JsonBytes;
 use ckb_types::packed::{OutPoint, Script};
@@ -88,6 +96,27 @@ impl Batch {
                     serde_json::to_vec(&preimage).expect("serialize Hash256 should be OK"),
                 );
             }
+            KeyValue::CkbInvoiceStatus(id, status) => {
+                let key = [&[CKB_INVOICE_STATUS_PREFIX], id.as_ref()].concat();
+                self.put(
+                    key,
+                    serde_json::to_vec(&status).expect("serialize CkbInvoiceStatus should be OK"),
+                );
+            }
+            KeyValue::CkbInvoiceHeldPayment(id, held_payment) => {
+                let key = [&[CKB_INVOICE_HELD_PAYMENT_PREFIX], id.as_ref()].concat();
+                self.put(
+                    key,
+                    serde_json::to_vec(&held_payment).expect("serialize HeldPayment should be OK"),
+                );
+            }
+            KeyValue::PendingMppPayment(id, payment) => {
+                let key = [&[PENDING_MPP_PAYMENT_PREFIX], id.as_ref()].concat();
+                self.put(
+                    key,
+                    serde_json::to_vec(&payment).expect("serialize PendingMppPayment should be OK"),
+                );
+            }
             KeyValue::PeerIdChannelId((peer_id, channel_id), state) => {
                 let key = [
                     &[PEER_ID_CHANNEL_ID_PREFIX],
@@ -138,6 +167,18 @@ impl Batch {
                         .expect("serialize PaymentSession should be OK"),
                 );
             }
+            KeyValue::PaymentHistoryTimedResult((channel_outpoint, target), result) => {
+                let key = [
+                    &[PAYMENT_HISTORY_TIMED_RESULT_PREFIX],
+                    channel_outpoint.as_slice(),
+                    target.serialize().as_ref(),
+                ]
+                .concat();
+                self.put(
+                    key,
+                    serde_json::to_vec(&result).expect("serialize TimedResult should be OK"),
+                );
+            }
             KeyValue::NodeInfo(id, node) => {
                 // Save node announcement timestamp to index, so that we can query nodes by timestamp
                 self.put(
@@ -164,6 +205,27 @@ impl Batch {
                     serde_json::to_vec(&multiaddr).expect("serialize Multiaddr should be OK"),
                 );
             }
+            KeyValue::BannedPeer(peer_id, until) => {
+                let key = [&[BANNED_PEER_PREFIX], peer_id.as_bytes()].concat();
+                self.put(
+                    key,
+                    serde_json::to_vec(&until).expect("serialize u64 should be OK"),
+                );
+            }
+            KeyValue::CchSendBTCOrder(payment_hash, order) => {
+                let key = [&[CCH_SEND_BTC_ORDER_PREFIX], payment_hash.as_bytes()].concat();
+                self.put(
+                    key,
+                    serde_json::to_vec(&order).expect("serialize SendBTCOrder should be OK"),
+                );
+            }
+            KeyValue::CchReceiveBTCOrder(payment_hash, order) => {
+                let key = [&[CCH_RECEIVE_BTC_ORDER_PREFIX], payment_hash.as_bytes()].concat();
+                self.put(
+                    key,
+                    serde_json::to_vec(&order).expect("serialize ReceiveBTCOrder should be OK"),
+                );
+            }
             KeyValue::WatchtowerChannel(channel_id, channel_data) => {
                 let key = [&[WATCHTOWER_CHANNEL_PREFIX], channel_id.as_ref()].concat();
                 self.put(
@@ -171,6 +233,21 @@ impl Batch {
                     serde_json::to_vec(&channel_data).expect("serialize ChannelData should be OK"),
                 );
             }
+            KeyValue::SweepOutput(channel_id, sweep_output) => {
+                let key = [&[SWEEP_OUTPUT_PREFIX], channel_id.as_ref()].concat();
+                self.put(
+                    key,
+                    serde_json::to_vec(&sweep_output).expect("serialize SweepOutput should be OK"),
+                );
+            }
+            KeyValue::TowerChannel(channel_data) => {
+                let key = tower_channel_key(&channel_data.client, &channel_data.channel_id);
+                self.put(
+                    key,
+                    serde_json::to_vec(&channel_data)
+                        .expect("serialize TowerChannelData should be OK"),
+                );
+            }
         }
     }
 
@@ -193,6 +270,10 @@ impl Batch {
 /// +--------------+--------------------+--------------------------+
 /// | 0            | Hash256            | ChannelActorState        |
 /// | 32           | Hash256            | CkbInvoice               |
+/// | 33           | Hash256            | CkbInvoicePreimage       |
+/// | 34           | Hash256            | CkbInvoiceStatus         |
+/// | 35           | Hash256            | HeldPayment              |
+/// | 36           | Hash256            | PendingMppPayment        |
 /// | 64           | PeerId | Hash256   | ChannelState             |
 /// | 96           | ChannelId          | ChannelInfo              |
 /// | 97           | Block | Index      | ChannelId                |
@@ -200,14 +281,23 @@ impl Batch {
 /// | 128          | NodeId             | NodeInfo                 |
 /// | 129          | Timestamp          | NodeId                   |
 /// | 160          | PeerId             | MultiAddr                |
+/// | 161          | PeerId             | BannedUntil              |
 /// | 192          | Hash256            | PaymentSession           |
+/// | 193          | OutPoint | Pubkey  | TimedResult              |
 /// | 224          | Hash256            | ChannelData              |
+/// | 225          | Hash256            | SweepOutput              |
+/// | 226          | Pubkey | Hash256   | TowerChannelData         |
+/// | 240          | PaymentHash        | SendBTCOrder             |
+/// | 241          | PaymentHash        | ReceiveBTCOrder          |
 /// +--------------+--------------------+--------------------------+
 ///
 
 const CHANNEL_ACTOR_STATE_PREFIX: u8 = 0;
 const CKB_INVOICE_PREFIX: u8 = 32;
 const CKB_INVOICE_PREIMAGE_PREFIX: u8 = 33;
+const CKB_INVOICE_STATUS_PREFIX: u8 = 34;
+const CKB_INVOICE_HELD_PAYMENT_PREFIX: u8 = 35;
+const PENDING_MPP_PAYMENT_PREFIX: u8 = 36;
 const PEER_ID_CHANNEL_ID_PREFIX: u8 = 64;
 const CHANNEL_INFO_PREFIX: u8 = 96;
 const CHANNEL_ANNOUNCEMENT_INDEX_PREFIX: u8 = 97;
@@ -215,19 +305,34 @@ const CHANNEL_UPDATE_INDEX_PREFIX: u8 = 98;
 const NODE_INFO_PREFIX: u8 = 128;
 const NODE_ANNOUNCEMENT_INDEX_PREFIX: u8 = 129;
 const PEER_ID_MULTIADDR_PREFIX: u8 = 160;
+const BANNED_PEER_PREFIX: u8 = 161;
 const PAYMENT_SESSION_PREFIX: u8 = 192;
+const PAYMENT_HISTORY_TIMED_RESULT_PREFIX: u8 = 193;
 const WATCHTOWER_CHANNEL_PREFIX: u8 = 224;
+const SWEEP_OUTPUT_PREFIX: u8 = 225;
+const TOWER_CHANNEL_PREFIX: u8 = 226;
+const CCH_SEND_BTC_ORDER_PREFIX: u8 = 240;
+const CCH_RECEIVE_BTC_ORDER_PREFIX: u8 = 241;
 
 enum KeyValue {
     ChannelActorState(Hash256, ChannelActorState),
     CkbInvoice(Hash256, CkbInvoice),
     CkbInvoicePreimage(Hash256, Hash256),
+    CkbInvoiceStatus(Hash256, CkbInvoiceStatus),
+    CkbInvoiceHeldPayment(Hash256, HeldPayment),
+    PendingMppPayment(Hash256, PendingMppPayment),
     PeerIdChannelId((PeerId, Hash256), ChannelState),
     PeerIdMultiAddr(PeerId, Multiaddr),
+    BannedPeer(PeerId, u64),
     NodeInfo(Pubkey, NodeInfo),
     ChannelInfo(OutPoint, ChannelInfo),
     WatchtowerChannel(Hash256, ChannelData),
+    SweepOutput(Hash256, SweepOutput),
+    TowerChannel(TowerChannelData),
     PaymentSession(Hash256, PaymentSession),
+    PaymentHistoryTimedResult((OutPoint, Pubkey), TimedResult),
+    CchSendBTCOrder(String, SendBTCOrder),
+    CchReceiveBTCOrder(String, ReceiveBTCOrder),
 }
 
 impl ChannelActorStateStore for Store {
@@ -330,6 +435,7 @@ impl InvoiceStore for Store {
         if let Some(preimage) = preimage {
             batch.put_kv(KeyValue::CkbInvoicePreimage(*hash, preimage));
         }
+        batch.put_kv(KeyValue::CkbInvoiceStatus(*hash, CkbInvoiceStatus::Open));
         batch.put_kv(KeyValue::CkbInvoice(*invoice.payment_hash(), invoice));
         batch.commit();
         return Ok(());
@@ -343,6 +449,113 @@ impl InvoiceStore for Store {
         self.get(key)
             .map(|v| serde_json::from_slice(v.as_ref()).expect("deserialize Hash256 should be OK"))
     }
+
+    fn insert_invoice_preimage(&self, id: &Hash256, preimage: Hash256) -> Result<(), InvoiceError> {
+        if self.get_invoice(id).is_none() {
+            return Err(InvoiceError::InvoiceNotFound(id.to_string()));
+        }
+        let mut batch = self.batch();
+        batch.put_kv(KeyValue::CkbInvoicePreimage(*id, preimage));
+        batch.commit();
+        Ok(())
+    }
+
+    fn get_invoices(&self) -> Vec<CkbInvoice> {
+        let prefix = [CKB_INVOICE_PREFIX];
+        self.db
+            .prefix_iterator(prefix.as_ref())
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .map(|(_, value)| {
+                serde_json::from_slice(value.as_ref()).expect("deserialize CkbInvoice should be OK")
+            })
+            .collect()
+    }
+
+    fn get_invoice_status(&self, id: &Hash256) -> Option<CkbInvoiceStatus> {
+        let mut key = Vec::with_capacity(33);
+        key.extend_from_slice(&[CKB_INVOICE_STATUS_PREFIX]);
+        key.extend_from_slice(id.as_ref());
+
+        match self.get(key) {
+            Some(v) => Some(
+                serde_json::from_slice(v.as_ref())
+                    .expect("deserialize CkbInvoiceStatus should be OK"),
+            ),
+            // The invoices created by older versions have no status saved.
+            None => self.get_invoice(id).map(|_| CkbInvoiceStatus::Open),
+        }
+    }
+
+    fn update_invoice_status(
+        &self,
+        id: &Hash256,
+        status: CkbInvoiceStatus,
+    ) -> Result<(), InvoiceError> {
+        if self.get_invoice(id).is_none() {
+            return Err(InvoiceError::InvoiceNotFound(id.to_string()));
+        }
+        let mut batch = self.batch();
+        batch.put_kv(KeyValue::CkbInvoiceStatus(*id, status));
+        batch.commit();
+        Ok(())
+    }
+
+    fn get_held_payments(&self) -> Vec<HeldPayment> {
+        let prefix = [CKB_INVOICE_HELD_PAYMENT_PREFIX];
+        self.db
+            .prefix_iterator(prefix.as_ref())
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .map(|(_key, value)| {
+                serde_json::from_slice(value.as_ref())
+                    .expect("deserialize HeldPayment should be OK")
+            })
+            .collect()
+    }
+
+    fn insert_held_payment(&self, held_payment: HeldPayment) {
+        let mut batch = self.batch();
+        batch.put_kv(KeyValue::CkbInvoiceHeldPayment(
+            held_payment.payment_hash,
+            held_payment,
+        ));
+        batch.commit();
+    }
+
+    fn remove_held_payment(&self, payment_hash: &Hash256) {
+        let key = [&[CKB_INVOICE_HELD_PAYMENT_PREFIX], payment_hash.as_ref()].concat();
+        self.db.delete(key).expect("delete should be OK");
+    }
+
+    fn get_pending_mpp_payments(&self) -> Vec<PendingMppPayment> {
+        let prefix = [PENDING_MPP_PAYMENT_PREFIX];
+        self.db
+            .prefix_iterator(prefix.as_ref())
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .map(|(_key, value)| {
+                serde_json::from_slice(value.as_ref())
+                    .expect("deserialize PendingMppPayment should be OK")
+            })
+            .collect()
+    }
+
+    fn insert_pending_mpp_payment(&self, payment: PendingMppPayment) {
+        let mut batch = self.batch();
+        batch.put_kv(KeyValue::PendingMppPayment(payment.payment_hash, payment));
+        batch.commit();
+    }
+
+    fn remove_pending_mpp_payment(&self, payment_hash: &Hash256) {
+        let key = [&[PENDING_MPP_PAYMENT_PREFIX], payment_hash.as_ref()].concat();
+        self.db.delete(key).expect("delete should be OK");
+    }
+}
+
+// The payment sessions saved by older versions have a different format, they are skipped
+// as they can't be resumed anyway.
+fn deserialize_payment_session(value: &[u8]) -> Option<PaymentSession> {
+    serde_json::from_slice(value)
+        .map_err(|err| tracing::warn!("Skipping undecodable payment session: {}", err))
+        .ok()
 }
 
 impl NetworkGraphStateStore for Store {
@@ -475,6 +688,35 @@ impl NetworkGraphStateStore for Store {
         batch.commit();
     }
 
+    fn delete_channel(&self, outpoint: &OutPoint) {
+        let Some(channel) = self.get_channels(Some(outpoint.clone())).pop() else {
+            return;
+        };
+        let mut batch = self.batch();
+        let update_index_key = [
+            CHANNEL_UPDATE_INDEX_PREFIX.to_be_bytes().as_slice(),
+            channel.timestamp.to_be_bytes().as_slice(),
+        ]
+        .concat();
+        // Channels announced at the same time share the same update index key.
+        if self
+            .get(&update_index_key)
+            .is_some_and(|value| value == outpoint.as_slice())
+        {
+            batch.delete(update_index_key);
+        }
+        batch.delete(
+            [
+                CHANNEL_ANNOUNCEMENT_INDEX_PREFIX.to_be_bytes().as_slice(),
+                channel.funding_tx_block_number.to_be_bytes().as_slice(),
+                channel.funding_tx_index.to_be_bytes().as_slice(),
+            ]
+            .concat(),
+        );
+        batch.delete([&[CHANNEL_INFO_PREFIX], outpoint.as_slice()].concat());
+        batch.commit();
+    }
+
     fn insert_node(&self, node: NodeInfo) {
         let mut batch = self.batch();
         batch.put_kv(KeyValue::NodeInfo(node.node_id, node.clone()));
@@ -498,11 +740,45 @@ impl NetworkGraphStateStore for Store {
         }
     }
 
+    fn insert_banned_peer(&self, peer_id: PeerId, until: u64) {
+        let mut batch = self.batch();
+        batch.put_kv(KeyValue::BannedPeer(peer_id, until));
+        batch.commit();
+    }
+
+    fn get_banned_peers(&self) -> Vec<(PeerId, u64)> {
+        let prefix = vec![BANNED_PEER_PREFIX];
+        self.db
+            .prefix_iterator(prefix.as_ref())
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .map(|(key, value)| {
+                let peer_id =
+                    PeerId::from_bytes(key[1..].into()).expect("deserialize peer id should be OK");
+                let until =
+                    serde_json::from_slice(value.as_ref()).expect("deserialize u64 should be OK");
+                (peer_id, until)
+            })
+            .collect()
+    }
+
+    fn remove_banned_peer(&self, peer_id: &PeerId) {
+        let key = [&[BANNED_PEER_PREFIX], peer_id.as_bytes()].concat();
+        self.db.delete(key).expect("delete should be OK");
+    }
+
     fn get_payment_session(&self, payment_hash: Hash256) -> Option<PaymentSession> {
         let prefix = [&[PAYMENT_SESSION_PREFIX], payment_hash.as_ref()].concat();
-        self.get(prefix).map(|v| {
-            serde_json::from_slice(v.as_ref()).expect("deserialize PaymentSession should be OK")
-        })
+        self.get(prefix)
+            .and_then(|v| deserialize_payment_session(v.as_ref()))
+    }
+
+    fn get_payment_sessions(&self) -> Vec<PaymentSession> {
+        let prefix = [PAYMENT_SESSION_PREFIX];
+        self.db
+            .prefix_iterator(prefix.as_ref())
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .filter_map(|(_key, value)| deserialize_payment_session(value.as_ref()))
+            .collect()
     }
 
     fn insert_payment_session(&self, session: PaymentSession) {
@@ -510,6 +786,141 @@ impl NetworkGraphStateStore for Store {
         batch.put_kv(KeyValue::PaymentSession(session.payment_hash(), session));
         batch.commit();
     }
+
+    fn get_payment_history_results(&self) -> Vec<(OutPoint, Pubkey, TimedResult)> {
+        let prefix = [PAYMENT_HISTORY_TIMED_RESULT_PREFIX];
+        self.db
+            .prefix_iterator(prefix.as_ref())
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .map(|(key, value)| {
+                let channel_outpoint = OutPoint::from_slice(&key[1..=OutPoint::TOTAL_SIZE])
+                    .expect("deserialize OutPoint should be OK");
+                let target = secp256k1::PublicKey::from_slice(&key[OutPoint::TOTAL_SIZE + 1..])
+                    .expect("deserialize Pubkey should be OK")
+                    .into();
+                let result = serde_json::from_slice(value.as_ref())
+                    .expect("deserialize TimedResult should be OK");
+                (channel_outpoint, target, result)
+            })
+            .collect()
+    }
+
+    fn insert_payment_history_result(
+        &self,
+        channel_outpoint: OutPoint,
+        target: Pubkey,
+        result: TimedResult,
+    ) {
+        let mut batch = self.batch();
+        batch.put_kv(KeyValue::PaymentHistoryTimedResult(
+            (channel_outpoint, target),
+            result,
+        ));
+        batch.commit();
+    }
+
+    fn remove_payment_history_results(&self, channel_outpoint: Option<OutPoint>) {
+        let prefix = match channel_outpoint {
+            Some(channel_outpoint) => [
+                &[PAYMENT_HISTORY_TIMED_RESULT_PREFIX],
+                channel_outpoint.as_slice(),
+            ]
+            .concat(),
+            None => vec![PAYMENT_HISTORY_TIMED_RESULT_PREFIX],
+        };
+        let iter = self
+            .db
+            .prefix_iterator(prefix.as_ref())
+            .take_while(|(key, _)| key.starts_with(&prefix));
+        for (key, _) in iter {
+            self.db.delete(key).expect("delete should be OK");
+        }
+    }
+}
+
+impl CchOrderStore for Store {
+    fn get_send_btc_order(&self, payment_hash: &str) -> Result<SendBTCOrder, CchDbError> {
+        let key = [&[CCH_SEND_BTC_ORDER_PREFIX], payment_hash.as_bytes()].concat();
+        self.get(key)
+            .map(|v| {
+                serde_json::from_slice(v.as_ref()).expect("deserialize SendBTCOrder should be OK")
+            })
+            .ok_or_else(|| CchDbError::NotFound(payment_hash.to_string()))
+    }
+
+    fn insert_send_btc_order(&self, order: SendBTCOrder) -> Result<(), CchDbError> {
+        let key = order.payment_hash.clone();
+        if self.get_send_btc_order(&key).is_ok() {
+            return Err(CchDbError::Duplicated(key));
+        }
+        let mut batch = self.batch();
+        batch.put_kv(KeyValue::CchSendBTCOrder(key, order));
+        batch.commit();
+        Ok(())
+    }
+
+    fn update_send_btc_order(&self, order: SendBTCOrder) -> Result<(), CchDbError> {
+        let key = order.payment_hash.clone();
+        self.get_send_btc_order(&key)?;
+        let mut batch = self.batch();
+        batch.put_kv(KeyValue::CchSendBTCOrder(key, order));
+        batch.commit();
+        Ok(())
+    }
+
+    fn get_send_btc_orders(&self) -> Vec<SendBTCOrder> {
+        let prefix = [CCH_SEND_BTC_ORDER_PREFIX];
+        self.db
+            .prefix_iterator(prefix.as_ref())
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .map(|(_, value)| {
+                serde_json::from_slice(value.as_ref())
+                    .expect("deserialize SendBTCOrder should be OK")
+            })
+            .collect()
+    }
+
+    fn get_receive_btc_order(&self, payment_hash: &str) -> Result<ReceiveBTCOrder, CchDbError> {
+        let key = [&[CCH_RECEIVE_BTC_ORDER_PREFIX], payment_hash.as_bytes()].concat();
+        self.get(key)
+            .map(|v| {
+                serde_json::from_slice(v.as_ref())
+                    .expect("deserialize ReceiveBTCOrder should be OK")
+            })
+            .ok_or_else(|| CchDbError::NotFound(payment_hash.to_string()))
+    }
+
+    fn insert_receive_btc_order(&self, order: ReceiveBTCOrder) -> Result<(), CchDbError> {
+        let key = order.payment_hash.clone();
+        if self.get_receive_btc_order(&key).is_ok() {
+            return Err(CchDbError::Duplicated(key));
+        }
+        let mut batch = self.batch();
+        batch.put_kv(KeyValue::CchReceiveBTCOrder(key, order));
+        batch.commit();
+        Ok(())
+    }
+
+    fn update_receive_btc_order(&self, order: ReceiveBTCOrder) -> Result<(), CchDbError> {
+        let key = order.payment_hash.clone();
+        self.get_receive_btc_order(&key)?;
+        let mut batch = self.batch();
+        batch.put_kv(KeyValue::CchReceiveBTCOrder(key, order));
+        batch.commit();
+        Ok(())
+    }
+
+    fn get_receive_btc_orders(&self) -> Vec<ReceiveBTCOrder> {
+        let prefix = [CCH_RECEIVE_BTC_ORDER_PREFIX];
+        self.db
+            .prefix_iterator(prefix.as_ref())
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .map(|(_, value)| {
+                serde_json::from_slice(value.as_ref())
+                    .expect("deserialize ReceiveBTCOrder should be OK")
+            })
+            .collect()
+    }
 }
 
 impl WatchtowerStore for Store {
@@ -528,12 +939,18 @@ impl WatchtowerStore for Store {
     fn insert_watch_channel(&self, channel_id: Hash256, funding_tx_lock: Script) {
         let mut batch = self.batch();
         let key = [&[WATCHTOWER_CHANNEL_PREFIX], channel_id.as_ref()].concat();
+        // The channel is watched again on reestablishing and splicing, keep the revocation data.
+        let revocation_data = self.get(&key).and_then(|v| {
+            serde_json::from_slice::<ChannelData>(v.as_ref())
+                .expect("deserialize ChannelData should be OK")
+                .revocation_data
+        });
         batch.put(
             key,
             serde_json::to_vec(&ChannelData {
                 channel_id,
                 funding_tx_lock,
-                revocation_data: None,
+                revocation_data,
             })
             .expect("serialize ChannelData should be OK"),
         );
@@ -559,14 +976,118 @@ impl WatchtowerStore for Store {
     }
 }
 
+fn tower_channel_key(client: &Pubkey, channel_id: &Hash256) -> Vec<u8> {
+    [
+        &[TOWER_CHANNEL_PREFIX],
+        client.serialize().as_slice(),
+        channel_id.as_ref(),
+    ]
+    .concat()
+}
+
+impl TowerStore for Store {
+    fn get_tower_channels(&self) -> Vec<TowerChannelData> {
+        let prefix = vec![TOWER_CHANNEL_PREFIX];
+        let iter = self
+            .db
+            .prefix_iterator(prefix.as_ref())
+            .take_while(|(col_key, _)| col_key.starts_with(&prefix));
+        iter.map(|(_key, value)| {
+            serde_json::from_slice(value.as_ref())
+                .expect("deserialize TowerChannelData should be OK")
+        })
+        .collect()
+    }
+
+    fn insert_tower_channel(&self, client: Pubkey, channel_id: Hash256, funding_tx_lock: Script) {
+        // The client watches the channel again on reestablishing and splicing, keep the revocation.
+        let revocation = self
+            .get(tower_channel_key(&client, &channel_id))
+            .and_then(|v| {
+                serde_json::from_slice::<TowerChannelData>(v.as_ref())
+                    .expect("deserialize TowerChannelData should be OK")
+                    .revocation
+            });
+        let mut batch = self.batch();
+        batch.put_kv(KeyValue::TowerChannel(TowerChannelData {
+            client,
+            channel_id,
+            funding_tx_lock,
+            revocation,
+        }));
+        batch.commit();
+    }
+
+    fn remove_tower_channel(&self, client: Pubkey, channel_id: Hash256) {
+        let key = tower_channel_key(&client, &channel_id);
+        self.db.delete(key).expect("delete should be OK");
+    }
+
+    fn update_tower_revocation(
+        &self,
+        client: Pubkey,
+        channel_id: Hash256,
+        revocation: EncryptedRevocationData,
+    ) {
+        let key = tower_channel_key(&client, &channel_id);
+        if let Some(mut channel_data) = self.get(key).map(|v| {
+            serde_json::from_slice::<TowerChannelData>(v.as_ref())
+                .expect("deserialize TowerChannelData should be OK")
+        }) {
+            if channel_data
+                .revocation
+                .as_ref()
+                .is_some_and(|r| r.commitment_number > revocation.commitment_number)
+            {
+                return;
+            }
+            channel_data.revocation = Some(revocation);
+            let mut batch = self.batch();
+            batch.put_kv(KeyValue::TowerChannel(channel_data));
+            batch.commit();
+        }
+    }
+}
+
+impl SweeperStore for Store {
+    fn get_sweep_outputs(&self) -> Vec<SweepOutput> {
+        let prefix = vec![SWEEP_OUTPUT_PREFIX];
+        let iter = self
+            .db
+            .prefix_iterator(prefix.as_ref())
+            .take_while(|(col_key, _)| col_key.starts_with(&prefix));
+        iter.map(|(_key, value)| {
+            serde_json::from_slice(value.as_ref()).expect("deserialize SweepOutput should be OK")
+        })
+        .collect()
+    }
+
+    fn insert_sweep_output(&self, sweep_output: SweepOutput) {
+        let mut batch = self.batch();
+        batch.put_kv(KeyValue::SweepOutput(sweep_output.channel_id, sweep_output));
+        batch.commit();
+    }
+
+    fn remove_sweep_output(&self, channel_id: &Hash256) {
+        let key = [&[SWEEP_OUTPUT_PREFIX], channel_id.as_ref()].concat();
+        self.db.delete(key).expect("delete should be OK");
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cch::CchOrderStatus;
+    use crate::fiber::channel::InMemorySigner;
     use crate::fiber::config::AnnouncedNodeName;
+    use crate::fiber::graph::PaymentSessionStatus;
+    use crate::fiber::hash_algorithm::HashAlgorithm;
+    use crate::fiber::network::SendPaymentData;
     use crate::fiber::test_utils::gen_sha256_hash;
     use crate::fiber::types::ChannelAnnouncement;
     use crate::fiber::types::NodeAnnouncement;
     use crate::invoice::*;
+    use crate::sweeper::{SettlementData, SweepTlc};
     use crate::watchtower::*;
     use ckb_types::packed::Bytes;
     use ckb_types::packed::CellOutput;
@@ -649,8 +1170,139 @@ mod tests {
         assert_eq!(store.get_invoice(hash), Some(invoice.clone()));
         assert_eq!(store.get_invoice_preimage(hash), Some(preimage));
 
+        assert_eq!(store.get_invoices(), vec![invoice.clone()]);
+
+        // A hold invoice is inserted without the preimage, which is provided on settlement.
+        let hold_preimage = gen_sha256_hash();
+        let hold_invoice = InvoiceBuilder::new(Currency::Fibb)
+            .amount(Some(1280))
+            .payment_hash(HashAlgorithm::default().hash(hold_preimage.as_ref()).into())
+            .build()
+            .unwrap();
+        let hold_hash = hold_invoice.payment_hash();
+        store.insert_invoice(hold_invoice.clone(), None).unwrap();
+        assert_eq!(store.get_invoice_preimage(hold_hash), None);
+        store
+            .insert_invoice_preimage(hold_hash, hold_preimage)
+            .unwrap();
+        assert_eq!(store.get_invoice_preimage(hold_hash), Some(hold_preimage));
+
+        let held_payment = HeldPayment {
+            payment_hash: *hold_hash,
+            tlcs: vec![(gen_sha256_hash(), 0), (gen_sha256_hash(), 1)],
+            expires_at: 1000,
+        };
+        store.insert_held_payment(held_payment.clone());
+        assert_eq!(store.get_held_payments(), vec![held_payment]);
+        store.remove_held_payment(hold_hash);
+        assert_eq!(store.get_held_payments(), vec![]);
+
+        let pending_mpp_payment = PendingMppPayment {
+            payment_hash: *hold_hash,
+            total_amount: 1280,
+            preimage: None,
+            parts: vec![(gen_sha256_hash(), 0, 1000), (gen_sha256_hash(), 1, 200)],
+            first_received_at: 1000,
+            expires_at: 2000,
+        };
+        store.insert_pending_mpp_payment(pending_mpp_payment.clone());
+        assert_eq!(store.get_pending_mpp_payments(), vec![pending_mpp_payment]);
+        store.remove_pending_mpp_payment(hold_hash);
+        assert_eq!(store.get_pending_mpp_payments(), vec![]);
+
+        assert_eq!(store.get_invoice_status(hash), Some(CkbInvoiceStatus::Open));
+        store
+            .update_invoice_status(hash, CkbInvoiceStatus::Paid)
+            .unwrap();
+        assert_eq!(store.get_invoice_status(hash), Some(CkbInvoiceStatus::Paid));
+
+        // An invoice without the saved status is open.
+        store
+            .db
+            .delete([&[CKB_INVOICE_STATUS_PREFIX], hash.as_ref()].concat())
+            .unwrap();
+        assert_eq!(store.get_invoice_status(hash), Some(CkbInvoiceStatus::Open));
+
         let invalid_hash = gen_sha256_hash();
         assert_eq!(store.get_invoice_preimage(&invalid_hash), None);
+        assert_eq!(store.get_invoice_status(&invalid_hash), None);
+        assert_eq!(
+            store.update_invoice_status(&invalid_hash, CkbInvoiceStatus::Cancelled),
+            Err(InvoiceError::InvoiceNotFound(invalid_hash.to_string()))
+        );
+    }
+
+    #[test]
+    fn test_store_cch_orders() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("cch_order_store");
+        let store = Store::new(path);
+
+        let payment_hash = format!("{:#x}", gen_sha256_hash());
+        let mut send_btc_order = SendBTCOrder {
+            created_at: 0,
+            expires_after: 3600,
+            ckb_final_tlc_expiry: 100,
+            currency: Currency::Fibb,
+            wrapped_btc_type_script: Default::default(),
+            btc_pay_req: "lnbc".to_string(),
+            ckb_pay_req: "fibb".to_string(),
+            payment_hash: payment_hash.clone(),
+            payment_preimage: None,
+            channel_id: None,
+            tlc_id: None,
+            amount_sats: 1000,
+            fee_sats: 10,
+            status: CchOrderStatus::Pending,
+        };
+        assert!(matches!(
+            store.update_send_btc_order(send_btc_order.clone()),
+            Err(CchDbError::NotFound(_))
+        ));
+        store.insert_send_btc_order(send_btc_order.clone()).unwrap();
+        assert!(matches!(
+            store.insert_send_btc_order(send_btc_order.clone()),
+            Err(CchDbError::Duplicated(_))
+        ));
+        send_btc_order.status = CchOrderStatus::InFlight;
+        store.update_send_btc_order(send_btc_order.clone()).unwrap();
+        assert_eq!(
+            store.get_send_btc_order(&payment_hash).unwrap().status,
+            CchOrderStatus::InFlight
+        );
+        assert_eq!(store.get_send_btc_orders().len(), 1);
+
+        // The receive orders are stored separately from the send orders.
+        assert!(matches!(
+            store.get_receive_btc_order(&payment_hash),
+            Err(CchDbError::NotFound(_))
+        ));
+        let receive_btc_order = ReceiveBTCOrder {
+            created_at: 0,
+            expires_after: 3600,
+            ckb_final_tlc_expiry: 100,
+            wrapped_btc_type_script: Default::default(),
+            btc_pay_req: "lnbc".to_string(),
+            payment_hash: payment_hash.clone(),
+            payment_preimage: None,
+            channel_id: gen_sha256_hash(),
+            tlc_id: None,
+            amount_sats: 1000,
+            fee_sats: 10,
+            status: CchOrderStatus::Pending,
+        };
+        store
+            .insert_receive_btc_order(receive_btc_order.clone())
+            .unwrap();
+        assert_eq!(
+            store
+                .get_receive_btc_order(&payment_hash)
+                .unwrap()
+                .channel_id,
+            receive_btc_order.channel_id
+        );
+        assert_eq!(store.get_receive_btc_orders().len(), 1);
+        assert_eq!(store.get_send_btc_orders().len(), 1);
     }
 
     #[test]
@@ -717,6 +1369,28 @@ mod tests {
         assert_eq!(res, nodes[1..=3]);
     }
 
+    #[test]
+    fn test_store_banned_peers() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("peer_store");
+        let store = Store::new(path);
+
+        let peer_id1 = PeerId::random();
+        let peer_id2 = PeerId::random();
+        store.insert_banned_peer(peer_id1.clone(), 100);
+        store.insert_banned_peer(peer_id2.clone(), u64::MAX);
+        store.insert_banned_peer(peer_id1.clone(), 200);
+
+        let banned_peers: std::collections::HashMap<_, _> =
+            store.get_banned_peers().into_iter().collect();
+        assert_eq!(banned_peers.len(), 2);
+        assert_eq!(banned_peers.get(&peer_id1), Some(&200));
+        assert_eq!(banned_peers.get(&peer_id2), Some(&u64::MAX));
+
+        store.remove_banned_peer(&peer_id1);
+        assert_eq!(store.get_banned_peers(), vec![(peer_id2, u64::MAX)]);
+    }
+
     #[test]
     fn test_store_wacthtower() {
         let dir = tempdir().unwrap();
@@ -755,4 +1429,159 @@ mod tests {
         store.remove_watch_channel(channel_id);
         assert_eq!(store.get_watch_channels(), vec![]);
     }
+
+    #[test]
+    fn test_store_tower() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("tower_store");
+        let store = Store::new(path);
+
+        let client: Pubkey = gen_rand_public_key().into();
+        let channel_id = gen_sha256_hash();
+        let funding_tx_lock = Script::default();
+        store.insert_tower_channel(client, channel_id, funding_tx_lock.clone());
+        let mut channel_data = TowerChannelData {
+            client,
+            channel_id,
+            funding_tx_lock,
+            revocation: None,
+        };
+        assert_eq!(store.get_tower_channels(), vec![channel_data.clone()]);
+
+        let revocation = EncryptedRevocationData {
+            commitment_number: 2,
+            blob: vec![1, 2, 3],
+        };
+        store.update_tower_revocation(client, channel_id, revocation.clone());
+        channel_data.revocation = Some(revocation);
+        assert_eq!(store.get_tower_channels(), vec![channel_data.clone()]);
+
+        // Watching the channel again updates the funding lock and keeps the revocation.
+        let funding_tx_lock = Script::new_builder().args(vec![1u8; 20].pack()).build();
+        store.insert_tower_channel(client, channel_id, funding_tx_lock.clone());
+        channel_data.funding_tx_lock = funding_tx_lock;
+        assert_eq!(store.get_tower_channels(), vec![channel_data.clone()]);
+
+        // An older revocation is ignored.
+        store.update_tower_revocation(
+            client,
+            channel_id,
+            EncryptedRevocationData {
+                commitment_number: 1,
+                blob: vec![4, 5, 6],
+            },
+        );
+        assert_eq!(store.get_tower_channels(), vec![channel_data.clone()]);
+
+        // The revocation of the same commitment number is replaced.
+        let revocation = EncryptedRevocationData {
+            commitment_number: 2,
+            blob: vec![7, 8, 9],
+        };
+        store.update_tower_revocation(client, channel_id, revocation.clone());
+        channel_data.revocation = Some(revocation);
+        assert_eq!(store.get_tower_channels(), vec![channel_data]);
+
+        store.remove_tower_channel(client, channel_id);
+        assert_eq!(store.get_tower_channels(), vec![]);
+    }
+
+    #[test]
+    fn test_store_sweep_output() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("sweeper_store");
+        let store = Store::new(path);
+
+        let channel_id = gen_sha256_hash();
+        let mut sweep_output = SweepOutput {
+            channel_id,
+            commitment_out_point: OutPoint::default(),
+            commitment_lock: Script::default(),
+            shutdown_script: Script::default(),
+            settlement: SettlementData {
+                commitment_number: 1,
+                x_only_aggregated_pubkey: [0u8; 32],
+                aggregated_signature: CompactSignature::from_bytes(&[0u8; 64]).unwrap(),
+                outputs: vec![CellOutput::default(), CellOutput::default()],
+                outputs_data: vec![Bytes::default(), Bytes::default()],
+                tlcs: vec![SweepTlc {
+                    entry: vec![1u8; 111],
+                    payment_hash: gen_sha256_hash(),
+                    amount: 100,
+                    offered: true,
+                    key_commitment_number: 1,
+                    preimage: None,
+                }],
+            },
+            signer: InMemorySigner::generate_from_seed(&[1u8; 32]),
+        };
+        store.insert_sweep_output(sweep_output.clone());
+        let sweep_outputs = store.get_sweep_outputs();
+        assert_eq!(sweep_outputs.len(), 1);
+        assert_eq!(sweep_outputs[0].settlement, sweep_output.settlement);
+        assert!(sweep_outputs[0].signer == sweep_output.signer);
+
+        // The tlc is resolved on chain.
+        sweep_output.settlement.tlcs.clear();
+        store.insert_sweep_output(sweep_output.clone());
+        let sweep_outputs = store.get_sweep_outputs();
+        assert_eq!(sweep_outputs.len(), 1);
+        assert_eq!(sweep_outputs[0].settlement.tlcs, vec![]);
+
+        store.remove_sweep_output(&channel_id);
+        assert!(store.get_sweep_outputs().is_empty());
+    }
+
+    #[test]
+    fn test_store_payment_session() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("payment_session_store");
+        let store = Store::new(path);
+
+        let payment_hash = gen_sha256_hash();
+        let payment_data = SendPaymentData {
+            target_pubkey: gen_rand_public_key().into(),
+            amount: 100,
+            payment_hash,
+            invoice: None,
+            final_cltv_delta: None,
+            timeout: None,
+            max_fee_amount: None,
+            max_parts: None,
+            keysend: false,
+            udt_type_script: None,
+            preimage: None,
+            probe: false,
+        };
+        let mut session = PaymentSession::new(payment_data, 3);
+        store.insert_payment_session(session.clone());
+        let res = store.get_payment_session(payment_hash).unwrap();
+        assert_eq!(res.status, PaymentSessionStatus::Created);
+        assert_eq!(res.retried_times, 0);
+
+        session.retried_times = 1;
+        session.mark_channel_failed(mock_channel().out_point());
+        session.set_failed_status("no path found");
+        store.insert_payment_session(session.clone());
+        let res = store.get_payment_session(payment_hash).unwrap();
+        assert_eq!(res.status, PaymentSessionStatus::Failed);
+        assert_eq!(res.retried_times, 1);
+        assert_eq!(res.failed_channels, session.failed_channels);
+        assert_eq!(res.last_error, Some("no path found".to_string()));
+
+        assert_eq!(store.get_payment_sessions().len(), 1);
+        assert!(store.get_payment_session(gen_sha256_hash()).is_none());
+
+        // The sessions saved in an older format are skipped.
+        let old_payment_hash = gen_sha256_hash();
+        store
+            .db
+            .put(
+                [&[PAYMENT_SESSION_PREFIX], old_payment_hash.as_ref()].concat(),
+                b"{\"request\":{}}",
+            )
+            .unwrap();
+        assert!(store.get_payment_session(old_payment_hash).is_none());
+        assert_eq!(store.get_payment_sessions().len(), 1);
+    }
 }
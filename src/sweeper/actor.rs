@@ -0,0 +1,695 @@
+use ckb_hash::blake2b_256;
+use ckb_types::{
+    self,
+    core::{Capacity, TransactionView},
+    packed::{Byte32, Bytes, CellInput, CellOutput, OutPoint, Script, Transaction},
+    prelude::*,
+};
+use ractor::{Actor, ActorProcessingErr, ActorRef};
+use thiserror::Error;
+use tracing::{debug, error, info, trace};
+
+use crate::{
+    ckb::{
+        contracts::{get_cell_deps, CommitmentLockArgs, Contract},
+        ChainSource, ChainSourceError,
+    },
+    fiber::types::Hash256,
+    invoice::InvoiceStore,
+    NetworkServiceEvent,
+};
+
+use super::{store::SweepTlc, SweepOutput, SweeperStore};
+
+// The length of an encoded tlc in the commitment lock args, see `TLC::encode_htlc`.
+const TLC_ENTRY_LEN: usize = 111;
+
+const EMPTY_WITNESS_ARGS: [u8; 16] = [16, 0, 0, 0, 16, 0, 0, 0, 16, 0, 0, 0, 16, 0, 0, 0];
+
+#[derive(Error, Debug)]
+enum SweepError {
+    #[error("Chain source error: {0}")]
+    ChainSource(#[from] ChainSourceError),
+
+    #[error("Invalid commitment cell: {0}")]
+    InvalidCommitmentCell(String),
+}
+
+/// The sweeper claims our funds from the commitment cells of force closed channels.
+/// Tlcs offered by us are claimed after they expire, and tlcs received by us are claimed
+/// with the preimages. The commitment cell is settled after the tlcs claimable by us are
+/// resolved and the contest delay is passed.
+pub struct SweeperActor<S, C> {
+    store: S,
+    chain_source: C,
+}
+
+impl<S: SweeperStore + InvoiceStore, C: ChainSource> SweeperActor<S, C> {
+    pub fn new(store: S, chain_source: C) -> Self {
+        Self {
+            store,
+            chain_source,
+        }
+    }
+}
+
+pub enum SweeperMessage {
+    NetworkServiceEvent(NetworkServiceEvent),
+    PeriodicCheck,
+}
+
+pub struct SweeperState {
+    // The fee rate of the sweeping transactions, in shannons per kilo-bytes.
+    fee_rate: u64,
+}
+
+#[ractor::async_trait]
+impl<S, C> Actor for SweeperActor<S, C>
+where
+    S: SweeperStore + InvoiceStore + Send + Sync + 'static,
+    C: ChainSource + 'static,
+{
+    type Msg = SweeperMessage;
+    type State = SweeperState;
+    type Arguments = u64;
+
+    async fn pre_start(
+        &self,
+        _myself: ActorRef<Self::Msg>,
+        fee_rate: Self::Arguments,
+    ) -> Result<Self::State, ActorProcessingErr> {
+        Ok(Self::State { fee_rate })
+    }
+
+    async fn handle(
+        &self,
+        _myself: ActorRef<Self::Msg>,
+        message: Self::Msg,
+        state: &mut Self::State,
+    ) -> Result<(), ActorProcessingErr> {
+        match message {
+            SweeperMessage::NetworkServiceEvent(event) => {
+                trace!("Received NetworkServiceEvent: {:?}", event);
+                match event {
+                    NetworkServiceEvent::CommitmentTransactionConfirmed(
+                        _peer_id,
+                        channel_id,
+                        sweep_output,
+                    ) => {
+                        info!(
+                            "Start sweeping the commitment cell {:?} of channel {:?}",
+                            sweep_output.commitment_out_point, channel_id
+                        );
+                        self.store.insert_sweep_output(sweep_output);
+                    }
+                    NetworkServiceEvent::TlcPreimageReceived(payment_hash, preimage) => {
+                        // The channel of the received tlc may be force closed before the
+                        // preimage is learned from the next hop.
+                        for mut sweep_output in self.store.get_sweep_outputs() {
+                            if add_preimages(&mut sweep_output, |hash| {
+                                (*hash == payment_hash).then_some(preimage)
+                            }) {
+                                self.store.insert_sweep_output(sweep_output);
+                            }
+                        }
+                    }
+                    _ => {
+                        // ignore
+                    }
+                }
+            }
+            SweeperMessage::PeriodicCheck => {
+                for sweep_output in self.store.get_sweep_outputs() {
+                    let channel_id = sweep_output.channel_id;
+                    if let Err(err) = self.sweep(sweep_output, state.fee_rate).await {
+                        error!(
+                            "Failed to sweep the commitment cell of channel {:?}: {:?}",
+                            channel_id, err
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<S: SweeperStore + InvoiceStore, C: ChainSource> SweeperActor<S, C> {
+    async fn sweep(&self, mut sweep_output: SweepOutput, fee_rate: u64) -> Result<(), SweepError> {
+        let channel_id = sweep_output.channel_id;
+
+        // Follow the tlc claiming transactions to the live commitment cell, the pending tlcs
+        // of the previous commitment cell are included in the witness which created the next one.
+        while let Some(tx) = self
+            .chain_source
+            .get_spending_transaction(sweep_output.commitment_lock.clone())
+            .await?
+        {
+            if !tx
+                .input_pts_iter()
+                .any(|out_point| out_point == sweep_output.commitment_out_point)
+            {
+                break;
+            }
+            match get_next_commitment_cell(&sweep_output.commitment_lock, &tx)? {
+                Some((out_point, lock, remaining)) => {
+                    sweep_output
+                        .settlement
+                        .tlcs
+                        .retain(|tlc| remaining.contains(&tlc.entry));
+                    sweep_output.commitment_out_point = out_point;
+                    sweep_output.commitment_lock = lock;
+                    self.store.insert_sweep_output(sweep_output.clone());
+                }
+                None => {
+                    info!(
+                        "The commitment cell of channel {:?} is consumed, stop sweeping it",
+                        channel_id
+                    );
+                    self.store.remove_sweep_output(&channel_id);
+                    return Ok(());
+                }
+            }
+        }
+
+        // The hold invoices may be settled after the channel is force closed.
+        if add_preimages(&mut sweep_output, |payment_hash| {
+            self.store.get_invoice_preimage(payment_hash)
+        }) {
+            self.store.insert_sweep_output(sweep_output.clone());
+        }
+
+        let out_point = sweep_output.commitment_out_point.clone();
+        let Some(tx) = self
+            .chain_source
+            .get_transaction(out_point.tx_hash())
+            .await?
+        else {
+            debug!(
+                "The commitment cell {:?} of channel {:?} is not committed yet",
+                out_point, channel_id
+            );
+            return Ok(());
+        };
+        let index: u32 = out_point.index().unpack();
+        let output = tx.output(index as usize).ok_or_else(|| {
+            SweepError::InvalidCommitmentCell(format!("Cannot find output {:?}", out_point))
+        })?;
+        let output_data = tx
+            .outputs_data()
+            .get(index as usize)
+            .map(|data| data.raw_data())
+            .unwrap_or_default();
+        let commitment_cell = (out_point, output, output_data);
+
+        // Claim the tlcs with preimages first, as they may be timed out by the peer.
+        let mut claimable_tlcs = (0..sweep_output.settlement.tlcs.len())
+            .filter(|&index| {
+                let tlc = &sweep_output.settlement.tlcs[index];
+                tlc.offered || tlc.preimage.is_some()
+            })
+            .collect::<Vec<_>>();
+        claimable_tlcs.sort_by_key(|&index| sweep_output.settlement.tlcs[index].offered);
+
+        if claimable_tlcs.is_empty() {
+            let (tx, witness) = build_settlement_tx(&sweep_output, &commitment_cell)?;
+            // The settlement tx is rejected until the contest delay is passed.
+            match self.send_sweep_tx(tx, |_| witness.clone(), fee_rate).await {
+                Ok(tx_hash) => info!(
+                    "Settlement tx {:?} sent for the commitment cell of channel {:?}",
+                    tx_hash, channel_id
+                ),
+                Err(err) => debug!(
+                    "Unable to settle the commitment cell of channel {:?} now: {:?}",
+                    channel_id, err
+                ),
+            }
+            return Ok(());
+        }
+
+        for index in claimable_tlcs {
+            let tx = build_tlc_claim_tx(&sweep_output, index, &commitment_cell)?;
+            let build_witness =
+                |tx: &TransactionView| build_tlc_claim_witness(&sweep_output, index, tx);
+            // The tlc offered by us is rejected until it expires, try the next one.
+            match self.send_sweep_tx(tx, build_witness, fee_rate).await {
+                Ok(tx_hash) => {
+                    info!(
+                        "Tlc claiming tx {:?} sent for the commitment cell of channel {:?}",
+                        tx_hash, channel_id
+                    );
+                    break;
+                }
+                Err(err) => debug!(
+                    "Unable to claim tlc {:?} of channel {:?} now: {:?}",
+                    sweep_output.settlement.tlcs[index].payment_hash, channel_id, err
+                ),
+            }
+        }
+        Ok(())
+    }
+
+    // Pay the fee of the tx spending the commitment cell and send it. The witness of the
+    // commitment cell is built again after the fee is paid, as the tx hash is changed
+    // by the fee inputs and the change output.
+    async fn send_sweep_tx<F>(
+        &self,
+        tx: TransactionView,
+        build_witness: F,
+        fee_rate: u64,
+    ) -> Result<Byte32, ChainSourceError>
+    where
+        F: Fn(&TransactionView) -> Vec<u8> + Send + Sync,
+    {
+        let tx = tx
+            .as_advanced_builder()
+            .set_witnesses(vec![build_witness(&tx).pack()])
+            .build();
+        let tx = self.chain_source.fund_fee(tx, fee_rate).await?;
+        let mut witnesses = tx.witnesses().into_iter().collect::<Vec<_>>();
+        witnesses[0] = build_witness(&tx).pack();
+        let tx = tx.as_advanced_builder().set_witnesses(witnesses).build();
+        let tx_hash = tx.hash();
+        self.chain_source.send_transaction(tx).await?;
+        Ok(tx_hash)
+    }
+}
+
+// Set the preimages of the received tlcs which are unknown yet, returns whether any is set.
+fn add_preimages<F>(sweep_output: &mut SweepOutput, get_preimage: F) -> bool
+where
+    F: Fn(&Hash256) -> Option<Hash256>,
+{
+    let mut updated = false;
+    for tlc in sweep_output
+        .settlement
+        .tlcs
+        .iter_mut()
+        .filter(|tlc| !tlc.offered && tlc.preimage.is_none())
+    {
+        if let Some(preimage) = get_preimage(&tlc.payment_hash) {
+            tlc.preimage = Some(preimage);
+            updated = true;
+        }
+    }
+    updated
+}
+
+// Get the commitment cell created by the tx consuming the previous one. A tlc claiming tx creates
+// the new commitment cell as the first output, the args of the commitment lock are updated but
+// the first 36 bytes (the aggregated pubkey hash, the delay and the version) are unchanged.
+// Returns None if the commitment cell is settled.
+fn get_next_commitment_cell(
+    commitment_lock: &Script,
+    tx: &TransactionView,
+) -> Result<Option<(OutPoint, Script, Vec<Vec<u8>>)>, SweepError> {
+    let args = commitment_lock.args().raw_data();
+    let Some(lock) = tx.output(0).map(|output| output.lock()) else {
+        return Ok(None);
+    };
+    let next_args = lock.args().raw_data();
+    if lock.code_hash() != commitment_lock.code_hash()
+        || lock.hash_type() != commitment_lock.hash_type()
+        || next_args.len() < 36
+        || next_args[0..36] != args[0..36]
+    {
+        return Ok(None);
+    }
+    let witness = tx
+        .witnesses()
+        .get(0)
+        .map(|witness| witness.raw_data())
+        .unwrap_or_default();
+    let remaining = get_remaining_tlc_entries(&witness).ok_or_else(|| {
+        SweepError::InvalidCommitmentCell(format!(
+            "Invalid commitment lock witness in tx {:?}",
+            tx.hash()
+        ))
+    })?;
+    Ok(Some((OutPoint::new(tx.hash(), 0), lock, remaining)))
+}
+
+// Get the entries of the tlcs remaining in the commitment cell created by a tlc claiming tx,
+// the witness is `empty_witness_args | unlock index | pending tlcs | signature | preimage`.
+fn get_remaining_tlc_entries(witness: &[u8]) -> Option<Vec<Vec<u8>>> {
+    let index = *witness.get(16)? as usize;
+    let count = *witness.get(17)? as usize;
+    let entries = witness.get(18..18 + count * TLC_ENTRY_LEN)?;
+    Some(
+        entries
+            .chunks(TLC_ENTRY_LEN)
+            .enumerate()
+            .filter(|(i, _)| *i != index)
+            .map(|(_, entry)| entry.to_vec())
+            .collect(),
+    )
+}
+
+// The pending tlcs in the commitment lock args and witness.
+fn encode_tlcs<'a>(tlcs: impl Iterator<Item = &'a SweepTlc>) -> Vec<u8> {
+    let entries = tlcs.map(|tlc| tlc.entry.as_slice()).collect::<Vec<_>>();
+    if entries.is_empty() {
+        Vec::new()
+    } else {
+        [&[entries.len() as u8], entries.concat().as_slice()].concat()
+    }
+}
+
+// Claim a tlc from the commitment cell, the remaining funds are locked in a new commitment cell
+// without this tlc. A tlc offered by us is claimed after it expires, and a tlc received by us is
+// claimed with the preimage. The witness is built by `build_tlc_claim_witness` after the fee is paid.
+fn build_tlc_claim_tx(
+    sweep_output: &SweepOutput,
+    index: usize,
+    commitment_cell: &(OutPoint, CellOutput, ckb_types::bytes::Bytes),
+) -> Result<TransactionView, SweepError> {
+    let (out_point, output, output_data) = commitment_cell;
+    let tlcs = &sweep_output.settlement.tlcs;
+    let tlc = &tlcs[index];
+
+    let args = output.lock().args().raw_data();
+    let remaining_tlcs = encode_tlcs(
+        tlcs.iter()
+            .enumerate()
+            .filter(|(i, _)| *i != index)
+            .map(|(_, tlc)| tlc),
+    );
+    let new_args = if remaining_tlcs.is_empty() {
+        args[0..36].to_vec()
+    } else {
+        [&args[0..36], &blake2b_256(&remaining_tlcs)[0..20]].concat()
+    };
+    let new_lock = output.lock().as_builder().args(new_args.pack()).build();
+    let capacity: u64 = output.capacity().unpack();
+    let insufficient_funds = || {
+        SweepError::InvalidCommitmentCell(format!(
+            "Insufficient funds in {:?} to claim tlc {:?}",
+            out_point, tlc.payment_hash
+        ))
+    };
+
+    let (new_commitment_output, claim_output) = match output.type_().to_opt() {
+        Some(type_script) => {
+            let udt_amount = output_data
+                .get(0..16)
+                .map(|data| u128::from_le_bytes(data.try_into().expect("16 bytes")))
+                .ok_or_else(|| {
+                    SweepError::InvalidCommitmentCell(format!(
+                        "Invalid udt data of {:?}",
+                        out_point
+                    ))
+                })?;
+            let new_commitment_output = (
+                output.clone().as_builder().lock(new_lock).build(),
+                udt_amount
+                    .checked_sub(tlc.amount)
+                    .ok_or_else(insufficient_funds)?
+                    .to_le_bytes()
+                    .pack(),
+            );
+            let claim_output = CellOutput::new_builder()
+                .lock(sweep_output.shutdown_script.clone())
+                .type_(Some(type_script).pack())
+                .build();
+            let claim_output_data = tlc.amount.to_le_bytes().pack();
+            let occupied_capacity = claim_output
+                .occupied_capacity(Capacity::bytes(claim_output_data.len()).unwrap())
+                .unwrap()
+                .as_u64();
+            (
+                new_commitment_output,
+                (
+                    claim_output
+                        .as_builder()
+                        .capacity(occupied_capacity.pack())
+                        .build(),
+                    claim_output_data,
+                ),
+            )
+        }
+        None => {
+            let tlc_amount = u64::try_from(tlc.amount).map_err(|_| insufficient_funds())?;
+            let new_commitment_output = (
+                output
+                    .clone()
+                    .as_builder()
+                    .lock(new_lock)
+                    .capacity(
+                        capacity
+                            .checked_sub(tlc_amount)
+                            .ok_or_else(insufficient_funds)?
+                            .pack(),
+                    )
+                    .build(),
+                Bytes::default(),
+            );
+            let claim_output = CellOutput::new_builder()
+                .lock(sweep_output.shutdown_script.clone())
+                .build();
+            let occupied_capacity = claim_output
+                .occupied_capacity(Capacity::zero())
+                .unwrap()
+                .as_u64();
+            (
+                new_commitment_output,
+                (
+                    claim_output
+                        .as_builder()
+                        .capacity(tlc_amount.max(occupied_capacity).pack())
+                        .build(),
+                    Bytes::default(),
+                ),
+            )
+        }
+    };
+
+    // The expiry of the tlc is the last 8 bytes of the entry.
+    let since = if tlc.offered {
+        u64::from_le_bytes(
+            tlc.entry
+                .get(TLC_ENTRY_LEN - 8..TLC_ENTRY_LEN)
+                .and_then(|expiry| expiry.try_into().ok())
+                .ok_or_else(|| {
+                    SweepError::InvalidCommitmentCell(format!(
+                        "Invalid entry of tlc {:?}",
+                        tlc.payment_hash
+                    ))
+                })?,
+        )
+    } else {
+        0
+    };
+    let (outputs, outputs_data): (Vec<_>, Vec<_>) = vec![new_commitment_output, claim_output]
+        .into_iter()
+        .unzip();
+
+    Ok(Transaction::default()
+        .as_advanced_builder()
+        .cell_deps(get_cell_deps(
+            vec![Contract::CommitmentLock],
+            &output.type_().to_opt(),
+        ))
+        .input(CellInput::new(out_point.clone(), since))
+        .outputs(outputs)
+        .outputs_data(outputs_data)
+        .build())
+}
+
+// The witness to claim a tlc is signed by our tlc key over the tx hash.
+fn build_tlc_claim_witness(
+    sweep_output: &SweepOutput,
+    index: usize,
+    tx: &TransactionView,
+) -> Vec<u8> {
+    let tlcs = &sweep_output.settlement.tlcs;
+    let tlc = &tlcs[index];
+    let pending_tlcs = encode_tlcs(tlcs.iter());
+    let tlc_key = sweep_output
+        .signer
+        .derive_tlc_key(tlc.key_commitment_number);
+    let message: [u8; 32] = tx.hash().as_slice().try_into().expect("valid tx hash");
+    let signature = tlc_key.sign_ecdsa_recoverable(&message);
+    let preimage = tlc
+        .preimage
+        .filter(|_| !tlc.offered)
+        .map(|preimage| preimage.as_ref().to_vec())
+        .unwrap_or_default();
+    [
+        EMPTY_WITNESS_ARGS.as_slice(),
+        &[index as u8],
+        pending_tlcs.as_slice(),
+        signature.as_slice(),
+        preimage.as_slice(),
+    ]
+    .concat()
+}
+
+// Settle the commitment cell with the settlement transaction signed by both parties,
+// which is only valid after the contest delay. Returns the tx and its witness.
+fn build_settlement_tx(
+    sweep_output: &SweepOutput,
+    commitment_cell: &(OutPoint, CellOutput, ckb_types::bytes::Bytes),
+) -> Result<(TransactionView, Vec<u8>), SweepError> {
+    let (out_point, output, _output_data) = commitment_cell;
+    let settlement = &sweep_output.settlement;
+    let delay = CommitmentLockArgs::decode(&output.lock().args().raw_data())
+        .map(|args| args.delay)
+        .ok_or_else(|| {
+            SweepError::InvalidCommitmentCell(format!("Invalid lock args of {:?}", out_point))
+        })?;
+    let witness = [
+        EMPTY_WITNESS_ARGS.as_slice(),
+        &[0xFF],
+        settlement.commitment_number.to_be_bytes().as_slice(),
+        settlement.x_only_aggregated_pubkey.as_slice(),
+        settlement.aggregated_signature.serialize().as_slice(),
+    ]
+    .concat();
+
+    let tx = Transaction::default()
+        .as_advanced_builder()
+        .cell_deps(get_cell_deps(
+            vec![Contract::CommitmentLock],
+            &output.type_().to_opt(),
+        ))
+        .input(CellInput::new(out_point.clone(), delay))
+        .outputs(settlement.outputs.clone())
+        .outputs_data(settlement.outputs_data.clone())
+        .build();
+    Ok((tx, witness))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ckb_types::core::FeeRate;
+    use ractor::call;
+    use tempfile::tempdir;
+
+    use crate::{
+        fiber::{
+            channel::{
+                tests::create_nodes_with_established_channel, ChannelCommand, ChannelCommandWithId,
+                ShutdownCommand, DEFAULT_COMMITMENT_FEE_RATE,
+            },
+            test_utils::init_tracing,
+            NetworkActorCommand, NetworkActorMessage,
+        },
+        store::Store,
+    };
+
+    fn sweep_tlc(entry: u8) -> SweepTlc {
+        SweepTlc {
+            entry: vec![entry; TLC_ENTRY_LEN],
+            payment_hash: Default::default(),
+            amount: 100,
+            offered: true,
+            key_commitment_number: 0,
+            preimage: None,
+        }
+    }
+
+    #[test]
+    fn test_get_remaining_tlc_entries() {
+        let tlcs = [sweep_tlc(1), sweep_tlc(2), sweep_tlc(3)];
+        let witness = [
+            EMPTY_WITNESS_ARGS.as_slice(),
+            &[1u8],
+            encode_tlcs(tlcs.iter()).as_slice(),
+            &[0u8; 65],
+        ]
+        .concat();
+        assert_eq!(
+            get_remaining_tlc_entries(&witness),
+            Some(vec![tlcs[0].entry.clone(), tlcs[2].entry.clone()])
+        );
+        assert_eq!(get_remaining_tlc_entries(&witness[0..100]), None);
+    }
+
+    #[tokio::test]
+    async fn test_sweeper_settle_force_closed_channel() {
+        init_tracing();
+
+        let (mut node_a, node_b, channel_id) =
+            create_nodes_with_established_channel(100000000000, 6200000000, false).await;
+        call!(node_a.network_actor, |rpc_reply| {
+            NetworkActorMessage::Command(NetworkActorCommand::ControlFiberChannel(
+                ChannelCommandWithId {
+                    channel_id,
+                    command: ChannelCommand::Shutdown(
+                        ShutdownCommand {
+                            close_script: Script::default(),
+                            fee_rate: FeeRate::from_u64(DEFAULT_COMMITMENT_FEE_RATE),
+                            force: true,
+                        },
+                        rpc_reply,
+                    ),
+                },
+            ))
+        })
+        .expect("node_a alive")
+        .expect("force close channel");
+        let sweep_output = node_a
+            .expect_to_process_event(|event| match event {
+                NetworkServiceEvent::CommitmentTransactionConfirmed(_, id, sweep_output)
+                    if *id == channel_id =>
+                {
+                    Some(sweep_output.clone())
+                }
+                _ => None,
+            })
+            .await;
+
+        let dir = tempdir().unwrap();
+        let store = Store::new(dir.path());
+        let (sweeper_actor, _handle) = Actor::spawn(
+            None,
+            SweeperActor::new(store.clone(), node_a.chain_actor.clone()),
+            1000,
+        )
+        .await
+        .expect("start sweeper actor");
+        for message in [
+            SweeperMessage::NetworkServiceEvent(
+                NetworkServiceEvent::CommitmentTransactionConfirmed(
+                    node_b.peer_id.clone(),
+                    channel_id,
+                    sweep_output.clone(),
+                ),
+            ),
+            SweeperMessage::PeriodicCheck,
+        ] {
+            sweeper_actor.send_message(message).expect("sweeper alive");
+        }
+
+        // The commitment cell without tlcs is settled with the settlement transaction.
+        let mut settlement_tx = None;
+        for _ in 0..50 {
+            settlement_tx = node_a
+                .chain_actor
+                .get_spending_transaction(sweep_output.commitment_lock.clone())
+                .await
+                .expect("get spending transaction");
+            if settlement_tx.is_some() {
+                break;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        }
+        let settlement_tx = settlement_tx.expect("settlement tx is sent");
+        assert_eq!(
+            settlement_tx.inputs().get(0).unwrap().previous_output(),
+            sweep_output.commitment_out_point
+        );
+        assert_eq!(
+            settlement_tx.outputs().into_iter().collect::<Vec<_>>(),
+            sweep_output.settlement.outputs
+        );
+
+        // The sweep output is removed once the commitment cell is consumed.
+        sweeper_actor
+            .send_message(SweeperMessage::PeriodicCheck)
+            .expect("sweeper alive");
+        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+        assert!(store.get_sweep_outputs().is_empty());
+    }
+}
@@ -0,0 +1,5 @@
+mod actor;
+mod store;
+
+pub use actor::{SweeperActor, SweeperMessage};
+pub use store::{SettlementData, SweepOutput, SweepTlc, SweeperStore};
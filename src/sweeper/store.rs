@@ -0,0 +1,79 @@
+use ckb_types::packed::{Bytes, CellOutput, OutPoint, Script};
+use musig2::CompactSignature;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::fiber::{channel::InMemorySigner, serde_utils::EntityHex, types::Hash256};
+
+pub trait SweeperStore {
+    /// Get the outputs of the confirmed commitment transactions that are not swept yet.
+    fn get_sweep_outputs(&self) -> Vec<SweepOutput>;
+    /// Insert or update the sweep output of a channel, the sweeper keeps claiming the funds
+    /// in the commitment cell until the output is removed.
+    fn insert_sweep_output(&self, sweep_output: SweepOutput);
+    /// Remove the sweep output of a channel after the commitment cell is consumed.
+    fn remove_sweep_output(&self, channel_id: &Hash256);
+}
+
+/// A tlc in the commitment cell of our latest commitment transaction.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SweepTlc {
+    /// The encoded tlc in the commitment lock args, which is also included in the witness
+    /// to unlock the commitment cell.
+    pub entry: Vec<u8>,
+    pub payment_hash: Hash256,
+    pub amount: u128,
+    /// Whether the tlc is offered by us. An offered tlc is claimed by us after it expires,
+    /// and a received tlc is claimed by us with the preimage.
+    pub offered: bool,
+    /// The commitment number to derive our tlc key from the channel signer.
+    pub key_commitment_number: u64,
+    pub preimage: Option<Hash256>,
+}
+
+/// The data to settle the commitment cell of our latest commitment transaction,
+/// which is saved when the commitment transaction is signed by the peer.
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SettlementData {
+    pub commitment_number: u64,
+    pub x_only_aggregated_pubkey: [u8; 32],
+    pub aggregated_signature: CompactSignature,
+    #[serde_as(as = "Vec<EntityHex>")]
+    pub outputs: Vec<CellOutput>,
+    #[serde_as(as = "Vec<EntityHex>")]
+    pub outputs_data: Vec<Bytes>,
+    /// The tlcs in the commitment cell, they are removed once resolved on chain.
+    pub tlcs: Vec<SweepTlc>,
+}
+
+/// The commitment cell of a force closed channel which the sweeper claims our funds from.
+#[serde_as]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SweepOutput {
+    pub channel_id: Hash256,
+    /// The commitment cell to sweep, it's replaced by the new commitment cell once a tlc is claimed.
+    #[serde_as(as = "EntityHex")]
+    pub commitment_out_point: OutPoint,
+    /// The lock script of the commitment cell to sweep, it's replaced along with the out point.
+    #[serde_as(as = "EntityHex")]
+    pub commitment_lock: Script,
+    /// The lock script to receive the claimed tlcs.
+    #[serde_as(as = "EntityHex")]
+    pub shutdown_script: Script,
+    pub settlement: SettlementData,
+    pub signer: InMemorySigner,
+}
+
+// The signer is omitted as it contains the private keys of the channel.
+impl std::fmt::Debug for SweepOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SweepOutput")
+            .field("channel_id", &self.channel_id)
+            .field("commitment_out_point", &self.commitment_out_point)
+            .field("commitment_lock", &self.commitment_lock)
+            .field("shutdown_script", &self.shutdown_script)
+            .field("settlement", &self.settlement)
+            .finish_non_exhaustive()
+    }
+}
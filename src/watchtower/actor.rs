@@ -1,41 +1,36 @@
-use anyhow::anyhow;
-use ckb_hash::new_blake2b;
-use ckb_jsonrpc_types::{Either, Status};
-use ckb_sdk::{
-    rpc::ckb_indexer::{CellType, Order, ScriptType, SearchKey, SearchMode, Tx},
-    traits::{CellCollector, CellQueryOptions, DefaultCellCollector, ValueRangeOption},
-    transaction::builder::FeeCalculator,
-    util::blake160,
-    CkbRpcClient, RpcError,
-};
 use ckb_types::{
-    self,
-    core::{Capacity, TransactionView},
-    packed::{Bytes, CellInput, CellOutput, OutPoint, Transaction, WitnessArgs},
+    core::TransactionView,
+    packed::{CellInput, OutPoint, Script, Transaction},
     prelude::*,
 };
-use molecule::prelude::Entity;
 use ractor::{Actor, ActorProcessingErr, ActorRef};
-use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
 use tracing::{error, info, trace, warn};
 
 use crate::{
     ckb::{
-        contracts::{get_cell_deps, get_script_by_contract, Contract},
-        CkbConfig,
+        contracts::{get_cell_deps, CommitmentLockArgs, Contract},
+        ChainSource, ChainSourceError,
     },
+    fiber::types::Hash256,
     NetworkServiceEvent,
 };
 
 use super::{store::RevocationData, WatchtowerStore};
 
-pub struct WatchtowerActor<S> {
+// TODO: move it to config or use https://github.com/nervosnetwork/ckb/pull/4477
+const REVOCATION_TX_FEE_RATE: u64 = 1000;
+
+pub struct WatchtowerActor<S, C> {
     store: S,
+    chain_source: C,
 }
 
-impl<S: WatchtowerStore> WatchtowerActor<S> {
-    pub fn new(store: S) -> Self {
-        Self { store }
+impl<S: WatchtowerStore, C: ChainSource> WatchtowerActor<S, C> {
+    pub fn new(store: S, chain_source: C) -> Self {
+        Self {
+            store,
+            chain_source,
+        }
     }
 }
 
@@ -44,34 +39,29 @@ pub enum WatchtowerMessage {
     PeriodicCheck,
 }
 
-pub struct WatchtowerState {
-    config: CkbConfig,
-    secret_key: SecretKey,
-}
-
 #[ractor::async_trait]
-impl<S> Actor for WatchtowerActor<S>
+impl<S, C> Actor for WatchtowerActor<S, C>
 where
     S: WatchtowerStore + Send + Sync + 'static,
+    C: ChainSource + 'static,
 {
     type Msg = WatchtowerMessage;
-    type State = WatchtowerState;
-    type Arguments = CkbConfig;
+    type State = ();
+    type Arguments = ();
 
     async fn pre_start(
         &self,
         _myself: ActorRef<Self::Msg>,
-        config: Self::Arguments,
+        _args: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
-        let secret_key = config.read_secret_key()?;
-        Ok(Self::State { config, secret_key })
+        Ok(())
     }
 
     async fn handle(
         &self,
         _myself: ActorRef<Self::Msg>,
         message: Self::Msg,
-        state: &mut Self::State,
+        _state: &mut Self::State,
     ) -> Result<(), ActorProcessingErr> {
         match message {
             WatchtowerMessage::NetworkServiceEvent(event) => {
@@ -81,39 +71,38 @@ where
                         peer_id,
                         channel_id,
                         funding_tx_out_point,
+                    )
+                    | NetworkServiceEvent::ChannelSpliced(
+                        peer_id,
+                        channel_id,
+                        funding_tx_out_point,
                     ) => {
-                        let rpc_url = state.config.rpc_url.clone();
-                        tokio::task::block_in_place(move || {
-                            let ckb_client = CkbRpcClient::new(&rpc_url);
-                            match ckb_client
-                                .get_transaction(funding_tx_out_point.tx_hash().unpack())
-                            {
-                                Ok(Some(tx_with_status)) => {
-                                    if tx_with_status.tx_status.status != Status::Committed {
-                                        error!("Funding tx: {:?} is not committed yet, maybe it's a bug in the fn on_channel_ready", funding_tx_out_point);
-                                    } else if let Some(tx) = tx_with_status.transaction {
-                                        match tx.inner {
-                                            Either::Left(tx) => {
-                                                let tx: Transaction = tx.inner.into();
-                                                self.store.insert_watch_channel(
-                                                    channel_id,
-                                                    tx.raw().outputs().get(0).unwrap().lock(),
-                                                );
-                                            }
-                                            Either::Right(_tx) => {
-                                                // unreachable, ignore
-                                            }
-                                        }
+                        match self
+                            .chain_source
+                            .get_transaction(funding_tx_out_point.tx_hash())
+                            .await
+                        {
+                            Ok(Some(tx)) => {
+                                let index: u32 = funding_tx_out_point.index().unpack();
+                                match tx.output(index as usize) {
+                                    Some(output) => {
+                                        self.store.insert_watch_channel(channel_id, output.lock());
+                                    }
+                                    None => {
+                                        error!(
+                                            "Cannot find funding output: {:?} for channel: {:?}",
+                                            funding_tx_out_point, channel_id
+                                        );
                                     }
-                                }
-                                Ok(None) => {
-                                    error!("Cannot find funding tx: {:?} for channel: {:?} from peer: {:?}", funding_tx_out_point, channel_id, peer_id);
-                                }
-                                Err(err) => {
-                                    error!("Failed to get funding tx: {:?}", err);
                                 }
                             }
-                        });
+                            Ok(None) => {
+                                error!("Cannot find committed funding tx: {:?} for channel: {:?} from peer: {:?}", funding_tx_out_point, channel_id, peer_id);
+                            }
+                            Err(err) => {
+                                error!("Failed to get funding tx: {:?}", err);
+                            }
+                        }
                     }
                     NetworkServiceEvent::ChannelClosed(_peer_id, channel_id, _close_tx_hash) => {
                         self.store.remove_watch_channel(channel_id);
@@ -145,121 +134,22 @@ where
             }
             WatchtowerMessage::PeriodicCheck => {
                 for channel_data in self.store.get_watch_channels() {
-                    if channel_data.revocation_data.is_none() {
+                    let Some(revocation_data) = channel_data.revocation_data else {
                         continue;
+                    };
+                    if let Err(err) = self
+                        .check_channel(
+                            channel_data.channel_id,
+                            channel_data.funding_tx_lock,
+                            revocation_data,
+                        )
+                        .await
+                    {
+                        error!(
+                            "Failed to check channel {:?}: {:?}",
+                            channel_data.channel_id, err
+                        );
                     }
-                    let revocation_data = channel_data.revocation_data.unwrap();
-                    let secret_key = state.secret_key;
-                    let rpc_url = state.config.rpc_url.clone();
-                    tokio::task::block_in_place(move || {
-                        let ckb_client = CkbRpcClient::new(&rpc_url);
-                        let mut cell_collector = DefaultCellCollector::new(&rpc_url);
-                        let search_key = SearchKey {
-                            script: channel_data.funding_tx_lock.clone().into(),
-                            script_type: ScriptType::Lock,
-                            script_search_mode: Some(SearchMode::Exact),
-                            with_data: None,
-                            filter: None,
-                            group_by_transaction: None,
-                        };
-                        // we need two parties' signatures to unlock the funding tx, so we can check the last one transaction only to see if it's an old version commitment tx
-                        match ckb_client.get_transactions(
-                            search_key,
-                            Order::Desc,
-                            1u32.into(),
-                            None,
-                        ) {
-                            Ok(txs) => {
-                                if let Some(Tx::Ungrouped(tx)) = txs.objects.first() {
-                                    if matches!(tx.io_type, CellType::Input) {
-                                        match ckb_client.get_transaction(tx.tx_hash.clone()) {
-                                            Ok(Some(tx_with_status)) => {
-                                                if tx_with_status.tx_status.status
-                                                    != Status::Committed
-                                                {
-                                                    error!("Cannot find the commitment tx: {:?}, status is {:?}, maybe ckb indexer bug?", tx_with_status.tx_status.status, tx.tx_hash);
-                                                } else if let Some(tx) = tx_with_status.transaction
-                                                {
-                                                    match tx.inner {
-                                                        Either::Left(tx) => {
-                                                            let tx: Transaction = tx.inner.into();
-                                                            if tx.raw().outputs().len() == 1 {
-                                                                let output = tx
-                                                                    .raw()
-                                                                    .outputs()
-                                                                    .get(0)
-                                                                    .unwrap();
-                                                                let lock_args =
-                                                                    output.lock().args().raw_data();
-                                                                let commitment_number =
-                                                                    u64::from_le_bytes(
-                                                                        lock_args[28..36]
-                                                                            .try_into()
-                                                                            .unwrap(),
-                                                                    );
-                                                                if revocation_data.commitment_number
-                                                                    >= commitment_number
-                                                                {
-                                                                    warn!("Found an old version commitment tx: {:?}, revocation commitment number: {}, commitment number: {}", tx.calc_tx_hash(), revocation_data.commitment_number, commitment_number);
-                                                                    let commitment_tx_out_point =
-                                                                        OutPoint::new(
-                                                                            tx.calc_tx_hash(),
-                                                                            0,
-                                                                        );
-                                                                    match build_revocation_tx(
-                                                                        commitment_tx_out_point,
-                                                                        revocation_data,
-                                                                        secret_key,
-                                                                        &mut cell_collector,
-                                                                    ) {
-                                                                        Ok(tx) => {
-                                                                            match ckb_client
-                                                                                .send_transaction(
-                                                                                    tx.data()
-                                                                                        .into(),
-                                                                                    None,
-                                                                                ) {
-                                                                                Ok(tx_hash) => {
-                                                                                    info!("Revocation tx: {:?} sent, tx_hash: {:?}", tx, tx_hash);
-                                                                                }
-                                                                                Err(err) => {
-                                                                                    error!("Failed to send revocation tx: {:?}, error: {:?}", tx, err);
-                                                                                }
-                                                                            }
-                                                                        }
-                                                                        Err(err) => {
-                                                                            error!("Failed to build revocation tx: {:?}", err);
-                                                                        }
-                                                                    }
-                                                                }
-                                                            } else {
-                                                                // there may be a race condition that PeriodicCheck is triggered before the remove_channel fn is called
-                                                                // it's a close channel tx, ignore
-                                                            }
-                                                        }
-                                                        Either::Right(_tx) => {
-                                                            // unreachable, ignore
-                                                        }
-                                                    }
-                                                } else {
-                                                    error!("Cannot find the commitment tx: {:?}, transcation is none, maybe ckb indexer bug?", tx.tx_hash);
-                                                }
-                                            }
-                                            Ok(None) => {
-                                                error!("Cannot find the commitment tx: {:?}, maybe ckb indexer bug?", tx.tx_hash);
-                                            }
-                                            Err(err) => {
-                                                error!("Failed to get funding tx: {:?}", err);
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                            Err(err) => {
-                                error!("Failed to get transactions: {:?}", err);
-                            }
-                        }
-                    });
                 }
             }
         }
@@ -267,12 +157,83 @@ where
     }
 }
 
+impl<S: WatchtowerStore, C: ChainSource> WatchtowerActor<S, C> {
+    /// Revoke the commitment transaction of the channel if it's an old version.
+    async fn check_channel(
+        &self,
+        channel_id: Hash256,
+        funding_tx_lock: Script,
+        revocation_data: RevocationData,
+    ) -> Result<(), ChainSourceError> {
+        if let Some(commitment_tx) = get_revoked_commitment_tx(
+            &self.chain_source,
+            funding_tx_lock,
+            revocation_data.commitment_number,
+        )
+        .await?
+        {
+            info!("Revoking commitment tx of channel: {:?}", channel_id);
+            send_revocation_tx(&self.chain_source, &commitment_tx, revocation_data).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Get the transaction consuming the funding cell if it's an old version commitment transaction,
+/// which is revoked by the revocation data of the commitment number.
+pub(super) async fn get_revoked_commitment_tx<C: ChainSource>(
+    chain_source: &C,
+    funding_tx_lock: Script,
+    revocation_commitment_number: u64,
+) -> Result<Option<TransactionView>, ChainSourceError> {
+    // we need two parties' signatures to unlock the funding tx, so we can check the last one transaction only to see if it's an old version commitment tx
+    let Some(tx) = chain_source
+        .get_spending_transaction(funding_tx_lock)
+        .await?
+    else {
+        return Ok(None);
+    };
+    if tx.outputs().len() != 1 {
+        // there may be a race condition that PeriodicCheck is triggered before the remove_channel fn is called
+        // it's a close channel tx, ignore
+        return Ok(None);
+    }
+    let output = tx.outputs().get(0).unwrap();
+    let Some(CommitmentLockArgs {
+        commitment_number, ..
+    }) = CommitmentLockArgs::decode(&output.lock().args().raw_data())
+    else {
+        return Ok(None);
+    };
+    if revocation_commitment_number < commitment_number {
+        return Ok(None);
+    }
+    warn!("Found an old version commitment tx: {:?}, revocation commitment number: {}, commitment number: {}", tx.hash(), revocation_commitment_number, commitment_number);
+    Ok(Some(tx))
+}
+
+/// Build, fund and send the transaction to revoke the old version commitment transaction.
+pub(super) async fn send_revocation_tx<C: ChainSource>(
+    chain_source: &C,
+    commitment_tx: &TransactionView,
+    revocation_data: RevocationData,
+) -> Result<(), ChainSourceError> {
+    let revocation_tx =
+        build_revocation_tx(OutPoint::new(commitment_tx.hash(), 0), revocation_data);
+    let revocation_tx = chain_source
+        .fund_fee(revocation_tx, REVOCATION_TX_FEE_RATE)
+        .await?;
+    let tx_hash = revocation_tx.hash();
+    chain_source.send_transaction(revocation_tx).await?;
+    info!("Revocation tx: {:?} sent", tx_hash);
+    Ok(())
+}
+
+/// Build the transaction to revoke an old version commitment transaction, the fee is not paid yet.
 fn build_revocation_tx(
     commitment_tx_out_point: OutPoint,
     revocation_data: RevocationData,
-    secret_key: SecretKey,
-    cell_collector: &mut DefaultCellCollector,
-) -> Result<TransactionView, Box<dyn std::error::Error>> {
+) -> TransactionView {
     let empty_witness_args = [16, 0, 0, 0, 16, 0, 0, 0, 16, 0, 0, 0, 16, 0, 0, 0];
     let witness = [
         empty_witness_args.to_vec(),
@@ -283,25 +244,10 @@ fn build_revocation_tx(
     ]
     .concat();
 
-    let pubkey = PublicKey::from_secret_key(&Secp256k1::new(), &secret_key);
-    let args = blake160(pubkey.serialize().as_ref());
-    let fee_provider_lock_script = get_script_by_contract(Contract::Secp256k1Lock, args.as_bytes());
-
-    let change_output = CellOutput::new_builder()
-        .lock(fee_provider_lock_script.clone())
-        .build();
-    let change_output_occupied_capacity = change_output
-        .occupied_capacity(Capacity::shannons(0))
-        .unwrap()
-        .as_u64();
-    let placeholder_witness = WitnessArgs::new_builder()
-        .lock(Some(ckb_types::bytes::Bytes::from(vec![0u8; 65])).pack())
-        .build();
-
-    let mut tx_builder = Transaction::default()
+    Transaction::default()
         .as_advanced_builder()
         .cell_deps(get_cell_deps(
-            vec![Contract::CommitmentLock, Contract::Secp256k1Lock],
+            vec![Contract::CommitmentLock],
             &revocation_data.output.type_().to_opt(),
         ))
         .input(
@@ -309,74 +255,308 @@ fn build_revocation_tx(
                 .previous_output(commitment_tx_out_point)
                 .build(),
         )
-        .output(revocation_data.output.clone())
+        .output(revocation_data.output)
         .output_data(revocation_data.output_data)
         .witness(witness.pack())
-        .output(change_output.clone())
-        .output_data(Bytes::default())
-        .witness(placeholder_witness.as_bytes().pack());
-
-    // TODO: move it to config or use https://github.com/nervosnetwork/ckb/pull/4477
-    let fee_calculator = FeeCalculator::new(1000);
-
-    let mut query = CellQueryOptions::new_lock(fee_provider_lock_script);
-    query.script_search_mode = Some(SearchMode::Exact);
-    query.secondary_script_len_range = Some(ValueRangeOption::new_exact(0));
-    query.data_len_range = Some(ValueRangeOption::new_exact(0));
-    let (cells, _total_capacity) = cell_collector.collect_live_cells(&query, true)?;
-
-    let mut inputs_capacity = 0u64;
-    for cell in cells {
-        let input_capacity: u64 = cell.output.capacity().unpack();
-        inputs_capacity += input_capacity;
-        tx_builder = tx_builder.input(
-            CellInput::new_builder()
-                .previous_output(cell.out_point)
-                .build(),
+        .build()
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use ckb_types::{
+        core::TransactionView,
+        packed::{self, CellOutput, OutPoint, Script},
+        prelude::*,
+    };
+    use ractor::{call, Actor};
+    use tempfile::tempdir;
+
+    use crate::{
+        ckb::{
+            contracts::{get_script_by_contract, CommitmentLockArgs, Contract},
+            ChainSource, ChainSourceError,
+        },
+        fiber::{
+            channel::{ChannelCommand, ChannelCommandWithId},
+            network::{AcceptChannelCommand, OpenChannelCommand},
+            test_utils::{init_tracing, NetworkNode},
+            types::Hash256,
+            NetworkActorCommand, NetworkActorMessage,
+        },
+        store::Store,
+        watchtower::RevocationData,
+        NetworkServiceEvent,
+    };
+
+    use super::{get_revoked_commitment_tx, WatchtowerActor, WatchtowerMessage};
+
+    /// A channel between node_a and node_b, in which node_b has revoked its first commitment transaction.
+    pub(in crate::watchtower) struct RevokedChannel {
+        pub node_a: NetworkNode,
+        pub node_b: NetworkNode,
+        pub channel_id: Hash256,
+        pub funding_tx_out_point: OutPoint,
+        pub old_commitment_tx: TransactionView,
+        pub revocation_data: RevocationData,
+    }
+
+    impl RevokedChannel {
+        pub async fn new() -> Self {
+            init_tracing();
+
+            let [mut node_a, mut node_b] = NetworkNode::new_n_interconnected_nodes().await;
+
+            let message = |rpc_reply| {
+                NetworkActorMessage::Command(NetworkActorCommand::OpenChannel(
+                    OpenChannelCommand {
+                        peer_id: node_b.peer_id.clone(),
+                        public: false,
+                        shutdown_script: None,
+                        funding_amount: 100000000000,
+                        funding_udt_type_script: None,
+                        commitment_fee_rate: None,
+                        funding_fee_rate: None,
+                        tlc_locktime_expiry_delta: None,
+                        tlc_min_value: None,
+                        tlc_max_value: None,
+                        tlc_fee_proportional_millionths: None,
+                        max_num_of_accept_tlcs: None,
+                        max_tlc_value_in_flight: None,
+                    },
+                    rpc_reply,
+                ))
+            };
+            let open_channel_result = call!(node_a.network_actor, message)
+                .expect("node_a alive")
+                .expect("open channel success");
+
+            node_b
+                .expect_event(|event| {
+                    matches!(event, NetworkServiceEvent::ChannelPendingToBeAccepted(..))
+                })
+                .await;
+            let message = |rpc_reply| {
+                NetworkActorMessage::Command(NetworkActorCommand::AcceptChannel(
+                    AcceptChannelCommand {
+                        temp_channel_id: open_channel_result.channel_id,
+                        funding_amount: 6200000000,
+                        shutdown_script: None,
+                    },
+                    rpc_reply,
+                ))
+            };
+            let accept_channel_result = call!(node_b.network_actor, message)
+                .expect("node_b alive")
+                .expect("accept channel success");
+            let channel_id = accept_channel_result.new_channel_id;
+
+            // The commitment transaction of node_b which is revoked later.
+            let old_commitment_tx = node_b
+                .expect_to_process_event(|event| match event {
+                    NetworkServiceEvent::RemoteCommitmentSigned(_, _, _, tx) => Some(tx.clone()),
+                    _ => None,
+                })
+                .await;
+
+            let funding_tx_out_point = node_a
+                .expect_to_process_event(|event| match event {
+                    NetworkServiceEvent::ChannelReady(_, _, out_point) => Some(out_point.clone()),
+                    _ => None,
+                })
+                .await;
+            node_b
+                .expect_event(|event| matches!(event, NetworkServiceEvent::ChannelReady(..)))
+                .await;
+
+            node_a
+                .network_actor
+                .send_message(NetworkActorMessage::Command(
+                    NetworkActorCommand::ControlFiberChannel(ChannelCommandWithId {
+                        channel_id,
+                        command: ChannelCommand::CommitmentSigned(),
+                    }),
+                ))
+                .expect("node_a alive");
+
+            let revocation_data = node_a
+                .expect_to_process_event(|event| match event {
+                    NetworkServiceEvent::RevokeAndAckReceived(
+                        _,
+                        _,
+                        commitment_number,
+                        x_only_aggregated_pubkey,
+                        signature,
+                        output,
+                        output_data,
+                    ) => Some(RevocationData {
+                        commitment_number: *commitment_number,
+                        x_only_aggregated_pubkey: *x_only_aggregated_pubkey,
+                        aggregated_signature: signature.clone(),
+                        output: output.clone(),
+                        output_data: output_data.clone(),
+                    }),
+                    _ => None,
+                })
+                .await;
+
+            Self {
+                node_a,
+                node_b,
+                channel_id,
+                funding_tx_out_point,
+                old_commitment_tx,
+                revocation_data,
+            }
+        }
+
+        /// Broadcast the revoked commitment transaction by node_b.
+        pub async fn broadcast_old_commitment_tx(&mut self) {
+            self.node_a.submit_tx(self.old_commitment_tx.clone()).await;
+        }
+
+        /// Wait for the transaction consuming the commitment cell of the revoked commitment transaction.
+        pub async fn wait_for_revocation_tx(&self) -> TransactionView {
+            let commitment_lock = self.old_commitment_tx.outputs().get(0).unwrap().lock();
+            for _ in 0..50 {
+                if let Some(tx) = self
+                    .node_a
+                    .chain_actor
+                    .get_spending_transaction(commitment_lock.clone())
+                    .await
+                    .expect("get spending transaction")
+                {
+                    assert_eq!(
+                        tx.inputs().get(0).unwrap().previous_output(),
+                        OutPoint::new(self.old_commitment_tx.hash(), 0)
+                    );
+                    return tx;
+                }
+                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+            }
+            panic!("revocation tx is not sent");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_watchtower_revoke_old_commitment_transaction() {
+        let mut channel = RevokedChannel::new().await;
+
+        let dir = tempdir().unwrap();
+        let (watchtower_actor, _handle) = Actor::spawn(
+            None,
+            WatchtowerActor::new(Store::new(dir.path()), channel.node_a.chain_actor.clone()),
+            (),
+        )
+        .await
+        .expect("start watchtower actor");
+        let revocation_data = channel.revocation_data.clone();
+        for event in [
+            NetworkServiceEvent::ChannelReady(
+                channel.node_b.peer_id.clone(),
+                channel.channel_id,
+                channel.funding_tx_out_point.clone(),
+            ),
+            NetworkServiceEvent::RevokeAndAckReceived(
+                channel.node_b.peer_id.clone(),
+                channel.channel_id,
+                revocation_data.commitment_number,
+                revocation_data.x_only_aggregated_pubkey,
+                revocation_data.aggregated_signature,
+                revocation_data.output,
+                revocation_data.output_data,
+            ),
+        ] {
+            watchtower_actor
+                .send_message(WatchtowerMessage::NetworkServiceEvent(event))
+                .expect("watchtower alive");
+        }
+
+        channel.broadcast_old_commitment_tx().await;
+        watchtower_actor
+            .send_message(WatchtowerMessage::PeriodicCheck)
+            .expect("watchtower alive");
+
+        let revocation_tx = channel.wait_for_revocation_tx().await;
+        assert_eq!(
+            revocation_tx.outputs().get(0),
+            Some(channel.revocation_data.output)
         );
-        let fee =
-            fee_calculator.fee(tx_builder.clone().build().data().serialized_size_in_block() as u64);
-        if inputs_capacity >= change_output_occupied_capacity + fee {
-            let new_change_output = change_output
-                .as_builder()
-                .capacity((inputs_capacity - fee).pack())
-                .build();
-            let tx = tx_builder
-                .set_outputs(vec![revocation_data.output, new_change_output])
-                .build();
-
-            let tx = sign_revocation_tx(tx, secret_key)?;
-            return Ok(tx);
+    }
+
+    /// A chain source in which the funding cell is consumed by the given transaction.
+    struct SpentFundingCell(TransactionView);
+
+    #[ractor::async_trait]
+    impl ChainSource for SpentFundingCell {
+        async fn get_transaction(
+            &self,
+            _tx_hash: packed::Byte32,
+        ) -> Result<Option<TransactionView>, ChainSourceError> {
+            Ok(Some(self.0.clone()))
+        }
+
+        async fn get_spending_transaction(
+            &self,
+            _lock_script: Script,
+        ) -> Result<Option<TransactionView>, ChainSourceError> {
+            Ok(Some(self.0.clone()))
+        }
+
+        async fn fund_fee(
+            &self,
+            tx: TransactionView,
+            _fee_rate: u64,
+        ) -> Result<TransactionView, ChainSourceError> {
+            Ok(tx)
+        }
+
+        async fn send_transaction(&self, _tx: TransactionView) -> Result<(), ChainSourceError> {
+            Ok(())
         }
     }
 
-    Err(Box::new(RpcError::Other(anyhow!("Not enough capacity"))))
-}
+    #[tokio::test]
+    async fn test_get_revoked_commitment_tx_with_large_commitment_number() {
+        // The commitment number is encoded in big endian, while the delay is in little endian.
+        let commitment_number = 0x0102u64;
+        let delay = 0x2000_0000_0000_0090u64;
+        let args = [
+            [42u8; 20].as_slice(),
+            delay.to_le_bytes().as_slice(),
+            commitment_number.to_be_bytes().as_slice(),
+        ]
+        .concat();
+        assert_eq!(
+            CommitmentLockArgs::decode(&args),
+            Some(CommitmentLockArgs {
+                pubkey_hash: [42u8; 20],
+                delay,
+                commitment_number,
+            })
+        );
+        assert_eq!(CommitmentLockArgs::decode(&args[0..35]), None);
+
+        let commitment_tx = TransactionView::new_advanced_builder()
+            .output(
+                CellOutput::new_builder()
+                    .lock(get_script_by_contract(Contract::CommitmentLock, &args))
+                    .build(),
+            )
+            .output_data(Default::default())
+            .build();
+        let chain_source = SpentFundingCell(commitment_tx.clone());
+        let funding_tx_lock = get_script_by_contract(Contract::FundingLock, &[0u8; 20]);
 
-fn sign_revocation_tx(
-    tx: TransactionView,
-    secret_key: SecretKey,
-) -> Result<TransactionView, Box<dyn std::error::Error>> {
-    let tx = tx.data();
-    let witness = tx.witnesses().get(1).unwrap();
-    let mut blake2b = new_blake2b();
-    blake2b.update(tx.calc_tx_hash().as_slice());
-    blake2b.update(&(witness.as_bytes().len() as u64).to_le_bytes());
-    blake2b.update(&witness.as_bytes());
-    let mut message = vec![0u8; 32];
-    blake2b.finalize(&mut message);
-    let secp256k1_message = Message::from_digest_slice(&message)?;
-    let secp256k1 = Secp256k1::new();
-    let signature = secp256k1.sign_ecdsa_recoverable(&secp256k1_message, &secret_key);
-    let (recov_id, data) = signature.serialize_compact();
-    let mut signature_bytes = [0u8; 65];
-    signature_bytes[0..64].copy_from_slice(&data[0..64]);
-    signature_bytes[64] = recov_id.to_i32() as u8;
-
-    let witness = WitnessArgs::new_builder()
-        .lock(Some(ckb_types::bytes::Bytes::from(signature_bytes.to_vec())).pack())
-        .build();
-    let witnesses = vec![tx.witnesses().get(0).unwrap(), witness.as_bytes().pack()];
-
-    Ok(tx.as_advanced_builder().set_witnesses(witnesses).build())
+        // The commitment transaction is not revoked by the revocation data of older commitments.
+        let revoked_tx = get_revoked_commitment_tx(&chain_source, funding_tx_lock.clone(), 0xFF)
+            .await
+            .expect("get revoked commitment tx");
+        assert!(revoked_tx.is_none());
+
+        let revoked_tx =
+            get_revoked_commitment_tx(&chain_source, funding_tx_lock, commitment_number)
+                .await
+                .expect("get revoked commitment tx");
+        assert_eq!(revoked_tx.map(|tx| tx.hash()), Some(commitment_tx.hash()));
+    }
 }
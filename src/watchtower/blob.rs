@@ -0,0 +1,97 @@
+use chacha20poly1305::{
+    aead::{Aead, KeyInit},
+    ChaCha20Poly1305, Nonce,
+};
+use ckb_hash::new_blake2b;
+
+use super::{EncryptedRevocationData, RevocationData};
+
+// The key to encrypt the revocation data of a channel is derived from the x-only aggregated
+// public key of the funding lock. Only the hash of the public key is in the funding lock args,
+// the public key itself is revealed in the witness of the transaction consuming the funding cell,
+// so the tower can't decrypt the revocation data until a commitment transaction is committed.
+fn new_cipher(funding_lock_xonly: &[u8; 32]) -> ChaCha20Poly1305 {
+    let mut hasher = new_blake2b();
+    hasher.update(b"FIBER_WATCHTOWER_BLOB");
+    hasher.update(funding_lock_xonly);
+    let mut key = [0u8; 32];
+    hasher.finalize(&mut key);
+    ChaCha20Poly1305::new_from_slice(&key).expect("valid key length")
+}
+
+// The commitment number is increased for every revocation of a channel,
+// so the nonce is never reused with the same key.
+fn new_nonce(commitment_number: u64) -> Nonce {
+    let mut nonce = [0u8; 12];
+    nonce[4..].copy_from_slice(&commitment_number.to_be_bytes());
+    *Nonce::from_slice(&nonce)
+}
+
+pub fn encrypt_revocation_data(
+    funding_lock_xonly: &[u8; 32],
+    revocation_data: &RevocationData,
+) -> EncryptedRevocationData {
+    let plaintext =
+        serde_json::to_vec(revocation_data).expect("serialize RevocationData should be OK");
+    let blob = new_cipher(funding_lock_xonly)
+        .encrypt(
+            &new_nonce(revocation_data.commitment_number),
+            plaintext.as_slice(),
+        )
+        .expect("encrypt revocation data");
+    EncryptedRevocationData {
+        commitment_number: revocation_data.commitment_number,
+        blob,
+    }
+}
+
+/// Decrypt the revocation data with the funding lock public key, returns None if the key is wrong
+/// or the blob is tampered.
+pub fn decrypt_revocation_data(
+    funding_lock_xonly: &[u8; 32],
+    encrypted: &EncryptedRevocationData,
+) -> Option<RevocationData> {
+    let plaintext = new_cipher(funding_lock_xonly)
+        .decrypt(
+            &new_nonce(encrypted.commitment_number),
+            encrypted.blob.as_slice(),
+        )
+        .ok()?;
+    serde_json::from_slice::<RevocationData>(&plaintext)
+        .ok()
+        .filter(|data| data.commitment_number == encrypted.commitment_number)
+}
+
+#[cfg(test)]
+mod tests {
+    use ckb_types::packed::{Bytes, CellOutput};
+    use musig2::CompactSignature;
+
+    use super::{decrypt_revocation_data, encrypt_revocation_data};
+    use crate::watchtower::RevocationData;
+
+    #[test]
+    fn test_encrypt_and_decrypt_revocation_data() {
+        let revocation_data = RevocationData {
+            commitment_number: 5,
+            x_only_aggregated_pubkey: [1u8; 32],
+            aggregated_signature: CompactSignature::from_bytes(&[0u8; 64]).unwrap(),
+            output: CellOutput::default(),
+            output_data: Bytes::default(),
+        };
+        let funding_lock_xonly = [2u8; 32];
+        let mut encrypted = encrypt_revocation_data(&funding_lock_xonly, &revocation_data);
+        assert_eq!(encrypted.commitment_number, 5);
+        assert_eq!(
+            decrypt_revocation_data(&funding_lock_xonly, &encrypted),
+            Some(revocation_data)
+        );
+        assert_eq!(decrypt_revocation_data(&[3u8; 32], &encrypted), None);
+
+        encrypted.commitment_number = 6;
+        assert_eq!(
+            decrypt_revocation_data(&funding_lock_xonly, &encrypted),
+            None
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use ractor::{Actor, ActorProcessingErr, ActorRef};
+use serde::Serialize;
+use serde_json::json;
+use thiserror::Error;
+use tracing::{debug, error, warn};
+
+use crate::{
+    fiber::{
+        channel::ChannelActorStateStore,
+        types::{Hash256, Privkey},
+    },
+    NetworkServiceEvent,
+};
+
+use super::{
+    encrypt_revocation_data, RemoveChannelRequest, RevocationData, TowerRequest, TowerRequestBody,
+    UpdateRevocationRequest, WatchChannelRequest,
+};
+
+#[derive(Error, Debug)]
+pub enum TowerClientError {
+    #[error("Failed to send request to the tower: {0}")]
+    HttpError(#[from] reqwest::Error),
+    #[error("The tower returned an error: {0}")]
+    RpcError(String),
+}
+
+/// The client which uploads the revocation data of the node's channels to the remote towers,
+/// so the channels are still protected while the node is offline.
+pub struct TowerClientActor<S> {
+    store: S,
+}
+
+impl<S: ChannelActorStateStore> TowerClientActor<S> {
+    pub fn new(store: S) -> Self {
+        Self { store }
+    }
+}
+
+pub enum TowerClientMessage {
+    WatchChannel(Hash256),
+    RemoveChannel(Hash256),
+    UpdateRevocation(Hash256, RevocationData),
+}
+
+impl TowerClientMessage {
+    /// The message to notify the towers of the network service event, if any.
+    pub fn from_event(event: &NetworkServiceEvent) -> Option<Self> {
+        match event {
+            // The spliced channel is watched again with the lock script of the new funding cell.
+            NetworkServiceEvent::ChannelReady(_peer_id, channel_id, _funding_tx_out_point)
+            | NetworkServiceEvent::ChannelSpliced(_peer_id, channel_id, _funding_tx_out_point) => {
+                Some(Self::WatchChannel(*channel_id))
+            }
+            NetworkServiceEvent::ChannelClosed(_peer_id, channel_id, _close_tx_hash) => {
+                Some(Self::RemoveChannel(*channel_id))
+            }
+            NetworkServiceEvent::RevokeAndAckReceived(
+                _peer_id,
+                channel_id,
+                commitment_number,
+                aggregated_pubkey,
+                signature,
+                output,
+                output_data,
+            ) => Some(Self::UpdateRevocation(
+                *channel_id,
+                RevocationData {
+                    commitment_number: *commitment_number,
+                    x_only_aggregated_pubkey: *aggregated_pubkey,
+                    aggregated_signature: signature.clone(),
+                    output: output.clone(),
+                    output_data: output_data.clone(),
+                },
+            )),
+            _ => None,
+        }
+    }
+}
+
+pub struct TowerClientState {
+    /// The JSON-RPC urls of the towers.
+    towers: Vec<String>,
+    /// The node private key to sign the requests, the towers identify the client by the public key.
+    privkey: Privkey,
+    /// The timestamp of the last request, the towers reject the requests which are not newer.
+    last_timestamp: u64,
+    http_client: reqwest::Client,
+}
+
+#[ractor::async_trait]
+impl<S> Actor for TowerClientActor<S>
+where
+    S: ChannelActorStateStore + Send + Sync + 'static,
+{
+    type Msg = TowerClientMessage;
+    type State = TowerClientState;
+    type Arguments = (Vec<String>, Privkey);
+
+    async fn pre_start(
+        &self,
+        _myself: ActorRef<Self::Msg>,
+        (towers, privkey): Self::Arguments,
+    ) -> Result<Self::State, ActorProcessingErr> {
+        Ok(TowerClientState {
+            towers,
+            privkey,
+            last_timestamp: 0,
+            http_client: reqwest::Client::new(),
+        })
+    }
+
+    async fn handle(
+        &self,
+        _myself: ActorRef<Self::Msg>,
+        message: Self::Msg,
+        state: &mut Self::State,
+    ) -> Result<(), ActorProcessingErr> {
+        match message {
+            TowerClientMessage::WatchChannel(channel_id) => {
+                let Some(channel_state) = self.store.get_channel_actor_state(&channel_id) else {
+                    error!("Cannot find channel {:?} to watch", channel_id);
+                    return Ok(());
+                };
+                let request = WatchChannelRequest {
+                    channel_id,
+                    funding_tx_lock: channel_state.get_funding_lock_script(),
+                };
+                state.send_request("create_watch_channel", request).await;
+            }
+            TowerClientMessage::RemoveChannel(channel_id) => {
+                state
+                    .send_request("remove_watch_channel", RemoveChannelRequest { channel_id })
+                    .await;
+            }
+            TowerClientMessage::UpdateRevocation(channel_id, revocation_data) => {
+                let Some(channel_state) = self.store.get_channel_actor_state(&channel_id) else {
+                    error!("Cannot find channel {:?} to update revocation", channel_id);
+                    return Ok(());
+                };
+                let request = UpdateRevocationRequest {
+                    channel_id,
+                    revocation: encrypt_revocation_data(
+                        &channel_state.get_funding_lock_script_xonly(),
+                        &revocation_data,
+                    ),
+                };
+                state.send_request("update_revocation", request).await;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TowerClientState {
+    // A failed upload is not retried, as the revocation data of a later commitment number
+    // also revokes all the previous commitment transactions.
+    async fn send_request<T: TowerRequestBody + Serialize>(&mut self, method: &str, body: T) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("SystemTime::now() should after UNIX_EPOCH")
+            .as_millis() as u64;
+        self.last_timestamp = now.max(self.last_timestamp + 1);
+        let request = TowerRequest::new(&self.privkey, body, self.last_timestamp);
+        for tower in &self.towers {
+            match self.call_tower(tower, method, &request).await {
+                Ok(()) => debug!("Sent {} to tower {}", method, tower),
+                Err(err) => warn!("Failed to send {} to tower {}: {}", method, tower, err),
+            }
+        }
+    }
+
+    async fn call_tower<T: Serialize>(
+        &self,
+        tower: &str,
+        method: &str,
+        params: &T,
+    ) -> Result<(), TowerClientError> {
+        let response: serde_json::Value = self
+            .http_client
+            .post(tower)
+            .json(&json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": method,
+                "params": [params],
+            }))
+            .send()
+            .await?
+            .json()
+            .await?;
+        match response.get("error") {
+            Some(error) => Err(TowerClientError::RpcError(error.to_string())),
+            None => Ok(()),
+        }
+    }
+}
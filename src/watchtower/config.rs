@@ -0,0 +1,56 @@
+use clap_serde_derive::{
+    clap::{self},
+    ClapSerde,
+};
+use std::{fs, path::PathBuf};
+
+use crate::fiber::config::CkbNetwork;
+
+pub const DEFAULT_WATCHTOWER_BASE_DIR_NAME: &str = "watchtower";
+/// The interval to check the client channels, in seconds.
+pub const DEFAULT_CHECK_INTERVAL_SECONDS: u64 = 60;
+
+// Use prefix `watchtower-`/`WATCHTOWER_`
+#[derive(ClapSerde, Debug, Clone)]
+pub struct WatchtowerConfig {
+    /// watchtower base directory
+    #[arg(
+        name = "WATCHTOWER_BASE_DIR",
+        long = "watchtower-base-dir",
+        env,
+        help = format!("base directory for the standalone watchtower [default: $BASE_DIR/{}]", DEFAULT_WATCHTOWER_BASE_DIR_NAME)
+    )]
+    pub base_dir: Option<PathBuf>,
+
+    /// name of the network to use (can be any of `mocknet`/`mainnet`/`testnet`/`staging`/`dev`)
+    #[arg(name = "WATCHTOWER_NETWORK", long = "watchtower-network", env)]
+    pub network: Option<CkbNetwork>,
+
+    /// The interval to check whether the client channels are force closed with revoked commitment transactions, in seconds. [default: 60]
+    #[arg(
+        name = "WATCHTOWER_CHECK_INTERVAL_SECONDS",
+        long = "watchtower-check-interval-seconds",
+        env,
+        help = "The interval to check whether the client channels are force closed with revoked commitment transactions, in seconds. [default: 60]"
+    )]
+    pub check_interval_seconds: Option<u64>,
+}
+
+impl WatchtowerConfig {
+    pub fn base_dir(&self) -> &PathBuf {
+        self.base_dir.as_ref().expect("have set base dir")
+    }
+
+    pub fn store_path(&self) -> PathBuf {
+        let path = self.base_dir().join("store");
+        if !path.exists() {
+            fs::create_dir_all(&path).expect("create store directory");
+        }
+        path
+    }
+
+    pub fn check_interval_seconds(&self) -> u64 {
+        self.check_interval_seconds
+            .unwrap_or(DEFAULT_CHECK_INTERVAL_SECONDS)
+    }
+}
@@ -1,5 +1,19 @@
 mod actor;
+mod blob;
+mod client;
+mod config;
 mod store;
+mod tower;
 
 pub use actor::{WatchtowerActor, WatchtowerMessage};
-pub use store::{ChannelData, RevocationData, WatchtowerStore};
+pub use blob::{decrypt_revocation_data, encrypt_revocation_data};
+pub use client::{TowerClientActor, TowerClientMessage};
+pub use config::{WatchtowerConfig, DEFAULT_WATCHTOWER_BASE_DIR_NAME};
+pub use store::{
+    ChannelData, EncryptedRevocationData, RevocationData, TowerChannelData, TowerStore,
+    WatchtowerStore,
+};
+pub use tower::{
+    RemoveChannelRequest, TowerActor, TowerMessage, TowerRequest, TowerRequestBody,
+    UpdateRevocationRequest, WatchChannelRequest,
+};
@@ -3,13 +3,17 @@ use musig2::CompactSignature;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
-use crate::fiber::{serde_utils::EntityHex, types::Hash256};
+use crate::fiber::{
+    serde_utils::{EntityHex, SliceHex, U64Hex},
+    types::{Hash256, Pubkey},
+};
 
 pub trait WatchtowerStore {
     /// Get the channels that are currently being watched by the watchtower
     fn get_watch_channels(&self) -> Vec<ChannelData>;
     /// Insert a channel's funding tx lock script into the store, it will be used to monitor the channel,
     /// please note that the lock script should be globally unique, so that the watchtower can identify the channel.
+    /// The lock script of an existing channel is updated, while its revocation data is kept.
     fn insert_watch_channel(&self, channel_id: Hash256, funding_tx_lock: Script);
     /// Remove a channel from the store, the watchtower will stop monitoring the channel
     fn remove_watch_channel(&self, channel_id: Hash256);
@@ -38,3 +42,46 @@ pub struct RevocationData {
     #[serde_as(as = "EntityHex")]
     pub output_data: Bytes,
 }
+
+/// The store of the standalone watchtower, which watches the channels of multiple clients.
+pub trait TowerStore {
+    /// Get the channels of all the clients that are currently being watched by the tower
+    fn get_tower_channels(&self) -> Vec<TowerChannelData>;
+    /// Insert a channel of the client into the store, the client is identified by its node public key.
+    /// The funding tx lock script of an existing channel is updated, while its revocation is kept.
+    fn insert_tower_channel(&self, client: Pubkey, channel_id: Hash256, funding_tx_lock: Script);
+    /// Remove a channel of the client from the store, the tower will stop monitoring the channel
+    fn remove_tower_channel(&self, client: Pubkey, channel_id: Hash256);
+    /// Update the encrypted revocation data of a channel, it's ignored if the commitment number
+    /// is less than the stored one. The revocation of the same commitment number is replaced,
+    /// e.g. by the revocation of the commitment transaction spending the spliced funding cell.
+    fn update_tower_revocation(
+        &self,
+        client: Pubkey,
+        channel_id: Hash256,
+        revocation: EncryptedRevocationData,
+    );
+}
+
+/// The data of a client channel that the tower is monitoring
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct TowerChannelData {
+    pub client: Pubkey,
+    pub channel_id: Hash256,
+    #[serde_as(as = "EntityHex")]
+    pub funding_tx_lock: Script,
+    pub revocation: Option<EncryptedRevocationData>,
+}
+
+/// The revocation data encrypted by the client, the tower can only decrypt it after
+/// the funding cell is consumed. The commitment number is not encrypted, so that the
+/// tower can keep the latest revocation data only.
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct EncryptedRevocationData {
+    #[serde_as(as = "U64Hex")]
+    pub commitment_number: u64,
+    #[serde_as(as = "SliceHex")]
+    pub blob: Vec<u8>,
+}
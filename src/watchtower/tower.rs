@@ -0,0 +1,325 @@
+use ckb_hash::blake2b_256;
+use ckb_types::packed::Script;
+use ractor::{Actor, ActorProcessingErr, ActorRef};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use tracing::{error, info, warn};
+
+use crate::{
+    ckb::{ChainSource, ChainSourceError},
+    fiber::{
+        serde_utils::{EntityHex, U64Hex},
+        types::{EcdsaSignature, Hash256, Privkey, Pubkey},
+    },
+};
+
+use super::{
+    actor::{get_revoked_commitment_tx, send_revocation_tx},
+    decrypt_revocation_data, EncryptedRevocationData, TowerChannelData, TowerStore,
+};
+
+/// The request from a client node to a standalone tower, signed by the node private key.
+/// The timestamp in milliseconds is signed along with the body, the tower only accepts
+/// recent requests with increasing timestamps from a client, so they cannot be replayed.
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TowerRequest<T> {
+    pub client: Pubkey,
+    #[serde(flatten)]
+    pub body: T,
+    #[serde_as(as = "U64Hex")]
+    pub timestamp: u64,
+    pub signature: EcdsaSignature,
+}
+
+pub trait TowerRequestBody {
+    /// The message signed by the client, which is prefixed by the request type.
+    fn message(&self) -> [u8; 32];
+}
+
+impl<T: TowerRequestBody> TowerRequest<T> {
+    pub fn new(privkey: &Privkey, body: T, timestamp: u64) -> Self {
+        let signature = privkey.sign(Self::message(&body, timestamp));
+        Self {
+            client: privkey.pubkey(),
+            body,
+            timestamp,
+            signature,
+        }
+    }
+
+    fn message(body: &T, timestamp: u64) -> [u8; 32] {
+        blake2b_256([body.message().as_slice(), &timestamp.to_be_bytes()].concat())
+    }
+
+    pub fn verify(&self) -> bool {
+        self.signature
+            .verify(&self.client, &Self::message(&self.body, self.timestamp))
+    }
+}
+
+/// Watch a channel with the lock script of the funding cell.
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WatchChannelRequest {
+    pub channel_id: Hash256,
+    #[serde_as(as = "EntityHex")]
+    pub funding_tx_lock: Script,
+}
+
+impl TowerRequestBody for WatchChannelRequest {
+    fn message(&self) -> [u8; 32] {
+        blake2b_256(
+            [
+                b"watch_channel".as_slice(),
+                self.channel_id.as_ref(),
+                self.funding_tx_lock.as_slice(),
+            ]
+            .concat(),
+        )
+    }
+}
+
+/// Stop watching a channel, e.g. the channel is closed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RemoveChannelRequest {
+    pub channel_id: Hash256,
+}
+
+impl TowerRequestBody for RemoveChannelRequest {
+    fn message(&self) -> [u8; 32] {
+        blake2b_256([b"remove_channel".as_slice(), self.channel_id.as_ref()].concat())
+    }
+}
+
+/// Upload the latest revocation data of a channel.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UpdateRevocationRequest {
+    pub channel_id: Hash256,
+    pub revocation: EncryptedRevocationData,
+}
+
+impl TowerRequestBody for UpdateRevocationRequest {
+    fn message(&self) -> [u8; 32] {
+        blake2b_256(
+            [
+                b"update_revocation".as_slice(),
+                self.channel_id.as_ref(),
+                self.revocation.commitment_number.to_be_bytes().as_slice(),
+                self.revocation.blob.as_slice(),
+            ]
+            .concat(),
+        )
+    }
+}
+
+/// The standalone watchtower which watches the channels of the client nodes,
+/// the requests of the clients are verified before sent to the actor.
+pub struct TowerActor<S, C> {
+    store: S,
+    chain_source: C,
+}
+
+impl<S: TowerStore, C: ChainSource> TowerActor<S, C> {
+    pub fn new(store: S, chain_source: C) -> Self {
+        Self {
+            store,
+            chain_source,
+        }
+    }
+}
+
+pub enum TowerMessage {
+    WatchChannel(Pubkey, WatchChannelRequest),
+    RemoveChannel(Pubkey, RemoveChannelRequest),
+    UpdateRevocation(Pubkey, UpdateRevocationRequest),
+    PeriodicCheck,
+}
+
+#[ractor::async_trait]
+impl<S, C> Actor for TowerActor<S, C>
+where
+    S: TowerStore + Send + Sync + 'static,
+    C: ChainSource + 'static,
+{
+    type Msg = TowerMessage;
+    type State = ();
+    type Arguments = ();
+
+    async fn pre_start(
+        &self,
+        _myself: ActorRef<Self::Msg>,
+        _args: Self::Arguments,
+    ) -> Result<Self::State, ActorProcessingErr> {
+        Ok(())
+    }
+
+    async fn handle(
+        &self,
+        _myself: ActorRef<Self::Msg>,
+        message: Self::Msg,
+        _state: &mut Self::State,
+    ) -> Result<(), ActorProcessingErr> {
+        match message {
+            TowerMessage::WatchChannel(client, request) => {
+                self.store.insert_tower_channel(
+                    client,
+                    request.channel_id,
+                    request.funding_tx_lock,
+                );
+            }
+            TowerMessage::RemoveChannel(client, request) => {
+                self.store.remove_tower_channel(client, request.channel_id);
+            }
+            TowerMessage::UpdateRevocation(client, request) => {
+                self.store
+                    .update_tower_revocation(client, request.channel_id, request.revocation);
+            }
+            TowerMessage::PeriodicCheck => {
+                for channel_data in self.store.get_tower_channels() {
+                    let (client, channel_id) = (channel_data.client, channel_data.channel_id);
+                    if let Err(err) = self.check_channel(channel_data).await {
+                        error!(
+                            "Failed to check channel {:?} of client {:?}: {:?}",
+                            channel_id, client, err
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<S: TowerStore, C: ChainSource> TowerActor<S, C> {
+    /// Revoke the commitment transaction of the client channel if it's an old version.
+    async fn check_channel(&self, channel_data: TowerChannelData) -> Result<(), ChainSourceError> {
+        let Some(revocation) = channel_data.revocation else {
+            return Ok(());
+        };
+        let Some(commitment_tx) = get_revoked_commitment_tx(
+            &self.chain_source,
+            channel_data.funding_tx_lock,
+            revocation.commitment_number,
+        )
+        .await?
+        else {
+            return Ok(());
+        };
+
+        // The witness to consume the funding cell is `empty_witness_args | x-only aggregated public key | signature`
+        let funding_lock_xonly = commitment_tx
+            .witnesses()
+            .get(0)
+            .and_then(|witness| witness.raw_data().get(16..48).map(|xonly| xonly.to_vec()))
+            .and_then(|xonly| <[u8; 32]>::try_from(xonly).ok());
+        let Some(revocation_data) =
+            funding_lock_xonly.and_then(|xonly| decrypt_revocation_data(&xonly, &revocation))
+        else {
+            warn!(
+                "Failed to decrypt the revocation data of channel {:?} from client {:?}",
+                channel_data.channel_id, channel_data.client
+            );
+            return Ok(());
+        };
+
+        info!(
+            "Revoking commitment tx of channel {:?} for client {:?}",
+            channel_data.channel_id, channel_data.client
+        );
+        send_revocation_tx(&self.chain_source, &commitment_tx, revocation_data).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ractor::Actor;
+    use tempfile::tempdir;
+
+    use crate::{
+        fiber::{channel::ChannelActorStateStore, types::Privkey},
+        store::Store,
+        watchtower::{actor::tests::RevokedChannel, encrypt_revocation_data},
+    };
+
+    use super::{
+        TowerActor, TowerMessage, TowerRequest, UpdateRevocationRequest, WatchChannelRequest,
+    };
+
+    #[test]
+    fn test_verify_tower_request() {
+        let privkey = Privkey::from([1u8; 32]);
+        let mut request = TowerRequest::new(
+            &privkey,
+            WatchChannelRequest {
+                channel_id: [2u8; 32].into(),
+                funding_tx_lock: Default::default(),
+            },
+            1,
+        );
+        assert!(request.verify());
+
+        // The timestamp is signed, so a request cannot be replayed with a newer timestamp.
+        request.timestamp = 2;
+        assert!(!request.verify());
+
+        request.timestamp = 1;
+        request.client = Privkey::from([3u8; 32]).pubkey();
+        assert!(!request.verify());
+    }
+
+    #[tokio::test]
+    async fn test_tower_revoke_old_commitment_transaction() {
+        let mut channel = RevokedChannel::new().await;
+
+        let channel_state = channel
+            .node_a
+            .store
+            .get_channel_actor_state(&channel.channel_id)
+            .expect("channel state");
+        let funding_tx_lock = channel_state.get_funding_lock_script();
+        let funding_lock_xonly = channel_state.get_funding_lock_script_xonly();
+
+        let dir = tempdir().unwrap();
+        let (tower_actor, _handle) = Actor::spawn(
+            None,
+            TowerActor::new(Store::new(dir.path()), channel.node_a.chain_actor.clone()),
+            (),
+        )
+        .await
+        .expect("start tower actor");
+        let client = Privkey::from([1u8; 32]).pubkey();
+        tower_actor
+            .send_message(TowerMessage::WatchChannel(
+                client,
+                WatchChannelRequest {
+                    channel_id: channel.channel_id,
+                    funding_tx_lock,
+                },
+            ))
+            .expect("tower alive");
+        tower_actor
+            .send_message(TowerMessage::UpdateRevocation(
+                client,
+                UpdateRevocationRequest {
+                    channel_id: channel.channel_id,
+                    revocation: encrypt_revocation_data(
+                        &funding_lock_xonly,
+                        &channel.revocation_data,
+                    ),
+                },
+            ))
+            .expect("tower alive");
+
+        channel.broadcast_old_commitment_tx().await;
+        tower_actor
+            .send_message(TowerMessage::PeriodicCheck)
+            .expect("tower alive");
+
+        let revocation_tx = channel.wait_for_revocation_tx().await;
+        assert_eq!(
+            revocation_tx.outputs().get(0),
+            Some(channel.revocation_data.output)
+        );
+    }
+}